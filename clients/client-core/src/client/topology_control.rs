@@ -14,6 +14,12 @@ use tokio::sync::{RwLock, RwLockReadGuard};
 use tokio::task::JoinHandle;
 use topology::{nym_topology_from_bonds, NymTopology};
 use url::Url;
+use validator_client::validator_api::verification::TrustedApis;
+
+// The validator apis refresh their caches independently of each other (every 30s by default),
+// so a trusted api might legitimately be a few blocks behind the one whose topology we have
+// accepted before. Only snapshots lagging behind by more than that are rejected.
+const MAX_BLOCK_HEIGHT_LAG: u64 = 20;
 
 // I'm extremely curious why compiler NEVER complained about lack of Debug here before
 #[derive(Debug)]
@@ -130,6 +136,7 @@ pub struct TopologyRefresherConfig {
     validator_api_urls: Vec<Url>,
     refresh_rate: time::Duration,
     client_version: String,
+    trusted_apis: Option<TrustedApis>,
 }
 
 impl TopologyRefresherConfig {
@@ -142,8 +149,15 @@ impl TopologyRefresherConfig {
             validator_api_urls,
             refresh_rate,
             client_version,
+            trusted_apis: None,
         }
     }
+
+    /// Makes the refresher only accept topology signed by one of the specified validator apis.
+    pub fn with_trusted_apis(mut self, trusted_apis: TrustedApis) -> Self {
+        self.trusted_apis = Some(trusted_apis);
+        self
+    }
 }
 
 pub struct TopologyRefresher {
@@ -156,20 +170,33 @@ pub struct TopologyRefresher {
 
     currently_used_api: usize,
     was_latest_valid: bool,
+
+    verify_topology: bool,
+    // the highest block height of a verified topology we have accepted so far
+    latest_block_height: u64,
 }
 
 impl TopologyRefresher {
     pub fn new(mut cfg: TopologyRefresherConfig, topology_accessor: TopologyAccessor) -> Self {
         cfg.validator_api_urls.shuffle(&mut thread_rng());
 
+        let verify_topology = cfg.trusted_apis.is_some();
+        let mut validator_client =
+            validator_client::ApiClient::new(cfg.validator_api_urls[0].clone());
+        if let Some(trusted_apis) = cfg.trusted_apis {
+            validator_client = validator_client.with_trusted_apis(trusted_apis);
+        }
+
         TopologyRefresher {
-            validator_client: validator_client::ApiClient::new(cfg.validator_api_urls[0].clone()),
+            validator_client,
             client_version: cfg.client_version,
             validator_api_urls: cfg.validator_api_urls,
             topology_accessor,
             refresh_rate: cfg.refresh_rate,
             currently_used_api: 0,
             was_latest_valid: true,
+            verify_topology,
+            latest_block_height: 0,
         }
     }
 
@@ -243,30 +270,88 @@ impl TopologyRefresher {
         true
     }
 
-    async fn get_current_compatible_topology(&self) -> Option<NymTopology> {
+    async fn get_current_compatible_topology(&mut self) -> Option<NymTopology> {
         // TODO: optimization for the future:
         // only refresh mixnodes on timer and refresh gateways only when
         // we have to send to a new, unknown, gateway
 
-        let mixnodes = match self.validator_client.get_cached_active_mixnodes().await {
-            Err(err) => {
-                error!("failed to get network mixnodes - {}", err);
-                return None;
-            }
-            Ok(mixes) => mixes,
-        };
+        let (mixnodes, gateways, layer_assignment) = if self.verify_topology {
+            let mixnodes = match self.validator_client.get_verified_active_mixnodes().await {
+                Err(err) => {
+                    error!("failed to get verified network mixnodes - {}", err);
+                    return None;
+                }
+                Ok(snapshot) => snapshot,
+            };
 
-        let gateways = match self.validator_client.get_cached_gateways().await {
-            Err(err) => {
-                error!("failed to get network gateways - {}", err);
+            let gateways = match self.validator_client.get_verified_gateways().await {
+                Err(err) => {
+                    error!("failed to get verified network gateways - {}", err);
+                    return None;
+                }
+                Ok(snapshot) => snapshot,
+            };
+
+            let layer_assignment = match self.validator_client.get_verified_layer_assignment().await
+            {
+                Err(err) => {
+                    error!("failed to get verified mixnode layer assignment - {}", err);
+                    return None;
+                }
+                Ok(snapshot) => snapshot,
+            };
+
+            // signatures only tell us who has produced the topology, so make sure a lagging
+            // (or malicious) api can't make us go back to an older view of the network
+            let block_height = mixnodes
+                .block_height
+                .min(gateways.block_height)
+                .min(layer_assignment.block_height);
+            if block_height.saturating_add(MAX_BLOCK_HEIGHT_LAG) < self.latest_block_height {
+                warn!(
+                    "the validator api returned topology from block {} while we have already seen one from block {}",
+                    block_height, self.latest_block_height
+                );
                 return None;
             }
-            Ok(gateways) => gateways,
+            self.latest_block_height = self.latest_block_height.max(block_height);
+
+            (mixnodes.nodes, gateways.nodes, layer_assignment.nodes)
+        } else {
+            let mixnodes = match self.validator_client.get_cached_active_mixnodes().await {
+                Err(err) => {
+                    error!("failed to get network mixnodes - {}", err);
+                    return None;
+                }
+                Ok(mixes) => mixes,
+            };
+
+            let gateways = match self.validator_client.get_cached_gateways().await {
+                Err(err) => {
+                    error!("failed to get network gateways - {}", err);
+                    return None;
+                }
+                Ok(gateways) => gateways,
+            };
+
+            // without the assignment, the mixnodes are just going to stay on their bond-time layers
+            let layer_assignment = match self.validator_client.get_cached_layer_assignment().await {
+                Err(err) => {
+                    warn!(
+                        "failed to get mixnode layer assignment - {}. The bond-time layers are going to be used instead",
+                        err
+                    );
+                    Vec::new()
+                }
+                Ok(layer_assignment) => layer_assignment,
+            };
+
+            (mixnodes, gateways, layer_assignment)
         };
 
         let mixnodes_count = mixnodes.len();
-        let topology =
-            nym_topology_from_bonds(mixnodes, gateways).filter_system_version(&self.client_version);
+        let topology = nym_topology_from_bonds(mixnodes, gateways, layer_assignment)
+            .filter_system_version(&self.client_version);
 
         if !self.check_layer_distribution(&topology, mixnodes_count) {
             warn!("The current filtered active topology has extremely skewed layer distribution. It cannot be used.");
@@ -22,6 +22,7 @@ const DEFAULT_MESSAGE_STREAM_AVERAGE_DELAY: Duration = Duration::from_millis(20)
 const DEFAULT_AVERAGE_PACKET_DELAY: Duration = Duration::from_millis(50);
 const DEFAULT_TOPOLOGY_REFRESH_RATE: Duration = Duration::from_secs(5 * 60); // every 5min
 const DEFAULT_TOPOLOGY_RESOLUTION_TIMEOUT: Duration = Duration::from_millis(5_000);
+const DEFAULT_MAXIMUM_TOPOLOGY_AGE: Duration = Duration::from_secs(10 * 60);
 // Set this to a high value for now, so that we don't risk sporadic timeouts that might cause
 // bought bandwidth tokens to not have time to be spent; Once we remove the gateway from the
 // bandwidth bridging protocol, we can come back to a smaller timeout value
@@ -143,6 +144,10 @@ impl<T: NymConfig> Config<T> {
         self.client.validator_api_urls = validator_api_urls;
     }
 
+    pub fn set_trusted_validator_api_keys(&mut self, trusted_keys: Vec<String>) {
+        self.client.trusted_validator_api_keys = trusted_keys;
+    }
+
     pub fn set_high_default_traffic_volume(&mut self) {
         self.debug.average_packet_delay = Duration::from_millis(10);
         self.debug.loop_cover_traffic_average_delay = Duration::from_millis(2000000); // basically don't really send cover messages
@@ -197,6 +202,10 @@ impl<T: NymConfig> Config<T> {
         self.client.validator_api_urls.clone()
     }
 
+    pub fn get_trusted_validator_api_keys(&self) -> Vec<String> {
+        self.client.trusted_validator_api_keys.clone()
+    }
+
     pub fn get_gateway_id(&self) -> String {
         self.client.gateway_id.clone()
     }
@@ -253,6 +262,10 @@ impl<T: NymConfig> Config<T> {
         self.debug.topology_refresh_rate
     }
 
+    pub fn get_maximum_topology_age(&self) -> Duration {
+        self.debug.maximum_topology_age
+    }
+
     pub fn get_topology_resolution_timeout(&self) -> Duration {
         self.debug.topology_resolution_timeout
     }
@@ -289,6 +302,11 @@ pub struct Client<T> {
     /// Addresses to APIs running on validator from which the client gets the view of the network.
     validator_api_urls: Vec<Url>,
 
+    /// Base58-encoded identity keys of validator APIs whose signed topology the client is
+    /// willing to accept. If empty, the topology is obtained without any signature verification.
+    #[serde(default)]
+    trusted_validator_api_keys: Vec<String>,
+
     /// Path to file containing private identity key.
     private_identity_key_file: PathBuf,
 
@@ -350,6 +368,7 @@ impl<T: NymConfig> Default for Client<T> {
             id: "".to_string(),
             testnet_mode: false,
             validator_api_urls: default_api_endpoints(),
+            trusted_validator_api_keys: Vec::new(),
             private_identity_key_file: Default::default(),
             public_identity_key_file: Default::default(),
             private_encryption_key_file: Default::default(),
@@ -460,6 +479,11 @@ pub struct Debug {
     #[serde(with = "humantime_serde")]
     topology_refresh_rate: Duration,
 
+    /// Maximum age of a signed topology snapshot for it to still be accepted.
+    /// Only relevant if trusted validator API keys are specified.
+    #[serde(with = "humantime_serde")]
+    maximum_topology_age: Duration,
+
     /// During topology refresh, test packets are sent through every single possible network
     /// path. This timeout determines waiting period until it is decided that the packet
     /// did not reach its destination.
@@ -478,6 +502,7 @@ impl Default for Debug {
             message_sending_average_delay: DEFAULT_MESSAGE_STREAM_AVERAGE_DELAY,
             gateway_response_timeout: DEFAULT_GATEWAY_RESPONSE_TIMEOUT,
             topology_refresh_rate: DEFAULT_TOPOLOGY_REFRESH_RATE,
+            maximum_topology_age: DEFAULT_MAXIMUM_TOPOLOGY_AGE,
             topology_resolution_timeout: DEFAULT_TOPOLOGY_RESOLUTION_TIMEOUT,
         }
     }
@@ -30,6 +30,14 @@ validator_api_urls = [
     {{/each}}
 ]
 
+# Base58-encoded identity keys of validator APIs whose signed topology the client is
+# willing to accept. If empty, the topology is obtained without any signature verification.
+trusted_validator_api_keys = [
+    {{#each client.trusted_validator_api_keys }}
+        '{{this}}',
+    {{/each}}
+]
+
 # Path to file containing private identity key.
 private_identity_key_file = '{{ client.private_identity_key_file }}'
 
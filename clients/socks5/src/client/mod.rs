@@ -28,6 +28,7 @@ use gateway_client::{
 use log::*;
 use nymsphinx::addressing::clients::Recipient;
 use nymsphinx::addressing::nodes::NodeIdentity;
+use validator_client::validator_api::verification::TrustedApis;
 
 use crate::client::config::Config;
 use crate::socks::{
@@ -194,11 +195,20 @@ impl NymClient {
     // future responsible for periodically polling directory server and updating
     // the current global view of topology
     async fn start_topology_refresher(&mut self, topology_accessor: TopologyAccessor) {
-        let topology_refresher_config = TopologyRefresherConfig::new(
+        let mut topology_refresher_config = TopologyRefresherConfig::new(
             self.config.get_base().get_validator_api_endpoints(),
             self.config.get_base().get_topology_refresh_rate(),
             env!("CARGO_PKG_VERSION").to_string(),
         );
+        let trusted_api_keys = self.config.get_base().get_trusted_validator_api_keys();
+        if !trusted_api_keys.is_empty() {
+            let trusted_apis = TrustedApis::from_base58_keys(
+                &trusted_api_keys,
+                self.config.get_base().get_maximum_topology_age(),
+            )
+            .expect("one of the trusted validator api keys is invalid!");
+            topology_refresher_config = topology_refresher_config.with_trusted_apis(trusted_apis);
+        }
         let mut topology_refresher =
             TopologyRefresher::new(topology_refresher_config, topology_accessor);
         // before returning, block entire runtime to refresh the current network view so that any
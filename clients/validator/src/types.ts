@@ -41,6 +41,7 @@ export type ContractStateParams = {
   minimum_gateway_pledge: string;
   mixnode_rewarded_set_size: number;
   mixnode_active_set_size: number;
+  unbonding_period: number;
 };
 
 export type LayerDistribution = {
@@ -270,7 +270,19 @@ impl NymClient {
             Ok(gateways) => gateways,
         };
 
-        let topology = nym_topology_from_bonds(mixnodes, gateways);
+        // without the assignment, the mixnodes are just going to stay on their bond-time layers
+        let layer_assignment = match validator_client.get_cached_layer_assignment().await {
+            Err(err) => {
+                console_warn!(
+                    "failed to get mixnode layer assignment - {}. The bond-time layers are going to be used instead",
+                    err
+                );
+                Vec::new()
+            }
+            Ok(layer_assignment) => layer_assignment,
+        };
+
+        let topology = nym_topology_from_bonds(mixnodes, gateways, layer_assignment);
         let version = env!("CARGO_PKG_VERSION");
         topology.filter_system_version(version)
     }
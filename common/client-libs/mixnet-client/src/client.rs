@@ -12,7 +12,7 @@ use std::collections::HashMap;
 use std::io;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicU32, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use tokio::net::TcpStream;
 use tokio::time::sleep;
@@ -41,6 +41,29 @@ impl Config {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectionBackoff {
+    pub initial: Duration,
+    pub maximum: Duration,
+}
+
+/// Handle allowing to adjust reconnection backoff of an already running `Client`.
+/// The new values are going to be used for any subsequent reconnection attempt.
+#[derive(Clone)]
+pub struct ReconnectionBackoffHandle {
+    backoff: Arc<RwLock<ReconnectionBackoff>>,
+}
+
+impl ReconnectionBackoffHandle {
+    pub fn current(&self) -> ReconnectionBackoff {
+        *self.backoff.read().unwrap()
+    }
+
+    pub fn update(&self, backoff: ReconnectionBackoff) {
+        *self.backoff.write().unwrap() = backoff
+    }
+}
+
 pub trait SendWithoutResponse {
     // Without response in this context means we will not listen for anything we might get back (not
     // that we should get anything), including any possible io errors
@@ -55,6 +78,7 @@ pub trait SendWithoutResponse {
 pub struct Client {
     conn_new: HashMap<NymNodeRoutingAddress, ConnectionSender>,
     config: Config,
+    reconnection_backoff: Arc<RwLock<ReconnectionBackoff>>,
 }
 
 struct ConnectionSender {
@@ -73,9 +97,21 @@ impl ConnectionSender {
 
 impl Client {
     pub fn new(config: Config) -> Client {
+        let reconnection_backoff = ReconnectionBackoff {
+            initial: config.initial_reconnection_backoff,
+            maximum: config.maximum_reconnection_backoff,
+        };
+
         Client {
             conn_new: HashMap::new(),
             config,
+            reconnection_backoff: Arc::new(RwLock::new(reconnection_backoff)),
+        }
+    }
+
+    pub fn reconnection_backoff_handle(&self) -> ReconnectionBackoffHandle {
+        ReconnectionBackoffHandle {
+            backoff: Arc::clone(&self.reconnection_backoff),
         }
     }
 
@@ -137,15 +173,16 @@ impl Client {
             // limit of tokio delay of about 2 years.
             // let's ensure our delay is always on a sane side of being maximum 1 hour.
             let maximum_sane_delay = Duration::from_secs(60 * 60);
+            let backoff = *self.reconnection_backoff.read().unwrap();
 
             Some(std::cmp::min(
                 maximum_sane_delay,
                 std::cmp::min(
-                    self.config
-                        .initial_reconnection_backoff
+                    backoff
+                        .initial
                         .checked_mul(2_u32.pow(current_attempt))
-                        .unwrap_or(self.config.maximum_reconnection_backoff),
-                    self.config.maximum_reconnection_backoff,
+                        .unwrap_or(backoff.maximum),
+                    backoff.maximum,
                 ),
             ))
         }
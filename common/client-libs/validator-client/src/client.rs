@@ -1,13 +1,14 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::validator_api::verification::TrustedApis;
 use crate::{validator_api, ValidatorClientError};
 use coconut_interface::{BlindSignRequestBody, BlindedSignatureResponse, VerificationKeyResponse};
-use mixnet_contract_common::{GatewayBond, IdentityKeyRef, MixNodeBond};
+use mixnet_contract_common::{GatewayBond, IdentityKeyRef, LayerAssignment, MixNodeBond};
 use url::Url;
 use validator_api_requests::models::{
     CoreNodeStatusResponse, MixnodeStatusResponse, RewardEstimationResponse,
-    StakeSaturationResponse,
+    StakeSaturationResponse, TopologySnapshot, TopologySnapshotKind,
 };
 
 #[cfg(feature = "nymd-client")]
@@ -19,8 +20,8 @@ use mixnet_contract_common::ContractStateParams;
 
 #[cfg(feature = "nymd-client")]
 use mixnet_contract_common::{
-    Delegation, IdentityKey, Interval, MixnetContractVersion, MixnodeRewardingStatusResponse,
-    RewardedSetNodeStatus, RewardedSetUpdateDetails,
+    Delegation, GatewayRewardingStatusResponse, IdentityKey, Interval, MixnetContractVersion,
+    MixnodeRewardingStatusResponse, RewardedSetNodeStatus, RewardedSetUpdateDetails,
 };
 #[cfg(feature = "nymd-client")]
 use std::collections::{HashMap, HashSet};
@@ -221,6 +222,12 @@ impl<C> Client<C> {
         Ok(self.validator_api.get_gateways().await?)
     }
 
+    pub async fn get_cached_layer_assignment(
+        &self,
+    ) -> Result<Vec<LayerAssignment>, ValidatorClientError> {
+        Ok(self.validator_api.get_layer_assignment().await?)
+    }
+
     pub async fn get_contract_settings(&self) -> Result<ContractStateParams, ValidatorClientError>
     where
         C: CosmWasmClient + Sync,
@@ -249,6 +256,20 @@ impl<C> Client<C> {
             .await?)
     }
 
+    pub async fn get_gateway_rewarding_status(
+        &self,
+        gateway_identity: mixnet_contract_common::IdentityKey,
+        rewarding_interval_nonce: u32,
+    ) -> Result<GatewayRewardingStatusResponse, ValidatorClientError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        Ok(self
+            .nymd
+            .get_gateway_rewarding_status(gateway_identity, rewarding_interval_nonce)
+            .await?)
+    }
+
     pub async fn get_reward_pool(&self) -> Result<u128, ValidatorClientError>
     where
         C: CosmWasmClient + Sync,
@@ -340,6 +361,42 @@ impl<C> Client<C> {
         Ok(identities)
     }
 
+    pub async fn get_all_nymd_layer_assignment(
+        &self,
+    ) -> Result<Vec<LayerAssignment>, ValidatorClientError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let mut assignment = Vec::new();
+        let mut start_after = None;
+        let mut height = None;
+
+        loop {
+            let mut paged_response = self
+                .nymd
+                .get_layer_assignment_paged(
+                    start_after.take(),
+                    self.rewarded_set_page_limit,
+                    height,
+                )
+                .await?;
+            assignment.append(&mut paged_response.assignment);
+
+            if height.is_none() {
+                // keep using the same height (the first query happened at the most recent height)
+                height = Some(paged_response.at_height)
+            }
+
+            if let Some(start_after_res) = paged_response.start_next_after {
+                start_after = Some(start_after_res)
+            } else {
+                break;
+            }
+        }
+
+        Ok(assignment)
+    }
+
     pub async fn get_nymd_rewarded_and_active_sets(
         &self,
     ) -> Result<Vec<(MixNodeBond, RewardedSetNodeStatus)>, ValidatorClientError>
@@ -430,6 +487,33 @@ impl<C> Client<C> {
         Ok(mixnodes)
     }
 
+    // the same as `get_all_nymd_mixnodes`, but every page is read as of the same, provided, height
+    pub async fn get_all_nymd_mixnodes_at_height(
+        &self,
+        height: u64,
+    ) -> Result<Vec<MixNodeBond>, ValidatorClientError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let mut mixnodes = Vec::new();
+        let mut start_after = None;
+        loop {
+            let mut paged_response = self
+                .nymd
+                .get_mixnodes_paged_at_height(start_after.take(), self.mixnode_page_limit, height)
+                .await?;
+            mixnodes.append(&mut paged_response.nodes);
+
+            if let Some(start_after_res) = paged_response.start_next_after {
+                start_after = Some(start_after_res)
+            } else {
+                break;
+            }
+        }
+
+        Ok(mixnodes)
+    }
+
     pub async fn get_all_nymd_gateways(&self) -> Result<Vec<GatewayBond>, ValidatorClientError>
     where
         C: CosmWasmClient + Sync,
@@ -453,6 +537,31 @@ impl<C> Client<C> {
         Ok(gateways)
     }
 
+    pub async fn get_all_nymd_pending_delegation_slashings(
+        &self,
+    ) -> Result<Vec<IdentityKey>, ValidatorClientError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let mut mix_identities = Vec::new();
+        let mut start_after = None;
+        loop {
+            let mut paged_response = self
+                .nymd
+                .get_pending_delegation_slashings_paged(start_after.take(), None)
+                .await?;
+            mix_identities.append(&mut paged_response.mix_identities);
+
+            if let Some(start_after_res) = paged_response.start_next_after {
+                start_after = Some(start_after_res)
+            } else {
+                break;
+            }
+        }
+
+        Ok(mix_identities)
+    }
+
     pub async fn get_all_nymd_single_mixnode_delegations(
         &self,
         identity: IdentityKey,
@@ -483,6 +592,36 @@ impl<C> Client<C> {
         Ok(delegations)
     }
 
+    pub async fn get_all_nymd_single_gateway_delegations(
+        &self,
+        identity: IdentityKey,
+    ) -> Result<Vec<Delegation>, ValidatorClientError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let mut delegations = Vec::new();
+        let mut start_after = None;
+        loop {
+            let mut paged_response = self
+                .nymd
+                .get_gateway_delegations_paged(
+                    identity.clone(),
+                    start_after.take(),
+                    self.mixnode_delegations_page_limit,
+                )
+                .await?;
+            delegations.append(&mut paged_response.delegations);
+
+            if let Some(start_after_res) = paged_response.start_next_after {
+                start_after = Some(start_after_res)
+            } else {
+                break;
+            }
+        }
+
+        Ok(delegations)
+    }
+
     pub async fn get_all_network_delegations(&self) -> Result<Vec<Delegation>, ValidatorClientError>
     where
         C: CosmWasmClient + Sync,
@@ -557,6 +696,7 @@ pub struct ApiClient {
     pub validator_api: validator_api::Client,
     // TODO: perhaps if we really need it at some (currently I don't see any reasons for it)
     // we could re-implement the communication with the REST API on port 1317
+    trusted_apis: Option<TrustedApis>,
 }
 
 impl ApiClient {
@@ -565,9 +705,22 @@ impl ApiClient {
 
         ApiClient {
             validator_api: validator_api_client,
+            trusted_apis: None,
         }
     }
 
+    /// Makes the client verify signed topology responses against the provided validator apis.
+    pub fn with_trusted_apis(mut self, trusted_apis: TrustedApis) -> Self {
+        self.trusted_apis = Some(trusted_apis);
+        self
+    }
+
+    fn trusted_apis(&self) -> Result<&TrustedApis, ValidatorClientError> {
+        self.trusted_apis
+            .as_ref()
+            .ok_or(ValidatorClientError::NoTrustedApis)
+    }
+
     pub fn change_validator_api(&mut self, new_endpoint: Url) {
         self.validator_api.change_url(new_endpoint);
     }
@@ -592,6 +745,48 @@ impl ApiClient {
         Ok(self.validator_api.get_gateways().await?)
     }
 
+    pub async fn get_cached_layer_assignment(
+        &self,
+    ) -> Result<Vec<LayerAssignment>, ValidatorClientError> {
+        Ok(self.validator_api.get_layer_assignment().await?)
+    }
+
+    pub async fn get_verified_active_mixnodes(
+        &self,
+    ) -> Result<TopologySnapshot<Vec<MixNodeBond>>, ValidatorClientError> {
+        let response = self.validator_api.get_signed_active_mixnodes().await?;
+        Ok(self
+            .trusted_apis()?
+            .verify(&response, TopologySnapshotKind::ActiveSet)?)
+    }
+
+    pub async fn get_verified_rewarded_mixnodes(
+        &self,
+    ) -> Result<TopologySnapshot<Vec<MixNodeBond>>, ValidatorClientError> {
+        let response = self.validator_api.get_signed_rewarded_mixnodes().await?;
+        Ok(self
+            .trusted_apis()?
+            .verify(&response, TopologySnapshotKind::RewardedSet)?)
+    }
+
+    pub async fn get_verified_gateways(
+        &self,
+    ) -> Result<TopologySnapshot<Vec<GatewayBond>>, ValidatorClientError> {
+        let response = self.validator_api.get_signed_gateways().await?;
+        Ok(self
+            .trusted_apis()?
+            .verify(&response, TopologySnapshotKind::Gateways)?)
+    }
+
+    pub async fn get_verified_layer_assignment(
+        &self,
+    ) -> Result<TopologySnapshot<Vec<LayerAssignment>>, ValidatorClientError> {
+        let response = self.validator_api.get_signed_layer_assignment().await?;
+        Ok(self
+            .trusted_apis()?
+            .verify(&response, TopologySnapshotKind::LayerAssignment)?)
+    }
+
     pub async fn get_gateway_core_status_count(
         &self,
         identity: IdentityKeyRef<'_>,
@@ -12,6 +12,9 @@ pub enum ValidatorClientError {
         source: validator_api::error::ValidatorAPIError,
     },
 
+    #[error("No trusted validator apis have been configured to verify the signed responses")]
+    NoTrustedApis,
+
     #[error("One of the provided URLs was malformed - {0}")]
     MalformedUrlProvided(#[from] url::ParseError),
 
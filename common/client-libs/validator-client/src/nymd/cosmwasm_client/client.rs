@@ -46,6 +46,21 @@ pub trait CosmWasmClient: rpc::Client {
         path: Option<abci::Path>,
         req: Req,
     ) -> Result<Res, NymdError>
+    where
+        Req: Message,
+        Res: Message + Default,
+    {
+        self.make_abci_query_at_height(path, req, None).await
+    }
+
+    // same as `make_abci_query`, but if the height is provided, the query is answered
+    // using the chain state as of that block rather than the latest one
+    async fn make_abci_query_at_height<Req, Res>(
+        &self,
+        path: Option<abci::Path>,
+        req: Req,
+        height: Option<block::Height>,
+    ) -> Result<Res, NymdError>
     where
         Req: Message,
         Res: Message + Default,
@@ -53,7 +68,7 @@ pub trait CosmWasmClient: rpc::Client {
         let mut buf = Vec::with_capacity(req.encoded_len());
         req.encode(&mut buf)?;
 
-        let res = self.abci_query(path, buf, None, false).await?;
+        let res = self.abci_query(path, buf, height, false).await?;
 
         match res.code {
             AbciCode::Err(code) => return Err(NymdError::AbciError(code, res.log)),
@@ -412,6 +427,20 @@ pub trait CosmWasmClient: rpc::Client {
         address: &AccountId,
         query_msg: &M,
     ) -> Result<T, NymdError>
+    where
+        M: ?Sized + Serialize + Sync,
+        for<'a> T: Deserialize<'a>,
+    {
+        self.query_contract_smart_at_height(address, query_msg, None)
+            .await
+    }
+
+    async fn query_contract_smart_at_height<M, T>(
+        &self,
+        address: &AccountId,
+        query_msg: &M,
+        height: Option<block::Height>,
+    ) -> Result<T, NymdError>
     where
         M: ?Sized + Serialize + Sync,
         for<'a> T: Deserialize<'a>,
@@ -431,7 +460,7 @@ pub trait CosmWasmClient: rpc::Client {
         };
 
         let res = self
-            .make_abci_query::<_, QuerySmartContractStateResponse>(path, req)
+            .make_abci_query_at_height::<_, QuerySmartContractStateResponse>(path, req, height)
             .await?;
 
         Ok(serde_json::from_slice(&res.data)?)
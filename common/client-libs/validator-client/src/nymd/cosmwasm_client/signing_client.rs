@@ -89,6 +89,35 @@ pub trait SigningCosmWasmClient: CosmWasmClient {
         self.query_simulate(Some(partial_tx), Vec::new()).await
     }
 
+    /// Simulates execution of the provided contract messages without broadcasting them.
+    async fn simulate_execute_multiple<I, M>(
+        &self,
+        sender_address: &AccountId,
+        contract_address: &AccountId,
+        msgs: I,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<SimulateResponse, NymdError>
+    where
+        I: IntoIterator<Item = (M, Vec<Coin>)> + Send,
+        M: Serialize,
+    {
+        let messages = msgs
+            .into_iter()
+            .map(|(msg, funds)| {
+                cosmwasm::MsgExecuteContract {
+                    sender: sender_address.clone(),
+                    contract: contract_address.clone(),
+                    msg: serde_json::to_vec(&msg)?,
+                    funds,
+                }
+                .to_any()
+                .map_err(|_| NymdError::SerializationError("MsgExecuteContract".to_owned()))
+            })
+            .collect::<Result<_, _>>()?;
+
+        self.simulate(sender_address, messages, memo).await
+    }
+
     async fn upload(
         &self,
         sender_address: &AccountId,
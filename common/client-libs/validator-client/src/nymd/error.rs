@@ -138,6 +138,19 @@ impl NymdError {
         }
     }
 
+    /// Checks whether the transaction failed due to exceeding its gas limit.
+    pub fn is_out_of_gas(&self) -> bool {
+        match &self {
+            NymdError::BroadcastTxErrorCheckTx { code, raw_log, .. }
+            | NymdError::BroadcastTxErrorDeliverTx { code, raw_log, .. } => {
+                // 11 is the code of `ErrOutOfGas` in the cosmos-sdk root codespace
+                // (https://github.com/cosmos/cosmos-sdk/blob/v0.44.5/types/errors/errors.go#L65)
+                *code == 11 || raw_log.contains("out of gas")
+            }
+            _ => false,
+        }
+    }
+
     pub fn is_tendermint_response_duplicate(&self) -> bool {
         match &self {
             NymdError::TendermintError(TendermintRpcError(
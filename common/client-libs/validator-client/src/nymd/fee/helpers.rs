@@ -21,15 +21,35 @@ pub enum Operation {
     UnbondMixnode,
     UnbondMixnodeOnBehalf,
     UpdateMixnodeConfig,
+    UpdateMixnodeDetails,
+    IncreaseMixnodePledge,
+    DecreaseMixnodePledge,
     DelegateToMixnode,
     DelegateToMixnodeOnBehalf,
     UndelegateFromMixnode,
     UndelegateFromMixnodeOnBehalf,
+    RedelegateToMixnode,
+    RedelegateToMixnodeOnBehalf,
+    ClaimPendingWithdrawals,
 
     BondGateway,
     BondGatewayOnBehalf,
     UnbondGateway,
     UnbondGatewayOnBehalf,
+    UpdateGatewayDetails,
+    DelegateToGateway,
+    UndelegateFromGateway,
+
+    CreateFamily,
+    JoinFamily,
+    LeaveFamily,
+
+    ClaimOperatorReward,
+    ClaimDelegatorReward,
+    CompoundDelegatorReward,
+    ClaimGatewayOperatorReward,
+    ClaimGatewayDelegatorReward,
+    CompoundGatewayDelegatorReward,
 
     UpdateContractSettings,
 
@@ -64,17 +84,37 @@ impl fmt::Display for Operation {
             Operation::BondMixnodeOnBehalf => f.write_str("BondMixnodeOnBehalf"),
             Operation::UnbondMixnode => f.write_str("UnbondMixnode"),
             Operation::UpdateMixnodeConfig => f.write_str("UpdateMixnodeConfig"),
+            Operation::UpdateMixnodeDetails => f.write_str("UpdateMixnodeDetails"),
+            Operation::IncreaseMixnodePledge => f.write_str("IncreaseMixnodePledge"),
+            Operation::DecreaseMixnodePledge => f.write_str("DecreaseMixnodePledge"),
+            Operation::ClaimPendingWithdrawals => f.write_str("ClaimPendingWithdrawals"),
             Operation::UnbondMixnodeOnBehalf => f.write_str("UnbondMixnodeOnBehalf"),
             Operation::BondGateway => f.write_str("BondGateway"),
             Operation::BondGatewayOnBehalf => f.write_str("BondGatewayOnBehalf"),
             Operation::UnbondGateway => f.write_str("UnbondGateway"),
             Operation::UnbondGatewayOnBehalf => f.write_str("UnbondGatewayOnBehalf"),
+            Operation::UpdateGatewayDetails => f.write_str("UpdateGatewayDetails"),
+            Operation::DelegateToGateway => f.write_str("DelegateToGateway"),
+            Operation::UndelegateFromGateway => f.write_str("UndelegateFromGateway"),
+            Operation::CreateFamily => f.write_str("CreateFamily"),
+            Operation::JoinFamily => f.write_str("JoinFamily"),
+            Operation::LeaveFamily => f.write_str("LeaveFamily"),
+            Operation::ClaimOperatorReward => f.write_str("ClaimOperatorReward"),
+            Operation::ClaimDelegatorReward => f.write_str("ClaimDelegatorReward"),
+            Operation::CompoundDelegatorReward => f.write_str("CompoundDelegatorReward"),
+            Operation::ClaimGatewayOperatorReward => f.write_str("ClaimGatewayOperatorReward"),
+            Operation::ClaimGatewayDelegatorReward => f.write_str("ClaimGatewayDelegatorReward"),
+            Operation::CompoundGatewayDelegatorReward => {
+                f.write_str("CompoundGatewayDelegatorReward")
+            }
             Operation::DelegateToMixnode => f.write_str("DelegateToMixnode"),
             Operation::DelegateToMixnodeOnBehalf => f.write_str("DelegateToMixnodeOnBehalf"),
             Operation::UndelegateFromMixnode => f.write_str("UndelegateFromMixnode"),
             Operation::UndelegateFromMixnodeOnBehalf => {
                 f.write_str("UndelegateFromMixnodeOnBehalf")
             }
+            Operation::RedelegateToMixnode => f.write_str("RedelegateToMixnode"),
+            Operation::RedelegateToMixnodeOnBehalf => f.write_str("RedelegateToMixnodeOnBehalf"),
             Operation::UpdateContractSettings => f.write_str("UpdateContractSettings"),
             Operation::BeginMixnodeRewarding => f.write_str("BeginMixnodeRewarding"),
             Operation::FinishMixnodeRewarding => f.write_str("FinishMixnodeRewarding"),
@@ -106,15 +146,35 @@ impl Operation {
             Operation::UnbondMixnode => 175_000u64.into(),
             Operation::UnbondMixnodeOnBehalf => 175_000u64.into(),
             Operation::UpdateMixnodeConfig => 175_000u64.into(),
+            Operation::UpdateMixnodeDetails => 175_000u64.into(),
+            Operation::IncreaseMixnodePledge => 175_000u64.into(),
+            Operation::DecreaseMixnodePledge => 175_000u64.into(),
+            Operation::ClaimPendingWithdrawals => 250_000u64.into(),
             Operation::DelegateToMixnode => 175_000u64.into(),
             Operation::DelegateToMixnodeOnBehalf => 175_000u64.into(),
             Operation::UndelegateFromMixnode => 175_000u64.into(),
             Operation::UndelegateFromMixnodeOnBehalf => 175_000u64.into(),
+            Operation::RedelegateToMixnode => 200_000u64.into(),
+            Operation::RedelegateToMixnodeOnBehalf => 200_000u64.into(),
 
             Operation::BondGateway => 175_000u64.into(),
             Operation::BondGatewayOnBehalf => 200_000u64.into(),
             Operation::UnbondGateway => 175_000u64.into(),
             Operation::UnbondGatewayOnBehalf => 200_000u64.into(),
+            Operation::UpdateGatewayDetails => 175_000u64.into(),
+            Operation::DelegateToGateway => 175_000u64.into(),
+            Operation::UndelegateFromGateway => 175_000u64.into(),
+
+            Operation::CreateFamily => 175_000u64.into(),
+            Operation::JoinFamily => 175_000u64.into(),
+            Operation::LeaveFamily => 175_000u64.into(),
+
+            Operation::ClaimOperatorReward => 175_000u64.into(),
+            Operation::ClaimDelegatorReward => 175_000u64.into(),
+            Operation::CompoundDelegatorReward => 175_000u64.into(),
+            Operation::ClaimGatewayOperatorReward => 175_000u64.into(),
+            Operation::ClaimGatewayDelegatorReward => 175_000u64.into(),
+            Operation::CompoundGatewayDelegatorReward => 175_000u64.into(),
 
             Operation::UpdateContractSettings => 175_000u64.into(),
             Operation::BeginMixnodeRewarding => 175_000u64.into(),
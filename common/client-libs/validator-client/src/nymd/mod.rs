@@ -14,11 +14,16 @@ use cosmwasm_std::{Coin, Uint128};
 pub use fee::gas_price::GasPrice;
 use fee::helpers::Operation;
 use mixnet_contract_common::{
-    ContractStateParams, Delegation, ExecuteMsg, Gateway, GatewayBond, GatewayOwnershipResponse,
-    IdentityKey, Interval, LayerDistribution, MixNode, MixNodeBond, MixOwnershipResponse,
-    MixnetContractVersion, MixnodeRewardingStatusResponse, PagedAllDelegationsResponse,
-    PagedDelegatorDelegationsResponse, PagedGatewayResponse, PagedMixDelegationsResponse,
-    PagedMixnodeResponse, PagedRewardedSetResponse, QueryMsg, RewardedSetUpdateDetails,
+    ContractStateParams, Delegation, ExecuteMsg, Family, Gateway, GatewayBond,
+    GatewayDetailsUpdate, GatewayOwnershipResponse, GatewayRewardingStatusResponse, IdentityKey,
+    Interval, LayerAssignment, LayerDistribution, MixNode, MixNodeBond, MixNodeDetailsUpdate,
+    MixOwnershipResponse, MixnetContractVersion, MixnodePerformanceStreak,
+    MixnodeRewardingStatusResponse, PagedAllDelegationsResponse, PagedDelegatorDelegationsResponse,
+    PagedFamiliesResponse, PagedFamilyMembersResponse, PagedGatewayResponse,
+    PagedLayerAssignmentResponse, PagedMixDelegationsResponse, PagedMixnodeResponse,
+    PagedPendingDelegationSlashingsResponse, PagedPendingWithdrawalsResponse,
+    PagedRewardedSetResponse, PagedSlashingHistoryResponse, PendingPledgeChange, QueryMsg,
+    RewardedSetSeed, RewardedSetUpdateDetails,
 };
 use serde::Serialize;
 use std::convert::TryInto;
@@ -305,6 +310,37 @@ impl<C> NymdClient<C> {
             .await
     }
 
+    pub async fn get_gateway_rewarding_status(
+        &self,
+        gateway_identity: IdentityKey,
+        interval_id: u32,
+    ) -> Result<GatewayRewardingStatusResponse, NymdError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let request = QueryMsg::GetGatewayRewardingStatus {
+            gateway_identity,
+            interval_id,
+        };
+        self.client
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
+            .await
+    }
+
+    /// Gets the pledge change of the mixnode that is going to be applied at the end of the current interval.
+    pub async fn get_pending_pledge_change(
+        &self,
+        mix_identity: IdentityKey,
+    ) -> Result<Option<PendingPledgeChange>, NymdError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let request = QueryMsg::GetPendingPledgeChange { mix_identity };
+        self.client
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
+            .await
+    }
+
     pub async fn query_current_rewarded_set_height(&self) -> Result<u64, NymdError>
     where
         C: CosmWasmClient + Sync,
@@ -327,6 +363,21 @@ impl<C> NymdClient<C> {
             .await
     }
 
+    /// Obtains the seed used for selecting the rewarded set written at the specified height,
+    /// or the current one if no height was provided.
+    pub async fn query_rewarded_set_seed(
+        &self,
+        height: Option<u64>,
+    ) -> Result<Option<RewardedSetSeed>, NymdError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let request = QueryMsg::GetRewardedSetSeed { height };
+        self.client
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
+            .await
+    }
+
     pub async fn get_rewarded_set_identities_paged(
         &self,
         start_after: Option<IdentityKey>,
@@ -347,6 +398,26 @@ impl<C> NymdClient<C> {
             .await
     }
 
+    pub async fn get_layer_assignment_paged(
+        &self,
+        start_after: Option<IdentityKey>,
+        page_limit: Option<u32>,
+        height: Option<u64>,
+    ) -> Result<PagedLayerAssignmentResponse, NymdError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let request = QueryMsg::GetLayerAssignment {
+            height,
+            start_after,
+            limit: page_limit,
+        };
+
+        self.client
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
+            .await
+    }
+
     pub async fn get_layer_distribution(&self) -> Result<LayerDistribution, NymdError>
     where
         C: CosmWasmClient + Sync,
@@ -464,6 +535,29 @@ impl<C> NymdClient<C> {
             .await
     }
 
+    /// Same as `get_mixnodes_paged`, but the bonds are read as they were at the provided height.
+    pub async fn get_mixnodes_paged_at_height(
+        &self,
+        start_after: Option<IdentityKey>,
+        page_limit: Option<u32>,
+        height: u64,
+    ) -> Result<PagedMixnodeResponse, NymdError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let request = QueryMsg::GetMixNodes {
+            start_after,
+            limit: page_limit,
+        };
+        self.client
+            .query_contract_smart_at_height(
+                self.mixnet_contract_address()?,
+                &request,
+                Some((height as u32).into()),
+            )
+            .await
+    }
+
     pub async fn get_gateways_paged(
         &self,
         start_after: Option<IdentityKey>,
@@ -557,212 +651,513 @@ impl<C> NymdClient<C> {
             .await
     }
 
-    /// Send funds from one address to another
-    pub async fn send(
+    /// Gets list of all delegations towards particular gateway on particular page.
+    pub async fn get_gateway_delegations_paged(
         &self,
-        recipient: &AccountId,
-        amount: Vec<CosmosCoin>,
-        memo: impl Into<String> + Send + 'static,
-    ) -> Result<broadcast::tx_commit::Response, NymdError>
+        gateway_identity: IdentityKey,
+        start_after: Option<String>,
+        page_limit: Option<u32>,
+    ) -> Result<PagedMixDelegationsResponse, NymdError>
     where
-        C: SigningCosmWasmClient + Sync,
+        C: CosmWasmClient + Sync,
     {
-        let fee = self.operation_fee(Operation::Send);
+        let request = QueryMsg::GetGatewayDelegations {
+            gateway_identity,
+            start_after,
+            limit: page_limit,
+        };
         self.client
-            .send_tokens(self.address(), recipient, amount, fee, memo)
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
             .await
     }
 
-    /// Send funds from one address to multiple others
-    pub async fn send_multiple(
+    /// Gets list of all the gateways on which a particular address delegated.
+    pub async fn get_delegator_gateway_delegations_paged(
         &self,
-        msgs: Vec<(AccountId, Vec<CosmosCoin>)>,
-        memo: impl Into<String> + Send + 'static,
-    ) -> Result<broadcast::tx_commit::Response, NymdError>
+        delegator: String,
+        start_after: Option<IdentityKey>,
+        page_limit: Option<u32>,
+    ) -> Result<PagedDelegatorDelegationsResponse, NymdError>
     where
-        C: SigningCosmWasmClient + Sync,
+        C: CosmWasmClient + Sync,
     {
-        let fee = self.repeated_operation_fee(Operation::Send, msgs.len() as u64);
+        let request = QueryMsg::GetDelegatorGatewayDelegations {
+            delegator,
+            start_after,
+            limit: page_limit,
+        };
         self.client
-            .send_tokens_multiple(self.address(), msgs, fee, memo)
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
             .await
     }
 
-    pub async fn execute<M>(
+    /// Checks value of delegation of given client towards particular gateway.
+    pub async fn get_gateway_delegation_details(
         &self,
-        contract_address: &AccountId,
-        msg: &M,
-        fee: Fee,
-        memo: impl Into<String> + Send + 'static,
-        funds: Vec<CosmosCoin>,
-    ) -> Result<ExecuteResult, NymdError>
+        gateway_identity: IdentityKey,
+        delegator: &AccountId,
+    ) -> Result<Delegation, NymdError>
     where
-        C: SigningCosmWasmClient + Sync,
-        M: ?Sized + Serialize + Sync,
+        C: CosmWasmClient + Sync,
     {
+        let request = QueryMsg::GetGatewayDelegationDetails {
+            gateway_identity,
+            delegator: delegator.to_string(),
+        };
         self.client
-            .execute(self.address(), contract_address, msg, fee, memo, funds)
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
             .await
     }
 
-    pub async fn execute_multiple<I, M>(
+    /// Gets list of all the operator families registered in the contract.
+    pub async fn get_families_paged(
         &self,
-        contract_address: &AccountId,
-        msgs: I,
-        fee: Fee,
-        memo: impl Into<String> + Send + 'static,
-    ) -> Result<ExecuteResult, NymdError>
+        start_after: Option<IdentityKey>,
+        page_limit: Option<u32>,
+    ) -> Result<PagedFamiliesResponse, NymdError>
     where
-        C: SigningCosmWasmClient + Sync,
-        I: IntoIterator<Item = (M, Vec<CosmosCoin>)> + Send,
-        M: Serialize,
+        C: CosmWasmClient + Sync,
     {
+        let request = QueryMsg::GetFamiliesPaged {
+            start_after,
+            limit: page_limit,
+        };
         self.client
-            .execute_multiple(self.address(), contract_address, msgs, fee, memo)
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
             .await
     }
 
-    pub async fn upload(
+    /// Gets identities of all the mixnodes belonging to the family with the specified head.
+    pub async fn get_family_members_paged(
         &self,
-        wasm_code: Vec<u8>,
-        memo: impl Into<String> + Send + 'static,
-    ) -> Result<UploadResult, NymdError>
+        family_head: IdentityKey,
+        start_after: Option<IdentityKey>,
+        page_limit: Option<u32>,
+    ) -> Result<PagedFamilyMembersResponse, NymdError>
     where
-        C: SigningCosmWasmClient + Sync,
+        C: CosmWasmClient + Sync,
     {
-        let fee = self.operation_fee(Operation::Upload);
+        let request = QueryMsg::GetFamilyMembersPaged {
+            family_head,
+            start_after,
+            limit: page_limit,
+        };
         self.client
-            .upload(self.address(), wasm_code, fee, memo)
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
             .await
     }
 
-    pub async fn instantiate<M>(
+    /// Gets the family the specified mixnode belongs to, if any.
+    pub async fn get_family_by_member(
         &self,
-        code_id: ContractCodeId,
-        msg: &M,
-        label: String,
-        memo: impl Into<String> + Send + 'static,
-        options: Option<InstantiateOptions>,
-    ) -> Result<InstantiateResult, NymdError>
+        member: IdentityKey,
+    ) -> Result<Option<Family>, NymdError>
     where
-        C: SigningCosmWasmClient + Sync,
-        M: ?Sized + Serialize + Sync,
+        C: CosmWasmClient + Sync,
     {
-        let fee = self.operation_fee(Operation::Init);
+        let request = QueryMsg::GetFamilyByMember { member };
         self.client
-            .instantiate(self.address(), code_id, msg, label, fee, memo, options)
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
             .await
     }
 
-    pub async fn update_admin(
-        &self,
-        contract_address: &AccountId,
-        new_admin: &AccountId,
-        memo: impl Into<String> + Send + 'static,
-    ) -> Result<ChangeAdminResult, NymdError>
+    /// Gets the nonce that has to be included in the next family-related message
+    /// signed with the identity key of the specified mixnode.
+    pub async fn get_family_signing_nonce(&self, identity: IdentityKey) -> Result<u64, NymdError>
     where
-        C: SigningCosmWasmClient + Sync,
+        C: CosmWasmClient + Sync,
     {
-        let fee = self.operation_fee(Operation::ChangeAdmin);
+        let request = QueryMsg::GetFamilySigningNonce { identity };
         self.client
-            .update_admin(self.address(), contract_address, new_admin, fee, memo)
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
             .await
     }
 
-    pub async fn clear_admin(
+    /// Gets the amount of operator rewards accrued by the specified mixnode that are yet to be claimed.
+    pub async fn get_pending_operator_reward(
         &self,
-        contract_address: &AccountId,
-        memo: impl Into<String> + Send + 'static,
-    ) -> Result<ChangeAdminResult, NymdError>
+        mix_identity: IdentityKey,
+    ) -> Result<Uint128, NymdError>
     where
-        C: SigningCosmWasmClient + Sync,
+        C: CosmWasmClient + Sync,
     {
-        let fee = self.operation_fee(Operation::ChangeAdmin);
+        let request = QueryMsg::GetPendingOperatorReward { mix_identity };
         self.client
-            .clear_admin(self.address(), contract_address, fee, memo)
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
             .await
     }
 
-    pub async fn migrate<M>(
+    /// Gets the amount of rewards accrued by the specified delegation that are yet to be claimed.
+    /// The proxy has to be set to the vesting contract address if the delegation was made with
+    /// vesting tokens.
+    pub async fn get_pending_delegator_reward(
         &self,
-        contract_address: &AccountId,
-        code_id: ContractCodeId,
-        msg: &M,
-        memo: impl Into<String> + Send + 'static,
-    ) -> Result<MigrateResult, NymdError>
+        mix_identity: IdentityKey,
+        delegator: &AccountId,
+        proxy: Option<&AccountId>,
+    ) -> Result<Uint128, NymdError>
     where
-        C: SigningCosmWasmClient + Sync,
-        M: ?Sized + Serialize + Sync,
+        C: CosmWasmClient + Sync,
     {
-        let fee = self.operation_fee(Operation::Migrate);
+        let request = QueryMsg::GetPendingDelegatorReward {
+            mix_identity,
+            delegator: delegator.to_string(),
+            proxy: proxy.map(|proxy| proxy.to_string()),
+        };
         self.client
-            .migrate(self.address(), contract_address, code_id, fee, msg, memo)
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
             .await
     }
 
-    /// Announce a mixnode, paying a fee.
-    pub async fn bond_mixnode(
+    /// Gets list of unbonded and undelegated tokens of particular address that are still being
+    /// held by the contract.
+    pub async fn get_pending_withdrawals_paged(
         &self,
-        mixnode: MixNode,
-        owner_signature: String,
-        pledge: Coin,
-    ) -> Result<ExecuteResult, NymdError>
+        owner: &AccountId,
+        start_after: Option<u64>,
+        page_limit: Option<u32>,
+    ) -> Result<PagedPendingWithdrawalsResponse, NymdError>
     where
-        C: SigningCosmWasmClient + Sync,
+        C: CosmWasmClient + Sync,
     {
-        let fee = self.operation_fee(Operation::BondMixnode);
-
-        let req = ExecuteMsg::BondMixnode {
-            mix_node: mixnode,
-            owner_signature,
+        let request = QueryMsg::GetPendingWithdrawals {
+            owner: owner.to_string(),
+            start_after,
+            limit: page_limit,
         };
         self.client
-            .execute(
-                self.address(),
-                self.mixnet_contract_address()?,
-                &req,
-                fee,
-                "Bonding mixnode from rust!",
-                vec![cosmwasm_coin_to_cosmos_coin(pledge)],
-            )
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
             .await
     }
 
-    /// Announce a mixnode on behalf of the owner, paying a fee.
-    pub async fn bond_mixnode_on_behalf(
+    /// Gets list of all unbonded and undelegated tokens in the network that are still being
+    /// held by the contract.
+    pub async fn get_all_pending_withdrawals_paged(
         &self,
-        mixnode: MixNode,
-        owner: String,
-        owner_signature: String,
-        pledge: Coin,
-    ) -> Result<ExecuteResult, NymdError>
+        start_after: Option<u64>,
+        page_limit: Option<u32>,
+    ) -> Result<PagedPendingWithdrawalsResponse, NymdError>
     where
-        C: SigningCosmWasmClient + Sync,
+        C: CosmWasmClient + Sync,
     {
-        let fee = self.operation_fee(Operation::BondMixnodeOnBehalf);
-
-        let req = ExecuteMsg::BondMixnodeOnBehalf {
-            mix_node: mixnode,
-            owner,
-            owner_signature,
+        let request = QueryMsg::GetAllPendingWithdrawals {
+            start_after,
+            limit: page_limit,
         };
         self.client
-            .execute(
-                self.address(),
-                self.mixnet_contract_address()?,
-                &req,
-                fee,
-                "Bonding mixnode on behalf from rust!",
-                vec![cosmwasm_coin_to_cosmos_coin(pledge)],
-            )
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
             .await
     }
 
-    /// Announce multiple mixnodes on behalf of other owners, paying a fee.
-    pub async fn bond_multiple_mixnodes_on_behalf(
+    /// Gets the number of consecutive intervals the particular mixnode has performed poorly in
+    /// while being in the active set.
+    pub async fn get_mixnode_performance_streak(
         &self,
-        mixnode_bonds_with_sigs: Vec<(MixNodeBond, String)>,
-    ) -> Result<ExecuteResult, NymdError>
+        mix_identity: IdentityKey,
+    ) -> Result<Option<MixnodePerformanceStreak>, NymdError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let request = QueryMsg::GetMixnodePerformanceStreak { mix_identity };
+        self.client
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
+            .await
+    }
+
+    /// Gets list of all times the particular mixnode has been slashed.
+    pub async fn get_mixnode_slashing_history_paged(
+        &self,
+        mix_identity: IdentityKey,
+        start_after: Option<u64>,
+        page_limit: Option<u32>,
+    ) -> Result<PagedSlashingHistoryResponse, NymdError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let request = QueryMsg::GetMixnodeSlashingHistory {
+            mix_identity,
+            start_after,
+            limit: page_limit,
+        };
+        self.client
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
+            .await
+    }
+
+    /// Gets list of all slashing events in the network.
+    pub async fn get_all_slashing_history_paged(
+        &self,
+        start_after: Option<u64>,
+        page_limit: Option<u32>,
+    ) -> Result<PagedSlashingHistoryResponse, NymdError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let request = QueryMsg::GetAllSlashingHistory {
+            start_after,
+            limit: page_limit,
+        };
+        self.client
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
+            .await
+    }
+
+    /// Gets list of all mixnodes whose delegations haven't been fully slashed yet.
+    pub async fn get_pending_delegation_slashings_paged(
+        &self,
+        start_after: Option<IdentityKey>,
+        page_limit: Option<u32>,
+    ) -> Result<PagedPendingDelegationSlashingsResponse, NymdError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let request = QueryMsg::GetPendingDelegationSlashings {
+            start_after,
+            limit: page_limit,
+        };
+        self.client
+            .query_contract_smart(self.mixnet_contract_address()?, &request)
+            .await
+    }
+
+    /// Send funds from one address to another
+    pub async fn send(
+        &self,
+        recipient: &AccountId,
+        amount: Vec<CosmosCoin>,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<broadcast::tx_commit::Response, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::Send);
+        self.client
+            .send_tokens(self.address(), recipient, amount, fee, memo)
+            .await
+    }
+
+    /// Send funds from one address to multiple others
+    pub async fn send_multiple(
+        &self,
+        msgs: Vec<(AccountId, Vec<CosmosCoin>)>,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<broadcast::tx_commit::Response, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.repeated_operation_fee(Operation::Send, msgs.len() as u64);
+        self.client
+            .send_tokens_multiple(self.address(), msgs, fee, memo)
+            .await
+    }
+
+    pub async fn execute<M>(
+        &self,
+        contract_address: &AccountId,
+        msg: &M,
+        fee: Fee,
+        memo: impl Into<String> + Send + 'static,
+        funds: Vec<CosmosCoin>,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+        M: ?Sized + Serialize + Sync,
+    {
+        self.client
+            .execute(self.address(), contract_address, msg, fee, memo, funds)
+            .await
+    }
+
+    pub async fn execute_multiple<I, M>(
+        &self,
+        contract_address: &AccountId,
+        msgs: I,
+        fee: Fee,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+        I: IntoIterator<Item = (M, Vec<CosmosCoin>)> + Send,
+        M: Serialize,
+    {
+        self.client
+            .execute_multiple(self.address(), contract_address, msgs, fee, memo)
+            .await
+    }
+
+    /// Simulates execution of the provided messages without broadcasting them and returns
+    /// the amount of gas they would have used.
+    pub async fn simulate_execute_multiple<I, M>(
+        &self,
+        contract_address: &AccountId,
+        msgs: I,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<Gas, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+        I: IntoIterator<Item = (M, Vec<CosmosCoin>)> + Send,
+        M: Serialize,
+    {
+        Ok(self
+            .client
+            .simulate_execute_multiple(self.address(), contract_address, msgs, memo)
+            .await?
+            .gas_info
+            .ok_or(NymdError::GasEstimationFailure)?
+            .gas_used)
+    }
+
+    /// Determines the fee of a transaction that was simulated to use the provided amount of gas,
+    /// adjusted by the simulated gas multiplier of this client.
+    pub fn simulated_gas_fee(&self, simulated_gas: Gas) -> Fee
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let gas_limit = (simulated_gas.value() as f32 * self.simulated_gas_multiplier) as u64;
+        Operation::determine_custom_fee(self.client.gas_price(), gas_limit.into()).into()
+    }
+
+    pub async fn upload(
+        &self,
+        wasm_code: Vec<u8>,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<UploadResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::Upload);
+        self.client
+            .upload(self.address(), wasm_code, fee, memo)
+            .await
+    }
+
+    pub async fn instantiate<M>(
+        &self,
+        code_id: ContractCodeId,
+        msg: &M,
+        label: String,
+        memo: impl Into<String> + Send + 'static,
+        options: Option<InstantiateOptions>,
+    ) -> Result<InstantiateResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+        M: ?Sized + Serialize + Sync,
+    {
+        let fee = self.operation_fee(Operation::Init);
+        self.client
+            .instantiate(self.address(), code_id, msg, label, fee, memo, options)
+            .await
+    }
+
+    pub async fn update_admin(
+        &self,
+        contract_address: &AccountId,
+        new_admin: &AccountId,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<ChangeAdminResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::ChangeAdmin);
+        self.client
+            .update_admin(self.address(), contract_address, new_admin, fee, memo)
+            .await
+    }
+
+    pub async fn clear_admin(
+        &self,
+        contract_address: &AccountId,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<ChangeAdminResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::ChangeAdmin);
+        self.client
+            .clear_admin(self.address(), contract_address, fee, memo)
+            .await
+    }
+
+    pub async fn migrate<M>(
+        &self,
+        contract_address: &AccountId,
+        code_id: ContractCodeId,
+        msg: &M,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<MigrateResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+        M: ?Sized + Serialize + Sync,
+    {
+        let fee = self.operation_fee(Operation::Migrate);
+        self.client
+            .migrate(self.address(), contract_address, code_id, fee, msg, memo)
+            .await
+    }
+
+    /// Announce a mixnode, paying a fee.
+    pub async fn bond_mixnode(
+        &self,
+        mixnode: MixNode,
+        owner_signature: String,
+        pledge: Coin,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::BondMixnode);
+
+        let req = ExecuteMsg::BondMixnode {
+            mix_node: mixnode,
+            owner_signature,
+        };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Bonding mixnode from rust!",
+                vec![cosmwasm_coin_to_cosmos_coin(pledge)],
+            )
+            .await
+    }
+
+    /// Announce a mixnode on behalf of the owner, paying a fee.
+    pub async fn bond_mixnode_on_behalf(
+        &self,
+        mixnode: MixNode,
+        owner: String,
+        owner_signature: String,
+        pledge: Coin,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::BondMixnodeOnBehalf);
+
+        let req = ExecuteMsg::BondMixnodeOnBehalf {
+            mix_node: mixnode,
+            owner,
+            owner_signature,
+        };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Bonding mixnode on behalf from rust!",
+                vec![cosmwasm_coin_to_cosmos_coin(pledge)],
+            )
+            .await
+    }
+
+    /// Announce multiple mixnodes on behalf of other owners, paying a fee.
+    pub async fn bond_multiple_mixnodes_on_behalf(
+        &self,
+        mixnode_bonds_with_sigs: Vec<(MixNodeBond, String)>,
+    ) -> Result<ExecuteResult, NymdError>
     where
         C: SigningCosmWasmClient + Sync,
     {
@@ -823,39 +1218,128 @@ impl<C> NymdClient<C> {
     {
         let fee = self.operation_fee(Operation::UnbondMixnodeOnBehalf);
 
-        let req = ExecuteMsg::UnbondMixnodeOnBehalf { owner };
+        let req = ExecuteMsg::UnbondMixnodeOnBehalf { owner };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Unbonding mixnode on behalf from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Update the configuration of a mixnode. Right now, only possible for profit margin.
+    pub async fn update_mixnode_config(
+        &self,
+        profit_margin_percent: u8,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::UpdateMixnodeConfig);
+
+        let req = ExecuteMsg::UpdateMixnodeConfig {
+            profit_margin_percent,
+        };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Updating mixnode configuration from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Update the network details of a bonded mixnode, such as its host or ports,
+    /// without affecting its bond or delegations.
+    pub async fn update_mixnode_details(
+        &self,
+        details: MixNodeDetailsUpdate,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::UpdateMixnodeDetails);
+
+        let req = ExecuteMsg::UpdateMixnodeDetails { details };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Updating mixnode details from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Increase the pledge of the bonded mixnode. The change takes effect at the start of the next interval.
+    pub async fn increase_mixnode_pledge(&self, amount: Coin) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::IncreaseMixnodePledge);
+
+        let req = ExecuteMsg::IncreaseMixnodePledge {};
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Increasing mixnode pledge from rust!",
+                vec![cosmwasm_coin_to_cosmos_coin(amount)],
+            )
+            .await
+    }
+
+    /// Decrease the pledge of the bonded mixnode. The change takes effect, and the tokens are
+    /// returned, at the start of the next interval.
+    pub async fn decrease_mixnode_pledge(&self, amount: Coin) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::DecreaseMixnodePledge);
+
+        let req = ExecuteMsg::DecreaseMixnodePledge { amount };
         self.client
             .execute(
                 self.address(),
                 self.mixnet_contract_address()?,
                 &req,
                 fee,
-                "Unbonding mixnode on behalf from rust!",
+                "Decreasing mixnode pledge from rust!",
                 Vec::new(),
             )
             .await
     }
 
-    /// Update the configuration of a mixnode. Right now, only possible for profit margin.
-    pub async fn update_mixnode_config(
+    /// Update the network details of a bonded gateway, such as its host, ports or location,
+    /// without affecting its bond.
+    pub async fn update_gateway_details(
         &self,
-        profit_margin_percent: u8,
+        details: GatewayDetailsUpdate,
     ) -> Result<ExecuteResult, NymdError>
     where
         C: SigningCosmWasmClient + Sync,
     {
-        let fee = self.operation_fee(Operation::UpdateMixnodeConfig);
+        let fee = self.operation_fee(Operation::UpdateGatewayDetails);
 
-        let req = ExecuteMsg::UpdateMixnodeConfig {
-            profit_margin_percent,
-        };
+        let req = ExecuteMsg::UpdateGatewayDetails { details };
         self.client
             .execute(
                 self.address(),
                 self.mixnet_contract_address()?,
                 &req,
                 fee,
-                "Updating mixnode configuration from rust!",
+                "Updating gateway details from rust!",
                 Vec::new(),
             )
             .await
@@ -1005,6 +1489,82 @@ impl<C> NymdClient<C> {
             .await
     }
 
+    /// Moves stake delegation from one mixnode to another, preserving its original block height.
+    pub async fn redelegate_to_mixnode(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::RedelegateToMixnode);
+
+        let req = ExecuteMsg::RedelegateToMixnode {
+            from: from.to_string(),
+            to: to.to_string(),
+        };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Redelegating stake to mixnode from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Moves stake delegation from one mixnode to another on behalf of a particular delegator.
+    pub async fn redelegate_to_mixnode_on_behalf(
+        &self,
+        from: &str,
+        to: &str,
+        delegate: &str,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::RedelegateToMixnodeOnBehalf);
+
+        let req = ExecuteMsg::RedelegateToMixnodeOnBehalf {
+            from: from.to_string(),
+            to: to.to_string(),
+            delegate: delegate.to_string(),
+        };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Redelegating stake to mixnode on behalf from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Claims back all unbonded and undelegated tokens whose unbonding period is over.
+    pub async fn claim_pending_withdrawals(&self) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::ClaimPendingWithdrawals);
+
+        let req = ExecuteMsg::ClaimPendingWithdrawals {};
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Claiming pending withdrawals from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
     /// Announce a gateway, paying a fee.
     pub async fn bond_gateway(
         &self,
@@ -1142,6 +1702,268 @@ impl<C> NymdClient<C> {
             .await
     }
 
+    /// Delegates specified amount of stake to particular gateway.
+    pub async fn delegate_to_gateway(
+        &self,
+        gateway_identity: &str,
+        amount: &Coin,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::DelegateToGateway);
+
+        let req = ExecuteMsg::DelegateToGateway {
+            gateway_identity: gateway_identity.to_string(),
+        };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Delegating to gateway from rust!",
+                vec![cosmwasm_coin_ptr_to_cosmos_coin(amount)],
+            )
+            .await
+    }
+
+    /// Removes stake delegation from a particular gateway.
+    pub async fn remove_gateway_delegation(
+        &self,
+        gateway_identity: &str,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::UndelegateFromGateway);
+
+        let req = ExecuteMsg::UndelegateFromGateway {
+            gateway_identity: gateway_identity.to_string(),
+        };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Removing gateway delegation from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Withdraws all operator rewards accrued by the mixnode of the sender.
+    pub async fn claim_operator_reward(&self) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::ClaimOperatorReward);
+
+        let req = ExecuteMsg::ClaimOperatorReward {};
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Claiming operator reward from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Withdraws all rewards accrued by the delegation of the sender towards the specified mixnode.
+    pub async fn claim_delegator_reward(
+        &self,
+        mix_identity: IdentityKey,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::ClaimDelegatorReward);
+
+        let req = ExecuteMsg::ClaimDelegatorReward { mix_identity };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Claiming delegator reward from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Adds all rewards accrued by the delegation of the sender towards the specified mixnode
+    /// to the delegation itself.
+    pub async fn compound_delegator_reward(
+        &self,
+        mix_identity: IdentityKey,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::CompoundDelegatorReward);
+
+        let req = ExecuteMsg::CompoundDelegatorReward { mix_identity };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Compounding delegator reward from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Withdraws all operator rewards accrued by the gateway of the sender.
+    pub async fn claim_gateway_operator_reward(&self) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::ClaimGatewayOperatorReward);
+
+        let req = ExecuteMsg::ClaimGatewayOperatorReward {};
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Claiming gateway operator reward from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Withdraws all rewards accrued by the delegation of the sender towards the specified gateway.
+    pub async fn claim_gateway_delegator_reward(
+        &self,
+        gateway_identity: IdentityKey,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::ClaimGatewayDelegatorReward);
+
+        let req = ExecuteMsg::ClaimGatewayDelegatorReward { gateway_identity };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Claiming gateway delegator reward from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Adds all rewards accrued by the delegation of the sender towards the specified gateway
+    /// to the delegation itself.
+    pub async fn compound_gateway_delegator_reward(
+        &self,
+        gateway_identity: IdentityKey,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::CompoundGatewayDelegatorReward);
+
+        let req = ExecuteMsg::CompoundGatewayDelegatorReward { gateway_identity };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Compounding gateway delegator reward from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Creates a new family with the mixnode of the sender as its head.
+    pub async fn create_family(
+        &self,
+        owner_signature: String,
+        label: String,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::CreateFamily);
+
+        let req = ExecuteMsg::CreateFamily {
+            owner_signature,
+            label,
+        };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Creating mixnode family from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Adds the mixnode of the sender to the family with the specified head.
+    /// The join permit is the signature of the family head on `family_join_permit_message`
+    /// of the joining node.
+    pub async fn join_family(
+        &self,
+        owner_signature: String,
+        family_head: IdentityKey,
+        join_permit: String,
+    ) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::JoinFamily);
+
+        let req = ExecuteMsg::JoinFamily {
+            owner_signature,
+            family_head,
+            join_permit,
+        };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Joining mixnode family from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
+    pub async fn leave_family(&self, owner_signature: String) -> Result<ExecuteResult, NymdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let fee = self.operation_fee(Operation::LeaveFamily);
+
+        let req = ExecuteMsg::LeaveFamily { owner_signature };
+        self.client
+            .execute(
+                self.address(),
+                self.mixnet_contract_address()?,
+                &req,
+                fee,
+                "Leaving mixnode family from rust!",
+                Vec::new(),
+            )
+            .await
+    }
+
     pub async fn update_contract_settings(
         &self,
         new_params: ContractStateParams,
@@ -1187,6 +2009,8 @@ impl<C> NymdClient<C> {
         &self,
         rewarded_set: Vec<IdentityKey>,
         expected_active_set_size: u32,
+        seed: RewardedSetSeed,
+        layer_assignment: Vec<LayerAssignment>,
     ) -> Result<ExecuteResult, NymdError>
     where
         C: SigningCosmWasmClient + Sync,
@@ -1196,6 +2020,8 @@ impl<C> NymdClient<C> {
         let req = ExecuteMsg::WriteRewardedSet {
             rewarded_set,
             expected_active_set_size,
+            seed,
+            layer_assignment,
         };
         self.client
             .execute(
@@ -8,7 +8,9 @@ use crate::nymd::fee::helpers::Operation;
 use crate::nymd::{cosmwasm_coin_to_cosmos_coin, NymdClient};
 use async_trait::async_trait;
 use cosmwasm_std::Coin;
-use mixnet_contract_common::{Gateway, IdentityKey, IdentityKeyRef, MixNode};
+use mixnet_contract_common::{
+    Gateway, GatewayDetailsUpdate, IdentityKey, IdentityKeyRef, MixNode, MixNodeDetailsUpdate,
+};
 use vesting_contract_common::messages::{ExecuteMsg as VestingExecuteMsg, VestingSpecification};
 
 #[async_trait]
@@ -18,6 +20,52 @@ pub trait VestingSigningClient {
         profix_margin_percent: u8,
     ) -> Result<ExecuteResult, NymdError>;
 
+    async fn vesting_update_mixnode_details(
+        &self,
+        details: MixNodeDetailsUpdate,
+    ) -> Result<ExecuteResult, NymdError>;
+
+    async fn vesting_update_gateway_details(
+        &self,
+        details: GatewayDetailsUpdate,
+    ) -> Result<ExecuteResult, NymdError>;
+
+    async fn vesting_increase_mixnode_pledge(
+        &self,
+        amount: Coin,
+    ) -> Result<ExecuteResult, NymdError>;
+
+    async fn vesting_decrease_mixnode_pledge(
+        &self,
+        amount: Coin,
+    ) -> Result<ExecuteResult, NymdError>;
+
+    async fn vesting_claim_pending_withdrawals(&self) -> Result<ExecuteResult, NymdError>;
+
+    async fn vesting_claim_operator_reward(&self) -> Result<ExecuteResult, NymdError>;
+
+    async fn vesting_claim_delegator_reward(
+        &self,
+        mix_identity: IdentityKey,
+    ) -> Result<ExecuteResult, NymdError>;
+
+    async fn vesting_compound_delegator_reward(
+        &self,
+        mix_identity: IdentityKey,
+    ) -> Result<ExecuteResult, NymdError>;
+
+    async fn vesting_claim_gateway_operator_reward(&self) -> Result<ExecuteResult, NymdError>;
+
+    async fn vesting_claim_gateway_delegator_reward(
+        &self,
+        gateway_identity: IdentityKey,
+    ) -> Result<ExecuteResult, NymdError>;
+
+    async fn vesting_compound_gateway_delegator_reward(
+        &self,
+        gateway_identity: IdentityKey,
+    ) -> Result<ExecuteResult, NymdError>;
+
     async fn update_mixnet_address(&self, address: &str) -> Result<ExecuteResult, NymdError>;
 
     async fn vesting_bond_gateway(
@@ -69,6 +117,23 @@ pub trait VestingSigningClient {
         mix_identity: IdentityKeyRef<'a>,
     ) -> Result<ExecuteResult, NymdError>;
 
+    async fn vesting_redelegate_to_mixnode<'a>(
+        &self,
+        from: IdentityKeyRef<'a>,
+        to: IdentityKeyRef<'a>,
+    ) -> Result<ExecuteResult, NymdError>;
+
+    async fn vesting_delegate_to_gateway<'a>(
+        &self,
+        gateway_identity: IdentityKeyRef<'a>,
+        amount: &Coin,
+    ) -> Result<ExecuteResult, NymdError>;
+
+    async fn vesting_undelegate_from_gateway<'a>(
+        &self,
+        gateway_identity: IdentityKeyRef<'a>,
+    ) -> Result<ExecuteResult, NymdError>;
+
     async fn create_periodic_vesting_account(
         &self,
         owner_address: &str,
@@ -280,6 +345,70 @@ impl<C: SigningCosmWasmClient + Sync + Send> VestingSigningClient for NymdClient
             )
             .await
     }
+
+    async fn vesting_delegate_to_gateway<'a>(
+        &self,
+        gateway_identity: IdentityKeyRef<'a>,
+        amount: &Coin,
+    ) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::DelegateToGateway);
+        let req = VestingExecuteMsg::DelegateToGateway {
+            gateway_identity: gateway_identity.into(),
+            amount: amount.clone(),
+        };
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::DelegateToGateway",
+                vec![],
+            )
+            .await
+    }
+
+    async fn vesting_undelegate_from_gateway<'a>(
+        &self,
+        gateway_identity: IdentityKeyRef<'a>,
+    ) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::UndelegateFromGateway);
+        let req = VestingExecuteMsg::UndelegateFromGateway {
+            gateway_identity: gateway_identity.into(),
+        };
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::UndelegateFromGateway",
+                vec![],
+            )
+            .await
+    }
+
+    async fn vesting_redelegate_to_mixnode<'a>(
+        &self,
+        from: IdentityKeyRef<'a>,
+        to: IdentityKeyRef<'a>,
+    ) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::RedelegateToMixnode);
+        let req = VestingExecuteMsg::RedelegateToMixnode {
+            from: from.into(),
+            to: to.into(),
+        };
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::RedelegateToMixnode",
+                vec![],
+            )
+            .await
+    }
     async fn create_periodic_vesting_account(
         &self,
         owner_address: &str,
@@ -341,4 +470,193 @@ impl<C: SigningCosmWasmClient + Sync + Send> VestingSigningClient for NymdClient
             )
             .await
     }
+
+    async fn vesting_update_mixnode_details(
+        &self,
+        details: MixNodeDetailsUpdate,
+    ) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::UpdateMixnodeDetails);
+        let req = VestingExecuteMsg::UpdateMixnodeDetails { details };
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::UpdateMixnodeDetails",
+                vec![],
+            )
+            .await
+    }
+
+    async fn vesting_increase_mixnode_pledge(
+        &self,
+        amount: Coin,
+    ) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::IncreaseMixnodePledge);
+        let req = VestingExecuteMsg::IncreaseMixnodePledge { amount };
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::IncreaseMixnodePledge",
+                vec![],
+            )
+            .await
+    }
+
+    async fn vesting_decrease_mixnode_pledge(
+        &self,
+        amount: Coin,
+    ) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::DecreaseMixnodePledge);
+        let req = VestingExecuteMsg::DecreaseMixnodePledge { amount };
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::DecreaseMixnodePledge",
+                vec![],
+            )
+            .await
+    }
+
+    async fn vesting_claim_pending_withdrawals(&self) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::ClaimPendingWithdrawals);
+        let req = VestingExecuteMsg::ClaimPendingWithdrawals {};
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::ClaimPendingWithdrawals",
+                vec![],
+            )
+            .await
+    }
+
+    async fn vesting_claim_operator_reward(&self) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::ClaimOperatorReward);
+        let req = VestingExecuteMsg::ClaimOperatorReward {};
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::ClaimOperatorReward",
+                vec![],
+            )
+            .await
+    }
+
+    async fn vesting_claim_delegator_reward(
+        &self,
+        mix_identity: IdentityKey,
+    ) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::ClaimDelegatorReward);
+        let req = VestingExecuteMsg::ClaimDelegatorReward { mix_identity };
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::ClaimDelegatorReward",
+                vec![],
+            )
+            .await
+    }
+
+    async fn vesting_compound_delegator_reward(
+        &self,
+        mix_identity: IdentityKey,
+    ) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::CompoundDelegatorReward);
+        let req = VestingExecuteMsg::CompoundDelegatorReward { mix_identity };
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::CompoundDelegatorReward",
+                vec![],
+            )
+            .await
+    }
+
+    async fn vesting_claim_gateway_operator_reward(&self) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::ClaimGatewayOperatorReward);
+        let req = VestingExecuteMsg::ClaimGatewayOperatorReward {};
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::ClaimGatewayOperatorReward",
+                vec![],
+            )
+            .await
+    }
+
+    async fn vesting_claim_gateway_delegator_reward(
+        &self,
+        gateway_identity: IdentityKey,
+    ) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::ClaimGatewayDelegatorReward);
+        let req = VestingExecuteMsg::ClaimGatewayDelegatorReward { gateway_identity };
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::ClaimGatewayDelegatorReward",
+                vec![],
+            )
+            .await
+    }
+
+    async fn vesting_compound_gateway_delegator_reward(
+        &self,
+        gateway_identity: IdentityKey,
+    ) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::CompoundGatewayDelegatorReward);
+        let req = VestingExecuteMsg::CompoundGatewayDelegatorReward { gateway_identity };
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::CompoundGatewayDelegatorReward",
+                vec![],
+            )
+            .await
+    }
+
+    async fn vesting_update_gateway_details(
+        &self,
+        details: GatewayDetailsUpdate,
+    ) -> Result<ExecuteResult, NymdError> {
+        let fee = self.operation_fee(Operation::UpdateGatewayDetails);
+        let req = VestingExecuteMsg::UpdateGatewayDetails { details };
+        self.client
+            .execute(
+                self.address(),
+                self.vesting_contract_address()?,
+                &req,
+                fee,
+                "VestingContract::UpdateGatewayDetails",
+                vec![],
+            )
+            .await
+    }
 }
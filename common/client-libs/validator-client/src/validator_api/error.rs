@@ -1,4 +1,5 @@
 use thiserror::Error;
+use validator_api_requests::models::TopologySnapshotKind;
 
 #[derive(Error, Debug)]
 pub enum ValidatorAPIError {
@@ -7,4 +8,28 @@ pub enum ValidatorAPIError {
         #[from]
         source: reqwest::Error,
     },
+
+    #[error("The trusted validator api key {key} is malformed")]
+    MalformedTrustedKey { key: String },
+
+    #[error("The topology snapshot has been signed by an untrusted validator api {signer}")]
+    UntrustedSigner { signer: String },
+
+    #[error("The signature of validator api {signer} on the topology snapshot is invalid")]
+    InvalidSignature { signer: String },
+
+    #[error("The signed topology snapshot is malformed - {source}")]
+    MalformedSnapshot {
+        #[from]
+        source: serde_json::Error,
+    },
+
+    #[error("Received topology snapshot of kind {received:?} while {expected:?} was expected")]
+    UnexpectedSnapshotKind {
+        expected: TopologySnapshotKind,
+        received: TopologySnapshotKind,
+    },
+
+    #[error("The topology snapshot from block {block_height} (created at {as_at}) is too old to be used")]
+    StaleSnapshot { as_at: i64, block_height: u64 },
 }
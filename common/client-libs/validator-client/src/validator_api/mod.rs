@@ -4,17 +4,19 @@
 use crate::validator_api::error::ValidatorAPIError;
 use crate::validator_api::routes::{CORE_STATUS_COUNT, SINCE_ARG};
 use coconut_interface::{BlindSignRequestBody, BlindedSignatureResponse, VerificationKeyResponse};
-use mixnet_contract_common::{GatewayBond, IdentityKeyRef, MixNodeBond};
+use mixnet_contract_common::{GatewayBond, IdentityKeyRef, LayerAssignment, MixNodeBond};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use url::Url;
 use validator_api_requests::models::{
-    CoreNodeStatusResponse, InclusionProbabilityResponse, MixnodeStatusResponse,
-    RewardEstimationResponse, StakeSaturationResponse,
+    CoreNodeStatusResponse, HistoryBucketing, InclusionProbabilityResponse, MixnodeStatusResponse,
+    NodeHistoryResponse, NodeLatencyResponse, NodeMonitorScoresResponse, RewardEstimationResponse,
+    SignedMonitorRunSummary, SignedTopologyResponse, StakeSaturationResponse,
 };
 
 pub mod error;
 pub(crate) mod routes;
+pub mod verification;
 
 type PathSegments<'a> = &'a [&'a str];
 type Params<'a, K, V> = &'a [(K, V)];
@@ -102,6 +104,67 @@ impl Client {
         .await
     }
 
+    pub async fn get_layer_assignment(&self) -> Result<Vec<LayerAssignment>, ValidatorAPIError> {
+        self.query_validator_api(
+            &[routes::API_VERSION, routes::MIXNODES, routes::LAYERS],
+            NO_PARAMS,
+        )
+        .await
+    }
+
+    pub async fn get_signed_gateways(&self) -> Result<SignedTopologyResponse, ValidatorAPIError> {
+        self.query_validator_api(
+            &[routes::API_VERSION, routes::GATEWAYS, routes::SIGNED],
+            NO_PARAMS,
+        )
+        .await
+    }
+
+    pub async fn get_signed_active_mixnodes(
+        &self,
+    ) -> Result<SignedTopologyResponse, ValidatorAPIError> {
+        self.query_validator_api(
+            &[
+                routes::API_VERSION,
+                routes::MIXNODES,
+                routes::ACTIVE,
+                routes::SIGNED,
+            ],
+            NO_PARAMS,
+        )
+        .await
+    }
+
+    pub async fn get_signed_rewarded_mixnodes(
+        &self,
+    ) -> Result<SignedTopologyResponse, ValidatorAPIError> {
+        self.query_validator_api(
+            &[
+                routes::API_VERSION,
+                routes::MIXNODES,
+                routes::REWARDED,
+                routes::SIGNED,
+            ],
+            NO_PARAMS,
+        )
+        .await
+    }
+
+    pub async fn get_signed_layer_assignment(
+        &self,
+    ) -> Result<SignedTopologyResponse, ValidatorAPIError> {
+        self.query_validator_api(
+            &[
+                routes::API_VERSION,
+                routes::MIXNODES,
+                routes::LAYERS,
+                routes::SIGNED,
+            ],
+            NO_PARAMS,
+        )
+        .await
+    }
+
     pub async fn get_probs_mixnode_rewarded(
         &self,
         mixnode_id: &str,
@@ -249,6 +312,143 @@ impl Client {
         .await
     }
 
+    pub async fn get_monitor_run_summaries(
+        &self,
+        since: Option<i64>,
+    ) -> Result<Vec<SignedMonitorRunSummary>, ValidatorAPIError> {
+        if let Some(since) = since {
+            self.query_validator_api(
+                &[routes::API_VERSION, routes::MONITOR, routes::SUMMARIES],
+                &[(SINCE_ARG, since.to_string())],
+            )
+            .await
+        } else {
+            self.query_validator_api(
+                &[routes::API_VERSION, routes::MONITOR, routes::SUMMARIES],
+                NO_PARAMS,
+            )
+            .await
+        }
+    }
+
+    pub async fn get_mixnode_monitor_scores(
+        &self,
+        identity: IdentityKeyRef<'_>,
+    ) -> Result<NodeMonitorScoresResponse, ValidatorAPIError> {
+        self.query_validator_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::MIXNODE,
+                identity,
+                routes::MONITOR_SCORES,
+            ],
+            NO_PARAMS,
+        )
+        .await
+    }
+
+    pub async fn get_gateway_monitor_scores(
+        &self,
+        identity: IdentityKeyRef<'_>,
+    ) -> Result<NodeMonitorScoresResponse, ValidatorAPIError> {
+        self.query_validator_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::GATEWAY,
+                identity,
+                routes::MONITOR_SCORES,
+            ],
+            NO_PARAMS,
+        )
+        .await
+    }
+
+    pub async fn get_mixnode_latency(
+        &self,
+        identity: IdentityKeyRef<'_>,
+    ) -> Result<NodeLatencyResponse, ValidatorAPIError> {
+        self.query_validator_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::MIXNODE,
+                identity,
+                routes::LATENCY,
+            ],
+            NO_PARAMS,
+        )
+        .await
+    }
+
+    pub async fn get_gateway_latency(
+        &self,
+        identity: IdentityKeyRef<'_>,
+    ) -> Result<NodeLatencyResponse, ValidatorAPIError> {
+        self.query_validator_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::GATEWAY,
+                identity,
+                routes::LATENCY,
+            ],
+            NO_PARAMS,
+        )
+        .await
+    }
+
+    pub async fn get_mixnode_history_range(
+        &self,
+        identity: IdentityKeyRef<'_>,
+        from: i64,
+        to: i64,
+        bucketing: HistoryBucketing,
+    ) -> Result<NodeHistoryResponse, ValidatorAPIError> {
+        self.query_validator_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::MIXNODE,
+                identity,
+                routes::HISTORY,
+                routes::RANGE,
+            ],
+            &[
+                (routes::FROM_ARG, from.to_string()),
+                (routes::TO_ARG, to.to_string()),
+                (routes::BUCKET_ARG, bucketing.to_string()),
+            ],
+        )
+        .await
+    }
+
+    pub async fn get_gateway_history_range(
+        &self,
+        identity: IdentityKeyRef<'_>,
+        from: i64,
+        to: i64,
+        bucketing: HistoryBucketing,
+    ) -> Result<NodeHistoryResponse, ValidatorAPIError> {
+        self.query_validator_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::GATEWAY,
+                identity,
+                routes::HISTORY,
+                routes::RANGE,
+            ],
+            &[
+                (routes::FROM_ARG, from.to_string()),
+                (routes::TO_ARG, to.to_string()),
+                (routes::BUCKET_ARG, bucketing.to_string()),
+            ],
+        )
+        .await
+    }
+
     pub async fn blind_sign(
         &self,
         request_body: &BlindSignRequestBody,
@@ -9,6 +9,8 @@ pub const GATEWAYS: &str = "gateways";
 
 pub const ACTIVE: &str = "active";
 pub const REWARDED: &str = "rewarded";
+pub const LAYERS: &str = "layers";
+pub const SIGNED: &str = "signed";
 
 pub const COCONUT_BLIND_SIGN: &str = "blind-sign";
 pub const COCONUT_VERIFICATION_KEY: &str = "verification-key";
@@ -24,3 +26,13 @@ pub const STATUS: &str = "status";
 pub const REWARD_ESTIMATION: &str = "reward-estimation";
 pub const STAKE_SATURATION: &str = "stake-saturation";
 pub const INCLUSION_CHANCE: &str = "inclusion-probability";
+pub const MONITOR_SCORES: &str = "monitor-scores";
+pub const LATENCY: &str = "latency";
+pub const HISTORY: &str = "history";
+pub const RANGE: &str = "range";
+pub const FROM_ARG: &str = "from";
+pub const TO_ARG: &str = "to";
+pub const BUCKET_ARG: &str = "bucket";
+
+pub const MONITOR: &str = "monitor";
+pub const SUMMARIES: &str = "summaries";
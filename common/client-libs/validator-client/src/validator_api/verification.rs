@@ -0,0 +1,183 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::validator_api::error::ValidatorAPIError;
+use crypto::asymmetric::identity;
+use serde::de::DeserializeOwned;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use validator_api_requests::models::{
+    SignedTopologyResponse, TopologySnapshot, TopologySnapshotKind,
+};
+
+/// Set of validator apis whose signed topology snapshots are going to be accepted.
+#[derive(Clone, Debug)]
+pub struct TrustedApis {
+    identity_keys: Vec<identity::PublicKey>,
+    maximum_snapshot_age: Duration,
+}
+
+impl TrustedApis {
+    pub fn new(identity_keys: Vec<identity::PublicKey>, maximum_snapshot_age: Duration) -> Self {
+        TrustedApis {
+            identity_keys,
+            maximum_snapshot_age,
+        }
+    }
+
+    /// Attempts to parse the base58-encoded identity keys of the trusted validator apis.
+    pub fn from_base58_keys<S: AsRef<str>>(
+        identity_keys: &[S],
+        maximum_snapshot_age: Duration,
+    ) -> Result<Self, ValidatorAPIError> {
+        let identity_keys = identity_keys
+            .iter()
+            .map(|key| {
+                identity::PublicKey::from_base58_string(key.as_ref()).map_err(|_| {
+                    ValidatorAPIError::MalformedTrustedKey {
+                        key: key.as_ref().to_string(),
+                    }
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(TrustedApis::new(identity_keys, maximum_snapshot_age))
+    }
+
+    /// Verifies the snapshot has been signed by one of the trusted apis, is of the expected kind
+    /// and is recent enough to be used.
+    ///
+    /// # Arguments
+    ///
+    /// * `response`: signed snapshot as received from the validator api.
+    /// * `expected_kind`: kind of the snapshot that has been requested.
+    pub fn verify<T: DeserializeOwned>(
+        &self,
+        response: &SignedTopologyResponse,
+        expected_kind: TopologySnapshotKind,
+    ) -> Result<TopologySnapshot<T>, ValidatorAPIError> {
+        let signer = self
+            .identity_keys
+            .iter()
+            .find(|key| key.to_base58_string() == response.signer)
+            .ok_or_else(|| ValidatorAPIError::UntrustedSigner {
+                signer: response.signer.clone(),
+            })?;
+
+        let signature =
+            identity::Signature::from_base58_string(&response.signature).map_err(|_| {
+                ValidatorAPIError::InvalidSignature {
+                    signer: response.signer.clone(),
+                }
+            })?;
+        signer
+            .verify(response.snapshot.as_bytes(), &signature)
+            .map_err(|_| ValidatorAPIError::InvalidSignature {
+                signer: response.signer.clone(),
+            })?;
+
+        let snapshot: TopologySnapshot<T> = serde_json::from_str(&response.snapshot)?;
+        if snapshot.kind != expected_kind {
+            return Err(ValidatorAPIError::UnexpectedSnapshotKind {
+                expected: expected_kind,
+                received: snapshot.kind,
+            });
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time is before the unix epoch")
+            .as_secs() as i64;
+        if now - snapshot.as_at > self.maximum_snapshot_age.as_secs() as i64 {
+            return Err(ValidatorAPIError::StaleSnapshot {
+                as_at: snapshot.as_at,
+                block_height: snapshot.block_height,
+            });
+        }
+
+        Ok(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signed_snapshot(
+        private_key: &identity::PrivateKey,
+        kind: TopologySnapshotKind,
+        as_at: i64,
+    ) -> SignedTopologyResponse {
+        let snapshot = serde_json::to_string(&TopologySnapshot {
+            kind,
+            block_height: 42,
+            as_at,
+            nodes: vec!["node".to_string()],
+        })
+        .unwrap();
+        let signature = private_key.sign(snapshot.as_bytes()).to_base58_string();
+
+        SignedTopologyResponse {
+            snapshot,
+            signer: identity::PublicKey::from(private_key).to_base58_string(),
+            signature,
+        }
+    }
+
+    fn now() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64
+    }
+
+    #[test]
+    fn recent_snapshot_signed_by_trusted_api_is_accepted() {
+        let private_key = identity::PrivateKey::from_bytes(&[1; 32]).unwrap();
+        let trusted = TrustedApis::new(
+            vec![identity::PublicKey::from(&private_key)],
+            Duration::from_secs(60),
+        );
+
+        let response = signed_snapshot(&private_key, TopologySnapshotKind::Gateways, now());
+        let snapshot: TopologySnapshot<Vec<String>> = trusted
+            .verify(&response, TopologySnapshotKind::Gateways)
+            .unwrap();
+        assert_eq!(snapshot.block_height, 42);
+        assert_eq!(snapshot.nodes, vec!["node".to_string()]);
+    }
+
+    #[test]
+    fn untrusted_tampered_mismatched_or_stale_snapshots_are_rejected() {
+        let private_key = identity::PrivateKey::from_bytes(&[1; 32]).unwrap();
+        let other_key = identity::PrivateKey::from_bytes(&[2; 32]).unwrap();
+        let trusted = TrustedApis::new(
+            vec![identity::PublicKey::from(&private_key)],
+            Duration::from_secs(60),
+        );
+
+        let untrusted = signed_snapshot(&other_key, TopologySnapshotKind::ActiveSet, now());
+        assert!(matches!(
+            trusted.verify::<Vec<String>>(&untrusted, TopologySnapshotKind::ActiveSet),
+            Err(ValidatorAPIError::UntrustedSigner { .. })
+        ));
+
+        let mut tampered = signed_snapshot(&private_key, TopologySnapshotKind::ActiveSet, now());
+        tampered.snapshot = tampered.snapshot.replace("42", "43");
+        assert!(matches!(
+            trusted.verify::<Vec<String>>(&tampered, TopologySnapshotKind::ActiveSet),
+            Err(ValidatorAPIError::InvalidSignature { .. })
+        ));
+
+        let rewarded = signed_snapshot(&private_key, TopologySnapshotKind::RewardedSet, now());
+        assert!(matches!(
+            trusted.verify::<Vec<String>>(&rewarded, TopologySnapshotKind::ActiveSet),
+            Err(ValidatorAPIError::UnexpectedSnapshotKind { .. })
+        ));
+
+        let stale = signed_snapshot(&private_key, TopologySnapshotKind::ActiveSet, now() - 120);
+        assert!(matches!(
+            trusted.verify::<Vec<String>>(&stale, TopologySnapshotKind::ActiveSet),
+            Err(ValidatorAPIError::StaleSnapshot { .. })
+        ));
+    }
+}
@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::mixnode::NodeRewardResult;
-use crate::{ContractStateParams, Delegation, IdentityKeyRef, Interval, Layer};
+use crate::{
+    ContractStateParams, Delegation, GatewayDetailsUpdate, IdentityKeyRef, Interval, Layer,
+    MixNodeDetailsUpdate, PendingWithdrawal, PledgeChange, RewardedSetSeed, SlashingRecord,
+};
 use cosmwasm_std::{Addr, Coin, Event, Uint128};
 
 pub use contracts_common::events::*;
@@ -10,15 +13,37 @@ pub use contracts_common::events::*;
 // event types
 pub const DELEGATION_EVENT_TYPE: &str = "delegation";
 pub const UNDELEGATION_EVENT_TYPE: &str = "undelegation";
+pub const REDELEGATION_EVENT_TYPE: &str = "redelegation";
 pub const GATEWAY_BONDING_EVENT_TYPE: &str = "gateway_bonding";
 pub const GATEWAY_UNBONDING_EVENT_TYPE: &str = "gateway_unbonding";
 pub const MIXNODE_BONDING_EVENT_TYPE: &str = "mixnode_bonding";
 pub const MIXNODE_UNBONDING_EVENT_TYPE: &str = "mixnode_unbonding";
+pub const MIXNODE_DETAILS_UPDATE_EVENT_TYPE: &str = "mixnode_details_update";
+pub const GATEWAY_DETAILS_UPDATE_EVENT_TYPE: &str = "gateway_details_update";
+pub const MIXNODE_PLEDGE_CHANGE_EVENT_TYPE: &str = "mixnode_pledge_change";
+pub const MIXNODE_PLEDGE_CHANGE_APPLIED_EVENT_TYPE: &str = "mixnode_pledge_change_applied";
+pub const MIXNODE_PLEDGE_CHANGE_CANCELLED_EVENT_TYPE: &str = "mixnode_pledge_change_cancelled";
 pub const SETTINGS_UPDATE_EVENT_TYPE: &str = "settings_update";
 pub const OPERATOR_REWARDING_EVENT_TYPE: &str = "mix_rewarding";
 pub const MIX_DELEGATORS_REWARDING_EVENT_TYPE: &str = "mix_delegators_rewarding";
 pub const CHANGE_REWARDED_SET_EVENT_TYPE: &str = "change_rewarded_set";
 pub const ADVANCE_INTERVAL_EVENT_TYPE: &str = "advance_interval";
+pub const PENDING_WITHDRAWAL_EVENT_TYPE: &str = "pending_withdrawal";
+pub const WITHDRAWAL_CLAIM_EVENT_TYPE: &str = "withdrawal_claim";
+pub const PERFORMANCE_REPORT_EVENT_TYPE: &str = "mix_performance_report";
+pub const MIXNODE_SLASHING_EVENT_TYPE: &str = "mixnode_slashing";
+pub const MIX_DELEGATORS_SLASHING_EVENT_TYPE: &str = "mix_delegators_slashing";
+pub const GATEWAY_DELEGATION_EVENT_TYPE: &str = "gateway_delegation";
+pub const GATEWAY_UNDELEGATION_EVENT_TYPE: &str = "gateway_undelegation";
+pub const GATEWAY_REWARDING_EVENT_TYPE: &str = "gateway_rewarding";
+pub const GATEWAY_DELEGATORS_REWARDING_EVENT_TYPE: &str = "gateway_delegators_rewarding";
+pub const FAMILY_CREATION_EVENT_TYPE: &str = "family_creation";
+pub const FAMILY_JOIN_EVENT_TYPE: &str = "family_join";
+pub const FAMILY_LEAVE_EVENT_TYPE: &str = "family_leave";
+pub const FAMILY_DISBAND_EVENT_TYPE: &str = "family_disband";
+pub const OPERATOR_REWARD_CLAIM_EVENT_TYPE: &str = "operator_reward_claim";
+pub const DELEGATOR_REWARD_CLAIM_EVENT_TYPE: &str = "delegator_reward_claim";
+pub const DELEGATOR_REWARD_COMPOUNDING_EVENT_TYPE: &str = "delegator_reward_compounding";
 
 // attributes that are used in multiple places
 pub const OWNER_KEY: &str = "owner";
@@ -30,27 +55,56 @@ pub const PROXY_KEY: &str = "proxy";
 // delegation/undelegation
 pub const DELEGATOR_KEY: &str = "delegator";
 pub const DELEGATION_TARGET_KEY: &str = "delegation_target";
+pub const DELEGATION_SOURCE_KEY: &str = "delegation_source";
 pub const DELEGATION_HEIGHT_KEY: &str = "delegation_latest_block_height";
 
 // bonding/unbonding
 pub const NODE_IDENTITY_KEY: &str = "identity";
 pub const ASSIGNED_LAYER_KEY: &str = "assigned_layer";
 
+// node details update
+pub const HOST_KEY: &str = "host";
+pub const MIX_PORT_KEY: &str = "mix_port";
+pub const VERLOC_PORT_KEY: &str = "verloc_port";
+pub const HTTP_API_PORT_KEY: &str = "http_api_port";
+pub const CLIENTS_PORT_KEY: &str = "clients_port";
+pub const LOCATION_KEY: &str = "location";
+pub const VERSION_KEY: &str = "version";
+
+// pledge change
+pub const PLEDGE_CHANGE_KEY: &str = "pledge_change";
+pub const NEW_PLEDGE_KEY: &str = "new_pledge";
+pub const CANCELLATION_REASON_KEY: &str = "cancellation_reason";
+pub const NODE_SLASHED_VALUE: &str = "node_slashed";
+pub const INSUFFICIENT_PLEDGE_VALUE: &str = "insufficient_pledge";
+
+// pending withdrawals
+pub const WITHDRAWAL_ID_KEY: &str = "withdrawal_id";
+pub const WITHDRAWAL_KIND_KEY: &str = "withdrawal_kind";
+pub const RELEASE_TIME_KEY: &str = "release_time";
+
 // settings change
 pub const OLD_MINIMUM_MIXNODE_PLEDGE_KEY: &str = "old_minimum_mixnode_pledge";
 pub const OLD_MINIMUM_GATEWAY_PLEDGE_KEY: &str = "old_minimum_gateway_pledge";
 pub const OLD_MIXNODE_REWARDED_SET_SIZE_KEY: &str = "old_mixnode_rewarded_set_size";
 pub const OLD_MIXNODE_ACTIVE_SET_SIZE_KEY: &str = "old_mixnode_active_set_size";
 pub const OLD_ACTIVE_SET_WORK_FACTOR_KEY: &str = "old_active_set_work_factor";
+pub const OLD_UNBONDING_PERIOD_KEY: &str = "old_unbonding_period";
+pub const OLD_SLASHING_PARAMS_KEY: &str = "old_slashing_params";
+pub const OLD_GATEWAY_REWARDING_PARAMS_KEY: &str = "old_gateway_rewarding_params";
 
 pub const NEW_MINIMUM_MIXNODE_PLEDGE_KEY: &str = "new_minimum_mixnode_pledge";
 pub const NEW_MINIMUM_GATEWAY_PLEDGE_KEY: &str = "new_minimum_gateway_pledge";
 pub const NEW_MIXNODE_REWARDED_SET_SIZE_KEY: &str = "new_mixnode_rewarded_set_size";
 pub const NEW_MIXNODE_ACTIVE_SET_SIZE_KEY: &str = "new_mixnode_active_set_size";
+pub const NEW_UNBONDING_PERIOD_KEY: &str = "new_unbonding_period";
+pub const NEW_SLASHING_PARAMS_KEY: &str = "new_slashing_params";
+pub const NEW_GATEWAY_REWARDING_PARAMS_KEY: &str = "new_gateway_rewarding_params";
 
 // rewarding
 pub const INTERVAL_ID_KEY: &str = "interval_id";
 pub const TOTAL_MIXNODE_REWARD_KEY: &str = "total_node_reward";
+pub const TOTAL_GATEWAY_REWARD_KEY: &str = "total_gateway_reward";
 pub const OPERATOR_REWARD_KEY: &str = "operator_reward";
 pub const TOTAL_PLEDGE_KEY: &str = "pledge";
 pub const TOTAL_DELEGATIONS_KEY: &str = "delegated";
@@ -63,11 +117,27 @@ pub const BOND_NOT_FOUND_VALUE: &str = "bond_not_found";
 pub const BOND_TOO_FRESH_VALUE: &str = "bond_too_fresh";
 pub const ZERO_UPTIME_VALUE: &str = "zero_uptime";
 
+// performance reports and slashing
+pub const UPTIME_KEY: &str = "uptime";
+pub const IN_ACTIVE_SET_KEY: &str = "in_active_set";
+pub const POOR_INTERVALS_KEY: &str = "poor_intervals";
+pub const SLASHING_ID_KEY: &str = "slashing_id";
+pub const SLASHED_PLEDGE_KEY: &str = "slashed_pledge";
+pub const SLASHED_DELEGATIONS_KEY: &str = "slashed_delegations";
+pub const SLASHING_DESTINATION_KEY: &str = "slashing_destination";
+pub const FURTHER_STAKE_TO_SLASH_KEY: &str = "further_stake";
+
+// families
+pub const FAMILY_HEAD_KEY: &str = "family_head";
+pub const FAMILY_LABEL_KEY: &str = "family_label";
+
 // rewarded set update
 pub const ACTIVE_SET_SIZE_KEY: &str = "active_set_size";
 pub const REWARDED_SET_SIZE_KEY: &str = "rewarded_set_size";
 pub const NODES_IN_REWARDED_SET_KEY: &str = "nodes_in_rewarded_set";
 pub const CURRENT_INTERVAL_ID_KEY: &str = "current_interval";
+pub const SEED_HEIGHT_KEY: &str = "seed_height";
+pub const SEED_BLOCK_HASH_KEY: &str = "seed_block_hash";
 
 pub const NEW_CURRENT_INTERVAL_KEY: &str = "new_current_interval";
 
@@ -111,6 +181,70 @@ pub fn new_undelegation_event(
         .add_attribute(DELEGATION_TARGET_KEY, mix_identity)
 }
 
+pub fn new_redelegation_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    amount: &Coin,
+    from: IdentityKeyRef<'_>,
+    to: IdentityKeyRef<'_>,
+    block_height: u64,
+) -> Event {
+    let mut event = Event::new(REDELEGATION_EVENT_TYPE).add_attribute(DELEGATOR_KEY, delegator);
+
+    if let Some(proxy) = proxy {
+        event = event.add_attribute(PROXY_KEY, proxy)
+    }
+
+    // coin implements Display trait and we use that implementation here
+    event
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(DELEGATION_HEIGHT_KEY, block_height.to_string())
+        .add_attribute(DELEGATION_SOURCE_KEY, from)
+        .add_attribute(DELEGATION_TARGET_KEY, to)
+}
+
+pub fn new_gateway_delegation_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    amount: &Coin,
+    gateway_identity: IdentityKeyRef<'_>,
+) -> Event {
+    let mut event =
+        Event::new(GATEWAY_DELEGATION_EVENT_TYPE).add_attribute(DELEGATOR_KEY, delegator);
+
+    if let Some(proxy) = proxy {
+        event = event.add_attribute(PROXY_KEY, proxy)
+    }
+
+    // coin implements Display trait and we use that implementation here
+    event
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, gateway_identity)
+}
+
+pub fn new_gateway_undelegation_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    old_delegation: &Delegation,
+    gateway_identity: IdentityKeyRef<'_>,
+) -> Event {
+    let mut event =
+        Event::new(GATEWAY_UNDELEGATION_EVENT_TYPE).add_attribute(DELEGATOR_KEY, delegator);
+
+    if let Some(proxy) = proxy {
+        event = event.add_attribute(PROXY_KEY, proxy)
+    }
+
+    // coin implements Display trait and we use that implementation here
+    event
+        .add_attribute(AMOUNT_KEY, old_delegation.amount.to_string())
+        .add_attribute(
+            DELEGATION_HEIGHT_KEY,
+            old_delegation.block_height.to_string(),
+        )
+        .add_attribute(DELEGATION_TARGET_KEY, gateway_identity)
+}
+
 pub fn new_gateway_bonding_event(
     owner: &Addr,
     proxy: &Option<Addr>,
@@ -186,6 +320,183 @@ pub fn new_mixnode_unbonding_event(
     event.add_attribute(AMOUNT_KEY, amount.to_string())
 }
 
+pub fn new_operator_reward_claim_event(
+    owner: &Addr,
+    proxy: &Option<Addr>,
+    amount: &Coin,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    let mut event = Event::new(OPERATOR_REWARD_CLAIM_EVENT_TYPE).add_attribute(OWNER_KEY, owner);
+
+    if let Some(proxy) = proxy {
+        event = event.add_attribute(PROXY_KEY, proxy)
+    }
+
+    event
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+}
+
+pub fn new_delegator_reward_claim_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    amount: &Coin,
+    mix_identity: IdentityKeyRef<'_>,
+) -> Event {
+    let mut event =
+        Event::new(DELEGATOR_REWARD_CLAIM_EVENT_TYPE).add_attribute(DELEGATOR_KEY, delegator);
+
+    if let Some(proxy) = proxy {
+        event = event.add_attribute(PROXY_KEY, proxy)
+    }
+
+    event
+        .add_attribute(DELEGATION_TARGET_KEY, mix_identity)
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+}
+
+pub fn new_delegator_reward_compounding_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    amount: &Coin,
+    mix_identity: IdentityKeyRef<'_>,
+) -> Event {
+    let mut event =
+        Event::new(DELEGATOR_REWARD_COMPOUNDING_EVENT_TYPE).add_attribute(DELEGATOR_KEY, delegator);
+
+    if let Some(proxy) = proxy {
+        event = event.add_attribute(PROXY_KEY, proxy)
+    }
+
+    event
+        .add_attribute(DELEGATION_TARGET_KEY, mix_identity)
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+}
+
+pub fn new_family_creation_event(
+    owner: &Addr,
+    family_head: IdentityKeyRef<'_>,
+    label: &str,
+) -> Event {
+    Event::new(FAMILY_CREATION_EVENT_TYPE)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(FAMILY_HEAD_KEY, family_head)
+        .add_attribute(FAMILY_LABEL_KEY, label)
+}
+
+pub fn new_family_join_event(
+    owner: &Addr,
+    family_head: IdentityKeyRef<'_>,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    Event::new(FAMILY_JOIN_EVENT_TYPE)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(FAMILY_HEAD_KEY, family_head)
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+}
+
+pub fn new_family_leave_event(
+    owner: &Addr,
+    family_head: IdentityKeyRef<'_>,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    Event::new(FAMILY_LEAVE_EVENT_TYPE)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(FAMILY_HEAD_KEY, family_head)
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+}
+
+pub fn new_family_disband_event(family_head: IdentityKeyRef<'_>) -> Event {
+    Event::new(FAMILY_DISBAND_EVENT_TYPE).add_attribute(FAMILY_HEAD_KEY, family_head)
+}
+
+pub fn new_mixnode_details_update_event(
+    owner: &Addr,
+    proxy: &Option<Addr>,
+    identity: IdentityKeyRef<'_>,
+    details: &MixNodeDetailsUpdate,
+) -> Event {
+    let mut event = Event::new(MIXNODE_DETAILS_UPDATE_EVENT_TYPE)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(NODE_IDENTITY_KEY, identity);
+
+    if let Some(proxy) = proxy {
+        event = event.add_attribute(PROXY_KEY, proxy)
+    }
+
+    event
+        .add_attribute(HOST_KEY, &details.host)
+        .add_attribute(MIX_PORT_KEY, details.mix_port.to_string())
+        .add_attribute(VERLOC_PORT_KEY, details.verloc_port.to_string())
+        .add_attribute(HTTP_API_PORT_KEY, details.http_api_port.to_string())
+        .add_attribute(VERSION_KEY, &details.version)
+}
+
+pub fn new_gateway_details_update_event(
+    owner: &Addr,
+    proxy: &Option<Addr>,
+    identity: IdentityKeyRef<'_>,
+    details: &GatewayDetailsUpdate,
+) -> Event {
+    let mut event = Event::new(GATEWAY_DETAILS_UPDATE_EVENT_TYPE)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(NODE_IDENTITY_KEY, identity);
+
+    if let Some(proxy) = proxy {
+        event = event.add_attribute(PROXY_KEY, proxy)
+    }
+
+    event
+        .add_attribute(HOST_KEY, &details.host)
+        .add_attribute(MIX_PORT_KEY, details.mix_port.to_string())
+        .add_attribute(CLIENTS_PORT_KEY, details.clients_port.to_string())
+        .add_attribute(LOCATION_KEY, &details.location)
+        .add_attribute(VERSION_KEY, &details.version)
+}
+
+pub fn new_mixnode_pledge_change_event(
+    owner: &Addr,
+    proxy: &Option<Addr>,
+    identity: IdentityKeyRef<'_>,
+    change: &PledgeChange,
+) -> Event {
+    let mut event = Event::new(MIXNODE_PLEDGE_CHANGE_EVENT_TYPE)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(NODE_IDENTITY_KEY, identity);
+
+    if let Some(proxy) = proxy {
+        event = event.add_attribute(PROXY_KEY, proxy)
+    }
+
+    event
+        .add_attribute(PLEDGE_CHANGE_KEY, change.to_string())
+        .add_attribute(AMOUNT_KEY, change.amount().to_string())
+}
+
+pub fn new_mixnode_pledge_change_applied_event(
+    identity: IdentityKeyRef<'_>,
+    change: &PledgeChange,
+    new_pledge: &Coin,
+) -> Event {
+    Event::new(MIXNODE_PLEDGE_CHANGE_APPLIED_EVENT_TYPE)
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(PLEDGE_CHANGE_KEY, change.to_string())
+        .add_attribute(AMOUNT_KEY, change.amount().to_string())
+        .add_attribute(NEW_PLEDGE_KEY, new_pledge.to_string())
+}
+
+pub fn new_mixnode_pledge_change_cancelled_event(
+    identity: IdentityKeyRef<'_>,
+    change: &PledgeChange,
+    reason: &str,
+) -> Event {
+    Event::new(MIXNODE_PLEDGE_CHANGE_CANCELLED_EVENT_TYPE)
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(PLEDGE_CHANGE_KEY, change.to_string())
+        .add_attribute(AMOUNT_KEY, change.amount().to_string())
+        .add_attribute(CANCELLATION_REASON_KEY, reason)
+}
+
 pub fn new_settings_update_event(
     old_params: &ContractStateParams,
     new_params: &ContractStateParams,
@@ -240,7 +551,114 @@ pub fn new_settings_update_event(
             )
     }
 
+    if old_params.unbonding_period != new_params.unbonding_period {
+        event = event
+            .add_attribute(
+                OLD_UNBONDING_PERIOD_KEY,
+                old_params.unbonding_period.to_string(),
+            )
+            .add_attribute(
+                NEW_UNBONDING_PERIOD_KEY,
+                new_params.unbonding_period.to_string(),
+            )
+    }
+
+    if old_params.slashing != new_params.slashing {
+        event = event
+            .add_attribute(OLD_SLASHING_PARAMS_KEY, old_params.slashing.to_string())
+            .add_attribute(NEW_SLASHING_PARAMS_KEY, new_params.slashing.to_string())
+    }
+
+    if old_params.gateway_rewarding != new_params.gateway_rewarding {
+        event = event
+            .add_attribute(
+                OLD_GATEWAY_REWARDING_PARAMS_KEY,
+                old_params.gateway_rewarding.to_string(),
+            )
+            .add_attribute(
+                NEW_GATEWAY_REWARDING_PARAMS_KEY,
+                new_params.gateway_rewarding.to_string(),
+            )
+    }
+
+    event
+}
+
+pub fn new_pending_withdrawal_event(withdrawal: &PendingWithdrawal) -> Event {
+    let mut event = Event::new(PENDING_WITHDRAWAL_EVENT_TYPE)
+        .add_attribute(WITHDRAWAL_ID_KEY, withdrawal.id.to_string())
+        .add_attribute(OWNER_KEY, &withdrawal.owner);
+
+    if let Some(proxy) = &withdrawal.proxy {
+        event = event.add_attribute(PROXY_KEY, proxy)
+    }
+
     event
+        .add_attribute(WITHDRAWAL_KIND_KEY, withdrawal.kind.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, withdrawal.kind.node_identity())
+        .add_attribute(AMOUNT_KEY, withdrawal.amount.to_string())
+        .add_attribute(RELEASE_TIME_KEY, withdrawal.release_time.to_string())
+}
+
+pub fn new_withdrawal_claim_event(withdrawal: &PendingWithdrawal) -> Event {
+    let mut event = Event::new(WITHDRAWAL_CLAIM_EVENT_TYPE)
+        .add_attribute(WITHDRAWAL_ID_KEY, withdrawal.id.to_string())
+        .add_attribute(OWNER_KEY, &withdrawal.owner);
+
+    if let Some(proxy) = &withdrawal.proxy {
+        event = event.add_attribute(PROXY_KEY, proxy)
+    }
+
+    event
+        .add_attribute(WITHDRAWAL_KIND_KEY, withdrawal.kind.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, withdrawal.kind.node_identity())
+        .add_attribute(AMOUNT_KEY, withdrawal.amount.to_string())
+}
+
+pub fn new_performance_report_event(
+    interval_id: u32,
+    identity: IdentityKeyRef<'_>,
+    uptime: u128,
+    in_active_set: bool,
+    poor_intervals: u32,
+) -> Event {
+    Event::new(PERFORMANCE_REPORT_EVENT_TYPE)
+        .add_attribute(INTERVAL_ID_KEY, interval_id.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(UPTIME_KEY, uptime.to_string())
+        .add_attribute(IN_ACTIVE_SET_KEY, in_active_set.to_string())
+        .add_attribute(POOR_INTERVALS_KEY, poor_intervals.to_string())
+}
+
+pub fn new_mixnode_slashing_event(record: &SlashingRecord, further_stake: bool) -> Event {
+    Event::new(MIXNODE_SLASHING_EVENT_TYPE)
+        .add_attribute(SLASHING_ID_KEY, record.id.to_string())
+        .add_attribute(INTERVAL_ID_KEY, record.interval_id.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, &record.mix_identity)
+        .add_attribute(OWNER_KEY, &record.owner)
+        .add_attribute(SLASHED_PLEDGE_KEY, record.pledge_slashed.to_string())
+        .add_attribute(
+            SLASHED_DELEGATIONS_KEY,
+            record.delegations_slashed.to_string(),
+        )
+        .add_attribute(SLASHING_DESTINATION_KEY, record.destination.to_string())
+        .add_attribute(FURTHER_STAKE_TO_SLASH_KEY, further_stake.to_string())
+}
+
+// emitted for every subsequent page of the stake slashed alongside the node
+pub fn new_mix_delegators_slashing_event(
+    slashing_id: u64,
+    identity: IdentityKeyRef<'_>,
+    pledge_slashed: &Coin,
+    delegations_slashed: &Coin,
+    further_stake: bool,
+) -> Event {
+    Event::new(MIX_DELEGATORS_SLASHING_EVENT_TYPE)
+        .add_attribute(SLASHING_ID_KEY, slashing_id.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(SLASHED_PLEDGE_KEY, pledge_slashed.to_string())
+        .add_attribute(SLASHED_DELEGATIONS_KEY, delegations_slashed.to_string())
+        .add_attribute(FURTHER_STAKE_TO_SLASH_KEY, further_stake.to_string())
 }
 
 pub fn new_not_found_mix_operator_rewarding_event(
@@ -325,18 +743,98 @@ pub fn new_mix_delegators_rewarding_event(
         )
 }
 
+pub fn new_not_found_gateway_rewarding_event(
+    interval_id: u32,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    Event::new(GATEWAY_REWARDING_EVENT_TYPE)
+        .add_attribute(INTERVAL_ID_KEY, interval_id.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(NO_REWARD_REASON_KEY, BOND_NOT_FOUND_VALUE)
+}
+
+pub fn new_too_fresh_bond_gateway_rewarding_event(
+    interval_id: u32,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    Event::new(GATEWAY_REWARDING_EVENT_TYPE)
+        .add_attribute(INTERVAL_ID_KEY, interval_id.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(NO_REWARD_REASON_KEY, BOND_TOO_FRESH_VALUE)
+}
+
+pub fn new_zero_uptime_gateway_rewarding_event(
+    interval_id: u32,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    Event::new(GATEWAY_REWARDING_EVENT_TYPE)
+        .add_attribute(INTERVAL_ID_KEY, interval_id.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(NO_REWARD_REASON_KEY, ZERO_UPTIME_VALUE)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn new_gateway_rewarding_event(
+    interval_id: u32,
+    identity: IdentityKeyRef<'_>,
+    total_reward: Uint128,
+    gateway_pledge: Uint128,
+    gateway_delegation: Uint128,
+    operator_reward: Uint128,
+    delegation_rewards_distributed: Uint128,
+    further_delegations: bool,
+) -> Event {
+    Event::new(GATEWAY_REWARDING_EVENT_TYPE)
+        .add_attribute(INTERVAL_ID_KEY, interval_id.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(TOTAL_PLEDGE_KEY, gateway_pledge)
+        .add_attribute(TOTAL_DELEGATIONS_KEY, gateway_delegation)
+        .add_attribute(TOTAL_GATEWAY_REWARD_KEY, total_reward)
+        .add_attribute(OPERATOR_REWARD_KEY, operator_reward)
+        .add_attribute(
+            DISTRIBUTED_DELEGATION_REWARDS_KEY,
+            delegation_rewards_distributed,
+        )
+        .add_attribute(
+            FURTHER_DELEGATIONS_TO_REWARD_KEY,
+            further_delegations.to_string(),
+        )
+}
+
+pub fn new_gateway_delegators_rewarding_event(
+    interval_id: u32,
+    identity: IdentityKeyRef<'_>,
+    delegation_rewards_distributed: Uint128,
+    further_delegations: bool,
+) -> Event {
+    Event::new(GATEWAY_DELEGATORS_REWARDING_EVENT_TYPE)
+        .add_attribute(INTERVAL_ID_KEY, interval_id.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(
+            DISTRIBUTED_DELEGATION_REWARDS_KEY,
+            delegation_rewards_distributed,
+        )
+        .add_attribute(
+            FURTHER_DELEGATIONS_TO_REWARD_KEY,
+            further_delegations.to_string(),
+        )
+}
+
 // note that when this event is emitted, we'll know the current block height
 pub fn new_change_rewarded_set_event(
     active_set_size: u32,
     rewarded_set_size: u32,
     nodes_in_rewarded_set: u32,
     current_interval_id: u32,
+    seed: &RewardedSetSeed,
 ) -> Event {
     Event::new(CHANGE_REWARDED_SET_EVENT_TYPE)
         .add_attribute(ACTIVE_SET_SIZE_KEY, active_set_size.to_string())
         .add_attribute(REWARDED_SET_SIZE_KEY, rewarded_set_size.to_string())
         .add_attribute(NODES_IN_REWARDED_SET_KEY, nodes_in_rewarded_set.to_string())
         .add_attribute(CURRENT_INTERVAL_ID_KEY, current_interval_id.to_string())
+        .add_attribute(SEED_HEIGHT_KEY, seed.height.to_string())
+        .add_attribute(SEED_BLOCK_HASH_KEY, seed.block_hash.to_base64())
 }
 
 pub fn new_advance_interval_event(interval: Interval) -> Event {
@@ -0,0 +1,91 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{Addr, IdentityKey};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Group of mixnodes that their operators declared to be run by the same entity.
+/// Clients should never construct a route going through more than a single node of any family.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+pub struct Family {
+    // identity of the mixnode that created the family. It also identifies the family itself
+    pub head: IdentityKey,
+
+    // owner of the head mixnode
+    pub owner: Addr,
+
+    pub label: String,
+}
+
+impl Family {
+    pub fn new(head: IdentityKey, owner: Addr, label: String) -> Self {
+        Family { head, owner, label }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+pub struct PagedFamiliesResponse {
+    pub families: Vec<Family>,
+    pub start_next_after: Option<IdentityKey>,
+}
+
+impl PagedFamiliesResponse {
+    pub fn new(families: Vec<Family>, start_next_after: Option<IdentityKey>) -> Self {
+        PagedFamiliesResponse {
+            families,
+            start_next_after,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+pub struct PagedFamilyMembersResponse {
+    pub family_head: IdentityKey,
+    pub members: Vec<IdentityKey>,
+    pub start_next_after: Option<IdentityKey>,
+}
+
+impl PagedFamilyMembersResponse {
+    pub fn new(
+        family_head: IdentityKey,
+        members: Vec<IdentityKey>,
+        start_next_after: Option<IdentityKey>,
+    ) -> Self {
+        PagedFamilyMembersResponse {
+            family_head,
+            members,
+            start_next_after,
+        }
+    }
+}
+
+// Family operations have to be signed with the identity key of the mixnode involved.
+// Every signed message is bound to the specific action, the sender and the current signing nonce
+// of the signing node (see `QueryMsg::GetFamilySigningNonce`), which gets incremented whenever
+// the signature is used, so that no signature can ever be replayed.
+
+/// Message the identity key of a mixnode has to sign in order to create a new family.
+pub fn family_creation_message(owner: &str, label: &str, nonce: u64) -> String {
+    format!("nym-family-create/{}/{}/{}", owner, label, nonce)
+}
+
+/// Message the identity key of a mixnode has to sign in order to join the family of `head`.
+pub fn family_join_message(owner: &str, head: &str, nonce: u64) -> String {
+    format!("nym-family-join/{}/{}/{}", owner, head, nonce)
+}
+
+/// Message the identity key of the family head has to sign in order to let `member` join
+/// its family. Note that the nonce is the current signing nonce of the *member*, so the permit
+/// becomes invalid as soon as it has been used.
+pub fn family_join_permit_message(head: &str, member: &str, member_nonce: u64) -> String {
+    format!(
+        "nym-family-join-permit/{}/{}/{}",
+        head, member, member_nonce
+    )
+}
+
+/// Message the identity key of a mixnode has to sign in order to leave its family.
+pub fn family_leave_message(owner: &str, nonce: u64) -> String {
+    format!("nym-family-leave/{}/{}", owner, nonce)
+}
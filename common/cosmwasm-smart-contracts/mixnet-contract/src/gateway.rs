@@ -2,11 +2,11 @@
 #![allow(clippy::field_reassign_with_default)]
 
 use crate::{IdentityKey, SphinxKey};
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Coin, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::fmt::Display;
+use std::fmt::{self, Display, Formatter};
 
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[derive(Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize, JsonSchema)]
@@ -21,6 +21,27 @@ pub struct Gateway {
     pub version: String,
 }
 
+/// Network details of a bonded gateway that can be changed without having to unbond it.
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize, JsonSchema)]
+pub struct GatewayDetailsUpdate {
+    pub host: String,
+    pub mix_port: u16,
+    pub clients_port: u16,
+    pub location: String,
+    pub version: String,
+}
+
+impl Gateway {
+    pub fn apply_details_update(&mut self, update: GatewayDetailsUpdate) {
+        self.host = update.host;
+        self.mix_port = update.mix_port;
+        self.clients_port = update.clients_port;
+        self.location = update.location;
+        self.version = update.version;
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
 pub struct GatewayBond {
     pub pledge_amount: Coin,
@@ -110,6 +131,149 @@ impl Display for GatewayBond {
     }
 }
 
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub struct GatewayRewardingParams {
+    // percentage of the interval reward pool that is distributed between the gateways.
+    // Zero disables gateway rewarding altogether.
+    pub reward_pool_share_percent: u8,
+
+    // number of gateways the gateway share of the interval reward pool is divided between,
+    // i.e. a gateway with perfect uptime receives `1 / rewarded_set_size` of it
+    pub rewarded_set_size: u32,
+
+    // percentage of the gateway reward that goes to the operator before the remainder is split
+    // between the pledge and the delegations proportionally to their size
+    pub operator_share_percent: u8,
+}
+
+impl GatewayRewardingParams {
+    pub fn is_enabled(&self) -> bool {
+        self.reward_pool_share_percent > 0 && self.rewarded_set_size > 0
+    }
+
+    /// Determines the part of the interval reward pool that is reserved for the gateways
+    /// and thus must not be distributed between the mixnodes.
+    pub fn gateway_reward_pool(&self, period_reward_pool: u128) -> u128 {
+        if !self.is_enabled() {
+            return 0;
+        }
+        period_reward_pool * self.reward_pool_share_percent.min(100) as u128 / 100
+    }
+
+    /// Determines the total reward of a gateway for the interval given its reward parameters.
+    pub fn gateway_reward(&self, params: &GatewayRewardParams) -> Uint128 {
+        if !self.is_enabled() {
+            return Uint128::zero();
+        }
+
+        let uptime = params.uptime().min(100);
+        Uint128::new(
+            params.period_reward_pool() * self.reward_pool_share_percent as u128 * uptime
+                / (100 * 100 * self.rewarded_set_size as u128),
+        )
+    }
+
+    /// Splits the gateway reward into the part going to the operator and the part
+    /// distributed between the delegators.
+    pub fn split_reward(
+        &self,
+        reward: Uint128,
+        pledge: Uint128,
+        total_delegation: Uint128,
+    ) -> (Uint128, Uint128) {
+        let operator_cut = reward.multiply_ratio(self.operator_share_percent as u128, 100u128);
+        let remaining = reward - operator_cut;
+        let total_stake = pledge + total_delegation;
+        if total_stake.is_zero() {
+            return (reward, Uint128::zero());
+        }
+
+        let pledge_share = remaining.multiply_ratio(pledge, total_stake);
+        (operator_cut + pledge_share, remaining - pledge_share)
+    }
+}
+
+impl Display for GatewayRewardingParams {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if !self.is_enabled() {
+            return write!(f, "disabled");
+        }
+        write!(
+            f,
+            "{}% of the interval pool split between {} gateways, {}% operator share",
+            self.reward_pool_share_percent, self.rewarded_set_size, self.operator_share_percent
+        )
+    }
+}
+
+#[derive(Debug, Clone, JsonSchema, PartialEq, Serialize, Deserialize, Copy)]
+pub struct GatewayRewardParams {
+    period_reward_pool: Uint128,
+    uptime: Uint128,
+    reward_blockstamp: u64,
+}
+
+impl GatewayRewardParams {
+    pub fn new(period_reward_pool: u128, uptime: u128, reward_blockstamp: u64) -> Self {
+        GatewayRewardParams {
+            period_reward_pool: Uint128::new(period_reward_pool),
+            uptime: Uint128::new(uptime),
+            reward_blockstamp,
+        }
+    }
+
+    pub fn set_reward_blockstamp(&mut self, blockstamp: u64) {
+        self.reward_blockstamp = blockstamp;
+    }
+
+    pub fn period_reward_pool(&self) -> u128 {
+        self.period_reward_pool.u128()
+    }
+
+    pub fn uptime(&self) -> u128 {
+        self.uptime.u128()
+    }
+
+    pub fn reward_blockstamp(&self) -> u64 {
+        self.reward_blockstamp
+    }
+}
+
+/// Snapshot of the delegator part of a gateway reward, taken when the gateway operator is rewarded,
+/// so that every delegator would get the same share regardless of the page they ended up on.
+#[derive(Debug, Clone, JsonSchema, PartialEq, Serialize, Deserialize, Copy)]
+pub struct GatewayDelegatorRewardParams {
+    delegators_reward: Uint128,
+    total_delegation: Uint128,
+    reward_blockstamp: u64,
+}
+
+impl GatewayDelegatorRewardParams {
+    pub fn new(
+        delegators_reward: Uint128,
+        total_delegation: Uint128,
+        reward_blockstamp: u64,
+    ) -> Self {
+        GatewayDelegatorRewardParams {
+            delegators_reward,
+            total_delegation,
+            reward_blockstamp,
+        }
+    }
+
+    pub fn determine_delegation_reward(&self, delegation_amount: Uint128) -> Uint128 {
+        if self.total_delegation.is_zero() {
+            return Uint128::zero();
+        }
+        self.delegators_reward
+            .multiply_ratio(delegation_amount, self.total_delegation)
+    }
+
+    pub fn reward_blockstamp(&self) -> u64 {
+        self.reward_blockstamp
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
 pub struct PagedGatewayResponse {
     pub nodes: Vec<GatewayBond>,
@@ -153,6 +317,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn gateway_reward_is_proportional_to_uptime() {
+        let rewarding = GatewayRewardingParams {
+            reward_pool_share_percent: 10,
+            rewarded_set_size: 50,
+            operator_share_percent: 0,
+        };
+
+        // 10% of 1_000_000 split between 50 gateways
+        let params = GatewayRewardParams::new(1_000_000, 100, 0);
+        assert_eq!(Uint128::new(2000), rewarding.gateway_reward(&params));
+
+        let params = GatewayRewardParams::new(1_000_000, 50, 0);
+        assert_eq!(Uint128::new(1000), rewarding.gateway_reward(&params));
+
+        let params = GatewayRewardParams::new(1_000_000, 0, 0);
+        assert_eq!(Uint128::zero(), rewarding.gateway_reward(&params));
+
+        let disabled = GatewayRewardingParams::default();
+        let params = GatewayRewardParams::new(1_000_000, 100, 0);
+        assert_eq!(Uint128::zero(), disabled.gateway_reward(&params));
+    }
+
+    #[test]
+    fn gateway_reward_pool_covers_full_rewarded_set() {
+        let rewarding = GatewayRewardingParams {
+            reward_pool_share_percent: 10,
+            rewarded_set_size: 50,
+            operator_share_percent: 0,
+        };
+        assert_eq!(100_000, rewarding.gateway_reward_pool(1_000_000));
+
+        // the rewarded set with perfect uptime receives the entire reserved pool
+        let params = GatewayRewardParams::new(1_000_000, 100, 0);
+        assert_eq!(
+            rewarding.gateway_reward_pool(1_000_000),
+            rewarding.gateway_reward(&params).u128() * 50
+        );
+
+        let disabled = GatewayRewardingParams::default();
+        assert_eq!(0, disabled.gateway_reward_pool(1_000_000));
+    }
+
+    #[test]
+    fn gateway_reward_split() {
+        let rewarding = GatewayRewardingParams {
+            reward_pool_share_percent: 10,
+            rewarded_set_size: 50,
+            operator_share_percent: 20,
+        };
+
+        // operator takes 200 off the top, the remaining 800 is split 1:3
+        let (operator, delegators) =
+            rewarding.split_reward(Uint128::new(1000), Uint128::new(100), Uint128::new(300));
+        assert_eq!(Uint128::new(400), operator);
+        assert_eq!(Uint128::new(600), delegators);
+
+        // without any delegations, everything goes to the operator
+        let (operator, delegators) =
+            rewarding.split_reward(Uint128::new(1000), Uint128::new(100), Uint128::zero());
+        assert_eq!(Uint128::new(1000), operator);
+        assert_eq!(Uint128::zero(), delegators);
+    }
+
     #[test]
     fn gateway_bond_partial_ord() {
         let _150foos = Coin::new(150, "foo");
@@ -4,23 +4,45 @@
 mod delegation;
 pub mod error;
 pub mod events;
+mod families;
 mod gateway;
 mod interval;
 pub mod mixnode;
 mod msg;
+mod rewarded_set;
+mod slashing;
 mod types;
+mod withdrawal;
 
 pub const MIXNODE_DELEGATORS_PAGE_LIMIT: usize = 250;
+pub const GATEWAY_DELEGATORS_PAGE_LIMIT: usize = 250;
 
 pub use cosmwasm_std::{Addr, Coin};
 pub use delegation::{
     Delegation, PagedAllDelegationsResponse, PagedDelegatorDelegationsResponse,
     PagedMixDelegationsResponse,
 };
-pub use gateway::{Gateway, GatewayBond, GatewayOwnershipResponse, PagedGatewayResponse};
+pub use families::{
+    family_creation_message, family_join_message, family_join_permit_message, family_leave_message,
+    Family, PagedFamiliesResponse, PagedFamilyMembersResponse,
+};
+pub use gateway::{
+    Gateway, GatewayBond, GatewayDelegatorRewardParams, GatewayDetailsUpdate,
+    GatewayOwnershipResponse, GatewayRewardParams, GatewayRewardingParams, PagedGatewayResponse,
+};
 pub use interval::Interval;
 pub use mixnode::{
-    Layer, MixNode, MixNodeBond, MixOwnershipResponse, PagedMixnodeResponse, RewardedSetNodeStatus,
+    Layer, MixNode, MixNodeBond, MixNodeDetailsUpdate, MixOwnershipResponse, PagedMixnodeResponse,
+    PendingPledgeChange, PledgeChange, RewardedSetNodeStatus,
 };
 pub use msg::*;
+pub use rewarded_set::{
+    assign_layers, determine_rewarded_set, verify_rewarded_set, LayerAssignment, RewardedSetSeed,
+    REWARDED_SET_SEED_LENGTH,
+};
+pub use slashing::{
+    MixnodePerformanceStreak, PagedPendingDelegationSlashingsResponse,
+    PagedSlashingHistoryResponse, SlashingDestination, SlashingParams, SlashingRecord,
+};
 pub use types::*;
+pub use withdrawal::{PagedPendingWithdrawalsResponse, PendingWithdrawal, PendingWithdrawalKind};
@@ -45,6 +45,27 @@ pub struct MixNode {
     pub profit_margin_percent: u8,
 }
 
+/// Network details of a bonded mixnode that can be changed without having to unbond it.
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[derive(Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize, JsonSchema)]
+pub struct MixNodeDetailsUpdate {
+    pub host: String,
+    pub mix_port: u16,
+    pub verloc_port: u16,
+    pub http_api_port: u16,
+    pub version: String,
+}
+
+impl MixNode {
+    pub fn apply_details_update(&mut self, update: MixNodeDetailsUpdate) {
+        self.host = update.host;
+        self.mix_port = update.mix_port;
+        self.verloc_port = update.verloc_port;
+        self.http_api_port = update.http_api_port;
+        self.version = update.version;
+    }
+}
+
 #[derive(
     Copy,
     Clone,
@@ -296,6 +317,9 @@ pub struct MixNodeBond {
     pub block_height: u64,
     pub mix_node: MixNode,
     pub proxy: Option<Addr>,
+    // head of the family this node belongs to, if any
+    #[serde(default)]
+    pub family: Option<IdentityKey>,
 }
 
 impl MixNodeBond {
@@ -315,6 +339,7 @@ impl MixNodeBond {
             block_height,
             mix_node,
             proxy,
+            family: None,
         }
     }
 
@@ -547,6 +572,56 @@ pub struct MixOwnershipResponse {
     pub mixnode: Option<MixNodeBond>,
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PledgeChange {
+    Increase(Coin),
+    Decrease(Coin),
+}
+
+impl PledgeChange {
+    pub fn amount(&self) -> &Coin {
+        match self {
+            PledgeChange::Increase(amount) | PledgeChange::Decrease(amount) => amount,
+        }
+    }
+
+    /// Returns the pledge the node is going to have once this change is applied.
+    pub fn apply_to(&self, pledge: &Coin) -> Coin {
+        let amount = match self {
+            PledgeChange::Increase(change) => pledge.amount + change.amount,
+            PledgeChange::Decrease(change) => pledge.amount.saturating_sub(change.amount),
+        };
+        coin(amount.u128(), &pledge.denom)
+    }
+}
+
+impl Display for PledgeChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PledgeChange::Increase(_) => write!(f, "increase"),
+            PledgeChange::Decrease(_) => write!(f, "decrease"),
+        }
+    }
+}
+
+/// Change of the mixnode pledge requested by its operator. It only gets applied once the current
+/// interval is over, so that the node is rewarded based on the same pledge for the entire interval.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+pub struct PendingPledgeChange {
+    pub mix_identity: IdentityKey,
+    pub change: PledgeChange,
+}
+
+impl PendingPledgeChange {
+    pub fn new(mix_identity: IdentityKey, change: PledgeChange) -> Self {
+        PendingPledgeChange {
+            mix_identity,
+            change,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -578,6 +653,7 @@ mod tests {
             block_height: 100,
             mix_node: mixnode_fixture(),
             proxy: None,
+            family: None,
         };
 
         let mix2 = MixNodeBond {
@@ -588,6 +664,7 @@ mod tests {
             block_height: 120,
             mix_node: mixnode_fixture(),
             proxy: None,
+            family: None,
         };
 
         let mix3 = MixNodeBond {
@@ -598,6 +675,7 @@ mod tests {
             block_height: 120,
             mix_node: mixnode_fixture(),
             proxy: None,
+            family: None,
         };
 
         let mix4 = MixNodeBond {
@@ -608,6 +686,7 @@ mod tests {
             block_height: 120,
             mix_node: mixnode_fixture(),
             proxy: None,
+            family: None,
         };
 
         let mix5 = MixNodeBond {
@@ -618,6 +697,7 @@ mod tests {
             block_height: 120,
             mix_node: mixnode_fixture(),
             proxy: None,
+            family: None,
         };
 
         // summary:
@@ -3,7 +3,11 @@
 
 use crate::mixnode::NodeRewardParams;
 use crate::ContractStateParams;
-use crate::{Gateway, IdentityKey, MixNode};
+use crate::{
+    Gateway, GatewayDetailsUpdate, GatewayRewardParams, IdentityKey, LayerAssignment, MixNode,
+    MixNodeDetailsUpdate, RewardedSetSeed,
+};
+use cosmwasm_std::Coin;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -27,11 +31,36 @@ pub enum ExecuteMsg {
         profit_margin_percent: u8,
         owner: String,
     },
+    UpdateMixnodeDetails {
+        details: MixNodeDetailsUpdate,
+    },
+    UpdateMixnodeDetailsOnBehalf {
+        details: MixNodeDetailsUpdate,
+        owner: String,
+    },
+    IncreaseMixnodePledge {},
+    IncreaseMixnodePledgeOnBehalf {
+        owner: String,
+    },
+    DecreaseMixnodePledge {
+        amount: Coin,
+    },
+    DecreaseMixnodePledgeOnBehalf {
+        amount: Coin,
+        owner: String,
+    },
     BondGateway {
         gateway: Gateway,
         owner_signature: String,
     },
     UnbondGateway {},
+    UpdateGatewayDetails {
+        details: GatewayDetailsUpdate,
+    },
+    UpdateGatewayDetailsOnBehalf {
+        details: GatewayDetailsUpdate,
+        owner: String,
+    },
     UpdateContractStateParams(ContractStateParams),
 
     DelegateToMixnode {
@@ -55,6 +84,19 @@ pub enum ExecuteMsg {
         // id of the current rewarding interval
         interval_id: u32,
     },
+    ReportMixnodePerformance {
+        identity: IdentityKey,
+        // the same parameters that were used for rewarding the node during this interval
+        params: NodeRewardParams,
+
+        // id of the current rewarding interval
+        interval_id: u32,
+    },
+    SlashNextMixDelegators {
+        mix_identity: IdentityKey,
+        // id of the current rewarding interval
+        interval_id: u32,
+    },
     DelegateToMixnodeOnBehalf {
         mix_identity: IdentityKey,
         delegate: String,
@@ -63,6 +105,15 @@ pub enum ExecuteMsg {
         mix_identity: IdentityKey,
         delegate: String,
     },
+    RedelegateToMixnode {
+        from: IdentityKey,
+        to: IdentityKey,
+    },
+    RedelegateToMixnodeOnBehalf {
+        from: IdentityKey,
+        to: IdentityKey,
+        delegate: String,
+    },
     BondMixnodeOnBehalf {
         mix_node: MixNode,
         owner: String,
@@ -82,8 +133,102 @@ pub enum ExecuteMsg {
     WriteRewardedSet {
         rewarded_set: Vec<IdentityKey>,
         expected_active_set_size: u32,
+        seed: RewardedSetSeed,
+        layer_assignment: Vec<LayerAssignment>,
     },
     AdvanceCurrentInterval {},
+    ClaimPendingWithdrawals {},
+    ClaimPendingWithdrawalsOnBehalf {
+        owner: String,
+    },
+    DelegateToGateway {
+        gateway_identity: IdentityKey,
+    },
+    UndelegateFromGateway {
+        gateway_identity: IdentityKey,
+    },
+    DelegateToGatewayOnBehalf {
+        gateway_identity: IdentityKey,
+        delegate: String,
+    },
+    UndelegateFromGatewayOnBehalf {
+        gateway_identity: IdentityKey,
+        delegate: String,
+    },
+    RewardGateway {
+        identity: IdentityKey,
+        params: GatewayRewardParams,
+
+        // id of the current rewarding interval
+        interval_id: u32,
+    },
+    RewardNextGatewayDelegators {
+        gateway_identity: IdentityKey,
+        // id of the current rewarding interval
+        interval_id: u32,
+    },
+    // creates a new family with the sender's mixnode as its head
+    CreateFamily {
+        // signature of `family_creation_message` made with the identity key of the sender's mixnode
+        owner_signature: String,
+        label: String,
+    },
+    JoinFamily {
+        // signature of `family_join_message` made with the identity key of the sender's mixnode
+        owner_signature: String,
+        family_head: IdentityKey,
+        // signature of `family_join_permit_message` made with the identity key of the family head
+        join_permit: String,
+    },
+    // if the sender's mixnode is the head of its family, the entire family is disbanded
+    LeaveFamily {
+        // signature of `family_leave_message` made with the identity key of the sender's mixnode
+        owner_signature: String,
+    },
+    // withdraws all operator rewards accrued by the sender's mixnode
+    ClaimOperatorReward {},
+    ClaimOperatorRewardOnBehalf {
+        owner: String,
+    },
+    // withdraws all rewards accrued by the sender's delegation towards the specified mixnode
+    ClaimDelegatorReward {
+        mix_identity: IdentityKey,
+    },
+    ClaimDelegatorRewardOnBehalf {
+        mix_identity: IdentityKey,
+        delegate: String,
+    },
+    // adds all rewards accrued by the sender's delegation towards the specified mixnode
+    // to the delegation itself
+    CompoundDelegatorReward {
+        mix_identity: IdentityKey,
+    },
+    CompoundDelegatorRewardOnBehalf {
+        mix_identity: IdentityKey,
+        delegate: String,
+    },
+    // withdraws all operator rewards accrued by the sender's gateway
+    ClaimGatewayOperatorReward {},
+    ClaimGatewayOperatorRewardOnBehalf {
+        owner: String,
+    },
+    // withdraws all rewards accrued by the sender's delegation towards the specified gateway
+    ClaimGatewayDelegatorReward {
+        gateway_identity: IdentityKey,
+    },
+    ClaimGatewayDelegatorRewardOnBehalf {
+        gateway_identity: IdentityKey,
+        delegate: String,
+    },
+    // adds all rewards accrued by the sender's delegation towards the specified gateway
+    // to the delegation itself
+    CompoundGatewayDelegatorReward {
+        gateway_identity: IdentityKey,
+    },
+    CompoundGatewayDelegatorRewardOnBehalf {
+        gateway_identity: IdentityKey,
+        delegate: String,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -152,8 +297,104 @@ pub enum QueryMsg {
     },
     GetRewardedSetUpdateDetails {},
     GetCurrentRewardedSetHeight {},
+    GetRewardedSetSeed {
+        height: Option<u64>,
+    },
+    GetLayerAssignment {
+        height: Option<u64>,
+        start_after: Option<IdentityKey>,
+        limit: Option<u32>,
+    },
     GetCurrentInterval {},
     GetRewardedSetRefreshBlocks {},
+    GetPendingPledgeChange {
+        mix_identity: IdentityKey,
+    },
+    // gets all [paged] pending withdrawals belonging to particular owner
+    GetPendingWithdrawals {
+        // since `owner` is user-provided input, we can't use `Addr` as we
+        // can't guarantee it's validated.
+        owner: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    // gets all [paged] pending withdrawals in the entire network
+    GetAllPendingWithdrawals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    GetMixnodePerformanceStreak {
+        mix_identity: IdentityKey,
+    },
+    // gets all [paged] slashing events of particular mixnode
+    GetMixnodeSlashingHistory {
+        mix_identity: IdentityKey,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    // gets all [paged] slashing events in the entire network
+    GetAllSlashingHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    // gets all [paged] mixnodes whose delegations are still being slashed
+    GetPendingDelegationSlashings {
+        start_after: Option<IdentityKey>,
+        limit: Option<u32>,
+    },
+    // gets all [paged] delegations associated with particular gateway
+    GetGatewayDelegations {
+        gateway_identity: IdentityKey,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // gets all [paged] gateway delegations associated with particular delegator
+    GetDelegatorGatewayDelegations {
+        delegator: String,
+        start_after: Option<IdentityKey>,
+        limit: Option<u32>,
+    },
+    // gets delegation associated with particular gateway, delegator pair
+    GetGatewayDelegationDetails {
+        gateway_identity: IdentityKey,
+        delegator: String,
+    },
+    GetGatewayRewardingStatus {
+        gateway_identity: IdentityKey,
+        interval_id: u32,
+    },
+    // gets all [paged] mixnode families
+    GetFamiliesPaged {
+        start_after: Option<IdentityKey>,
+        limit: Option<u32>,
+    },
+    // gets all [paged] members of particular family
+    GetFamilyMembersPaged {
+        family_head: IdentityKey,
+        start_after: Option<IdentityKey>,
+        limit: Option<u32>,
+    },
+    // gets the family particular mixnode belongs to (if any)
+    GetFamilyByMember {
+        member: IdentityKey,
+    },
+    // gets the nonce that has to be included in the next family-related message signed by the node
+    GetFamilySigningNonce {
+        identity: IdentityKey,
+    },
+    // gets the amount of operator rewards accrued by particular mixnode (or gateway)
+    // that are yet to be claimed
+    GetPendingOperatorReward {
+        mix_identity: IdentityKey,
+    },
+    // gets the amount of rewards accrued by particular delegation that are yet to be claimed.
+    // It can also be used for gateway delegations, with the gateway identity as `mix_identity`
+    GetPendingDelegatorReward {
+        mix_identity: IdentityKey,
+        delegator: String,
+        // address of the vesting contract if the delegation was made with vesting tokens
+        proxy: Option<String>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
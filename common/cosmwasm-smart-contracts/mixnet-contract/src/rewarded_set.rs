@@ -0,0 +1,354 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{IdentityKey, Layer, MixNodeBond, RewardedSetNodeStatus};
+use cosmwasm_std::Binary;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+pub const REWARDED_SET_SEED_LENGTH: usize = 32;
+
+/// Public source of randomness used for selecting a particular rewarded set.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+pub struct RewardedSetSeed {
+    // height of the block whose hash is used as the seed. It must be the first block
+    // at which the rewarded set was allowed to get updated. The nodes are selected
+    // from the bonds as they were at this height.
+    pub height: u64,
+
+    pub block_hash: Binary,
+}
+
+impl RewardedSetSeed {
+    pub fn new(height: u64, block_hash: [u8; REWARDED_SET_SEED_LENGTH]) -> Self {
+        RewardedSetSeed {
+            height,
+            block_hash: Binary::from(block_hash),
+        }
+    }
+
+    pub fn seed(&self) -> Option<[u8; REWARDED_SET_SEED_LENGTH]> {
+        self.block_hash.as_slice().try_into().ok()
+    }
+}
+
+/// Mix layer a node from the rewarded set is meant to be used on for as long as that set is current.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+pub struct LayerAssignment {
+    pub identity: IdentityKey,
+    pub layer: Layer,
+}
+
+impl LayerAssignment {
+    pub fn new(identity: IdentityKey, layer: Layer) -> Self {
+        LayerAssignment { identity, layer }
+    }
+}
+
+/// Deterministically chooses up to `nodes_to_select` mixnodes, weighted by their total stake,
+/// so that anybody in possession of the same seed and bonds could reproduce the result.
+/// The nodes are returned in the order they were chosen in, i.e. the first k of them should
+/// be put into the active set.
+pub fn determine_rewarded_set(
+    seed: [u8; REWARDED_SET_SEED_LENGTH],
+    mixnodes: &[MixNodeBond],
+    nodes_to_select: u32,
+) -> Vec<IdentityKey> {
+    // if for some reason node is invalid, treat it as 0 stake/weight, i.e. it can't get chosen
+    let mut candidates = mixnodes
+        .iter()
+        .map(|bond| (bond.identity(), bond.total_bond().unwrap_or_default()))
+        .filter(|(_, weight)| *weight > 0)
+        .collect::<Vec<_>>();
+
+    // the order in which the bonds were obtained must not influence the outcome
+    candidates.sort_by(|(identity_a, _), (identity_b, _)| identity_a.cmp(identity_b));
+
+    // note that all integer arithmetic is used as opposed to floats (like in
+    // `choose_multiple_weighted`) as its result wouldn't be guaranteed to be identical on all platforms.
+    // the total can't overflow as it's bounded by the total supply of all tokens
+    let mut total_weight: u128 = candidates.iter().map(|(_, weight)| weight).sum();
+    let mut rng = ChaCha20Rng::from_seed(seed);
+    let mut rewarded_set = Vec::with_capacity(candidates.len().min(nodes_to_select as usize));
+
+    while rewarded_set.len() < nodes_to_select as usize && !candidates.is_empty() {
+        let mut target = rng.gen_range(0..total_weight);
+        let chosen = candidates
+            .iter()
+            .position(|(_, weight)| {
+                if target < *weight {
+                    true
+                } else {
+                    target -= weight;
+                    false
+                }
+            })
+            // the target is always smaller than the sum of remaining weights
+            .expect("weighted selection went out of bounds");
+
+        let (identity, weight) = candidates.remove(chosen);
+        total_weight -= weight;
+        rewarded_set.push(identity.clone());
+    }
+
+    rewarded_set
+}
+
+/// Spreads the rewarded set across the three mix layers, so that each of them ends up with
+/// (nearly) the same number of nodes and, as far as that allows, the same total stake.
+/// The active and the standby nodes are balanced independently of each other, as only
+/// the active ones are going to be used for routing packets.
+pub fn assign_layers(
+    rewarded_set: &[IdentityKey],
+    active_set_size: u32,
+    mixnodes: &[MixNodeBond],
+) -> Vec<LayerAssignment> {
+    let stakes = mixnodes
+        .iter()
+        .map(|bond| (bond.identity(), bond.total_bond().unwrap_or_default()))
+        .collect::<HashMap<_, _>>();
+
+    let active_set_size = (active_set_size as usize).min(rewarded_set.len());
+    let (active, standby) = rewarded_set.split_at(active_set_size);
+
+    let mut assignment = balance_layers(active, &stakes);
+    assignment.append(&mut balance_layers(standby, &stakes));
+    assignment
+}
+
+fn balance_layers(nodes: &[IdentityKey], stakes: &HashMap<&String, u128>) -> Vec<LayerAssignment> {
+    const LAYERS: [Layer; 3] = [Layer::One, Layer::Two, Layer::Three];
+
+    let mut nodes = nodes
+        .iter()
+        .map(|identity| (identity, stakes.get(identity).copied().unwrap_or_default()))
+        .collect::<Vec<_>>();
+
+    // heaviest nodes go first, so that the lighter ones could even out the differences
+    nodes.sort_by(|(identity_a, stake_a), (identity_b, stake_b)| {
+        stake_b
+            .cmp(stake_a)
+            .then_with(|| identity_a.cmp(identity_b))
+    });
+
+    // no layer is allowed to have more than its fair share of nodes
+    let max_per_layer = (nodes.len() + LAYERS.len() - 1) / LAYERS.len();
+    let mut layer_counts = [0usize; 3];
+    let mut layer_stakes = [0u128; 3];
+
+    nodes
+        .into_iter()
+        .map(|(identity, stake)| {
+            // there's always at least one layer that isn't full yet. In case of a tie, prefer the lower layer
+            let chosen = (0..LAYERS.len())
+                .filter(|&i| layer_counts[i] < max_per_layer)
+                .min_by_key(|&i| (layer_stakes[i], layer_counts[i], i))
+                .expect("all layers are full");
+
+            layer_counts[chosen] += 1;
+            layer_stakes[chosen] += stake;
+            LayerAssignment::new(identity.clone(), LAYERS[chosen])
+        })
+        .collect()
+}
+
+/// Checks whether the provided rewarded set, as stored in the contract, is exactly the one
+/// that would have been chosen given the seed and the bonds as they were at `seed.height`.
+pub fn verify_rewarded_set(
+    seed: &RewardedSetSeed,
+    mixnodes: &[MixNodeBond],
+    rewarded_set_size: u32,
+    active_set_size: u32,
+    rewarded_set: &[(IdentityKey, RewardedSetNodeStatus)],
+) -> bool {
+    let seed = match seed.seed() {
+        Some(seed) => seed,
+        None => return false,
+    };
+
+    let expected = determine_rewarded_set(seed, mixnodes, rewarded_set_size)
+        .into_iter()
+        .enumerate()
+        .map(|(i, identity)| {
+            let status = if i < active_set_size as usize {
+                RewardedSetNodeStatus::Active
+            } else {
+                RewardedSetNodeStatus::Standby
+            };
+            (identity, status)
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let actual = rewarded_set.iter().cloned().collect::<BTreeMap<_, _>>();
+
+    // make sure there were no duplicates in the provided set
+    actual.len() == rewarded_set.len() && expected == actual
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Layer, MixNode};
+    use cosmwasm_std::{coin, Addr};
+
+    fn bond(identity: &str, stake: u128) -> MixNodeBond {
+        MixNodeBond::new(
+            coin(stake, "unym"),
+            Addr::unchecked(format!("owner-{}", identity)),
+            Layer::One,
+            12345,
+            MixNode {
+                host: "1.1.1.1".to_string(),
+                mix_port: 1789,
+                verloc_port: 1790,
+                http_api_port: 8000,
+                sphinx_key: "sphinx".to_string(),
+                identity_key: identity.to_string(),
+                version: "0.12.0".to_string(),
+                profit_margin_percent: 10,
+            },
+            None,
+        )
+    }
+
+    fn nodes() -> Vec<MixNodeBond> {
+        (0..50)
+            .map(|i| bond(&format!("identity{:02}", i), 100 + i * 10))
+            .collect()
+    }
+
+    #[test]
+    fn selection_is_reproducible_and_independent_of_bond_ordering() {
+        let seed = [42u8; REWARDED_SET_SEED_LENGTH];
+        let mut mixnodes = nodes();
+
+        let first = determine_rewarded_set(seed, &mixnodes, 20);
+        mixnodes.reverse();
+        let second = determine_rewarded_set(seed, &mixnodes, 20);
+        assert_eq!(20, first.len());
+        assert_eq!(first, second);
+
+        let other = determine_rewarded_set([43u8; REWARDED_SET_SEED_LENGTH], &mixnodes, 20);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn selection_never_picks_nodes_twice_or_without_stake() {
+        let seed = [1u8; REWARDED_SET_SEED_LENGTH];
+        let mut mixnodes = nodes();
+        mixnodes.push(bond("no-stake", 0));
+
+        let rewarded_set = determine_rewarded_set(seed, &mixnodes, 100);
+        assert_eq!(50, rewarded_set.len());
+        assert!(!rewarded_set.contains(&"no-stake".to_string()));
+
+        let mut deduped = rewarded_set.clone();
+        deduped.sort();
+        deduped.dedup();
+        assert_eq!(rewarded_set.len(), deduped.len());
+    }
+
+    #[test]
+    fn verifying_rewarded_set() {
+        let block_hash = [7u8; REWARDED_SET_SEED_LENGTH];
+        let seed = RewardedSetSeed::new(1234, block_hash);
+        let mixnodes = nodes();
+
+        let mut stored = determine_rewarded_set(block_hash, &mixnodes, 20)
+            .into_iter()
+            .enumerate()
+            .map(|(i, identity)| {
+                if i < 10 {
+                    (identity, RewardedSetNodeStatus::Active)
+                } else {
+                    (identity, RewardedSetNodeStatus::Standby)
+                }
+            })
+            .collect::<Vec<_>>();
+        // the contract returns the nodes ordered by their identities
+        stored.sort_by(|a, b| a.0.cmp(&b.0));
+        assert!(verify_rewarded_set(&seed, &mixnodes, 20, 10, &stored));
+
+        // different active set
+        assert!(!verify_rewarded_set(&seed, &mixnodes, 20, 11, &stored));
+
+        // different seed
+        let other_seed = RewardedSetSeed::new(1234, [8u8; REWARDED_SET_SEED_LENGTH]);
+        assert!(!verify_rewarded_set(
+            &other_seed,
+            &mixnodes,
+            20,
+            10,
+            &stored
+        ));
+
+        // seed of invalid length
+        let invalid_seed = RewardedSetSeed {
+            height: 1234,
+            block_hash: Binary::from(vec![7u8; 16]),
+        };
+        assert!(!verify_rewarded_set(
+            &invalid_seed,
+            &mixnodes,
+            20,
+            10,
+            &stored
+        ));
+
+        // node swapped for another one
+        stored[0].0 = "identity99".to_string();
+        assert!(!verify_rewarded_set(&seed, &mixnodes, 20, 10, &stored));
+    }
+
+    #[test]
+    fn assigning_layers() {
+        let mixnodes = nodes();
+        let rewarded_set = determine_rewarded_set([3u8; REWARDED_SET_SEED_LENGTH], &mixnodes, 40);
+        let assignment = assign_layers(&rewarded_set, 31, &mixnodes);
+
+        // every node gets exactly one layer
+        assert_eq!(rewarded_set.len(), assignment.len());
+        for identity in &rewarded_set {
+            assert_eq!(
+                1,
+                assignment
+                    .iter()
+                    .filter(|assigned| &assigned.identity == identity)
+                    .count()
+            );
+        }
+        assert!(assignment
+            .iter()
+            .all(|assigned| assigned.layer != Layer::Gateway));
+
+        let stakes = mixnodes
+            .iter()
+            .map(|bond| (bond.identity().clone(), bond.total_bond().unwrap()))
+            .collect::<HashMap<_, _>>();
+
+        // the node counts within the active and standby sets differ by at most one between layers
+        // and the stake differences are bounded by the stake of a single node
+        for part in [&assignment[..31], &assignment[31..]] {
+            let mut counts = HashMap::new();
+            let mut layer_stakes = HashMap::new();
+            for assigned in part {
+                *counts.entry(assigned.layer).or_insert(0) += 1;
+                *layer_stakes.entry(assigned.layer).or_insert(0) += stakes[&assigned.identity];
+            }
+            let max_count = counts.values().max().unwrap();
+            let min_count = counts.values().min().unwrap();
+            assert!(max_count - min_count <= 1);
+
+            let max_stake = layer_stakes.values().max().unwrap();
+            let min_stake = layer_stakes.values().min().unwrap();
+            let max_node_stake = stakes.values().max().unwrap();
+            assert!(max_stake - min_stake <= *max_node_stake);
+        }
+
+        // and the assignment is deterministic
+        assert_eq!(assignment, assign_layers(&rewarded_set, 31, &mixnodes));
+    }
+}
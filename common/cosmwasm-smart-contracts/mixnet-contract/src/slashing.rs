@@ -0,0 +1,123 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{Addr, IdentityKey};
+use cosmwasm_std::Coin;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SlashingDestination {
+    // the slashed tokens are removed from the supply
+    Burn,
+    // the slashed tokens are put back into the reward pool
+    RewardPool,
+}
+
+impl Default for SlashingDestination {
+    fn default() -> Self {
+        SlashingDestination::Burn
+    }
+}
+
+impl Display for SlashingDestination {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SlashingDestination::Burn => write!(f, "burn"),
+            SlashingDestination::RewardPool => write!(f, "reward_pool"),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub struct SlashingParams {
+    // uptime (in percents) below which an active set mixnode is considered to have performed
+    // poorly during the interval. Zero disables slashing altogether.
+    pub uptime_threshold: u8,
+
+    // number of consecutive poorly performing intervals in the active set after which the node gets slashed
+    pub poor_intervals: u32,
+
+    // percentage of the pledge that gets slashed
+    pub slashed_percent: u8,
+
+    // whether delegations towards the slashed node should also be slashed by the same percentage
+    pub slash_delegations: bool,
+
+    pub destination: SlashingDestination,
+}
+
+impl SlashingParams {
+    pub fn is_enabled(&self) -> bool {
+        self.uptime_threshold > 0 && self.poor_intervals > 0 && self.slashed_percent > 0
+    }
+}
+
+impl Display for SlashingParams {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if !self.is_enabled() {
+            return write!(f, "disabled");
+        }
+        write!(
+            f,
+            "{}% slashed ({}) after {} consecutive intervals with uptime below {}%",
+            self.slashed_percent, self.destination, self.poor_intervals, self.uptime_threshold
+        )?;
+        if self.slash_delegations {
+            write!(f, ", including delegations")?;
+        }
+        Ok(())
+    }
+}
+
+/// Number of consecutive intervals, up to and including `last_reported_interval`,
+/// during which the mixnode has performed poorly while being in the active set.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub struct MixnodePerformanceStreak {
+    pub last_reported_interval: u32,
+    pub poor_intervals: u32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+pub struct SlashingRecord {
+    pub id: u64,
+    pub mix_identity: IdentityKey,
+    pub owner: Addr,
+    pub interval_id: u32,
+    pub block_height: u64,
+    pub pledge_slashed: Coin,
+    pub delegations_slashed: Coin,
+    pub destination: SlashingDestination,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+pub struct PagedSlashingHistoryResponse {
+    pub records: Vec<SlashingRecord>,
+    pub start_next_after: Option<u64>,
+}
+
+impl PagedSlashingHistoryResponse {
+    pub fn new(records: Vec<SlashingRecord>, start_next_after: Option<u64>) -> Self {
+        PagedSlashingHistoryResponse {
+            records,
+            start_next_after,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+pub struct PagedPendingDelegationSlashingsResponse {
+    pub mix_identities: Vec<IdentityKey>,
+    pub start_next_after: Option<IdentityKey>,
+}
+
+impl PagedPendingDelegationSlashingsResponse {
+    pub fn new(mix_identities: Vec<IdentityKey>, start_next_after: Option<IdentityKey>) -> Self {
+        PagedPendingDelegationSlashingsResponse {
+            mix_identities,
+            start_next_after,
+        }
+    }
+}
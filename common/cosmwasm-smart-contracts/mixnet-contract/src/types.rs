@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::mixnode::DelegatorRewardParams;
-use crate::{Layer, RewardedSetNodeStatus};
+use crate::{
+    GatewayDelegatorRewardParams, GatewayRewardingParams, Layer, LayerAssignment,
+    RewardedSetNodeStatus, SlashingParams,
+};
 use cosmwasm_std::{Addr, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -43,6 +46,21 @@ pub struct ContractStateParams {
     // subset of rewarded mixnodes that are actively receiving mix traffic
     // used to handle shorter-term (e.g. hourly) fluctuations of demand
     pub mixnode_active_set_size: u32,
+
+    // time, in seconds, that unbonded pledges and undelegated stake are held for before they can be
+    // claimed back. Zero means the tokens are returned immediately, as was the case historically.
+    #[serde(default)]
+    pub unbonding_period: u64,
+
+    // rules for slashing pledges (and possibly delegations) of consistently underperforming
+    // active set mixnodes. Disabled by default.
+    #[serde(default)]
+    pub slashing: SlashingParams,
+
+    // rules for distributing part of the interval reward pool between the gateways
+    // (and their delegators). Disabled by default.
+    #[serde(default)]
+    pub gateway_rewarding: GatewayRewardingParams,
 }
 
 impl Display for ContractStateParams {
@@ -60,14 +78,17 @@ impl Display for ContractStateParams {
         )?;
         write!(
             f,
-            "mixnode rewarded set size: {}",
+            "mixnode rewarded set size: {}; ",
             self.mixnode_rewarded_set_size
         )?;
         write!(
             f,
-            "mixnode active set size: {}",
+            "mixnode active set size: {}; ",
             self.mixnode_active_set_size
-        )
+        )?;
+        write!(f, "unbonding period: {}s; ", self.unbonding_period)?;
+        write!(f, "slashing: {}; ", self.slashing)?;
+        write!(f, "gateway rewarding: {} ]", self.gateway_rewarding)
     }
 }
 
@@ -98,6 +119,27 @@ pub struct MixnodeRewardingStatusResponse {
     pub status: Option<RewardingStatus>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PendingGatewayDelegatorRewarding {
+    // keep track of the running rewarding results so we'd known how much was the operator and its delegators rewarded
+    pub running_results: RewardingResult,
+
+    pub next_start: Addr,
+
+    pub rewarding_params: GatewayDelegatorRewardParams,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum GatewayRewardingStatus {
+    Complete(RewardingResult),
+    PendingNextDelegatorPage(PendingGatewayDelegatorRewarding),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GatewayRewardingStatusResponse {
+    pub status: Option<GatewayRewardingStatus>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MixnetContractVersion {
     // VERGEN_BUILD_TIMESTAMP
@@ -131,6 +173,13 @@ pub struct PagedRewardedSetResponse {
     pub at_height: u64,
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
+pub struct PagedLayerAssignmentResponse {
+    pub assignment: Vec<LayerAssignment>,
+    pub start_next_after: Option<IdentityKey>,
+    pub at_height: u64,
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
 pub struct RewardedSetUpdateDetails {
     pub refresh_rate_blocks: u64,
@@ -0,0 +1,89 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{Addr, IdentityKey};
+use cosmwasm_std::Coin;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PendingWithdrawalKind {
+    MixnodeUnbonding { mix_identity: IdentityKey },
+    Undelegation { mix_identity: IdentityKey },
+    GatewayUndelegation { gateway_identity: IdentityKey },
+}
+
+impl PendingWithdrawalKind {
+    pub fn node_identity(&self) -> &IdentityKey {
+        match self {
+            PendingWithdrawalKind::MixnodeUnbonding { mix_identity } => mix_identity,
+            PendingWithdrawalKind::Undelegation { mix_identity } => mix_identity,
+            PendingWithdrawalKind::GatewayUndelegation { gateway_identity } => gateway_identity,
+        }
+    }
+}
+
+impl Display for PendingWithdrawalKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PendingWithdrawalKind::MixnodeUnbonding { .. } => write!(f, "mixnode_unbonding"),
+            PendingWithdrawalKind::Undelegation { .. } => write!(f, "undelegation"),
+            PendingWithdrawalKind::GatewayUndelegation { .. } => {
+                write!(f, "gateway_undelegation")
+            }
+        }
+    }
+}
+
+/// Tokens that were unbonded or undelegated and are being held by the contract until
+/// `release_time` (unix timestamp, in seconds) after which the owner can claim them.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+pub struct PendingWithdrawal {
+    pub id: u64,
+    pub owner: Addr,
+    pub proxy: Option<Addr>,
+    pub amount: Coin,
+    pub kind: PendingWithdrawalKind,
+    pub release_time: u64,
+}
+
+impl PendingWithdrawal {
+    pub fn new(
+        id: u64,
+        owner: Addr,
+        proxy: Option<Addr>,
+        amount: Coin,
+        kind: PendingWithdrawalKind,
+        release_time: u64,
+    ) -> Self {
+        PendingWithdrawal {
+            id,
+            owner,
+            proxy,
+            amount,
+            kind,
+            release_time,
+        }
+    }
+
+    pub fn is_claimable(&self, now: u64) -> bool {
+        self.release_time <= now
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+pub struct PagedPendingWithdrawalsResponse {
+    pub withdrawals: Vec<PendingWithdrawal>,
+    pub start_next_after: Option<u64>,
+}
+
+impl PagedPendingWithdrawalsResponse {
+    pub fn new(withdrawals: Vec<PendingWithdrawal>, start_next_after: Option<u64>) -> Self {
+        PagedPendingWithdrawalsResponse {
+            withdrawals,
+            start_next_after,
+        }
+    }
+}
@@ -11,15 +11,31 @@ pub const NEW_PERIODIC_VESTING_ACCOUNT_EVENT_TYPE: &str = "new_periodic_vesting_
 
 pub const VESTING_DELEGATION_EVENT_TYPE: &str = "vesting_delegation";
 pub const VESTING_UNDELEGATION_EVENT_TYPE: &str = "vesting_undelegation";
+pub const VESTING_REDELEGATION_EVENT_TYPE: &str = "vesting_redelegation";
+pub const VESTING_GATEWAY_DELEGATION_EVENT_TYPE: &str = "vesting_gateway_delegation";
+pub const VESTING_GATEWAY_UNDELEGATION_EVENT_TYPE: &str = "vesting_gateway_undelegation";
 pub const VESTING_GATEWAY_BONDING_EVENT_TYPE: &str = "vesting_gateway_bonding";
 pub const VESTING_GATEWAY_UNBONDING_EVENT_TYPE: &str = "vesting_gateway_unbonding";
 pub const VESTING_MIXNODE_BONDING_EVENT_TYPE: &str = "vesting_mixnode_bonding";
 pub const VESTING_MIXNODE_UNBONDING_EVENT_TYPE: &str = "vesting_mixnode_unbonding";
 pub const VESTING_UPDATE_MIXNODE_CONFIG_EVENT_TYPE: &str = "vesting_update_mixnode_config";
+pub const VESTING_UPDATE_MIXNODE_DETAILS_EVENT_TYPE: &str = "vesting_update_mixnode_details";
+pub const VESTING_UPDATE_GATEWAY_DETAILS_EVENT_TYPE: &str = "vesting_update_gateway_details";
+pub const VESTING_INCREASE_MIXNODE_PLEDGE_EVENT_TYPE: &str = "vesting_increase_mixnode_pledge";
+pub const VESTING_DECREASE_MIXNODE_PLEDGE_EVENT_TYPE: &str = "vesting_decrease_mixnode_pledge";
+pub const VESTING_CLAIM_PENDING_WITHDRAWALS_EVENT_TYPE: &str = "vesting_claim_pending_withdrawals";
+pub const VESTING_CLAIM_OPERATOR_REWARD_EVENT_TYPE: &str = "vesting_claim_operator_reward";
+pub const VESTING_CLAIM_DELEGATOR_REWARD_EVENT_TYPE: &str = "vesting_claim_delegator_reward";
+pub const VESTING_COMPOUND_DELEGATOR_REWARD_EVENT_TYPE: &str = "vesting_compound_delegator_reward";
 
 pub const TRACK_MIXNODE_UNBOND_EVENT_TYPE: &str = "track_mixnode_unbond";
 pub const TRACK_GATEWAY_UNBOND_EVENT_TYPE: &str = "track_gateway_unbond";
+pub const TRACK_MIXNODE_PLEDGE_DECREASE_EVENT_TYPE: &str = "track_mixnode_pledge_decrease";
+pub const TRACK_SLASHED_MIXNODE_PLEDGE_EVENT_TYPE: &str = "track_slashed_mixnode_pledge";
 pub const TRACK_UNDELEGATION_EVENT_TYPE: &str = "track_undelegation";
+pub const TRACK_SLASHED_DELEGATION_EVENT_TYPE: &str = "track_slashed_delegation";
+pub const TRACK_GATEWAY_UNDELEGATION_EVENT_TYPE: &str = "track_gateway_undelegation";
+pub const TRACK_REWARD_EVENT_TYPE: &str = "track_reward";
 
 // attributes that are used in multiple places
 pub const OWNER_KEY: &str = "owner";
@@ -113,6 +129,38 @@ pub fn new_vesting_update_mixnode_config_event() -> Event {
     Event::new(VESTING_UPDATE_MIXNODE_CONFIG_EVENT_TYPE)
 }
 
+pub fn new_vesting_update_mixnode_details_event() -> Event {
+    Event::new(VESTING_UPDATE_MIXNODE_DETAILS_EVENT_TYPE)
+}
+
+pub fn new_vesting_update_gateway_details_event() -> Event {
+    Event::new(VESTING_UPDATE_GATEWAY_DETAILS_EVENT_TYPE)
+}
+
+pub fn new_vesting_increase_mixnode_pledge_event() -> Event {
+    Event::new(VESTING_INCREASE_MIXNODE_PLEDGE_EVENT_TYPE)
+}
+
+pub fn new_vesting_decrease_mixnode_pledge_event() -> Event {
+    Event::new(VESTING_DECREASE_MIXNODE_PLEDGE_EVENT_TYPE)
+}
+
+pub fn new_vesting_claim_pending_withdrawals_event() -> Event {
+    Event::new(VESTING_CLAIM_PENDING_WITHDRAWALS_EVENT_TYPE)
+}
+
+pub fn new_vesting_claim_operator_reward_event() -> Event {
+    Event::new(VESTING_CLAIM_OPERATOR_REWARD_EVENT_TYPE)
+}
+
+pub fn new_vesting_claim_delegator_reward_event() -> Event {
+    Event::new(VESTING_CLAIM_DELEGATOR_REWARD_EVENT_TYPE)
+}
+
+pub fn new_vesting_compound_delegator_reward_event() -> Event {
+    Event::new(VESTING_COMPOUND_DELEGATOR_REWARD_EVENT_TYPE)
+}
+
 pub fn new_vesting_mixnode_unbonding_event() -> Event {
     Event::new(VESTING_MIXNODE_UNBONDING_EVENT_TYPE)
 }
@@ -125,6 +173,18 @@ pub fn new_vesting_undelegation_event() -> Event {
     Event::new(VESTING_UNDELEGATION_EVENT_TYPE)
 }
 
+pub fn new_vesting_redelegation_event() -> Event {
+    Event::new(VESTING_REDELEGATION_EVENT_TYPE)
+}
+
+pub fn new_vesting_gateway_delegation_event() -> Event {
+    Event::new(VESTING_GATEWAY_DELEGATION_EVENT_TYPE)
+}
+
+pub fn new_vesting_gateway_undelegation_event() -> Event {
+    Event::new(VESTING_GATEWAY_UNDELEGATION_EVENT_TYPE)
+}
+
 pub fn new_track_mixnode_unbond_event() -> Event {
     Event::new(TRACK_MIXNODE_UNBOND_EVENT_TYPE)
 }
@@ -133,6 +193,26 @@ pub fn new_track_gateway_unbond_event() -> Event {
     Event::new(TRACK_GATEWAY_UNBOND_EVENT_TYPE)
 }
 
+pub fn new_track_mixnode_pledge_decrease_event() -> Event {
+    Event::new(TRACK_MIXNODE_PLEDGE_DECREASE_EVENT_TYPE)
+}
+
+pub fn new_track_slashed_mixnode_pledge_event() -> Event {
+    Event::new(TRACK_SLASHED_MIXNODE_PLEDGE_EVENT_TYPE)
+}
+
 pub fn new_track_undelegation_event() -> Event {
     Event::new(TRACK_UNDELEGATION_EVENT_TYPE)
 }
+
+pub fn new_track_slashed_delegation_event() -> Event {
+    Event::new(TRACK_SLASHED_DELEGATION_EVENT_TYPE)
+}
+
+pub fn new_track_gateway_undelegation_event() -> Event {
+    Event::new(TRACK_GATEWAY_UNDELEGATION_EVENT_TYPE)
+}
+
+pub fn new_track_reward_event() -> Event {
+    Event::new(TRACK_REWARD_EVENT_TYPE)
+}
@@ -1,5 +1,7 @@
-use cosmwasm_std::{Coin, Timestamp};
-use mixnet_contract_common::{Gateway, IdentityKey, MixNode};
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use mixnet_contract_common::{
+    Gateway, GatewayDetailsUpdate, IdentityKey, MixNode, MixNodeDetailsUpdate,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -13,11 +15,32 @@ pub struct InitMsg {
 #[serde(rename_all = "snake_case")]
 pub struct MigrateMsg {}
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VestingSchedule {
+    // the same amount of tokens vests at the end of each period
+    Periodic,
+    // the specified amount of tokens vests at the end of each corresponding period
+    CustomAmounts(Vec<Uint128>),
+    // tokens vest continuously throughout the entire vesting duration
+    Linear,
+}
+
+impl Default for VestingSchedule {
+    fn default() -> Self {
+        VestingSchedule::Periodic
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
 pub struct VestingSpecification {
     start_time: Option<u64>,
     period_seconds: Option<u64>,
     num_periods: Option<u64>,
+    // nothing vests until the cliff is over, at which point everything that would have
+    // vested in the meantime is released at once
+    cliff_seconds: Option<u64>,
+    schedule: Option<VestingSchedule>,
 }
 
 impl VestingSpecification {
@@ -30,9 +53,21 @@ impl VestingSpecification {
             start_time,
             period_seconds,
             num_periods,
+            cliff_seconds: None,
+            schedule: None,
         }
     }
 
+    pub fn with_cliff(mut self, cliff_seconds: u64) -> Self {
+        self.cliff_seconds = Some(cliff_seconds);
+        self
+    }
+
+    pub fn with_schedule(mut self, schedule: VestingSchedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
     pub fn start_time(&self) -> Option<u64> {
         self.start_time
     }
@@ -42,7 +77,25 @@ impl VestingSpecification {
     }
 
     pub fn num_periods(&self) -> u64 {
-        self.num_periods.unwrap_or(8)
+        match (self.num_periods, &self.schedule) {
+            (Some(num_periods), _) => num_periods,
+            // if not specified explicitly, there's a period for each custom amount
+            (None, Some(VestingSchedule::CustomAmounts(amounts))) => amounts.len() as u64,
+            (None, _) => 8,
+        }
+    }
+
+    pub fn cliff_seconds(&self) -> u64 {
+        self.cliff_seconds.unwrap_or_default()
+    }
+
+    pub fn schedule(&self) -> VestingSchedule {
+        self.schedule.clone().unwrap_or_default()
+    }
+
+    /// Returns `None` if the duration does not fit in `u64`.
+    pub fn total_duration(&self) -> Option<u64> {
+        self.period_seconds().checked_mul(self.num_periods())
     }
 }
 
@@ -52,6 +105,12 @@ pub enum ExecuteMsg {
     UpdateMixnodeConfig {
         profit_margin_percent: u8,
     },
+    UpdateMixnodeDetails {
+        details: MixNodeDetailsUpdate,
+    },
+    UpdateGatewayDetails {
+        details: GatewayDetailsUpdate,
+    },
     UpdateMixnetAddress {
         address: String,
     },
@@ -62,6 +121,17 @@ pub enum ExecuteMsg {
     UndelegateFromMixnode {
         mix_identity: IdentityKey,
     },
+    RedelegateToMixnode {
+        from: IdentityKey,
+        to: IdentityKey,
+    },
+    DelegateToGateway {
+        gateway_identity: IdentityKey,
+        amount: Coin,
+    },
+    UndelegateFromGateway {
+        gateway_identity: IdentityKey,
+    },
     CreateAccount {
         owner_address: String,
         staking_address: Option<String>,
@@ -85,6 +155,25 @@ pub enum ExecuteMsg {
         owner: String,
         amount: Coin,
     },
+    IncreaseMixnodePledge {
+        amount: Coin,
+    },
+    DecreaseMixnodePledge {
+        amount: Coin,
+    },
+    TrackDecreaseMixnodePledge {
+        owner: String,
+        amount: Coin,
+    },
+    TrackSlashedMixnodePledge {
+        owner: String,
+        amount: Coin,
+    },
+    TrackSlashedDelegation {
+        owner: String,
+        mix_identity: IdentityKey,
+        amount: Coin,
+    },
     BondGateway {
         gateway: Gateway,
         owner_signature: String,
@@ -95,6 +184,30 @@ pub enum ExecuteMsg {
         owner: String,
         amount: Coin,
     },
+    TrackGatewayUndelegation {
+        owner: String,
+        gateway_identity: IdentityKey,
+        amount: Coin,
+    },
+    ClaimPendingWithdrawals {},
+    ClaimOperatorReward {},
+    ClaimDelegatorReward {
+        mix_identity: IdentityKey,
+    },
+    CompoundDelegatorReward {
+        mix_identity: IdentityKey,
+    },
+    ClaimGatewayOperatorReward {},
+    ClaimGatewayDelegatorReward {
+        gateway_identity: IdentityKey,
+    },
+    CompoundGatewayDelegatorReward {
+        gateway_identity: IdentityKey,
+    },
+    TrackReward {
+        owner: String,
+        amount: Coin,
+    },
     TransferOwnership {
         to_address: String,
     },
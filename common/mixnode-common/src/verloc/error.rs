@@ -74,3 +74,38 @@ impl Display for RttError {
 }
 
 impl std::error::Error for RttError {}
+
+#[derive(Debug)]
+pub enum VerlocVerificationError {
+    SerializationFailure(serde_json::Error),
+    MissingSignature,
+    MalformedSignature,
+    InvalidSignature,
+    UnexpectedSigner { expected: String, actual: String },
+}
+
+impl Display for VerlocVerificationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            VerlocVerificationError::SerializationFailure(err) => {
+                write!(f, "Failed to serialize the verloc results - {}", err)
+            }
+            VerlocVerificationError::MissingSignature => {
+                write!(f, "The verloc results were not signed")
+            }
+            VerlocVerificationError::MalformedSignature => {
+                write!(f, "The verloc results had malformed signature")
+            }
+            VerlocVerificationError::InvalidSignature => {
+                write!(f, "The verloc results had invalid signature")
+            }
+            VerlocVerificationError::UnexpectedSigner { expected, actual } => write!(
+                f,
+                "The verloc results were signed by {} while {} was expected",
+                actual, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VerlocVerificationError {}
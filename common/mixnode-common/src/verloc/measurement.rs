@@ -1,83 +1,167 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::verloc::error::VerlocVerificationError;
+use crate::verloc::signing::SignedVerlocResult;
 use crypto::asymmetric::identity;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::cmp::Ordering;
 use std::fmt::{self, Display, Formatter};
+use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
 
 pub struct AtomicVerlocResult {
-    inner: Arc<RwLock<VerlocResult>>,
+    identity: Arc<identity::KeyPair>,
+    inner: Arc<RwLock<SignedVerlocResult>>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VerlocResult {
-    total_tested: usize,
+    pub(crate) total_tested: usize,
     #[serde(with = "humantime_serde")]
-    run_started: Option<std::time::SystemTime>,
+    pub(crate) run_started: Option<std::time::SystemTime>,
     #[serde(with = "humantime_serde")]
-    run_finished: Option<std::time::SystemTime>,
-    results: Vec<Verloc>,
+    pub(crate) run_finished: Option<std::time::SystemTime>,
+    pub(crate) results: Vec<Verloc>,
 }
 
-impl AtomicVerlocResult {
+impl VerlocResult {
     pub(crate) fn new() -> Self {
+        VerlocResult {
+            total_tested: 0,
+            run_started: None,
+            run_finished: None,
+            results: Vec::new(),
+        }
+    }
+
+    pub fn total_tested(&self) -> usize {
+        self.total_tested
+    }
+
+    pub fn run_started(&self) -> Option<std::time::SystemTime> {
+        self.run_started
+    }
+
+    pub fn run_finished(&self) -> Option<std::time::SystemTime> {
+        self.run_finished
+    }
+
+    pub fn results(&self) -> &[Verloc] {
+        &self.results
+    }
+
+    /// Bytes covered by the signature of the node that performed the measurements.
+    pub(crate) fn signable_bytes(&self) -> Result<Vec<u8>, VerlocVerificationError> {
+        serde_json::to_vec(self).map_err(VerlocVerificationError::SerializationFailure)
+    }
+}
+
+impl AtomicVerlocResult {
+    pub(crate) fn new(identity: Arc<identity::KeyPair>) -> Self {
+        let signed = SignedVerlocResult::new(VerlocResult::new(), &identity);
         AtomicVerlocResult {
-            inner: Arc::new(RwLock::new(VerlocResult {
-                total_tested: 0,
-                run_started: None,
-                run_finished: None,
-                results: Vec::new(),
-            })),
+            identity,
+            inner: Arc::new(RwLock::new(signed)),
         }
     }
 
     // this could have also been achieved with a normal #[derive(Clone)] but I prefer to be explicit about it
     pub(crate) fn clone_data_pointer(&self) -> Self {
         AtomicVerlocResult {
+            identity: Arc::clone(&self.identity),
             inner: Arc::clone(&self.inner),
         }
     }
 
     pub(crate) async fn reset_results(&self, new_tested: usize) {
         let mut write_permit = self.inner.write().await;
-        write_permit.total_tested = new_tested;
-        write_permit.run_started = Some(std::time::SystemTime::now());
-        write_permit.run_finished = None;
-        write_permit.results = Vec::new()
+        let results = write_permit.results_mut();
+        results.total_tested = new_tested;
+        results.run_started = Some(std::time::SystemTime::now());
+        results.run_finished = None;
+        results.results = Vec::new();
+        write_permit.resign(&self.identity)
     }
 
     pub(crate) async fn append_results(&self, mut new_data: Vec<Verloc>) {
         let mut write_permit = self.inner.write().await;
-        write_permit.results.append(&mut new_data);
+        let results = write_permit.results_mut();
+        results.results.append(&mut new_data);
         // make sure the data always stays in order.
         // TODO: considering the front of the results is guaranteed to be sorted, should perhaps
         // a non-default sorting algorithm be used?
-        write_permit.results.sort()
+        results.results.sort();
+        // each batch of measurements gets signed as soon as it becomes available
+        write_permit.resign(&self.identity)
     }
 
     pub(crate) async fn finish_measurements(&self) {
-        self.inner.write().await.run_finished = Some(std::time::SystemTime::now());
+        let mut write_permit = self.inner.write().await;
+        write_permit.results_mut().run_finished = Some(std::time::SystemTime::now());
+        write_permit.resign(&self.identity)
     }
 
     // Considering that on every read we will need to clone data regardless, let's make our
     // lives simpler and clone it here rather than deal with lifetime of the permit
     pub async fn clone_data(&self) -> VerlocResult {
+        self.inner.read().await.results().clone()
+    }
+
+    pub async fn clone_signed_data(&self) -> SignedVerlocResult {
         self.inner.read().await.clone()
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeType {
+    Mixnode,
+    Gateway,
+}
+
+impl Display for NodeType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeType::Mixnode => write!(f, "mixnode"),
+            NodeType::Gateway => write!(f, "gateway"),
+        }
+    }
+}
+
+/// Result of measuring a single resolved address of a node.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressMeasurement {
+    pub address: SocketAddr,
+    pub measurement: Option<Measurement>,
+}
+
+impl AddressMeasurement {
+    pub(crate) fn new(address: SocketAddr, measurement: Option<Measurement>) -> Self {
+        AddressMeasurement {
+            address,
+            measurement,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Verloc {
-    #[serde(serialize_with = "serialize_identity_as_string")]
+    #[serde(
+        serialize_with = "serialize_identity_as_string",
+        deserialize_with = "deserialize_identity_from_string"
+    )]
     pub identity: identity::PublicKey,
+    pub node_type: NodeType,
+    /// The best measurement out of all measured addresses of the node.
     pub latest_measurement: Option<Measurement>,
+    /// Measurements of every address the node's host has resolved to.
+    pub address_measurements: Vec<AddressMeasurement>,
 }
 
-fn serialize_identity_as_string<S>(
+pub(crate) fn serialize_identity_as_string<S>(
     identity: &identity::PublicKey,
     serializer: S,
 ) -> Result<S::Ok, S::Error>
@@ -87,12 +171,34 @@ where
     serializer.serialize_str(&identity.to_base58_string())
 }
 
+pub(crate) fn deserialize_identity_from_string<'de, D>(
+    deserializer: D,
+) -> Result<identity::PublicKey, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    identity::PublicKey::from_base58_string(s).map_err(serde::de::Error::custom)
+}
+
 impl Display for Verloc {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         if let Some(measurement) = self.latest_measurement {
-            write!(f, "{} - {}", self.identity, measurement)
+            write!(
+                f,
+                "{} ({}) - {}",
+                self.identity, self.node_type, measurement
+            )?;
+            if self.is_partially_reachable() {
+                write!(f, " (some addresses COULD NOT BE MEASURED)")?;
+            }
+            Ok(())
         } else {
-            write!(f, "{} - COULD NOT MEASURE", self.identity)
+            write!(
+                f,
+                "{} ({}) - COULD NOT MEASURE",
+                self.identity, self.node_type
+            )
         }
     }
 }
@@ -125,24 +231,44 @@ impl Ord for Verloc {
 impl Verloc {
     pub(crate) fn new(
         identity: identity::PublicKey,
-        latest_measurement: Option<Measurement>,
+        node_type: NodeType,
+        address_measurements: Vec<AddressMeasurement>,
     ) -> Self {
+        let latest_measurement = address_measurements
+            .iter()
+            .filter_map(|address_measurement| address_measurement.measurement)
+            .min();
+
         Verloc {
             identity,
+            node_type,
             latest_measurement,
+            address_measurements,
         }
     }
+
+    /// Checks whether the node could be measured on some, but not all, of its addresses,
+    /// which usually indicates a broken dual-stack (IPv4 + IPv6) configuration.
+    pub fn is_partially_reachable(&self) -> bool {
+        let measured = self
+            .address_measurements
+            .iter()
+            .filter(|address_measurement| address_measurement.measurement.is_some())
+            .count();
+
+        measured > 0 && measured < self.address_measurements.len()
+    }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Measurement {
-    #[serde(serialize_with = "humantime_serde::serialize")]
+    #[serde(with = "humantime_serde")]
     pub minimum: Duration,
-    #[serde(serialize_with = "humantime_serde::serialize")]
+    #[serde(with = "humantime_serde")]
     pub mean: Duration,
-    #[serde(serialize_with = "humantime_serde::serialize")]
+    #[serde(with = "humantime_serde")]
     pub maximum: Duration,
-    #[serde(serialize_with = "humantime_serde::serialize")]
+    #[serde(with = "humantime_serde")]
     pub standard_deviation: Duration,
 }
 
@@ -231,35 +357,100 @@ impl Ord for Measurement {
 mod tests {
     use super::*;
 
+    fn some_identity() -> identity::PublicKey {
+        identity::PublicKey::from_base58_string("Be9wH7xuXBRJAuV1pC7MALZv6a61RvWQ3SypsNarqTt")
+            .unwrap()
+    }
+
+    fn measurement_with_base(base_millis: u64) -> Measurement {
+        Measurement {
+            minimum: Duration::from_millis(base_millis),
+            mean: Duration::from_millis(base_millis + 1),
+            maximum: Duration::from_millis(base_millis + 2),
+            standard_deviation: Duration::from_millis(base_millis + 3),
+        }
+    }
+
     #[test]
     fn sorting_vec_of_verlocs() {
-        let some_identity =
-            identity::PublicKey::from_base58_string("Be9wH7xuXBRJAuV1pC7MALZv6a61RvWQ3SypsNarqTt")
-                .unwrap();
-        let no_measurement = Verloc::new(some_identity, None);
+        let address = "1.2.3.4:1790".parse().unwrap();
+
+        let no_measurement = Verloc::new(
+            some_identity(),
+            NodeType::Mixnode,
+            vec![AddressMeasurement::new(address, None)],
+        );
         let low_min = Verloc::new(
-            some_identity,
-            Some(Measurement {
-                minimum: Duration::from_millis(42),
-                mean: Duration::from_millis(43),
-                maximum: Duration::from_millis(44),
-                standard_deviation: Duration::from_millis(45),
-            }),
+            some_identity(),
+            NodeType::Mixnode,
+            vec![AddressMeasurement::new(
+                address,
+                Some(measurement_with_base(42)),
+            )],
         );
         let higher_min = Verloc::new(
-            some_identity,
-            Some(Measurement {
-                minimum: Duration::from_millis(420),
-                mean: Duration::from_millis(430),
-                maximum: Duration::from_millis(440),
-                standard_deviation: Duration::from_millis(450),
-            }),
+            some_identity(),
+            NodeType::Gateway,
+            vec![AddressMeasurement::new(
+                address,
+                Some(measurement_with_base(420)),
+            )],
         );
 
-        let mut vec_verloc = vec![no_measurement, low_min, no_measurement, higher_min];
+        let mut vec_verloc = vec![
+            no_measurement.clone(),
+            low_min.clone(),
+            no_measurement.clone(),
+            higher_min.clone(),
+        ];
         vec_verloc.sort();
 
-        let expected_sorted = vec![low_min, higher_min, no_measurement, no_measurement];
+        let expected_sorted = vec![low_min, higher_min, no_measurement.clone(), no_measurement];
         assert_eq!(expected_sorted, vec_verloc);
     }
+
+    #[test]
+    fn verloc_uses_best_address_measurement() {
+        let ipv4 = "1.2.3.4:1790".parse().unwrap();
+        let ipv6 = "[2001:db8::1]:1790".parse().unwrap();
+
+        let both_measured = Verloc::new(
+            some_identity(),
+            NodeType::Mixnode,
+            vec![
+                AddressMeasurement::new(ipv4, Some(measurement_with_base(100))),
+                AddressMeasurement::new(ipv6, Some(measurement_with_base(42))),
+            ],
+        );
+        assert_eq!(
+            Some(measurement_with_base(42)),
+            both_measured.latest_measurement
+        );
+        assert!(!both_measured.is_partially_reachable());
+
+        let broken_ipv6 = Verloc::new(
+            some_identity(),
+            NodeType::Mixnode,
+            vec![
+                AddressMeasurement::new(ipv4, Some(measurement_with_base(100))),
+                AddressMeasurement::new(ipv6, None),
+            ],
+        );
+        assert_eq!(
+            Some(measurement_with_base(100)),
+            broken_ipv6.latest_measurement
+        );
+        assert!(broken_ipv6.is_partially_reachable());
+
+        let unreachable = Verloc::new(
+            some_identity(),
+            NodeType::Gateway,
+            vec![
+                AddressMeasurement::new(ipv4, None),
+                AddressMeasurement::new(ipv6, None),
+            ],
+        );
+        assert!(unreachable.latest_measurement.is_none());
+        assert!(!unreachable.is_partially_reachable());
+    }
 }
@@ -2,14 +2,18 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::verloc::listener::PacketListener;
-pub use crate::verloc::measurement::{AtomicVerlocResult, Verloc, VerlocResult};
+pub use crate::verloc::measurement::{
+    AddressMeasurement, AtomicVerlocResult, Measurement, NodeType, Verloc, VerlocResult,
+};
 use crate::verloc::sender::{PacketSender, TestedNode};
+pub use crate::verloc::signing::{verify_verloc_result, SignedVerlocResult};
 use crypto::asymmetric::identity;
 use futures::stream::FuturesUnordered;
 use futures::StreamExt;
 use log::*;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
+use std::collections::HashSet;
 use std::net::{SocketAddr, ToSocketAddrs};
 use std::sync::Arc;
 use std::time::Duration;
@@ -23,6 +27,7 @@ pub(crate) mod listener;
 pub(crate) mod measurement;
 pub(crate) mod packet;
 pub(crate) mod sender;
+pub(crate) mod signing;
 
 // TODO: MUST BE UPDATED BEFORE ACTUAL RELEASE!!
 pub const MINIMUM_NODE_VERSION: &str = "0.10.1";
@@ -36,6 +41,11 @@ const DEFAULT_DELAY_BETWEEN_PACKETS: Duration = Duration::from_millis(50);
 const DEFAULT_BATCH_SIZE: usize = 50;
 const DEFAULT_TESTING_INTERVAL: Duration = Duration::from_secs(60 * 60 * 12);
 const DEFAULT_RETRY_TIMEOUT: Duration = Duration::from_secs(60 * 30);
+const DEFAULT_MEASURE_GATEWAYS: bool = true;
+
+// gateways do not announce their verloc port in their bonds, so for the time being
+// we assume they all use the default one
+const GATEWAY_VERLOC_PORT: u16 = DEFAULT_VERLOC_PORT;
 
 #[derive(Clone, Debug)]
 pub struct Config {
@@ -67,6 +77,9 @@ pub struct Config {
     /// due to being unable to get the list of nodes.
     retry_timeout: Duration,
 
+    /// Specifies whether gateways should also be measured alongside mixnodes.
+    measure_gateways: bool,
+
     /// URLs to the validator apis for obtaining network topology.
     validator_api_urls: Vec<Url>,
 }
@@ -130,6 +143,11 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn measure_gateways(mut self, measure_gateways: bool) -> Self {
+        self.0.measure_gateways = measure_gateways;
+        self
+    }
+
     pub fn validator_api_urls(mut self, validator_api_urls: Vec<Url>) -> Self {
         self.0.validator_api_urls = validator_api_urls;
         self
@@ -156,11 +174,43 @@ impl Default for ConfigBuilder {
             tested_nodes_batch_size: DEFAULT_BATCH_SIZE,
             testing_interval: DEFAULT_TESTING_INTERVAL,
             retry_timeout: DEFAULT_RETRY_TIMEOUT,
+            measure_gateways: DEFAULT_MEASURE_GATEWAYS,
             validator_api_urls: vec![],
         })
     }
 }
 
+/// Starts listening for and replying to echo packets without performing any measurements on our own.
+/// It allows nodes that do not run the full `VerlocMeasurer`, such as gateways, to still get measured.
+pub fn start_echo_listener(
+    listening_address: SocketAddr,
+    identity: Arc<identity::KeyPair>,
+) -> JoinHandle<()> {
+    let packet_listener = Arc::new(PacketListener::new(listening_address, identity));
+    tokio::spawn(packet_listener.run())
+}
+
+/// Resolves all addresses (both IPv4 and IPv6) of the provided host.
+// TODO: possible problem in the future, this does name resolution and theoretically
+// if a lot of nodes maliciously mis-configured themselves, it might take a while to resolve them all
+// However, maybe it's not a problem as if they are misconfigured, they will eventually be
+// pushed out of the network and on top of that, verloc is done in separate task that runs
+// only every few hours.
+fn resolve_all_addresses(host: &str, port: u16) -> Option<Vec<SocketAddr>> {
+    let mut seen = HashSet::new();
+    let addresses = (host, port)
+        .to_socket_addrs()
+        .ok()?
+        .filter(|address| seen.insert(*address))
+        .collect::<Vec<_>>();
+
+    if addresses.is_empty() {
+        None
+    } else {
+        Some(addresses)
+    }
+}
+
 pub struct VerlocMeasurer {
     config: Config,
     packet_sender: Arc<PacketSender>,
@@ -197,7 +247,7 @@ impl VerlocMeasurer {
                 config.validator_api_urls[0].clone(),
             ),
             config,
-            results: AtomicVerlocResult::new(),
+            results: AtomicVerlocResult::new(identity),
         }
     }
 
@@ -222,6 +272,27 @@ impl VerlocMeasurer {
         tokio::spawn(packet_listener.run())
     }
 
+    fn to_tested_node(
+        &self,
+        version: &str,
+        identity_key: &str,
+        host: &str,
+        verloc_port: u16,
+        node_type: NodeType,
+    ) -> Option<TestedNode> {
+        // check if the node has sufficient version to be able to understand the packets
+        let node_version = parse_version(version).ok()?;
+        if node_version < self.config.minimum_compatible_node_version {
+            return None;
+        }
+
+        // try to parse the identity and host
+        let node_identity = identity::PublicKey::from_base58_string(identity_key).ok()?;
+        let addresses = resolve_all_addresses(host, verloc_port)?;
+
+        Some(TestedNode::new(addresses, node_identity, node_type))
+    }
+
     async fn perform_measurement(&self, nodes_to_test: Vec<TestedNode>) {
         for chunk in nodes_to_test.chunks(self.config.tested_nodes_batch_size) {
             let mut chunk_results = Vec::with_capacity(chunk.len());
@@ -229,7 +300,7 @@ impl VerlocMeasurer {
             let mut measurement_chunk = chunk
                 .iter()
                 .map(|node| {
-                    let node = *node;
+                    let node = node.clone();
                     let packet_sender = Arc::clone(&self.packet_sender);
                     // TODO: there's a potential issue here. if we make the measurement go into separate
                     // task, we risk biasing results with the bunch of context switches overhead
@@ -237,9 +308,12 @@ impl VerlocMeasurer {
 
                     // TODO: check performance difference when it's not spawned as a separate task
                     tokio::spawn(async move {
+                        let identity = node.identity;
+                        let node_type = node.node_type;
                         (
                             packet_sender.send_packets_to_node(node).await,
-                            node.identity,
+                            identity,
+                            node_type,
                         )
                     })
                 })
@@ -249,19 +323,9 @@ impl VerlocMeasurer {
             while let Some(result) = measurement_chunk.next().await {
                 // if we receive JoinError it means the task failed to get executed, so either there's a bigger issue with tokio
                 // or there was a panic inside the task itself. In either case, we should just terminate ourselves.
-                let execution_result = result.expect("the measurement task panicked!");
-                let measurement_result = match execution_result.0 {
-                    Err(err) => {
-                        debug!(
-                            "Failed to perform measurement for {} - {}",
-                            execution_result.1.to_base58_string(),
-                            err
-                        );
-                        None
-                    }
-                    Ok(result) => Some(result),
-                };
-                chunk_results.push(Verloc::new(execution_result.1, measurement_result));
+                let (address_measurements, identity, node_type) =
+                    result.expect("the measurement task panicked!");
+                chunk_results.push(Verloc::new(identity, node_type, address_measurements));
             }
 
             // update the results vector with chunks as they become available (by default every 50 nodes)
@@ -273,7 +337,6 @@ impl VerlocMeasurer {
         self.start_listening();
         loop {
             info!(target: "verloc", "Starting verloc measurements");
-            // TODO: should we also measure gateways?
 
             let all_mixes = match self.validator_client.get_cached_mixnodes().await {
                 Ok(nodes) => nodes,
@@ -287,37 +350,49 @@ impl VerlocMeasurer {
                     continue;
                 }
             };
-            if all_mixes.is_empty() {
-                warn!("There does not seem there are any nodes to measure...")
-            }
 
-            // we only care about address and identity
-            let tested_nodes = all_mixes
-                .into_iter()
-                .filter_map(|node| {
-                    // check if the node has sufficient version to be able to understand the packets
-                    let node_version = parse_version(&node.mix_node.version).ok()?;
-                    if node_version < self.config.minimum_compatible_node_version {
-                        return None;
+            let all_gateways = if self.config.measure_gateways {
+                match self.validator_client.get_cached_gateways().await {
+                    Ok(nodes) => nodes,
+                    Err(err) => {
+                        // we still got the mixnodes so there's no point in abandoning the whole run
+                        warn!(
+                            "failed to obtain list of gateways from the validator - {}. Only mixnodes are going to be measured in this run",
+                            err
+                        );
+                        Vec::new()
                     }
+                }
+            } else {
+                Vec::new()
+            };
 
-                    // try to parse the identity and host
-                    let node_identity =
-                        identity::PublicKey::from_base58_string(node.mix_node.identity_key).ok()?;
-
-                    let verloc_host = (&*node.mix_node.host, node.mix_node.verloc_port)
-                        .to_socket_addrs()
-                        .ok()?
-                        .next()?;
-
-                    // TODO: possible problem in the future, this does name resolution and theoretically
-                    // if a lot of nodes maliciously mis-configured themselves, it might take a while to resolve them all
-                    // However, maybe it's not a problem as if they are misconfigured, they will eventually be
-                    // pushed out of the network and on top of that, verloc is done in separate task that runs
-                    // only every few hours.
-                    Some(TestedNode::new(verloc_host, node_identity))
-                })
-                .collect::<Vec<_>>();
+            if all_mixes.is_empty() && all_gateways.is_empty() {
+                warn!("There does not seem there are any nodes to measure...")
+            }
+
+            // we only care about addresses and identity
+            let tested_mixes = all_mixes.into_iter().filter_map(|node| {
+                self.to_tested_node(
+                    &node.mix_node.version,
+                    &node.mix_node.identity_key,
+                    &node.mix_node.host,
+                    node.mix_node.verloc_port,
+                    NodeType::Mixnode,
+                )
+            });
+
+            let tested_gateways = all_gateways.into_iter().filter_map(|node| {
+                self.to_tested_node(
+                    &node.gateway.version,
+                    &node.gateway.identity_key,
+                    &node.gateway.host,
+                    GATEWAY_VERLOC_PORT,
+                    NodeType::Gateway,
+                )
+            });
+
+            let tested_nodes = tested_mixes.chain(tested_gateways).collect::<Vec<_>>();
 
             // on start of each run remove old results
             self.results.reset_results(tested_nodes.len()).await;
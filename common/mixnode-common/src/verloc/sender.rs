@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::verloc::error::RttError;
-use crate::verloc::measurement::Measurement;
+use crate::verloc::measurement::{AddressMeasurement, Measurement, NodeType};
 use crate::verloc::packet::{EchoPacket, ReplyPacket};
 use crypto::asymmetric::identity;
 use log::*;
@@ -15,15 +15,24 @@ use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
 use tokio::time::sleep;
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub(crate) struct TestedNode {
-    pub(crate) address: SocketAddr,
+    pub(crate) addresses: Vec<SocketAddr>,
     pub(crate) identity: identity::PublicKey,
+    pub(crate) node_type: NodeType,
 }
 
 impl TestedNode {
-    pub(crate) fn new(address: SocketAddr, identity: identity::PublicKey) -> Self {
-        TestedNode { address, identity }
+    pub(crate) fn new(
+        addresses: Vec<SocketAddr>,
+        identity: identity::PublicKey,
+        node_type: NodeType,
+    ) -> Self {
+        TestedNode {
+            addresses,
+            identity,
+            node_type,
+        }
     }
 }
 
@@ -64,26 +73,55 @@ impl PacketSender {
         }
     }
 
-    // TODO: split this function
+    /// Measures all resolved addresses of the node. It is done sequentially rather than concurrently
+    /// so that the measurements would not bias each other.
     pub(super) async fn send_packets_to_node(
         self: Arc<Self>,
         tested_node: TestedNode,
+    ) -> Vec<AddressMeasurement> {
+        let mut measurements = Vec::with_capacity(tested_node.addresses.len());
+        for address in tested_node.addresses {
+            let measurement = match self
+                .send_packets_to_address(address, tested_node.identity)
+                .await
+            {
+                Err(err) => {
+                    debug!(
+                        "Failed to perform measurement for {} on {} - {}",
+                        tested_node.identity.to_base58_string(),
+                        address,
+                        err
+                    );
+                    None
+                }
+                Ok(measurement) => Some(measurement),
+            };
+            measurements.push(AddressMeasurement::new(address, measurement));
+        }
+        measurements
+    }
+
+    // TODO: split this function
+    async fn send_packets_to_address(
+        &self,
+        address: SocketAddr,
+        node_identity: identity::PublicKey,
     ) -> Result<Measurement, RttError> {
         let mut conn = match tokio::time::timeout(
             self.connection_timeout,
-            TcpStream::connect(tested_node.address),
+            TcpStream::connect(address),
         )
         .await
         {
             Err(_timeout) => {
                 return Err(RttError::UnreachableNode(
-                    tested_node.identity.to_base58_string(),
+                    node_identity.to_base58_string(),
                     io::ErrorKind::TimedOut.into(),
                 ))
             }
             Ok(Err(err)) => {
                 return Err(RttError::UnreachableNode(
-                    tested_node.identity.to_base58_string(),
+                    node_identity.to_base58_string(),
                     err,
                 ))
             }
@@ -105,7 +143,7 @@ impl PacketSender {
             .await
             {
                 Err(_timeout) => {
-                    let identity_string = tested_node.identity.to_base58_string();
+                    let identity_string = node_identity.to_base58_string();
                     debug!(
                         "failed to write echo packet to {} within {:?}. Stopping the test.",
                         identity_string, self.packet_timeout
@@ -116,7 +154,7 @@ impl PacketSender {
                     ));
                 }
                 Ok(Err(err)) => {
-                    let identity_string = tested_node.identity.to_base58_string();
+                    let identity_string = node_identity.to_base58_string();
                     debug!(
                         "failed to write echo packet to {} - {}. Stopping the test.",
                         identity_string, err
@@ -136,15 +174,15 @@ impl PacketSender {
                 if let Err(err) = conn.read_exact(&mut buf).await {
                     debug!(
                         "failed to read reply packet from {} - {}. Stopping the test.",
-                        tested_node.identity.to_base58_string(),
+                        node_identity.to_base58_string(),
                         err
                     );
                     return Err(RttError::UnexpectedConnectionFailureRead(
-                        tested_node.identity.to_base58_string(),
+                        node_identity.to_base58_string(),
                         err,
                     ));
                 }
-                ReplyPacket::try_from_bytes(&buf, &tested_node.identity)
+                ReplyPacket::try_from_bytes(&buf, &node_identity)
             };
 
             let reply_packet =
@@ -158,7 +196,7 @@ impl PacketSender {
                         self.packet_timeout
                     );
                         return Err(RttError::ConnectionReadTimeout(
-                            tested_node.identity.to_base58_string(),
+                            node_identity.to_base58_string(),
                         ));
                     }
                 };
@@ -0,0 +1,169 @@
+// Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::verloc::error::VerlocVerificationError;
+use crate::verloc::measurement::{
+    deserialize_identity_from_string, serialize_identity_as_string, VerlocResult,
+};
+use crypto::asymmetric::identity;
+use log::*;
+use serde::{Deserialize, Serialize};
+
+/// Verloc measurements alongside the signature of the node that has performed them,
+/// so that anyone could verify the results were actually reported by the said node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignedVerlocResult {
+    /// Identity of the node that has performed the measurements.
+    #[serde(
+        serialize_with = "serialize_identity_as_string",
+        deserialize_with = "deserialize_identity_from_string"
+    )]
+    signer: identity::PublicKey,
+
+    results: VerlocResult,
+
+    /// Base58 encoded signature on the serialized `results` created with the identity key of the `signer`.
+    /// It is only ever missing if the results could not have been serialized, which should never happen.
+    signature: Option<String>,
+}
+
+impl SignedVerlocResult {
+    pub(crate) fn new(results: VerlocResult, identity: &identity::KeyPair) -> Self {
+        let mut signed = SignedVerlocResult {
+            signer: *identity.public_key(),
+            results,
+            signature: None,
+        };
+        signed.resign(identity);
+        signed
+    }
+
+    pub(crate) fn results_mut(&mut self) -> &mut VerlocResult {
+        &mut self.results
+    }
+
+    /// Recomputes the signature after the underlying results got modified.
+    pub(crate) fn resign(&mut self, identity: &identity::KeyPair) {
+        self.signature = match self.results.signable_bytes() {
+            Ok(bytes) => Some(identity.private_key().sign(&bytes).to_base58_string()),
+            Err(err) => {
+                error!("Failed to sign verloc results - {}", err);
+                None
+            }
+        };
+    }
+
+    pub fn signer(&self) -> &identity::PublicKey {
+        &self.signer
+    }
+
+    pub fn results(&self) -> &VerlocResult {
+        &self.results
+    }
+
+    pub fn signature(&self) -> Option<&str> {
+        self.signature.as_deref()
+    }
+
+    /// Checks whether the results were signed by the claimed signer.
+    /// Note that it does not check whether the signer is the node we expected. For that use `verify_for`.
+    pub fn verify(&self) -> Result<(), VerlocVerificationError> {
+        let signature = self
+            .signature
+            .as_ref()
+            .ok_or(VerlocVerificationError::MissingSignature)?;
+        let signature = identity::Signature::from_base58_string(signature)
+            .map_err(|_| VerlocVerificationError::MalformedSignature)?;
+        verify_verloc_result(&self.results, &self.signer, &signature)
+    }
+
+    /// Checks whether the results were signed by the node with the expected identity,
+    /// for example the one obtained from its bond.
+    pub fn verify_for(
+        &self,
+        expected_signer: &identity::PublicKey,
+    ) -> Result<(), VerlocVerificationError> {
+        if &self.signer != expected_signer {
+            return Err(VerlocVerificationError::UnexpectedSigner {
+                expected: expected_signer.to_base58_string(),
+                actual: self.signer.to_base58_string(),
+            });
+        }
+        self.verify()
+    }
+
+    pub fn into_verified_results(self) -> Result<VerlocResult, VerlocVerificationError> {
+        self.verify()?;
+        Ok(self.results)
+    }
+}
+
+/// Verifies whether the provided verloc results were signed with the identity key of the specified node.
+pub fn verify_verloc_result(
+    results: &VerlocResult,
+    signer: &identity::PublicKey,
+    signature: &identity::Signature,
+) -> Result<(), VerlocVerificationError> {
+    let signed_bytes = results.signable_bytes()?;
+    signer
+        .verify(&signed_bytes, signature)
+        .map_err(|_| VerlocVerificationError::InvalidSignature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verloc::measurement::{AddressMeasurement, Measurement, NodeType, Verloc};
+    use std::time::Duration;
+
+    fn test_keys(seed: u8) -> identity::KeyPair {
+        let private_key = identity::PrivateKey::from_bytes(&[seed; 32]).unwrap();
+        let public_key = identity::PublicKey::from(&private_key);
+        identity::KeyPair::from_bytes(&private_key.to_bytes(), &public_key.to_bytes()).unwrap()
+    }
+
+    fn dummy_results(identity: &identity::KeyPair) -> VerlocResult {
+        let mut results = VerlocResult::new();
+        results.total_tested = 1;
+        results.run_started = Some(std::time::SystemTime::now());
+        results.results = vec![Verloc::new(
+            *identity.public_key(),
+            NodeType::Mixnode,
+            vec![AddressMeasurement::new(
+                "1.2.3.4:1790".parse().unwrap(),
+                Some(Measurement::new(&[
+                    Duration::from_millis(42),
+                    Duration::from_millis(123),
+                ])),
+            )],
+        )];
+        results
+    }
+
+    #[test]
+    fn signed_results_survive_serialization_roundtrip() {
+        let keys = test_keys(1);
+
+        let signed = SignedVerlocResult::new(dummy_results(&keys), &keys);
+        assert!(signed.verify().is_ok());
+        assert!(signed.verify_for(keys.public_key()).is_ok());
+
+        let serialized = serde_json::to_string(&signed).unwrap();
+        let deserialized: SignedVerlocResult = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(signed, deserialized);
+        assert!(deserialized.verify().is_ok());
+    }
+
+    #[test]
+    fn tampered_results_fail_verification() {
+        let keys = test_keys(1);
+        let other_keys = test_keys(2);
+
+        let mut signed = SignedVerlocResult::new(dummy_results(&keys), &keys);
+        signed.results_mut().total_tested = 42;
+        assert!(signed.verify().is_err());
+
+        let signed = SignedVerlocResult::new(dummy_results(&keys), &keys);
+        assert!(signed.verify_for(other_keys.public_key()).is_err());
+    }
+}
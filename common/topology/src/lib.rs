@@ -3,11 +3,11 @@
 
 use crate::filter::VersionFilterable;
 use log::warn;
-use mixnet_contract_common::{GatewayBond, MixNodeBond};
+use mixnet_contract_common::{GatewayBond, LayerAssignment, MixNodeBond};
 use nymsphinx_addressing::nodes::NodeIdentity;
 use nymsphinx_types::Node as SphinxNode;
 use rand::Rng;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
 use std::fmt::{self, Display, Formatter};
 use std::io;
@@ -26,6 +26,7 @@ pub enum NymTopologyError {
 
     InvalidNumberOfHopsError,
     NoMixesOnLayerAvailable(MixLayer),
+    NoFamilyDisjointMixesAvailable(MixLayer),
 }
 
 #[derive(Debug, Clone)]
@@ -126,30 +127,92 @@ impl NymTopology {
         // I don't think there's a need for this RNG to be crypto-secure
         R: Rng + ?Sized,
     {
-        use rand::seq::SliceRandom;
-
         if self.mixes.len() < num_mix_hops as usize {
             return Err(NymTopologyError::InvalidNumberOfHopsError);
         }
         let mut route = Vec::with_capacity(num_mix_hops as usize);
+        let mut used_families = HashSet::new();
 
         // there is no "layer 0"
-        for layer in 1..=num_mix_hops {
-            // get all mixes on particular layer
-            let layer_mixes = self
-                .mixes
-                .get(&layer)
-                .ok_or(NymTopologyError::NoMixesOnLayerAvailable(layer))?;
-
-            // choose a random mix from the above list
-            // this can return a 'None' only if slice is empty
-            let random_mix = layer_mixes
-                .choose(rng)
-                .ok_or(NymTopologyError::NoMixesOnLayerAvailable(layer))?;
-            route.push(random_mix.into());
+        self.extend_family_disjoint_route(rng, 1, num_mix_hops, &mut used_families, &mut route)?;
+
+        Ok(route.into_iter().map(Into::into).collect())
+    }
+
+    // Chooses a random mix on the specified layer and recursively continues with the following ones.
+    // If the families chosen so far leave no eligible mix on some further layer,
+    // it backtracks and tries mixes of the other families instead.
+    fn extend_family_disjoint_route<'a, R>(
+        &'a self,
+        rng: &mut R,
+        layer: MixLayer,
+        num_mix_hops: u8,
+        used_families: &mut HashSet<&'a str>,
+        route: &mut Vec<&'a mix::Node>,
+    ) -> Result<(), NymTopologyError>
+    where
+        R: Rng + ?Sized,
+    {
+        use rand::seq::SliceRandom;
+
+        if layer > num_mix_hops {
+            return Ok(());
+        }
+
+        // get all mixes on particular layer
+        let layer_mixes = self
+            .mixes
+            .get(&layer)
+            .ok_or(NymTopologyError::NoMixesOnLayerAvailable(layer))?;
+
+        if layer_mixes.is_empty() {
+            return Err(NymTopologyError::NoMixesOnLayerAvailable(layer));
+        }
+
+        // make sure a single operator family can never control more than one hop of the route
+        let mut candidates = layer_mixes
+            .iter()
+            .filter(|mix| match &mix.family {
+                Some(family) => !used_families.contains(family.as_str()),
+                None => true,
+            })
+            .collect::<Vec<_>>();
+        candidates.shuffle(rng);
+
+        // the further layers only depend on the family of the chosen mix,
+        // so there's no point in trying more than a single mix of each family.
+        // if there are no candidates, all nodes on the layer belong to already used families
+        let mut tried_families = HashSet::new();
+        let mut result = Err(NymTopologyError::NoFamilyDisjointMixesAvailable(layer));
+        for mix in candidates {
+            let family = mix.family.as_deref();
+            if !tried_families.insert(family) {
+                continue;
+            }
+
+            if let Some(family) = family {
+                used_families.insert(family);
+            }
+            route.push(mix);
+
+            result = self.extend_family_disjoint_route(
+                rng,
+                layer + 1,
+                num_mix_hops,
+                used_families,
+                route,
+            );
+            if result.is_ok() {
+                return result;
+            }
+
+            route.pop();
+            if let Some(family) = family {
+                used_families.remove(family);
+            }
         }
 
-        Ok(route)
+        result
     }
 
     /// Tries to create a route to the specified gateway, such that it goes through mixnode on layer 1,
@@ -224,12 +287,25 @@ impl NymTopology {
     }
 }
 
+/// Builds the topology out of the provided bonds, placing the mixnodes on the layers
+/// they got assigned to for the current rewarded set. Nodes without an assignment
+/// fall back to the layer they have chosen when bonding.
 pub fn nym_topology_from_bonds(
     mix_bonds: Vec<MixNodeBond>,
     gateway_bonds: Vec<GatewayBond>,
+    layer_assignment: Vec<LayerAssignment>,
 ) -> NymTopology {
+    let layer_assignment = layer_assignment
+        .into_iter()
+        .map(|assigned| (assigned.identity, assigned.layer))
+        .collect::<HashMap<_, _>>();
+
     let mut mixes = HashMap::new();
-    for bond in mix_bonds.into_iter() {
+    for mut bond in mix_bonds.into_iter() {
+        if let Some(assigned_layer) = layer_assignment.get(bond.identity()) {
+            bond.layer = *assigned_layer;
+        }
+
         let layer = bond.layer as MixLayer;
         if layer == 0 || layer > 3 {
             warn!(
@@ -294,6 +370,7 @@ mod converting_mixes_to_vec {
                 .unwrap(),
                 layer: Layer::One,
                 version: "0.x.0".to_string(),
+                family: None,
             };
 
             let node2 = mix::Node {
@@ -328,3 +405,114 @@ mod converting_mixes_to_vec {
         }
     }
 }
+
+#[cfg(test)]
+mod random_mix_route {
+    use super::*;
+    use crypto::asymmetric::{encryption, identity};
+    use mixnet_contract_common::Layer;
+
+    fn node_fixture(layer: Layer, family: Option<&str>) -> mix::Node {
+        let mut rng = rand::thread_rng();
+        mix::Node {
+            owner: "N/A".to_string(),
+            stake: 0,
+            delegation: 0,
+            host: "3.3.3.3".parse().unwrap(),
+            mix_host: "3.3.3.3:1789".parse().unwrap(),
+            identity_key: *identity::KeyPair::new(&mut rng).public_key(),
+            sphinx_key: *encryption::KeyPair::new(&mut rng).public_key(),
+            layer,
+            version: "0.x.0".to_string(),
+            family: family.map(ToString::to_string),
+        }
+    }
+
+    #[test]
+    fn never_chooses_two_nodes_of_the_same_family() {
+        let mut mixes: HashMap<MixLayer, Vec<mix::Node>> = HashMap::new();
+        mixes.insert(
+            1,
+            vec![
+                node_fixture(Layer::One, Some("family1")),
+                node_fixture(Layer::One, Some("family1")),
+            ],
+        );
+        mixes.insert(
+            2,
+            vec![
+                node_fixture(Layer::Two, Some("family1")),
+                node_fixture(Layer::Two, None),
+            ],
+        );
+        mixes.insert(
+            3,
+            vec![
+                node_fixture(Layer::Three, Some("family1")),
+                node_fixture(Layer::Three, Some("family2")),
+            ],
+        );
+        let topology = NymTopology::new(mixes, vec![]);
+
+        let mut rng = rand::thread_rng();
+        let family_free: Vec<SphinxNode> = vec![
+            (&topology.mixes[&2][1]).into(),
+            (&topology.mixes[&3][1]).into(),
+        ];
+        for _ in 0..50 {
+            let route = topology.random_mix_route(&mut rng, 3).unwrap();
+            assert_eq!(family_free[0].pub_key, route[1].pub_key);
+            assert_eq!(family_free[1].pub_key, route[2].pub_key);
+        }
+    }
+
+    #[test]
+    fn fails_if_all_layer_nodes_belong_to_used_families() {
+        let mut mixes: HashMap<MixLayer, Vec<mix::Node>> = HashMap::new();
+        mixes.insert(1, vec![node_fixture(Layer::One, Some("family1"))]);
+        mixes.insert(2, vec![node_fixture(Layer::Two, Some("family1"))]);
+        mixes.insert(3, vec![node_fixture(Layer::Three, None)]);
+        let topology = NymTopology::new(mixes, vec![]);
+
+        assert!(matches!(
+            topology.random_mix_route(&mut rand::thread_rng(), 3),
+            Err(NymTopologyError::NoFamilyDisjointMixesAvailable(2))
+        ));
+    }
+
+    #[test]
+    fn finds_family_disjoint_route_the_greedy_choice_would_miss() {
+        // choosing family2 on the first layer (or family1 on the second one)
+        // leaves no eligible node on some further layer
+        let mut mixes: HashMap<MixLayer, Vec<mix::Node>> = HashMap::new();
+        mixes.insert(
+            1,
+            vec![
+                node_fixture(Layer::One, Some("family1")),
+                node_fixture(Layer::One, Some("family2")),
+            ],
+        );
+        mixes.insert(
+            2,
+            vec![
+                node_fixture(Layer::Two, Some("family1")),
+                node_fixture(Layer::Two, Some("family3")),
+            ],
+        );
+        mixes.insert(3, vec![node_fixture(Layer::Three, Some("family2"))]);
+        let topology = NymTopology::new(mixes, vec![]);
+
+        let mut rng = rand::thread_rng();
+        let expected: Vec<SphinxNode> = vec![
+            (&topology.mixes[&1][0]).into(),
+            (&topology.mixes[&2][1]).into(),
+            (&topology.mixes[&3][0]).into(),
+        ];
+        for _ in 0..50 {
+            let route = topology.random_mix_route(&mut rng, 3).unwrap();
+            for (expected, chosen) in expected.iter().zip(route.iter()) {
+                assert_eq!(expected.pub_key, chosen.pub_key);
+            }
+        }
+    }
+}
@@ -9,12 +9,21 @@ use crate::delegations::queries::query_all_network_delegations_paged;
 use crate::delegations::queries::query_delegator_delegations_paged;
 use crate::delegations::queries::query_mixnode_delegation;
 use crate::delegations::queries::query_mixnode_delegations_paged;
+use crate::delegations::queries::{
+    query_delegator_gateway_delegations_paged, query_gateway_delegation,
+    query_gateway_delegations_paged,
+};
 use crate::error::ContractError;
+use crate::families::queries::{
+    query_families_paged, query_family_by_member, query_family_members_paged,
+    query_family_signing_nonce,
+};
 use crate::gateways::queries::query_gateways_paged;
 use crate::gateways::queries::query_owns_gateway;
 use crate::interval::queries::{
-    query_current_interval, query_current_rewarded_set_height, query_rewarded_set,
-    query_rewarded_set_heights_for_interval, query_rewarded_set_refresh_minimum_blocks,
+    query_current_interval, query_current_rewarded_set_height, query_layer_assignment,
+    query_rewarded_set, query_rewarded_set_heights_for_interval,
+    query_rewarded_set_refresh_minimum_blocks, query_rewarded_set_seed,
     query_rewarded_set_update_details,
 };
 use crate::interval::storage as interval_storage;
@@ -27,9 +36,17 @@ use crate::mixnodes::bonding_queries as mixnode_queries;
 use crate::mixnodes::bonding_queries::query_mixnodes_paged;
 use crate::mixnodes::layer_queries::query_layer_distribution;
 use crate::rewards::queries::{
-    query_circulating_supply, query_reward_pool, query_rewarding_status,
+    query_circulating_supply, query_gateway_rewarding_status, query_pending_delegator_reward,
+    query_pending_operator_reward, query_reward_pool, query_rewarding_status,
 };
 use crate::rewards::storage as rewards_storage;
+use crate::slashing::queries::{
+    query_all_slashing_history_paged, query_mixnode_performance_streak,
+    query_mixnode_slashing_history_paged, query_pending_delegation_slashings_paged,
+};
+use crate::withdrawals::queries::{
+    query_all_pending_withdrawals_paged, query_pending_withdrawals_paged,
+};
 use cosmwasm_std::{
     entry_point, to_binary, Addr, Deps, DepsMut, Env, MessageInfo, QueryResponse, Response, Uint128,
 };
@@ -47,6 +64,9 @@ pub const INITIAL_MIXNODE_PLEDGE: Uint128 = Uint128::new(100_000_000);
 pub const INITIAL_MIXNODE_REWARDED_SET_SIZE: u32 = 200;
 pub const INITIAL_MIXNODE_ACTIVE_SET_SIZE: u32 = 100;
 
+/// Time, in seconds, during which unbonded and undelegated tokens are held before they can be claimed
+pub const INITIAL_UNBONDING_PERIOD: u64 = 14 * 24 * 60 * 60;
+
 pub const INITIAL_REWARD_POOL: u128 = 250_000_000_000_000;
 pub const INITIAL_ACTIVE_SET_WORK_FACTOR: u8 = 10;
 
@@ -62,6 +82,9 @@ fn default_initial_state(owner: Addr, rewarding_validator_address: Addr) -> Cont
             minimum_gateway_pledge: INITIAL_GATEWAY_PLEDGE,
             mixnode_rewarded_set_size: INITIAL_MIXNODE_REWARDED_SET_SIZE,
             mixnode_active_set_size: INITIAL_MIXNODE_ACTIVE_SET_SIZE,
+            unbonding_period: INITIAL_UNBONDING_PERIOD,
+            slashing: Default::default(),
+            gateway_rewarding: Default::default(),
         },
     }
 }
@@ -112,7 +135,7 @@ pub fn execute(
             owner_signature,
         ),
         ExecuteMsg::UnbondMixnode {} => {
-            crate::mixnodes::transactions::try_remove_mixnode(deps, info)
+            crate::mixnodes::transactions::try_remove_mixnode(deps, env, info)
         }
         ExecuteMsg::UpdateMixnodeConfig {
             profit_margin_percent,
@@ -132,6 +155,28 @@ pub fn execute(
             profit_margin_percent,
             owner,
         ),
+        ExecuteMsg::UpdateMixnodeDetails { details } => {
+            crate::mixnodes::transactions::try_update_mixnode_details(deps, info, details)
+        }
+        ExecuteMsg::UpdateMixnodeDetailsOnBehalf { details, owner } => {
+            crate::mixnodes::transactions::try_update_mixnode_details_on_behalf(
+                deps, info, details, owner,
+            )
+        }
+        ExecuteMsg::IncreaseMixnodePledge {} => {
+            crate::mixnodes::transactions::try_increase_mixnode_pledge(deps, info)
+        }
+        ExecuteMsg::IncreaseMixnodePledgeOnBehalf { owner } => {
+            crate::mixnodes::transactions::try_increase_mixnode_pledge_on_behalf(deps, info, owner)
+        }
+        ExecuteMsg::DecreaseMixnodePledge { amount } => {
+            crate::mixnodes::transactions::try_decrease_mixnode_pledge(deps, info, amount)
+        }
+        ExecuteMsg::DecreaseMixnodePledgeOnBehalf { amount, owner } => {
+            crate::mixnodes::transactions::try_decrease_mixnode_pledge_on_behalf(
+                deps, info, amount, owner,
+            )
+        }
         ExecuteMsg::BondGateway {
             gateway,
             owner_signature,
@@ -145,6 +190,14 @@ pub fn execute(
         ExecuteMsg::UnbondGateway {} => {
             crate::gateways::transactions::try_remove_gateway(deps, info)
         }
+        ExecuteMsg::UpdateGatewayDetails { details } => {
+            crate::gateways::transactions::try_update_gateway_details(deps, info, details)
+        }
+        ExecuteMsg::UpdateGatewayDetailsOnBehalf { details, owner } => {
+            crate::gateways::transactions::try_update_gateway_details_on_behalf(
+                deps, info, details, owner,
+            )
+        }
         ExecuteMsg::UpdateContractStateParams(params) => {
             crate::mixnet_contract_settings::transactions::try_update_contract_settings(
                 deps, info, params,
@@ -168,6 +221,7 @@ pub fn execute(
         ExecuteMsg::UndelegateFromMixnode { mix_identity } => {
             crate::delegations::transactions::try_remove_delegation_from_mixnode(
                 deps,
+                env,
                 info,
                 mix_identity,
             )
@@ -181,6 +235,27 @@ pub fn execute(
             mix_identity,
             interval_id,
         ),
+        ExecuteMsg::ReportMixnodePerformance {
+            identity,
+            params,
+            interval_id,
+        } => crate::slashing::transactions::try_report_mixnode_performance(
+            deps,
+            env,
+            info,
+            identity,
+            params,
+            interval_id,
+        ),
+        ExecuteMsg::SlashNextMixDelegators {
+            mix_identity,
+            interval_id,
+        } => crate::slashing::transactions::try_slash_next_mix_delegators(
+            deps,
+            info,
+            mix_identity,
+            interval_id,
+        ),
         ExecuteMsg::DelegateToMixnodeOnBehalf {
             mix_identity,
             delegate,
@@ -196,10 +271,19 @@ pub fn execute(
             delegate,
         } => crate::delegations::transactions::try_remove_delegation_from_mixnode_on_behalf(
             deps,
+            env,
             info,
             mix_identity,
             delegate,
         ),
+        ExecuteMsg::RedelegateToMixnode { from, to } => {
+            crate::delegations::transactions::try_redelegate_to_mixnode(deps, info, from, to)
+        }
+        ExecuteMsg::RedelegateToMixnodeOnBehalf { from, to, delegate } => {
+            crate::delegations::transactions::try_redelegate_to_mixnode_on_behalf(
+                deps, info, from, to, delegate,
+            )
+        }
         ExecuteMsg::BondMixnodeOnBehalf {
             mix_node,
             owner,
@@ -213,7 +297,7 @@ pub fn execute(
             owner_signature,
         ),
         ExecuteMsg::UnbondMixnodeOnBehalf { owner } => {
-            crate::mixnodes::transactions::try_remove_mixnode_on_behalf(deps, info, owner)
+            crate::mixnodes::transactions::try_remove_mixnode_on_behalf(deps, env, info, owner)
         }
         ExecuteMsg::BondGatewayOnBehalf {
             gateway,
@@ -233,16 +317,173 @@ pub fn execute(
         ExecuteMsg::WriteRewardedSet {
             rewarded_set,
             expected_active_set_size,
+            seed,
+            layer_assignment,
         } => crate::interval::transactions::try_write_rewarded_set(
             deps,
             env,
             info,
             rewarded_set,
             expected_active_set_size,
+            seed,
+            layer_assignment,
         ),
         ExecuteMsg::AdvanceCurrentInterval {} => {
             crate::interval::transactions::try_advance_interval(env, deps.storage)
         }
+        ExecuteMsg::ClaimPendingWithdrawals {} => {
+            crate::withdrawals::transactions::try_claim_pending_withdrawals(deps, env, info)
+        }
+        ExecuteMsg::ClaimPendingWithdrawalsOnBehalf { owner } => {
+            crate::withdrawals::transactions::try_claim_pending_withdrawals_on_behalf(
+                deps, env, info, owner,
+            )
+        }
+        ExecuteMsg::DelegateToGateway { gateway_identity } => {
+            crate::delegations::transactions::try_delegate_to_gateway(
+                deps,
+                env,
+                info,
+                gateway_identity,
+            )
+        }
+        ExecuteMsg::UndelegateFromGateway { gateway_identity } => {
+            crate::delegations::transactions::try_remove_delegation_from_gateway(
+                deps,
+                env,
+                info,
+                gateway_identity,
+            )
+        }
+        ExecuteMsg::DelegateToGatewayOnBehalf {
+            gateway_identity,
+            delegate,
+        } => crate::delegations::transactions::try_delegate_to_gateway_on_behalf(
+            deps,
+            env,
+            info,
+            gateway_identity,
+            delegate,
+        ),
+        ExecuteMsg::UndelegateFromGatewayOnBehalf {
+            gateway_identity,
+            delegate,
+        } => crate::delegations::transactions::try_remove_delegation_from_gateway_on_behalf(
+            deps,
+            env,
+            info,
+            gateway_identity,
+            delegate,
+        ),
+        ExecuteMsg::RewardGateway {
+            identity,
+            params,
+            interval_id,
+        } => crate::rewards::transactions::try_reward_gateway(
+            deps,
+            env,
+            info,
+            identity,
+            params,
+            interval_id,
+        ),
+        ExecuteMsg::RewardNextGatewayDelegators {
+            gateway_identity,
+            interval_id,
+        } => crate::rewards::transactions::try_reward_next_gateway_delegators(
+            deps,
+            info,
+            gateway_identity,
+            interval_id,
+        ),
+        ExecuteMsg::CreateFamily {
+            owner_signature,
+            label,
+        } => crate::families::transactions::try_create_family(deps, info, owner_signature, label),
+        ExecuteMsg::JoinFamily {
+            owner_signature,
+            family_head,
+            join_permit,
+        } => crate::families::transactions::try_join_family(
+            deps,
+            info,
+            owner_signature,
+            family_head,
+            join_permit,
+        ),
+        ExecuteMsg::LeaveFamily { owner_signature } => {
+            crate::families::transactions::try_leave_family(deps, info, owner_signature)
+        }
+        ExecuteMsg::ClaimOperatorReward {} => {
+            crate::rewards::transactions::try_claim_operator_reward(deps, info)
+        }
+        ExecuteMsg::ClaimOperatorRewardOnBehalf { owner } => {
+            crate::rewards::transactions::try_claim_operator_reward_on_behalf(deps, info, owner)
+        }
+        ExecuteMsg::ClaimDelegatorReward { mix_identity } => {
+            crate::rewards::transactions::try_claim_delegator_reward(deps, info, mix_identity)
+        }
+        ExecuteMsg::ClaimDelegatorRewardOnBehalf {
+            mix_identity,
+            delegate,
+        } => crate::rewards::transactions::try_claim_delegator_reward_on_behalf(
+            deps,
+            info,
+            mix_identity,
+            delegate,
+        ),
+        ExecuteMsg::CompoundDelegatorReward { mix_identity } => {
+            crate::rewards::transactions::try_compound_delegator_reward(deps, info, mix_identity)
+        }
+        ExecuteMsg::CompoundDelegatorRewardOnBehalf {
+            mix_identity,
+            delegate,
+        } => crate::rewards::transactions::try_compound_delegator_reward_on_behalf(
+            deps,
+            info,
+            mix_identity,
+            delegate,
+        ),
+        ExecuteMsg::ClaimGatewayOperatorReward {} => {
+            crate::rewards::transactions::try_claim_gateway_operator_reward(deps, info)
+        }
+        ExecuteMsg::ClaimGatewayOperatorRewardOnBehalf { owner } => {
+            crate::rewards::transactions::try_claim_gateway_operator_reward_on_behalf(
+                deps, info, owner,
+            )
+        }
+        ExecuteMsg::ClaimGatewayDelegatorReward { gateway_identity } => {
+            crate::rewards::transactions::try_claim_gateway_delegator_reward(
+                deps,
+                info,
+                gateway_identity,
+            )
+        }
+        ExecuteMsg::ClaimGatewayDelegatorRewardOnBehalf {
+            gateway_identity,
+            delegate,
+        } => crate::rewards::transactions::try_claim_gateway_delegator_reward_on_behalf(
+            deps,
+            info,
+            gateway_identity,
+            delegate,
+        ),
+        ExecuteMsg::CompoundGatewayDelegatorReward { gateway_identity } => {
+            crate::rewards::transactions::try_compound_gateway_delegator_reward(
+                deps,
+                info,
+                gateway_identity,
+            )
+        }
+        ExecuteMsg::CompoundGatewayDelegatorRewardOnBehalf {
+            gateway_identity,
+            delegate,
+        } => crate::rewards::transactions::try_compound_gateway_delegator_reward_on_behalf(
+            deps,
+            info,
+            gateway_identity,
+            delegate,
+        ),
     }
 }
 
@@ -317,10 +558,124 @@ pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> Result<QueryResponse, C
         QueryMsg::GetCurrentRewardedSetHeight {} => {
             to_binary(&query_current_rewarded_set_height(deps.storage)?)
         }
+        QueryMsg::GetRewardedSetSeed { height } => {
+            to_binary(&query_rewarded_set_seed(deps.storage, height)?)
+        }
+        QueryMsg::GetLayerAssignment {
+            height,
+            start_after,
+            limit,
+        } => to_binary(&query_layer_assignment(
+            deps.storage,
+            height,
+            start_after,
+            limit,
+        )?),
         QueryMsg::GetCurrentInterval {} => to_binary(&query_current_interval(deps.storage)?),
+        QueryMsg::GetPendingPledgeChange { mix_identity } => to_binary(
+            &mixnode_queries::query_pending_pledge_change(deps, mix_identity)?,
+        ),
         QueryMsg::GetRewardedSetRefreshBlocks {} => {
             to_binary(&query_rewarded_set_refresh_minimum_blocks())
         }
+        QueryMsg::GetPendingWithdrawals {
+            owner,
+            start_after,
+            limit,
+        } => to_binary(&query_pending_withdrawals_paged(
+            deps,
+            owner,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::GetAllPendingWithdrawals { start_after, limit } => to_binary(
+            &query_all_pending_withdrawals_paged(deps, start_after, limit)?,
+        ),
+        QueryMsg::GetMixnodePerformanceStreak { mix_identity } => {
+            to_binary(&query_mixnode_performance_streak(deps, mix_identity)?)
+        }
+        QueryMsg::GetMixnodeSlashingHistory {
+            mix_identity,
+            start_after,
+            limit,
+        } => to_binary(&query_mixnode_slashing_history_paged(
+            deps,
+            mix_identity,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::GetAllSlashingHistory { start_after, limit } => {
+            to_binary(&query_all_slashing_history_paged(deps, start_after, limit)?)
+        }
+        QueryMsg::GetPendingDelegationSlashings { start_after, limit } => to_binary(
+            &query_pending_delegation_slashings_paged(deps, start_after, limit)?,
+        ),
+        QueryMsg::GetGatewayDelegations {
+            gateway_identity,
+            start_after,
+            limit,
+        } => to_binary(&query_gateway_delegations_paged(
+            deps,
+            gateway_identity,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::GetDelegatorGatewayDelegations {
+            delegator,
+            start_after,
+            limit,
+        } => to_binary(&query_delegator_gateway_delegations_paged(
+            deps,
+            delegator,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::GetGatewayDelegationDetails {
+            gateway_identity,
+            delegator,
+        } => to_binary(&query_gateway_delegation(
+            deps,
+            gateway_identity,
+            delegator,
+        )?),
+        QueryMsg::GetGatewayRewardingStatus {
+            gateway_identity,
+            interval_id,
+        } => to_binary(&query_gateway_rewarding_status(
+            deps,
+            gateway_identity,
+            interval_id,
+        )?),
+        QueryMsg::GetFamiliesPaged { start_after, limit } => {
+            to_binary(&query_families_paged(deps, start_after, limit)?)
+        }
+        QueryMsg::GetFamilyMembersPaged {
+            family_head,
+            start_after,
+            limit,
+        } => to_binary(&query_family_members_paged(
+            deps,
+            family_head,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::GetFamilyByMember { member } => to_binary(&query_family_by_member(deps, member)?),
+        QueryMsg::GetFamilySigningNonce { identity } => {
+            to_binary(&query_family_signing_nonce(deps, identity)?)
+        }
+        QueryMsg::GetPendingOperatorReward { mix_identity } => {
+            to_binary(&query_pending_operator_reward(deps, mix_identity)?)
+        }
+        QueryMsg::GetPendingDelegatorReward {
+            mix_identity,
+            delegator,
+            proxy,
+        } => to_binary(&query_pending_delegator_reward(
+            deps,
+            mix_identity,
+            delegator,
+            proxy,
+        )?),
     };
 
     Ok(query_res?)
@@ -368,6 +723,9 @@ pub fn migrate(deps: DepsMut<'_>, env: Env, _msg: MigrateMsg) -> Result<Response
         minimum_gateway_pledge: old_state.params.minimum_mixnode_pledge,
         mixnode_rewarded_set_size: old_state.params.mixnode_rewarded_set_size,
         mixnode_active_set_size: old_state.params.mixnode_active_set_size,
+        unbonding_period: INITIAL_UNBONDING_PERIOD,
+        slashing: Default::default(),
+        gateway_rewarding: Default::default(),
     };
 
     let new_state = crate::mixnet_contract_settings::models::ContractState {
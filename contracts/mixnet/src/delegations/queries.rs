@@ -123,6 +123,89 @@ pub(crate) fn query_mixnode_delegations_paged(
     ))
 }
 
+pub(crate) fn query_gateway_delegations_paged(
+    deps: Deps<'_>,
+    gateway_identity: IdentityKey,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<PagedMixDelegationsResponse> {
+    let limit = limit
+        .unwrap_or(storage::DELEGATION_PAGE_DEFAULT_LIMIT)
+        .min(storage::DELEGATION_PAGE_MAX_LIMIT) as usize;
+
+    let start = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?
+        .map(|addr| Bound::Exclusive((gateway_identity.clone(), addr).joined_key()));
+
+    let delegations = storage::gateway_delegations()
+        .idx
+        .gateway
+        .prefix(gateway_identity)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|record| record.map(|r| r.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let start_next_after = delegations.last().map(|delegation| delegation.owner());
+
+    Ok(PagedMixDelegationsResponse::new(
+        delegations,
+        start_next_after,
+    ))
+}
+
+pub(crate) fn query_delegator_gateway_delegations_paged(
+    deps: Deps<'_>,
+    delegation_owner: String,
+    start_after: Option<IdentityKey>,
+    limit: Option<u32>,
+) -> StdResult<PagedDelegatorDelegationsResponse> {
+    let validated_owner = deps.api.addr_validate(&delegation_owner)?;
+
+    let limit = limit
+        .unwrap_or(storage::DELEGATION_PAGE_DEFAULT_LIMIT)
+        .min(storage::DELEGATION_PAGE_MAX_LIMIT) as usize;
+    let start = start_after.map(|gateway_identity| {
+        Bound::Exclusive((gateway_identity, validated_owner.clone()).joined_key())
+    });
+
+    let delegations = storage::gateway_delegations()
+        .idx
+        .owner
+        .prefix(validated_owner)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|record| record.map(|r| r.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let start_next_after = delegations
+        .last()
+        .map(|delegation| delegation.node_identity());
+
+    Ok(PagedDelegatorDelegationsResponse::new(
+        delegations,
+        start_next_after,
+    ))
+}
+
+// queries for delegation value of given address for particular gateway
+pub(crate) fn query_gateway_delegation(
+    deps: Deps<'_>,
+    gateway_identity: IdentityKey,
+    delegator: String,
+) -> Result<Delegation, ContractError> {
+    let validated_delegator = deps.api.addr_validate(&delegator)?;
+    let storage_key = (gateway_identity.clone(), validated_delegator.clone()).joined_key();
+
+    storage::gateway_delegations()
+        .may_load(deps.storage, storage_key)?
+        .ok_or(ContractError::NoGatewayDelegationFound {
+            identity: gateway_identity,
+            address: validated_delegator,
+        })
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
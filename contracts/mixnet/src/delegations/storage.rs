@@ -8,6 +8,9 @@ use mixnet_contract_common::{Addr, Delegation, IdentityKey};
 const DELEGATION_PK_NAMESPACE: &str = "dl";
 const DELEGATION_OWNER_IDX_NAMESPACE: &str = "dlo";
 const DELEGATION_MIXNODE_IDX_NAMESPACE: &str = "dlm";
+const GATEWAY_DELEGATION_PK_NAMESPACE: &str = "gdl";
+const GATEWAY_DELEGATION_OWNER_IDX_NAMESPACE: &str = "gdlo";
+const GATEWAY_DELEGATION_GATEWAY_IDX_NAMESPACE: &str = "gdlg";
 
 // paged retrieval limits for all queries and transactions
 pub(crate) const DELEGATION_PAGE_MAX_LIMIT: u32 = 500;
@@ -60,6 +63,40 @@ pub(crate) fn delegations<'a>() -> IndexedMap<'a, PrimaryKey, Delegation, Delega
     IndexedMap::new(DELEGATION_PK_NAMESPACE, indexes)
 }
 
+pub(crate) struct GatewayDelegationIndex<'a> {
+    pub(crate) owner: MultiIndex<'a, Addr, Delegation>,
+
+    pub(crate) gateway: MultiIndex<'a, IdentityKey, Delegation>,
+}
+
+impl<'a> IndexList<Delegation> for GatewayDelegationIndex<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Delegation>> + '_> {
+        let v: Vec<&dyn Index<Delegation>> = vec![&self.owner, &self.gateway];
+        Box::new(v.into_iter())
+    }
+}
+
+// gateway delegations are kept completely separate from the mixnode ones so that none of the
+// existing mixnode delegation queries and rewarding would ever have to filter them out.
+// Apart from that, they're stored in exactly the same way, i.e. keyed by (gateway identity, delegator)
+pub(crate) fn gateway_delegations<'a>(
+) -> IndexedMap<'a, PrimaryKey, Delegation, GatewayDelegationIndex<'a>> {
+    let indexes = GatewayDelegationIndex {
+        owner: MultiIndex::new(
+            |d| d.owner.clone(),
+            GATEWAY_DELEGATION_PK_NAMESPACE,
+            GATEWAY_DELEGATION_OWNER_IDX_NAMESPACE,
+        ),
+        gateway: MultiIndex::new(
+            |d| d.node_identity.clone(),
+            GATEWAY_DELEGATION_PK_NAMESPACE,
+            GATEWAY_DELEGATION_GATEWAY_IDX_NAMESPACE,
+        ),
+    };
+
+    IndexedMap::new(GATEWAY_DELEGATION_PK_NAMESPACE, indexes)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::delegations::storage;
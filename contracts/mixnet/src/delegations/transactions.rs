@@ -2,13 +2,24 @@
 // SPDX-License-Identifier: Apache-2.0
 use super::storage;
 use crate::error::ContractError;
+use crate::gateways::storage as gateways_storage;
+use crate::interval::storage as interval_storage;
 use crate::mixnodes::storage as mixnodes_storage;
+use crate::rewards::storage as rewards_storage;
+use crate::slashing::transactions::ensure_delegations_not_being_slashed;
 use crate::support::helpers::generate_storage_key;
+use crate::withdrawals::storage as withdrawals_storage;
+use crate::withdrawals::transactions as withdrawals_transactions;
 use config::defaults::DENOM;
-use cosmwasm_std::{coins, wasm_execute, Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{
+    coins, wasm_execute, Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Response, Storage,
+};
 use cw_storage_plus::PrimaryKey;
-use mixnet_contract_common::events::{new_delegation_event, new_undelegation_event};
-use mixnet_contract_common::{Delegation, IdentityKey};
+use mixnet_contract_common::events::{
+    new_delegation_event, new_gateway_delegation_event, new_gateway_undelegation_event,
+    new_redelegation_event, new_undelegation_event,
+};
+use mixnet_contract_common::{Delegation, IdentityKey, PendingWithdrawalKind};
 use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
 use vesting_contract_common::one_ucoin;
 
@@ -86,6 +97,24 @@ pub(crate) fn _try_delegate_to_mixnode(
             identity: mix_identity,
         });
     }
+    ensure_delegations_not_being_slashed(deps.storage, &mix_identity)?;
+
+    // the vesting contract only stops tracking an undelegation once the tokens are claimed
+    // and it does so for all delegations towards the node at once, so don't let it delegate
+    // to the same node again before that happens
+    if proxy.is_some()
+        && withdrawals_storage::has_pending_undelegation(
+            deps.storage,
+            delegate.clone(),
+            proxy.as_ref(),
+            &mix_identity,
+        )?
+    {
+        return Err(ContractError::UndelegationStillPending {
+            owner: delegate,
+            identity: mix_identity,
+        });
+    }
 
     let maybe_proxy_storage = generate_storage_key(&delegate, proxy.as_ref());
     let storage_key = (mix_identity.clone(), maybe_proxy_storage).joined_key();
@@ -133,28 +162,33 @@ pub(crate) fn _try_delegate_to_mixnode(
 
 pub(crate) fn try_remove_delegation_from_mixnode(
     deps: DepsMut<'_>,
+    env: Env,
     info: MessageInfo,
     mix_identity: IdentityKey,
 ) -> Result<Response, ContractError> {
-    _try_remove_delegation_from_mixnode(deps, mix_identity, info.sender.as_str(), None)
+    _try_remove_delegation_from_mixnode(deps, env, mix_identity, info.sender.as_str(), None)
 }
 
 pub(crate) fn try_remove_delegation_from_mixnode_on_behalf(
     deps: DepsMut<'_>,
+    env: Env,
     info: MessageInfo,
     mix_identity: IdentityKey,
     delegate: String,
 ) -> Result<Response, ContractError> {
-    _try_remove_delegation_from_mixnode(deps, mix_identity, &delegate, Some(info.sender))
+    _try_remove_delegation_from_mixnode(deps, env, mix_identity, &delegate, Some(info.sender))
 }
 
 pub(crate) fn _try_remove_delegation_from_mixnode(
     deps: DepsMut<'_>,
+    env: Env,
     mix_identity: IdentityKey,
     delegate: &str,
     proxy: Option<Addr>,
 ) -> Result<Response, ContractError> {
     let delegate = deps.api.addr_validate(delegate)?;
+    ensure_delegations_not_being_slashed(deps.storage, &mix_identity)?;
+
     let delegation_map = storage::delegations();
     let maybe_proxy_storage = generate_storage_key(&delegate, proxy.as_ref());
     let storage_key = (mix_identity.clone(), maybe_proxy_storage).joined_key();
@@ -179,15 +213,6 @@ pub(crate) fn _try_remove_delegation_from_mixnode(
             // and we've already performed `may_load` and have access to pre-existing data
             delegation_map.replace(deps.storage, storage_key, None, Some(&old_delegation))?;
 
-            // send delegated funds back to the delegation owner
-            let return_tokens = BankMsg::Send {
-                to_address: proxy.as_ref().unwrap_or(&delegate).to_string(),
-                amount: coins(
-                    old_delegation.amount.amount.u128(),
-                    old_delegation.amount.denom.clone(),
-                ),
-            };
-
             // update total_delegation of this node
             mixnodes_storage::TOTAL_DELEGATION.update::<_, ContractError>(
                 deps.storage,
@@ -204,19 +229,46 @@ pub(crate) fn _try_remove_delegation_from_mixnode(
                 },
             )?;
 
-            let mut response = Response::new().add_message(return_tokens);
-
-            if let Some(proxy) = &proxy {
-                let msg = Some(VestingContractExecuteMsg::TrackUndelegation {
-                    owner: delegate.as_str().to_string(),
-                    mix_identity: mix_identity.clone(),
-                    amount: old_delegation.amount.clone(),
-                });
-
-                let track_undelegation_msg = wasm_execute(proxy, &msg, vec![one_ucoin()])?;
+            let unbonding_period = withdrawals_transactions::unbonding_period(deps.storage)?;
+            let response = if unbonding_period > 0 {
+                // the funds are held by the contract until the unbonding period is over
+                withdrawals_transactions::hold_pending_withdrawal(
+                    deps.storage,
+                    &env,
+                    delegate.clone(),
+                    proxy.clone(),
+                    old_delegation.amount.clone(),
+                    PendingWithdrawalKind::Undelegation {
+                        mix_identity: mix_identity.clone(),
+                    },
+                    unbonding_period,
+                )?
+            } else {
+                // send delegated funds back to the delegation owner
+                let return_tokens = BankMsg::Send {
+                    to_address: proxy.as_ref().unwrap_or(&delegate).to_string(),
+                    amount: coins(
+                        old_delegation.amount.amount.u128(),
+                        old_delegation.amount.denom.clone(),
+                    ),
+                };
+
+                let mut response = Response::new().add_message(return_tokens);
+
+                if let Some(proxy) = &proxy {
+                    let msg = Some(VestingContractExecuteMsg::TrackUndelegation {
+                        owner: delegate.as_str().to_string(),
+                        mix_identity: mix_identity.clone(),
+                        amount: old_delegation.amount.clone(),
+                    });
+
+                    let track_undelegation_msg = wasm_execute(proxy, &msg, vec![one_ucoin()])?;
+
+                    response = response.add_message(track_undelegation_msg);
+                }
+                response
+            };
 
-                response = response.add_message(track_undelegation_msg);
-            }
             Ok(response.add_event(new_undelegation_event(
                 &delegate,
                 &proxy,
@@ -227,6 +279,391 @@ pub(crate) fn _try_remove_delegation_from_mixnode(
     }
 }
 
+pub(crate) fn try_redelegate_to_mixnode(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    from: IdentityKey,
+    to: IdentityKey,
+) -> Result<Response, ContractError> {
+    _try_redelegate_to_mixnode(deps, from, to, info.sender.as_str(), None)
+}
+
+pub(crate) fn try_redelegate_to_mixnode_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    from: IdentityKey,
+    to: IdentityKey,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    _try_redelegate_to_mixnode(deps, from, to, &delegate, Some(info.sender))
+}
+
+// a delegation that has already been rewarded by one node during the current interval must not be
+// rewarded again by the other one, nor should it miss out on the rewards if neither node got them yet
+fn ensure_not_mid_rewarding(
+    storage: &dyn Storage,
+    from: &IdentityKey,
+    to: &IdentityKey,
+) -> Result<(), ContractError> {
+    let interval_id = interval_storage::CURRENT_INTERVAL.load(storage)?.id();
+    let from_rewarded = rewards_storage::REWARDING_STATUS.has(storage, (interval_id, from.clone()));
+    let to_rewarded = rewards_storage::REWARDING_STATUS.has(storage, (interval_id, to.clone()));
+
+    if from_rewarded != to_rewarded {
+        Err(ContractError::RedelegationDuringRewarding {
+            from: from.clone(),
+            to: to.clone(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn _try_redelegate_to_mixnode(
+    deps: DepsMut<'_>,
+    from: IdentityKey,
+    to: IdentityKey,
+    delegate: &str,
+    proxy: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(delegate)?;
+
+    if from == to {
+        return Err(ContractError::RedelegationToSameMixnode { identity: from });
+    }
+
+    // check if the target node actually exists
+    if mixnodes_storage::mixnodes()
+        .may_load(deps.storage, &to)?
+        .is_none()
+    {
+        return Err(ContractError::MixNodeBondNotFound { identity: to });
+    }
+    ensure_delegations_not_being_slashed(deps.storage, &from)?;
+    ensure_delegations_not_being_slashed(deps.storage, &to)?;
+
+    // same reasoning as when delegating via a proxy
+    if proxy.is_some()
+        && withdrawals_storage::has_pending_undelegation(
+            deps.storage,
+            delegate.clone(),
+            proxy.as_ref(),
+            &to,
+        )?
+    {
+        return Err(ContractError::UndelegationStillPending {
+            owner: delegate,
+            identity: to,
+        });
+    }
+
+    ensure_not_mid_rewarding(deps.storage, &from, &to)?;
+
+    let delegation_map = storage::delegations();
+    let maybe_proxy_storage = generate_storage_key(&delegate, proxy.as_ref());
+    let old_storage_key = (from.clone(), maybe_proxy_storage.clone()).joined_key();
+
+    let old_delegation = match delegation_map.may_load(deps.storage, old_storage_key.clone())? {
+        None => {
+            return Err(ContractError::NoMixnodeDelegationFound {
+                identity: from,
+                address: delegate,
+            })
+        }
+        Some(old_delegation) => old_delegation,
+    };
+
+    if proxy != old_delegation.proxy {
+        return Err(ContractError::ProxyMismatch {
+            existing: old_delegation
+                .proxy
+                .map_or_else(|| "None".to_string(), |a| a.to_string()),
+            incoming: proxy.map_or_else(|| "None".to_string(), |a| a.to_string()),
+        });
+    }
+
+    delegation_map.replace(deps.storage, old_storage_key, None, Some(&old_delegation))?;
+
+    // move the stake between total delegations of both nodes
+    mixnodes_storage::TOTAL_DELEGATION.update::<_, ContractError>(
+        deps.storage,
+        &from,
+        |total_delegation| {
+            // the unwraps are fine for exactly the same reasons as during undelegation
+            Ok(total_delegation
+                .unwrap()
+                .checked_sub(old_delegation.amount.amount)
+                .unwrap())
+        },
+    )?;
+    mixnodes_storage::TOTAL_DELEGATION.update::<_, ContractError>(
+        deps.storage,
+        &to,
+        |total_delegation| {
+            // the target node exists, so its total delegation entry MUST exist as well
+            Ok(total_delegation.unwrap() + old_delegation.amount.amount)
+        },
+    )?;
+
+    // the delegation keeps its original block height so that it would remain eligible for rewards.
+    // If there already was a delegation towards the target node, the more recent of the two heights is used,
+    // so that none of the stake becomes eligible earlier than it would have otherwise
+    let new_storage_key = (to.clone(), maybe_proxy_storage).joined_key();
+    let new_delegation = delegation_map.update::<_, ContractError>(
+        deps.storage,
+        new_storage_key,
+        |existing_delegation| {
+            Ok(match existing_delegation {
+                Some(mut existing_delegation) => {
+                    let block_height = existing_delegation
+                        .block_height
+                        .max(old_delegation.block_height);
+                    existing_delegation
+                        .increment_amount(old_delegation.amount.amount, Some(block_height));
+                    existing_delegation
+                }
+                None => Delegation::new(
+                    delegate.clone(),
+                    to.clone(),
+                    old_delegation.amount.clone(),
+                    old_delegation.block_height,
+                    proxy.clone(),
+                ),
+            })
+        },
+    )?;
+
+    Ok(Response::new().add_event(new_redelegation_event(
+        &delegate,
+        &proxy,
+        &old_delegation.amount,
+        &from,
+        &to,
+        new_delegation.block_height,
+    )))
+}
+
+pub(crate) fn try_delegate_to_gateway(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    gateway_identity: IdentityKey,
+) -> Result<Response, ContractError> {
+    // check if the delegation contains any funds of the appropriate denomination
+    let amount = validate_delegation_stake(info.funds)?;
+
+    _try_delegate_to_gateway(
+        deps,
+        env,
+        gateway_identity,
+        info.sender.as_str(),
+        amount,
+        None,
+    )
+}
+
+pub(crate) fn try_delegate_to_gateway_on_behalf(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    gateway_identity: IdentityKey,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    // check if the delegation contains any funds of the appropriate denomination
+    let amount = validate_delegation_stake(info.funds)?;
+
+    _try_delegate_to_gateway(
+        deps,
+        env,
+        gateway_identity,
+        &delegate,
+        amount,
+        Some(info.sender),
+    )
+}
+
+pub(crate) fn _try_delegate_to_gateway(
+    deps: DepsMut<'_>,
+    env: Env,
+    gateway_identity: IdentityKey,
+    delegate: &str,
+    amount: Coin,
+    proxy: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(delegate)?;
+
+    // check if the target gateway actually exists
+    if gateways_storage::gateways()
+        .may_load(deps.storage, &gateway_identity)?
+        .is_none()
+    {
+        return Err(ContractError::GatewayBondNotFound {
+            identity: gateway_identity,
+        });
+    }
+
+    // the same restriction as with mixnode delegations applies, the vesting contract
+    // stops tracking all delegations towards the gateway at once
+    if proxy.is_some()
+        && withdrawals_storage::has_pending_gateway_undelegation(
+            deps.storage,
+            delegate.clone(),
+            proxy.as_ref(),
+            &gateway_identity,
+        )?
+    {
+        return Err(ContractError::UndelegationStillPending {
+            owner: delegate,
+            identity: gateway_identity,
+        });
+    }
+
+    let maybe_proxy_storage = generate_storage_key(&delegate, proxy.as_ref());
+    let storage_key = (gateway_identity.clone(), maybe_proxy_storage).joined_key();
+
+    // update total delegation of this gateway. Note that, unlike with mixnodes, the entry might
+    // not exist yet if the gateway has been bonded before gateway delegations were introduced
+    gateways_storage::TOTAL_GATEWAY_DELEGATION.update::<_, ContractError>(
+        deps.storage,
+        &gateway_identity,
+        |total_delegation| Ok(total_delegation.unwrap_or_default() + amount.amount),
+    )?;
+
+    // update [or create new] delegation of this delegator
+    storage::gateway_delegations().update::<_, ContractError>(
+        deps.storage,
+        storage_key,
+        |existing_delegation| {
+            Ok(match existing_delegation {
+                Some(mut existing_delegation) => {
+                    existing_delegation.increment_amount(amount.amount, Some(env.block.height));
+                    existing_delegation
+                }
+                None => Delegation::new(
+                    delegate.clone(),
+                    gateway_identity.clone(),
+                    amount.clone(),
+                    env.block.height,
+                    proxy.clone(),
+                ),
+            })
+        },
+    )?;
+
+    Ok(Response::new().add_event(new_gateway_delegation_event(
+        &delegate,
+        &proxy,
+        &amount,
+        &gateway_identity,
+    )))
+}
+
+pub(crate) fn try_remove_delegation_from_gateway(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    gateway_identity: IdentityKey,
+) -> Result<Response, ContractError> {
+    _try_remove_delegation_from_gateway(deps, env, gateway_identity, info.sender.as_str(), None)
+}
+
+pub(crate) fn try_remove_delegation_from_gateway_on_behalf(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    gateway_identity: IdentityKey,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    _try_remove_delegation_from_gateway(deps, env, gateway_identity, &delegate, Some(info.sender))
+}
+
+pub(crate) fn _try_remove_delegation_from_gateway(
+    deps: DepsMut<'_>,
+    env: Env,
+    gateway_identity: IdentityKey,
+    delegate: &str,
+    proxy: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(delegate)?;
+    let delegation_map = storage::gateway_delegations();
+    let maybe_proxy_storage = generate_storage_key(&delegate, proxy.as_ref());
+    let storage_key = (gateway_identity.clone(), maybe_proxy_storage).joined_key();
+
+    let old_delegation = match delegation_map.may_load(deps.storage, storage_key.clone())? {
+        Some(delegation) => delegation,
+        None => {
+            return Err(ContractError::NoGatewayDelegationFound {
+                identity: gateway_identity,
+                address: delegate,
+            })
+        }
+    };
+
+    if proxy != old_delegation.proxy {
+        return Err(ContractError::ProxyMismatch {
+            existing: old_delegation
+                .proxy
+                .map_or_else(|| "None".to_string(), |a| a.to_string()),
+            incoming: proxy.map_or_else(|| "None".to_string(), |a| a.to_string()),
+        });
+    }
+
+    delegation_map.replace(deps.storage, storage_key, None, Some(&old_delegation))?;
+
+    gateways_storage::TOTAL_GATEWAY_DELEGATION.update::<_, ContractError>(
+        deps.storage,
+        &gateway_identity,
+        |total_delegation| {
+            // the same reasoning as with mixnode undelegation applies here,
+            // the entry MUST exist and it must not underflow
+            Ok(total_delegation
+                .unwrap()
+                .checked_sub(old_delegation.amount.amount)
+                .unwrap())
+        },
+    )?;
+
+    let unbonding_period = withdrawals_transactions::unbonding_period(deps.storage)?;
+    let response = if unbonding_period > 0 {
+        withdrawals_transactions::hold_pending_withdrawal(
+            deps.storage,
+            &env,
+            delegate.clone(),
+            proxy.clone(),
+            old_delegation.amount.clone(),
+            PendingWithdrawalKind::GatewayUndelegation {
+                gateway_identity: gateway_identity.clone(),
+            },
+            unbonding_period,
+        )?
+    } else {
+        let mut response = Response::new().add_message(BankMsg::Send {
+            to_address: proxy.as_ref().unwrap_or(&delegate).to_string(),
+            amount: vec![old_delegation.amount.clone()],
+        });
+
+        if let Some(proxy) = &proxy {
+            let msg = Some(VestingContractExecuteMsg::TrackGatewayUndelegation {
+                owner: delegate.as_str().to_string(),
+                gateway_identity: gateway_identity.clone(),
+                amount: old_delegation.amount.clone(),
+            });
+
+            let track_undelegation_msg = wasm_execute(proxy, &msg, vec![one_ucoin()])?;
+
+            response = response.add_message(track_undelegation_msg);
+        }
+        response
+    };
+
+    Ok(response.add_event(new_gateway_undelegation_event(
+        &delegate,
+        &proxy,
+        &old_delegation,
+        &gateway_identity,
+    )))
+}
+
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::coins;
@@ -364,7 +801,7 @@ mod tests {
                 deps.as_mut(),
             );
             let delegation_owner = Addr::unchecked("sender");
-            try_remove_mixnode(deps.as_mut(), mock_info(mixnode_owner, &[])).unwrap();
+            try_remove_mixnode(deps.as_mut(), mock_env(), mock_info(mixnode_owner, &[])).unwrap();
             assert_eq!(
                 Err(ContractError::MixNodeBondNotFound {
                     identity: identity.clone()
@@ -387,7 +824,7 @@ mod tests {
                 tests::fixtures::good_mixnode_pledge(),
                 deps.as_mut(),
             );
-            try_remove_mixnode(deps.as_mut(), mock_info(mixnode_owner, &[])).unwrap();
+            try_remove_mixnode(deps.as_mut(), mock_env(), mock_info(mixnode_owner, &[])).unwrap();
             let identity = test_helpers::add_mixnode(
                 mixnode_owner,
                 tests::fixtures::good_mixnode_pledge(),
@@ -591,7 +1028,7 @@ mod tests {
                 identity.clone(),
             )
             .unwrap();
-            try_remove_mixnode(deps.as_mut(), mock_info(mixnode_owner, &[])).unwrap();
+            try_remove_mixnode(deps.as_mut(), mock_env(), mock_info(mixnode_owner, &[])).unwrap();
             assert_eq!(
                 Err(ContractError::MixNodeBondNotFound {
                     identity: identity.clone()
@@ -714,7 +1151,7 @@ mod tests {
                 identity.clone(),
             )
             .unwrap();
-            try_remove_mixnode(deps.as_mut(), mock_info(mixnode_owner, &[])).unwrap();
+            try_remove_mixnode(deps.as_mut(), mock_env(), mock_info(mixnode_owner, &[])).unwrap();
 
             let expected = Delegation::new(
                 delegation_owner.clone(),
@@ -740,8 +1177,11 @@ mod tests {
         use cosmwasm_std::Addr;
         use cosmwasm_std::Uint128;
 
+        use crate::contract::INITIAL_UNBONDING_PERIOD;
         use crate::mixnodes::transactions::try_remove_mixnode;
         use crate::support::tests;
+        use mixnet_contract_common::events::new_pending_withdrawal_event;
+        use mixnet_contract_common::PendingWithdrawal;
 
         use super::storage;
         use super::*;
@@ -763,6 +1203,7 @@ mod tests {
                 }),
                 try_remove_delegation_from_mixnode(
                     deps.as_mut(),
+                    mock_env(),
                     mock_info(delegation_owner.as_str(), &[]),
                     identity,
                 )
@@ -793,11 +1234,19 @@ mod tests {
             )
             .unwrap();
 
+            // the funds are held until the unbonding period is over
+            let expected_withdrawal = PendingWithdrawal::new(
+                1,
+                delegation_owner.clone(),
+                None,
+                coin(100, DENOM),
+                PendingWithdrawalKind::Undelegation {
+                    mix_identity: identity.clone(),
+                },
+                mock_env().block.time.seconds() + INITIAL_UNBONDING_PERIOD,
+            );
             let expected_response = Response::new()
-                .add_message(BankMsg::Send {
-                    to_address: delegation_owner.clone().into(),
-                    amount: coins(100, DENOM),
-                })
+                .add_event(new_pending_withdrawal_event(&expected_withdrawal))
                 .add_event(new_undelegation_event(
                     &delegation_owner,
                     &None,
@@ -809,6 +1258,7 @@ mod tests {
                 Ok(expected_response),
                 try_remove_delegation_from_mixnode(
                     deps.as_mut(),
+                    mock_env(),
                     mock_info(delegation_owner.as_str(), &[]),
                     identity.clone(),
                 )
@@ -853,11 +1303,19 @@ mod tests {
                 delegation_owner.clone().into_string(),
             )
             .unwrap();
+            // the unbonded mixnode pledge is already held as the first pending withdrawal
+            let expected_withdrawal = PendingWithdrawal::new(
+                2,
+                delegation_owner.clone(),
+                None,
+                coin(100, DENOM),
+                PendingWithdrawalKind::Undelegation {
+                    mix_identity: identity.clone(),
+                },
+                mock_env().block.time.seconds() + INITIAL_UNBONDING_PERIOD,
+            );
             let expected_response = Response::new()
-                .add_message(BankMsg::Send {
-                    to_address: delegation_owner.clone().into(),
-                    amount: coins(100, DENOM),
-                })
+                .add_event(new_pending_withdrawal_event(&expected_withdrawal))
                 .add_event(new_undelegation_event(
                     &delegation_owner,
                     &None,
@@ -865,11 +1323,12 @@ mod tests {
                     &identity,
                 ));
 
-            try_remove_mixnode(deps.as_mut(), mock_info(mixnode_owner, &[])).unwrap();
+            try_remove_mixnode(deps.as_mut(), mock_env(), mock_info(mixnode_owner, &[])).unwrap();
             assert_eq!(
                 Ok(expected_response),
                 try_remove_delegation_from_mixnode(
                     deps.as_mut(),
+                    mock_env(),
                     mock_info(delegation_owner.as_str(), &[]),
                     identity.clone(),
                 )
@@ -910,6 +1369,7 @@ mod tests {
             // sender1 undelegates
             try_remove_delegation_from_mixnode(
                 deps.as_mut(),
+                mock_env(),
                 mock_info(delegation_owner1.as_str(), &[]),
                 identity.clone(),
             )
@@ -925,6 +1385,507 @@ mod tests {
         }
     }
 
+    #[cfg(test)]
+    mod redelegating_mix_stake {
+        use super::storage;
+        use super::*;
+        use crate::delegations::queries::query_mixnode_delegation;
+        use cosmwasm_std::testing::mock_env;
+        use cosmwasm_std::testing::mock_info;
+        use cosmwasm_std::{coin, Uint128};
+        use mixnet_contract_common::{RewardingResult, RewardingStatus};
+
+        #[test]
+        fn fails_for_the_same_node() {
+            let mut deps = test_helpers::init_contract();
+            let identity = test_helpers::add_mixnode(
+                "bob",
+                tests::fixtures::good_mixnode_pledge(),
+                deps.as_mut(),
+            );
+
+            assert_eq!(
+                Err(ContractError::RedelegationToSameMixnode {
+                    identity: identity.clone()
+                }),
+                try_redelegate_to_mixnode(
+                    deps.as_mut(),
+                    mock_info("sender", &[]),
+                    identity.clone(),
+                    identity,
+                )
+            );
+        }
+
+        #[test]
+        fn fails_if_target_node_doesnt_exist() {
+            let mut deps = test_helpers::init_contract();
+            let identity = test_helpers::add_mixnode(
+                "bob",
+                tests::fixtures::good_mixnode_pledge(),
+                deps.as_mut(),
+            );
+
+            assert_eq!(
+                Err(ContractError::MixNodeBondNotFound {
+                    identity: "non-existent-mix-identity".into()
+                }),
+                try_redelegate_to_mixnode(
+                    deps.as_mut(),
+                    mock_info("sender", &[]),
+                    identity,
+                    "non-existent-mix-identity".into(),
+                )
+            );
+        }
+
+        #[test]
+        fn fails_if_delegation_never_existed() {
+            let mut deps = test_helpers::init_contract();
+            let identity1 = test_helpers::add_mixnode(
+                "bob",
+                tests::fixtures::good_mixnode_pledge(),
+                deps.as_mut(),
+            );
+            let identity2 = test_helpers::add_mixnode(
+                "alice",
+                tests::fixtures::good_mixnode_pledge(),
+                deps.as_mut(),
+            );
+
+            assert_eq!(
+                Err(ContractError::NoMixnodeDelegationFound {
+                    identity: identity1.clone(),
+                    address: Addr::unchecked("sender"),
+                }),
+                try_redelegate_to_mixnode(
+                    deps.as_mut(),
+                    mock_info("sender", &[]),
+                    identity1,
+                    identity2,
+                )
+            );
+        }
+
+        #[test]
+        fn moves_delegation_and_preserves_its_block_height() {
+            let mut deps = test_helpers::init_contract();
+            let identity1 = test_helpers::add_mixnode(
+                "bob",
+                tests::fixtures::good_mixnode_pledge(),
+                deps.as_mut(),
+            );
+            let identity2 = test_helpers::add_mixnode(
+                "alice",
+                tests::fixtures::good_mixnode_pledge(),
+                deps.as_mut(),
+            );
+
+            let mut env = mock_env();
+            env.block.height = 42;
+            try_delegate_to_mixnode(
+                deps.as_mut(),
+                env,
+                mock_info("sender", &coins(100, DENOM)),
+                identity1.clone(),
+            )
+            .unwrap();
+
+            let res = try_redelegate_to_mixnode(
+                deps.as_mut(),
+                mock_info("sender", &[]),
+                identity1.clone(),
+                identity2.clone(),
+            )
+            .unwrap();
+            assert!(res.messages.is_empty());
+            assert_eq!(
+                vec![new_redelegation_event(
+                    &Addr::unchecked("sender"),
+                    &None,
+                    &coin(100, DENOM),
+                    &identity1,
+                    &identity2,
+                    42,
+                )],
+                res.events
+            );
+
+            assert!(storage::delegations()
+                .may_load(
+                    &deps.storage,
+                    (identity1.clone(), Addr::unchecked("sender")).joined_key(),
+                )
+                .unwrap()
+                .is_none());
+            let delegation =
+                query_mixnode_delegation(deps.as_ref(), identity2.clone(), "sender".into())
+                    .unwrap();
+            assert_eq!(coin(100, DENOM), delegation.amount);
+            assert_eq!(42, delegation.block_height);
+
+            assert_eq!(
+                Uint128::zero(),
+                mixnodes_storage::TOTAL_DELEGATION
+                    .load(&deps.storage, &identity1)
+                    .unwrap()
+            );
+            assert_eq!(
+                Uint128::new(100),
+                mixnodes_storage::TOTAL_DELEGATION
+                    .load(&deps.storage, &identity2)
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn is_merged_with_existing_delegation_using_more_recent_block_height() {
+            let mut deps = test_helpers::init_contract();
+            let identity1 = test_helpers::add_mixnode(
+                "bob",
+                tests::fixtures::good_mixnode_pledge(),
+                deps.as_mut(),
+            );
+            let identity2 = test_helpers::add_mixnode(
+                "alice",
+                tests::fixtures::good_mixnode_pledge(),
+                deps.as_mut(),
+            );
+
+            let mut env = mock_env();
+            env.block.height = 42;
+            try_delegate_to_mixnode(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("sender", &coins(100, DENOM)),
+                identity1.clone(),
+            )
+            .unwrap();
+            env.block.height = 100;
+            try_delegate_to_mixnode(
+                deps.as_mut(),
+                env,
+                mock_info("sender", &coins(50, DENOM)),
+                identity2.clone(),
+            )
+            .unwrap();
+
+            try_redelegate_to_mixnode(
+                deps.as_mut(),
+                mock_info("sender", &[]),
+                identity1,
+                identity2.clone(),
+            )
+            .unwrap();
+
+            let delegation =
+                query_mixnode_delegation(deps.as_ref(), identity2.clone(), "sender".into())
+                    .unwrap();
+            assert_eq!(coin(150, DENOM), delegation.amount);
+            assert_eq!(100, delegation.block_height);
+            assert_eq!(
+                Uint128::new(150),
+                mixnodes_storage::TOTAL_DELEGATION
+                    .load(&deps.storage, &identity2)
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn fails_if_only_one_of_the_nodes_was_rewarded_in_current_interval() {
+            let mut deps = test_helpers::init_contract();
+            let identity1 = test_helpers::add_mixnode(
+                "bob",
+                tests::fixtures::good_mixnode_pledge(),
+                deps.as_mut(),
+            );
+            let identity2 = test_helpers::add_mixnode(
+                "alice",
+                tests::fixtures::good_mixnode_pledge(),
+                deps.as_mut(),
+            );
+            try_delegate_to_mixnode(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("sender", &coins(100, DENOM)),
+                identity1.clone(),
+            )
+            .unwrap();
+
+            let interval_id = interval_storage::CURRENT_INTERVAL
+                .load(&deps.storage)
+                .unwrap()
+                .id();
+            rewards_storage::REWARDING_STATUS
+                .save(
+                    &mut deps.storage,
+                    (interval_id, identity1.clone()),
+                    &RewardingStatus::Complete(RewardingResult::default()),
+                )
+                .unwrap();
+
+            assert_eq!(
+                Err(ContractError::RedelegationDuringRewarding {
+                    from: identity1.clone(),
+                    to: identity2.clone(),
+                }),
+                try_redelegate_to_mixnode(
+                    deps.as_mut(),
+                    mock_info("sender", &[]),
+                    identity1.clone(),
+                    identity2.clone(),
+                )
+            );
+
+            // once both nodes got rewarded, the delegation can be moved again
+            rewards_storage::REWARDING_STATUS
+                .save(
+                    &mut deps.storage,
+                    (interval_id, identity2.clone()),
+                    &RewardingStatus::Complete(RewardingResult::default()),
+                )
+                .unwrap();
+            assert!(try_redelegate_to_mixnode(
+                deps.as_mut(),
+                mock_info("sender", &[]),
+                identity1,
+                identity2,
+            )
+            .is_ok());
+        }
+    }
+
+    #[cfg(test)]
+    mod gateway_stake_delegation {
+        use super::*;
+        use cosmwasm_std::coin;
+        use cosmwasm_std::testing::mock_env;
+        use cosmwasm_std::testing::mock_info;
+        use cosmwasm_std::Uint128;
+
+        #[test]
+        fn fails_if_gateway_doesnt_exist() {
+            let mut deps = test_helpers::init_contract();
+            assert_eq!(
+                Err(ContractError::GatewayBondNotFound {
+                    identity: "non-existent-gateway-identity".into()
+                }),
+                try_delegate_to_gateway(
+                    deps.as_mut(),
+                    mock_env(),
+                    mock_info("sender", &coins(123, DENOM)),
+                    "non-existent-gateway-identity".into(),
+                )
+            );
+        }
+
+        #[test]
+        fn succeeds_for_existing_gateway() {
+            let mut deps = test_helpers::init_contract();
+            let identity = test_helpers::add_gateway(
+                "bob",
+                tests::fixtures::good_gateway_pledge(),
+                deps.as_mut(),
+            );
+            let delegation_owner = Addr::unchecked("sender");
+            let delegation = coin(123, DENOM);
+            assert!(try_delegate_to_gateway(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(delegation_owner.as_str(), &[delegation.clone()]),
+                identity.clone(),
+            )
+            .is_ok());
+
+            let expected = Delegation::new(
+                delegation_owner.clone(),
+                identity.clone(),
+                delegation.clone(),
+                mock_env().block.height,
+                None,
+            );
+            assert_eq!(
+                expected,
+                storage::gateway_delegations()
+                    .load(
+                        &deps.storage,
+                        (identity.clone(), delegation_owner).joined_key()
+                    )
+                    .unwrap()
+            );
+
+            // it doesn't affect the mixnode delegations
+            assert!(storage::delegations()
+                .range(&deps.storage, None, None, cosmwasm_std::Order::Ascending)
+                .next()
+                .is_none());
+
+            // gateway's "total_delegation" is increased
+            assert_eq!(
+                delegation.amount,
+                gateways_storage::TOTAL_GATEWAY_DELEGATION
+                    .load(&deps.storage, &identity)
+                    .unwrap()
+            )
+        }
+
+        #[test]
+        fn removing_fails_if_delegation_never_existed() {
+            let mut deps = test_helpers::init_contract();
+            let identity = test_helpers::add_gateway(
+                "bob",
+                tests::fixtures::good_gateway_pledge(),
+                deps.as_mut(),
+            );
+            assert_eq!(
+                Err(ContractError::NoGatewayDelegationFound {
+                    identity: identity.clone(),
+                    address: Addr::unchecked("sender"),
+                }),
+                try_remove_delegation_from_gateway(
+                    deps.as_mut(),
+                    mock_env(),
+                    mock_info("sender", &[]),
+                    identity,
+                )
+            );
+        }
+
+        #[test]
+        fn removing_clears_delegation_and_total() {
+            let mut deps = test_helpers::init_contract();
+            let identity = test_helpers::add_gateway(
+                "bob",
+                tests::fixtures::good_gateway_pledge(),
+                deps.as_mut(),
+            );
+            for delegator in ["alice", "sender"] {
+                try_delegate_to_gateway(
+                    deps.as_mut(),
+                    mock_env(),
+                    mock_info(delegator, &coins(100, DENOM)),
+                    identity.clone(),
+                )
+                .unwrap();
+            }
+
+            try_remove_delegation_from_gateway(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("sender", &[]),
+                identity.clone(),
+            )
+            .unwrap();
+
+            assert!(storage::gateway_delegations()
+                .may_load(&deps.storage, (identity.clone(), "sender").joined_key())
+                .unwrap()
+                .is_none());
+            assert_eq!(
+                Uint128::new(100),
+                gateways_storage::TOTAL_GATEWAY_DELEGATION
+                    .load(&deps.storage, &identity)
+                    .unwrap()
+            )
+        }
+
+        #[test]
+        fn delegation_on_behalf_is_kept_separately_from_direct_one() {
+            let mut deps = test_helpers::init_contract();
+            let identity = test_helpers::add_gateway(
+                "bob",
+                tests::fixtures::good_gateway_pledge(),
+                deps.as_mut(),
+            );
+            let delegate = Addr::unchecked("sender");
+            let proxy = Addr::unchecked("proxy");
+
+            try_delegate_to_gateway(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(delegate.as_str(), &coins(100, DENOM)),
+                identity.clone(),
+            )
+            .unwrap();
+            try_delegate_to_gateway_on_behalf(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(proxy.as_str(), &coins(50, DENOM)),
+                identity.clone(),
+                delegate.to_string(),
+            )
+            .unwrap();
+
+            let proxy_storage_key = (
+                identity.clone(),
+                generate_storage_key(&delegate, Some(&proxy)),
+            )
+                .joined_key();
+            let expected = Delegation::new(
+                delegate.clone(),
+                identity.clone(),
+                coin(50, DENOM),
+                mock_env().block.height,
+                Some(proxy.clone()),
+            );
+            assert_eq!(
+                expected,
+                storage::gateway_delegations()
+                    .load(&deps.storage, proxy_storage_key.clone())
+                    .unwrap()
+            );
+
+            // removing the direct delegation doesn't touch the one made via the proxy
+            try_remove_delegation_from_gateway(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(delegate.as_str(), &[]),
+                identity.clone(),
+            )
+            .unwrap();
+            assert!(storage::gateway_delegations()
+                .may_load(&deps.storage, proxy_storage_key.clone())
+                .unwrap()
+                .is_some());
+
+            // and the tokens of the one made via the proxy are held on its behalf
+            try_remove_delegation_from_gateway_on_behalf(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(proxy.as_str(), &[one_ucoin()]),
+                identity.clone(),
+                delegate.to_string(),
+            )
+            .unwrap();
+            assert!(storage::gateway_delegations()
+                .may_load(&deps.storage, proxy_storage_key)
+                .unwrap()
+                .is_none());
+            assert!(withdrawals_storage::has_pending_gateway_undelegation(
+                &deps.storage,
+                delegate.clone(),
+                Some(&proxy),
+                &identity,
+            )
+            .unwrap());
+
+            // so it can't delegate to the same gateway again until they're claimed
+            assert_eq!(
+                Err(ContractError::UndelegationStillPending {
+                    owner: delegate.clone(),
+                    identity: identity.clone(),
+                }),
+                try_delegate_to_gateway_on_behalf(
+                    deps.as_mut(),
+                    mock_env(),
+                    mock_info(proxy.as_str(), &coins(50, DENOM)),
+                    identity,
+                    delegate.to_string(),
+                )
+            );
+        }
+    }
+
     // #[cfg(test)]
     // mod multi_delegations {
     //     use super::*;
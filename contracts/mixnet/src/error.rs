@@ -118,6 +118,102 @@ pub enum ContractError {
         current_height: u64,
     },
 
+    #[error("MIXNET ({}): The rewarded set seed must be derived from the block at height {expected}. Got: {received}", line!())]
+    UnexpectedRewardedSetSeedHeight { received: u64, expected: u64 },
+
+    #[error("MIXNET ({}): The rewarded set seed must consist of exactly {expected} bytes. Got: {received}", line!())]
+    InvalidRewardedSetSeedLength { received: usize, expected: usize },
+
+    #[error("MIXNET ({}): Mixnode {identity} can't be assigned to the gateway layer", line!())]
+    InvalidLayerAssignment { identity: IdentityKey },
+
+    #[error("MIXNET ({}): The layer assignment does not cover exactly the nodes of the rewarded set", line!())]
+    LayerAssignmentMismatch,
+
+    #[error("MIXNET ({}): Mixnode {identity} already has a pledge change pending for the current interval", line!())]
+    PledgeChangeAlreadyPending { identity: IdentityKey },
+
+    #[error("MIXNET ({}): The pledge change amount must be greater than zero", line!())]
+    EmptyPledgeChange,
+
+    #[error("MIXNET ({}): Can't redelegate from mixnode {identity} to itself", line!())]
+    RedelegationToSameMixnode { identity: IdentityKey },
+
+    #[error("MIXNET ({}): Can't redelegate from mixnode {from} to {to} as only one of them has been rewarded during the current interval. Try again once the interval is advanced", line!())]
+    RedelegationDuringRewarding { from: IdentityKey, to: IdentityKey },
+
+    #[error("MIXNET ({}): {owner} does not have any pending withdrawals that could be claimed at this time", line!())]
+    NoClaimableWithdrawals { owner: Addr },
+
+    #[error("MIXNET ({}): {owner} is still undelegating from node {identity} - wait until the unbonding period is over and claim the tokens before delegating to it again", line!())]
+    UndelegationStillPending { owner: Addr, identity: IdentityKey },
+
+    #[error("MIXNET ({}): Performance of mixnode {identity} has already been reported for interval {interval_id}", line!())]
+    MixnodePerformanceAlreadyReported {
+        identity: IdentityKey,
+        interval_id: u32,
+    },
+
+    #[error("MIXNET ({}): Delegations towards mixnode {identity} are currently being slashed - try again once that is finished", line!())]
+    DelegationsBeingSlashed { identity: IdentityKey },
+
+    #[error("MIXNET ({}): There are no delegations towards mixnode {identity} left to slash", line!())]
+    NoPendingDelegationSlashing { identity: IdentityKey },
+
+    #[error("MIXNET ({}): Invalid slashing parameters - uptime threshold and slashed percentage must not exceed 100", line!())]
+    InvalidSlashingParams,
+
+    #[error("MIXNET ({}): Gateway ({identity}) does not exist", line!())]
+    GatewayBondNotFound { identity: IdentityKey },
+
+    #[error("MIXNET ({}): Could not find any delegation information associated with gateway {identity} for {address}", line!())]
+    NoGatewayDelegationFound {
+        identity: IdentityKey,
+        address: Addr,
+    },
+
+    #[error("MIXNET ({}): Gateway {identity} has already been rewarded during the current rewarding interval", line!())]
+    GatewayAlreadyRewarded { identity: IdentityKey },
+
+    #[error("MIXNET ({}): Some of gateway {identity} delegators are still pending reward", line!())]
+    GatewayDelegatorsPendingReward { identity: IdentityKey },
+
+    #[error("MIXNET ({}): Gateway's {identity} operator has not been rewarded yet - cannot perform delegator rewarding until that happens", line!())]
+    GatewayOperatorNotRewarded { identity: IdentityKey },
+
+    #[error("MIXNET ({}): Gateway rewarding is currently disabled", line!())]
+    GatewayRewardingDisabled,
+
+    #[error("MIXNET ({}): All {rewarded_set_size} gateways of the rewarded set have already been rewarded in interval {interval_id}", line!())]
+    GatewayRewardedSetFull {
+        interval_id: u32,
+        rewarded_set_size: u32,
+    },
+
+    #[error("MIXNET ({}): Invalid gateway rewarding parameters - reward pool share and operator share must not exceed 100", line!())]
+    InvalidGatewayRewardingParams,
+
+    #[error("MIXNET ({}): Mixnode {identity} already belongs to the family of {head}", line!())]
+    AlreadyInFamily {
+        identity: IdentityKey,
+        head: IdentityKey,
+    },
+
+    #[error("MIXNET ({}): Family with head {head} does not exist", line!())]
+    FamilyNotFound { head: IdentityKey },
+
+    #[error("MIXNET ({}): Mixnode {identity} does not belong to any family", line!())]
+    NotInFamily { identity: IdentityKey },
+
+    #[error("MIXNET ({}): Family label must be non-empty and at most {max_length} characters long", line!())]
+    InvalidFamilyLabel { max_length: usize },
+
+    #[error("MIXNET ({}): There are no pending rewards of {address} for {identity}", line!())]
+    NoRewardsToClaim {
+        identity: IdentityKey,
+        address: Addr,
+    },
+
     #[error("MIXNET ({}): Can't change to the desired interval as it's not in progress yet. It starts at {interval_start} and finishes at {interval_end}, while the current block time is {current_block_time}", line!())]
     IntervalNotInProgress {
         current_block_time: u64,
@@ -0,0 +1,75 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::storage;
+use cosmwasm_std::{Deps, Order, StdResult};
+use cw_storage_plus::Bound;
+use mixnet_contract_common::{
+    Family, IdentityKey, PagedFamiliesResponse, PagedFamilyMembersResponse,
+};
+
+pub(crate) fn query_families_paged(
+    deps: Deps<'_>,
+    start_after: Option<IdentityKey>,
+    limit: Option<u32>,
+) -> StdResult<PagedFamiliesResponse> {
+    let limit = limit
+        .unwrap_or(storage::FAMILY_PAGE_DEFAULT_LIMIT)
+        .min(storage::FAMILY_PAGE_MAX_LIMIT) as usize;
+
+    let start = start_after.map(Bound::exclusive);
+
+    let families = storage::FAMILIES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|record| record.map(|r| r.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let start_next_after = families.last().map(|family| family.head.clone());
+
+    Ok(PagedFamiliesResponse::new(families, start_next_after))
+}
+
+pub(crate) fn query_family_members_paged(
+    deps: Deps<'_>,
+    family_head: IdentityKey,
+    start_after: Option<IdentityKey>,
+    limit: Option<u32>,
+) -> StdResult<PagedFamilyMembersResponse> {
+    let limit = limit
+        .unwrap_or(storage::FAMILY_PAGE_DEFAULT_LIMIT)
+        .min(storage::FAMILY_PAGE_MAX_LIMIT) as usize;
+
+    let start = start_after.map(Bound::exclusive);
+
+    let members = storage::family_members()
+        .idx
+        .head
+        .prefix(family_head.clone())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|record| record.map(|r| r.1.member))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let start_next_after = members.last().cloned();
+
+    Ok(PagedFamilyMembersResponse::new(
+        family_head,
+        members,
+        start_next_after,
+    ))
+}
+
+pub(crate) fn query_family_by_member(
+    deps: Deps<'_>,
+    member: IdentityKey,
+) -> StdResult<Option<Family>> {
+    match storage::family_head(deps.storage, &member)? {
+        Some(head) => storage::FAMILIES.may_load(deps.storage, &head),
+        None => Ok(None),
+    }
+}
+
+pub(crate) fn query_family_signing_nonce(deps: Deps<'_>, identity: IdentityKey) -> StdResult<u64> {
+    storage::signing_nonce(deps.storage, &identity)
+}
@@ -0,0 +1,83 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use cosmwasm_std::{StdResult, Storage};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Map, MultiIndex};
+use mixnet_contract_common::{Family, IdentityKey, IdentityKeyRef};
+use serde::{Deserialize, Serialize};
+
+// storage prefixes
+const FAMILIES_NAMESPACE: &str = "fam";
+const FAMILY_MEMBERS_PK_NAMESPACE: &str = "fmb";
+const FAMILY_MEMBERS_HEAD_IDX_NAMESPACE: &str = "fmbh";
+const SIGNING_NONCES_NAMESPACE: &str = "fsn";
+
+// paged retrieval limits for all queries
+pub(crate) const FAMILY_PAGE_MAX_LIMIT: u32 = 100;
+pub(crate) const FAMILY_PAGE_DEFAULT_LIMIT: u32 = 50;
+
+pub(crate) const MAX_FAMILY_LABEL_LENGTH: usize = 64;
+
+// families keyed by the identity of their head
+pub(crate) const FAMILIES: Map<'_, IdentityKeyRef<'_>, Family> = Map::new(FAMILIES_NAMESPACE);
+
+// nonces included in all family-related signed messages, keyed by the identity of the signer.
+// They are never removed, even after the node unbonds, so that old signatures can't be replayed
+// after bonding the same node again.
+pub(crate) const SIGNING_NONCES: Map<'_, IdentityKeyRef<'_>, u64> =
+    Map::new(SIGNING_NONCES_NAMESPACE);
+
+// note that the head of the family is also a member of it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct FamilyMembership {
+    pub(crate) member: IdentityKey,
+    pub(crate) head: IdentityKey,
+}
+
+pub(crate) struct FamilyMembershipIndex<'a> {
+    pub(crate) head: MultiIndex<'a, IdentityKey, FamilyMembership>,
+}
+
+impl<'a> IndexList<FamilyMembership> for FamilyMembershipIndex<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<FamilyMembership>> + '_> {
+        let v: Vec<&dyn Index<FamilyMembership>> = vec![&self.head];
+        Box::new(v.into_iter())
+    }
+}
+
+// memberships keyed by the identity of the member
+pub(crate) fn family_members<'a>(
+) -> IndexedMap<'a, IdentityKeyRef<'a>, FamilyMembership, FamilyMembershipIndex<'a>> {
+    let indexes = FamilyMembershipIndex {
+        head: MultiIndex::new(
+            |m| m.head.clone(),
+            FAMILY_MEMBERS_PK_NAMESPACE,
+            FAMILY_MEMBERS_HEAD_IDX_NAMESPACE,
+        ),
+    };
+
+    IndexedMap::new(FAMILY_MEMBERS_PK_NAMESPACE, indexes)
+}
+
+pub(crate) fn family_head(
+    storage: &dyn Storage,
+    identity: IdentityKeyRef<'_>,
+) -> StdResult<Option<IdentityKey>> {
+    Ok(family_members()
+        .may_load(storage, identity)?
+        .map(|membership| membership.head))
+}
+
+pub(crate) fn signing_nonce(storage: &dyn Storage, identity: IdentityKeyRef<'_>) -> StdResult<u64> {
+    Ok(SIGNING_NONCES
+        .may_load(storage, identity)?
+        .unwrap_or_default())
+}
+
+pub(crate) fn increment_signing_nonce(
+    storage: &mut dyn Storage,
+    identity: IdentityKeyRef<'_>,
+) -> StdResult<()> {
+    let nonce = signing_nonce(storage, identity)?;
+    SIGNING_NONCES.save(storage, identity, &(nonce + 1))
+}
@@ -0,0 +1,490 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::storage;
+use super::storage::FamilyMembership;
+use crate::error::ContractError;
+use crate::mixnodes::storage as mixnodes_storage;
+use crate::mixnodes::storage::StoredMixnodeBond;
+use crate::support::helpers::validate_ed25519_signature;
+use cosmwasm_std::{Addr, Deps, DepsMut, Event, MessageInfo, Order, Response, StdResult, Storage};
+use mixnet_contract_common::events::{
+    new_family_creation_event, new_family_disband_event, new_family_join_event,
+    new_family_leave_event,
+};
+use mixnet_contract_common::{
+    family_creation_message, family_join_message, family_join_permit_message, family_leave_message,
+    Family, IdentityKey, IdentityKeyRef,
+};
+
+fn load_mixnode(deps: Deps<'_>, owner: &Addr) -> Result<StoredMixnodeBond, ContractError> {
+    mixnodes_storage::mixnodes()
+        .idx
+        .owner
+        .item(deps.storage, owner.clone())?
+        .map(|record| record.1)
+        .ok_or_else(|| ContractError::NoAssociatedMixNodeBond {
+            owner: owner.clone(),
+        })
+}
+
+// makes sure the provided message, bound to the current signing nonce of the node,
+// has been signed with its identity key
+fn validate_family_signature(
+    deps: Deps<'_>,
+    message: impl FnOnce(u64) -> String,
+    signature: String,
+    identity: IdentityKeyRef<'_>,
+) -> Result<(), ContractError> {
+    let nonce = storage::signing_nonce(deps.storage, identity)?;
+    validate_ed25519_signature(deps, message(nonce).as_bytes(), signature, identity)
+}
+
+fn ensure_not_in_family(
+    storage: &dyn Storage,
+    identity: IdentityKeyRef<'_>,
+) -> Result<(), ContractError> {
+    match storage::family_head(storage, identity)? {
+        Some(head) => Err(ContractError::AlreadyInFamily {
+            identity: identity.to_string(),
+            head,
+        }),
+        None => Ok(()),
+    }
+}
+
+// Removes the family alongside all of its members.
+// Families are expected to be small, so reading all members at once is fine.
+fn disband_family(storage: &mut dyn Storage, head: IdentityKeyRef<'_>) -> StdResult<()> {
+    let members = storage::family_members()
+        .idx
+        .head
+        .prefix(head.to_string())
+        .range(storage, None, None, Order::Ascending)
+        .map(|record| record.map(|r| r.1.member))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for member in members {
+        storage::family_members().remove(storage, &member)?;
+    }
+    storage::FAMILIES.remove(storage, head);
+    Ok(())
+}
+
+// Removes the node from its family (if any). If the node happened to be the head of the family,
+// the entire family is disbanded. Returns the event describing the change.
+pub(crate) fn remove_from_family(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    identity: IdentityKeyRef<'_>,
+) -> StdResult<Option<Event>> {
+    let membership = match storage::family_members().may_load(storage, identity)? {
+        Some(membership) => membership,
+        None => return Ok(None),
+    };
+
+    if membership.head == identity {
+        disband_family(storage, identity)?;
+        Ok(Some(new_family_disband_event(identity)))
+    } else {
+        storage::family_members().remove(storage, identity)?;
+        Ok(Some(new_family_leave_event(
+            owner,
+            &membership.head,
+            identity,
+        )))
+    }
+}
+
+pub(crate) fn try_create_family(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    owner_signature: String,
+    label: String,
+) -> Result<Response, ContractError> {
+    if label.is_empty() || label.len() > storage::MAX_FAMILY_LABEL_LENGTH {
+        return Err(ContractError::InvalidFamilyLabel {
+            max_length: storage::MAX_FAMILY_LABEL_LENGTH,
+        });
+    }
+
+    let bond = load_mixnode(deps.as_ref(), &info.sender)?;
+    let head = bond.identity();
+    validate_family_signature(
+        deps.as_ref(),
+        |nonce| family_creation_message(info.sender.as_str(), &label, nonce),
+        owner_signature,
+        head,
+    )?;
+    ensure_not_in_family(deps.storage, head)?;
+
+    let family = Family::new(head.clone(), info.sender.clone(), label);
+    storage::FAMILIES.save(deps.storage, head, &family)?;
+    storage::family_members().save(
+        deps.storage,
+        head,
+        &FamilyMembership {
+            member: head.clone(),
+            head: head.clone(),
+        },
+    )?;
+    storage::increment_signing_nonce(deps.storage, head)?;
+
+    Ok(Response::new().add_event(new_family_creation_event(&info.sender, head, &family.label)))
+}
+
+pub(crate) fn try_join_family(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    owner_signature: String,
+    family_head: IdentityKey,
+    join_permit: String,
+) -> Result<Response, ContractError> {
+    let bond = load_mixnode(deps.as_ref(), &info.sender)?;
+    let identity = bond.identity();
+    validate_family_signature(
+        deps.as_ref(),
+        |nonce| family_join_message(info.sender.as_str(), &family_head, nonce),
+        owner_signature,
+        identity,
+    )?;
+    ensure_not_in_family(deps.storage, identity)?;
+
+    if storage::FAMILIES
+        .may_load(deps.storage, &family_head)?
+        .is_none()
+    {
+        return Err(ContractError::FamilyNotFound { head: family_head });
+    }
+
+    // the head of the family has to agree to the node joining it. The permit is bound to the nonce
+    // of the joining node, so it can only ever be used once
+    let nonce = storage::signing_nonce(deps.storage, identity)?;
+    validate_ed25519_signature(
+        deps.as_ref(),
+        family_join_permit_message(&family_head, identity, nonce).as_bytes(),
+        join_permit,
+        &family_head,
+    )?;
+
+    storage::family_members().save(
+        deps.storage,
+        identity,
+        &FamilyMembership {
+            member: identity.clone(),
+            head: family_head.clone(),
+        },
+    )?;
+    storage::increment_signing_nonce(deps.storage, identity)?;
+
+    Ok(Response::new().add_event(new_family_join_event(&info.sender, &family_head, identity)))
+}
+
+pub(crate) fn try_leave_family(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    owner_signature: String,
+) -> Result<Response, ContractError> {
+    let bond = load_mixnode(deps.as_ref(), &info.sender)?;
+    validate_family_signature(
+        deps.as_ref(),
+        |nonce| family_leave_message(info.sender.as_str(), nonce),
+        owner_signature,
+        bond.identity(),
+    )?;
+
+    match remove_from_family(deps.storage, &info.sender, bond.identity())? {
+        Some(event) => {
+            storage::increment_signing_nonce(deps.storage, bond.identity())?;
+            Ok(Response::new().add_event(event))
+        }
+        None => Err(ContractError::NotInFamily {
+            identity: bond.identity().clone(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::families::queries::{query_family_by_member, query_family_members_paged};
+    use crate::mixnodes::transactions::try_remove_mixnode;
+    use crate::support::tests::{fixtures, test_helpers};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use crypto::asymmetric::identity::KeyPair;
+
+    fn sign(keypair: &KeyPair, message: String) -> String {
+        keypair
+            .private_key()
+            .sign(message.as_bytes())
+            .to_base58_string()
+    }
+
+    fn join_permit(head_keypair: &KeyPair, member_keypair: &KeyPair, nonce: u64) -> String {
+        sign(
+            head_keypair,
+            family_join_permit_message(
+                &head_keypair.public_key().to_base58_string(),
+                &member_keypair.public_key().to_base58_string(),
+                nonce,
+            ),
+        )
+    }
+
+    #[test]
+    fn creating_family() {
+        let mut deps = test_helpers::init_contract();
+        let head = test_helpers::add_mixnode_with_keypair(
+            "alice",
+            fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+        let head_identity = head.public_key().to_base58_string();
+
+        // signature has to be valid
+        let res = try_create_family(
+            deps.as_mut(),
+            mock_info("alice", &[]),
+            sign(&head, family_creation_message("bob", "family", 0)),
+            "family".into(),
+        );
+        assert_eq!(Err(ContractError::InvalidEd25519Signature), res);
+
+        // label cannot be empty
+        let res = try_create_family(
+            deps.as_mut(),
+            mock_info("alice", &[]),
+            sign(&head, family_creation_message("alice", "", 0)),
+            "".into(),
+        );
+        assert_eq!(
+            Err(ContractError::InvalidFamilyLabel {
+                max_length: storage::MAX_FAMILY_LABEL_LENGTH
+            }),
+            res
+        );
+
+        let res = try_create_family(
+            deps.as_mut(),
+            mock_info("alice", &[]),
+            sign(&head, family_creation_message("alice", "family", 0)),
+            "family".into(),
+        );
+        assert!(res.is_ok());
+
+        let family = query_family_by_member(deps.as_ref(), head_identity.clone())
+            .unwrap()
+            .unwrap();
+        assert_eq!(head_identity, family.head);
+        assert_eq!("family", family.label);
+
+        // cannot create another one while being in a family
+        let res = try_create_family(
+            deps.as_mut(),
+            mock_info("alice", &[]),
+            sign(&head, family_creation_message("alice", "family2", 1)),
+            "family2".into(),
+        );
+        assert_eq!(
+            Err(ContractError::AlreadyInFamily {
+                identity: head_identity.clone(),
+                head: head_identity
+            }),
+            res
+        );
+    }
+
+    #[test]
+    fn joining_family() {
+        let mut deps = test_helpers::init_contract();
+        let head = test_helpers::add_mixnode_with_keypair(
+            "alice",
+            fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+        let member = test_helpers::add_mixnode_with_keypair(
+            "bob",
+            fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+        let head_identity = head.public_key().to_base58_string();
+        let member_identity = member.public_key().to_base58_string();
+
+        // family has to exist
+        let res = try_join_family(
+            deps.as_mut(),
+            mock_info("bob", &[]),
+            sign(&member, family_join_message("bob", &head_identity, 0)),
+            head_identity.clone(),
+            join_permit(&head, &member, 0),
+        );
+        assert_eq!(
+            Err(ContractError::FamilyNotFound {
+                head: head_identity.clone()
+            }),
+            res
+        );
+
+        try_create_family(
+            deps.as_mut(),
+            mock_info("alice", &[]),
+            sign(&head, family_creation_message("alice", "family", 0)),
+            "family".into(),
+        )
+        .unwrap();
+
+        // the permit has to be signed by the head of the family
+        let res = try_join_family(
+            deps.as_mut(),
+            mock_info("bob", &[]),
+            sign(&member, family_join_message("bob", &head_identity, 0)),
+            head_identity.clone(),
+            join_permit(&member, &member, 0),
+        );
+        assert_eq!(Err(ContractError::InvalidEd25519Signature), res);
+
+        let res = try_join_family(
+            deps.as_mut(),
+            mock_info("bob", &[]),
+            sign(&member, family_join_message("bob", &head_identity, 0)),
+            head_identity.clone(),
+            join_permit(&head, &member, 0),
+        );
+        assert!(res.is_ok());
+
+        let members =
+            query_family_members_paged(deps.as_ref(), head_identity.clone(), None, None).unwrap();
+        assert_eq!(2, members.members.len());
+        assert!(members.members.contains(&member_identity));
+
+        // the signatures cannot be replayed
+        let res = try_join_family(
+            deps.as_mut(),
+            mock_info("bob", &[]),
+            sign(&member, family_join_message("bob", &head_identity, 0)),
+            head_identity.clone(),
+            join_permit(&head, &member, 0),
+        );
+        assert_eq!(Err(ContractError::InvalidEd25519Signature), res);
+
+        // cannot join twice
+        let res = try_join_family(
+            deps.as_mut(),
+            mock_info("bob", &[]),
+            sign(&member, family_join_message("bob", &head_identity, 1)),
+            head_identity.clone(),
+            join_permit(&head, &member, 1),
+        );
+        assert_eq!(
+            Err(ContractError::AlreadyInFamily {
+                identity: member_identity,
+                head: head_identity
+            }),
+            res
+        );
+    }
+
+    #[test]
+    fn leaving_family() {
+        let mut deps = test_helpers::init_contract();
+        let head = test_helpers::add_mixnode_with_keypair(
+            "alice",
+            fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+        let member1 = test_helpers::add_mixnode_with_keypair(
+            "bob",
+            fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+        let member2 = test_helpers::add_mixnode_with_keypair(
+            "carol",
+            fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+        let head_identity = head.public_key().to_base58_string();
+        let member1_identity = member1.public_key().to_base58_string();
+        let member2_identity = member2.public_key().to_base58_string();
+
+        // cannot leave if not in any family
+        let res = try_leave_family(
+            deps.as_mut(),
+            mock_info("bob", &[]),
+            sign(&member1, family_leave_message("bob", 0)),
+        );
+        assert_eq!(
+            Err(ContractError::NotInFamily {
+                identity: member1_identity.clone()
+            }),
+            res
+        );
+
+        try_create_family(
+            deps.as_mut(),
+            mock_info("alice", &[]),
+            sign(&head, family_creation_message("alice", "family", 0)),
+            "family".into(),
+        )
+        .unwrap();
+        for (owner, member) in [("bob", &member1), ("carol", &member2)] {
+            try_join_family(
+                deps.as_mut(),
+                mock_info(owner, &[]),
+                sign(member, family_join_message(owner, &head_identity, 0)),
+                head_identity.clone(),
+                join_permit(&head, member, 0),
+            )
+            .unwrap();
+        }
+
+        // the permit used for joining cannot be reused after leaving
+        try_leave_family(
+            deps.as_mut(),
+            mock_info("bob", &[]),
+            sign(&member1, family_leave_message("bob", 1)),
+        )
+        .unwrap();
+        let res = try_join_family(
+            deps.as_mut(),
+            mock_info("bob", &[]),
+            sign(&member1, family_join_message("bob", &head_identity, 2)),
+            head_identity.clone(),
+            join_permit(&head, &member1, 0),
+        );
+        assert_eq!(Err(ContractError::InvalidEd25519Signature), res);
+        try_join_family(
+            deps.as_mut(),
+            mock_info("bob", &[]),
+            sign(&member1, family_join_message("bob", &head_identity, 2)),
+            head_identity.clone(),
+            join_permit(&head, &member1, 2),
+        )
+        .unwrap();
+
+        // regular member leaving only removes itself
+        try_leave_family(
+            deps.as_mut(),
+            mock_info("bob", &[]),
+            sign(&member1, family_leave_message("bob", 3)),
+        )
+        .unwrap();
+        assert!(query_family_by_member(deps.as_ref(), member1_identity)
+            .unwrap()
+            .is_none());
+        assert!(
+            query_family_by_member(deps.as_ref(), member2_identity.clone())
+                .unwrap()
+                .is_some()
+        );
+
+        // while unbonding the head disbands the entire family
+        try_remove_mixnode(deps.as_mut(), mock_env(), mock_info("alice", &[])).unwrap();
+        assert!(query_family_by_member(deps.as_ref(), member2_identity)
+            .unwrap()
+            .is_none());
+        assert!(storage::FAMILIES
+            .may_load(deps.as_ref().storage, &head_identity)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -1,13 +1,17 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use cosmwasm_std::Addr;
-use cw_storage_plus::{Index, IndexList, IndexedMap, UniqueIndex};
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Map, UniqueIndex};
 use mixnet_contract_common::{GatewayBond, IdentityKeyRef};
 
 // storage prefixes
 const GATEWAYS_PK_NAMESPACE: &str = "gt";
 const GATEWAYS_OWNER_IDX_NAMESPACE: &str = "gto";
+const TOTAL_GATEWAY_DELEGATION_NAMESPACE: &str = "tgd";
+
+pub(crate) const TOTAL_GATEWAY_DELEGATION: Map<'_, IdentityKeyRef<'_>, Uint128> =
+    Map::new(TOTAL_GATEWAY_DELEGATION_NAMESPACE);
 
 pub(crate) struct GatewayBondIndex<'a> {
     pub(crate) owner: UniqueIndex<'a, Addr, GatewayBond>,
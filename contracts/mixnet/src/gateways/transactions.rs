@@ -4,13 +4,16 @@
 use super::storage;
 use crate::error::ContractError;
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
+use crate::rewards::storage as rewards_storage;
 use crate::support::helpers::{ensure_no_existing_bond, validate_node_identity_signature};
 use config::defaults::DENOM;
 use cosmwasm_std::{
     wasm_execute, Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Response, Uint128,
 };
-use mixnet_contract_common::events::{new_gateway_bonding_event, new_gateway_unbonding_event};
-use mixnet_contract_common::{Gateway, GatewayBond, Layer};
+use mixnet_contract_common::events::{
+    new_gateway_bonding_event, new_gateway_details_update_event, new_gateway_unbonding_event,
+};
+use mixnet_contract_common::{Gateway, GatewayBond, GatewayDetailsUpdate, Layer};
 use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
 use vesting_contract_common::one_ucoin;
 
@@ -109,6 +112,16 @@ pub(crate) fn _try_add_gateway(
     );
 
     storage::gateways().save(deps.storage, bond.identity(), &bond)?;
+
+    // same as with mixnodes, only write 0 total delegation if this is a fresh gateway,
+    // otherwise the gateway has just rebonded and its delegations are still there
+    if storage::TOTAL_GATEWAY_DELEGATION
+        .may_load(deps.storage, bond.identity())?
+        .is_none()
+    {
+        storage::TOTAL_GATEWAY_DELEGATION.save(deps.storage, bond.identity(), &Uint128::zero())?;
+    }
+
     mixnet_params_storage::increment_layer_count(deps.storage, Layer::Gateway)?;
 
     Ok(Response::new().add_event(new_gateway_bonding_event(
@@ -157,10 +170,15 @@ pub(crate) fn _try_remove_gateway(
         });
     }
 
+    // any operator rewards that haven't been claimed yet are returned alongside the pledge
+    let mut returned_amount = gateway_bond.pledge_amount();
+    returned_amount.amount +=
+        rewards_storage::take_operator_reward(deps.storage, gateway_bond.identity())?;
+
     // send bonded funds back to the bond owner
     let return_tokens = BankMsg::Send {
         to_address: proxy.as_ref().unwrap_or(&owner).to_string(),
-        amount: vec![gateway_bond.pledge_amount()],
+        amount: vec![returned_amount.clone()],
     };
 
     // remove the bond
@@ -174,7 +192,7 @@ pub(crate) fn _try_remove_gateway(
     if let Some(proxy) = &proxy {
         let msg = VestingContractExecuteMsg::TrackUnbondGateway {
             owner: owner.as_str().to_string(),
-            amount: gateway_bond.pledge_amount(),
+            amount: returned_amount.clone(),
         };
 
         let track_unbond_message = wasm_execute(proxy, &msg, vec![one_ucoin()])?;
@@ -184,11 +202,74 @@ pub(crate) fn _try_remove_gateway(
     Ok(response.add_event(new_gateway_unbonding_event(
         &owner,
         &proxy,
-        &gateway_bond.pledge_amount,
+        &returned_amount,
         gateway_bond.identity(),
     )))
 }
 
+pub(crate) fn try_update_gateway_details(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    details: GatewayDetailsUpdate,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(info.sender.as_ref())?;
+    _try_update_gateway_details(deps, details, owner, None)
+}
+
+pub(crate) fn try_update_gateway_details_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    details: GatewayDetailsUpdate,
+    owner: String,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let proxy = deps.api.addr_validate(info.sender.as_ref())?;
+    _try_update_gateway_details(deps, details, owner, Some(proxy))
+}
+
+pub(crate) fn _try_update_gateway_details(
+    deps: DepsMut<'_>,
+    details: GatewayDetailsUpdate,
+    owner: Addr,
+    proxy: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let mut gateway_bond = match storage::gateways()
+        .idx
+        .owner
+        .item(deps.storage, owner.clone())?
+    {
+        Some(record) => record.1,
+        None => return Err(ContractError::NoAssociatedGatewayBond { owner }),
+    };
+
+    if proxy != gateway_bond.proxy {
+        return Err(ContractError::ProxyMismatch {
+            existing: gateway_bond
+                .proxy
+                .map_or_else(|| "None".to_string(), |a| a.as_str().to_string()),
+            incoming: proxy.map_or_else(|| "None".to_string(), |a| a.as_str().to_string()),
+        });
+    }
+
+    let event = new_gateway_details_update_event(&owner, &proxy, gateway_bond.identity(), &details);
+
+    gateway_bond.gateway.apply_details_update(details);
+    storage::gateways().save(deps.storage, gateway_bond.identity(), &gateway_bond)?;
+
+    let mut response = Response::new().add_event(event);
+
+    if let Some(proxy) = proxy {
+        // Returns one_ucoin proxy had to send in order to execute the contract to contract transaction
+        let return_one_ucoin = BankMsg::Send {
+            to_address: proxy.as_str().to_string(),
+            amount: vec![one_ucoin()],
+        };
+        response = response.add_message(return_one_ucoin);
+    }
+
+    Ok(response)
+}
+
 fn validate_gateway_pledge(
     mut pledge: Vec<Coin>,
     minimum_pledge: Uint128,
@@ -230,7 +311,9 @@ pub mod tests {
     use cosmwasm_std::testing::{mock_env, mock_info};
     use cosmwasm_std::{coins, BankMsg, Response};
     use cosmwasm_std::{from_binary, Addr, Uint128};
-    use mixnet_contract_common::{ExecuteMsg, Gateway, PagedGatewayResponse, QueryMsg};
+    use mixnet_contract_common::{
+        ExecuteMsg, Gateway, GatewayDetailsUpdate, PagedGatewayResponse, QueryMsg,
+    };
 
     #[test]
     fn gateway_add() {
@@ -541,6 +624,78 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn updating_gateway_details() {
+        let sender = "alice";
+        let mut deps = test_helpers::init_contract();
+        let info = mock_info(sender, &[]);
+
+        let details = GatewayDetailsUpdate {
+            host: "1.1.1.1".to_string(),
+            mix_port: 1790,
+            clients_port: 9001,
+            location: "Neptune".to_string(),
+            version: "0.13.0".to_string(),
+        };
+
+        // try updating a non existing gateway bond
+        let msg = ExecuteMsg::UpdateGatewayDetails {
+            details: details.clone(),
+        };
+        let ret = execute(deps.as_mut(), mock_env(), info.clone(), msg);
+        assert_eq!(
+            ret,
+            Err(ContractError::NoAssociatedGatewayBond {
+                owner: Addr::unchecked(sender)
+            })
+        );
+
+        let identity = test_helpers::add_gateway(
+            sender,
+            tests::fixtures::good_gateway_pledge(),
+            deps.as_mut(),
+        );
+        let bond_before = storage::gateways()
+            .load(deps.as_ref().storage, &identity)
+            .unwrap();
+
+        // the vesting contract can't update details of a liquid bond
+        let msg = ExecuteMsg::UpdateGatewayDetailsOnBehalf {
+            details: details.clone(),
+            owner: sender.to_string(),
+        };
+        let ret = execute(deps.as_mut(), mock_env(), mock_info("proxy", &[]), msg);
+        assert!(matches!(ret, Err(ContractError::ProxyMismatch { .. })));
+
+        let msg = ExecuteMsg::UpdateGatewayDetails {
+            details: details.clone(),
+        };
+        let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(1, res.events.len());
+
+        let bond_after = storage::gateways()
+            .load(deps.as_ref().storage, &identity)
+            .unwrap();
+        assert_eq!(details.host, bond_after.gateway.host);
+        assert_eq!(details.mix_port, bond_after.gateway.mix_port);
+        assert_eq!(details.clients_port, bond_after.gateway.clients_port);
+        assert_eq!(details.location, bond_after.gateway.location);
+        assert_eq!(details.version, bond_after.gateway.version);
+
+        // everything else about the bond is left intact
+        assert_eq!(
+            bond_before.gateway.identity_key,
+            bond_after.gateway.identity_key
+        );
+        assert_eq!(
+            bond_before.gateway.sphinx_key,
+            bond_after.gateway.sphinx_key
+        );
+        assert_eq!(bond_before.pledge_amount, bond_after.pledge_amount);
+        assert_eq!(bond_before.block_height, bond_after.block_height);
+        assert_eq!(bond_before.owner, bond_after.owner);
+    }
+
     #[test]
     fn validating_gateway_bond() {
         // you must send SOME funds
@@ -6,8 +6,9 @@ use crate::error::ContractError;
 use cosmwasm_std::{Env, Order, StdResult, Storage};
 use cw_storage_plus::Bound;
 use mixnet_contract_common::{
-    IdentityKey, Interval, IntervalRewardedSetHeightsResponse, PagedRewardedSetResponse,
-    RewardedSetNodeStatus, RewardedSetUpdateDetails,
+    IdentityKey, Interval, IntervalRewardedSetHeightsResponse, LayerAssignment,
+    PagedLayerAssignmentResponse, PagedRewardedSetResponse, RewardedSetNodeStatus, RewardedSetSeed,
+    RewardedSetUpdateDetails,
 };
 
 pub fn query_current_interval(storage: &dyn Storage) -> Result<Interval, ContractError> {
@@ -95,6 +96,55 @@ pub fn query_rewarded_set(
     }
 }
 
+pub fn query_layer_assignment(
+    storage: &dyn Storage,
+    height: Option<u64>,
+    start_after: Option<IdentityKey>,
+    limit: Option<u32>,
+) -> Result<PagedLayerAssignmentResponse, ContractError> {
+    let height = match height {
+        Some(height) => height,
+        None => query_current_rewarded_set_height(storage)?,
+    };
+    let limit = limit
+        .unwrap_or(storage::REWARDED_NODE_DEFAULT_PAGE_LIMIT)
+        .min(storage::REWARDED_NODE_MAX_PAGE_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let assignment = storage::LAYER_ASSIGNMENTS
+        .prefix(height)
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|res| res.map(|(identity, layer)| LayerAssignment::new(identity, layer)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    // if we got fewer results than the limit, there's nothing more to query
+    let start_next_after = if assignment.len() < limit {
+        None
+    } else {
+        assignment.last().map(|assigned| assigned.identity.clone())
+    };
+
+    Ok(PagedLayerAssignmentResponse {
+        assignment,
+        start_next_after,
+        at_height: height,
+    })
+}
+
+// returns `None` for rewarded sets that were written before the seeds were introduced
+pub fn query_rewarded_set_seed(
+    storage: &dyn Storage,
+    height: Option<u64>,
+) -> Result<Option<RewardedSetSeed>, ContractError> {
+    let height = match height {
+        Some(height) => height,
+        None => query_current_rewarded_set_height(storage)?,
+    };
+
+    Ok(storage::REWARDED_SET_SEEDS.may_load(storage, height)?)
+}
+
 // this was all put together into the same query so that all information would be synced together
 pub fn query_rewarded_set_update_details(
     env: Env,
@@ -3,7 +3,9 @@
 
 use cosmwasm_std::{StdResult, Storage};
 use cw_storage_plus::{Item, Map};
-use mixnet_contract_common::{IdentityKey, Interval, RewardedSetNodeStatus};
+use mixnet_contract_common::{
+    IdentityKey, Interval, Layer, LayerAssignment, RewardedSetNodeStatus, RewardedSetSeed,
+};
 
 // type aliases for better reasoning for storage keys
 // (I found it helpful)
@@ -26,6 +28,12 @@ pub(crate) const REWARDED_SET_HEIGHTS_FOR_INTERVAL: Map<'_, (IntervalId, BlockHe
 pub(crate) const REWARDED_SET: Map<'_, (BlockHeight, IdentityKey), RewardedSetNodeStatus> =
     Map::new("rs");
 
+// seeds used for selecting rewarded sets written at particular heights
+pub(crate) const REWARDED_SET_SEEDS: Map<'_, BlockHeight, RewardedSetSeed> = Map::new("rss");
+
+// layers the nodes of the rewarded set written at particular height are supposed to be used on
+pub(crate) const LAYER_ASSIGNMENTS: Map<'_, (BlockHeight, IdentityKey), Layer> = Map::new("las");
+
 pub(crate) fn save_rewarded_set(
     storage: &mut dyn Storage,
     height: BlockHeight,
@@ -46,6 +54,18 @@ pub(crate) fn save_rewarded_set(
     Ok(())
 }
 
+pub(crate) fn save_layer_assignment(
+    storage: &mut dyn Storage,
+    height: BlockHeight,
+    assignment: Vec<LayerAssignment>,
+) -> StdResult<()> {
+    for assigned in assignment {
+        LAYER_ASSIGNMENTS.save(storage, (height, assigned.identity), &assigned.layer)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,9 +5,13 @@ use super::storage;
 use crate::error::ContractError;
 use crate::error::ContractError::IntervalNotInProgress;
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
+use crate::mixnodes::transactions::apply_pending_pledge_changes;
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Storage};
 use mixnet_contract_common::events::{new_advance_interval_event, new_change_rewarded_set_event};
-use mixnet_contract_common::IdentityKey;
+use mixnet_contract_common::{
+    IdentityKey, Layer, LayerAssignment, RewardedSetSeed, REWARDED_SET_SEED_LENGTH,
+};
+use std::collections::HashSet;
 
 pub fn try_write_rewarded_set(
     deps: DepsMut<'_>,
@@ -15,6 +19,8 @@ pub fn try_write_rewarded_set(
     info: MessageInfo,
     rewarded_set: Vec<IdentityKey>,
     active_set_size: u32,
+    seed: RewardedSetSeed,
+    layer_assignment: Vec<LayerAssignment>,
 ) -> Result<Response, ContractError> {
     let state = mixnet_params_storage::CONTRACT_STATE.load(deps.storage)?;
 
@@ -50,15 +56,38 @@ pub fn try_write_rewarded_set(
         });
     }
 
+    // the seed has to come from the first block at which the update was possible, so that
+    // the validator could not have chosen the one that yields the most favourable set.
+    // Note that the contract has no access to block hashes, so it's up to everyone else
+    // to check whether the provided hash is valid.
+    let expected_seed_height = last_update + crate::constants::REWARDED_SET_REFRESH_BLOCKS;
+    if seed.height != expected_seed_height {
+        return Err(ContractError::UnexpectedRewardedSetSeedHeight {
+            received: seed.height,
+            expected: expected_seed_height,
+        });
+    }
+
+    if seed.block_hash.len() != REWARDED_SET_SEED_LENGTH {
+        return Err(ContractError::InvalidRewardedSetSeedLength {
+            received: seed.block_hash.len(),
+            expected: REWARDED_SET_SEED_LENGTH,
+        });
+    }
+
+    validate_layer_assignment(&rewarded_set, &layer_assignment)?;
+
     let current_interval = storage::CURRENT_INTERVAL.load(deps.storage)?.id();
     let num_nodes = rewarded_set.len();
 
     storage::save_rewarded_set(deps.storage, block_height, active_set_size, rewarded_set)?;
+    storage::save_layer_assignment(deps.storage, block_height, layer_assignment)?;
     storage::REWARDED_SET_HEIGHTS_FOR_INTERVAL.save(
         deps.storage,
         (current_interval, block_height),
         &0u8,
     )?;
+    storage::REWARDED_SET_SEEDS.save(deps.storage, block_height, &seed)?;
     storage::CURRENT_REWARDED_SET_HEIGHT.save(deps.storage, &block_height)?;
 
     Ok(Response::new().add_event(new_change_rewarded_set_event(
@@ -66,9 +95,35 @@ pub fn try_write_rewarded_set(
         state.params.mixnode_rewarded_set_size,
         num_nodes as u32,
         current_interval,
+        &seed,
     )))
 }
 
+// every node of the rewarded set has to be put on exactly one of the mix layers
+fn validate_layer_assignment(
+    rewarded_set: &[IdentityKey],
+    layer_assignment: &[LayerAssignment],
+) -> Result<(), ContractError> {
+    if rewarded_set.len() != layer_assignment.len() {
+        return Err(ContractError::LayerAssignmentMismatch);
+    }
+
+    let rewarded_set = rewarded_set.iter().collect::<HashSet<_>>();
+    let mut assigned = HashSet::with_capacity(layer_assignment.len());
+    for assignment in layer_assignment {
+        if assignment.layer == Layer::Gateway {
+            return Err(ContractError::InvalidLayerAssignment {
+                identity: assignment.identity.clone(),
+            });
+        }
+        if !rewarded_set.contains(&assignment.identity) || !assigned.insert(&assignment.identity) {
+            return Err(ContractError::LayerAssignmentMismatch);
+        }
+    }
+
+    Ok(())
+}
+
 pub fn try_advance_interval(
     env: Env,
     storage: &mut dyn Storage,
@@ -97,7 +152,10 @@ pub fn try_advance_interval(
 
     storage::CURRENT_INTERVAL.save(storage, &next_interval)?;
 
-    Ok(Response::new().add_event(new_advance_interval_event(next_interval)))
+    // pledge changes requested during the interval that has just finished take effect now
+    let response = apply_pending_pledge_changes(storage)?;
+
+    Ok(response.add_event(new_advance_interval_event(next_interval)))
 }
 
 #[cfg(test)]
@@ -105,7 +163,7 @@ mod tests {
     use super::*;
     use crate::support::tests::test_helpers;
     use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::Timestamp;
+    use cosmwasm_std::{Binary, Timestamp};
     use mixnet_contract_common::{Interval, RewardedSetNodeStatus};
     use std::time::Duration;
     use time::OffsetDateTime;
@@ -125,6 +183,14 @@ mod tests {
         storage::CURRENT_REWARDED_SET_HEIGHT
             .save(deps.as_mut().storage, &last_update)
             .unwrap();
+        let seed = RewardedSetSeed::new(
+            last_update + crate::constants::REWARDED_SET_REFRESH_BLOCKS,
+            [42u8; REWARDED_SET_SEED_LENGTH],
+        );
+        let layer_assignment = full_rewarded_set
+            .iter()
+            .map(|identity| LayerAssignment::new(identity.clone(), Layer::One))
+            .collect::<Vec<_>>();
 
         // can only be performed by the permitted validator
         let dummy_sender = mock_info("dummy_sender", &[]);
@@ -135,7 +201,9 @@ mod tests {
                 env.clone(),
                 dummy_sender,
                 full_rewarded_set.clone(),
-                current_state.params.mixnode_active_set_size
+                current_state.params.mixnode_active_set_size,
+                seed.clone(),
+                layer_assignment.clone()
             )
         );
 
@@ -150,7 +218,9 @@ mod tests {
                 env.clone(),
                 authorised_sender.clone(),
                 full_rewarded_set.clone(),
-                123
+                123,
+                seed.clone(),
+                layer_assignment.clone()
             )
         );
 
@@ -167,7 +237,9 @@ mod tests {
                 env.clone(),
                 authorised_sender.clone(),
                 bigger_set,
-                current_state.params.mixnode_active_set_size
+                current_state.params.mixnode_active_set_size,
+                seed.clone(),
+                layer_assignment.clone()
             )
         );
 
@@ -184,17 +256,106 @@ mod tests {
                 env.clone(),
                 authorised_sender.clone(),
                 full_rewarded_set.clone(),
-                current_state.params.mixnode_active_set_size
+                current_state.params.mixnode_active_set_size,
+                seed.clone(),
+                layer_assignment.clone()
+            )
+        );
+
+        // the seed must be derived from the first block at which the update was possible
+        env.block.height = last_update + crate::constants::REWARDED_SET_REFRESH_BLOCKS + 10;
+        let mut later_seed = seed.clone();
+        later_seed.height += 1;
+        assert_eq!(
+            Err(ContractError::UnexpectedRewardedSetSeedHeight {
+                received: later_seed.height,
+                expected: seed.height,
+            }),
+            try_write_rewarded_set(
+                deps.as_mut(),
+                env.clone(),
+                authorised_sender.clone(),
+                full_rewarded_set.clone(),
+                current_state.params.mixnode_active_set_size,
+                later_seed,
+                layer_assignment.clone()
+            )
+        );
+
+        // and it must be a valid hash
+        let mut short_seed = seed.clone();
+        short_seed.block_hash = Binary::from(vec![42u8; 16]);
+        assert_eq!(
+            Err(ContractError::InvalidRewardedSetSeedLength {
+                received: 16,
+                expected: REWARDED_SET_SEED_LENGTH,
+            }),
+            try_write_rewarded_set(
+                deps.as_mut(),
+                env.clone(),
+                authorised_sender.clone(),
+                full_rewarded_set.clone(),
+                current_state.params.mixnode_active_set_size,
+                short_seed,
+                layer_assignment.clone()
+            )
+        );
+
+        // every node of the rewarded set must be put on exactly one mix layer
+        let mut incomplete_assignment = layer_assignment.clone();
+        incomplete_assignment.pop();
+        assert_eq!(
+            Err(ContractError::LayerAssignmentMismatch),
+            try_write_rewarded_set(
+                deps.as_mut(),
+                env.clone(),
+                authorised_sender.clone(),
+                full_rewarded_set.clone(),
+                current_state.params.mixnode_active_set_size,
+                seed.clone(),
+                incomplete_assignment.clone()
+            )
+        );
+
+        let mut duplicate_assignment = incomplete_assignment.clone();
+        duplicate_assignment.push(incomplete_assignment[0].clone());
+        assert_eq!(
+            Err(ContractError::LayerAssignmentMismatch),
+            try_write_rewarded_set(
+                deps.as_mut(),
+                env.clone(),
+                authorised_sender.clone(),
+                full_rewarded_set.clone(),
+                current_state.params.mixnode_active_set_size,
+                seed.clone(),
+                duplicate_assignment
+            )
+        );
+
+        let mut gateway_assignment = layer_assignment.clone();
+        gateway_assignment[0].layer = Layer::Gateway;
+        assert_eq!(
+            Err(ContractError::InvalidLayerAssignment {
+                identity: gateway_assignment[0].identity.clone()
+            }),
+            try_write_rewarded_set(
+                deps.as_mut(),
+                env.clone(),
+                authorised_sender.clone(),
+                full_rewarded_set.clone(),
+                current_state.params.mixnode_active_set_size,
+                seed.clone(),
+                gateway_assignment
             )
         );
 
         // after successful rewarded set write, all internal storage structures are updated appropriately
-        env.block.height = last_update + crate::constants::REWARDED_SET_REFRESH_BLOCKS;
         let expected_response = Response::new().add_event(new_change_rewarded_set_event(
             current_state.params.mixnode_active_set_size,
             current_state.params.mixnode_rewarded_set_size,
             full_rewarded_set.len() as u32,
             0,
+            &seed,
         ));
 
         assert_eq!(
@@ -204,7 +365,9 @@ mod tests {
                 env.clone(),
                 authorised_sender,
                 full_rewarded_set.clone(),
-                current_state.params.mixnode_active_set_size
+                current_state.params.mixnode_active_set_size,
+                seed.clone(),
+                layer_assignment.clone()
             )
         );
 
@@ -227,6 +390,20 @@ mod tests {
         }
         assert!(storage::REWARDED_SET_HEIGHTS_FOR_INTERVAL
             .has(deps.as_ref().storage, (0, env.block.height)));
+        for assigned in layer_assignment {
+            assert_eq!(
+                assigned.layer,
+                storage::LAYER_ASSIGNMENTS
+                    .load(deps.as_ref().storage, (env.block.height, assigned.identity))
+                    .unwrap()
+            )
+        }
+        assert_eq!(
+            seed,
+            storage::REWARDED_SET_SEEDS
+                .load(deps.as_ref().storage, env.block.height)
+                .unwrap()
+        );
         assert_eq!(
             env.block.height,
             storage::CURRENT_REWARDED_SET_HEIGHT
@@ -5,9 +5,12 @@ mod constants;
 pub mod contract;
 mod delegations;
 mod error;
+mod families;
 mod gateways;
 mod interval;
 mod mixnet_contract_settings;
 mod mixnodes;
 mod rewards;
+mod slashing;
 mod support;
+mod withdrawals;
@@ -45,6 +45,9 @@ pub(crate) mod tests {
                 minimum_gateway_pledge: 456u128.into(),
                 mixnode_rewarded_set_size: 1000,
                 mixnode_active_set_size: 500,
+                unbonding_period: 86400,
+                slashing: Default::default(),
+                gateway_rewarding: Default::default(),
             },
         };
 
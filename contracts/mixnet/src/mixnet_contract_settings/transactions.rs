@@ -35,6 +35,16 @@ pub(crate) fn try_update_contract_settings(
         return Err(ContractError::InvalidActiveSetSize);
     }
 
+    if params.slashing.uptime_threshold > 100 || params.slashing.slashed_percent > 100 {
+        return Err(ContractError::InvalidSlashingParams);
+    }
+
+    if params.gateway_rewarding.reward_pool_share_percent > 100
+        || params.gateway_rewarding.operator_share_percent > 100
+    {
+        return Err(ContractError::InvalidGatewayRewardingParams);
+    }
+
     let response = Response::new().add_event(new_settings_update_event(&state.params, &params));
 
     state.params = params;
@@ -52,7 +62,9 @@ pub mod tests {
     use crate::support::tests::test_helpers;
     use cosmwasm_std::testing::mock_info;
     use cosmwasm_std::Response;
-    use mixnet_contract_common::ContractStateParams;
+    use mixnet_contract_common::{
+        ContractStateParams, GatewayRewardingParams, SlashingDestination, SlashingParams,
+    };
 
     #[test]
     fn updating_contract_settings() {
@@ -63,6 +75,19 @@ pub mod tests {
             minimum_gateway_pledge: INITIAL_GATEWAY_PLEDGE,
             mixnode_rewarded_set_size: 100,
             mixnode_active_set_size: 50,
+            unbonding_period: 3600,
+            slashing: SlashingParams {
+                uptime_threshold: 50,
+                poor_intervals: 3,
+                slashed_percent: 5,
+                slash_delegations: false,
+                destination: SlashingDestination::Burn,
+            },
+            gateway_rewarding: GatewayRewardingParams {
+                reward_pool_share_percent: 10,
+                rewarded_set_size: 50,
+                operator_share_percent: 20,
+            },
         };
 
         let initial_params = storage::CONTRACT_STATE
@@ -106,9 +131,23 @@ pub mod tests {
 
         // error is thrown for 0 size active set
         let info = mock_info("creator", &[]);
-        let mut new_params = current_state.params;
+        let mut new_params = current_state.params.clone();
         new_params.mixnode_active_set_size = 0;
         let res = try_update_contract_settings(deps.as_mut(), info, new_params);
         assert_eq!(Err(ContractError::ZeroActiveSet), res);
+
+        // error is thrown for slashing more than the entire pledge
+        let info = mock_info("creator", &[]);
+        let mut new_params = current_state.params.clone();
+        new_params.slashing.slashed_percent = 101;
+        let res = try_update_contract_settings(deps.as_mut(), info, new_params);
+        assert_eq!(Err(ContractError::InvalidSlashingParams), res);
+
+        // error is thrown for giving the operator more than the entire gateway reward
+        let info = mock_info("creator", &[]);
+        let mut new_params = current_state.params;
+        new_params.gateway_rewarding.operator_share_percent = 101;
+        let res = try_update_contract_settings(deps.as_mut(), info, new_params);
+        assert_eq!(Err(ContractError::InvalidGatewayRewardingParams), res);
     }
 }
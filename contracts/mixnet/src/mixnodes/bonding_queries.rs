@@ -2,10 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::storage;
+use crate::families::storage as families_storage;
 use cosmwasm_std::{Deps, Order, StdResult};
 use cw_storage_plus::Bound;
 use mixnet_contract_common::{
-    IdentityKey, MixNodeBond, MixOwnershipResponse, PagedMixnodeResponse,
+    IdentityKey, MixNodeBond, MixOwnershipResponse, PagedMixnodeResponse, PendingPledgeChange,
 };
 
 pub fn query_mixnodes_paged(
@@ -25,20 +26,27 @@ pub fn query_mixnodes_paged(
         .map(|res| res.map(|item| item.1))
         .map(|stored_bond| {
             // I really don't like this additional read per entry, but I don't see an obvious way to remove it
-            stored_bond.map(|stored_bond| {
+            stored_bond.and_then(|stored_bond| {
                 let total_delegation =
-                    storage::TOTAL_DELEGATION.load(deps.storage, stored_bond.identity());
-                total_delegation
-                    .map(|total_delegation| stored_bond.attach_delegation(total_delegation))
+                    storage::TOTAL_DELEGATION.load(deps.storage, stored_bond.identity())?;
+                let family = families_storage::family_head(deps.storage, stored_bond.identity())?;
+                Ok(stored_bond.attach_delegation_and_family(total_delegation, family))
             })
         })
-        .collect::<StdResult<StdResult<Vec<MixNodeBond>>>>()??;
+        .collect::<StdResult<Vec<MixNodeBond>>>()?;
 
     let start_next_after = nodes.last().map(|node| node.identity().clone());
 
     Ok(PagedMixnodeResponse::new(nodes, limit, start_next_after))
 }
 
+pub fn query_pending_pledge_change(
+    deps: Deps<'_>,
+    mix_identity: IdentityKey,
+) -> StdResult<Option<PendingPledgeChange>> {
+    storage::PENDING_PLEDGE_CHANGES.may_load(deps.storage, &mix_identity)
+}
+
 pub fn query_owns_mixnode(deps: Deps<'_>, address: String) -> StdResult<MixOwnershipResponse> {
     let validated_addr = deps.api.addr_validate(&address)?;
     let stored_bond = storage::mixnodes()
@@ -52,7 +60,8 @@ pub fn query_owns_mixnode(deps: Deps<'_>, address: String) -> StdResult<MixOwner
         Some(bond) => {
             let total_delegation =
                 storage::TOTAL_DELEGATION.may_load(deps.storage, bond.identity())?;
-            Some(bond.attach_delegation(total_delegation.unwrap_or_default()))
+            let family = families_storage::family_head(deps.storage, bond.identity())?;
+            Some(bond.attach_delegation_and_family(total_delegation.unwrap_or_default(), family))
         }
     };
 
@@ -225,8 +234,12 @@ pub(crate) mod tests {
         assert!(res.mixnode.is_some());
 
         // but after unbonding it, he doesn't own one anymore
-        crate::mixnodes::transactions::try_remove_mixnode(deps.as_mut(), mock_info("fred", &[]))
-            .unwrap();
+        crate::mixnodes::transactions::try_remove_mixnode(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("fred", &[]),
+        )
+        .unwrap();
 
         let res = query_owns_mixnode(deps.as_ref(), "fred".to_string()).unwrap();
         assert!(res.mixnode.is_none());
@@ -1,10 +1,13 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::families::storage as families_storage;
 use config::defaults::DENOM;
 use cosmwasm_std::{StdResult, Storage, Uint128};
 use cw_storage_plus::{Index, IndexList, IndexedMap, Map, UniqueIndex};
-use mixnet_contract_common::{Addr, Coin, IdentityKeyRef, Layer, MixNode, MixNodeBond};
+use mixnet_contract_common::{
+    Addr, Coin, IdentityKey, IdentityKeyRef, Layer, MixNode, MixNodeBond, PendingPledgeChange,
+};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -12,14 +15,22 @@ use std::fmt::{Display, Formatter};
 const TOTAL_DELEGATION_NAMESPACE: &str = "td";
 const MIXNODES_PK_NAMESPACE: &str = "mn";
 const MIXNODES_OWNER_IDX_NAMESPACE: &str = "mno";
+const PENDING_PLEDGE_CHANGES_NAMESPACE: &str = "ppc";
 
 // paged retrieval limits for all queries and transactions
 pub(crate) const BOND_PAGE_MAX_LIMIT: u32 = 75;
 pub(crate) const BOND_PAGE_DEFAULT_LIMIT: u32 = 50;
 
+// maximum number of pending pledge changes applied when advancing a single interval
+pub(crate) const PLEDGE_CHANGES_PER_INTERVAL_ADVANCE: usize = 100;
+
 pub(crate) const TOTAL_DELEGATION: Map<'_, IdentityKeyRef<'_>, Uint128> =
     Map::new(TOTAL_DELEGATION_NAMESPACE);
 
+// pledge changes that are going to be applied when the current interval is advanced
+pub(crate) const PENDING_PLEDGE_CHANGES: Map<'_, IdentityKeyRef<'_>, PendingPledgeChange> =
+    Map::new(PENDING_PLEDGE_CHANGES_NAMESPACE);
+
 pub(crate) struct MixnodeBondIndex<'a> {
     pub(crate) owner: UniqueIndex<'a, Addr, StoredMixnodeBond>,
 }
@@ -71,7 +82,11 @@ impl StoredMixnodeBond {
         }
     }
 
-    pub(crate) fn attach_delegation(self, total_delegation: Uint128) -> MixNodeBond {
+    pub(crate) fn attach_delegation_and_family(
+        self,
+        total_delegation: Uint128,
+        family: Option<IdentityKey>,
+    ) -> MixNodeBond {
         MixNodeBond {
             total_delegation: Coin {
                 denom: self.pledge_amount.denom.clone(),
@@ -83,6 +98,7 @@ impl StoredMixnodeBond {
             block_height: self.block_height,
             mix_node: self.mix_node,
             proxy: self.proxy,
+            family,
         }
     }
 
@@ -114,6 +130,7 @@ pub(crate) fn read_full_mixnode_bond(
         None => Ok(None),
         Some(stored_bond) => {
             let total_delegation = TOTAL_DELEGATION.may_load(storage, mix_identity)?;
+            let family = families_storage::family_head(storage, mix_identity)?;
             Ok(Some(MixNodeBond {
                 pledge_amount: stored_bond.pledge_amount,
                 total_delegation: Coin {
@@ -125,6 +142,7 @@ pub(crate) fn read_full_mixnode_bond(
                 block_height: stored_bond.block_height,
                 mix_node: stored_bond.mix_node,
                 proxy: stored_bond.proxy,
+                family,
             }))
         }
     }
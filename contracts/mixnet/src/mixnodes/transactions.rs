@@ -3,16 +3,26 @@
 
 use super::storage;
 use crate::error::ContractError;
+use crate::families::transactions as families_transactions;
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
 use crate::mixnodes::layer_queries::query_layer_distribution;
 use crate::mixnodes::storage::StoredMixnodeBond;
+use crate::rewards::storage as rewards_storage;
 use crate::support::helpers::{ensure_no_existing_bond, validate_node_identity_signature};
+use crate::withdrawals::transactions as withdrawals_transactions;
 use config::defaults::DENOM;
 use cosmwasm_std::{
-    wasm_execute, Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Response, Uint128,
+    wasm_execute, Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+    Storage, Uint128,
+};
+use mixnet_contract_common::events::{
+    new_mixnode_bonding_event, new_mixnode_details_update_event,
+    new_mixnode_pledge_change_applied_event, new_mixnode_pledge_change_cancelled_event,
+    new_mixnode_pledge_change_event, new_mixnode_unbonding_event, INSUFFICIENT_PLEDGE_VALUE,
+};
+use mixnet_contract_common::{
+    MixNode, MixNodeDetailsUpdate, PendingPledgeChange, PendingWithdrawalKind, PledgeChange,
 };
-use mixnet_contract_common::events::{new_mixnode_bonding_event, new_mixnode_unbonding_event};
-use mixnet_contract_common::MixNode;
 use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
 use vesting_contract_common::one_ucoin;
 
@@ -145,19 +155,25 @@ fn _try_add_mixnode(
 
 pub fn try_remove_mixnode_on_behalf(
     deps: DepsMut<'_>,
+    env: Env,
     info: MessageInfo,
     owner: String,
 ) -> Result<Response, ContractError> {
     let proxy = info.sender;
-    _try_remove_mixnode(deps, &owner, Some(proxy))
+    _try_remove_mixnode(deps, env, &owner, Some(proxy))
 }
 
-pub fn try_remove_mixnode(deps: DepsMut<'_>, info: MessageInfo) -> Result<Response, ContractError> {
-    _try_remove_mixnode(deps, info.sender.as_ref(), None)
+pub fn try_remove_mixnode(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    _try_remove_mixnode(deps, env, info.sender.as_ref(), None)
 }
 
 pub(crate) fn _try_remove_mixnode(
     deps: DepsMut<'_>,
+    env: Env,
     owner: &str,
     proxy: Option<Addr>,
 ) -> Result<Response, ContractError> {
@@ -181,38 +197,79 @@ pub(crate) fn _try_remove_mixnode(
             incoming: proxy.map_or_else(|| "None".to_string(), |a| a.as_str().to_string()),
         });
     }
-    // send bonded funds back to the bond owner
-    let return_tokens = BankMsg::Send {
-        to_address: proxy.as_ref().unwrap_or(&owner).to_string(),
-        amount: vec![mixnode_bond.pledge_amount()],
-    };
+    // a pledge change that hasn't been applied yet is discarded, but if it was an increase,
+    // the additional funds have to be returned alongside the pledge
+    let mut returned_amount = mixnode_bond.pledge_amount();
+    if let Some(pending) =
+        storage::PENDING_PLEDGE_CHANGES.may_load(deps.storage, mixnode_bond.identity())?
+    {
+        if let PledgeChange::Increase(increase) = pending.change {
+            returned_amount.amount += increase.amount;
+        }
+        storage::PENDING_PLEDGE_CHANGES.remove(deps.storage, mixnode_bond.identity());
+    }
+
+    // any operator rewards that haven't been claimed yet are returned alongside the pledge
+    returned_amount.amount +=
+        rewards_storage::take_operator_reward(deps.storage, mixnode_bond.identity())?;
 
     // remove the bond
     storage::mixnodes().remove(deps.storage, mixnode_bond.identity())?;
 
+    // the node can no longer be part of any family
+    let family_event =
+        families_transactions::remove_from_family(deps.storage, &owner, mixnode_bond.identity())?;
+
     // decrement layer count
     mixnet_params_storage::decrement_layer_count(deps.storage, mixnode_bond.layer)?;
 
-    let mut response = Response::new();
-
-    if let Some(proxy) = &proxy {
-        let msg = VestingContractExecuteMsg::TrackUnbondMixnode {
-            owner: owner.as_str().to_string(),
-            amount: mixnode_bond.pledge_amount(),
+    let unbonding_period = withdrawals_transactions::unbonding_period(deps.storage)?;
+    let response = if unbonding_period > 0 {
+        // the funds are held by the contract until the unbonding period is over
+        withdrawals_transactions::hold_pending_withdrawal(
+            deps.storage,
+            &env,
+            owner.clone(),
+            proxy.clone(),
+            returned_amount.clone(),
+            PendingWithdrawalKind::MixnodeUnbonding {
+                mix_identity: mixnode_bond.identity().clone(),
+            },
+            unbonding_period,
+        )?
+    } else {
+        // send bonded funds back to the bond owner
+        let return_tokens = BankMsg::Send {
+            to_address: proxy.as_ref().unwrap_or(&owner).to_string(),
+            amount: vec![returned_amount.clone()],
         };
 
-        let track_unbond_message = wasm_execute(proxy, &msg, vec![one_ucoin()])?;
-        response = response.add_message(track_unbond_message);
-    }
+        let mut response = Response::new();
+
+        if let Some(proxy) = &proxy {
+            let msg = VestingContractExecuteMsg::TrackUnbondMixnode {
+                owner: owner.as_str().to_string(),
+                amount: returned_amount.clone(),
+            };
 
-    let response = response.add_message(return_tokens);
+            let track_unbond_message = wasm_execute(proxy, &msg, vec![one_ucoin()])?;
+            response = response.add_message(track_unbond_message);
+        }
+
+        response.add_message(return_tokens)
+    };
 
-    Ok(response.add_event(new_mixnode_unbonding_event(
+    let response = response.add_event(new_mixnode_unbonding_event(
         &owner,
         &proxy,
-        &mixnode_bond.pledge_amount,
+        &returned_amount,
         mixnode_bond.identity(),
-    )))
+    ));
+
+    Ok(match family_event {
+        Some(event) => response.add_event(event),
+        None => response,
+    })
 }
 
 pub(crate) fn try_update_mixnode_config(
@@ -292,6 +349,277 @@ pub(crate) fn _try_update_mixnode_config(
     Ok(response)
 }
 
+pub(crate) fn try_update_mixnode_details(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    details: MixNodeDetailsUpdate,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(info.sender.as_ref())?;
+    _try_update_mixnode_details(deps, details, owner, None)
+}
+
+pub(crate) fn try_update_mixnode_details_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    details: MixNodeDetailsUpdate,
+    owner: String,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let proxy = deps.api.addr_validate(info.sender.as_ref())?;
+    _try_update_mixnode_details(deps, details, owner, Some(proxy))
+}
+
+// note that unlike the config update, this does not reset the bond block height,
+// as the bond, pledge and all delegations remain unchanged
+pub(crate) fn _try_update_mixnode_details(
+    deps: DepsMut<'_>,
+    details: MixNodeDetailsUpdate,
+    owner: Addr,
+    proxy: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let mixnode_bond = storage::mixnodes()
+        .idx
+        .owner
+        .item(deps.storage, owner.clone())?
+        .ok_or(ContractError::NoAssociatedMixNodeBond {
+            owner: owner.clone(),
+        })?
+        .1;
+
+    if proxy != mixnode_bond.proxy {
+        return Err(ContractError::ProxyMismatch {
+            existing: mixnode_bond
+                .proxy
+                .map_or_else(|| "None".to_string(), |a| a.as_str().to_string()),
+            incoming: proxy.map_or_else(|| "None".to_string(), |a| a.as_str().to_string()),
+        });
+    }
+
+    let event = new_mixnode_details_update_event(&owner, &proxy, mixnode_bond.identity(), &details);
+
+    storage::mixnodes().update(deps.storage, mixnode_bond.identity(), |mixnode_bond_opt| {
+        mixnode_bond_opt
+            .map(|mut mixnode_bond| {
+                mixnode_bond.mix_node.apply_details_update(details);
+                mixnode_bond
+            })
+            .ok_or(ContractError::NoBondFound)
+    })?;
+
+    let mut response = Response::new().add_event(event);
+
+    if let Some(proxy) = proxy {
+        // Returns one_ucoin proxy had to send in order to execute the contract to contract transaction
+        let return_one_ucoin = BankMsg::Send {
+            to_address: proxy.as_str().to_string(),
+            amount: vec![one_ucoin()],
+        };
+        response = response.add_message(return_one_ucoin);
+    }
+
+    Ok(response)
+}
+
+pub(crate) fn try_increase_mixnode_pledge(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let increase = validate_pledge_increase(info.funds)?;
+    let owner = deps.api.addr_validate(info.sender.as_ref())?;
+    _try_change_mixnode_pledge(deps, PledgeChange::Increase(increase), owner, None)
+}
+
+pub(crate) fn try_increase_mixnode_pledge_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    owner: String,
+) -> Result<Response, ContractError> {
+    let increase = validate_pledge_increase(info.funds)?;
+    let owner = deps.api.addr_validate(&owner)?;
+    let proxy = deps.api.addr_validate(info.sender.as_ref())?;
+    _try_change_mixnode_pledge(deps, PledgeChange::Increase(increase), owner, Some(proxy))
+}
+
+pub(crate) fn try_decrease_mixnode_pledge(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    amount: Coin,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(info.sender.as_ref())?;
+    _try_change_mixnode_pledge(deps, PledgeChange::Decrease(amount), owner, None)
+}
+
+pub(crate) fn try_decrease_mixnode_pledge_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    amount: Coin,
+    owner: String,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let proxy = deps.api.addr_validate(info.sender.as_ref())?;
+    _try_change_mixnode_pledge(deps, PledgeChange::Decrease(amount), owner, Some(proxy))
+}
+
+// the change is only recorded here and gets applied when the current interval is advanced,
+// so that the pledge used for rewarding stays the same throughout the entire interval
+pub(crate) fn _try_change_mixnode_pledge(
+    deps: DepsMut<'_>,
+    change: PledgeChange,
+    owner: Addr,
+    proxy: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let mixnode_bond = storage::mixnodes()
+        .idx
+        .owner
+        .item(deps.storage, owner.clone())?
+        .ok_or(ContractError::NoAssociatedMixNodeBond {
+            owner: owner.clone(),
+        })?
+        .1;
+
+    if proxy != mixnode_bond.proxy {
+        return Err(ContractError::ProxyMismatch {
+            existing: mixnode_bond
+                .proxy
+                .map_or_else(|| "None".to_string(), |a| a.as_str().to_string()),
+            incoming: proxy.map_or_else(|| "None".to_string(), |a| a.as_str().to_string()),
+        });
+    }
+
+    if storage::PENDING_PLEDGE_CHANGES.has(deps.storage, mixnode_bond.identity()) {
+        return Err(ContractError::PledgeChangeAlreadyPending {
+            identity: mixnode_bond.identity().clone(),
+        });
+    }
+
+    if let PledgeChange::Decrease(decrease) = &change {
+        if decrease.denom != DENOM {
+            return Err(ContractError::WrongDenom);
+        }
+        if decrease.amount.is_zero() {
+            return Err(ContractError::EmptyPledgeChange);
+        }
+
+        // rewards are accrued separately and only a single change can be pending at a time,
+        // so the pledge can only shrink through slashing before the change is applied.
+        // In that case the pending decrease gets cancelled, so it's sufficient to check
+        // the minimum against the current value.
+        let minimum_pledge = mixnet_params_storage::CONTRACT_STATE
+            .load(deps.storage)?
+            .params
+            .minimum_mixnode_pledge;
+        let remaining = change.apply_to(&mixnode_bond.pledge_amount).amount;
+        if remaining < minimum_pledge {
+            return Err(ContractError::InsufficientMixNodeBond {
+                received: remaining.u128(),
+                minimum: minimum_pledge.u128(),
+            });
+        }
+    }
+
+    let event = new_mixnode_pledge_change_event(&owner, &proxy, mixnode_bond.identity(), &change);
+
+    storage::PENDING_PLEDGE_CHANGES.save(
+        deps.storage,
+        mixnode_bond.identity(),
+        &PendingPledgeChange::new(mixnode_bond.identity().clone(), change.clone()),
+    )?;
+
+    let mut response = Response::new().add_event(event);
+
+    // when increasing the pledge, the proxy sends the actual funds rather than the 1ucoin
+    if let (Some(proxy), PledgeChange::Decrease(_)) = (proxy, change) {
+        // Returns one_ucoin proxy had to send in order to execute the contract to contract transaction
+        let return_one_ucoin = BankMsg::Send {
+            to_address: proxy.as_str().to_string(),
+            amount: vec![one_ucoin()],
+        };
+        response = response.add_message(return_one_ucoin);
+    }
+
+    Ok(response)
+}
+
+/// Applies pledge changes requested during the interval that has just finished
+/// and returns the withdrawn funds to the owners (or their proxies).
+///
+/// To keep the gas usage of advancing the interval bounded, at most
+/// `PLEDGE_CHANGES_PER_INTERVAL_ADVANCE` changes are applied at once. Any remaining ones
+/// stay pending and get applied when the following interval is advanced.
+pub(crate) fn apply_pending_pledge_changes(
+    storage: &mut dyn Storage,
+) -> Result<Response, ContractError> {
+    let pending_changes = storage::PENDING_PLEDGE_CHANGES
+        .range(storage, None, None, Order::Ascending)
+        .take(storage::PLEDGE_CHANGES_PER_INTERVAL_ADVANCE)
+        .map(|res| res.map(|item| item.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut response = Response::new();
+    for pending in pending_changes {
+        storage::PENDING_PLEDGE_CHANGES.remove(storage, &pending.mix_identity);
+
+        // if the node got unbonded in the meantime, the change has already been dealt with
+        let mut mixnode_bond = match storage::mixnodes().may_load(storage, &pending.mix_identity)? {
+            Some(mixnode_bond) => mixnode_bond,
+            None => continue,
+        };
+
+        // slashing cancels any pending decrease, but never send out more than what's pledged
+        if let PledgeChange::Decrease(decrease) = &pending.change {
+            if decrease.amount > mixnode_bond.pledge_amount.amount {
+                response = response.add_event(new_mixnode_pledge_change_cancelled_event(
+                    &pending.mix_identity,
+                    &pending.change,
+                    INSUFFICIENT_PLEDGE_VALUE,
+                ));
+                continue;
+            }
+        }
+
+        mixnode_bond.pledge_amount = pending.change.apply_to(&mixnode_bond.pledge_amount);
+        storage::mixnodes().save(storage, &pending.mix_identity, &mixnode_bond)?;
+
+        if let PledgeChange::Decrease(decrease) = &pending.change {
+            let return_tokens = BankMsg::Send {
+                to_address: mixnode_bond
+                    .proxy
+                    .as_ref()
+                    .unwrap_or(&mixnode_bond.owner)
+                    .to_string(),
+                amount: vec![decrease.clone()],
+            };
+            response = response.add_message(return_tokens);
+
+            if let Some(proxy) = &mixnode_bond.proxy {
+                let msg = VestingContractExecuteMsg::TrackDecreaseMixnodePledge {
+                    owner: mixnode_bond.owner.as_str().to_string(),
+                    amount: decrease.clone(),
+                };
+
+                let track_decrease_message = wasm_execute(proxy, &msg, vec![one_ucoin()])?;
+                response = response.add_message(track_decrease_message);
+            }
+        }
+
+        response = response.add_event(new_mixnode_pledge_change_applied_event(
+            &pending.mix_identity,
+            &pending.change,
+            &mixnode_bond.pledge_amount,
+        ));
+    }
+
+    Ok(response)
+}
+
+fn validate_pledge_increase(funds: Vec<Coin>) -> Result<Coin, ContractError> {
+    let increase = validate_mixnode_pledge(funds, Uint128::zero())?;
+    if increase.amount.is_zero() {
+        return Err(ContractError::EmptyPledgeChange);
+    }
+    Ok(increase)
+}
+
 fn validate_mixnode_pledge(
     mut pledge: Vec<Coin>,
     minimum_pledge: Uint128,
@@ -324,17 +652,21 @@ fn validate_mixnode_pledge(
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use crate::contract::{execute, query, INITIAL_MIXNODE_PLEDGE};
+    use crate::contract::{execute, query, INITIAL_MIXNODE_PLEDGE, INITIAL_UNBONDING_PERIOD};
     use crate::error::ContractError;
+    use crate::interval::storage as interval_storage;
     use crate::mixnodes::transactions::validate_mixnode_pledge;
     use crate::support::tests;
     use crate::support::tests::test_helpers;
+    use crate::withdrawals::storage as withdrawals_storage;
     use config::defaults::DENOM;
     use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::{coins, BankMsg, Response};
+    use cosmwasm_std::{coin, coins, BankMsg, Response, SubMsg, Timestamp};
     use cosmwasm_std::{from_binary, Addr, Uint128};
+    use mixnet_contract_common::events::new_pending_withdrawal_event;
     use mixnet_contract_common::{
-        ExecuteMsg, Layer, LayerDistribution, MixNode, PagedMixnodeResponse, QueryMsg,
+        ExecuteMsg, Layer, LayerDistribution, MixNode, MixNodeDetailsUpdate, PagedMixnodeResponse,
+        PendingWithdrawal, QueryMsg,
     };
 
     #[test]
@@ -594,22 +926,27 @@ pub mod tests {
         let msg = ExecuteMsg::UnbondMixnode {};
         let remove_fred = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
 
-        // we should see a funds transfer from the contract back to fred
-        let expected_message = BankMsg::Send {
-            to_address: String::from(info.sender),
-            amount: tests::fixtures::good_mixnode_pledge(),
-        };
+        // fred's pledge should be held by the contract until the unbonding period is over
+        let expected_withdrawal = PendingWithdrawal::new(
+            1,
+            info.sender,
+            None,
+            tests::fixtures::good_mixnode_pledge()[0].clone(),
+            PendingWithdrawalKind::MixnodeUnbonding {
+                mix_identity: fred_identity.clone(),
+            },
+            mock_env().block.time.seconds() + INITIAL_UNBONDING_PERIOD,
+        );
 
         // run the executor and check that we got back the correct results
-        let expected_response =
-            Response::new()
-                .add_message(expected_message)
-                .add_event(new_mixnode_unbonding_event(
-                    &Addr::unchecked("fred"),
-                    &None,
-                    &tests::fixtures::good_gateway_pledge()[0],
-                    &fred_identity,
-                ));
+        let expected_response = Response::new()
+            .add_event(new_pending_withdrawal_event(&expected_withdrawal))
+            .add_event(new_mixnode_unbonding_event(
+                &Addr::unchecked("fred"),
+                &None,
+                &tests::fixtures::good_gateway_pledge()[0],
+                &fred_identity,
+            ));
 
         assert_eq!(expected_response, remove_fred);
 
@@ -739,6 +1076,300 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn updating_mixnode_details() {
+        let sender = "bob";
+        let mut deps = test_helpers::init_contract();
+        let info = mock_info(sender, &[]);
+
+        let details = MixNodeDetailsUpdate {
+            host: "1.1.1.1".to_string(),
+            mix_port: 1790,
+            verloc_port: 1791,
+            http_api_port: 8080,
+            version: "0.13.0".to_string(),
+        };
+
+        // try updating a non existing mixnode bond
+        let msg = ExecuteMsg::UpdateMixnodeDetails {
+            details: details.clone(),
+        };
+        let ret = execute(deps.as_mut(), mock_env(), info.clone(), msg);
+        assert_eq!(
+            ret,
+            Err(ContractError::NoAssociatedMixNodeBond {
+                owner: Addr::unchecked(sender)
+            })
+        );
+
+        let identity = test_helpers::add_mixnode(
+            sender,
+            tests::fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+        test_helpers::save_dummy_delegation(deps.as_mut().storage, &identity, "delegator");
+        let bond_before = storage::mixnodes()
+            .load(deps.as_ref().storage, &identity)
+            .unwrap();
+
+        // the vesting contract can't update details of a liquid bond
+        let msg = ExecuteMsg::UpdateMixnodeDetailsOnBehalf {
+            details: details.clone(),
+            owner: sender.to_string(),
+        };
+        let ret = execute(deps.as_mut(), mock_env(), mock_info("proxy", &[]), msg);
+        assert!(matches!(ret, Err(ContractError::ProxyMismatch { .. })));
+
+        let msg = ExecuteMsg::UpdateMixnodeDetails {
+            details: details.clone(),
+        };
+        let mut env = mock_env();
+        env.block.height += 1000;
+        let res = execute(deps.as_mut(), env, info, msg).unwrap();
+        assert_eq!(1, res.events.len());
+
+        let bond_after = storage::mixnodes()
+            .load(deps.as_ref().storage, &identity)
+            .unwrap();
+        assert_eq!(details.host, bond_after.mix_node.host);
+        assert_eq!(details.mix_port, bond_after.mix_node.mix_port);
+        assert_eq!(details.verloc_port, bond_after.mix_node.verloc_port);
+        assert_eq!(details.http_api_port, bond_after.mix_node.http_api_port);
+        assert_eq!(details.version, bond_after.mix_node.version);
+
+        // everything else about the bond is left intact
+        assert_eq!(
+            bond_before.mix_node.identity_key,
+            bond_after.mix_node.identity_key
+        );
+        assert_eq!(
+            bond_before.mix_node.sphinx_key,
+            bond_after.mix_node.sphinx_key
+        );
+        assert_eq!(
+            bond_before.mix_node.profit_margin_percent,
+            bond_after.mix_node.profit_margin_percent
+        );
+        assert_eq!(bond_before.pledge_amount, bond_after.pledge_amount);
+        assert_eq!(bond_before.block_height, bond_after.block_height);
+        assert_eq!(bond_before.layer, bond_after.layer);
+        assert!(
+            test_helpers::read_delegation(deps.as_ref().storage, &identity, "delegator").is_some()
+        );
+    }
+
+    #[test]
+    fn changing_mixnode_pledge() {
+        let sender = "bob";
+        let mut deps = test_helpers::init_contract();
+        let mut env = mock_env();
+
+        let initial_pledge = Uint128::new(INITIAL_MIXNODE_PLEDGE.u128() * 2);
+        let identity =
+            test_helpers::add_mixnode(sender, coins(initial_pledge.u128(), DENOM), deps.as_mut());
+
+        // can't decrease the pledge below the minimum
+        let msg = ExecuteMsg::DecreaseMixnodePledge {
+            amount: coin(initial_pledge.u128() - 1, DENOM),
+        };
+        let ret = execute(deps.as_mut(), env.clone(), mock_info(sender, &[]), msg);
+        assert_eq!(
+            ret,
+            Err(ContractError::InsufficientMixNodeBond {
+                received: 1,
+                minimum: INITIAL_MIXNODE_PLEDGE.u128(),
+            })
+        );
+
+        // nor increase it by nothing
+        let msg = ExecuteMsg::IncreaseMixnodePledge {};
+        let ret = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(sender, &coins(0, DENOM)),
+            msg,
+        );
+        assert_eq!(ret, Err(ContractError::EmptyPledgeChange));
+
+        // the increase is only recorded until the interval is over
+        let msg = ExecuteMsg::IncreaseMixnodePledge {};
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(sender, &coins(1000, DENOM)),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(
+            initial_pledge,
+            test_helpers::read_mixnode_pledge_amount(deps.as_ref().storage, &identity).unwrap()
+        );
+        assert_eq!(
+            Some(PendingPledgeChange::new(
+                identity.clone(),
+                PledgeChange::Increase(coin(1000, DENOM))
+            )),
+            storage::PENDING_PLEDGE_CHANGES
+                .may_load(deps.as_ref().storage, &identity)
+                .unwrap()
+        );
+
+        // and only a single change can be pending at any time
+        let msg = ExecuteMsg::DecreaseMixnodePledge {
+            amount: coin(500, DENOM),
+        };
+        let ret = execute(deps.as_mut(), env.clone(), mock_info(sender, &[]), msg);
+        assert_eq!(
+            ret,
+            Err(ContractError::PledgeChangeAlreadyPending {
+                identity: identity.clone()
+            })
+        );
+
+        test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
+        assert_eq!(
+            initial_pledge + Uint128::new(1000),
+            test_helpers::read_mixnode_pledge_amount(deps.as_ref().storage, &identity).unwrap()
+        );
+        assert!(!storage::PENDING_PLEDGE_CHANGES.has(deps.as_ref().storage, &identity));
+
+        // decreased funds are returned once the change is applied
+        let msg = ExecuteMsg::DecreaseMixnodePledge {
+            amount: coin(500, DENOM),
+        };
+        let res = execute(deps.as_mut(), env.clone(), mock_info(sender, &[]), msg).unwrap();
+        assert!(res.messages.is_empty());
+
+        let next_interval = interval_storage::CURRENT_INTERVAL
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .next_interval();
+        env.block.time = Timestamp::from_seconds(next_interval.start_unix_timestamp() as u64 + 123);
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("anyone", &[]),
+            ExecuteMsg::AdvanceCurrentInterval {},
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0],
+            SubMsg::new(BankMsg::Send {
+                to_address: sender.to_string(),
+                amount: coins(500, DENOM),
+            })
+        );
+        assert_eq!(
+            initial_pledge + Uint128::new(500),
+            test_helpers::read_mixnode_pledge_amount(deps.as_ref().storage, &identity).unwrap()
+        );
+    }
+
+    #[test]
+    fn applying_pending_pledge_changes_is_bounded() {
+        let mut deps = test_helpers::init_contract();
+        let mut env = mock_env();
+
+        let changes = storage::PLEDGE_CHANGES_PER_INTERVAL_ADVANCE + 1;
+        for i in 0..changes {
+            let sender = format!("owner{}", i);
+            test_helpers::add_mixnode(
+                &sender,
+                tests::fixtures::good_mixnode_pledge(),
+                deps.as_mut(),
+            );
+            let msg = ExecuteMsg::IncreaseMixnodePledge {};
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(&sender, &coins(1000, DENOM)),
+                msg,
+            )
+            .unwrap();
+        }
+
+        // the changes that did not fit are left for the following interval
+        test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
+        let pending = storage::PENDING_PLEDGE_CHANGES
+            .range(deps.as_ref().storage, None, None, Order::Ascending)
+            .count();
+        assert_eq!(1, pending);
+
+        test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
+        let pending = storage::PENDING_PLEDGE_CHANGES
+            .range(deps.as_ref().storage, None, None, Order::Ascending)
+            .count();
+        assert_eq!(0, pending);
+    }
+
+    #[test]
+    fn decrease_exceeding_the_pledge_is_not_applied() {
+        let sender = "bob";
+        let mut deps = test_helpers::init_contract();
+        let identity = test_helpers::add_mixnode(
+            sender,
+            coins(INITIAL_MIXNODE_PLEDGE.u128() * 2, DENOM),
+            deps.as_mut(),
+        );
+
+        let msg = ExecuteMsg::DecreaseMixnodePledge {
+            amount: coin(1000, DENOM),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(sender, &[]), msg).unwrap();
+
+        // pretend the pledge has shrunk in the meantime
+        let mut bond = storage::mixnodes()
+            .load(deps.as_ref().storage, &identity)
+            .unwrap();
+        bond.pledge_amount = coin(500, DENOM);
+        storage::mixnodes()
+            .save(deps.as_mut().storage, &identity, &bond)
+            .unwrap();
+
+        let res = apply_pending_pledge_changes(deps.as_mut().storage).unwrap();
+        assert!(res.messages.is_empty());
+        assert_eq!(
+            res.events[0].ty,
+            mixnet_contract_common::events::MIXNODE_PLEDGE_CHANGE_CANCELLED_EVENT_TYPE
+        );
+        assert_eq!(
+            Uint128::new(500),
+            test_helpers::read_mixnode_pledge_amount(deps.as_ref().storage, &identity).unwrap()
+        );
+        assert!(!storage::PENDING_PLEDGE_CHANGES.has(deps.as_ref().storage, &identity));
+    }
+
+    #[test]
+    fn unbonding_returns_pending_pledge_increase() {
+        let sender = "bob";
+        let mut deps = test_helpers::init_contract();
+        let identity = test_helpers::add_mixnode(
+            sender,
+            tests::fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+
+        let msg = ExecuteMsg::IncreaseMixnodePledge {};
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &coins(1000, DENOM)),
+            msg,
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::UnbondMixnode {};
+        execute(deps.as_mut(), mock_env(), mock_info(sender, &[]), msg).unwrap();
+        let withdrawal = withdrawals_storage::pending_withdrawals()
+            .load(deps.as_ref().storage, 1)
+            .unwrap();
+        assert_eq!(
+            coin(INITIAL_MIXNODE_PLEDGE.u128() + 1000, DENOM),
+            withdrawal.amount
+        );
+        assert!(!storage::PENDING_PLEDGE_CHANGES.has(deps.as_ref().storage, &identity));
+    }
+
     #[test]
     fn validating_mixnode_bond() {
         // you must send SOME funds
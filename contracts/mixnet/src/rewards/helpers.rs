@@ -3,13 +3,17 @@
 
 use super::storage;
 use crate::error::ContractError;
+use crate::gateways::storage as gateways_storage;
 use crate::mixnodes::storage as mixnodes_storage;
 use cosmwasm_std::{Addr, Storage, Uint128};
 use mixnet_contract_common::mixnode::DelegatorRewardParams;
 use mixnet_contract_common::{
-    IdentityKey, IdentityKeyRef, PendingDelegatorRewarding, RewardingResult, RewardingStatus,
+    GatewayDelegatorRewardParams, GatewayRewardingStatus, IdentityKey, IdentityKeyRef,
+    PendingDelegatorRewarding, PendingGatewayDelegatorRewarding, RewardingResult, RewardingStatus,
 };
 
+// the rewards are only accrued, i.e. neither the pledge nor the delegations are increased
+// until the rewards are explicitly compounded
 pub(crate) fn update_post_rewarding_storage(
     storage: &mut dyn Storage,
     mix_identity: IdentityKeyRef<'_>,
@@ -20,31 +24,16 @@ pub(crate) fn update_post_rewarding_storage(
         return Ok(());
     }
 
-    // update pledge
     if operator_reward > Uint128::zero() {
-        mixnodes_storage::mixnodes().update(storage, mix_identity, |current_bond| {
-            match current_bond {
-                None => Err(ContractError::MixNodeBondNotFound {
-                    identity: mix_identity.to_string(),
-                }),
-                Some(mut mixnode_bond) => {
-                    mixnode_bond.pledge_amount.amount += operator_reward;
-                    Ok(mixnode_bond)
-                }
-            }
-        })?;
-    }
-
-    // update total_delegation
-    if delegators_reward > Uint128::zero() {
-        mixnodes_storage::TOTAL_DELEGATION.update(storage, mix_identity, |current_total| {
-            match current_total {
-                None => Err(ContractError::MixNodeBondNotFound {
-                    identity: mix_identity.to_string(),
-                }),
-                Some(current_total) => Ok(current_total + delegators_reward),
-            }
-        })?;
+        if mixnodes_storage::mixnodes()
+            .may_load(storage, mix_identity)?
+            .is_none()
+        {
+            return Err(ContractError::MixNodeBondNotFound {
+                identity: mix_identity.to_string(),
+            });
+        }
+        storage::accrue_operator_reward(storage, mix_identity, operator_reward)?;
     }
 
     // update reward pool
@@ -81,3 +70,61 @@ pub(crate) fn update_rewarding_status(
 
     Ok(())
 }
+
+// just like with mixnodes, the gateway rewards are only accrued
+pub(crate) fn update_post_gateway_rewarding_storage(
+    storage: &mut dyn Storage,
+    gateway_identity: IdentityKeyRef<'_>,
+    operator_reward: Uint128,
+    delegators_reward: Uint128,
+) -> Result<(), ContractError> {
+    if operator_reward == Uint128::zero() && delegators_reward == Uint128::zero() {
+        return Ok(());
+    }
+
+    if operator_reward > Uint128::zero() {
+        if gateways_storage::gateways()
+            .may_load(storage, gateway_identity)?
+            .is_none()
+        {
+            return Err(ContractError::GatewayBondNotFound {
+                identity: gateway_identity.to_string(),
+            });
+        }
+        storage::accrue_operator_reward(storage, gateway_identity, operator_reward)?;
+    }
+
+    // update reward pool
+    storage::decr_reward_pool(storage, operator_reward + delegators_reward)?;
+
+    Ok(())
+}
+
+pub(crate) fn update_gateway_rewarding_status(
+    storage: &mut dyn Storage,
+    interval_id: u32,
+    gateway_identity: IdentityKey,
+    rewarding_results: RewardingResult,
+    next_start: Option<Addr>,
+    delegators_rewarding_params: GatewayDelegatorRewardParams,
+) -> Result<(), ContractError> {
+    if let Some(next_start) = next_start {
+        storage::GATEWAY_REWARDING_STATUS.save(
+            storage,
+            (interval_id, gateway_identity),
+            &GatewayRewardingStatus::PendingNextDelegatorPage(PendingGatewayDelegatorRewarding {
+                running_results: rewarding_results,
+                next_start,
+                rewarding_params: delegators_rewarding_params,
+            }),
+        )?;
+    } else {
+        storage::GATEWAY_REWARDING_STATUS.save(
+            storage,
+            (interval_id, gateway_identity),
+            &GatewayRewardingStatus::Complete(rewarding_results),
+        )?;
+    }
+
+    Ok(())
+}
@@ -2,9 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::storage;
+use crate::support::helpers::generate_storage_key;
 use cosmwasm_std::Uint128;
 use cosmwasm_std::{Deps, StdResult};
-use mixnet_contract_common::{IdentityKey, MixnodeRewardingStatusResponse};
+use cw_storage_plus::PrimaryKey;
+use mixnet_contract_common::{
+    GatewayRewardingStatusResponse, IdentityKey, MixnodeRewardingStatusResponse,
+};
 
 pub(crate) fn query_reward_pool(deps: Deps<'_>) -> StdResult<Uint128> {
     storage::REWARD_POOL.load(deps.storage)
@@ -24,6 +28,48 @@ pub(crate) fn query_rewarding_status(
     Ok(MixnodeRewardingStatusResponse { status })
 }
 
+pub(crate) fn query_gateway_rewarding_status(
+    deps: Deps<'_>,
+    gateway_identity: IdentityKey,
+    interval_id: u32,
+) -> StdResult<GatewayRewardingStatusResponse> {
+    let status = storage::GATEWAY_REWARDING_STATUS
+        .may_load(deps.storage, (interval_id, gateway_identity))?;
+
+    Ok(GatewayRewardingStatusResponse { status })
+}
+
+pub(crate) fn query_pending_operator_reward(
+    deps: Deps<'_>,
+    mix_identity: IdentityKey,
+) -> StdResult<Uint128> {
+    Ok(storage::PENDING_OPERATOR_REWARDS
+        .may_load(deps.storage, &mix_identity)?
+        .unwrap_or_default())
+}
+
+pub(crate) fn query_pending_delegator_reward(
+    deps: Deps<'_>,
+    mix_identity: IdentityKey,
+    delegator: String,
+    proxy: Option<String>,
+) -> StdResult<Uint128> {
+    let delegator = deps.api.addr_validate(&delegator)?;
+    let proxy = proxy
+        .map(|proxy| deps.api.addr_validate(&proxy))
+        .transpose()?;
+
+    let storage_key = (
+        mix_identity,
+        generate_storage_key(&delegator, proxy.as_ref()),
+    )
+        .joined_key();
+
+    Ok(storage::PENDING_DELEGATOR_REWARDS
+        .may_load(deps.storage, storage_key)?
+        .unwrap_or_default())
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
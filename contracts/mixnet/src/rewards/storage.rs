@@ -5,13 +5,72 @@ use crate::error::ContractError;
 use config::defaults::TOTAL_SUPPLY;
 use cosmwasm_std::{StdResult, Storage, Uint128};
 use cw_storage_plus::{Item, Map};
-use mixnet_contract_common::{IdentityKey, RewardingStatus};
+use mixnet_contract_common::{
+    GatewayRewardingStatus, IdentityKey, IdentityKeyRef, RewardingStatus,
+};
 
 pub(crate) const REWARD_POOL: Item<'_, Uint128> = Item::new("pool");
 // TODO: Do we need a migration for this?
 pub(crate) const REWARDING_STATUS: Map<'_, (u32, IdentityKey), RewardingStatus> = Map::new("rm");
+pub(crate) const GATEWAY_REWARDING_STATUS: Map<'_, (u32, IdentityKey), GatewayRewardingStatus> =
+    Map::new("grm");
+// number of gateways that received a non-zero reward in given interval. It must never exceed
+// the size of the gateway rewarded set.
+pub(crate) const REWARDED_GATEWAYS_COUNT: Map<'_, u32, u32> = Map::new("rgc");
+
+// neither mixnode nor gateway rewards are added to the pledges and delegations directly,
+// instead they're accrued here until they're explicitly claimed (or compounded).
+// Both kinds of nodes share the maps as they're keyed by the (unique) node identity
+pub(crate) const PENDING_OPERATOR_REWARDS: Map<'_, IdentityKeyRef<'_>, Uint128> = Map::new("por");
+// keyed by the same composite key as the delegation that has accrued the rewards
+pub(crate) const PENDING_DELEGATOR_REWARDS: Map<'_, Vec<u8>, Uint128> = Map::new("pdr");
+
+pub(crate) fn accrue_operator_reward(
+    storage: &mut dyn Storage,
+    mix_identity: IdentityKeyRef<'_>,
+    reward: Uint128,
+) -> StdResult<()> {
+    PENDING_OPERATOR_REWARDS.update(storage, mix_identity, |pending| {
+        StdResult::Ok(pending.unwrap_or_default() + reward)
+    })?;
+    Ok(())
+}
+
+pub(crate) fn accrue_delegator_reward(
+    storage: &mut dyn Storage,
+    delegation_key: Vec<u8>,
+    reward: Uint128,
+) -> StdResult<()> {
+    PENDING_DELEGATOR_REWARDS.update(storage, delegation_key, |pending| {
+        StdResult::Ok(pending.unwrap_or_default() + reward)
+    })?;
+    Ok(())
+}
+
+// removes and returns all rewards accrued by the operator of the specified node
+pub(crate) fn take_operator_reward(
+    storage: &mut dyn Storage,
+    mix_identity: IdentityKeyRef<'_>,
+) -> StdResult<Uint128> {
+    let pending = PENDING_OPERATOR_REWARDS
+        .may_load(storage, mix_identity)?
+        .unwrap_or_default();
+    PENDING_OPERATOR_REWARDS.remove(storage, mix_identity);
+    Ok(pending)
+}
+
+// removes and returns all rewards accrued by the specified delegation
+pub(crate) fn take_delegator_reward(
+    storage: &mut dyn Storage,
+    delegation_key: Vec<u8>,
+) -> StdResult<Uint128> {
+    let pending = PENDING_DELEGATOR_REWARDS
+        .may_load(storage, delegation_key.clone())?
+        .unwrap_or_default();
+    PENDING_DELEGATOR_REWARDS.remove(storage, delegation_key);
+    Ok(pending)
+}
 
-#[allow(dead_code)]
 pub fn incr_reward_pool(
     amount: Uint128,
     storage: &mut dyn Storage,
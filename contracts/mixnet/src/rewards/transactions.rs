@@ -5,24 +5,38 @@ use super::storage;
 use crate::constants;
 use crate::delegations::storage as delegations_storage;
 use crate::error::ContractError;
+use crate::gateways::storage as gateways_storage;
 use crate::interval::storage as interval_storage;
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
 use crate::mixnodes::storage as mixnodes_storage;
 use crate::rewards::helpers;
-use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128};
+use crate::slashing::transactions::ensure_delegations_not_being_slashed;
+use crate::support::helpers::generate_storage_key;
+use config::defaults::DENOM;
+use cosmwasm_std::{
+    coin, wasm_execute, Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Response, StdResult,
+    Storage, Uint128,
+};
 use cw_storage_plus::{Bound, PrimaryKey};
 use mixnet_contract_common::events::{
+    new_delegator_reward_claim_event, new_delegator_reward_compounding_event,
+    new_gateway_delegators_rewarding_event, new_gateway_rewarding_event,
     new_mix_delegators_rewarding_event, new_mix_operator_rewarding_event,
-    new_not_found_mix_operator_rewarding_event, new_too_fresh_bond_mix_operator_rewarding_event,
+    new_not_found_gateway_rewarding_event, new_not_found_mix_operator_rewarding_event,
+    new_operator_reward_claim_event, new_too_fresh_bond_gateway_rewarding_event,
+    new_too_fresh_bond_mix_operator_rewarding_event, new_zero_uptime_gateway_rewarding_event,
     new_zero_uptime_mix_operator_rewarding_event,
 };
 use mixnet_contract_common::mixnode::{DelegatorRewardParams, NodeRewardParams};
 use mixnet_contract_common::{
-    IdentityKey, RewardingResult, RewardingStatus, MIXNODE_DELEGATORS_PAGE_LIMIT,
+    GatewayDelegatorRewardParams, GatewayRewardParams, GatewayRewardingStatus, IdentityKey,
+    RewardingResult, RewardingStatus, GATEWAY_DELEGATORS_PAGE_LIMIT, MIXNODE_DELEGATORS_PAGE_LIMIT,
 };
+use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
+use vesting_contract_common::one_ucoin;
 
 #[derive(Debug)]
-struct MixDelegationRewardingResult {
+struct DelegationRewardingResult {
     total_rewarded: Uint128,
     start_next: Option<Addr>,
 }
@@ -37,7 +51,7 @@ struct MixDelegationRewardingResult {
 /// * `storage`: reference (kinda) to the underlying storage pool of the contract used to read the current state
 /// * `info`: contains the essential info for authorization, such as identity of the call
 /// * `interval_id`: expected id of the current interval sent alongside the call
-fn verify_rewarding_state(
+pub(crate) fn verify_rewarding_state(
     storage: &dyn Storage,
     info: MessageInfo,
     interval_id: u32,
@@ -69,7 +83,7 @@ fn reward_mix_delegators(
     mix_identity: IdentityKey,
     start: Option<Addr>,
     params: DelegatorRewardParams,
-) -> StdResult<MixDelegationRewardingResult> {
+) -> StdResult<DelegationRewardingResult> {
     // TODO: some checks to make sure stuff is not TOO stale.
 
     let chunk_size = MIXNODE_DELEGATORS_PAGE_LIMIT;
@@ -88,7 +102,7 @@ fn reward_mix_delegators(
 
     // I really hate this intermediate allocation, but I don't think there's a nice
     // way around it as we need to have immutable borrow into the bucket to retrieve delegation
-    // itself and then we need a mutable one to insert an updated one back
+    // itself and then we need a mutable one to insert the accrued reward
     let mut rewarded_delegations = Vec::new();
 
     // get `chunk_size` + 1 of delegations
@@ -107,35 +121,36 @@ fn reward_mix_delegators(
     {
         items += 1;
 
-        let (_pk, mut delegation) = delegation?;
+        let (pk, delegation) = delegation?;
 
         if items == chunk_size + 1 {
             // we shouldn't process this data, it's for the next call
             start_next = Some(delegation.owner());
             break;
         } else {
-            // and for each of them increase the stake proportionally to the reward
+            // and for each of them accrue the reward proportional to the stake
             // if at least `MINIMUM_BLOCK_AGE_FOR_REWARDING` blocks have been created
             // since they delegated
             if delegation.block_height + constants::MINIMUM_BLOCK_AGE_FOR_REWARDING
                 <= params.node_reward_params().reward_blockstamp()
             {
-                let reward = params.determine_delegation_reward(delegation.amount.amount);
-                delegation.increment_amount(Uint128::new(reward), None);
-                total_rewarded += Uint128::new(reward);
+                let reward =
+                    Uint128::new(params.determine_delegation_reward(delegation.amount.amount));
+                total_rewarded += reward;
 
-                rewarded_delegations.push(delegation);
+                rewarded_delegations.push((pk, reward));
             }
         }
     }
 
-    // finally save all delegation data back into the storage
-    for rewarded_delegation in rewarded_delegations {
-        let storage_key = rewarded_delegation.storage_key().joined_key();
-        delegations.save(storage, storage_key, &rewarded_delegation)?;
+    // finally save all the accrued rewards into the storage
+    for (delegation_key, reward) in rewarded_delegations {
+        if !reward.is_zero() {
+            storage::accrue_delegator_reward(storage, delegation_key, reward)?;
+        }
     }
 
-    Ok(MixDelegationRewardingResult {
+    Ok(DelegationRewardingResult {
         total_rewarded,
         start_next,
     })
@@ -326,204 +341,838 @@ pub(crate) fn try_reward_mixnode(
     )))
 }
 
-#[cfg(test)]
-pub mod tests {
-    use super::*;
-    use crate::constants::SYBIL_RESISTANCE_PERCENT;
-    use crate::delegations::transactions::try_delegate_to_mixnode;
-    use crate::error::ContractError;
-    use crate::mixnet_contract_settings::storage as mixnet_params_storage;
-    use crate::mixnodes::storage as mixnodes_storage;
-    use crate::mixnodes::storage::StoredMixnodeBond;
-    use crate::rewards::transactions::try_reward_mixnode;
-    use crate::support::tests;
-    use crate::support::tests::test_helpers;
-    use config::defaults::DENOM;
-    use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::Coin;
-    use cosmwasm_std::Order;
-    use cosmwasm_std::{coin, coins, Addr, Uint128};
-    use mixnet_contract_common::events::{
-        must_find_attribute, BOND_TOO_FRESH_VALUE, DISTRIBUTED_DELEGATION_REWARDS_KEY,
-        FURTHER_DELEGATIONS_TO_REWARD_KEY, NO_REWARD_REASON_KEY, OPERATOR_REWARDING_EVENT_TYPE,
-        OPERATOR_REWARD_KEY, TOTAL_MIXNODE_REWARD_KEY,
-    };
-    use mixnet_contract_common::mixnode::NodeRewardParams;
-    use mixnet_contract_common::{Delegation, IdentityKey, Layer, MixNode};
+fn reward_gateway_delegators(
+    storage: &mut dyn Storage,
+    gateway_identity: IdentityKey,
+    start: Option<Addr>,
+    params: GatewayDelegatorRewardParams,
+) -> StdResult<DelegationRewardingResult> {
+    let chunk_size = GATEWAY_DELEGATORS_PAGE_LIMIT;
 
-    #[test]
-    fn rewarding_mixnodes_with_incorrect_interval_id() {
-        let mut deps = test_helpers::init_contract();
-        let mut env = mock_env();
-        let current_state = mixnet_params_storage::CONTRACT_STATE
-            .load(deps.as_mut().storage)
-            .unwrap();
-        let rewarding_validator_address = current_state.rewarding_validator_address;
+    let start_value =
+        start.map(|start| Bound::Inclusive((gateway_identity.clone(), start).joined_key()));
 
-        // bond the node
-        let node_owner: Addr = Addr::unchecked("node-owner");
-        let node_identity = test_helpers::add_mixnode(
-            node_owner.as_str(),
-            tests::fixtures::good_mixnode_pledge(),
-            deps.as_mut(),
-        );
+    let delegations = delegations_storage::gateway_delegations();
 
-        let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+    let mut total_rewarded = Uint128::zero();
+    let mut items = 0;
+    let mut start_next = None;
+    let mut rewarded_delegations = Vec::new();
 
-        let res = try_reward_mixnode(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            node_identity.clone(),
-            tests::fixtures::node_rewarding_params_fixture(100),
-            1,
-        );
-        assert_eq!(
-            Err(ContractError::InvalidIntervalId {
-                received: 1,
-                expected: 0
-            }),
-            res
-        );
+    // the logic is identical to the one used for mixnode delegators, i.e. we read one additional
+    // delegation to know the starting point of the next call
+    for delegation in delegations
+        .idx
+        .gateway
+        .prefix(gateway_identity)
+        .range(storage, start_value, None, cosmwasm_std::Order::Ascending)
+        .take(chunk_size + 1)
+    {
+        items += 1;
 
-        let res = try_reward_mixnode(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            node_identity.clone(),
-            tests::fixtures::node_rewarding_params_fixture(100),
-            2,
-        );
-        assert_eq!(
-            Err(ContractError::InvalidIntervalId {
-                received: 2,
-                expected: 0
-            }),
-            res
-        );
+        let (pk, delegation) = delegation?;
 
-        let res = try_reward_mixnode(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            node_identity.clone(),
-            tests::fixtures::node_rewarding_params_fixture(100),
-            0,
-        );
-        assert!(res.is_ok());
+        if items == chunk_size + 1 {
+            start_next = Some(delegation.owner());
+            break;
+        } else if delegation.block_height + constants::MINIMUM_BLOCK_AGE_FOR_REWARDING
+            <= params.reward_blockstamp()
+        {
+            let reward = params.determine_delegation_reward(delegation.amount.amount);
+            total_rewarded += reward;
 
-        test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
+            rewarded_delegations.push((pk, reward));
+        }
+    }
 
-        let res = try_reward_mixnode(
-            deps.as_mut(),
-            env.clone(),
-            info,
-            node_identity,
-            tests::fixtures::node_rewarding_params_fixture(100),
-            1,
-        );
-        assert!(res.is_ok());
+    // the rewards are accrued under the keys the delegations were read from, as the ones made via
+    // a proxy are not stored under their owner's address
+    for (delegation_key, reward) in rewarded_delegations {
+        if !reward.is_zero() {
+            storage::accrue_delegator_reward(storage, delegation_key, reward)?;
+        }
     }
 
-    #[test]
-    fn attempting_rewarding_mixnode_multiple_times_per_interval() {
-        let mut deps = test_helpers::init_contract();
-        let mut env = mock_env();
-        let current_state = mixnet_params_storage::CONTRACT_STATE
-            .load(deps.as_mut().storage)
-            .unwrap();
-        let rewarding_validator_address = current_state.rewarding_validator_address;
+    Ok(DelegationRewardingResult {
+        total_rewarded,
+        start_next,
+    })
+}
 
-        // bond the node
-        let node_owner: Addr = Addr::unchecked("node-owner");
-        let node_identity = test_helpers::add_mixnode(
-            node_owner.as_str(),
-            tests::fixtures::good_mixnode_pledge(),
-            deps.as_mut(),
-        );
+pub(crate) fn try_reward_next_gateway_delegators(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    gateway_identity: IdentityKey,
+    interval_id: u32,
+) -> Result<Response, ContractError> {
+    verify_rewarding_state(deps.storage, info, interval_id)?;
 
-        let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+    match storage::GATEWAY_REWARDING_STATUS
+        .may_load(deps.storage, (interval_id, gateway_identity.clone()))?
+    {
+        None => Err(ContractError::GatewayOperatorNotRewarded {
+            identity: gateway_identity,
+        }),
+        Some(GatewayRewardingStatus::Complete(_)) => Err(ContractError::GatewayAlreadyRewarded {
+            identity: gateway_identity,
+        }),
+        Some(GatewayRewardingStatus::PendingNextDelegatorPage(next_page_info)) => {
+            let delegation_rewarding_result = reward_gateway_delegators(
+                deps.storage,
+                gateway_identity.clone(),
+                Some(next_page_info.next_start),
+                next_page_info.rewarding_params,
+            )?;
 
-        // first reward goes through just fine
-        let res = try_reward_mixnode(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            node_identity.clone(),
-            tests::fixtures::node_rewarding_params_fixture(100),
-            0,
-        );
-        assert!(res.is_ok());
+            helpers::update_post_gateway_rewarding_storage(
+                deps.storage,
+                &gateway_identity,
+                Uint128::zero(),
+                delegation_rewarding_result.total_rewarded,
+            )?;
 
-        // but the other one fails
-        let res = try_reward_mixnode(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            node_identity.clone(),
-            tests::fixtures::node_rewarding_params_fixture(100),
-            0,
-        );
-        assert_eq!(
-            Err(ContractError::MixnodeAlreadyRewarded {
-                identity: node_identity.clone()
-            }),
-            res
-        );
+            let mut rewarding_results = next_page_info.running_results;
+            rewarding_results.total_delegator_reward += delegation_rewarding_result.total_rewarded;
 
-        // but rewarding the same node in the following interval is fine again
-        test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
+            let round_increase = delegation_rewarding_result.total_rewarded;
+            let more_delegators = delegation_rewarding_result.start_next.is_some();
 
-        let res = try_reward_mixnode(
-            deps.as_mut(),
-            env,
-            info,
-            node_identity,
-            tests::fixtures::node_rewarding_params_fixture(100),
-            1,
-        );
-        assert!(res.is_ok());
+            helpers::update_gateway_rewarding_status(
+                deps.storage,
+                interval_id,
+                gateway_identity.clone(),
+                rewarding_results,
+                delegation_rewarding_result.start_next,
+                next_page_info.rewarding_params,
+            )?;
+
+            Ok(
+                Response::new().add_event(new_gateway_delegators_rewarding_event(
+                    interval_id,
+                    &gateway_identity,
+                    round_increase,
+                    more_delegators,
+                )),
+            )
+        }
     }
+}
 
-    #[test]
-    fn rewarding_mixnode_blockstamp_based() {
-        let mut deps = test_helpers::init_contract();
-        let mut env = mock_env();
-        let current_state = mixnet_params_storage::CONTRACT_STATE
-            .load(deps.as_mut().storage)
-            .unwrap();
-        let rewarding_validator_address = current_state.rewarding_validator_address;
+pub(crate) fn try_reward_gateway(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    gateway_identity: IdentityKey,
+    params: GatewayRewardParams,
+    interval_id: u32,
+) -> Result<Response, ContractError> {
+    verify_rewarding_state(deps.storage, info, interval_id)?;
 
-        let node_owner: Addr = Addr::unchecked("node-owner");
-        let node_identity: IdentityKey = "nodeidentity".into();
+    let rewarding_params = mixnet_params_storage::CONTRACT_STATE
+        .load(deps.storage)?
+        .params
+        .gateway_rewarding;
+    if !rewarding_params.is_enabled() {
+        return Err(ContractError::GatewayRewardingDisabled);
+    }
 
-        let initial_bond = 10000_000000;
-        let initial_delegation = 20000_000000;
-        let mixnode_bond = StoredMixnodeBond {
-            pledge_amount: coin(initial_bond, DENOM),
-            owner: node_owner,
-            layer: Layer::One,
-            block_height: env.block.height,
-            mix_node: MixNode {
-                identity_key: node_identity.clone(),
-                ..tests::fixtures::mix_node_fixture()
-            },
-            proxy: None,
-        };
+    // check if the gateway hasn't been rewarded in this rewarding interval already
+    match storage::GATEWAY_REWARDING_STATUS
+        .may_load(deps.storage, (interval_id, gateway_identity.clone()))?
+    {
+        None => (),
+        Some(GatewayRewardingStatus::Complete(_)) => {
+            return Err(ContractError::GatewayAlreadyRewarded {
+                identity: gateway_identity,
+            })
+        }
+        Some(GatewayRewardingStatus::PendingNextDelegatorPage(_)) => {
+            return Err(ContractError::GatewayDelegatorsPendingReward {
+                identity: gateway_identity,
+            })
+        }
+    }
 
-        mixnodes_storage::mixnodes()
-            .save(deps.as_mut().storage, &node_identity, &mixnode_bond)
-            .unwrap();
-        mixnodes_storage::TOTAL_DELEGATION
-            .save(
-                deps.as_mut().storage,
-                &node_identity,
-                &Uint128::new(initial_delegation),
-            )
-            .unwrap();
+    // check if the bond even exists
+    let current_bond =
+        match gateways_storage::gateways().may_load(deps.storage, &gateway_identity)? {
+            Some(bond) => bond,
+            None => {
+                return Ok(
+                    Response::new().add_event(new_not_found_gateway_rewarding_event(
+                        interval_id,
+                        &gateway_identity,
+                    )),
+                )
+            }
+        };
 
-        // delegation happens later, but not later enough
-        env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING - 1;
+    // check if gateway is old enough for rewarding
+    if current_bond.block_height + constants::MINIMUM_BLOCK_AGE_FOR_REWARDING > env.block.height {
+        storage::GATEWAY_REWARDING_STATUS.save(
+            deps.storage,
+            (interval_id, gateway_identity.clone()),
+            &GatewayRewardingStatus::Complete(Default::default()),
+        )?;
+
+        return Ok(
+            Response::new().add_event(new_too_fresh_bond_gateway_rewarding_event(
+                interval_id,
+                &gateway_identity,
+            )),
+        );
+    }
+
+    // check if it has non-zero uptime
+    if params.uptime() == 0 {
+        storage::GATEWAY_REWARDING_STATUS.save(
+            deps.storage,
+            (interval_id, gateway_identity.clone()),
+            &GatewayRewardingStatus::Complete(Default::default()),
+        )?;
+
+        return Ok(
+            Response::new().add_event(new_zero_uptime_gateway_rewarding_event(
+                interval_id,
+                &gateway_identity,
+            )),
+        );
+    }
+
+    // the gateway share of the reward pool only covers the rewarded set
+    let rewarded_gateways = storage::REWARDED_GATEWAYS_COUNT
+        .may_load(deps.storage, interval_id)?
+        .unwrap_or_default();
+    if rewarded_gateways >= rewarding_params.rewarded_set_size {
+        return Err(ContractError::GatewayRewardedSetFull {
+            interval_id,
+            rewarded_set_size: rewarding_params.rewarded_set_size,
+        });
+    }
+    storage::REWARDED_GATEWAYS_COUNT.save(deps.storage, interval_id, &(rewarded_gateways + 1))?;
+
+    let gateway_pledge = current_bond.pledge_amount.amount;
+    let gateway_delegation = gateways_storage::TOTAL_GATEWAY_DELEGATION
+        .may_load(deps.storage, &gateway_identity)?
+        .unwrap_or_default();
+
+    let mut gateway_reward_params = params;
+    gateway_reward_params.set_reward_blockstamp(env.block.height);
+
+    let total_reward = rewarding_params.gateway_reward(&gateway_reward_params);
+    let (operator_reward, delegators_reward) =
+        rewarding_params.split_reward(total_reward, gateway_pledge, gateway_delegation);
+
+    let delegator_params = GatewayDelegatorRewardParams::new(
+        delegators_reward,
+        gateway_delegation,
+        gateway_reward_params.reward_blockstamp(),
+    );
+    let delegation_rewarding_result = reward_gateway_delegators(
+        deps.storage,
+        gateway_identity.clone(),
+        None,
+        delegator_params,
+    )?;
+
+    helpers::update_post_gateway_rewarding_storage(
+        deps.storage,
+        &gateway_identity,
+        operator_reward,
+        delegation_rewarding_result.total_rewarded,
+    )?;
+
+    let rewarding_results = RewardingResult {
+        operator_reward,
+        total_delegator_reward: delegation_rewarding_result.total_rewarded,
+    };
+    let total_delegator_reward = rewarding_results.total_delegator_reward;
+    let further_delegations = delegation_rewarding_result.start_next.is_some();
+
+    helpers::update_gateway_rewarding_status(
+        deps.storage,
+        interval_id,
+        gateway_identity.clone(),
+        rewarding_results,
+        delegation_rewarding_result.start_next,
+        delegator_params,
+    )?;
+
+    Ok(Response::new().add_event(new_gateway_rewarding_event(
+        interval_id,
+        &gateway_identity,
+        total_reward,
+        gateway_pledge,
+        gateway_delegation,
+        operator_reward,
+        total_delegator_reward,
+        further_delegations,
+    )))
+}
+
+// sends the claimed reward either directly to its owner or to the vesting contract,
+// which then has to track it for the owner's vesting account
+fn claimed_reward_payout(
+    owner: &Addr,
+    proxy: &Option<Addr>,
+    reward: &Coin,
+) -> Result<Response, ContractError> {
+    let return_tokens = BankMsg::Send {
+        to_address: proxy.as_ref().unwrap_or(owner).to_string(),
+        amount: vec![reward.clone()],
+    };
+
+    let mut response = Response::new().add_message(return_tokens);
+
+    if let Some(proxy) = proxy {
+        let msg = VestingContractExecuteMsg::TrackReward {
+            owner: owner.as_str().to_string(),
+            amount: reward.clone(),
+        };
+
+        let track_reward_message = wasm_execute(proxy, &msg, vec![one_ucoin()])?;
+        response = response.add_message(track_reward_message);
+    }
+
+    Ok(response)
+}
+
+pub(crate) fn try_claim_operator_reward(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    _try_claim_operator_reward(deps, info.sender.as_str(), None)
+}
+
+pub(crate) fn try_claim_operator_reward_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    owner: String,
+) -> Result<Response, ContractError> {
+    _try_claim_operator_reward(deps, &owner, Some(info.sender))
+}
+
+pub(crate) fn _try_claim_operator_reward(
+    deps: DepsMut<'_>,
+    owner: &str,
+    proxy: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(owner)?;
+
+    let mixnode_bond = match mixnodes_storage::mixnodes()
+        .idx
+        .owner
+        .item(deps.storage, owner.clone())?
+    {
+        Some(record) => record.1,
+        None => return Err(ContractError::NoAssociatedMixNodeBond { owner }),
+    };
+
+    if proxy != mixnode_bond.proxy {
+        return Err(ContractError::ProxyMismatch {
+            existing: mixnode_bond
+                .proxy
+                .map_or_else(|| "None".to_string(), |a| a.as_str().to_string()),
+            incoming: proxy.map_or_else(|| "None".to_string(), |a| a.as_str().to_string()),
+        });
+    }
+
+    let reward = storage::take_operator_reward(deps.storage, mixnode_bond.identity())?;
+    if reward.is_zero() {
+        return Err(ContractError::NoRewardsToClaim {
+            identity: mixnode_bond.identity().clone(),
+            address: owner,
+        });
+    }
+
+    let reward = coin(reward.u128(), DENOM);
+    let response = claimed_reward_payout(&owner, &proxy, &reward)?;
+
+    Ok(response.add_event(new_operator_reward_claim_event(
+        &owner,
+        &proxy,
+        &reward,
+        mixnode_bond.identity(),
+    )))
+}
+
+pub(crate) fn try_claim_delegator_reward(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_identity: IdentityKey,
+) -> Result<Response, ContractError> {
+    _try_claim_delegator_reward(deps, mix_identity, info.sender.as_str(), None)
+}
+
+pub(crate) fn try_claim_delegator_reward_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_identity: IdentityKey,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    _try_claim_delegator_reward(deps, mix_identity, &delegate, Some(info.sender))
+}
+
+// note that the rewards remain claimable even after the delegation itself is gone
+pub(crate) fn _try_claim_delegator_reward(
+    deps: DepsMut<'_>,
+    mix_identity: IdentityKey,
+    delegate: &str,
+    proxy: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(delegate)?;
+    let maybe_proxy_storage = generate_storage_key(&delegate, proxy.as_ref());
+    let storage_key = (mix_identity.clone(), maybe_proxy_storage).joined_key();
+
+    let reward = storage::take_delegator_reward(deps.storage, storage_key)?;
+    if reward.is_zero() {
+        return Err(ContractError::NoRewardsToClaim {
+            identity: mix_identity,
+            address: delegate,
+        });
+    }
+
+    let reward = coin(reward.u128(), DENOM);
+    let response = claimed_reward_payout(&delegate, &proxy, &reward)?;
+
+    Ok(response.add_event(new_delegator_reward_claim_event(
+        &delegate,
+        &proxy,
+        &reward,
+        &mix_identity,
+    )))
+}
+
+pub(crate) fn try_compound_delegator_reward(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_identity: IdentityKey,
+) -> Result<Response, ContractError> {
+    _try_compound_delegator_reward(deps, mix_identity, info.sender.as_str(), None)
+}
+
+pub(crate) fn try_compound_delegator_reward_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_identity: IdentityKey,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    _try_compound_delegator_reward(deps, mix_identity, &delegate, Some(info.sender))
+}
+
+pub(crate) fn _try_compound_delegator_reward(
+    deps: DepsMut<'_>,
+    mix_identity: IdentityKey,
+    delegate: &str,
+    proxy: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(delegate)?;
+
+    // compounding increases the stake on the node, so it has to still be around
+    if mixnodes_storage::mixnodes()
+        .may_load(deps.storage, &mix_identity)?
+        .is_none()
+    {
+        return Err(ContractError::MixNodeBondNotFound {
+            identity: mix_identity,
+        });
+    }
+    ensure_delegations_not_being_slashed(deps.storage, &mix_identity)?;
+
+    let maybe_proxy_storage = generate_storage_key(&delegate, proxy.as_ref());
+    let storage_key = (mix_identity.clone(), maybe_proxy_storage).joined_key();
+
+    let mut delegation =
+        match delegations_storage::delegations().may_load(deps.storage, storage_key.clone())? {
+            Some(delegation) => delegation,
+            None => {
+                return Err(ContractError::NoMixnodeDelegationFound {
+                    identity: mix_identity,
+                    address: delegate,
+                })
+            }
+        };
+
+    let reward = storage::take_delegator_reward(deps.storage, storage_key.clone())?;
+    if reward.is_zero() {
+        return Err(ContractError::NoRewardsToClaim {
+            identity: mix_identity,
+            address: delegate,
+        });
+    }
+
+    // the block height is left intact as the rewards were earned by the already eligible stake
+    delegation.increment_amount(reward, None);
+    delegations_storage::delegations().save(deps.storage, storage_key, &delegation)?;
+
+    mixnodes_storage::TOTAL_DELEGATION.update::<_, ContractError>(
+        deps.storage,
+        &mix_identity,
+        |total_delegation| Ok(total_delegation.unwrap_or_default() + reward),
+    )?;
+
+    Ok(
+        Response::new().add_event(new_delegator_reward_compounding_event(
+            &delegate,
+            &proxy,
+            &coin(reward.u128(), DENOM),
+            &mix_identity,
+        )),
+    )
+}
+
+pub(crate) fn try_claim_gateway_operator_reward(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    _try_claim_gateway_operator_reward(deps, info.sender.as_str(), None)
+}
+
+pub(crate) fn try_claim_gateway_operator_reward_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    owner: String,
+) -> Result<Response, ContractError> {
+    _try_claim_gateway_operator_reward(deps, &owner, Some(info.sender))
+}
+
+pub(crate) fn _try_claim_gateway_operator_reward(
+    deps: DepsMut<'_>,
+    owner: &str,
+    proxy: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(owner)?;
+
+    let gateway_bond = match gateways_storage::gateways()
+        .idx
+        .owner
+        .item(deps.storage, owner.clone())?
+    {
+        Some(record) => record.1,
+        None => return Err(ContractError::NoAssociatedGatewayBond { owner }),
+    };
+
+    if proxy != gateway_bond.proxy {
+        return Err(ContractError::ProxyMismatch {
+            existing: gateway_bond
+                .proxy
+                .map_or_else(|| "None".to_string(), |a| a.as_str().to_string()),
+            incoming: proxy.map_or_else(|| "None".to_string(), |a| a.as_str().to_string()),
+        });
+    }
+
+    let reward = storage::take_operator_reward(deps.storage, gateway_bond.identity())?;
+    if reward.is_zero() {
+        return Err(ContractError::NoRewardsToClaim {
+            identity: gateway_bond.identity().clone(),
+            address: owner,
+        });
+    }
+
+    let reward = coin(reward.u128(), DENOM);
+    let response = claimed_reward_payout(&owner, &proxy, &reward)?;
+
+    Ok(response.add_event(new_operator_reward_claim_event(
+        &owner,
+        &proxy,
+        &reward,
+        gateway_bond.identity(),
+    )))
+}
+
+// gateway delegator rewards are accrued under the same kind of key as the mixnode ones,
+// so claiming them is identical
+pub(crate) fn try_claim_gateway_delegator_reward(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    gateway_identity: IdentityKey,
+) -> Result<Response, ContractError> {
+    _try_claim_delegator_reward(deps, gateway_identity, info.sender.as_str(), None)
+}
+
+pub(crate) fn try_claim_gateway_delegator_reward_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    gateway_identity: IdentityKey,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    _try_claim_delegator_reward(deps, gateway_identity, &delegate, Some(info.sender))
+}
+
+pub(crate) fn try_compound_gateway_delegator_reward(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    gateway_identity: IdentityKey,
+) -> Result<Response, ContractError> {
+    _try_compound_gateway_delegator_reward(deps, gateway_identity, info.sender.as_str(), None)
+}
+
+pub(crate) fn try_compound_gateway_delegator_reward_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    gateway_identity: IdentityKey,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    _try_compound_gateway_delegator_reward(deps, gateway_identity, &delegate, Some(info.sender))
+}
+
+pub(crate) fn _try_compound_gateway_delegator_reward(
+    deps: DepsMut<'_>,
+    gateway_identity: IdentityKey,
+    delegate: &str,
+    proxy: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(delegate)?;
+
+    if gateways_storage::gateways()
+        .may_load(deps.storage, &gateway_identity)?
+        .is_none()
+    {
+        return Err(ContractError::GatewayBondNotFound {
+            identity: gateway_identity,
+        });
+    }
+
+    let maybe_proxy_storage = generate_storage_key(&delegate, proxy.as_ref());
+    let storage_key = (gateway_identity.clone(), maybe_proxy_storage).joined_key();
+
+    let mut delegation = match delegations_storage::gateway_delegations()
+        .may_load(deps.storage, storage_key.clone())?
+    {
+        Some(delegation) => delegation,
+        None => {
+            return Err(ContractError::NoGatewayDelegationFound {
+                identity: gateway_identity,
+                address: delegate,
+            })
+        }
+    };
+
+    let reward = storage::take_delegator_reward(deps.storage, storage_key.clone())?;
+    if reward.is_zero() {
+        return Err(ContractError::NoRewardsToClaim {
+            identity: gateway_identity,
+            address: delegate,
+        });
+    }
+
+    delegation.increment_amount(reward, None);
+    delegations_storage::gateway_delegations().save(deps.storage, storage_key, &delegation)?;
+
+    gateways_storage::TOTAL_GATEWAY_DELEGATION.update::<_, ContractError>(
+        deps.storage,
+        &gateway_identity,
+        |total_delegation| Ok(total_delegation.unwrap_or_default() + reward),
+    )?;
+
+    Ok(
+        Response::new().add_event(new_delegator_reward_compounding_event(
+            &delegate,
+            &proxy,
+            &coin(reward.u128(), DENOM),
+            &gateway_identity,
+        )),
+    )
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::constants::SYBIL_RESISTANCE_PERCENT;
+    use crate::delegations::transactions::try_delegate_to_mixnode;
+    use crate::error::ContractError;
+    use crate::mixnet_contract_settings::storage as mixnet_params_storage;
+    use crate::mixnodes::storage as mixnodes_storage;
+    use crate::mixnodes::storage::StoredMixnodeBond;
+    use crate::rewards::transactions::try_reward_mixnode;
+    use crate::support::tests;
+    use crate::support::tests::test_helpers;
+    use config::defaults::DENOM;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Coin;
+    use cosmwasm_std::Order;
+    use cosmwasm_std::{coin, coins, Addr, Uint128};
+    use mixnet_contract_common::events::{
+        must_find_attribute, BOND_TOO_FRESH_VALUE, DISTRIBUTED_DELEGATION_REWARDS_KEY,
+        FURTHER_DELEGATIONS_TO_REWARD_KEY, NO_REWARD_REASON_KEY, OPERATOR_REWARDING_EVENT_TYPE,
+        OPERATOR_REWARD_KEY, TOTAL_MIXNODE_REWARD_KEY,
+    };
+    use mixnet_contract_common::mixnode::NodeRewardParams;
+    use mixnet_contract_common::{Delegation, IdentityKey, Layer, MixNode};
+
+    #[test]
+    fn rewarding_mixnodes_with_incorrect_interval_id() {
+        let mut deps = test_helpers::init_contract();
+        let mut env = mock_env();
+        let current_state = mixnet_params_storage::CONTRACT_STATE
+            .load(deps.as_mut().storage)
+            .unwrap();
+        let rewarding_validator_address = current_state.rewarding_validator_address;
+
+        // bond the node
+        let node_owner: Addr = Addr::unchecked("node-owner");
+        let node_identity = test_helpers::add_mixnode(
+            node_owner.as_str(),
+            tests::fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+
+        let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+
+        let res = try_reward_mixnode(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            node_identity.clone(),
+            tests::fixtures::node_rewarding_params_fixture(100),
+            1,
+        );
+        assert_eq!(
+            Err(ContractError::InvalidIntervalId {
+                received: 1,
+                expected: 0
+            }),
+            res
+        );
+
+        let res = try_reward_mixnode(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            node_identity.clone(),
+            tests::fixtures::node_rewarding_params_fixture(100),
+            2,
+        );
+        assert_eq!(
+            Err(ContractError::InvalidIntervalId {
+                received: 2,
+                expected: 0
+            }),
+            res
+        );
+
+        let res = try_reward_mixnode(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            node_identity.clone(),
+            tests::fixtures::node_rewarding_params_fixture(100),
+            0,
+        );
+        assert!(res.is_ok());
+
+        test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
+
+        let res = try_reward_mixnode(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            node_identity,
+            tests::fixtures::node_rewarding_params_fixture(100),
+            1,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn attempting_rewarding_mixnode_multiple_times_per_interval() {
+        let mut deps = test_helpers::init_contract();
+        let mut env = mock_env();
+        let current_state = mixnet_params_storage::CONTRACT_STATE
+            .load(deps.as_mut().storage)
+            .unwrap();
+        let rewarding_validator_address = current_state.rewarding_validator_address;
+
+        // bond the node
+        let node_owner: Addr = Addr::unchecked("node-owner");
+        let node_identity = test_helpers::add_mixnode(
+            node_owner.as_str(),
+            tests::fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+
+        let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+
+        // first reward goes through just fine
+        let res = try_reward_mixnode(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            node_identity.clone(),
+            tests::fixtures::node_rewarding_params_fixture(100),
+            0,
+        );
+        assert!(res.is_ok());
+
+        // but the other one fails
+        let res = try_reward_mixnode(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            node_identity.clone(),
+            tests::fixtures::node_rewarding_params_fixture(100),
+            0,
+        );
+        assert_eq!(
+            Err(ContractError::MixnodeAlreadyRewarded {
+                identity: node_identity.clone()
+            }),
+            res
+        );
+
+        // but rewarding the same node in the following interval is fine again
+        test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
+
+        let res = try_reward_mixnode(
+            deps.as_mut(),
+            env,
+            info,
+            node_identity,
+            tests::fixtures::node_rewarding_params_fixture(100),
+            1,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn rewarding_mixnode_blockstamp_based() {
+        let mut deps = test_helpers::init_contract();
+        let mut env = mock_env();
+        let current_state = mixnet_params_storage::CONTRACT_STATE
+            .load(deps.as_mut().storage)
+            .unwrap();
+        let rewarding_validator_address = current_state.rewarding_validator_address;
+
+        let node_owner: Addr = Addr::unchecked("node-owner");
+        let node_identity: IdentityKey = "nodeidentity".into();
+
+        let initial_bond = 10000_000000;
+        let initial_delegation = 20000_000000;
+        let mixnode_bond = StoredMixnodeBond {
+            pledge_amount: coin(initial_bond, DENOM),
+            owner: node_owner,
+            layer: Layer::One,
+            block_height: env.block.height,
+            mix_node: MixNode {
+                identity_key: node_identity.clone(),
+                ..tests::fixtures::mix_node_fixture()
+            },
+            proxy: None,
+        };
+
+        mixnodes_storage::mixnodes()
+            .save(deps.as_mut().storage, &node_identity, &mixnode_bond)
+            .unwrap();
+        mixnodes_storage::TOTAL_DELEGATION
+            .save(
+                deps.as_mut().storage,
+                &node_identity,
+                &Uint128::new(initial_delegation),
+            )
+            .unwrap();
+
+        // delegation happens later, but not later enough
+        env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING - 1;
 
         delegations_storage::delegations()
             .save(
@@ -532,288 +1181,734 @@ pub mod tests {
                 &Delegation::new(
                     Addr::unchecked("delegator"),
                     node_identity.clone(),
-                    coin(initial_delegation, DENOM),
-                    env.block.height,
-                    None,
-                ),
+                    coin(initial_delegation, DENOM),
+                    env.block.height,
+                    None,
+                ),
+            )
+            .unwrap();
+
+        let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+
+        let res = try_reward_mixnode(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            node_identity.clone(),
+            tests::fixtures::node_rewarding_params_fixture(100),
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(
+            initial_bond,
+            test_helpers::read_mixnode_pledge_amount(deps.as_ref().storage, &node_identity)
+                .unwrap()
+                .u128()
+        );
+        assert_eq!(
+            initial_delegation,
+            mixnodes_storage::TOTAL_DELEGATION
+                .load(deps.as_ref().storage, &node_identity)
+                .unwrap()
+                .u128()
+        );
+        assert_eq!(1, res.events.len());
+        assert_eq!(OPERATOR_REWARDING_EVENT_TYPE, res.events[0].ty);
+        assert_eq!(
+            BOND_TOO_FRESH_VALUE,
+            must_find_attribute(&res.events[0], NO_REWARD_REASON_KEY)
+        );
+
+        // reward can happen now, but only for bonded node
+        env.block.height += 1;
+        test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
+
+        let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+        let res = try_reward_mixnode(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            node_identity.clone(),
+            tests::fixtures::node_rewarding_params_fixture(100),
+            1,
+        )
+        .unwrap();
+
+        // the reward is only accrued, the pledge itself stays the same
+        assert_eq!(
+            initial_bond,
+            test_helpers::read_mixnode_pledge_amount(deps.as_ref().storage, &node_identity)
+                .unwrap()
+                .u128()
+        );
+        assert!(!storage::PENDING_OPERATOR_REWARDS
+            .load(deps.as_ref().storage, &node_identity)
+            .unwrap()
+            .is_zero());
+        assert_eq!(
+            initial_delegation,
+            mixnodes_storage::TOTAL_DELEGATION
+                .load(deps.as_ref().storage, &node_identity)
+                .unwrap()
+                .u128()
+        );
+
+        assert_eq!(1, res.events.len());
+        let event = &res.events[0];
+        assert_eq!(OPERATOR_REWARDING_EVENT_TYPE, event.ty);
+        assert_ne!("0", must_find_attribute(event, TOTAL_MIXNODE_REWARD_KEY));
+        assert_ne!("0", must_find_attribute(event, OPERATOR_REWARD_KEY));
+        assert_eq!(
+            "0",
+            must_find_attribute(event, DISTRIBUTED_DELEGATION_REWARDS_KEY)
+        );
+        assert_eq!(
+            false.to_string(),
+            must_find_attribute(event, FURTHER_DELEGATIONS_TO_REWARD_KEY)
+        );
+
+        // reward happens now, both for node owner and delegators
+        env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING - 1;
+        test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
+
+        let pending_before_rewarding = storage::PENDING_OPERATOR_REWARDS
+            .load(deps.as_ref().storage, &node_identity)
+            .unwrap();
+
+        let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+        let res = try_reward_mixnode(
+            deps.as_mut(),
+            env,
+            info.clone(),
+            node_identity.clone(),
+            tests::fixtures::node_rewarding_params_fixture(100),
+            2,
+        )
+        .unwrap();
+
+        assert_eq!(
+            initial_bond,
+            test_helpers::read_mixnode_pledge_amount(deps.as_ref().storage, &node_identity)
+                .unwrap()
+                .u128()
+        );
+        assert!(
+            storage::PENDING_OPERATOR_REWARDS
+                .load(deps.as_ref().storage, &node_identity)
+                .unwrap()
+                > pending_before_rewarding
+        );
+        assert_eq!(
+            initial_delegation,
+            mixnodes_storage::TOTAL_DELEGATION
+                .load(deps.as_ref().storage, &node_identity)
+                .unwrap()
+                .u128()
+        );
+        assert!(!test_helpers::read_pending_delegator_reward(
+            deps.as_ref().storage,
+            &node_identity,
+            "delegator"
+        )
+        .is_zero());
+
+        assert_eq!(1, res.events.len());
+        let event = &res.events[0];
+        assert_eq!(OPERATOR_REWARDING_EVENT_TYPE, event.ty);
+        assert_ne!("0", must_find_attribute(event, TOTAL_MIXNODE_REWARD_KEY));
+        assert_ne!("0", must_find_attribute(event, OPERATOR_REWARD_KEY));
+        assert_ne!(
+            "0",
+            must_find_attribute(event, DISTRIBUTED_DELEGATION_REWARDS_KEY)
+        );
+        assert_eq!(
+            false.to_string(),
+            must_find_attribute(event, FURTHER_DELEGATIONS_TO_REWARD_KEY)
+        );
+    }
+
+    #[test]
+    fn test_tokenomics_rewarding() {
+        use crate::constants::INTERVAL_REWARD_PERCENT;
+        use crate::contract::INITIAL_REWARD_POOL;
+
+        type U128 = fixed::types::U75F53;
+
+        let mut deps = test_helpers::init_contract();
+        let mut env = mock_env();
+        let current_state = mixnet_params_storage::CONTRACT_STATE
+            .load(deps.as_ref().storage)
+            .unwrap();
+        let rewarding_validator_address = current_state.rewarding_validator_address;
+        let period_reward_pool = (INITIAL_REWARD_POOL / 100) * INTERVAL_REWARD_PERCENT as u128;
+        assert_eq!(period_reward_pool, 5_000_000_000_000);
+        let rewarded_set_size = 200; // Imagining our reward set size is 200
+        let active_set_size = 100;
+        let active_set_work_factor = 10;
+        let circulating_supply = storage::circulating_supply(&deps.storage).unwrap().u128();
+        assert_eq!(circulating_supply, 750_000_000_000_000u128);
+
+        let node_owner: Addr = Addr::unchecked("alice");
+        let node_identity = test_helpers::add_mixnode(
+            node_owner.as_str(),
+            coins(10_000_000_000, DENOM),
+            deps.as_mut(),
+        );
+
+        try_delegate_to_mixnode(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice_d1", &[coin(8000_000000, DENOM)]),
+            node_identity.clone(),
+        )
+        .unwrap();
+
+        try_delegate_to_mixnode(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice_d2", &[coin(2000_000000, DENOM)]),
+            node_identity.clone(),
+        )
+        .unwrap();
+
+        let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+        env.block.height += 2 * constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
+
+        let mix_1 = mixnodes_storage::read_full_mixnode_bond(&deps.storage, &node_identity)
+            .unwrap()
+            .unwrap();
+        let mix_1_uptime = 100;
+
+        let mut params = NodeRewardParams::new(
+            period_reward_pool,
+            rewarded_set_size,
+            active_set_size,
+            0,
+            circulating_supply,
+            mix_1_uptime,
+            SYBIL_RESISTANCE_PERCENT,
+            true,
+            active_set_work_factor,
+        );
+
+        params.set_reward_blockstamp(env.block.height);
+
+        assert_eq!(params.performance(), 1);
+
+        let mix_1_reward_result = mix_1.reward(&params);
+
+        assert_eq!(
+            mix_1_reward_result.sigma(),
+            U128::from_num(0.0000266666666666)
+        );
+        assert_eq!(
+            mix_1_reward_result.lambda(),
+            U128::from_num(0.0000133333333333)
+        );
+        assert_eq!(mix_1_reward_result.reward().int(), 186562237);
+
+        let mix1_operator_profit = mix_1.operator_reward(&params);
+
+        let mix1_delegator1_reward = mix_1.reward_delegation(Uint128::new(8000_000000), &params);
+
+        let mix1_delegator2_reward = mix_1.reward_delegation(Uint128::new(2000_000000), &params);
+
+        assert_eq!(mix1_operator_profit, U128::from_num(120609230));
+        assert_eq!(mix1_delegator1_reward, U128::from_num(52762405));
+        assert_eq!(mix1_delegator2_reward, U128::from_num(13190601));
+
+        let pre_reward_bond =
+            test_helpers::read_mixnode_pledge_amount(&deps.storage, &node_identity)
+                .unwrap()
+                .u128();
+        assert_eq!(pre_reward_bond, 10_000_000_000);
+
+        let pre_reward_delegation = mixnodes_storage::TOTAL_DELEGATION
+            .load(&deps.storage, &node_identity)
+            .unwrap()
+            .u128();
+        assert_eq!(pre_reward_delegation, 10_000_000_000);
+
+        try_reward_mixnode(deps.as_mut(), env, info, node_identity.clone(), params, 0).unwrap();
+
+        assert_eq!(
+            test_helpers::read_mixnode_pledge_amount(&deps.storage, &node_identity)
+                .unwrap()
+                .u128(),
+            pre_reward_bond
+        );
+        assert_eq!(
+            storage::PENDING_OPERATOR_REWARDS
+                .load(&deps.storage, &node_identity)
+                .unwrap()
+                .u128(),
+            mix1_operator_profit
+        );
+        assert_eq!(
+            mixnodes_storage::TOTAL_DELEGATION
+                .load(&deps.storage, &node_identity)
+                .unwrap()
+                .u128(),
+            pre_reward_delegation
+        );
+        assert_eq!(
+            test_helpers::read_pending_delegator_reward(&deps.storage, &node_identity, "alice_d1")
+                .u128(),
+            mix1_delegator1_reward
+        );
+        assert_eq!(
+            test_helpers::read_pending_delegator_reward(&deps.storage, &node_identity, "alice_d2")
+                .u128(),
+            mix1_delegator2_reward
+        );
+
+        assert_eq!(
+            storage::REWARD_POOL.load(&deps.storage).unwrap().u128(),
+            U128::from_num(INITIAL_REWARD_POOL)
+                - (U128::from_num(mix1_operator_profit)
+                    + U128::from_num(mix1_delegator1_reward)
+                    + U128::from_num(mix1_delegator2_reward))
+        );
+
+        // it's all correctly saved
+        match storage::REWARDING_STATUS
+            .load(deps.as_ref().storage, (0u32, node_identity))
+            .unwrap()
+        {
+            RewardingStatus::Complete(result) => assert_eq!(
+                RewardingResult {
+                    operator_reward: Uint128::new(mix1_operator_profit),
+                    total_delegator_reward: Uint128::new(
+                        mix1_delegator1_reward + mix1_delegator2_reward
+                    )
+                },
+                result
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    #[cfg(test)]
+    mod mixnode_rewarding_distributes_rewards_to_up_to_one_page_of_delegators {
+
+        #[test]
+        fn with_10_delegations() {
+            use super::*;
+
+            let mut deps = test_helpers::init_contract();
+            let mut env = mock_env();
+            let current_state = mixnet_params_storage::CONTRACT_STATE
+                .load(deps.as_mut().storage)
+                .unwrap();
+            let rewarding_validator_address = current_state.rewarding_validator_address;
+
+            #[allow(clippy::inconsistent_digit_grouping)]
+            let mix_bond = Uint128::new(10000_000_000);
+            let delegation_value = 2000_000000;
+
+            let node_owner: Addr = Addr::unchecked("10delegators");
+            let node_identity = test_helpers::add_mixnode(
+                node_owner.as_str(),
+                vec![Coin {
+                    denom: DENOM.to_string(),
+                    amount: mix_bond,
+                }],
+                deps.as_mut(),
+            );
+
+            for i in 0..10 {
+                try_delegate_to_mixnode(
+                    deps.as_mut(),
+                    env.clone(),
+                    mock_info(
+                        &*format!("delegator{}", i),
+                        &[coin(delegation_value, DENOM)],
+                    ),
+                    node_identity.clone(),
+                )
+                .unwrap();
+            }
+
+            env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
+
+            let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+            let res = try_reward_mixnode(
+                deps.as_mut(),
+                env,
+                info,
+                node_identity.clone(),
+                tests::fixtures::node_rewarding_params_fixture(100),
+                0,
             )
             .unwrap();
+            assert_eq!(
+                false.to_string(),
+                must_find_attribute(&res.events[0], FURTHER_DELEGATIONS_TO_REWARD_KEY)
+            );
 
-        let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+            for i in 0..10 {
+                let pending = test_helpers::read_pending_delegator_reward(
+                    &deps.storage,
+                    node_identity.clone(),
+                    format!("delegator{}", i),
+                );
 
-        let res = try_reward_mixnode(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            node_identity.clone(),
-            tests::fixtures::node_rewarding_params_fixture(100),
-            0,
-        )
-        .unwrap();
+                assert!(!pending.is_zero());
+            }
+        }
 
-        assert_eq!(
-            initial_bond,
-            test_helpers::read_mixnode_pledge_amount(deps.as_ref().storage, &node_identity)
-                .unwrap()
-                .u128()
-        );
-        assert_eq!(
-            initial_delegation,
-            mixnodes_storage::TOTAL_DELEGATION
-                .load(deps.as_ref().storage, &node_identity)
-                .unwrap()
-                .u128()
-        );
-        assert_eq!(1, res.events.len());
-        assert_eq!(OPERATOR_REWARDING_EVENT_TYPE, res.events[0].ty);
-        assert_eq!(
-            BOND_TOO_FRESH_VALUE,
-            must_find_attribute(&res.events[0], NO_REWARD_REASON_KEY)
-        );
+        #[test]
+        fn with_full_page_limit() {
+            use super::*;
 
-        // reward can happen now, but only for bonded node
-        env.block.height += 1;
-        test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
+            let mut deps = test_helpers::init_contract();
+            let mut env = mock_env();
+            let current_state = mixnet_params_storage::CONTRACT_STATE
+                .load(deps.as_mut().storage)
+                .unwrap();
+            let rewarding_validator_address = current_state.rewarding_validator_address;
 
-        let info = mock_info(rewarding_validator_address.as_ref(), &[]);
-        let res = try_reward_mixnode(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            node_identity.clone(),
-            tests::fixtures::node_rewarding_params_fixture(100),
-            1,
-        )
-        .unwrap();
+            #[allow(clippy::inconsistent_digit_grouping)]
+            let mix_bond = Uint128::new(10000_000_000);
+            let delegation_value = 2000_000000;
 
-        assert!(
-            test_helpers::read_mixnode_pledge_amount(deps.as_ref().storage, &node_identity)
-                .unwrap()
-                .u128()
-                > initial_bond
-        );
-        assert_eq!(
-            initial_delegation,
-            mixnodes_storage::TOTAL_DELEGATION
-                .load(deps.as_ref().storage, &node_identity)
-                .unwrap()
-                .u128()
-        );
+            let node_owner: Addr = Addr::unchecked("MIXNODE_DELEGATORS_PAGE_LIMIT_delegators");
+            let node_identity = test_helpers::add_mixnode(
+                node_owner.as_str(),
+                vec![Coin {
+                    denom: DENOM.to_string(),
+                    amount: mix_bond,
+                }],
+                deps.as_mut(),
+            );
 
-        assert_eq!(1, res.events.len());
-        let event = &res.events[0];
-        assert_eq!(OPERATOR_REWARDING_EVENT_TYPE, event.ty);
-        assert_ne!("0", must_find_attribute(event, TOTAL_MIXNODE_REWARD_KEY));
-        assert_ne!("0", must_find_attribute(event, OPERATOR_REWARD_KEY));
-        assert_eq!(
-            "0",
-            must_find_attribute(event, DISTRIBUTED_DELEGATION_REWARDS_KEY)
-        );
-        assert_eq!(
-            false.to_string(),
-            must_find_attribute(event, FURTHER_DELEGATIONS_TO_REWARD_KEY)
-        );
+            for i in 0..MIXNODE_DELEGATORS_PAGE_LIMIT {
+                try_delegate_to_mixnode(
+                    deps.as_mut(),
+                    env.clone(),
+                    mock_info(
+                        &*format!("delegator{}", i),
+                        &[coin(delegation_value, DENOM)],
+                    ),
+                    node_identity.clone(),
+                )
+                .unwrap();
+            }
 
-        // reward happens now, both for node owner and delegators
-        env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING - 1;
-        test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
+            env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
 
-        let pledge_before_rewarding =
-            test_helpers::read_mixnode_pledge_amount(deps.as_ref().storage, &node_identity)
-                .unwrap()
-                .u128();
+            let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+            let res = try_reward_mixnode(
+                deps.as_mut(),
+                env,
+                info,
+                node_identity.clone(),
+                tests::fixtures::node_rewarding_params_fixture(100),
+                0,
+            )
+            .unwrap();
+            assert_eq!(
+                false.to_string(),
+                must_find_attribute(&res.events[0], FURTHER_DELEGATIONS_TO_REWARD_KEY)
+            );
 
-        let info = mock_info(rewarding_validator_address.as_ref(), &[]);
-        let res = try_reward_mixnode(
-            deps.as_mut(),
-            env,
-            info.clone(),
-            node_identity.clone(),
-            tests::fixtures::node_rewarding_params_fixture(100),
-            2,
-        )
-        .unwrap();
+            for i in 0..MIXNODE_DELEGATORS_PAGE_LIMIT {
+                let pending = test_helpers::read_pending_delegator_reward(
+                    &deps.storage,
+                    node_identity.clone(),
+                    format!("delegator{}", i),
+                );
 
-        assert!(
-            test_helpers::read_mixnode_pledge_amount(deps.as_ref().storage, &node_identity)
-                .unwrap()
-                .u128()
-                > pledge_before_rewarding
-        );
-        assert!(
-            mixnodes_storage::TOTAL_DELEGATION
-                .load(deps.as_ref().storage, &node_identity)
-                .unwrap()
-                .u128()
-                > initial_delegation
-        );
+                assert!(!pending.is_zero());
+            }
+        }
 
-        assert_eq!(1, res.events.len());
-        let event = &res.events[0];
-        assert_eq!(OPERATOR_REWARDING_EVENT_TYPE, event.ty);
-        assert_ne!("0", must_find_attribute(event, TOTAL_MIXNODE_REWARD_KEY));
-        assert_ne!("0", must_find_attribute(event, OPERATOR_REWARD_KEY));
-        assert_ne!(
-            "0",
-            must_find_attribute(event, DISTRIBUTED_DELEGATION_REWARDS_KEY)
-        );
-        assert_eq!(
-            false.to_string(),
-            must_find_attribute(event, FURTHER_DELEGATIONS_TO_REWARD_KEY)
-        );
-    }
+        #[test]
+        fn with_more_than_full_page_limit() {
+            use super::*;
 
-    #[test]
-    fn test_tokenomics_rewarding() {
-        use crate::constants::INTERVAL_REWARD_PERCENT;
-        use crate::contract::INITIAL_REWARD_POOL;
+            let mut deps = test_helpers::init_contract();
+            let mut env = mock_env();
+            let current_state = mixnet_params_storage::CONTRACT_STATE
+                .load(deps.as_mut().storage)
+                .unwrap();
+            let rewarding_validator_address = current_state.rewarding_validator_address;
 
-        type U128 = fixed::types::U75F53;
+            #[allow(clippy::inconsistent_digit_grouping)]
+            let mix_bond = Uint128::new(10000_000_000);
+            let delegation_value = 2000_000000;
 
-        let mut deps = test_helpers::init_contract();
-        let mut env = mock_env();
-        let current_state = mixnet_params_storage::CONTRACT_STATE
-            .load(deps.as_ref().storage)
+            let node_owner: Addr = Addr::unchecked("MIXNODE_DELEGATORS_PAGE_LIMIT+1_delegators");
+            let node_identity = test_helpers::add_mixnode(
+                node_owner.as_str(),
+                vec![Coin {
+                    denom: DENOM.to_string(),
+                    amount: mix_bond,
+                }],
+                deps.as_mut(),
+            );
+
+            for i in 0..MIXNODE_DELEGATORS_PAGE_LIMIT + 1 {
+                try_delegate_to_mixnode(
+                    deps.as_mut(),
+                    env.clone(),
+                    mock_info(
+                        &*format!("delegator{:04}", i),
+                        &[coin(delegation_value, DENOM)],
+                    ),
+                    node_identity.clone(),
+                )
+                .unwrap();
+            }
+
+            env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
+
+            let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+            let res = try_reward_mixnode(
+                deps.as_mut(),
+                env,
+                info,
+                node_identity.clone(),
+                tests::fixtures::node_rewarding_params_fixture(100),
+                0,
+            )
             .unwrap();
-        let rewarding_validator_address = current_state.rewarding_validator_address;
-        let period_reward_pool = (INITIAL_REWARD_POOL / 100) * INTERVAL_REWARD_PERCENT as u128;
-        assert_eq!(period_reward_pool, 5_000_000_000_000);
-        let rewarded_set_size = 200; // Imagining our reward set size is 200
-        let active_set_size = 100;
-        let active_set_work_factor = 10;
-        let circulating_supply = storage::circulating_supply(&deps.storage).unwrap().u128();
-        assert_eq!(circulating_supply, 750_000_000_000_000u128);
+            assert_eq!(
+                true.to_string(),
+                must_find_attribute(&res.events[0], FURTHER_DELEGATIONS_TO_REWARD_KEY)
+            );
+
+            for i in 0..MIXNODE_DELEGATORS_PAGE_LIMIT {
+                let pending = test_helpers::read_pending_delegator_reward(
+                    &deps.storage,
+                    node_identity.clone(),
+                    format!("delegator{:04}", i),
+                );
+
+                assert!(!pending.is_zero());
+            }
+
+            let pending = test_helpers::read_pending_delegator_reward(
+                &deps.storage,
+                node_identity,
+                format!("delegator{:04}", MIXNODE_DELEGATORS_PAGE_LIMIT),
+            );
 
-        let node_owner: Addr = Addr::unchecked("alice");
+            assert!(pending.is_zero());
+        }
+    }
+
+    #[test]
+    fn rewarding_mix_delegators_return_consistent_results() {
+        // with single page
+        let mut deps = test_helpers::init_contract();
+        let mut env = mock_env();
+
+        let node_owner: Addr = Addr::unchecked("bob");
+
+        #[allow(clippy::inconsistent_digit_grouping)]
         let node_identity = test_helpers::add_mixnode(
             node_owner.as_str(),
-            coins(10_000_000_000, DENOM),
+            coins(10000_000_000, DENOM),
             deps.as_mut(),
         );
 
-        try_delegate_to_mixnode(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("alice_d1", &[coin(8000_000000, DENOM)]),
-            node_identity.clone(),
-        )
-        .unwrap();
+        let bond = mixnodes_storage::read_full_mixnode_bond(deps.as_ref().storage, &*node_identity)
+            .unwrap()
+            .unwrap();
 
-        try_delegate_to_mixnode(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("alice_d2", &[coin(2000_000000, DENOM)]),
-            node_identity.clone(),
-        )
-        .unwrap();
+        let base_delegation = 200_000000;
+        let delegations = 123;
 
-        let info = mock_info(rewarding_validator_address.as_ref(), &[]);
-        env.block.height += 2 * constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
+        for i in 0..delegations {
+            try_delegate_to_mixnode(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(
+                    &*format!("delegator{:04}", i),
+                    &[coin(base_delegation, DENOM)],
+                ),
+                node_identity.clone(),
+            )
+            .unwrap();
+        }
 
-        let mix_1 = mixnodes_storage::read_full_mixnode_bond(&deps.storage, &node_identity)
-            .unwrap()
+        env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING + 1;
+        let mut node_rewarding_params = tests::fixtures::node_rewarding_params_fixture(100);
+        node_rewarding_params.set_reward_blockstamp(env.block.height);
+
+        let params = DelegatorRewardParams::new(&bond, node_rewarding_params);
+        let res = reward_mix_delegators(deps.as_mut().storage, node_identity.clone(), None, params)
             .unwrap();
-        let mix_1_uptime = 100;
 
-        let mut params = NodeRewardParams::new(
-            period_reward_pool,
-            rewarded_set_size,
-            active_set_size,
-            0,
-            circulating_supply,
-            mix_1_uptime,
-            SYBIL_RESISTANCE_PERCENT,
-            true,
-            active_set_work_factor,
-        );
+        let mut actual_reward = Uint128::new(0);
+        for delegation in delegations_storage::delegations()
+            .idx
+            .mixnode
+            .prefix(node_identity)
+            .range(deps.as_ref().storage, None, None, Order::Ascending)
+        {
+            let (primary_key, _) = delegation.unwrap();
+            actual_reward += storage::PENDING_DELEGATOR_REWARDS
+                .may_load(deps.as_ref().storage, primary_key)
+                .unwrap()
+                .unwrap_or_default();
+        }
 
-        params.set_reward_blockstamp(env.block.height);
+        // sanity check to make sure we actually gave out any rewards
+        assert_ne!(actual_reward, Uint128::zero());
 
-        assert_eq!(params.performance(), 1);
+        assert_eq!(actual_reward, res.total_rewarded);
+        assert!(res.start_next.is_none());
 
-        let mix_1_reward_result = mix_1.reward(&params);
+        // with paging
+        let node_owner: Addr = Addr::unchecked("alice");
 
-        assert_eq!(
-            mix_1_reward_result.sigma(),
-            U128::from_num(0.0000266666666666)
-        );
-        assert_eq!(
-            mix_1_reward_result.lambda(),
-            U128::from_num(0.0000133333333333)
+        #[allow(clippy::inconsistent_digit_grouping)]
+        let node_identity = test_helpers::add_mixnode(
+            node_owner.as_str(),
+            coins(10000_000_000, DENOM),
+            deps.as_mut(),
         );
-        assert_eq!(mix_1_reward_result.reward().int(), 186562237);
 
-        let mix1_operator_profit = mix_1.operator_reward(&params);
+        let bond = mixnodes_storage::read_full_mixnode_bond(deps.as_ref().storage, &*node_identity)
+            .unwrap()
+            .unwrap();
 
-        let mix1_delegator1_reward = mix_1.reward_delegation(Uint128::new(8000_000000), &params);
+        let base_delegation = 200_000000;
+        let delegations = MIXNODE_DELEGATORS_PAGE_LIMIT + 123;
 
-        let mix1_delegator2_reward = mix_1.reward_delegation(Uint128::new(2000_000000), &params);
+        for i in 0..delegations {
+            try_delegate_to_mixnode(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(
+                    &*format!("delegator{:04}", i),
+                    &[coin(base_delegation, DENOM)],
+                ),
+                node_identity.clone(),
+            )
+            .unwrap();
+        }
 
-        assert_eq!(mix1_operator_profit, U128::from_num(120609230));
-        assert_eq!(mix1_delegator1_reward, U128::from_num(52762405));
-        assert_eq!(mix1_delegator2_reward, U128::from_num(13190601));
+        env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING + 1;
+        let mut node_rewarding_params = tests::fixtures::node_rewarding_params_fixture(100);
+        node_rewarding_params.set_reward_blockstamp(env.block.height);
 
-        let pre_reward_bond =
-            test_helpers::read_mixnode_pledge_amount(&deps.storage, &node_identity)
-                .unwrap()
-                .u128();
-        assert_eq!(pre_reward_bond, 10_000_000_000);
+        let params = DelegatorRewardParams::new(&bond, node_rewarding_params);
+        let res = reward_mix_delegators(deps.as_mut().storage, node_identity.clone(), None, params)
+            .unwrap();
 
-        let pre_reward_delegation = mixnodes_storage::TOTAL_DELEGATION
-            .load(&deps.storage, &node_identity)
-            .unwrap()
-            .u128();
-        assert_eq!(pre_reward_delegation, 10_000_000_000);
+        let mut actual_reward = Uint128::new(0);
+        for delegation in delegations_storage::delegations()
+            .idx
+            .mixnode
+            .prefix(node_identity.clone())
+            .range_raw(deps.as_ref().storage, None, None, Order::Ascending)
+        {
+            let (primary_key, _) = delegation.unwrap();
+            let delegator_reward = storage::PENDING_DELEGATOR_REWARDS
+                .may_load(deps.as_ref().storage, primary_key.clone())
+                .unwrap()
+                .unwrap_or_default();
+            actual_reward += delegator_reward;
 
-        try_reward_mixnode(deps.as_mut(), env, info, node_identity.clone(), params, 0).unwrap();
+            // we start from index 2 as first 2 bytes are used to indicate length of first part
+            // of the composite key
+            let id_delegator = String::from_utf8_lossy(&primary_key[2..]);
 
-        assert_eq!(
-            test_helpers::read_mixnode_pledge_amount(&deps.storage, &node_identity)
+            let delegator_id: usize = id_delegator
+                .strip_prefix(&node_identity)
                 .unwrap()
-                .u128(),
-            U128::from_num(pre_reward_bond) + U128::from_num(mix1_operator_profit)
-        );
-        assert_eq!(
-            mixnodes_storage::TOTAL_DELEGATION
-                .load(&deps.storage, &node_identity)
+                .strip_prefix("delegator")
                 .unwrap()
-                .u128(),
-            pre_reward_delegation + mix1_delegator1_reward + mix1_delegator2_reward
-        );
+                .parse()
+                .unwrap();
+            if delegator_id >= MIXNODE_DELEGATORS_PAGE_LIMIT {
+                // if they were in next page, they shouldn't have gotten anything!
+                assert_eq!(Uint128::zero(), delegator_reward)
+            } else {
+                assert_ne!(Uint128::zero(), delegator_reward);
+            }
+        }
 
-        assert_eq!(
-            storage::REWARD_POOL.load(&deps.storage).unwrap().u128(),
-            U128::from_num(INITIAL_REWARD_POOL)
-                - (U128::from_num(mix1_operator_profit)
-                    + U128::from_num(mix1_delegator1_reward)
-                    + U128::from_num(mix1_delegator2_reward))
-        );
+        assert_eq!(actual_reward, res.total_rewarded);
+        let expected_next_page_start = format!("delegator{:04}", MIXNODE_DELEGATORS_PAGE_LIMIT);
+        assert_eq!(expected_next_page_start, res.start_next.clone().unwrap());
 
-        // it's all correctly saved
-        match storage::REWARDING_STATUS
-            .load(deps.as_ref().storage, (0u32, node_identity))
-            .unwrap()
+        let res2 = reward_mix_delegators(
+            deps.as_mut().storage,
+            node_identity.clone(),
+            res.start_next.clone(),
+            params,
+        )
+        .unwrap();
+
+        let start = res.start_next.unwrap();
+        let mut actual_reward = Uint128::new(0);
+
+        let start = Bound::Inclusive((node_identity.clone(), start).joined_key());
+        for delegation in delegations_storage::delegations()
+            .idx
+            .mixnode
+            .prefix(node_identity)
+            .range(deps.as_ref().storage, Some(start), None, Order::Ascending)
         {
-            RewardingStatus::Complete(result) => assert_eq!(
-                RewardingResult {
-                    operator_reward: Uint128::new(mix1_operator_profit),
-                    total_delegator_reward: Uint128::new(
-                        mix1_delegator1_reward + mix1_delegator2_reward
-                    )
-                },
-                result
-            ),
-            _ => unreachable!(),
+            let (primary_key, _) = delegation.unwrap();
+            actual_reward += storage::PENDING_DELEGATOR_REWARDS
+                .may_load(deps.as_ref().storage, primary_key)
+                .unwrap()
+                .unwrap_or_default();
         }
+
+        assert_eq!(actual_reward, res2.total_rewarded);
+        assert!(res2.start_next.is_none());
     }
 
     #[cfg(test)]
-    mod mixnode_rewarding_distributes_rewards_to_up_to_one_page_of_delegators {
+    mod delegator_rewarding_tx {
+        use super::*;
 
         #[test]
-        fn with_10_delegations() {
-            use super::*;
+        fn can_only_be_called_by_specified_validator_address() {
+            let mut deps = test_helpers::init_contract();
+
+            let res = try_reward_next_mixnode_delegators(
+                deps.as_mut(),
+                mock_info("not-the-approved-validator", &[]),
+                "alice's mixnode".to_string(),
+                1,
+            );
+            assert_eq!(Err(ContractError::Unauthorized), res);
+        }
+
+        #[test]
+        fn cannot_be_called_if_mixnodes_operator_wasnt_rewarded() {
+            let mut deps = test_helpers::init_contract();
+            let current_state = mixnet_params_storage::CONTRACT_STATE
+                .load(deps.as_mut().storage)
+                .unwrap();
+            let rewarding_validator_address = current_state.rewarding_validator_address;
+
+            let res = try_reward_next_mixnode_delegators(
+                deps.as_mut(),
+                mock_info(rewarding_validator_address.as_ref(), &[]),
+                "alice's mixnode".to_string(),
+                0,
+            );
+
+            assert_eq!(
+                Err(ContractError::MixnodeOperatorNotRewarded {
+                    identity: "alice's mixnode".to_string()
+                }),
+                res
+            )
+        }
 
+        #[test]
+        fn cannot_be_called_if_mixnode_is_fully_rewarded() {
+            // everything was done in a single reward call
             let mut deps = test_helpers::init_contract();
             let mut env = mock_env();
             let current_state = mixnet_params_storage::CONTRACT_STATE
@@ -821,66 +1916,102 @@ pub mod tests {
                 .unwrap();
             let rewarding_validator_address = current_state.rewarding_validator_address;
 
+            let node_owner: Addr = Addr::unchecked("alice");
+
             #[allow(clippy::inconsistent_digit_grouping)]
-            let mix_bond = Uint128::new(10000_000_000);
-            let delegation_value = 2000_000000;
+            let node_identity = test_helpers::add_mixnode(
+                node_owner.as_str(),
+                coins(10000_000_000, DENOM),
+                deps.as_mut(),
+            );
 
-            let node_owner: Addr = Addr::unchecked("10delegators");
+            env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
+
+            try_reward_mixnode(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(rewarding_validator_address.as_ref(), &[]),
+                node_identity.clone(),
+                tests::fixtures::node_rewarding_params_fixture(100),
+                0,
+            )
+            .unwrap();
+
+            let res = try_reward_next_mixnode_delegators(
+                deps.as_mut(),
+                mock_info(rewarding_validator_address.as_ref(), &[]),
+                node_identity.clone(),
+                0,
+            );
+
+            assert_eq!(
+                Err(ContractError::MixnodeAlreadyRewarded {
+                    identity: node_identity
+                }),
+                res
+            );
+
+            // there was another page of delegators, but they were already dealt with
+            let node_owner: Addr = Addr::unchecked("bob");
+
+            #[allow(clippy::inconsistent_digit_grouping)]
             let node_identity = test_helpers::add_mixnode(
                 node_owner.as_str(),
-                vec![Coin {
-                    denom: DENOM.to_string(),
-                    amount: mix_bond,
-                }],
+                coins(10000_000_000, DENOM),
                 deps.as_mut(),
             );
 
-            for i in 0..10 {
+            for i in 0..MIXNODE_DELEGATORS_PAGE_LIMIT + 1 {
                 try_delegate_to_mixnode(
                     deps.as_mut(),
                     env.clone(),
-                    mock_info(
-                        &*format!("delegator{}", i),
-                        &[coin(delegation_value, DENOM)],
-                    ),
+                    mock_info(&*format!("delegator{:04}", i), &[coin(2000_000000, DENOM)]),
                     node_identity.clone(),
                 )
                 .unwrap();
             }
 
             env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
+            test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
 
             let info = mock_info(rewarding_validator_address.as_ref(), &[]);
-            let res = try_reward_mixnode(
+            try_reward_mixnode(
                 deps.as_mut(),
                 env,
                 info,
                 node_identity.clone(),
                 tests::fixtures::node_rewarding_params_fixture(100),
-                0,
+                1,
             )
             .unwrap();
-            assert_eq!(
-                false.to_string(),
-                must_find_attribute(&res.events[0], FURTHER_DELEGATIONS_TO_REWARD_KEY)
-            );
 
-            for i in 0..10 {
-                let delegation = test_helpers::read_delegation(
-                    &deps.storage,
-                    node_identity.clone(),
-                    format!("delegator{}", i),
-                )
-                .unwrap();
+            // rewards all pending
+            try_reward_next_mixnode_delegators(
+                deps.as_mut(),
+                mock_info(rewarding_validator_address.as_ref(), &[]),
+                node_identity.clone(),
+                1,
+            )
+            .unwrap();
 
-                assert!(delegation.amount.amount > Uint128::new(delegation_value));
-            }
+            let res = try_reward_next_mixnode_delegators(
+                deps.as_mut(),
+                mock_info(rewarding_validator_address.as_ref(), &[]),
+                node_identity.clone(),
+                1,
+            );
+
+            assert_eq!(
+                Err(ContractError::MixnodeAlreadyRewarded {
+                    identity: node_identity
+                }),
+                res
+            );
         }
 
         #[test]
-        fn with_full_page_limit() {
-            use super::*;
-
+        fn rewards_all_delegators_on_the_next_page() {
+            // setup: bond > page limit delegators, reward operator + first batch
             let mut deps = test_helpers::init_contract();
             let mut env = mock_env();
             let current_state = mixnet_params_storage::CONTRACT_STATE
@@ -892,7 +2023,9 @@ pub mod tests {
             let mix_bond = Uint128::new(10000_000_000);
             let delegation_value = 2000_000000;
 
-            let node_owner: Addr = Addr::unchecked("MIXNODE_DELEGATORS_PAGE_LIMIT_delegators");
+            let total_delegators = 2 * MIXNODE_DELEGATORS_PAGE_LIMIT + 123;
+
+            let node_owner: Addr = Addr::unchecked("alice");
             let node_identity = test_helpers::add_mixnode(
                 node_owner.as_str(),
                 vec![Coin {
@@ -902,12 +2035,12 @@ pub mod tests {
                 deps.as_mut(),
             );
 
-            for i in 0..MIXNODE_DELEGATORS_PAGE_LIMIT {
+            for i in 0..total_delegators {
                 try_delegate_to_mixnode(
                     deps.as_mut(),
                     env.clone(),
                     mock_info(
-                        &*format!("delegator{}", i),
+                        &*format!("delegator{:04}", i),
                         &[coin(delegation_value, DENOM)],
                     ),
                     node_identity.clone(),
@@ -918,7 +2051,7 @@ pub mod tests {
             env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
 
             let info = mock_info(rewarding_validator_address.as_ref(), &[]);
-            let res = try_reward_mixnode(
+            try_reward_mixnode(
                 deps.as_mut(),
                 env,
                 info,
@@ -927,27 +2060,45 @@ pub mod tests {
                 0,
             )
             .unwrap();
-            assert_eq!(
-                false.to_string(),
-                must_find_attribute(&res.events[0], FURTHER_DELEGATIONS_TO_REWARD_KEY)
+
+            // we have 3 pages in total, so we have to call this twice
+            try_reward_next_mixnode_delegators(
+                deps.as_mut(),
+                mock_info(rewarding_validator_address.as_ref(), &[]),
+                node_identity.clone(),
+                0,
+            )
+            .unwrap();
+            try_reward_next_mixnode_delegators(
+                deps.as_mut(),
+                mock_info(rewarding_validator_address.as_ref(), &[]),
+                node_identity.clone(),
+                0,
+            )
+            .unwrap();
+
+            let expected = test_helpers::read_pending_delegator_reward(
+                &deps.storage,
+                node_identity.clone(),
+                "delegator0001",
             );
 
-            for i in 0..MIXNODE_DELEGATORS_PAGE_LIMIT {
-                let delegation = test_helpers::read_delegation(
+            for i in 0..total_delegators {
+                // everyone was rewarded (and the same amount, because they all delegated the same amount)
+                let pending = test_helpers::read_pending_delegator_reward(
                     &deps.storage,
                     node_identity.clone(),
-                    format!("delegator{}", i),
-                )
-                .unwrap();
+                    format!("delegator{:04}", i),
+                );
 
-                assert!(delegation.amount.amount > Uint128::new(delegation_value));
+                assert!(!pending.is_zero());
+                assert_eq!(expected, pending)
             }
         }
 
         #[test]
-        fn with_more_than_full_page_limit() {
-            use super::*;
-
+        fn ignores_delegators_that_updated_their_pledge_in_the_meantime() {
+            // setup: bond > page limit delegators, reward operator + first batch
             let mut deps = test_helpers::init_contract();
             let mut env = mock_env();
             let current_state = mixnet_params_storage::CONTRACT_STATE
@@ -959,7 +2110,9 @@ pub mod tests {
             let mix_bond = Uint128::new(10000_000_000);
             let delegation_value = 2000_000000;
 
-            let node_owner: Addr = Addr::unchecked("MIXNODE_DELEGATORS_PAGE_LIMIT+1_delegators");
+            let total_delegators = MIXNODE_DELEGATORS_PAGE_LIMIT + 123;
+
+            let node_owner: Addr = Addr::unchecked("alice");
             let node_identity = test_helpers::add_mixnode(
                 node_owner.as_str(),
                 vec![Coin {
@@ -969,7 +2122,7 @@ pub mod tests {
                 deps.as_mut(),
             );
 
-            for i in 0..MIXNODE_DELEGATORS_PAGE_LIMIT + 1 {
+            for i in 0..total_delegators {
                 try_delegate_to_mixnode(
                     deps.as_mut(),
                     env.clone(),
@@ -984,541 +2137,760 @@ pub mod tests {
 
             env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
 
-            let info = mock_info(rewarding_validator_address.as_ref(), &[]);
-            let res = try_reward_mixnode(
-                deps.as_mut(),
-                env,
-                info,
-                node_identity.clone(),
-                tests::fixtures::node_rewarding_params_fixture(100),
-                0,
-            )
-            .unwrap();
-            assert_eq!(
-                true.to_string(),
-                must_find_attribute(&res.events[0], FURTHER_DELEGATIONS_TO_REWARD_KEY)
-            );
-
-            for i in 0..MIXNODE_DELEGATORS_PAGE_LIMIT {
-                let delegation = test_helpers::read_delegation(
-                    &deps.storage,
-                    node_identity.clone(),
-                    format!("delegator{:04}", i),
-                )
-                .unwrap();
-
-                assert!(delegation.amount.amount > Uint128::new(delegation_value));
-            }
-
-            let delegation = test_helpers::read_delegation(
-                &deps.storage,
-                node_identity,
-                format!("delegator{:04}", MIXNODE_DELEGATORS_PAGE_LIMIT),
-            )
-            .unwrap();
-
-            assert_eq!(delegation.amount.amount, Uint128::new(delegation_value));
-        }
-    }
-
-    #[test]
-    fn rewarding_mix_delegators_return_consistent_results() {
-        // with single page
-        let mut deps = test_helpers::init_contract();
-        let mut env = mock_env();
-
-        let node_owner: Addr = Addr::unchecked("bob");
-
-        #[allow(clippy::inconsistent_digit_grouping)]
-        let node_identity = test_helpers::add_mixnode(
-            node_owner.as_str(),
-            coins(10000_000_000, DENOM),
-            deps.as_mut(),
-        );
-
-        let bond = mixnodes_storage::read_full_mixnode_bond(deps.as_ref().storage, &*node_identity)
-            .unwrap()
-            .unwrap();
-
-        let base_delegation = 200_000000;
-        let delegations = 123;
-
-        for i in 0..delegations {
+            // update some delegations (on 'main' page and the secondary call)
             try_delegate_to_mixnode(
                 deps.as_mut(),
                 env.clone(),
-                mock_info(
-                    &*format!("delegator{:04}", i),
-                    &[coin(base_delegation, DENOM)],
-                ),
+                mock_info("delegator0123", &[coin(delegation_value, DENOM)]),
                 node_identity.clone(),
             )
             .unwrap();
-        }
-
-        env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING + 1;
-        let mut node_rewarding_params = tests::fixtures::node_rewarding_params_fixture(100);
-        node_rewarding_params.set_reward_blockstamp(env.block.height);
-
-        let params = DelegatorRewardParams::new(&bond, node_rewarding_params);
-        let res = reward_mix_delegators(deps.as_mut().storage, node_identity.clone(), None, params)
-            .unwrap();
-
-        let mut actual_reward = Uint128::new(0);
-        for delegation in delegations_storage::delegations()
-            .idx
-            .mixnode
-            .prefix(node_identity)
-            .range(deps.as_ref().storage, None, None, Order::Ascending)
-        {
-            actual_reward +=
-                Uint128::new(delegation.unwrap().1.amount.amount.u128() - base_delegation);
-        }
-
-        // sanity check to make sure we actually gave out any rewards
-        assert_ne!(actual_reward, Uint128::zero());
-
-        assert_eq!(actual_reward, res.total_rewarded);
-        assert!(res.start_next.is_none());
-
-        // with paging
-        let node_owner: Addr = Addr::unchecked("alice");
-
-        #[allow(clippy::inconsistent_digit_grouping)]
-        let node_identity = test_helpers::add_mixnode(
-            node_owner.as_str(),
-            coins(10000_000_000, DENOM),
-            deps.as_mut(),
-        );
-
-        let bond = mixnodes_storage::read_full_mixnode_bond(deps.as_ref().storage, &*node_identity)
-            .unwrap()
-            .unwrap();
-
-        let base_delegation = 200_000000;
-        let delegations = MIXNODE_DELEGATORS_PAGE_LIMIT + 123;
 
-        for i in 0..delegations {
             try_delegate_to_mixnode(
                 deps.as_mut(),
                 env.clone(),
-                mock_info(
-                    &*format!("delegator{:04}", i),
-                    &[coin(base_delegation, DENOM)],
+                mock_info(
+                    &*format!("delegator{:04}", 123 + MIXNODE_DELEGATORS_PAGE_LIMIT),
+                    &[coin(delegation_value, DENOM)],
                 ),
                 node_identity.clone(),
             )
             .unwrap();
-        }
 
-        env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING + 1;
-        let mut node_rewarding_params = tests::fixtures::node_rewarding_params_fixture(100);
-        node_rewarding_params.set_reward_blockstamp(env.block.height);
+            env.block.height += 123;
 
-        let params = DelegatorRewardParams::new(&bond, node_rewarding_params);
-        let res = reward_mix_delegators(deps.as_mut().storage, node_identity.clone(), None, params)
+            let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+            try_reward_mixnode(
+                deps.as_mut(),
+                env,
+                info,
+                node_identity.clone(),
+                tests::fixtures::node_rewarding_params_fixture(100),
+                0,
+            )
             .unwrap();
 
-        let mut actual_reward = Uint128::new(0);
-        for delegation in delegations_storage::delegations()
-            .idx
-            .mixnode
-            .prefix(node_identity.clone())
-            .range_raw(deps.as_ref().storage, None, None, Order::Ascending)
-        {
-            let (primary_key, delegation) = delegation.unwrap();
-            let delegator_reward = Uint128::new(delegation.amount.amount.u128() - base_delegation);
-            actual_reward += delegator_reward;
-
-            // we start from index 2 as first 2 bytes are used to indicate length of first part
-            // of the composite key
-            let id_delegator = String::from_utf8_lossy(&primary_key[2..]);
-
-            let delegator_id: usize = id_delegator
-                .strip_prefix(&node_identity)
-                .unwrap()
-                .strip_prefix("delegator")
-                .unwrap()
-                .parse()
-                .unwrap();
-            if delegator_id >= MIXNODE_DELEGATORS_PAGE_LIMIT {
-                // if they were in next page, they shouldn't have gotten anything!
-                assert_eq!(Uint128::zero(), delegator_reward)
-            } else {
-                assert_ne!(Uint128::zero(), delegator_reward);
-            }
-        }
-
-        assert_eq!(actual_reward, res.total_rewarded);
-        let expected_next_page_start = format!("delegator{:04}", MIXNODE_DELEGATORS_PAGE_LIMIT);
-        assert_eq!(expected_next_page_start, res.start_next.clone().unwrap());
+            // we have 3 pages in total, so we have to call this twice
+            try_reward_next_mixnode_delegators(
+                deps.as_mut(),
+                mock_info(rewarding_validator_address.as_ref(), &[]),
+                node_identity.clone(),
+                0,
+            )
+            .unwrap();
 
-        let res2 = reward_mix_delegators(
-            deps.as_mut().storage,
-            node_identity.clone(),
-            res.start_next.clone(),
-            params,
-        )
-        .unwrap();
+            let expected = test_helpers::read_pending_delegator_reward(
+                &deps.storage,
+                node_identity.clone(),
+                "delegator0001",
+            );
 
-        let start = res.start_next.unwrap();
-        let mut actual_reward = Uint128::new(0);
+            for i in 0..total_delegators {
+                // everyone was rewarded (and the same amount, because they all delegated the same amount)
+                let pending = test_helpers::read_pending_delegator_reward(
+                    &deps.storage,
+                    node_identity.clone(),
+                    format!("delegator{:04}", i),
+                );
 
-        let start = Bound::Inclusive((node_identity.clone(), start).joined_key());
-        for delegation in delegations_storage::delegations()
-            .idx
-            .mixnode
-            .prefix(node_identity)
-            .range(deps.as_ref().storage, Some(start), None, Order::Ascending)
-        {
-            actual_reward +=
-                Uint128::new(delegation.unwrap().1.amount.amount.u128() - base_delegation);
+                if i == 123 || i == 123 + MIXNODE_DELEGATORS_PAGE_LIMIT {
+                    assert!(pending.is_zero())
+                } else {
+                    assert!(!pending.is_zero());
+                    assert_eq!(expected, pending)
+                }
+            }
         }
-
-        assert_eq!(actual_reward, res2.total_rewarded);
-        assert!(res2.start_next.is_none());
     }
 
     #[cfg(test)]
-    mod delegator_rewarding_tx {
+    mod gateway_rewarding {
         use super::*;
+        use crate::delegations::transactions::try_delegate_to_gateway;
+        use mixnet_contract_common::{GatewayRewardParams, GatewayRewardingParams};
+
+        fn enable_gateway_rewarding(deps: DepsMut<'_>) {
+            let mut state = mixnet_params_storage::CONTRACT_STATE
+                .load(deps.storage)
+                .unwrap();
+            state.params.gateway_rewarding = GatewayRewardingParams {
+                reward_pool_share_percent: 10,
+                rewarded_set_size: 10,
+                operator_share_percent: 20,
+            };
+            mixnet_params_storage::CONTRACT_STATE
+                .save(deps.storage, &state)
+                .unwrap();
+        }
+
+        fn reward_params(uptime: u128) -> GatewayRewardParams {
+            GatewayRewardParams::new(1_000_000_000, uptime, 0)
+        }
 
         #[test]
-        fn can_only_be_called_by_specified_validator_address() {
+        fn fails_if_gateway_rewarding_is_disabled() {
             let mut deps = test_helpers::init_contract();
+            let mut env = mock_env();
+            let rewarding_validator_address = mixnet_params_storage::CONTRACT_STATE
+                .load(deps.as_ref().storage)
+                .unwrap()
+                .rewarding_validator_address;
 
-            let res = try_reward_next_mixnode_delegators(
+            let identity = test_helpers::add_gateway(
+                "gateway-owner",
+                tests::fixtures::good_gateway_pledge(),
                 deps.as_mut(),
-                mock_info("not-the-approved-validator", &[]),
-                "alice's mixnode".to_string(),
-                1,
             );
-            assert_eq!(Err(ContractError::Unauthorized), res);
+            env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
+
+            let res = try_reward_gateway(
+                deps.as_mut(),
+                env,
+                mock_info(rewarding_validator_address.as_ref(), &[]),
+                identity,
+                reward_params(100),
+                0,
+            );
+            assert_eq!(Err(ContractError::GatewayRewardingDisabled), res);
         }
 
         #[test]
-        fn cannot_be_called_if_mixnodes_operator_wasnt_rewarded() {
+        fn splits_reward_between_operator_and_delegators() {
             let mut deps = test_helpers::init_contract();
-            let current_state = mixnet_params_storage::CONTRACT_STATE
-                .load(deps.as_mut().storage)
-                .unwrap();
-            let rewarding_validator_address = current_state.rewarding_validator_address;
+            let mut env = mock_env();
+            let rewarding_validator_address = mixnet_params_storage::CONTRACT_STATE
+                .load(deps.as_ref().storage)
+                .unwrap()
+                .rewarding_validator_address;
+            let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+            enable_gateway_rewarding(deps.as_mut());
 
-            let res = try_reward_next_mixnode_delegators(
+            let identity = test_helpers::add_gateway(
+                "gateway-owner",
+                tests::fixtures::good_gateway_pledge(),
                 deps.as_mut(),
-                mock_info(rewarding_validator_address.as_ref(), &[]),
-                "alice's mixnode".to_string(),
+            );
+            let pledge = tests::fixtures::good_gateway_pledge()[0].amount;
+            try_delegate_to_gateway(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("delegator", &[coin(pledge.u128(), DENOM)]),
+                identity.clone(),
+            )
+            .unwrap();
+            env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
+
+            let pool_before = storage::REWARD_POOL.load(deps.as_ref().storage).unwrap();
+            try_reward_gateway(
+                deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                identity.clone(),
+                reward_params(100),
                 0,
+            )
+            .unwrap();
+
+            // 10% of the pool shared between 10 gateways
+            let total_reward = Uint128::new(10_000_000);
+            // the operator takes 20% cut and half of the remainder, as it owns half of the stake
+            let operator_reward = Uint128::new(6_000_000);
+            let delegator_reward = Uint128::new(4_000_000);
+
+            // the rewards are only accrued, neither the pledge nor the delegation change
+            let bond = gateways_storage::gateways()
+                .load(deps.as_ref().storage, &identity)
+                .unwrap();
+            assert_eq!(pledge, bond.pledge_amount.amount);
+            assert_eq!(
+                operator_reward,
+                storage::PENDING_OPERATOR_REWARDS
+                    .load(deps.as_ref().storage, &identity)
+                    .unwrap()
             );
 
+            let delegation = delegations_storage::gateway_delegations()
+                .load(
+                    deps.as_ref().storage,
+                    (identity.clone(), "delegator").joined_key(),
+                )
+                .unwrap();
+            assert_eq!(pledge, delegation.amount.amount);
             assert_eq!(
-                Err(ContractError::MixnodeOperatorNotRewarded {
-                    identity: "alice's mixnode".to_string()
+                pledge,
+                gateways_storage::TOTAL_GATEWAY_DELEGATION
+                    .load(deps.as_ref().storage, &identity)
+                    .unwrap()
+            );
+            assert_eq!(
+                delegator_reward,
+                test_helpers::read_pending_delegator_reward(
+                    deps.as_ref().storage,
+                    &identity,
+                    "delegator"
+                )
+            );
+            assert_eq!(
+                pool_before - total_reward,
+                storage::REWARD_POOL.load(deps.as_ref().storage).unwrap()
+            );
+
+            // and it can't be rewarded again in the same interval
+            assert_eq!(
+                Err(ContractError::GatewayAlreadyRewarded {
+                    identity: identity.clone()
                 }),
-                res
-            )
+                try_reward_gateway(deps.as_mut(), env, info, identity, reward_params(100), 0,)
+            );
         }
 
         #[test]
-        fn cannot_be_called_if_mixnode_is_fully_rewarded() {
-            // everything was done in a single reward call
+        fn zero_uptime_gateway_gets_nothing() {
             let mut deps = test_helpers::init_contract();
             let mut env = mock_env();
-            let current_state = mixnet_params_storage::CONTRACT_STATE
-                .load(deps.as_mut().storage)
-                .unwrap();
-            let rewarding_validator_address = current_state.rewarding_validator_address;
+            let rewarding_validator_address = mixnet_params_storage::CONTRACT_STATE
+                .load(deps.as_ref().storage)
+                .unwrap()
+                .rewarding_validator_address;
+            enable_gateway_rewarding(deps.as_mut());
 
-            let node_owner: Addr = Addr::unchecked("alice");
+            let identity = test_helpers::add_gateway(
+                "gateway-owner",
+                tests::fixtures::good_gateway_pledge(),
+                deps.as_mut(),
+            );
+            env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
 
-            #[allow(clippy::inconsistent_digit_grouping)]
-            let node_identity = test_helpers::add_mixnode(
-                node_owner.as_str(),
-                coins(10000_000_000, DENOM),
+            try_reward_gateway(
                 deps.as_mut(),
+                env,
+                mock_info(rewarding_validator_address.as_ref(), &[]),
+                identity.clone(),
+                reward_params(0),
+                0,
+            )
+            .unwrap();
+
+            let bond = gateways_storage::gateways()
+                .load(deps.as_ref().storage, &identity)
+                .unwrap();
+            assert_eq!(
+                tests::fixtures::good_gateway_pledge()[0].amount,
+                bond.pledge_amount.amount
             );
+            assert!(matches!(
+                storage::GATEWAY_REWARDING_STATUS
+                    .load(deps.as_ref().storage, (0, identity))
+                    .unwrap(),
+                GatewayRewardingStatus::Complete(_)
+            ));
+        }
 
+        #[test]
+        fn at_most_rewarded_set_size_gateways_get_rewarded() {
+            let mut deps = test_helpers::init_contract();
+            let mut env = mock_env();
+            let rewarding_validator_address = mixnet_params_storage::CONTRACT_STATE
+                .load(deps.as_ref().storage)
+                .unwrap()
+                .rewarding_validator_address;
+            let info = mock_info(rewarding_validator_address.as_ref(), &[]);
+            enable_gateway_rewarding(deps.as_mut());
+
+            let identities = (0..11)
+                .map(|i| {
+                    test_helpers::add_gateway(
+                        &format!("gateway-owner{}", i),
+                        tests::fixtures::good_gateway_pledge(),
+                        deps.as_mut(),
+                    )
+                })
+                .collect::<Vec<_>>();
             env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
 
-            try_reward_mixnode(
+            // gateways without any uptime do not take up the rewarded set
+            try_reward_gateway(
                 deps.as_mut(),
                 env.clone(),
-                mock_info(rewarding_validator_address.as_ref(), &[]),
-                node_identity.clone(),
-                tests::fixtures::node_rewarding_params_fixture(100),
+                info.clone(),
+                identities[10].clone(),
+                reward_params(0),
                 0,
             )
             .unwrap();
 
-            let res = try_reward_next_mixnode_delegators(
+            for identity in &identities[..10] {
+                try_reward_gateway(
+                    deps.as_mut(),
+                    env.clone(),
+                    info.clone(),
+                    identity.clone(),
+                    reward_params(100),
+                    0,
+                )
+                .unwrap();
+            }
+
+            let extra_identity = test_helpers::add_gateway(
+                "gateway-owner-extra",
+                tests::fixtures::good_gateway_pledge(),
+                deps.as_mut(),
+            );
+            env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
+            assert_eq!(
+                Err(ContractError::GatewayRewardedSetFull {
+                    interval_id: 0,
+                    rewarded_set_size: 10
+                }),
+                try_reward_gateway(
+                    deps.as_mut(),
+                    env,
+                    info,
+                    extra_identity,
+                    reward_params(100),
+                    0
+                )
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod reward_claiming {
+        use super::*;
+        use crate::delegations::transactions::{
+            try_delegate_to_gateway, try_delegate_to_mixnode_on_behalf,
+        };
+        use crate::gateways::transactions::try_remove_gateway;
+        use crate::mixnodes::transactions::try_remove_mixnode;
+        use cosmwasm_std::{from_binary, CosmosMsg, WasmMsg};
+        use mixnet_contract_common::events::{AMOUNT_KEY, MIXNODE_UNBONDING_EVENT_TYPE};
+
+        #[test]
+        fn operator_reward_is_sent_to_the_owner_and_cleared() {
+            let mut deps = test_helpers::init_contract();
+            let node_identity = test_helpers::add_mixnode(
+                "alice",
+                tests::fixtures::good_mixnode_pledge(),
                 deps.as_mut(),
-                mock_info(rewarding_validator_address.as_ref(), &[]),
-                node_identity.clone(),
-                0,
             );
 
+            let res = try_claim_operator_reward(deps.as_mut(), mock_info("alice", &[]));
             assert_eq!(
-                Err(ContractError::MixnodeAlreadyRewarded {
-                    identity: node_identity
+                Err(ContractError::NoRewardsToClaim {
+                    identity: node_identity.clone(),
+                    address: Addr::unchecked("alice")
                 }),
                 res
             );
 
-            // there was another page of delegators, but they were already dealt with
-            let node_owner: Addr = Addr::unchecked("bob");
+            storage::accrue_operator_reward(
+                deps.as_mut().storage,
+                &node_identity,
+                Uint128::new(1000),
+            )
+            .unwrap();
+            storage::accrue_operator_reward(
+                deps.as_mut().storage,
+                &node_identity,
+                Uint128::new(234),
+            )
+            .unwrap();
 
-            #[allow(clippy::inconsistent_digit_grouping)]
+            let res = try_claim_operator_reward(deps.as_mut(), mock_info("alice", &[])).unwrap();
+            assert_eq!(
+                vec![CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "alice".to_string(),
+                    amount: coins(1234, DENOM),
+                })],
+                res.messages.into_iter().map(|m| m.msg).collect::<Vec<_>>()
+            );
+            assert!(storage::PENDING_OPERATOR_REWARDS
+                .may_load(deps.as_ref().storage, &node_identity)
+                .unwrap()
+                .is_none());
+
+            // the pledge itself is untouched
+            assert_eq!(
+                tests::fixtures::good_mixnode_pledge()[0].amount,
+                test_helpers::read_mixnode_pledge_amount(deps.as_ref().storage, &node_identity)
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn operator_reward_can_only_be_claimed_by_the_same_proxy() {
+            let mut deps = test_helpers::init_contract();
             let node_identity = test_helpers::add_mixnode(
-                node_owner.as_str(),
-                coins(10000_000_000, DENOM),
+                "alice",
+                tests::fixtures::good_mixnode_pledge(),
                 deps.as_mut(),
             );
+            storage::accrue_operator_reward(
+                deps.as_mut().storage,
+                &node_identity,
+                Uint128::new(1000),
+            )
+            .unwrap();
 
-            for i in 0..MIXNODE_DELEGATORS_PAGE_LIMIT + 1 {
-                try_delegate_to_mixnode(
-                    deps.as_mut(),
-                    env.clone(),
-                    mock_info(&*format!("delegator{:04}", i), &[coin(2000_000000, DENOM)]),
-                    node_identity.clone(),
-                )
-                .unwrap();
-            }
-
-            env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
-            test_helpers::update_env_and_progress_interval(&mut env, deps.as_mut().storage);
+            let res = try_claim_operator_reward_on_behalf(
+                deps.as_mut(),
+                mock_info("proxy", &[]),
+                "alice".to_string(),
+            );
+            assert_eq!(
+                Err(ContractError::ProxyMismatch {
+                    existing: "None".to_string(),
+                    incoming: "proxy".to_string()
+                }),
+                res
+            );
+        }
 
-            let info = mock_info(rewarding_validator_address.as_ref(), &[]);
-            try_reward_mixnode(
+        #[test]
+        fn delegator_reward_is_sent_to_the_proxy_and_tracked() {
+            let mut deps = test_helpers::init_contract();
+            let node_identity = test_helpers::add_mixnode(
+                "alice",
+                tests::fixtures::good_mixnode_pledge(),
                 deps.as_mut(),
-                env,
-                info,
+            );
+            try_delegate_to_mixnode_on_behalf(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("proxy", &coins(1000, DENOM)),
                 node_identity.clone(),
-                tests::fixtures::node_rewarding_params_fixture(100),
-                1,
+                "bob".to_string(),
             )
             .unwrap();
 
-            // rewards all pending
-            try_reward_next_mixnode_delegators(
-                deps.as_mut(),
-                mock_info(rewarding_validator_address.as_ref(), &[]),
+            let storage_key = (
                 node_identity.clone(),
-                1,
+                generate_storage_key(&Addr::unchecked("bob"), Some(&Addr::unchecked("proxy"))),
             )
-            .unwrap();
+                .joined_key();
+            storage::accrue_delegator_reward(deps.as_mut().storage, storage_key, Uint128::new(42))
+                .unwrap();
 
-            let res = try_reward_next_mixnode_delegators(
+            // the reward belongs to the vesting delegation and not the liquid one
+            let res = try_claim_delegator_reward(
                 deps.as_mut(),
-                mock_info(rewarding_validator_address.as_ref(), &[]),
+                mock_info("bob", &[]),
                 node_identity.clone(),
-                1,
             );
-
             assert_eq!(
-                Err(ContractError::MixnodeAlreadyRewarded {
-                    identity: node_identity
+                Err(ContractError::NoRewardsToClaim {
+                    identity: node_identity.clone(),
+                    address: Addr::unchecked("bob")
                 }),
                 res
             );
+
+            let res = try_claim_delegator_reward_on_behalf(
+                deps.as_mut(),
+                mock_info("proxy", &[]),
+                node_identity.clone(),
+                "bob".to_string(),
+            )
+            .unwrap();
+
+            let messages = res.messages.into_iter().map(|m| m.msg).collect::<Vec<_>>();
+            assert_eq!(2, messages.len());
+            assert_eq!(
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "proxy".to_string(),
+                    amount: coins(42, DENOM),
+                }),
+                messages[0]
+            );
+            match &messages[1] {
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr, msg, ..
+                }) => {
+                    assert_eq!("proxy", contract_addr);
+                    assert_eq!(
+                        VestingContractExecuteMsg::TrackReward {
+                            owner: "bob".to_string(),
+                            amount: coin(42, DENOM),
+                        },
+                        from_binary(msg).unwrap()
+                    );
+                }
+                _ => panic!("expected the reward to be tracked by the vesting contract"),
+            }
         }
 
         #[test]
-        fn rewards_all_delegators_on_the_next_page() {
-            // setup: bond > page limit delegators, reward operator + first batch
+        fn compounding_increases_the_delegation() {
             let mut deps = test_helpers::init_contract();
-            let mut env = mock_env();
-            let current_state = mixnet_params_storage::CONTRACT_STATE
-                .load(deps.as_mut().storage)
-                .unwrap();
-            let rewarding_validator_address = current_state.rewarding_validator_address;
-
-            #[allow(clippy::inconsistent_digit_grouping)]
-            let mix_bond = Uint128::new(10000_000_000);
-            let delegation_value = 2000_000000;
-
-            let total_delegators = 2 * MIXNODE_DELEGATORS_PAGE_LIMIT + 123;
-
-            let node_owner: Addr = Addr::unchecked("alice");
             let node_identity = test_helpers::add_mixnode(
-                node_owner.as_str(),
-                vec![Coin {
-                    denom: DENOM.to_string(),
-                    amount: mix_bond,
-                }],
+                "alice",
+                tests::fixtures::good_mixnode_pledge(),
                 deps.as_mut(),
             );
 
-            for i in 0..total_delegators {
-                try_delegate_to_mixnode(
-                    deps.as_mut(),
-                    env.clone(),
-                    mock_info(
-                        &*format!("delegator{:04}", i),
-                        &[coin(delegation_value, DENOM)],
-                    ),
-                    node_identity.clone(),
-                )
-                .unwrap();
-            }
-
-            env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
+            let res = try_compound_delegator_reward(
+                deps.as_mut(),
+                mock_info("bob", &[]),
+                node_identity.clone(),
+            );
+            assert_eq!(
+                Err(ContractError::NoMixnodeDelegationFound {
+                    identity: node_identity.clone(),
+                    address: Addr::unchecked("bob")
+                }),
+                res
+            );
 
-            let info = mock_info(rewarding_validator_address.as_ref(), &[]);
-            try_reward_mixnode(
+            try_delegate_to_mixnode(
                 deps.as_mut(),
-                env,
-                info,
+                mock_env(),
+                mock_info("bob", &coins(1000, DENOM)),
                 node_identity.clone(),
-                tests::fixtures::node_rewarding_params_fixture(100),
-                0,
+            )
+            .unwrap();
+            storage::accrue_delegator_reward(
+                deps.as_mut().storage,
+                (node_identity.clone(), "bob").joined_key(),
+                Uint128::new(100),
             )
             .unwrap();
 
-            // we have 3 pages in total, so we have to call this twice
-            try_reward_next_mixnode_delegators(
+            let res = try_compound_delegator_reward(
                 deps.as_mut(),
-                mock_info(rewarding_validator_address.as_ref(), &[]),
+                mock_info("bob", &[]),
                 node_identity.clone(),
-                0,
             )
             .unwrap();
-            try_reward_next_mixnode_delegators(
+            assert!(res.messages.is_empty());
+
+            let delegation =
+                test_helpers::read_delegation(deps.as_ref().storage, &node_identity, "bob")
+                    .unwrap();
+            assert_eq!(coin(1100, DENOM), delegation.amount);
+            assert_eq!(
+                Uint128::new(1100),
+                mixnodes_storage::TOTAL_DELEGATION
+                    .load(deps.as_ref().storage, &node_identity)
+                    .unwrap()
+            );
+            assert!(test_helpers::read_pending_delegator_reward(
+                deps.as_ref().storage,
+                &node_identity,
+                "bob"
+            )
+            .is_zero());
+        }
+
+        #[test]
+        fn unbonding_returns_unclaimed_operator_reward() {
+            let mut deps = test_helpers::init_contract();
+            let node_identity = test_helpers::add_mixnode(
+                "alice",
+                tests::fixtures::good_mixnode_pledge(),
                 deps.as_mut(),
-                mock_info(rewarding_validator_address.as_ref(), &[]),
-                node_identity.clone(),
-                0,
+            );
+            storage::accrue_operator_reward(
+                deps.as_mut().storage,
+                &node_identity,
+                Uint128::new(1000),
             )
             .unwrap();
 
-            let expected = delegations_storage::delegations()
-                .load(
-                    deps.as_ref().storage,
-                    (node_identity.clone(), "delegator0001").joined_key(),
-                )
-                .unwrap()
-                .amount;
+            let res =
+                try_remove_mixnode(deps.as_mut(), mock_env(), mock_info("alice", &[])).unwrap();
 
-            for i in 0..total_delegators {
-                // everyone was rewarded (and the same amount, because they all delegated the same amount)
-                let delegation = test_helpers::read_delegation(
-                    &deps.storage,
-                    node_identity.clone(),
-                    format!("delegator{:04}", i),
-                )
+            let expected = coin(
+                tests::fixtures::good_mixnode_pledge()[0].amount.u128() + 1000,
+                DENOM,
+            );
+            let unbonding_event = res
+                .events
+                .iter()
+                .find(|event| event.ty == MIXNODE_UNBONDING_EVENT_TYPE)
                 .unwrap();
-
-                assert!(delegation.amount.amount > Uint128::new(delegation_value));
-                assert_eq!(expected, delegation.amount)
-            }
+            assert_eq!(
+                expected.to_string(),
+                must_find_attribute(unbonding_event, AMOUNT_KEY)
+            );
+            assert!(storage::PENDING_OPERATOR_REWARDS
+                .may_load(deps.as_ref().storage, &node_identity)
+                .unwrap()
+                .is_none());
         }
 
         #[test]
-        fn ignores_delegators_that_updated_their_pledge_in_the_meantime() {
-            // setup: bond > page limit delegators, reward operator + first batch
+        fn gateway_operator_reward_is_sent_to_the_owner_and_cleared() {
             let mut deps = test_helpers::init_contract();
-            let mut env = mock_env();
-            let current_state = mixnet_params_storage::CONTRACT_STATE
-                .load(deps.as_mut().storage)
-                .unwrap();
-            let rewarding_validator_address = current_state.rewarding_validator_address;
+            let gateway_identity = test_helpers::add_gateway(
+                "alice",
+                tests::fixtures::good_gateway_pledge(),
+                deps.as_mut(),
+            );
 
-            #[allow(clippy::inconsistent_digit_grouping)]
-            let mix_bond = Uint128::new(10000_000_000);
-            let delegation_value = 2000_000000;
+            let res = try_claim_gateway_operator_reward(deps.as_mut(), mock_info("alice", &[]));
+            assert_eq!(
+                Err(ContractError::NoRewardsToClaim {
+                    identity: gateway_identity.clone(),
+                    address: Addr::unchecked("alice")
+                }),
+                res
+            );
 
-            let total_delegators = MIXNODE_DELEGATORS_PAGE_LIMIT + 123;
+            storage::accrue_operator_reward(
+                deps.as_mut().storage,
+                &gateway_identity,
+                Uint128::new(1234),
+            )
+            .unwrap();
 
-            let node_owner: Addr = Addr::unchecked("alice");
-            let node_identity = test_helpers::add_mixnode(
-                node_owner.as_str(),
-                vec![Coin {
-                    denom: DENOM.to_string(),
-                    amount: mix_bond,
-                }],
+            let res = try_claim_gateway_operator_reward_on_behalf(
                 deps.as_mut(),
+                mock_info("proxy", &[]),
+                "alice".to_string(),
+            );
+            assert_eq!(
+                Err(ContractError::ProxyMismatch {
+                    existing: "None".to_string(),
+                    incoming: "proxy".to_string()
+                }),
+                res
             );
 
-            for i in 0..total_delegators {
-                try_delegate_to_mixnode(
-                    deps.as_mut(),
-                    env.clone(),
-                    mock_info(
-                        &*format!("delegator{:04}", i),
-                        &[coin(delegation_value, DENOM)],
-                    ),
-                    node_identity.clone(),
-                )
-                .unwrap();
-            }
+            let res =
+                try_claim_gateway_operator_reward(deps.as_mut(), mock_info("alice", &[])).unwrap();
+            assert_eq!(
+                vec![CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "alice".to_string(),
+                    amount: coins(1234, DENOM),
+                })],
+                res.messages.into_iter().map(|m| m.msg).collect::<Vec<_>>()
+            );
+            assert!(storage::PENDING_OPERATOR_REWARDS
+                .may_load(deps.as_ref().storage, &gateway_identity)
+                .unwrap()
+                .is_none());
 
-            env.block.height += constants::MINIMUM_BLOCK_AGE_FOR_REWARDING;
+            // the pledge itself is untouched
+            assert_eq!(
+                tests::fixtures::good_gateway_pledge()[0].amount,
+                gateways_storage::gateways()
+                    .load(deps.as_ref().storage, &gateway_identity)
+                    .unwrap()
+                    .pledge_amount
+                    .amount
+            );
+        }
 
-            // update some delegations (on 'main' page and the secondary call)
-            try_delegate_to_mixnode(
+        #[test]
+        fn gateway_delegator_reward_can_be_claimed_or_compounded() {
+            let mut deps = test_helpers::init_contract();
+            let gateway_identity = test_helpers::add_gateway(
+                "alice",
+                tests::fixtures::good_gateway_pledge(),
                 deps.as_mut(),
-                env.clone(),
-                mock_info("delegator0123", &[coin(delegation_value, DENOM)]),
-                node_identity.clone(),
+            );
+
+            let res = try_compound_gateway_delegator_reward(
+                deps.as_mut(),
+                mock_info("bob", &[]),
+                gateway_identity.clone(),
+            );
+            assert_eq!(
+                Err(ContractError::NoGatewayDelegationFound {
+                    identity: gateway_identity.clone(),
+                    address: Addr::unchecked("bob")
+                }),
+                res
+            );
+
+            try_delegate_to_gateway(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("bob", &coins(1000, DENOM)),
+                gateway_identity.clone(),
             )
             .unwrap();
+            let storage_key = (gateway_identity.clone(), "bob").joined_key();
 
-            try_delegate_to_mixnode(
+            storage::accrue_delegator_reward(
+                deps.as_mut().storage,
+                storage_key.clone(),
+                Uint128::new(100),
+            )
+            .unwrap();
+            let res = try_compound_gateway_delegator_reward(
                 deps.as_mut(),
-                env.clone(),
-                mock_info(
-                    &*format!("delegator{:04}", 123 + MIXNODE_DELEGATORS_PAGE_LIMIT),
-                    &[coin(delegation_value, DENOM)],
-                ),
-                node_identity.clone(),
+                mock_info("bob", &[]),
+                gateway_identity.clone(),
             )
             .unwrap();
+            assert!(res.messages.is_empty());
 
-            env.block.height += 123;
+            let delegation = delegations_storage::gateway_delegations()
+                .load(deps.as_ref().storage, storage_key.clone())
+                .unwrap();
+            assert_eq!(coin(1100, DENOM), delegation.amount);
+            assert_eq!(
+                Uint128::new(1100),
+                gateways_storage::TOTAL_GATEWAY_DELEGATION
+                    .load(deps.as_ref().storage, &gateway_identity)
+                    .unwrap()
+            );
 
-            let info = mock_info(rewarding_validator_address.as_ref(), &[]);
-            try_reward_mixnode(
+            storage::accrue_delegator_reward(deps.as_mut().storage, storage_key, Uint128::new(42))
+                .unwrap();
+            let res = try_claim_gateway_delegator_reward(
                 deps.as_mut(),
-                env,
-                info,
-                node_identity.clone(),
-                tests::fixtures::node_rewarding_params_fixture(100),
-                0,
+                mock_info("bob", &[]),
+                gateway_identity.clone(),
             )
             .unwrap();
+            assert_eq!(
+                vec![CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "bob".to_string(),
+                    amount: coins(42, DENOM),
+                })],
+                res.messages.into_iter().map(|m| m.msg).collect::<Vec<_>>()
+            );
+            assert!(test_helpers::read_pending_delegator_reward(
+                deps.as_ref().storage,
+                &gateway_identity,
+                "bob"
+            )
+            .is_zero());
+        }
 
-            // we have 3 pages in total, so we have to call this twice
-            try_reward_next_mixnode_delegators(
+        #[test]
+        fn gateway_unbonding_returns_unclaimed_operator_reward() {
+            let mut deps = test_helpers::init_contract();
+            let gateway_identity = test_helpers::add_gateway(
+                "alice",
+                tests::fixtures::good_gateway_pledge(),
                 deps.as_mut(),
-                mock_info(rewarding_validator_address.as_ref(), &[]),
-                node_identity.clone(),
-                0,
+            );
+            storage::accrue_operator_reward(
+                deps.as_mut().storage,
+                &gateway_identity,
+                Uint128::new(1000),
             )
             .unwrap();
 
-            let expected = test_helpers::read_delegation(
-                &deps.storage,
-                node_identity.clone(),
-                "delegator0001",
-            )
-            .unwrap()
-            .amount;
-
-            for i in 0..total_delegators {
-                // everyone was rewarded (and the same amount, because they all delegated the same amount)
-                let delegation = test_helpers::read_delegation(
-                    &deps.storage,
-                    node_identity.clone(),
-                    format!("delegator{:04}", i),
-                )
-                .unwrap();
+            let res = try_remove_gateway(deps.as_mut(), mock_info("alice", &[])).unwrap();
 
-                if i == 123 || i == 123 + MIXNODE_DELEGATORS_PAGE_LIMIT {
-                    assert_eq!(delegation.amount.amount, Uint128::new(2 * delegation_value))
-                } else {
-                    assert!(delegation.amount.amount > Uint128::new(delegation_value));
-                    assert_eq!(expected, delegation.amount)
-                }
-            }
+            assert_eq!(
+                vec![CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "alice".to_string(),
+                    amount: coins(
+                        tests::fixtures::good_gateway_pledge()[0].amount.u128() + 1000,
+                        DENOM
+                    ),
+                })],
+                res.messages.into_iter().map(|m| m.msg).collect::<Vec<_>>()
+            );
+            assert!(storage::PENDING_OPERATOR_REWARDS
+                .may_load(deps.as_ref().storage, &gateway_identity)
+                .unwrap()
+                .is_none());
         }
     }
 }
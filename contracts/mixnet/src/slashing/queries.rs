@@ -0,0 +1,90 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::storage;
+use cosmwasm_std::{Deps, Order, StdResult};
+use cw_storage_plus::Bound;
+use mixnet_contract_common::{
+    IdentityKey, MixnodePerformanceStreak, PagedPendingDelegationSlashingsResponse,
+    PagedSlashingHistoryResponse,
+};
+
+pub(crate) fn query_mixnode_performance_streak(
+    deps: Deps<'_>,
+    mix_identity: IdentityKey,
+) -> StdResult<Option<MixnodePerformanceStreak>> {
+    storage::PERFORMANCE_STREAKS.may_load(deps.storage, &mix_identity)
+}
+
+pub(crate) fn query_mixnode_slashing_history_paged(
+    deps: Deps<'_>,
+    mix_identity: IdentityKey,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<PagedSlashingHistoryResponse> {
+    let limit = limit
+        .unwrap_or(storage::SLASHING_PAGE_DEFAULT_LIMIT)
+        .min(storage::SLASHING_PAGE_MAX_LIMIT) as usize;
+
+    let start = start_after.map(|id| Bound::Exclusive(id.to_be_bytes().to_vec()));
+
+    let records = storage::slashing_records()
+        .idx
+        .mixnode
+        .prefix(mix_identity)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|record| record.map(|r| r.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let start_next_after = records.last().map(|record| record.id);
+
+    Ok(PagedSlashingHistoryResponse::new(records, start_next_after))
+}
+
+pub(crate) fn query_all_slashing_history_paged(
+    deps: Deps<'_>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<PagedSlashingHistoryResponse> {
+    let limit = limit
+        .unwrap_or(storage::SLASHING_PAGE_DEFAULT_LIMIT)
+        .min(storage::SLASHING_PAGE_MAX_LIMIT) as usize;
+
+    let start = start_after.map(|id| Bound::Exclusive(id.to_be_bytes().to_vec()));
+
+    let records = storage::slashing_records()
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|record| record.map(|r| r.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let start_next_after = records.last().map(|record| record.id);
+
+    Ok(PagedSlashingHistoryResponse::new(records, start_next_after))
+}
+
+pub(crate) fn query_pending_delegation_slashings_paged(
+    deps: Deps<'_>,
+    start_after: Option<IdentityKey>,
+    limit: Option<u32>,
+) -> StdResult<PagedPendingDelegationSlashingsResponse> {
+    let limit = limit
+        .unwrap_or(storage::SLASHING_PAGE_DEFAULT_LIMIT)
+        .min(storage::SLASHING_PAGE_MAX_LIMIT) as usize;
+
+    let start = start_after.map(Bound::exclusive);
+
+    let mix_identities = storage::PENDING_DELEGATION_SLASHINGS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|record| record.map(|r| r.1.mix_identity))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let start_next_after = mix_identities.last().cloned();
+
+    Ok(PagedPendingDelegationSlashingsResponse::new(
+        mix_identities,
+        start_next_after,
+    ))
+}
@@ -0,0 +1,91 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use cosmwasm_std::{StdResult, Storage};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use mixnet_contract_common::{
+    IdentityKey, IdentityKeyRef, MixnodePerformanceStreak, SlashingDestination, SlashingRecord,
+};
+use serde::{Deserialize, Serialize};
+
+// storage prefixes
+const PERFORMANCE_STREAKS_NAMESPACE: &str = "ps";
+const SLASHING_RECORDS_PK_NAMESPACE: &str = "sl";
+const SLASHING_RECORDS_MIXNODE_IDX_NAMESPACE: &str = "slm";
+const SLASHING_RECORD_ID_COUNTER_KEY: &str = "slc";
+const PENDING_DELEGATION_SLASHINGS_NAMESPACE: &str = "psl";
+
+// paged retrieval limits for all queries
+pub(crate) const SLASHING_PAGE_MAX_LIMIT: u32 = 100;
+pub(crate) const SLASHING_PAGE_DEFAULT_LIMIT: u32 = 50;
+
+pub(crate) const PERFORMANCE_STREAKS: Map<'_, IdentityKeyRef<'_>, MixnodePerformanceStreak> =
+    Map::new(PERFORMANCE_STREAKS_NAMESPACE);
+
+// id of the most recently created slashing record
+pub(crate) const SLASHING_RECORD_ID_COUNTER: Item<'_, u64> =
+    Item::new(SLASHING_RECORD_ID_COUNTER_KEY);
+
+// the stake slashed alongside the node is processed in pages, this is where the next page starts
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) enum SlashingCursor {
+    // primary key of the last delegation that has already been slashed
+    Delegations(Option<Vec<u8>>),
+    // id of the last pending withdrawal that has already been slashed
+    Withdrawals(u64),
+}
+
+// the identity is duplicated inside the stored value so that we would never have to recover it
+// from the raw key
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct PendingDelegationSlashing {
+    pub(crate) mix_identity: IdentityKey,
+    pub(crate) record_id: u64,
+    pub(crate) slashed_percent: u8,
+    pub(crate) slash_delegations: bool,
+    pub(crate) destination: SlashingDestination,
+    // withdrawals created after the node got slashed are not affected by it
+    pub(crate) last_withdrawal_id: u64,
+    pub(crate) cursor: SlashingCursor,
+}
+
+// While an entry exists, the delegations towards the node can't be changed and its pending
+// withdrawals can't be claimed, so that none of the stake could escape the slashing.
+pub(crate) const PENDING_DELEGATION_SLASHINGS: Map<
+    '_,
+    IdentityKeyRef<'_>,
+    PendingDelegationSlashing,
+> = Map::new(PENDING_DELEGATION_SLASHINGS_NAMESPACE);
+
+pub(crate) struct SlashingRecordIndex<'a> {
+    pub(crate) mixnode: MultiIndex<'a, IdentityKey, SlashingRecord>,
+}
+
+impl<'a> IndexList<SlashingRecord> for SlashingRecordIndex<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<SlashingRecord>> + '_> {
+        let v: Vec<&dyn Index<SlashingRecord>> = vec![&self.mixnode];
+        Box::new(v.into_iter())
+    }
+}
+
+pub(crate) fn slashing_records<'a>() -> IndexedMap<'a, u64, SlashingRecord, SlashingRecordIndex<'a>>
+{
+    let indexes = SlashingRecordIndex {
+        mixnode: MultiIndex::new(
+            |r| r.mix_identity.clone(),
+            SLASHING_RECORDS_PK_NAMESPACE,
+            SLASHING_RECORDS_MIXNODE_IDX_NAMESPACE,
+        ),
+    };
+
+    IndexedMap::new(SLASHING_RECORDS_PK_NAMESPACE, indexes)
+}
+
+pub(crate) fn next_slashing_record_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let id = SLASHING_RECORD_ID_COUNTER
+        .may_load(storage)?
+        .unwrap_or_default()
+        + 1;
+    SLASHING_RECORD_ID_COUNTER.save(storage, &id)?;
+    Ok(id)
+}
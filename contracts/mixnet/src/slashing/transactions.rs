@@ -0,0 +1,954 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::storage::{self, PendingDelegationSlashing, SlashingCursor};
+use crate::delegations::storage as delegations_storage;
+use crate::error::ContractError;
+use crate::mixnet_contract_settings::storage as mixnet_params_storage;
+use crate::mixnodes::storage as mixnodes_storage;
+use crate::mixnodes::storage::StoredMixnodeBond;
+use crate::rewards::storage as rewards_storage;
+use crate::rewards::transactions::verify_rewarding_state;
+use crate::withdrawals::storage as withdrawals_storage;
+use config::defaults::DENOM;
+use cosmwasm_std::{
+    coin, wasm_execute, Addr, BankMsg, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+    Storage, Uint128, WasmMsg,
+};
+use cw_storage_plus::Bound;
+use mixnet_contract_common::events::{
+    new_mix_delegators_slashing_event, new_mixnode_pledge_change_cancelled_event,
+    new_mixnode_slashing_event, new_performance_report_event, NODE_SLASHED_VALUE,
+};
+use mixnet_contract_common::mixnode::NodeRewardParams;
+use mixnet_contract_common::{
+    IdentityKey, MixnodePerformanceStreak, PendingWithdrawalKind, PledgeChange,
+    SlashingDestination, SlashingParams, SlashingRecord, MIXNODE_DELEGATORS_PAGE_LIMIT,
+};
+use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
+use vesting_contract_common::one_ucoin;
+
+// the stake slashed alongside the node is processed in pages of the same size as when rewarding
+// the delegators, so that slashing could never run out of gas
+const SLASHING_PAGE_LIMIT: usize = MIXNODE_DELEGATORS_PAGE_LIMIT;
+
+fn slash_percent(amount: Uint128, percent: u8) -> Uint128 {
+    amount.multiply_ratio(percent as u128, 100u128)
+}
+
+#[derive(Debug, Default)]
+struct SlashedStake {
+    pledge: Uint128,
+    delegations: Uint128,
+    // notifications for the vesting contract about the stake it tracks getting slashed
+    track_msgs: Vec<WasmMsg>,
+}
+
+impl SlashedStake {
+    fn total(&self) -> Uint128 {
+        self.pledge + self.delegations
+    }
+}
+
+// Delegations, and the stake held as pending withdrawals, can't change until the slashing
+// of the node is finished, so that none of it could escape the slashing.
+pub(crate) fn ensure_delegations_not_being_slashed(
+    storage: &dyn Storage,
+    mix_identity: &str,
+) -> Result<(), ContractError> {
+    if storage::PENDING_DELEGATION_SLASHINGS.has(storage, mix_identity) {
+        Err(ContractError::DelegationsBeingSlashed {
+            identity: mix_identity.to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+// slashes the next page of the delegations towards the node, followed by the pledges and delegations
+// that are held by the contract as pending withdrawals. Returns whether everything has been slashed.
+fn slash_next_stake_page(
+    storage: &mut dyn Storage,
+    slashing: &mut PendingDelegationSlashing,
+    slashed: &mut SlashedStake,
+) -> Result<bool, ContractError> {
+    let mut remaining = SLASHING_PAGE_LIMIT;
+
+    if let SlashingCursor::Delegations(start_after) = &slashing.cursor {
+        let start = start_after.clone().map(Bound::Exclusive);
+        let delegations = delegations_storage::delegations()
+            .idx
+            .mixnode
+            .prefix(slashing.mix_identity.clone())
+            .range(storage, start, None, Order::Ascending)
+            .take(remaining + 1)
+            .collect::<StdResult<Vec<_>>>()?;
+        let more_delegations = delegations.len() > remaining;
+
+        let mut delegations_slashed = Uint128::zero();
+        for (pk, mut delegation) in delegations.into_iter().take(remaining) {
+            remaining -= 1;
+            slashing.cursor = SlashingCursor::Delegations(Some(pk.clone()));
+
+            let amount = slash_percent(delegation.amount.amount, slashing.slashed_percent);
+            if amount.is_zero() {
+                continue;
+            }
+            delegation.amount.amount = delegation.amount.amount - amount;
+            delegations_slashed += amount;
+            delegations_storage::delegations().save(storage, pk, &delegation)?;
+
+            if let Some(proxy) = &delegation.proxy {
+                let msg = VestingContractExecuteMsg::TrackSlashedDelegation {
+                    owner: delegation.owner.as_str().to_string(),
+                    mix_identity: slashing.mix_identity.clone(),
+                    amount: coin(amount.u128(), &delegation.amount.denom),
+                };
+                slashed
+                    .track_msgs
+                    .push(wasm_execute(proxy, &msg, vec![one_ucoin()])?);
+            }
+        }
+
+        // the total delegation is always equal to the sum of all delegations, including the ones
+        // we have just slashed. Note that it no longer exists if the node has unbonded in the meantime
+        if let Some(total_delegation) =
+            mixnodes_storage::TOTAL_DELEGATION.may_load(storage, &slashing.mix_identity)?
+        {
+            mixnodes_storage::TOTAL_DELEGATION.save(
+                storage,
+                &slashing.mix_identity,
+                &total_delegation.saturating_sub(delegations_slashed),
+            )?;
+        }
+        slashed.delegations += delegations_slashed;
+
+        if more_delegations {
+            return Ok(false);
+        }
+        slashing.cursor = SlashingCursor::Withdrawals(0);
+    }
+
+    if let SlashingCursor::Withdrawals(start_after) = slashing.cursor {
+        let start = Some(Bound::Exclusive(start_after.to_be_bytes().to_vec()));
+        let withdrawals = withdrawals_storage::pending_withdrawals()
+            .idx
+            .node
+            .prefix(slashing.mix_identity.clone())
+            .range(storage, start, None, Order::Ascending)
+            .map(|record| record.map(|r| r.1))
+            .take_while(|withdrawal| {
+                withdrawal
+                    .as_ref()
+                    .map_or(true, |w| w.id <= slashing.last_withdrawal_id)
+            })
+            .take(remaining + 1)
+            .collect::<StdResult<Vec<_>>>()?;
+        let more_withdrawals = withdrawals.len() > remaining;
+
+        // there's no need to notify the vesting contract here, as it's going to be told about
+        // the reduced amount once the withdrawal is claimed
+        for mut withdrawal in withdrawals.into_iter().take(remaining) {
+            slashing.cursor = SlashingCursor::Withdrawals(withdrawal.id);
+
+            let amount = slash_percent(withdrawal.amount.amount, slashing.slashed_percent);
+            match withdrawal.kind {
+                PendingWithdrawalKind::MixnodeUnbonding { .. } => slashed.pledge += amount,
+                PendingWithdrawalKind::Undelegation { .. } if slashing.slash_delegations => {
+                    slashed.delegations += amount
+                }
+                _ => continue,
+            }
+            if amount.is_zero() {
+                continue;
+            }
+            withdrawal.amount.amount = withdrawal.amount.amount - amount;
+            withdrawals_storage::pending_withdrawals().save(storage, withdrawal.id, &withdrawal)?;
+        }
+
+        if more_withdrawals {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+fn add_slashed_tokens(
+    storage: &mut dyn Storage,
+    response: Response,
+    destination: SlashingDestination,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Ok(response);
+    }
+    match destination {
+        SlashingDestination::Burn => Ok(response.add_message(BankMsg::Burn {
+            amount: vec![coin(amount.u128(), DENOM)],
+        })),
+        SlashingDestination::RewardPool => {
+            rewards_storage::incr_reward_pool(amount, storage)?;
+            Ok(response)
+        }
+    }
+}
+
+// slashes the node, which might have unbonded since the rewarding validator has obtained its list
+// of nodes, and the first page of the remaining stake. The rest of it is slashed via `SlashNextMixDelegators`
+#[allow(clippy::too_many_arguments)]
+fn slash_mixnode(
+    storage: &mut dyn Storage,
+    env: &Env,
+    mix_identity: IdentityKey,
+    owner: Addr,
+    mixnode_bond: Option<StoredMixnodeBond>,
+    interval_id: u32,
+    params: SlashingParams,
+    mut response: Response,
+) -> Result<Response, ContractError> {
+    let mut slashed = SlashedStake::default();
+
+    if let Some(mut mixnode_bond) = mixnode_bond {
+        let pledge_slashed =
+            slash_percent(mixnode_bond.pledge_amount.amount, params.slashed_percent);
+        mixnode_bond.pledge_amount.amount = mixnode_bond.pledge_amount.amount - pledge_slashed;
+        slashed.pledge += pledge_slashed;
+        mixnodes_storage::mixnodes().save(storage, &mix_identity, &mixnode_bond)?;
+
+        if let Some(proxy) = &mixnode_bond.proxy {
+            if !pledge_slashed.is_zero() {
+                let msg = VestingContractExecuteMsg::TrackSlashedMixnodePledge {
+                    owner: mixnode_bond.owner.as_str().to_string(),
+                    amount: coin(pledge_slashed.u128(), &mixnode_bond.pledge_amount.denom),
+                };
+                slashed
+                    .track_msgs
+                    .push(wasm_execute(proxy, &msg, vec![one_ucoin()])?);
+            }
+        }
+    }
+
+    // a pending decrease was validated against the pledge from before the slashing,
+    // so it might no longer be possible to fulfill it
+    if let Some(pending) =
+        mixnodes_storage::PENDING_PLEDGE_CHANGES.may_load(storage, &mix_identity)?
+    {
+        if let PledgeChange::Decrease(_) = pending.change {
+            mixnodes_storage::PENDING_PLEDGE_CHANGES.remove(storage, &mix_identity);
+            response = response.add_event(new_mixnode_pledge_change_cancelled_event(
+                &mix_identity,
+                &pending.change,
+                NODE_SLASHED_VALUE,
+            ));
+        }
+    }
+
+    let mut slashing = PendingDelegationSlashing {
+        mix_identity: mix_identity.clone(),
+        record_id: storage::next_slashing_record_id(storage)?,
+        slashed_percent: params.slashed_percent,
+        slash_delegations: params.slash_delegations,
+        destination: params.destination,
+        last_withdrawal_id: withdrawals_storage::PENDING_WITHDRAWAL_ID_COUNTER
+            .may_load(storage)?
+            .unwrap_or_default(),
+        cursor: if params.slash_delegations {
+            SlashingCursor::Delegations(None)
+        } else {
+            SlashingCursor::Withdrawals(0)
+        },
+    };
+    let finished = slash_next_stake_page(storage, &mut slashing, &mut slashed)?;
+    if !finished {
+        storage::PENDING_DELEGATION_SLASHINGS.save(storage, &mix_identity, &slashing)?;
+    }
+
+    let record = SlashingRecord {
+        id: slashing.record_id,
+        mix_identity,
+        owner,
+        interval_id,
+        block_height: env.block.height,
+        pledge_slashed: coin(slashed.pledge.u128(), DENOM),
+        delegations_slashed: coin(slashed.delegations.u128(), DENOM),
+        destination: params.destination,
+    };
+    storage::slashing_records().save(storage, record.id, &record)?;
+
+    let response = add_slashed_tokens(storage, response, params.destination, slashed.total())?;
+    Ok(response
+        .add_messages(slashed.track_msgs)
+        .add_event(new_mixnode_slashing_event(&record, !finished)))
+}
+
+pub(crate) fn try_slash_next_mix_delegators(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_identity: IdentityKey,
+    interval_id: u32,
+) -> Result<Response, ContractError> {
+    verify_rewarding_state(deps.storage, info, interval_id)?;
+
+    let mut slashing = storage::PENDING_DELEGATION_SLASHINGS
+        .may_load(deps.storage, &mix_identity)?
+        .ok_or_else(|| ContractError::NoPendingDelegationSlashing {
+            identity: mix_identity.clone(),
+        })?;
+
+    let mut slashed = SlashedStake::default();
+    let finished = slash_next_stake_page(deps.storage, &mut slashing, &mut slashed)?;
+    if finished {
+        storage::PENDING_DELEGATION_SLASHINGS.remove(deps.storage, &mix_identity);
+    } else {
+        storage::PENDING_DELEGATION_SLASHINGS.save(deps.storage, &mix_identity, &slashing)?;
+    }
+
+    let mut record = storage::slashing_records().load(deps.storage, slashing.record_id)?;
+    record.pledge_slashed.amount += slashed.pledge;
+    record.delegations_slashed.amount += slashed.delegations;
+    storage::slashing_records().save(deps.storage, record.id, &record)?;
+
+    let response = add_slashed_tokens(
+        deps.storage,
+        Response::new(),
+        slashing.destination,
+        slashed.total(),
+    )?;
+    Ok(response
+        .add_messages(slashed.track_msgs)
+        .add_event(new_mix_delegators_slashing_event(
+            record.id,
+            &mix_identity,
+            &coin(slashed.pledge.u128(), DENOM),
+            &coin(slashed.delegations.u128(), DENOM),
+            !finished,
+        )))
+}
+
+pub(crate) fn try_report_mixnode_performance(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    mix_identity: IdentityKey,
+    params: NodeRewardParams,
+    interval_id: u32,
+) -> Result<Response, ContractError> {
+    verify_rewarding_state(deps.storage, info, interval_id)?;
+
+    let mut streak = match storage::PERFORMANCE_STREAKS.may_load(deps.storage, &mix_identity)? {
+        Some(streak) if streak.last_reported_interval == interval_id => {
+            return Err(ContractError::MixnodePerformanceAlreadyReported {
+                identity: mix_identity,
+                interval_id,
+            })
+        }
+        Some(streak) => streak,
+        None => MixnodePerformanceStreak::default(),
+    };
+
+    // the node might have unbonded since the rewarding validator has obtained its list of nodes,
+    // in which case its pledge is still held by the contract until the unbonding period is over
+    let mixnode_bond = mixnodes_storage::mixnodes().may_load(deps.storage, &mix_identity)?;
+    let owner = match &mixnode_bond {
+        Some(mixnode_bond) => mixnode_bond.owner.clone(),
+        None => match held_pledge_owner(deps.storage, &mix_identity)? {
+            Some(owner) => owner,
+            None => return Ok(Response::new()),
+        },
+    };
+
+    let slashing_params = mixnet_params_storage::CONTRACT_STATE
+        .load(deps.storage)?
+        .params
+        .slashing;
+
+    // only the performance in the active set counts, so being in the standby set neither
+    // breaks nor extends the streak
+    if params.in_active_set() {
+        if params.uptime() < slashing_params.uptime_threshold as u128 {
+            streak.poor_intervals += 1;
+        } else {
+            streak.poor_intervals = 0;
+        }
+    }
+    streak.last_reported_interval = interval_id;
+
+    let mut response = Response::new().add_event(new_performance_report_event(
+        interval_id,
+        &mix_identity,
+        params.uptime(),
+        params.in_active_set(),
+        streak.poor_intervals,
+    ));
+
+    // a node can't be slashed again before the stake slashed the previous time has been fully processed
+    if slashing_params.is_enabled()
+        && streak.poor_intervals >= slashing_params.poor_intervals
+        && !storage::PENDING_DELEGATION_SLASHINGS.has(deps.storage, &mix_identity)
+    {
+        response = slash_mixnode(
+            deps.storage,
+            &env,
+            mix_identity.clone(),
+            owner,
+            mixnode_bond,
+            interval_id,
+            slashing_params,
+            response,
+        )?;
+
+        // the node gets another chance after being slashed
+        streak.poor_intervals = 0;
+    }
+
+    storage::PERFORMANCE_STREAKS.save(deps.storage, &mix_identity, &streak)?;
+
+    Ok(response)
+}
+
+// owner of the most recently unbonded pledge of the node that is still held by the contract
+fn held_pledge_owner(storage: &dyn Storage, mix_identity: &str) -> StdResult<Option<Addr>> {
+    for withdrawal in withdrawals_storage::pending_withdrawals()
+        .idx
+        .node
+        .prefix(mix_identity.to_string())
+        .range(storage, None, None, Order::Descending)
+    {
+        let withdrawal = withdrawal?.1;
+        if let PendingWithdrawalKind::MixnodeUnbonding { .. } = withdrawal.kind {
+            return Ok(Some(withdrawal.owner));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::INITIAL_MIXNODE_PLEDGE;
+    use crate::delegations::transactions::{
+        try_delegate_to_mixnode, try_delegate_to_mixnode_on_behalf,
+        try_remove_delegation_from_mixnode,
+    };
+    use crate::interval::storage as interval_storage;
+    use crate::mixnodes::transactions::{try_decrease_mixnode_pledge, try_remove_mixnode};
+    use crate::slashing::queries;
+    use crate::support::tests;
+    use crate::support::tests::test_helpers;
+    use crate::withdrawals::transactions::try_claim_pending_withdrawals;
+    use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+    use cosmwasm_std::{coins, CosmosMsg, OwnedDeps, SubMsg};
+    use cw_storage_plus::PrimaryKey;
+
+    fn report_params(uptime: u8, in_active_set: bool) -> NodeRewardParams {
+        NodeRewardParams::new(0, 0, 0, 0, 0, uptime as u128, 0, in_active_set, 0)
+    }
+
+    fn set_slashing_params(deps: DepsMut<'_>, slashing: SlashingParams) {
+        mixnet_params_storage::CONTRACT_STATE
+            .update::<_, ContractError>(deps.storage, |mut state| {
+                state.params.slashing = slashing;
+                Ok(state)
+            })
+            .unwrap();
+    }
+
+    fn rewarding_validator(storage: &dyn Storage) -> MessageInfo {
+        let state = mixnet_params_storage::CONTRACT_STATE.load(storage).unwrap();
+        mock_info(state.rewarding_validator_address.as_str(), &[])
+    }
+
+    fn report(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        identity: &str,
+        uptime: u8,
+        in_active_set: bool,
+    ) -> Result<Response, ContractError> {
+        let interval_id = interval_storage::CURRENT_INTERVAL
+            .load(&deps.storage)
+            .unwrap()
+            .id();
+        let info = rewarding_validator(&deps.storage);
+        let res = try_report_mixnode_performance(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            identity.to_string(),
+            report_params(uptime, in_active_set),
+            interval_id,
+        );
+
+        // pretend the interval is over
+        interval_storage::CURRENT_INTERVAL
+            .update::<_, ContractError>(&mut deps.storage, |interval| Ok(interval.next_interval()))
+            .unwrap();
+        res
+    }
+
+    fn slashing_params(destination: SlashingDestination) -> SlashingParams {
+        SlashingParams {
+            uptime_threshold: 50,
+            poor_intervals: 3,
+            slashed_percent: 10,
+            slash_delegations: false,
+            destination,
+        }
+    }
+
+    #[test]
+    fn can_only_be_reported_by_rewarding_validator_once_per_interval() {
+        let mut deps = test_helpers::init_contract();
+        let identity =
+            test_helpers::add_mixnode("bob", tests::fixtures::good_mixnode_pledge(), deps.as_mut());
+        let interval_id = interval_storage::CURRENT_INTERVAL
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .id();
+
+        let res = try_report_mixnode_performance(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("not-the-validator", &[]),
+            identity.clone(),
+            report_params(10, true),
+            interval_id,
+        );
+        assert_eq!(Err(ContractError::Unauthorized), res);
+
+        let info = rewarding_validator(&deps.storage);
+        assert!(try_report_mixnode_performance(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            identity.clone(),
+            report_params(10, true),
+            interval_id,
+        )
+        .is_ok());
+
+        let res = try_report_mixnode_performance(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            identity.clone(),
+            report_params(10, true),
+            interval_id,
+        );
+        assert_eq!(
+            Err(ContractError::MixnodePerformanceAlreadyReported {
+                identity,
+                interval_id
+            }),
+            res
+        );
+    }
+
+    #[test]
+    fn nothing_is_slashed_when_disabled() {
+        let mut deps = test_helpers::init_contract();
+        let identity =
+            test_helpers::add_mixnode("bob", tests::fixtures::good_mixnode_pledge(), deps.as_mut());
+
+        for _ in 0..10 {
+            let res = report(&mut deps, &identity, 0, true).unwrap();
+            assert!(res.messages.is_empty());
+        }
+
+        let bond = mixnodes_storage::mixnodes()
+            .load(deps.as_ref().storage, &identity)
+            .unwrap();
+        assert_eq!(
+            tests::fixtures::good_mixnode_pledge()[0],
+            bond.pledge_amount
+        );
+    }
+
+    #[test]
+    fn pledge_is_slashed_after_consecutive_poor_intervals() {
+        let mut deps = test_helpers::init_contract();
+        set_slashing_params(deps.as_mut(), slashing_params(SlashingDestination::Burn));
+        let identity =
+            test_helpers::add_mixnode("bob", tests::fixtures::good_mixnode_pledge(), deps.as_mut());
+        let pledge = tests::fixtures::good_mixnode_pledge()[0].amount;
+        let expected_slash = pledge.multiply_ratio(10u128, 100u128);
+
+        // good performance resets the streak
+        report(&mut deps, &identity, 10, true).unwrap();
+        report(&mut deps, &identity, 10, true).unwrap();
+        report(&mut deps, &identity, 90, true).unwrap();
+        // and being outside the active set doesn't affect it
+        report(&mut deps, &identity, 10, true).unwrap();
+        report(&mut deps, &identity, 0, false).unwrap();
+        let res = report(&mut deps, &identity, 10, true).unwrap();
+        assert!(res.messages.is_empty());
+        assert_eq!(
+            2,
+            queries::query_mixnode_performance_streak(deps.as_ref(), identity.clone())
+                .unwrap()
+                .unwrap()
+                .poor_intervals
+        );
+
+        let res = report(&mut deps, &identity, 10, true).unwrap();
+        assert_eq!(
+            vec![SubMsg::new(BankMsg::Burn {
+                amount: coins(expected_slash.u128(), DENOM),
+            })],
+            res.messages
+        );
+
+        let bond = mixnodes_storage::mixnodes()
+            .load(deps.as_ref().storage, &identity)
+            .unwrap();
+        assert_eq!(pledge - expected_slash, bond.pledge_amount.amount);
+
+        let history = queries::query_mixnode_slashing_history_paged(
+            deps.as_ref(),
+            identity.clone(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(1, history.records.len());
+        assert_eq!(
+            coin(expected_slash.u128(), DENOM),
+            history.records[0].pledge_slashed
+        );
+        assert_eq!(Some(1), history.start_next_after);
+
+        // and the streak starts again
+        assert_eq!(
+            0,
+            queries::query_mixnode_performance_streak(deps.as_ref(), identity)
+                .unwrap()
+                .unwrap()
+                .poor_intervals
+        );
+    }
+
+    #[test]
+    fn delegations_can_be_slashed_into_reward_pool() {
+        let mut deps = test_helpers::init_contract();
+        set_slashing_params(
+            deps.as_mut(),
+            SlashingParams {
+                poor_intervals: 1,
+                slash_delegations: true,
+                ..slashing_params(SlashingDestination::RewardPool)
+            },
+        );
+        let identity =
+            test_helpers::add_mixnode("bob", tests::fixtures::good_mixnode_pledge(), deps.as_mut());
+        try_delegate_to_mixnode(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &coins(1000, DENOM)),
+            identity.clone(),
+        )
+        .unwrap();
+        let pool_before = rewards_storage::REWARD_POOL
+            .load(deps.as_ref().storage)
+            .unwrap();
+        let pledge_slash = tests::fixtures::good_mixnode_pledge()[0]
+            .amount
+            .multiply_ratio(10u128, 100u128);
+
+        let res = report(&mut deps, &identity, 10, true).unwrap();
+        assert!(res.messages.is_empty());
+
+        let delegation = delegations_storage::delegations()
+            .load(
+                deps.as_ref().storage,
+                (identity.clone(), Addr::unchecked("alice")).joined_key(),
+            )
+            .unwrap();
+        assert_eq!(Uint128::new(900), delegation.amount.amount);
+        assert_eq!(
+            Uint128::new(900),
+            mixnodes_storage::TOTAL_DELEGATION
+                .load(deps.as_ref().storage, &identity)
+                .unwrap()
+        );
+        assert_eq!(
+            pool_before + pledge_slash + Uint128::new(100),
+            rewards_storage::REWARD_POOL
+                .load(deps.as_ref().storage)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn slashing_cancels_pending_pledge_decrease() {
+        let mut deps = test_helpers::init_contract();
+        set_slashing_params(
+            deps.as_mut(),
+            SlashingParams {
+                poor_intervals: 1,
+                ..slashing_params(SlashingDestination::Burn)
+            },
+        );
+        // make sure the pledge can actually be decreased
+        let identity = test_helpers::add_mixnode(
+            "bob",
+            coins(INITIAL_MIXNODE_PLEDGE.u128() * 2, DENOM),
+            deps.as_mut(),
+        );
+        try_decrease_mixnode_pledge(deps.as_mut(), mock_info("bob", &[]), coin(1, DENOM)).unwrap();
+
+        let res = report(&mut deps, &identity, 10, true).unwrap();
+        assert!(!mixnodes_storage::PENDING_PLEDGE_CHANGES.has(deps.as_ref().storage, &identity));
+        assert!(res
+            .events
+            .contains(&new_mixnode_pledge_change_cancelled_event(
+                &identity,
+                &PledgeChange::Decrease(coin(1, DENOM)),
+                NODE_SLASHED_VALUE,
+            )));
+    }
+
+    #[test]
+    fn delegations_are_slashed_in_pages() {
+        let mut deps = test_helpers::init_contract();
+        let mut env = mock_env();
+        set_slashing_params(
+            deps.as_mut(),
+            SlashingParams {
+                poor_intervals: 1,
+                slash_delegations: true,
+                ..slashing_params(SlashingDestination::Burn)
+            },
+        );
+        let identity =
+            test_helpers::add_mixnode("bob", tests::fixtures::good_mixnode_pledge(), deps.as_mut());
+        let delegators = SLASHING_PAGE_LIMIT + 10;
+        for i in 0..delegators {
+            try_delegate_to_mixnode(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(&format!("delegator{}", i), &coins(1000, DENOM)),
+                identity.clone(),
+            )
+            .unwrap();
+        }
+        // the tokens of somebody who has already undelegated are still held by the contract
+        try_delegate_to_mixnode(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("undelegator", &coins(1000, DENOM)),
+            identity.clone(),
+        )
+        .unwrap();
+        try_remove_delegation_from_mixnode(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("undelegator", &[]),
+            identity.clone(),
+        )
+        .unwrap();
+        let pledge_slash = tests::fixtures::good_mixnode_pledge()[0]
+            .amount
+            .multiply_ratio(10u128, 100u128);
+
+        let res = report(&mut deps, &identity, 10, true).unwrap();
+        assert_eq!(
+            vec![SubMsg::new(BankMsg::Burn {
+                amount: coins(
+                    pledge_slash.u128() + 100 * SLASHING_PAGE_LIMIT as u128,
+                    DENOM
+                ),
+            })],
+            res.messages
+        );
+        assert_eq!(
+            vec![identity.clone()],
+            queries::query_pending_delegation_slashings_paged(deps.as_ref(), None, None)
+                .unwrap()
+                .mix_identities
+        );
+
+        // nothing can escape the slashing in the meantime
+        let res = try_delegate_to_mixnode(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("delegator0", &coins(1000, DENOM)),
+            identity.clone(),
+        );
+        assert_eq!(
+            Err(ContractError::DelegationsBeingSlashed {
+                identity: identity.clone()
+            }),
+            res
+        );
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(crate::contract::INITIAL_UNBONDING_PERIOD);
+        let res = try_claim_pending_withdrawals(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("undelegator", &[]),
+        );
+        assert_eq!(
+            Err(ContractError::NoClaimableWithdrawals {
+                owner: Addr::unchecked("undelegator")
+            }),
+            res
+        );
+
+        let interval_id = interval_storage::CURRENT_INTERVAL
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .id();
+        let info = rewarding_validator(&deps.storage);
+        let res = try_slash_next_mix_delegators(
+            deps.as_mut(),
+            info.clone(),
+            identity.clone(),
+            interval_id,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![SubMsg::new(BankMsg::Burn {
+                amount: coins(100 * 11, DENOM),
+            })],
+            res.messages
+        );
+        assert!(
+            queries::query_pending_delegation_slashings_paged(deps.as_ref(), None, None)
+                .unwrap()
+                .mix_identities
+                .is_empty()
+        );
+        assert_eq!(
+            Uint128::new(900 * delegators as u128),
+            mixnodes_storage::TOTAL_DELEGATION
+                .load(deps.as_ref().storage, &identity)
+                .unwrap()
+        );
+
+        let record = storage::slashing_records()
+            .load(deps.as_ref().storage, 1)
+            .unwrap();
+        assert_eq!(coin(pledge_slash.u128(), DENOM), record.pledge_slashed);
+        assert_eq!(
+            coin(100 * (delegators as u128 + 1), DENOM),
+            record.delegations_slashed
+        );
+
+        let res = try_claim_pending_withdrawals(deps.as_mut(), env, mock_info("undelegator", &[]))
+            .unwrap();
+        assert_eq!(
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: "undelegator".to_string(),
+                amount: coins(900, DENOM),
+            })],
+            res.messages
+        );
+
+        let res = try_slash_next_mix_delegators(deps.as_mut(), info, identity.clone(), interval_id);
+        assert_eq!(
+            Err(ContractError::NoPendingDelegationSlashing { identity }),
+            res
+        );
+    }
+
+    #[test]
+    fn held_pledge_is_slashed_after_unbonding() {
+        let mut deps = test_helpers::init_contract();
+        set_slashing_params(
+            deps.as_mut(),
+            SlashingParams {
+                poor_intervals: 1,
+                ..slashing_params(SlashingDestination::Burn)
+            },
+        );
+        let identity =
+            test_helpers::add_mixnode("bob", tests::fixtures::good_mixnode_pledge(), deps.as_mut());
+        try_remove_mixnode(deps.as_mut(), mock_env(), mock_info("bob", &[])).unwrap();
+        let pledge = tests::fixtures::good_mixnode_pledge()[0].amount;
+        let expected_slash = pledge.multiply_ratio(10u128, 100u128);
+
+        let res = report(&mut deps, &identity, 10, true).unwrap();
+        assert_eq!(
+            vec![SubMsg::new(BankMsg::Burn {
+                amount: coins(expected_slash.u128(), DENOM),
+            })],
+            res.messages
+        );
+
+        let withdrawal = withdrawals_storage::pending_withdrawals()
+            .load(deps.as_ref().storage, 1)
+            .unwrap();
+        assert_eq!(pledge - expected_slash, withdrawal.amount.amount);
+
+        let record = storage::slashing_records()
+            .load(deps.as_ref().storage, 1)
+            .unwrap();
+        assert_eq!(Addr::unchecked("bob"), record.owner);
+        assert_eq!(coin(expected_slash.u128(), DENOM), record.pledge_slashed);
+    }
+
+    #[test]
+    fn vesting_contract_is_notified_about_slashed_stake() {
+        let mut deps = test_helpers::init_contract();
+        set_slashing_params(
+            deps.as_mut(),
+            SlashingParams {
+                poor_intervals: 1,
+                slash_delegations: true,
+                ..slashing_params(SlashingDestination::RewardPool)
+            },
+        );
+        let identity =
+            test_helpers::add_mixnode("bob", tests::fixtures::good_mixnode_pledge(), deps.as_mut());
+        // pretend the node was bonded via the vesting contract
+        mixnodes_storage::mixnodes()
+            .update::<_, ContractError>(deps.as_mut().storage, &identity, |bond| {
+                let mut bond = bond.unwrap();
+                bond.proxy = Some(Addr::unchecked("vesting"));
+                Ok(bond)
+            })
+            .unwrap();
+        try_delegate_to_mixnode_on_behalf(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("vesting", &coins(1000, DENOM)),
+            identity.clone(),
+            "alice".to_string(),
+        )
+        .unwrap();
+        let pledge_slash = tests::fixtures::good_mixnode_pledge()[0]
+            .amount
+            .multiply_ratio(10u128, 100u128);
+
+        let res = report(&mut deps, &identity, 10, true).unwrap();
+        let expected: Vec<CosmosMsg> = vec![
+            wasm_execute(
+                "vesting",
+                &VestingContractExecuteMsg::TrackSlashedMixnodePledge {
+                    owner: "bob".to_string(),
+                    amount: coin(pledge_slash.u128(), DENOM),
+                },
+                vec![one_ucoin()],
+            )
+            .unwrap()
+            .into(),
+            wasm_execute(
+                "vesting",
+                &VestingContractExecuteMsg::TrackSlashedDelegation {
+                    owner: "alice".to_string(),
+                    mix_identity: identity,
+                    amount: coin(100, DENOM),
+                },
+                vec![one_ucoin()],
+            )
+            .unwrap()
+            .into(),
+        ];
+        assert_eq!(
+            expected,
+            res.messages.into_iter().map(|m| m.msg).collect::<Vec<_>>()
+        );
+    }
+}
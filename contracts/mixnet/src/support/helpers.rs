@@ -53,8 +53,16 @@ pub(crate) fn validate_node_identity_signature(
     signature: String,
     identity: IdentityKeyRef<'_>,
 ) -> Result<(), ContractError> {
-    let owner_bytes = owner.as_bytes();
+    validate_ed25519_signature(deps, owner.as_bytes(), signature, identity)
+}
 
+// checks whether the provided message has been signed with the specified node identity key
+pub(crate) fn validate_ed25519_signature(
+    deps: Deps<'_>,
+    message: &[u8],
+    signature: String,
+    identity: IdentityKeyRef<'_>,
+) -> Result<(), ContractError> {
     let mut identity_bytes = [0u8; 32];
     let mut signature_bytes = [0u8; 64];
 
@@ -79,7 +87,7 @@ pub(crate) fn validate_node_identity_signature(
 
     let res = deps
         .api
-        .ed25519_verify(owner_bytes, &signature_bytes, &identity_bytes)
+        .ed25519_verify(message, &signature_bytes, &identity_bytes)
         .map_err(cosmwasm_std::StdError::verification_err)?;
     if !res {
         Err(ContractError::InvalidEd25519Signature)
@@ -16,6 +16,7 @@ pub mod test_helpers {
     use crate::interval::storage as interval_storage;
     use crate::mixnodes::storage as mixnodes_storage;
     use crate::mixnodes::transactions::try_add_mixnode;
+    use crate::rewards::storage as rewards_storage;
     use crate::support::tests;
     use config::defaults::DENOM;
     use cosmwasm_std::testing::mock_dependencies;
@@ -34,6 +35,18 @@ pub mod test_helpers {
     use rand::thread_rng;
 
     pub fn add_mixnode(sender: &str, stake: Vec<Coin>, deps: DepsMut<'_>) -> String {
+        add_mixnode_with_keypair(sender, stake, deps)
+            .public_key()
+            .to_base58_string()
+    }
+
+    // same as `add_mixnode`, but gives back the identity keys so that the caller
+    // could produce further signatures with them
+    pub fn add_mixnode_with_keypair(
+        sender: &str,
+        stake: Vec<Coin>,
+        deps: DepsMut<'_>,
+    ) -> crypto::asymmetric::identity::KeyPair {
         let keypair = crypto::asymmetric::identity::KeyPair::new(&mut thread_rng());
         let owner_signature = keypair
             .private_key()
@@ -48,13 +61,13 @@ pub mod test_helpers {
             mock_env(),
             info,
             MixNode {
-                identity_key: key.clone(),
+                identity_key: key,
                 ..tests::fixtures::mix_node_fixture()
             },
             owner_signature,
         )
         .unwrap();
-        key
+        keypair
     }
 
     pub fn add_gateway(sender: &str, stake: Vec<Coin>, deps: DepsMut<'_>) -> String {
@@ -128,6 +141,17 @@ pub mod test_helpers {
             .unwrap()
     }
 
+    pub(crate) fn read_pending_delegator_reward(
+        storage: &dyn Storage,
+        mix: impl Into<String>,
+        owner: impl Into<String>,
+    ) -> cosmwasm_std::Uint128 {
+        rewards_storage::PENDING_DELEGATOR_REWARDS
+            .may_load(storage, (mix.into(), owner.into()).joined_key())
+            .unwrap()
+            .unwrap_or_default()
+    }
+
     pub(crate) fn update_env_and_progress_interval(env: &mut Env, storage: &mut dyn Storage) {
         // make sure current block time is within the expected next interval
         env.block.time = Timestamp::from_seconds(
@@ -0,0 +1,63 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::storage;
+use cosmwasm_std::{Deps, Order, StdResult};
+use cw_storage_plus::Bound;
+use mixnet_contract_common::PagedPendingWithdrawalsResponse;
+
+pub(crate) fn query_pending_withdrawals_paged(
+    deps: Deps<'_>,
+    owner: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<PagedPendingWithdrawalsResponse> {
+    let validated_owner = deps.api.addr_validate(&owner)?;
+
+    let limit = limit
+        .unwrap_or(storage::WITHDRAWAL_PAGE_DEFAULT_LIMIT)
+        .min(storage::WITHDRAWAL_PAGE_MAX_LIMIT) as usize;
+
+    let start = start_after.map(|id| Bound::Exclusive(id.to_be_bytes().to_vec()));
+
+    let withdrawals = storage::pending_withdrawals()
+        .idx
+        .owner
+        .prefix(validated_owner)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|record| record.map(|r| r.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let start_next_after = withdrawals.last().map(|withdrawal| withdrawal.id);
+
+    Ok(PagedPendingWithdrawalsResponse::new(
+        withdrawals,
+        start_next_after,
+    ))
+}
+
+pub(crate) fn query_all_pending_withdrawals_paged(
+    deps: Deps<'_>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<PagedPendingWithdrawalsResponse> {
+    let limit = limit
+        .unwrap_or(storage::WITHDRAWAL_PAGE_DEFAULT_LIMIT)
+        .min(storage::WITHDRAWAL_PAGE_MAX_LIMIT) as usize;
+
+    let start = start_after.map(|id| Bound::Exclusive(id.to_be_bytes().to_vec()));
+
+    let withdrawals = storage::pending_withdrawals()
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|record| record.map(|r| r.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let start_next_after = withdrawals.last().map(|withdrawal| withdrawal.id);
+
+    Ok(PagedPendingWithdrawalsResponse::new(
+        withdrawals,
+        start_next_after,
+    ))
+}
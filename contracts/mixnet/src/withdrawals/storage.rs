@@ -0,0 +1,116 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use cosmwasm_std::{Order, StdResult, Storage};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, MultiIndex};
+use mixnet_contract_common::{Addr, IdentityKey, PendingWithdrawal, PendingWithdrawalKind};
+
+// storage prefixes
+const PENDING_WITHDRAWALS_PK_NAMESPACE: &str = "pw";
+const PENDING_WITHDRAWALS_OWNER_IDX_NAMESPACE: &str = "pwo";
+const PENDING_WITHDRAWALS_NODE_IDX_NAMESPACE: &str = "pwn";
+const PENDING_WITHDRAWAL_ID_COUNTER_KEY: &str = "pwc";
+
+// paged retrieval limits for all queries and transactions
+pub(crate) const WITHDRAWAL_PAGE_MAX_LIMIT: u32 = 100;
+pub(crate) const WITHDRAWAL_PAGE_DEFAULT_LIMIT: u32 = 50;
+
+// id of the most recently created pending withdrawal
+pub(crate) const PENDING_WITHDRAWAL_ID_COUNTER: Item<'_, u64> =
+    Item::new(PENDING_WITHDRAWAL_ID_COUNTER_KEY);
+
+pub(crate) struct PendingWithdrawalIndex<'a> {
+    pub(crate) owner: MultiIndex<'a, Addr, PendingWithdrawal>,
+
+    pub(crate) node: MultiIndex<'a, IdentityKey, PendingWithdrawal>,
+}
+
+impl<'a> IndexList<PendingWithdrawal> for PendingWithdrawalIndex<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<PendingWithdrawal>> + '_> {
+        let v: Vec<&dyn Index<PendingWithdrawal>> = vec![&self.owner, &self.node];
+        Box::new(v.into_iter())
+    }
+}
+
+// the id is duplicated inside the stored value so that we would never have to recover it
+// from the raw primary key bytes
+pub(crate) fn pending_withdrawals<'a>(
+) -> IndexedMap<'a, u64, PendingWithdrawal, PendingWithdrawalIndex<'a>> {
+    let indexes = PendingWithdrawalIndex {
+        owner: MultiIndex::new(
+            |w| w.owner.clone(),
+            PENDING_WITHDRAWALS_PK_NAMESPACE,
+            PENDING_WITHDRAWALS_OWNER_IDX_NAMESPACE,
+        ),
+        node: MultiIndex::new(
+            |w| w.kind.node_identity().clone(),
+            PENDING_WITHDRAWALS_PK_NAMESPACE,
+            PENDING_WITHDRAWALS_NODE_IDX_NAMESPACE,
+        ),
+    };
+
+    IndexedMap::new(PENDING_WITHDRAWALS_PK_NAMESPACE, indexes)
+}
+
+pub(crate) fn next_pending_withdrawal_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let id = PENDING_WITHDRAWAL_ID_COUNTER
+        .may_load(storage)?
+        .unwrap_or_default()
+        + 1;
+    PENDING_WITHDRAWAL_ID_COUNTER.save(storage, &id)?;
+    Ok(id)
+}
+
+// checks whether `owner` is still waiting for stake, that was delegated to `mix_identity` via `proxy`,
+// to be released
+pub(crate) fn has_pending_undelegation(
+    storage: &dyn Storage,
+    owner: Addr,
+    proxy: Option<&Addr>,
+    mix_identity: &str,
+) -> StdResult<bool> {
+    for withdrawal in
+        pending_withdrawals()
+            .idx
+            .owner
+            .prefix(owner)
+            .range(storage, None, None, Order::Ascending)
+    {
+        let withdrawal = withdrawal?.1;
+        if let PendingWithdrawalKind::Undelegation {
+            mix_identity: ref pending_identity,
+        } = withdrawal.kind
+        {
+            if withdrawal.proxy.as_ref() == proxy && pending_identity == mix_identity {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+pub(crate) fn has_pending_gateway_undelegation(
+    storage: &dyn Storage,
+    owner: Addr,
+    proxy: Option<&Addr>,
+    gateway_identity: &str,
+) -> StdResult<bool> {
+    for withdrawal in
+        pending_withdrawals()
+            .idx
+            .owner
+            .prefix(owner)
+            .range(storage, None, None, Order::Ascending)
+    {
+        let withdrawal = withdrawal?.1;
+        if let PendingWithdrawalKind::GatewayUndelegation {
+            gateway_identity: ref pending_identity,
+        } = withdrawal.kind
+        {
+            if withdrawal.proxy.as_ref() == proxy && pending_identity == gateway_identity {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
@@ -0,0 +1,395 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::storage;
+use crate::error::ContractError;
+use crate::mixnet_contract_settings::storage as mixnet_params_storage;
+use crate::slashing::storage as slashing_storage;
+use cosmwasm_std::{
+    wasm_execute, Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+    Storage,
+};
+use mixnet_contract_common::events::{new_pending_withdrawal_event, new_withdrawal_claim_event};
+use mixnet_contract_common::{PendingWithdrawal, PendingWithdrawalKind};
+use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
+use vesting_contract_common::one_ucoin;
+
+pub(crate) fn unbonding_period(storage: &dyn Storage) -> StdResult<u64> {
+    mixnet_params_storage::CONTRACT_STATE
+        .load(storage)
+        .map(|state| state.params.unbonding_period)
+}
+
+// holds the unbonded or undelegated tokens until the unbonding period is over.
+// the returned response does not contain any token transfers apart from returning the
+// `one_ucoin` the proxy has attached to its request (if applicable)
+pub(crate) fn hold_pending_withdrawal(
+    storage: &mut dyn Storage,
+    env: &Env,
+    owner: Addr,
+    proxy: Option<Addr>,
+    amount: Coin,
+    kind: PendingWithdrawalKind,
+    unbonding_period: u64,
+) -> Result<Response, ContractError> {
+    let id = storage::next_pending_withdrawal_id(storage)?;
+    let release_time = env.block.time.seconds() + unbonding_period;
+    let withdrawal = PendingWithdrawal::new(id, owner, proxy, amount, kind, release_time);
+
+    storage::pending_withdrawals().save(storage, id, &withdrawal)?;
+
+    let mut response = Response::new();
+    if let Some(proxy) = &withdrawal.proxy {
+        // tracking in the vesting contract is only going to happen once the tokens are claimed
+        response = response.add_message(BankMsg::Send {
+            to_address: proxy.to_string(),
+            amount: vec![one_ucoin()],
+        });
+    }
+
+    Ok(response.add_event(new_pending_withdrawal_event(&withdrawal)))
+}
+
+pub(crate) fn try_claim_pending_withdrawals(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    _try_claim_pending_withdrawals(deps, env, info.sender, None)
+}
+
+pub(crate) fn try_claim_pending_withdrawals_on_behalf(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+    _try_claim_pending_withdrawals(deps, env, owner, Some(info.sender))
+}
+
+// the stake slashed alongside a mixnode includes the withdrawals that are still held by the contract
+fn is_being_slashed(storage: &dyn Storage, withdrawal: &PendingWithdrawal) -> bool {
+    match &withdrawal.kind {
+        PendingWithdrawalKind::MixnodeUnbonding { mix_identity }
+        | PendingWithdrawalKind::Undelegation { mix_identity } => {
+            slashing_storage::PENDING_DELEGATION_SLASHINGS.has(storage, mix_identity)
+        }
+        PendingWithdrawalKind::GatewayUndelegation { .. } => false,
+    }
+}
+
+pub(crate) fn _try_claim_pending_withdrawals(
+    deps: DepsMut<'_>,
+    env: Env,
+    owner: Addr,
+    proxy: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let now = env.block.time.seconds();
+
+    // only release withdrawals that were created via the same proxy, so that the tokens
+    // would get back to wherever they came from. Also limit the number of withdrawals
+    // released in a single transaction so that we wouldn't run out of gas
+    let storage: &dyn Storage = deps.storage;
+    let claimable = storage::pending_withdrawals()
+        .idx
+        .owner
+        .prefix(owner.clone())
+        .range(storage, None, None, Order::Ascending)
+        .map(|record| record.map(|r| r.1))
+        .filter(|withdrawal| {
+            withdrawal.as_ref().map_or(true, |w| {
+                w.proxy == proxy && w.is_claimable(now) && !is_being_slashed(storage, w)
+            })
+        })
+        .take(storage::WITHDRAWAL_PAGE_MAX_LIMIT as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    if claimable.is_empty() {
+        return Err(ContractError::NoClaimableWithdrawals { owner });
+    }
+
+    let mut response = Response::new();
+
+    for withdrawal in claimable {
+        storage::pending_withdrawals().remove(deps.storage, withdrawal.id)?;
+
+        if let Some(proxy) = &proxy {
+            let msg = match &withdrawal.kind {
+                PendingWithdrawalKind::MixnodeUnbonding { .. } => {
+                    VestingContractExecuteMsg::TrackUnbondMixnode {
+                        owner: owner.as_str().to_string(),
+                        amount: withdrawal.amount.clone(),
+                    }
+                }
+                PendingWithdrawalKind::Undelegation { mix_identity } => {
+                    VestingContractExecuteMsg::TrackUndelegation {
+                        owner: owner.as_str().to_string(),
+                        mix_identity: mix_identity.clone(),
+                        amount: withdrawal.amount.clone(),
+                    }
+                }
+                PendingWithdrawalKind::GatewayUndelegation { gateway_identity } => {
+                    VestingContractExecuteMsg::TrackGatewayUndelegation {
+                        owner: owner.as_str().to_string(),
+                        gateway_identity: gateway_identity.clone(),
+                        amount: withdrawal.amount.clone(),
+                    }
+                }
+            };
+
+            let track_msg = wasm_execute(proxy, &msg, vec![])?;
+            response = response.add_message(track_msg);
+        }
+
+        // send the released funds back to the owner
+        let return_tokens = BankMsg::Send {
+            to_address: proxy.as_ref().unwrap_or(&owner).to_string(),
+            amount: vec![withdrawal.amount.clone()],
+        };
+
+        response = response
+            .add_message(return_tokens)
+            .add_event(new_withdrawal_claim_event(&withdrawal));
+    }
+
+    if let Some(proxy) = &proxy {
+        response = response.add_message(BankMsg::Send {
+            to_address: proxy.to_string(),
+            amount: vec![one_ucoin()],
+        });
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::{execute, INITIAL_UNBONDING_PERIOD};
+    use crate::support::tests;
+    use crate::support::tests::test_helpers;
+    use config::defaults::DENOM;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, coins, SubMsg, Timestamp};
+    use mixnet_contract_common::ExecuteMsg;
+
+    #[test]
+    fn withdrawals_can_only_be_claimed_after_unbonding_period() {
+        let mut deps = test_helpers::init_contract();
+        let mut env = mock_env();
+        let identity = test_helpers::add_mixnode(
+            "mix-owner",
+            tests::fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("delegator", &coins(1000, DENOM)),
+            ExecuteMsg::DelegateToMixnode {
+                mix_identity: identity.clone(),
+            },
+        )
+        .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("delegator", &[]),
+            ExecuteMsg::UndelegateFromMixnode {
+                mix_identity: identity.clone(),
+            },
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+
+        let withdrawal = storage::pending_withdrawals()
+            .load(deps.as_ref().storage, 1)
+            .unwrap();
+        assert_eq!(
+            env.block.time.seconds() + INITIAL_UNBONDING_PERIOD,
+            withdrawal.release_time
+        );
+
+        // nothing can be claimed before the period is over
+        env.block.time = Timestamp::from_seconds(withdrawal.release_time - 1);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("delegator", &[]),
+            ExecuteMsg::ClaimPendingWithdrawals {},
+        );
+        assert_eq!(
+            res,
+            Err(ContractError::NoClaimableWithdrawals {
+                owner: Addr::unchecked("delegator")
+            })
+        );
+
+        env.block.time = Timestamp::from_seconds(withdrawal.release_time);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("delegator", &[]),
+            ExecuteMsg::ClaimPendingWithdrawals {},
+        )
+        .unwrap();
+        assert_eq!(
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: "delegator".to_string(),
+                amount: coins(1000, DENOM),
+            })],
+            res.messages
+        );
+        assert!(storage::pending_withdrawals()
+            .may_load(deps.as_ref().storage, 1)
+            .unwrap()
+            .is_none());
+
+        // and it can't be claimed twice
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("delegator", &[]),
+            ExecuteMsg::ClaimPendingWithdrawals {},
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn claiming_on_behalf_tracks_released_funds_in_proxy() {
+        let mut deps = test_helpers::init_contract();
+        let mut env = mock_env();
+        let identity = test_helpers::add_mixnode(
+            "mix-owner",
+            tests::fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("proxy", &coins(1000, DENOM)),
+            ExecuteMsg::DelegateToMixnodeOnBehalf {
+                mix_identity: identity.clone(),
+                delegate: "delegator".to_string(),
+            },
+        )
+        .unwrap();
+
+        // the proxy gets back the coin it attached to the request
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("proxy", &[one_ucoin()]),
+            ExecuteMsg::UndelegateFromMixnodeOnBehalf {
+                mix_identity: identity.clone(),
+                delegate: "delegator".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: "proxy".to_string(),
+                amount: vec![one_ucoin()],
+            })],
+            res.messages
+        );
+
+        // the proxy can't delegate to the same node until the tokens are claimed
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("proxy", &coins(1000, DENOM)),
+            ExecuteMsg::DelegateToMixnodeOnBehalf {
+                mix_identity: identity.clone(),
+                delegate: "delegator".to_string(),
+            },
+        );
+        assert_eq!(
+            res,
+            Err(ContractError::UndelegationStillPending {
+                owner: Addr::unchecked("delegator"),
+                identity: identity.clone(),
+            })
+        );
+
+        env.block.time =
+            Timestamp::from_seconds(env.block.time.seconds() + INITIAL_UNBONDING_PERIOD);
+
+        // the owner can't claim the tokens directly as they have to go back through the proxy
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("delegator", &[]),
+            ExecuteMsg::ClaimPendingWithdrawals {},
+        );
+        assert!(res.is_err());
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("proxy", &[one_ucoin()]),
+            ExecuteMsg::ClaimPendingWithdrawalsOnBehalf {
+                owner: "delegator".to_string(),
+            },
+        )
+        .unwrap();
+
+        let track_msg = VestingContractExecuteMsg::TrackUndelegation {
+            owner: "delegator".to_string(),
+            mix_identity: identity,
+            amount: coin(1000, DENOM),
+        };
+        assert_eq!(
+            vec![
+                SubMsg::new(wasm_execute("proxy", &track_msg, vec![]).unwrap()),
+                SubMsg::new(BankMsg::Send {
+                    to_address: "proxy".to_string(),
+                    amount: coins(1000, DENOM),
+                }),
+                SubMsg::new(BankMsg::Send {
+                    to_address: "proxy".to_string(),
+                    amount: vec![one_ucoin()],
+                }),
+            ],
+            res.messages
+        );
+    }
+
+    #[test]
+    fn tokens_are_returned_immediately_without_unbonding_period() {
+        let mut deps = test_helpers::init_contract();
+        mixnet_params_storage::CONTRACT_STATE
+            .update::<_, ContractError>(deps.as_mut().storage, |mut state| {
+                state.params.unbonding_period = 0;
+                Ok(state)
+            })
+            .unwrap();
+
+        test_helpers::add_mixnode(
+            "mix-owner",
+            tests::fixtures::good_mixnode_pledge(),
+            deps.as_mut(),
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("mix-owner", &[]),
+            ExecuteMsg::UnbondMixnode {},
+        )
+        .unwrap();
+        assert_eq!(
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: "mix-owner".to_string(),
+                amount: tests::fixtures::good_mixnode_pledge(),
+            })],
+            res.messages
+        );
+        assert!(storage::PENDING_WITHDRAWAL_ID_COUNTER
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -3,21 +3,30 @@ use crate::storage::{account_from_address, ADMIN, MIXNET_CONTRACT_ADDRESS};
 use crate::traits::{
     DelegatingAccount, GatewayBondingAccount, MixnodeBondingAccount, VestingAccount,
 };
-use crate::vesting::{populate_vesting_periods, Account, PledgeData};
+use crate::vesting::{validate_vesting_spec, Account, PledgeData};
 use config::defaults::DENOM;
 use cosmwasm_std::{
-    coin, entry_point, to_binary, BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, QueryResponse,
-    Response, Timestamp, Uint128,
+    coin, entry_point, to_binary, wasm_execute, BankMsg, Coin, Deps, DepsMut, Env, MessageInfo,
+    QueryResponse, Response, Timestamp, Uint128,
+};
+use mixnet_contract_common::{
+    ExecuteMsg as MixnetExecuteMsg, Gateway, GatewayDetailsUpdate, IdentityKey, MixNode,
+    MixNodeDetailsUpdate,
 };
-use mixnet_contract_common::{Gateway, IdentityKey, MixNode};
 use vesting_contract_common::events::{
     new_ownership_transfer_event, new_periodic_vesting_account_event,
     new_staking_address_update_event, new_track_gateway_unbond_event,
-    new_track_mixnode_unbond_event, new_track_undelegation_event, new_vested_coins_withdraw_event,
+    new_track_gateway_undelegation_event, new_track_mixnode_pledge_decrease_event,
+    new_track_mixnode_unbond_event, new_track_reward_event, new_track_slashed_delegation_event,
+    new_track_slashed_mixnode_pledge_event, new_track_undelegation_event,
+    new_vested_coins_withdraw_event, new_vesting_claim_delegator_reward_event,
+    new_vesting_claim_operator_reward_event, new_vesting_claim_pending_withdrawals_event,
+    new_vesting_compound_delegator_reward_event,
 };
 use vesting_contract_common::messages::{
     ExecuteMsg, InitMsg, MigrateMsg, QueryMsg, VestingSpecification,
 };
+use vesting_contract_common::one_ucoin;
 
 #[entry_point]
 pub fn instantiate(
@@ -48,6 +57,12 @@ pub fn execute(
         ExecuteMsg::UpdateMixnodeConfig {
             profit_margin_percent,
         } => try_update_mixnode_config(profit_margin_percent, info, deps),
+        ExecuteMsg::UpdateMixnodeDetails { details } => {
+            try_update_mixnode_details(details, info, deps)
+        }
+        ExecuteMsg::UpdateGatewayDetails { details } => {
+            try_update_gateway_details(details, info, deps)
+        }
         ExecuteMsg::UpdateMixnetAddress { address } => {
             try_update_mixnet_address(address, info, deps)
         }
@@ -58,6 +73,16 @@ pub fn execute(
         ExecuteMsg::UndelegateFromMixnode { mix_identity } => {
             try_undelegate_from_mixnode(mix_identity, info, deps)
         }
+        ExecuteMsg::RedelegateToMixnode { from, to } => {
+            try_redelegate_to_mixnode(from, to, info, deps)
+        }
+        ExecuteMsg::DelegateToGateway {
+            gateway_identity,
+            amount,
+        } => try_delegate_to_gateway(gateway_identity, amount, info, env, deps),
+        ExecuteMsg::UndelegateFromGateway { gateway_identity } => {
+            try_undelegate_from_gateway(gateway_identity, info, deps)
+        }
         ExecuteMsg::CreateAccount {
             owner_address,
             staking_address,
@@ -87,6 +112,23 @@ pub fn execute(
         ExecuteMsg::TrackUnbondMixnode { owner, amount } => {
             try_track_unbond_mixnode(&owner, amount, info, deps)
         }
+        ExecuteMsg::IncreaseMixnodePledge { amount } => {
+            try_increase_mixnode_pledge(amount, info, deps)
+        }
+        ExecuteMsg::DecreaseMixnodePledge { amount } => {
+            try_decrease_mixnode_pledge(amount, info, deps)
+        }
+        ExecuteMsg::TrackDecreaseMixnodePledge { owner, amount } => {
+            try_track_decrease_mixnode_pledge(&owner, amount, info, deps)
+        }
+        ExecuteMsg::TrackSlashedMixnodePledge { owner, amount } => {
+            try_track_slashed_mixnode_pledge(&owner, amount, info, deps)
+        }
+        ExecuteMsg::TrackSlashedDelegation {
+            owner,
+            mix_identity,
+            amount,
+        } => try_track_slashed_delegation(&owner, mix_identity, amount, info, deps),
         ExecuteMsg::BondGateway {
             gateway,
             owner_signature,
@@ -96,6 +138,27 @@ pub fn execute(
         ExecuteMsg::TrackUnbondGateway { owner, amount } => {
             try_track_unbond_gateway(&owner, amount, info, deps)
         }
+        ExecuteMsg::TrackGatewayUndelegation {
+            owner,
+            gateway_identity,
+            amount,
+        } => try_track_gateway_undelegation(&owner, gateway_identity, amount, info, deps),
+        ExecuteMsg::ClaimPendingWithdrawals {} => try_claim_pending_withdrawals(info, deps),
+        ExecuteMsg::ClaimOperatorReward {} => try_claim_operator_reward(info, deps),
+        ExecuteMsg::ClaimDelegatorReward { mix_identity } => {
+            try_claim_delegator_reward(mix_identity, info, deps)
+        }
+        ExecuteMsg::CompoundDelegatorReward { mix_identity } => {
+            try_compound_delegator_reward(mix_identity, info, deps)
+        }
+        ExecuteMsg::ClaimGatewayOperatorReward {} => try_claim_gateway_operator_reward(info, deps),
+        ExecuteMsg::ClaimGatewayDelegatorReward { gateway_identity } => {
+            try_claim_gateway_delegator_reward(gateway_identity, info, deps)
+        }
+        ExecuteMsg::CompoundGatewayDelegatorReward { gateway_identity } => {
+            try_compound_gateway_delegator_reward(gateway_identity, info, deps)
+        }
+        ExecuteMsg::TrackReward { owner, amount } => try_track_reward(&owner, amount, info, deps),
         ExecuteMsg::TransferOwnership { to_address } => {
             try_transfer_ownership(to_address, info, deps)
         }
@@ -105,6 +168,160 @@ pub fn execute(
     }
 }
 
+// unbonded and undelegated tokens are only released by the mixnet contract once its unbonding
+// period is over, at which point they are tracked via `TrackUnbondMixnode`, `TrackUndelegation`
+// and `TrackGatewayUndelegation`
+pub fn try_claim_pending_withdrawals(
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    let msg = MixnetExecuteMsg::ClaimPendingWithdrawalsOnBehalf {
+        owner: account.owner_address().into_string(),
+    };
+    let claim_msg = wasm_execute(
+        MIXNET_CONTRACT_ADDRESS.load(deps.storage)?,
+        &msg,
+        vec![one_ucoin()],
+    )?;
+
+    Ok(Response::new()
+        .add_message(claim_msg)
+        .add_event(new_vesting_claim_pending_withdrawals_event()))
+}
+
+// rewards accrued by vesting-funded bonds and delegations are sent back to this contract
+// and are tracked via `TrackReward`
+pub fn try_claim_operator_reward(
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    let msg = MixnetExecuteMsg::ClaimOperatorRewardOnBehalf {
+        owner: account.owner_address().into_string(),
+    };
+    let claim_msg = wasm_execute(
+        MIXNET_CONTRACT_ADDRESS.load(deps.storage)?,
+        &msg,
+        vec![one_ucoin()],
+    )?;
+
+    Ok(Response::new()
+        .add_message(claim_msg)
+        .add_event(new_vesting_claim_operator_reward_event()))
+}
+
+pub fn try_claim_delegator_reward(
+    mix_identity: IdentityKey,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    let msg = MixnetExecuteMsg::ClaimDelegatorRewardOnBehalf {
+        mix_identity,
+        delegate: account.owner_address().into_string(),
+    };
+    let claim_msg = wasm_execute(
+        MIXNET_CONTRACT_ADDRESS.load(deps.storage)?,
+        &msg,
+        vec![one_ucoin()],
+    )?;
+
+    Ok(Response::new()
+        .add_message(claim_msg)
+        .add_event(new_vesting_claim_delegator_reward_event()))
+}
+
+// the balance is not affected as the tokens never leave the mixnet contract
+pub fn try_compound_delegator_reward(
+    mix_identity: IdentityKey,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    let msg = MixnetExecuteMsg::CompoundDelegatorRewardOnBehalf {
+        mix_identity,
+        delegate: account.owner_address().into_string(),
+    };
+    let compound_msg = wasm_execute(MIXNET_CONTRACT_ADDRESS.load(deps.storage)?, &msg, vec![])?;
+
+    Ok(Response::new()
+        .add_message(compound_msg)
+        .add_event(new_vesting_compound_delegator_reward_event()))
+}
+
+pub fn try_claim_gateway_operator_reward(
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    let msg = MixnetExecuteMsg::ClaimGatewayOperatorRewardOnBehalf {
+        owner: account.owner_address().into_string(),
+    };
+    let claim_msg = wasm_execute(
+        MIXNET_CONTRACT_ADDRESS.load(deps.storage)?,
+        &msg,
+        vec![one_ucoin()],
+    )?;
+
+    Ok(Response::new()
+        .add_message(claim_msg)
+        .add_event(new_vesting_claim_operator_reward_event()))
+}
+
+pub fn try_claim_gateway_delegator_reward(
+    gateway_identity: IdentityKey,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    let msg = MixnetExecuteMsg::ClaimGatewayDelegatorRewardOnBehalf {
+        gateway_identity,
+        delegate: account.owner_address().into_string(),
+    };
+    let claim_msg = wasm_execute(
+        MIXNET_CONTRACT_ADDRESS.load(deps.storage)?,
+        &msg,
+        vec![one_ucoin()],
+    )?;
+
+    Ok(Response::new()
+        .add_message(claim_msg)
+        .add_event(new_vesting_claim_delegator_reward_event()))
+}
+
+pub fn try_compound_gateway_delegator_reward(
+    gateway_identity: IdentityKey,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    let msg = MixnetExecuteMsg::CompoundGatewayDelegatorRewardOnBehalf {
+        gateway_identity,
+        delegate: account.owner_address().into_string(),
+    };
+    let compound_msg = wasm_execute(MIXNET_CONTRACT_ADDRESS.load(deps.storage)?, &msg, vec![])?;
+
+    Ok(Response::new()
+        .add_message(compound_msg)
+        .add_event(new_vesting_compound_delegator_reward_event()))
+}
+
+pub fn try_track_reward(
+    owner: &str,
+    amount: Coin,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if info.sender != MIXNET_CONTRACT_ADDRESS.load(deps.storage)? {
+        return Err(ContractError::NotMixnetContract(info.sender));
+    }
+    let account = account_from_address(owner, deps.storage, deps.api)?;
+    let new_balance = account.load_balance(deps.storage)? + amount.amount;
+    account.save_balance(new_balance, deps.storage)?;
+    Ok(Response::new().add_event(new_track_reward_event()))
+}
+
 pub fn try_update_mixnode_config(
     profit_margin_percent: u8,
     info: MessageInfo,
@@ -114,6 +331,24 @@ pub fn try_update_mixnode_config(
     account.try_update_mixnode_config(profit_margin_percent, deps.storage)
 }
 
+pub fn try_update_mixnode_details(
+    details: MixNodeDetailsUpdate,
+    info: MessageInfo,
+    deps: DepsMut,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    account.try_update_mixnode_details(details, deps.storage)
+}
+
+pub fn try_update_gateway_details(
+    details: GatewayDetailsUpdate,
+    info: MessageInfo,
+    deps: DepsMut,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    account.try_update_gateway_details(details, deps.storage)
+}
+
 // Only contract admin, set at init
 pub fn try_update_mixnet_address(
     address: String,
@@ -269,6 +504,54 @@ pub fn try_track_unbond_mixnode(
     Ok(Response::new().add_event(new_track_mixnode_unbond_event()))
 }
 
+pub fn try_increase_mixnode_pledge(
+    amount: Coin,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let amount = validate_funds(&[amount])?;
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    account.try_increase_mixnode_pledge(amount, deps.storage)
+}
+
+pub fn try_decrease_mixnode_pledge(
+    amount: Coin,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let amount = validate_funds(&[amount])?;
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    account.try_decrease_mixnode_pledge(amount, deps.storage)
+}
+
+pub fn try_track_decrease_mixnode_pledge(
+    owner: &str,
+    amount: Coin,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if info.sender != MIXNET_CONTRACT_ADDRESS.load(deps.storage)? {
+        return Err(ContractError::NotMixnetContract(info.sender));
+    }
+    let account = account_from_address(owner, deps.storage, deps.api)?;
+    account.try_track_decrease_mixnode_pledge(amount, deps.storage)?;
+    Ok(Response::new().add_event(new_track_mixnode_pledge_decrease_event()))
+}
+
+pub fn try_track_slashed_mixnode_pledge(
+    owner: &str,
+    amount: Coin,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if info.sender != MIXNET_CONTRACT_ADDRESS.load(deps.storage)? {
+        return Err(ContractError::NotMixnetContract(info.sender));
+    }
+    let account = account_from_address(owner, deps.storage, deps.api)?;
+    account.try_track_slashed_mixnode_pledge(amount, deps.storage)?;
+    Ok(Response::new().add_event(new_track_slashed_mixnode_pledge_event()))
+}
+
 fn try_track_undelegation(
     address: &str,
     mix_identity: IdentityKey,
@@ -284,6 +567,36 @@ fn try_track_undelegation(
     Ok(Response::new().add_event(new_track_undelegation_event()))
 }
 
+fn try_track_slashed_delegation(
+    address: &str,
+    mix_identity: IdentityKey,
+    amount: Coin,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if info.sender != MIXNET_CONTRACT_ADDRESS.load(deps.storage)? {
+        return Err(ContractError::NotMixnetContract(info.sender));
+    }
+    let account = account_from_address(address, deps.storage, deps.api)?;
+    account.track_slashed_delegation(mix_identity, amount, deps.storage)?;
+    Ok(Response::new().add_event(new_track_slashed_delegation_event()))
+}
+
+fn try_track_gateway_undelegation(
+    address: &str,
+    gateway_identity: IdentityKey,
+    amount: Coin,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if info.sender != MIXNET_CONTRACT_ADDRESS.load(deps.storage)? {
+        return Err(ContractError::NotMixnetContract(info.sender));
+    }
+    let account = account_from_address(address, deps.storage, deps.api)?;
+    account.track_gateway_undelegation(gateway_identity, amount, deps.storage)?;
+    Ok(Response::new().add_event(new_track_gateway_undelegation_event()))
+}
+
 fn try_delegate_to_mixnode(
     mix_identity: IdentityKey,
     amount: Coin,
@@ -305,6 +618,37 @@ fn try_undelegate_from_mixnode(
     account.try_undelegate_from_mixnode(mix_identity, deps.storage)
 }
 
+fn try_redelegate_to_mixnode(
+    from: IdentityKey,
+    to: IdentityKey,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    account.try_redelegate_to_mixnode(from, to, deps.storage)
+}
+
+fn try_delegate_to_gateway(
+    gateway_identity: IdentityKey,
+    amount: Coin,
+    info: MessageInfo,
+    env: Env,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let amount = validate_funds(&[amount])?;
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    account.try_delegate_to_gateway(gateway_identity, amount, &env, deps.storage)
+}
+
+fn try_undelegate_from_gateway(
+    gateway_identity: IdentityKey,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    account.try_undelegate_from_gateway(gateway_identity, deps.storage)
+}
+
 fn try_create_periodic_vesting_account(
     owner_address: &str,
     staking_address: Option<String>,
@@ -336,7 +680,7 @@ fn try_create_periodic_vesting_account(
         .start_time()
         .unwrap_or_else(|| env.block.time.seconds());
 
-    let periods = populate_vesting_periods(start_time, vesting_spec);
+    validate_vesting_spec(&vesting_spec, start_time, coin.amount)?;
 
     let start_time = Timestamp::from_seconds(start_time);
     Account::new(
@@ -344,7 +688,7 @@ fn try_create_periodic_vesting_account(
         staking_address.clone(),
         coin.clone(),
         start_time,
-        periods,
+        &vesting_spec,
         deps.storage,
     )?;
 
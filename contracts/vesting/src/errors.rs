@@ -32,6 +32,8 @@ pub enum ContractError {
     MultipleDenoms,
     #[error("VESTING ({}): No delegations found for account {0}, mix_identity {1}", line!())]
     NoSuchDelegation(Addr, IdentityKey),
+    #[error("VESTING ({}): No gateway delegations found for account {0}, gateway_identity {1}", line!())]
+    NoSuchGatewayDelegation(Addr, IdentityKey),
     #[error("VESTING ({}): Only mixnet contract can perform this operation, got {0}", line!())]
     NotMixnetContract(Addr),
     #[error("VESTING ({}): Calculation underflowed", line!())]
@@ -44,4 +46,16 @@ pub enum ContractError {
     InvalidAddress(String),
     #[error("VESTING ({}): Account already exists: {0}", line!())]
     AccountAlreadyExists(String),
+    #[error("VESTING ({}): Vesting specification must contain at least a single period", line!())]
+    NoVestingPeriods,
+    #[error("VESTING ({}): Vesting periods must be longer than 0s", line!())]
+    ZeroVestingPeriodLength,
+    #[error("VESTING ({}): Expected {0} custom vesting amounts, got {1}", line!())]
+    InvalidNumberOfVestingAmounts(u64, usize),
+    #[error("VESTING ({}): Custom vesting amounts add up to {0}, expected {1}", line!())]
+    VestingAmountsMismatch(u128, u128),
+    #[error("VESTING ({}): Vesting cliff of {0}s exceeds the total vesting duration of {1}s", line!())]
+    CliffTooLong(u64, u64),
+    #[error("VESTING ({}): {1} vesting periods of {0}s starting at {2} would end past the maximum timestamp", line!())]
+    VestingDurationOverflow(u64, u64, u64),
 }
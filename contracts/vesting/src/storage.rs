@@ -11,9 +11,12 @@ pub const KEY: Item<'_, u32> = Item::new("key");
 const ACCOUNTS: Map<'_, String, Account> = Map::new("acc");
 // Holds data related to individual accounts
 const BALANCES: Map<'_, u32, Uint128> = Map::new("blc");
+// the mixnet contract identifies bonds by their owner, so an account can have
+// at most a single mixnode and a single gateway pledge
 const BOND_PLEDGES: Map<'_, u32, PledgeData> = Map::new("bnd");
 const GATEWAY_PLEDGES: Map<'_, u32, PledgeData> = Map::new("gtw");
 pub const DELEGATIONS: Map<'_, (u32, IdentityKey, BlockHeight), Uint128> = Map::new("dlg");
+pub const GATEWAY_DELEGATIONS: Map<'_, (u32, IdentityKey, BlockHeight), Uint128> = Map::new("gdl");
 pub const ADMIN: Item<'_, String> = Item::new("adm");
 pub const MIXNET_CONTRACT_ADDRESS: Item<'_, String> = Item::new("mix");
 
@@ -34,6 +37,23 @@ pub fn remove_delegation(
     Ok(())
 }
 
+pub fn save_gateway_delegation(
+    key: (u32, IdentityKey, BlockHeight),
+    amount: Uint128,
+    storage: &mut dyn Storage,
+) -> Result<(), ContractError> {
+    GATEWAY_DELEGATIONS.save(storage, key, &amount)?;
+    Ok(())
+}
+
+pub fn remove_gateway_delegation(
+    key: (u32, IdentityKey, BlockHeight),
+    storage: &mut dyn Storage,
+) -> Result<(), ContractError> {
+    GATEWAY_DELEGATIONS.remove(storage, key);
+    Ok(())
+}
+
 pub fn delete_account(address: &Addr, storage: &mut dyn Storage) -> Result<(), ContractError> {
     ACCOUNTS.remove(storage, address.to_owned().to_string());
     Ok(())
@@ -1,7 +1,7 @@
 #[cfg(test)]
 pub mod helpers {
     use crate::contract::instantiate;
-    use crate::vesting::{populate_vesting_periods, Account};
+    use crate::vesting::Account;
     use config::defaults::DENOM;
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
     use cosmwasm_std::{Addr, Coin, Empty, Env, MemoryStorage, OwnedDeps, Storage, Uint128};
@@ -19,9 +19,15 @@ pub mod helpers {
     }
 
     pub fn vesting_account_fixture(storage: &mut dyn Storage, env: &Env) -> Account {
+        vesting_account_with_spec_fixture(storage, env, VestingSpecification::default())
+    }
+
+    pub fn vesting_account_with_spec_fixture(
+        storage: &mut dyn Storage,
+        env: &Env,
+        vesting_spec: VestingSpecification,
+    ) -> Account {
         let start_time = env.block.time;
-        let periods =
-            populate_vesting_periods(start_time.seconds(), VestingSpecification::default());
 
         Account::new(
             Addr::unchecked("owner"),
@@ -31,7 +37,7 @@ pub mod helpers {
                 denom: DENOM.to_string(),
             },
             start_time,
-            periods,
+            &vesting_spec,
             storage,
         )
         .unwrap()
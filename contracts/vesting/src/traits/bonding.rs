@@ -1,6 +1,6 @@
 use crate::errors::ContractError;
 use cosmwasm_std::{Coin, Env, Response, Storage};
-use mixnet_contract_common::{Gateway, MixNode};
+use mixnet_contract_common::{Gateway, GatewayDetailsUpdate, MixNode, MixNodeDetailsUpdate};
 
 pub trait MixnodeBondingAccount {
     fn try_bond_mixnode(
@@ -25,6 +25,37 @@ pub trait MixnodeBondingAccount {
         profit_margin_percent: u8,
         storage: &mut dyn Storage,
     ) -> Result<Response, ContractError>;
+
+    fn try_update_mixnode_details(
+        &self,
+        details: MixNodeDetailsUpdate,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
+    fn try_increase_mixnode_pledge(
+        &self,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<Response, ContractError>;
+
+    fn try_decrease_mixnode_pledge(
+        &self,
+        amount: Coin,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
+    fn try_track_decrease_mixnode_pledge(
+        &self,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError>;
+
+    // reduces the tracked pledge after the mixnet contract has slashed the node
+    fn try_track_slashed_mixnode_pledge(
+        &self,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError>;
 }
 
 pub trait GatewayBondingAccount {
@@ -44,4 +75,10 @@ pub trait GatewayBondingAccount {
         amount: Coin,
         storage: &mut dyn Storage,
     ) -> Result<(), ContractError>;
+
+    fn try_update_gateway_details(
+        &self,
+        details: GatewayDetailsUpdate,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
 }
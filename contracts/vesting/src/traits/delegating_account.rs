@@ -17,6 +17,15 @@ pub trait DelegatingAccount {
         storage: &dyn Storage,
     ) -> Result<Response, ContractError>;
 
+    // moves the delegation tracked in this contract between the nodes and instructs the
+    // mixnet contract to do the same. No tokens are moved in the process.
+    fn try_redelegate_to_mixnode(
+        &self,
+        from: IdentityKey,
+        to: IdentityKey,
+        storage: &mut dyn Storage,
+    ) -> Result<Response, ContractError>;
+
     // track_delegation performs internal vesting accounting necessary when
     // delegating from a vesting account. It accepts the current block height, the
     // delegation amount and balance of all coins whose denomination exists in
@@ -38,4 +47,34 @@ pub trait DelegatingAccount {
         amount: Coin,
         storage: &mut dyn Storage,
     ) -> Result<(), ContractError>;
+
+    // reduces the tracked delegations after the mixnet contract has slashed them alongside the node
+    fn track_slashed_delegation(
+        &self,
+        mix_identity: IdentityKey,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError>;
+
+    fn try_delegate_to_gateway(
+        &self,
+        gateway_identity: IdentityKey,
+        amount: Coin,
+        env: &Env,
+        storage: &mut dyn Storage,
+    ) -> Result<Response, ContractError>;
+
+    fn try_undelegate_from_gateway(
+        &self,
+        gateway_identity: IdentityKey,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
+    // gateway equivalent of track_undelegation
+    fn track_gateway_undelegation(
+        &self,
+        gateway_identity: IdentityKey,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError>;
 }
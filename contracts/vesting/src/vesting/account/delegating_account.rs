@@ -1,12 +1,14 @@
 use crate::errors::ContractError;
-use crate::storage::save_delegation;
 use crate::storage::MIXNET_CONTRACT_ADDRESS;
+use crate::storage::{save_delegation, save_gateway_delegation};
 use crate::traits::DelegatingAccount;
 use cosmwasm_std::{wasm_execute, Coin, Env, Response, Storage, Uint128};
 use mixnet_contract_common::ExecuteMsg as MixnetExecuteMsg;
 use mixnet_contract_common::IdentityKey;
 use vesting_contract_common::events::{
-    new_vesting_delegation_event, new_vesting_undelegation_event,
+    new_vesting_delegation_event, new_vesting_gateway_delegation_event,
+    new_vesting_gateway_undelegation_event, new_vesting_redelegation_event,
+    new_vesting_undelegation_event,
 };
 use vesting_contract_common::one_ucoin;
 
@@ -78,6 +80,32 @@ impl DelegatingAccount for Account {
             .add_event(new_vesting_undelegation_event()))
     }
 
+    fn try_redelegate_to_mixnode(
+        &self,
+        from: IdentityKey,
+        to: IdentityKey,
+        storage: &mut dyn Storage,
+    ) -> Result<Response, ContractError> {
+        if !self.any_delegation_for_mix(&from, storage) {
+            return Err(ContractError::NoSuchDelegation(self.owner_address(), from));
+        }
+
+        // the balance is not affected as the tokens never leave the mixnet contract
+        self.move_delegations_for_mix(&from, &to, storage)?;
+
+        let msg = MixnetExecuteMsg::RedelegateToMixnodeOnBehalf {
+            from,
+            to,
+            delegate: self.owner_address().into_string(),
+        };
+        let redelegate_to_mixnode =
+            wasm_execute(MIXNET_CONTRACT_ADDRESS.load(storage)?, &msg, vec![])?;
+
+        Ok(Response::new()
+            .add_message(redelegate_to_mixnode)
+            .add_event(new_vesting_redelegation_event()))
+    }
+
     fn track_delegation(
         &self,
         block_height: u64,
@@ -107,4 +135,91 @@ impl DelegatingAccount for Account {
         self.save_balance(new_balance, storage)?;
         Ok(())
     }
+
+    fn track_slashed_delegation(
+        &self,
+        mix_identity: IdentityKey,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        self.slash_delegations_for_mix(&mix_identity, amount.amount, storage)
+    }
+
+    fn try_delegate_to_gateway(
+        &self,
+        gateway_identity: IdentityKey,
+        coin: Coin,
+        env: &Env,
+        storage: &mut dyn Storage,
+    ) -> Result<Response, ContractError> {
+        let current_balance = self.load_balance(storage)?;
+
+        if current_balance < coin.amount {
+            return Err(ContractError::InsufficientBalance(
+                self.owner_address().as_str().to_string(),
+                current_balance.u128(),
+            ));
+        }
+
+        let msg = MixnetExecuteMsg::DelegateToGatewayOnBehalf {
+            gateway_identity: gateway_identity.clone(),
+            delegate: self.owner_address().into_string(),
+        };
+        let delegate_to_gateway = wasm_execute(
+            MIXNET_CONTRACT_ADDRESS.load(storage)?,
+            &msg,
+            vec![coin.clone()],
+        )?;
+
+        save_gateway_delegation(
+            (self.storage_key(), gateway_identity, env.block.height),
+            coin.amount,
+            storage,
+        )?;
+        let new_balance = Uint128::new(current_balance.u128() - coin.amount.u128());
+        self.save_balance(new_balance, storage)?;
+
+        Ok(Response::new()
+            .add_message(delegate_to_gateway)
+            .add_event(new_vesting_gateway_delegation_event()))
+    }
+
+    fn try_undelegate_from_gateway(
+        &self,
+        gateway_identity: IdentityKey,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        if !self.any_delegation_for_gateway(&gateway_identity, storage) {
+            return Err(ContractError::NoSuchGatewayDelegation(
+                self.owner_address(),
+                gateway_identity,
+            ));
+        }
+
+        let msg = MixnetExecuteMsg::UndelegateFromGatewayOnBehalf {
+            gateway_identity,
+            delegate: self.owner_address().into_string(),
+        };
+        let undelegate_from_gateway = wasm_execute(
+            MIXNET_CONTRACT_ADDRESS.load(storage)?,
+            &msg,
+            vec![one_ucoin()],
+        )?;
+
+        Ok(Response::new()
+            .add_message(undelegate_from_gateway)
+            .add_event(new_vesting_gateway_undelegation_event()))
+    }
+
+    fn track_gateway_undelegation(
+        &self,
+        gateway_identity: IdentityKey,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        self.remove_delegations_for_gateway(&gateway_identity, storage)?;
+        let new_balance = Uint128::new(self.load_balance(storage)?.u128() + amount.amount.u128());
+        self.save_balance(new_balance, storage)?;
+        Ok(())
+    }
 }
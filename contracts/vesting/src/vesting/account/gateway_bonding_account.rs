@@ -3,9 +3,10 @@ use crate::errors::ContractError;
 use crate::storage::MIXNET_CONTRACT_ADDRESS;
 use crate::traits::GatewayBondingAccount;
 use cosmwasm_std::{wasm_execute, Coin, Env, Response, Storage, Uint128};
-use mixnet_contract_common::{ExecuteMsg as MixnetExecuteMsg, Gateway};
+use mixnet_contract_common::{ExecuteMsg as MixnetExecuteMsg, Gateway, GatewayDetailsUpdate};
 use vesting_contract_common::events::{
     new_vesting_gateway_bonding_event, new_vesting_gateway_unbonding_event,
+    new_vesting_update_gateway_details_event,
 };
 use vesting_contract_common::one_ucoin;
 
@@ -29,6 +30,7 @@ impl GatewayBondingAccount for Account {
             ));
         }
 
+        // the mixnet contract allows a single gateway per owner
         let pledge_data = if self.load_gateway_pledge(storage)?.is_some() {
             return Err(ContractError::AlreadyBonded(
                 self.owner_address().as_str().to_string(),
@@ -64,7 +66,7 @@ impl GatewayBondingAccount for Account {
             owner: self.owner_address().into_string(),
         };
 
-        if let Some(_bond) = self.load_gateway_pledge(storage)? {
+        if self.load_gateway_pledge(storage)?.is_some() {
             let unbond_msg = wasm_execute(
                 MIXNET_CONTRACT_ADDRESS.load(storage)?,
                 &msg,
@@ -92,4 +94,31 @@ impl GatewayBondingAccount for Account {
         self.remove_gateway_pledge(storage)?;
         Ok(())
     }
+
+    fn try_update_gateway_details(
+        &self,
+        details: GatewayDetailsUpdate,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        if self.load_gateway_pledge(storage)?.is_none() {
+            return Err(ContractError::NoBondFound(
+                self.owner_address().as_str().to_string(),
+            ));
+        }
+
+        let msg = MixnetExecuteMsg::UpdateGatewayDetailsOnBehalf {
+            details,
+            owner: self.owner_address().into_string(),
+        };
+
+        let update_gateway_details_msg = wasm_execute(
+            MIXNET_CONTRACT_ADDRESS.load(storage)?,
+            &msg,
+            vec![one_ucoin()],
+        )?;
+
+        Ok(Response::new()
+            .add_message(update_gateway_details_msg)
+            .add_event(new_vesting_update_gateway_details_event()))
+    }
 }
@@ -3,10 +3,11 @@ use crate::errors::ContractError;
 use crate::storage::MIXNET_CONTRACT_ADDRESS;
 use crate::traits::MixnodeBondingAccount;
 use cosmwasm_std::{wasm_execute, Coin, Env, Response, Storage, Uint128};
-use mixnet_contract_common::{ExecuteMsg as MixnetExecuteMsg, MixNode};
+use mixnet_contract_common::{ExecuteMsg as MixnetExecuteMsg, MixNode, MixNodeDetailsUpdate};
 use vesting_contract_common::events::{
+    new_vesting_decrease_mixnode_pledge_event, new_vesting_increase_mixnode_pledge_event,
     new_vesting_mixnode_bonding_event, new_vesting_mixnode_unbonding_event,
-    new_vesting_update_mixnode_config_event,
+    new_vesting_update_mixnode_config_event, new_vesting_update_mixnode_details_event,
 };
 use vesting_contract_common::one_ucoin;
 
@@ -34,6 +35,130 @@ impl MixnodeBondingAccount for Account {
             .add_event(new_vesting_update_mixnode_config_event()))
     }
 
+    fn try_update_mixnode_details(
+        &self,
+        details: MixNodeDetailsUpdate,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        if self.load_mixnode_pledge(storage)?.is_none() {
+            return Err(ContractError::NoBondFound(
+                self.owner_address().as_str().to_string(),
+            ));
+        }
+
+        let msg = MixnetExecuteMsg::UpdateMixnodeDetailsOnBehalf {
+            details,
+            owner: self.owner_address().into_string(),
+        };
+
+        let update_mixnode_details_msg = wasm_execute(
+            MIXNET_CONTRACT_ADDRESS.load(storage)?,
+            &msg,
+            vec![one_ucoin()],
+        )?;
+
+        Ok(Response::new()
+            .add_message(update_mixnode_details_msg)
+            .add_event(new_vesting_update_mixnode_details_event()))
+    }
+
+    fn try_increase_mixnode_pledge(
+        &self,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<Response, ContractError> {
+        let current_balance = self.load_balance(storage)?;
+
+        if current_balance < amount.amount {
+            return Err(ContractError::InsufficientBalance(
+                self.owner_address().as_str().to_string(),
+                current_balance.u128(),
+            ));
+        }
+
+        let mut pledge_data = self
+            .load_mixnode_pledge(storage)?
+            .ok_or_else(|| ContractError::NoBondFound(self.owner_address().as_str().to_string()))?;
+
+        let msg = MixnetExecuteMsg::IncreaseMixnodePledgeOnBehalf {
+            owner: self.owner_address().into_string(),
+        };
+
+        let new_balance = Uint128::new(current_balance.u128() - amount.amount.u128());
+        // the tokens leave the account straight away, even though the mixnet contract
+        // is only going to take them into account from the next interval
+        pledge_data.amount += amount.amount;
+
+        let increase_pledge_msg =
+            wasm_execute(MIXNET_CONTRACT_ADDRESS.load(storage)?, &msg, vec![amount])?;
+
+        self.save_balance(new_balance, storage)?;
+        self.save_mixnode_pledge(pledge_data, storage)?;
+
+        Ok(Response::new()
+            .add_message(increase_pledge_msg)
+            .add_event(new_vesting_increase_mixnode_pledge_event()))
+    }
+
+    fn try_decrease_mixnode_pledge(
+        &self,
+        amount: Coin,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        if self.load_mixnode_pledge(storage)?.is_none() {
+            return Err(ContractError::NoBondFound(
+                self.owner_address().as_str().to_string(),
+            ));
+        }
+
+        // the pledge data is only updated once the mixnet contract returns the tokens
+        let msg = MixnetExecuteMsg::DecreaseMixnodePledgeOnBehalf {
+            amount,
+            owner: self.owner_address().into_string(),
+        };
+
+        let decrease_pledge_msg = wasm_execute(
+            MIXNET_CONTRACT_ADDRESS.load(storage)?,
+            &msg,
+            vec![one_ucoin()],
+        )?;
+
+        Ok(Response::new()
+            .add_message(decrease_pledge_msg)
+            .add_event(new_vesting_decrease_mixnode_pledge_event()))
+    }
+
+    fn try_track_decrease_mixnode_pledge(
+        &self,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        let mut pledge_data = self
+            .load_mixnode_pledge(storage)?
+            .ok_or_else(|| ContractError::NoBondFound(self.owner_address().as_str().to_string()))?;
+        // never track a negative pledge, even if the mixnet contract returned more than expected
+        pledge_data.amount = pledge_data.amount.saturating_sub(amount.amount);
+
+        let new_balance = Uint128::new(self.load_balance(storage)?.u128() + amount.amount.u128());
+        self.save_balance(new_balance, storage)?;
+        self.save_mixnode_pledge(pledge_data, storage)?;
+        Ok(())
+    }
+
+    fn try_track_slashed_mixnode_pledge(
+        &self,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        let mut pledge_data = self
+            .load_mixnode_pledge(storage)?
+            .ok_or_else(|| ContractError::NoBondFound(self.owner_address().as_str().to_string()))?;
+        // the slashed tokens are gone, so unlike with a decrease, the balance stays the same
+        pledge_data.amount = pledge_data.amount.saturating_sub(amount.amount);
+        self.save_mixnode_pledge(pledge_data, storage)?;
+        Ok(())
+    }
+
     fn try_bond_mixnode(
         &self,
         mix_node: MixNode,
@@ -51,6 +176,8 @@ impl MixnodeBondingAccount for Account {
             ));
         }
 
+        // the mixnet contract allows a single mixnode per owner, so any pledge still tracked,
+        // including one of a node that is being unbonded, has to be cleared first
         let pledge_data = if self.load_mixnode_pledge(storage)?.is_some() {
             return Err(ContractError::AlreadyBonded(
                 self.owner_address().as_str().to_string(),
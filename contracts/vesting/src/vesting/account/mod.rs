@@ -1,15 +1,16 @@
-use super::{PledgeData, VestingPeriod};
+use super::{populate_vesting_periods, PledgeData, VestingPeriod};
 use crate::errors::ContractError;
 use crate::storage::{
     load_balance, load_bond_pledge, load_gateway_pledge, remove_bond_pledge, remove_delegation,
-    remove_gateway_pledge, save_account, save_balance, save_bond_pledge, save_gateway_pledge,
-    DELEGATIONS, KEY,
+    remove_gateway_delegation, remove_gateway_pledge, save_account, save_balance, save_bond_pledge,
+    save_delegation, save_gateway_pledge, DELEGATIONS, GATEWAY_DELEGATIONS, KEY,
 };
-use cosmwasm_std::{Addr, Coin, Order, Storage, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Coin, Order, StdResult, Storage, Timestamp, Uint128};
 use cw_storage_plus::Bound;
 use mixnet_contract_common::IdentityKey;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use vesting_contract_common::messages::{VestingSchedule, VestingSpecification};
 
 mod delegating_account;
 mod gateway_bonding_account;
@@ -37,6 +38,11 @@ pub struct Account {
     periods: Vec<VestingPeriod>,
     coin: Coin,
     storage_key: u32,
+    // accounts created before cliffs and custom schedules were introduced have neither
+    #[serde(default)]
+    cliff_time: Option<Timestamp>,
+    #[serde(default)]
+    schedule: VestingSchedule,
 }
 
 impl Account {
@@ -45,11 +51,17 @@ impl Account {
         staking_address: Option<Addr>,
         coin: Coin,
         start_time: Timestamp,
-        periods: Vec<VestingPeriod>,
+        vesting_spec: &VestingSpecification,
         storage: &mut dyn Storage,
     ) -> Result<Self, ContractError> {
         let storage_key = generate_storage_key(storage)?;
         let amount = coin.amount;
+        let periods = populate_vesting_periods(start_time.seconds(), vesting_spec);
+        let cliff_time = if vesting_spec.cliff_seconds() > 0 {
+            Some(start_time.plus_seconds(vesting_spec.cliff_seconds()))
+        } else {
+            None
+        };
         let account = Account {
             owner_address,
             staking_address,
@@ -57,6 +69,8 @@ impl Account {
             periods,
             coin,
             storage_key,
+            cliff_time,
+            schedule: vesting_spec.schedule(),
         };
         save_account(&account, storage)?;
         account.save_balance(amount, storage)?;
@@ -89,6 +103,10 @@ impl Account {
         self.start_time
     }
 
+    pub fn schedule(&self) -> &VestingSchedule {
+        &self.schedule
+    }
+
     pub fn tokens_per_period(&self) -> Result<u128, ContractError> {
         let amount = self.coin.amount.u128();
         if amount < self.num_vesting_periods() as u128 {
@@ -169,6 +187,18 @@ impl Account {
         remove_gateway_pledge(self.storage_key(), storage)
     }
 
+    // the mixnode and gateway pledges of this account, an account can hold at most one of each
+    pub fn load_all_pledges(
+        &self,
+        storage: &dyn Storage,
+    ) -> Result<Vec<PledgeData>, ContractError> {
+        Ok(self
+            .load_mixnode_pledge(storage)?
+            .into_iter()
+            .chain(self.load_gateway_pledge(storage)?)
+            .collect())
+    }
+
     pub fn any_delegation_for_mix(&self, mix: &str, storage: &dyn Storage) -> bool {
         DELEGATIONS
             .prefix((self.storage_key(), mix.to_string()))
@@ -214,6 +244,65 @@ impl Account {
         Ok(())
     }
 
+    pub fn move_delegations_for_mix(
+        &self,
+        from: &str,
+        to: &str,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        let delegations = DELEGATIONS
+            .prefix((self.storage_key(), from.to_string()))
+            .range(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        // the original block heights are preserved, if the account has already delegated
+        // to the target node at the same height, the amounts are combined
+        for (block_height, amount) in delegations {
+            remove_delegation(
+                (self.storage_key(), from.to_string(), block_height),
+                storage,
+            )?;
+
+            let key = (self.storage_key(), to.to_string(), block_height);
+            let existing = DELEGATIONS
+                .may_load(storage, key.clone())?
+                .unwrap_or_default();
+            save_delegation(key, existing + amount, storage)?;
+        }
+        Ok(())
+    }
+
+    // The mixnet contract only knows about the combined delegation, so the slashed amount
+    // is taken from the most recent delegations first.
+    pub fn slash_delegations_for_mix(
+        &self,
+        mix: &str,
+        amount: Uint128,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        let delegations = DELEGATIONS
+            .prefix((self.storage_key(), mix.to_string()))
+            .range(storage, None, None, Order::Descending)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        let mut remaining = amount;
+        for (block_height, delegated) in delegations {
+            if remaining.is_zero() {
+                break;
+            }
+            let slashed = delegated.min(remaining);
+            remaining = remaining - slashed;
+
+            let key = (self.storage_key(), mix.to_string(), block_height);
+            if slashed == delegated {
+                remove_delegation(key, storage)?;
+            } else {
+                save_delegation(key, delegated - slashed, storage)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn total_delegations_for_mix(
         &self,
         mix: IdentityKey,
@@ -226,10 +315,44 @@ impl Account {
             .fold(Uint128::zero(), |acc, (_key, val)| acc + val))
     }
 
+    pub fn any_delegation_for_gateway(&self, gateway: &str, storage: &dyn Storage) -> bool {
+        GATEWAY_DELEGATIONS
+            .prefix((self.storage_key(), gateway.to_string()))
+            .range(storage, None, None, Order::Ascending)
+            .next()
+            .is_some()
+    }
+
+    pub fn remove_delegations_for_gateway(
+        &self,
+        gateway: &str,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        let block_heights = GATEWAY_DELEGATIONS
+            .prefix((self.storage_key(), gateway.to_string()))
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        for block_height in block_heights {
+            remove_gateway_delegation(
+                (self.storage_key(), gateway.to_string(), block_height),
+                storage,
+            )?;
+        }
+        Ok(())
+    }
+
+    // includes delegations towards both mixnodes and gateways
     pub fn total_delegations(&self, storage: &dyn Storage) -> Result<Uint128, ContractError> {
         Ok(DELEGATIONS
             .sub_prefix(self.storage_key())
             .range(storage, None, None, Order::Ascending)
+            .chain(GATEWAY_DELEGATIONS.sub_prefix(self.storage_key()).range(
+                storage,
+                None,
+                None,
+                Order::Ascending,
+            ))
             .filter_map(|x| x.ok())
             .fold(Uint128::zero(), |acc, (_key, val)| acc + val))
     }
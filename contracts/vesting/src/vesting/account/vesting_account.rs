@@ -1,8 +1,9 @@
 use crate::errors::ContractError;
-use crate::storage::{delete_account, save_account, DELEGATIONS};
+use crate::storage::{delete_account, save_account, DELEGATIONS, GATEWAY_DELEGATIONS};
 use crate::traits::VestingAccount;
 use config::defaults::DENOM;
 use cosmwasm_std::{Addr, Coin, Env, Order, Storage, Timestamp, Uint128};
+use vesting_contract_common::messages::VestingSchedule;
 
 use super::{Account, Period};
 
@@ -58,23 +59,40 @@ impl VestingAccount for Account {
         env: &Env,
     ) -> Result<Coin, ContractError> {
         let block_time = block_time.unwrap_or(env.block.time);
-        let period = self.get_current_vesting_period(block_time);
 
-        let amount = match period {
-            Period::Before => Coin {
-                amount: Uint128::new(0),
-                denom: DENOM.to_string(),
-            },
-            Period::In(idx) => Coin {
-                amount: Uint128::new(self.tokens_per_period()? * idx as u128),
-                denom: DENOM.to_string(),
-            },
-            Period::After => Coin {
-                amount: self.coin.amount,
-                denom: DENOM.to_string(),
+        // nothing is vested until the cliff is over
+        if let Some(cliff_time) = self.cliff_time {
+            if block_time < cliff_time {
+                return Ok(Coin {
+                    amount: Uint128::zero(),
+                    denom: DENOM.to_string(),
+                });
+            }
+        }
+
+        let amount = match self.get_current_vesting_period(block_time) {
+            Period::Before => Uint128::zero(),
+            Period::In(idx) => match &self.schedule {
+                VestingSchedule::Periodic => Uint128::new(self.tokens_per_period()? * idx as u128),
+                VestingSchedule::CustomAmounts(amounts) => amounts
+                    .iter()
+                    .take(idx)
+                    .fold(Uint128::zero(), |acc, amount| acc + amount),
+                VestingSchedule::Linear => {
+                    let start_time = self.periods[0].start_time;
+                    let duration = self.get_end_time().seconds() - start_time;
+                    self.coin
+                        .amount
+                        .multiply_ratio(block_time.seconds() - start_time, duration)
+                }
             },
+            Period::After => self.coin.amount,
         };
-        Ok(amount)
+
+        Ok(Coin {
+            amount,
+            denom: DENOM.to_string(),
+        })
     }
 
     fn get_vesting_coins(
@@ -119,6 +137,12 @@ impl VestingAccount for Account {
         let coin = DELEGATIONS
             .sub_prefix(self.storage_key())
             .range(storage, None, None, Order::Ascending)
+            .chain(GATEWAY_DELEGATIONS.sub_prefix(self.storage_key()).range(
+                storage,
+                None,
+                None,
+                Order::Ascending,
+            ))
             .filter_map(|x| x.ok())
             .filter(|((_mix, block_time), _amount)| *block_time < start_time)
             .fold(Uint128::zero(), |acc, ((_mix, _block_time), amount)| {
@@ -166,18 +190,11 @@ impl VestingAccount for Account {
             Period::In(idx) => self.periods[idx as usize].start_time,
         };
 
-        let amount = if let Some(bond) = self
-            .load_mixnode_pledge(storage)?
-            .or(self.load_gateway_pledge(storage)?)
-        {
-            if bond.block_time.seconds() < start_time {
-                bond.amount
-            } else {
-                Uint128::zero()
-            }
-        } else {
-            Uint128::zero()
-        };
+        let amount = self
+            .load_all_pledges(storage)?
+            .into_iter()
+            .filter(|pledge| pledge.block_time.seconds() < start_time)
+            .fold(Uint128::zero(), |acc, pledge| acc + pledge.amount);
 
         let amount = Uint128::new(amount.u128().min(max_vested.amount.u128()));
 
@@ -196,21 +213,15 @@ impl VestingAccount for Account {
         let block_time = block_time.unwrap_or(env.block.time);
         let bonded_free = self.get_pledged_free(Some(block_time), env, storage)?;
 
-        if let Some(bond) = self
-            .load_mixnode_pledge(storage)?
-            .or(self.load_gateway_pledge(storage)?)
-        {
-            let amount = bond.amount - bonded_free.amount;
-            Ok(Coin {
-                amount,
-                denom: DENOM.to_string(),
-            })
-        } else {
-            Ok(Coin {
-                amount: Uint128::zero(),
-                denom: DENOM.to_string(),
-            })
-        }
+        let total_pledged = self
+            .load_all_pledges(storage)?
+            .into_iter()
+            .fold(Uint128::zero(), |acc, pledge| acc + pledge.amount);
+
+        Ok(Coin {
+            amount: total_pledged - bonded_free.amount,
+            denom: DENOM.to_string(),
+        })
     }
 
     fn transfer_ownership(
@@ -1,3 +1,4 @@
+use crate::errors::ContractError;
 use cosmwasm_std::{Timestamp, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -5,7 +6,7 @@ use serde::{Deserialize, Serialize};
 mod account;
 pub use account::*;
 
-use vesting_contract_common::messages::VestingSpecification;
+use vesting_contract_common::messages::{VestingSchedule, VestingSpecification};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct VestingPeriod {
@@ -27,7 +28,7 @@ pub struct PledgeData {
 
 pub fn populate_vesting_periods(
     start_time: u64,
-    vesting_spec: VestingSpecification,
+    vesting_spec: &VestingSpecification,
 ) -> Vec<VestingPeriod> {
     let mut periods = Vec::with_capacity(vesting_spec.num_periods() as usize);
     for i in 0..vesting_spec.num_periods() {
@@ -40,11 +41,70 @@ pub fn populate_vesting_periods(
     periods
 }
 
+pub fn validate_vesting_spec(
+    vesting_spec: &VestingSpecification,
+    start_time: u64,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    if vesting_spec.num_periods() == 0 {
+        return Err(ContractError::NoVestingPeriods);
+    }
+
+    // zero-length periods would make the vesting computation divide by zero
+    if vesting_spec.period_seconds() == 0 {
+        return Err(ContractError::ZeroVestingPeriodLength);
+    }
+
+    // the period start times are derived from the start time and the period length,
+    // so the whole schedule has to fit within the timestamp range
+    let total_duration = vesting_spec
+        .total_duration()
+        .filter(|duration| start_time.checked_add(*duration).is_some())
+        .ok_or_else(|| {
+            ContractError::VestingDurationOverflow(
+                vesting_spec.period_seconds(),
+                vesting_spec.num_periods(),
+                start_time,
+            )
+        })?;
+
+    if vesting_spec.cliff_seconds() > total_duration {
+        return Err(ContractError::CliffTooLong(
+            vesting_spec.cliff_seconds(),
+            total_duration,
+        ));
+    }
+
+    if let VestingSchedule::CustomAmounts(amounts) = vesting_spec.schedule() {
+        if amounts.len() as u64 != vesting_spec.num_periods() {
+            return Err(ContractError::InvalidNumberOfVestingAmounts(
+                vesting_spec.num_periods(),
+                amounts.len(),
+            ));
+        }
+
+        let total = amounts
+            .iter()
+            .fold(Uint128::zero(), |acc, amount| acc + amount);
+        if total != amount {
+            return Err(ContractError::VestingAmountsMismatch(
+                total.u128(),
+                amount.u128(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::contract::execute;
+    use crate::errors::ContractError;
     use crate::storage::load_account;
-    use crate::support::tests::helpers::{init_contract, vesting_account_fixture};
+    use crate::support::tests::helpers::{
+        init_contract, vesting_account_fixture, vesting_account_with_spec_fixture,
+    };
     use crate::traits::DelegatingAccount;
     use crate::traits::VestingAccount;
     use crate::traits::{GatewayBondingAccount, MixnodeBondingAccount};
@@ -52,8 +112,33 @@ mod tests {
     use config::defaults::DENOM;
     use cosmwasm_std::testing::{mock_env, mock_info};
     use cosmwasm_std::{coins, Addr, Coin, Timestamp, Uint128};
-    use mixnet_contract_common::{Gateway, MixNode};
-    use vesting_contract_common::messages::ExecuteMsg;
+    use mixnet_contract_common::{Gateway, GatewayDetailsUpdate, MixNode, MixNodeDetailsUpdate};
+    use vesting_contract_common::messages::{ExecuteMsg, VestingSchedule, VestingSpecification};
+
+    fn mix_node_fixture(identity: &str) -> MixNode {
+        MixNode {
+            host: "mix.node.org".to_string(),
+            mix_port: 1789,
+            verloc_port: 1790,
+            http_api_port: 8000,
+            sphinx_key: "sphinx".to_string(),
+            identity_key: identity.to_string(),
+            version: "0.10.0".to_string(),
+            profit_margin_percent: 10,
+        }
+    }
+
+    fn gateway_fixture(identity: &str) -> Gateway {
+        Gateway {
+            host: "1.1.1.1".to_string(),
+            mix_port: 1789,
+            clients_port: 9000,
+            location: "Sweden".to_string(),
+            sphinx_key: "sphinx".to_string(),
+            identity_key: identity.to_string(),
+            version: "0.10.0".to_string(),
+        }
+    }
 
     #[test]
     fn test_account_creation() {
@@ -351,6 +436,66 @@ mod tests {
         assert_eq!(Uint128::zero(), delegated_free.amount);
     }
 
+    #[test]
+    fn test_redelegations() {
+        let mut deps = init_contract();
+        let mut env = mock_env();
+
+        let account = vesting_account_fixture(&mut deps.storage, &env);
+
+        // there's nothing to move yet
+        let err = account.try_redelegate_to_mixnode(
+            "alice".to_string(),
+            "bob".to_string(),
+            &mut deps.storage,
+        );
+        assert!(err.is_err());
+
+        account
+            .try_delegate_to_mixnode(
+                "alice".to_string(),
+                Coin::new(100_000_000_000, DENOM),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+        env.block.height += 1;
+        account
+            .try_delegate_to_mixnode(
+                "alice".to_string(),
+                Coin::new(200_000_000_000, DENOM),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+        account
+            .try_delegate_to_mixnode(
+                "bob".to_string(),
+                Coin::new(50_000_000_000, DENOM),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+
+        let res = account
+            .try_redelegate_to_mixnode("alice".to_string(), "bob".to_string(), &mut deps.storage)
+            .unwrap();
+        assert_eq!(1, res.messages.len());
+
+        assert!(!account.any_delegation_for_mix("alice", &deps.storage));
+        assert_eq!(
+            Uint128::new(350_000_000_000),
+            account
+                .total_delegations_for_mix("bob".to_string(), &deps.storage)
+                .unwrap()
+        );
+        // redelegating doesn't affect the balance of the account
+        assert_eq!(
+            Uint128::new(650_000_000_000),
+            account.load_balance(&deps.storage).unwrap()
+        );
+    }
+
     #[test]
     fn test_mixnode_bonds() {
         let mut deps = init_contract();
@@ -576,4 +721,705 @@ mod tests {
             .unwrap();
         assert_eq!(Uint128::zero(), bonded_vesting.amount);
     }
+
+    #[test]
+    fn test_node_details_updates() {
+        let mut deps = init_contract();
+        let env = mock_env();
+
+        let account = vesting_account_fixture(&mut deps.storage, &env);
+
+        let mixnode_details = MixNodeDetailsUpdate {
+            host: "2.2.2.2".to_string(),
+            mix_port: 1789,
+            verloc_port: 1790,
+            http_api_port: 8000,
+            version: "0.13.0".to_string(),
+        };
+        let gateway_details = GatewayDetailsUpdate {
+            host: "2.2.2.2".to_string(),
+            mix_port: 1789,
+            clients_port: 9000,
+            location: "Norway".to_string(),
+            version: "0.13.0".to_string(),
+        };
+
+        // nothing is bonded yet
+        assert!(account
+            .try_update_mixnode_details(mixnode_details.clone(), &deps.storage)
+            .is_err());
+        assert!(account
+            .try_update_gateway_details(gateway_details.clone(), &deps.storage)
+            .is_err());
+
+        let gateway = Gateway {
+            host: "1.1.1.1".to_string(),
+            mix_port: 1789,
+            clients_port: 9000,
+            location: "Sweden".to_string(),
+            sphinx_key: "sphinx".to_string(),
+            identity_key: "identity".to_string(),
+            version: "0.10.0".to_string(),
+        };
+        account
+            .try_bond_gateway(
+                gateway,
+                "alice".to_string(),
+                Coin {
+                    amount: Uint128::new(500_000_000_000),
+                    denom: DENOM.to_string(),
+                },
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+
+        let balance_before = account.load_balance(&deps.storage).unwrap();
+        let res = account
+            .try_update_gateway_details(gateway_details, &deps.storage)
+            .unwrap();
+        assert_eq!(1, res.messages.len());
+
+        // the pledge is not touched by the update
+        assert_eq!(balance_before, account.load_balance(&deps.storage).unwrap());
+        assert_eq!(
+            Uint128::new(500_000_000_000),
+            account
+                .load_gateway_pledge(&deps.storage)
+                .unwrap()
+                .unwrap()
+                .amount
+        );
+
+        // but we still don't have a mixnode
+        assert!(account
+            .try_update_mixnode_details(mixnode_details, &deps.storage)
+            .is_err());
+    }
+
+    #[test]
+    fn test_mixnode_pledge_changes() {
+        let mut deps = init_contract();
+        let env = mock_env();
+
+        let account = vesting_account_fixture(&mut deps.storage, &env);
+        let coin = |amount| Coin {
+            amount: Uint128::new(amount),
+            denom: DENOM.to_string(),
+        };
+
+        // there's nothing to change yet
+        assert!(account
+            .try_increase_mixnode_pledge(coin(100_000_000_000), &mut deps.storage)
+            .is_err());
+        assert!(account
+            .try_decrease_mixnode_pledge(coin(100_000_000_000), &deps.storage)
+            .is_err());
+
+        let mix_node = MixNode {
+            host: "mix.node.org".to_string(),
+            mix_port: 1789,
+            verloc_port: 1790,
+            http_api_port: 8000,
+            sphinx_key: "sphinx".to_string(),
+            identity_key: "identity".to_string(),
+            version: "0.10.0".to_string(),
+            profit_margin_percent: 10,
+        };
+        account
+            .try_bond_mixnode(
+                mix_node,
+                "alice".to_string(),
+                coin(500_000_000_000),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+
+        // can't pledge more than what's left in the account
+        assert!(account
+            .try_increase_mixnode_pledge(coin(500_000_000_001), &mut deps.storage)
+            .is_err());
+
+        let res = account
+            .try_increase_mixnode_pledge(coin(100_000_000_000), &mut deps.storage)
+            .unwrap();
+        assert_eq!(1, res.messages.len());
+        assert_eq!(
+            Uint128::new(400_000_000_000),
+            account.load_balance(&deps.storage).unwrap()
+        );
+        assert_eq!(
+            Uint128::new(600_000_000_000),
+            account
+                .load_mixnode_pledge(&deps.storage)
+                .unwrap()
+                .unwrap()
+                .amount
+        );
+
+        // decreasing doesn't change anything until the mixnet contract returns the tokens
+        let res = account
+            .try_decrease_mixnode_pledge(coin(200_000_000_000), &deps.storage)
+            .unwrap();
+        assert_eq!(1, res.messages.len());
+        assert_eq!(
+            Uint128::new(400_000_000_000),
+            account.load_balance(&deps.storage).unwrap()
+        );
+
+        // only the mixnet contract can report the returned tokens
+        let info = mock_info("not-mixnet", &[]);
+        let msg = ExecuteMsg::TrackDecreaseMixnodePledge {
+            owner: account.owner_address().into_string(),
+            amount: coin(200_000_000_000),
+        };
+        assert!(execute(deps.as_mut(), env, info, msg).is_err());
+
+        account
+            .try_track_decrease_mixnode_pledge(coin(200_000_000_000), &mut deps.storage)
+            .unwrap();
+        assert_eq!(
+            Uint128::new(600_000_000_000),
+            account.load_balance(&deps.storage).unwrap()
+        );
+        assert_eq!(
+            Uint128::new(400_000_000_000),
+            account
+                .load_mixnode_pledge(&deps.storage)
+                .unwrap()
+                .unwrap()
+                .amount
+        );
+
+        // the mixnet pledge might include compounded rewards we're not tracking,
+        // so more than the tracked pledge might get returned
+        account
+            .try_track_decrease_mixnode_pledge(coin(450_000_000_000), &mut deps.storage)
+            .unwrap();
+        assert_eq!(
+            Uint128::new(1_050_000_000_000),
+            account.load_balance(&deps.storage).unwrap()
+        );
+        assert_eq!(
+            Uint128::zero(),
+            account
+                .load_mixnode_pledge(&deps.storage)
+                .unwrap()
+                .unwrap()
+                .amount
+        );
+    }
+
+    #[test]
+    fn test_slashed_stake_tracking() {
+        let mut deps = init_contract();
+        let mut env = mock_env();
+
+        let account = vesting_account_fixture(&mut deps.storage, &env);
+        let coin = |amount| Coin {
+            amount: Uint128::new(amount),
+            denom: DENOM.to_string(),
+        };
+
+        account
+            .try_bond_mixnode(
+                mix_node_fixture("identity"),
+                "alice".to_string(),
+                coin(500_000_000_000),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+        account
+            .try_delegate_to_mixnode(
+                "bob".to_string(),
+                coin(100_000_000_000),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+        env.block.height += 1;
+        account
+            .try_delegate_to_mixnode(
+                "bob".to_string(),
+                coin(200_000_000_000),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+
+        // only the mixnet contract can report the slashing
+        let info = mock_info("not-mixnet", &[]);
+        let msg = ExecuteMsg::TrackSlashedMixnodePledge {
+            owner: account.owner_address().into_string(),
+            amount: coin(50_000_000_000),
+        };
+        assert!(execute(deps.as_mut(), env.clone(), info, msg).is_err());
+        let info = mock_info("not-mixnet", &[]);
+        let msg = ExecuteMsg::TrackSlashedDelegation {
+            owner: account.owner_address().into_string(),
+            mix_identity: "bob".to_string(),
+            amount: coin(250_000_000_000),
+        };
+        assert!(execute(deps.as_mut(), env, info, msg).is_err());
+
+        // the slashed tokens are gone, so the balance doesn't change
+        account
+            .try_track_slashed_mixnode_pledge(coin(50_000_000_000), &mut deps.storage)
+            .unwrap();
+        assert_eq!(
+            Uint128::new(450_000_000_000),
+            account
+                .load_mixnode_pledge(&deps.storage)
+                .unwrap()
+                .unwrap()
+                .amount
+        );
+        assert_eq!(
+            Uint128::new(200_000_000_000),
+            account.load_balance(&deps.storage).unwrap()
+        );
+
+        // the most recent delegation is slashed first
+        account
+            .track_slashed_delegation("bob".to_string(), coin(250_000_000_000), &mut deps.storage)
+            .unwrap();
+        assert_eq!(
+            Uint128::new(50_000_000_000),
+            account
+                .total_delegations_for_mix("bob".to_string(), &deps.storage)
+                .unwrap()
+        );
+        assert_eq!(
+            Uint128::new(200_000_000_000),
+            account.load_balance(&deps.storage).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_reward_claims() {
+        let mut deps = init_contract();
+        let env = mock_env();
+
+        let account = vesting_account_fixture(&mut deps.storage, &env);
+
+        // claims are simply forwarded to the mixnet contract
+        for msg in [
+            ExecuteMsg::ClaimOperatorReward {},
+            ExecuteMsg::ClaimDelegatorReward {
+                mix_identity: "alice".to_string(),
+            },
+            ExecuteMsg::CompoundDelegatorReward {
+                mix_identity: "alice".to_string(),
+            },
+            ExecuteMsg::ClaimGatewayOperatorReward {},
+            ExecuteMsg::ClaimGatewayDelegatorReward {
+                gateway_identity: "bob".to_string(),
+            },
+            ExecuteMsg::CompoundGatewayDelegatorReward {
+                gateway_identity: "bob".to_string(),
+            },
+        ] {
+            let res = execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+            assert_eq!(1, res.messages.len());
+        }
+
+        // and they require an existing account
+        let msg = ExecuteMsg::ClaimOperatorReward {};
+        assert!(execute(deps.as_mut(), env.clone(), mock_info("not-owner", &[]), msg).is_err());
+
+        // only the mixnet contract can report the claimed rewards
+        let msg = ExecuteMsg::TrackReward {
+            owner: account.owner_address().into_string(),
+            amount: Coin {
+                amount: Uint128::new(1_000_000),
+                denom: DENOM.to_string(),
+            },
+        };
+        assert!(execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("not-mixnet", &[]),
+            msg.clone()
+        )
+        .is_err());
+
+        execute(deps.as_mut(), env, mock_info("test", &[]), msg).unwrap();
+        assert_eq!(
+            Uint128::new(1_000_001_000_000),
+            account.load_balance(&deps.storage).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_vesting_cliff() {
+        let mut deps = init_contract();
+        let env = mock_env();
+        let vesting_period = 3 * 30 * 86400;
+        let cliff = 2 * vesting_period + 100;
+
+        let account = vesting_account_with_spec_fixture(
+            &mut deps.storage,
+            &env,
+            VestingSpecification::default().with_cliff(cliff),
+        );
+        let start_time = account.start_time().seconds();
+        let tokens_per_period = account.tokens_per_period().unwrap();
+
+        // two periods are already over, but the cliff is not
+        let block_time = Timestamp::from_seconds(start_time + cliff - 1);
+        assert_eq!(
+            Period::In(2),
+            account.get_current_vesting_period(block_time)
+        );
+        let vested_coins = account.get_vested_coins(Some(block_time), &env).unwrap();
+        assert_eq!(Uint128::zero(), vested_coins.amount);
+        let locked_coins = account
+            .locked_coins(Some(block_time), &env, &deps.storage)
+            .unwrap();
+        assert_eq!(account.get_original_vesting().amount, locked_coins.amount);
+
+        // everything that was vested during the cliff is released at once
+        let block_time = Timestamp::from_seconds(start_time + cliff);
+        let vested_coins = account.get_vested_coins(Some(block_time), &env).unwrap();
+        assert_eq!(Uint128::new(2 * tokens_per_period), vested_coins.amount);
+
+        let block_time = Timestamp::from_seconds(start_time + 3 * vesting_period + 1);
+        let vested_coins = account.get_vested_coins(Some(block_time), &env).unwrap();
+        assert_eq!(Uint128::new(3 * tokens_per_period), vested_coins.amount);
+    }
+
+    #[test]
+    fn test_custom_vesting_amounts() {
+        let mut deps = init_contract();
+        let env = mock_env();
+        let vesting_period = 3 * 30 * 86400;
+        let amounts = vec![
+            Uint128::new(100_000_000_000),
+            Uint128::zero(),
+            Uint128::new(300_000_000_000),
+            Uint128::new(600_000_000_000),
+        ];
+
+        let account = vesting_account_with_spec_fixture(
+            &mut deps.storage,
+            &env,
+            VestingSpecification::default()
+                .with_schedule(VestingSchedule::CustomAmounts(amounts.clone())),
+        );
+        let start_time = account.start_time().seconds();
+
+        // there's a period for each of the amounts
+        assert_eq!(4, account.num_vesting_periods());
+
+        let mut expected = Uint128::zero();
+        for (i, amount) in amounts.iter().enumerate() {
+            let block_time = Timestamp::from_seconds(start_time + i as u64 * vesting_period + 1);
+            let vested_coins = account.get_vested_coins(Some(block_time), &env).unwrap();
+            assert_eq!(expected, vested_coins.amount);
+            expected += amount;
+        }
+
+        let vested_coins = account
+            .get_vested_coins(Some(account.get_end_time().plus_seconds(1)), &env)
+            .unwrap();
+        assert_eq!(account.get_original_vesting().amount, vested_coins.amount);
+    }
+
+    #[test]
+    fn test_linear_vesting() {
+        let mut deps = init_contract();
+        let env = mock_env();
+
+        let account = vesting_account_with_spec_fixture(
+            &mut deps.storage,
+            &env,
+            VestingSpecification::new(None, Some(1000), Some(4))
+                .with_schedule(VestingSchedule::Linear),
+        );
+        let start_time = account.start_time().seconds();
+
+        let vested_coins = account
+            .get_vested_coins(Some(Timestamp::from_seconds(start_time + 500)), &env)
+            .unwrap();
+        assert_eq!(Uint128::new(125_000_000_000), vested_coins.amount);
+
+        let vested_coins = account
+            .get_vested_coins(Some(Timestamp::from_seconds(start_time + 2000)), &env)
+            .unwrap();
+        assert_eq!(Uint128::new(500_000_000_000), vested_coins.amount);
+
+        let vesting_coins = account
+            .get_vesting_coins(Some(Timestamp::from_seconds(start_time + 3999)), &env)
+            .unwrap();
+        assert_eq!(Uint128::new(250_000_000), vesting_coins.amount);
+
+        let vested_coins = account
+            .get_vested_coins(Some(Timestamp::from_seconds(start_time + 4001)), &env)
+            .unwrap();
+        assert_eq!(account.get_original_vesting().amount, vested_coins.amount);
+    }
+
+    #[test]
+    fn test_invalid_vesting_specifications() {
+        let mut deps = init_contract();
+        let env = mock_env();
+        let info = mock_info("admin", &coins(1_000_000_000_000, DENOM));
+
+        let create_account = |vesting_spec| ExecuteMsg::CreateAccount {
+            owner_address: "owner".to_string(),
+            staking_address: None,
+            vesting_spec: Some(vesting_spec),
+        };
+
+        let msg = create_account(VestingSpecification::new(None, None, Some(0)));
+        let response = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(Err(ContractError::NoVestingPeriods), response);
+
+        let msg = create_account(
+            VestingSpecification::new(None, Some(0), Some(4))
+                .with_schedule(VestingSchedule::Linear),
+        );
+        let response = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(Err(ContractError::ZeroVestingPeriodLength), response);
+
+        let msg =
+            create_account(VestingSpecification::new(None, Some(1000), Some(4)).with_cliff(4001));
+        let response = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(Err(ContractError::CliffTooLong(4001, 4000)), response);
+
+        let msg = create_account(VestingSpecification::new(
+            Some(1000),
+            Some(u64::MAX / 2),
+            Some(3),
+        ));
+        let response = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(
+            Err(ContractError::VestingDurationOverflow(
+                u64::MAX / 2,
+                3,
+                1000
+            )),
+            response
+        );
+
+        let msg = create_account(
+            VestingSpecification::new(None, None, Some(3)).with_schedule(
+                VestingSchedule::CustomAmounts(vec![Uint128::new(1_000_000_000_000)]),
+            ),
+        );
+        let response = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(
+            Err(ContractError::InvalidNumberOfVestingAmounts(3, 1)),
+            response
+        );
+
+        let msg = create_account(VestingSpecification::default().with_schedule(
+            VestingSchedule::CustomAmounts(vec![Uint128::new(1), Uint128::new(2)]),
+        ));
+        let response = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(
+            Err(ContractError::VestingAmountsMismatch(3, 1_000_000_000_000)),
+            response
+        );
+
+        // and nothing got created
+        assert!(load_account(&Addr::unchecked("owner"), &deps.storage)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_single_pledge_per_node_kind() {
+        let mut deps = init_contract();
+        let env = mock_env();
+
+        let account = vesting_account_fixture(&mut deps.storage, &env);
+        let coin = |amount| Coin {
+            amount: Uint128::new(amount),
+            denom: DENOM.to_string(),
+        };
+
+        account
+            .try_bond_mixnode(
+                mix_node_fixture("mix1"),
+                "alice".to_string(),
+                coin(100_000_000_000),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+        account
+            .try_bond_gateway(
+                gateway_fixture("gateway1"),
+                "alice".to_string(),
+                coin(300_000_000_000),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+
+        // the mixnet contract allows a single node of each kind per owner
+        assert_eq!(
+            Err(ContractError::AlreadyBonded("owner".to_string())),
+            account.try_bond_mixnode(
+                mix_node_fixture("mix2"),
+                "alice".to_string(),
+                coin(100_000_000_000),
+                &env,
+                &mut deps.storage,
+            )
+        );
+        assert_eq!(
+            Err(ContractError::AlreadyBonded("owner".to_string())),
+            account.try_bond_gateway(
+                gateway_fixture("gateway2"),
+                "alice".to_string(),
+                coin(100_000_000_000),
+                &env,
+                &mut deps.storage,
+            )
+        );
+        assert_eq!(
+            Uint128::new(600_000_000_000),
+            account.load_balance(&deps.storage).unwrap()
+        );
+
+        // both pledges are accounted for
+        let pledged_vesting = account
+            .get_pledged_vesting(None, &env, &deps.storage)
+            .unwrap();
+        assert_eq!(Uint128::new(400_000_000_000), pledged_vesting.amount);
+
+        // and the pledge increase applies to the only mixnode
+        account
+            .try_increase_mixnode_pledge(coin(100_000_000_000), &mut deps.storage)
+            .unwrap();
+        assert_eq!(
+            Uint128::new(200_000_000_000),
+            account
+                .load_mixnode_pledge(&deps.storage)
+                .unwrap()
+                .unwrap()
+                .amount
+        );
+
+        // once the unbonded pledge is returned, another mixnode can be bonded
+        account
+            .try_track_unbond_mixnode(coin(200_000_000_000), &mut deps.storage)
+            .unwrap();
+        account
+            .try_bond_mixnode(
+                mix_node_fixture("mix2"),
+                "alice".to_string(),
+                coin(100_000_000_000),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+        assert_eq!(
+            Uint128::new(100_000_000_000),
+            account
+                .load_mixnode_pledge(&deps.storage)
+                .unwrap()
+                .unwrap()
+                .amount
+        );
+        assert_eq!(
+            Uint128::new(600_000_000_000),
+            account.load_balance(&deps.storage).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_gateway_delegations() {
+        let mut deps = init_contract();
+        let env = mock_env();
+
+        let account = vesting_account_fixture(&mut deps.storage, &env);
+        let coin = |amount| Coin {
+            amount: Uint128::new(amount),
+            denom: DENOM.to_string(),
+        };
+
+        // nothing to undelegate yet
+        assert!(account
+            .try_undelegate_from_gateway("gateway".to_string(), &deps.storage)
+            .is_err());
+
+        assert!(account
+            .try_delegate_to_gateway(
+                "gateway".to_string(),
+                coin(1_000_000_000_001),
+                &env,
+                &mut deps.storage
+            )
+            .is_err());
+
+        let res = account
+            .try_delegate_to_gateway(
+                "gateway".to_string(),
+                coin(300_000_000_000),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+        assert_eq!(1, res.messages.len());
+        account
+            .try_delegate_to_mixnode(
+                "alice".to_string(),
+                coin(200_000_000_000),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+
+        assert_eq!(
+            Uint128::new(500_000_000_000),
+            account.load_balance(&deps.storage).unwrap()
+        );
+        // gateway delegations count towards the delegated coins
+        assert_eq!(
+            Uint128::new(500_000_000_000),
+            account.total_delegations(&deps.storage).unwrap()
+        );
+        assert_eq!(
+            Uint128::new(500_000_000_000),
+            account
+                .get_delegated_vesting(None, &env, &deps.storage)
+                .unwrap()
+                .amount
+        );
+
+        let res = account
+            .try_undelegate_from_gateway("gateway".to_string(), &deps.storage)
+            .unwrap();
+        assert_eq!(1, res.messages.len());
+
+        // only the mixnet contract can report the returned tokens
+        let msg = ExecuteMsg::TrackGatewayUndelegation {
+            owner: account.owner_address().into_string(),
+            gateway_identity: "gateway".to_string(),
+            amount: coin(300_000_000_000),
+        };
+        assert!(execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("not-mixnet", &[]),
+            msg.clone()
+        )
+        .is_err());
+        execute(deps.as_mut(), env, mock_info("test", &[]), msg).unwrap();
+
+        assert!(!account.any_delegation_for_gateway("gateway", &deps.storage));
+        assert_eq!(
+            Uint128::new(800_000_000_000),
+            account.load_balance(&deps.storage).unwrap()
+        );
+        assert_eq!(
+            Uint128::new(200_000_000_000),
+            account.total_delegations(&deps.storage).unwrap()
+        );
+    }
 }
@@ -11,6 +11,8 @@ use crate::node::storage::PersistentStorage;
 use crypto::asymmetric::{encryption, identity};
 use log::*;
 use mixnet_client::forwarder::{MixForwardingSender, PacketForwarder};
+use mixnode_common::verloc;
+use network_defaults::DEFAULT_VERLOC_LISTENING_PORT;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
 use std::net::SocketAddr;
@@ -174,6 +176,18 @@ impl Gateway {
         );
     }
 
+    fn start_verloc_echo_listener(&self) {
+        info!("Starting verloc echo listener...");
+
+        // gateways do not announce their verloc port, so the default one has to be used
+        let listening_address = SocketAddr::new(
+            self.config.get_listening_address(),
+            DEFAULT_VERLOC_LISTENING_PORT,
+        );
+
+        verloc::start_echo_listener(listening_address, Arc::clone(&self.identity_keypair));
+    }
+
     fn start_packet_forwarder(&self) -> MixForwardingSender {
         info!("Starting mix packet forwarder...");
 
@@ -269,6 +283,8 @@ impl Gateway {
             erc20_bridge,
         );
 
+        self.start_verloc_echo_listener();
+
         info!("Finished nym gateway startup procedure - it should now be able to receive mix and client traffic!");
 
         self.wait_for_interrupt().await
@@ -0,0 +1,28 @@
+/*
+ * Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+CREATE TABLE stats_snapshot
+(
+    id                   INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    timestamp            INTEGER NOT NULL,
+    interval_seconds     INTEGER NOT NULL,
+    packets_received     INTEGER NOT NULL,
+    packets_rate_limited INTEGER NOT NULL,
+    connections_rejected INTEGER NOT NULL
+);
+
+CREATE TABLE peer_stats
+(
+    id              INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    snapshot_id     INTEGER NOT NULL,
+    peer            TEXT    NOT NULL,
+    packets_sent    INTEGER NOT NULL,
+    packets_dropped INTEGER NOT NULL,
+
+    FOREIGN KEY (snapshot_id) REFERENCES stats_snapshot (id) ON DELETE CASCADE
+);
+
+CREATE INDEX `stats_snapshot_timestamp_index` ON `stats_snapshot` (`timestamp`);
+CREATE INDEX `peer_stats_snapshot_index` ON `peer_stats` (`snapshot_id`);
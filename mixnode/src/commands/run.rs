@@ -3,9 +3,10 @@
 
 use crate::commands::{override_config, version_check};
 use crate::config::Config;
-use crate::node::MixNode;
+use crate::node::{MixNode, ShutdownReason};
 use clap::Args;
 use config::NymConfig;
+use std::process::{self, Command};
 
 use super::OverrideConfig;
 
@@ -70,6 +71,38 @@ fn show_binding_warning(address: String) {
     println!("\n\n");
 }
 
+// replaces the current process with a fresh instance of the mixnode started with the same arguments,
+// so that all sockets are released and the config alongside the keys are reloaded from disk
+fn restart_process() -> ! {
+    let executable = match std::env::current_exe() {
+        Ok(executable) => executable,
+        Err(err) => {
+            error!("Failed to determine the current executable - {}", err);
+            process::exit(1)
+        }
+    };
+    let mut command = Command::new(executable);
+    command.args(std::env::args_os().skip(1));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // exec only returns if it failed
+        let err = command.exec();
+        error!("Failed to restart the mixnode - {}", err);
+        process::exit(1)
+    }
+
+    #[cfg(not(unix))]
+    {
+        if let Err(err) = command.spawn() {
+            error!("Failed to restart the mixnode - {}", err);
+            process::exit(1)
+        }
+        process::exit(0)
+    }
+}
+
 fn special_addresses() -> Vec<&'static str> {
     vec!["localhost", "127.0.0.1", "0.0.0.0", "::1", "[::1]"]
 }
@@ -107,5 +140,7 @@ pub(crate) async fn execute(args: &Run) {
          Select the correct version and install it to your machine. You will need to provide the following: \n ");
     mixnode.print_node_details();
 
-    mixnode.run().await
+    if mixnode.run().await == ShutdownReason::RestartRequested {
+        restart_process()
+    }
 }
@@ -24,6 +24,25 @@ const DEFAULT_DELAY_BETWEEN_PACKETS: Duration = Duration::from_millis(50);
 const DEFAULT_BATCH_SIZE: usize = 50;
 const DEFAULT_TESTING_INTERVAL: Duration = Duration::from_secs(60 * 60 * 12);
 const DEFAULT_RETRY_TIMEOUT: Duration = Duration::from_secs(60 * 30);
+const DEFAULT_MEASURE_GATEWAYS: bool = true;
+
+// 'RATE LIMITING'
+const DEFAULT_RATE_LIMITING_ENABLED: bool = true;
+const DEFAULT_MAXIMUM_CONNECTIONS_PER_IP: usize = 32;
+const DEFAULT_MAXIMUM_PACKETS_PER_SECOND_PER_IP: u32 = 5_000;
+const DEFAULT_MAXIMUM_PACKET_BURST_PER_IP: u32 = 10_000;
+const DEFAULT_ALLOWLIST_TOPOLOGY_PEERS: bool = true;
+const DEFAULT_ALLOWLIST_REFRESH_RATE: Duration = Duration::from_secs(60 * 5);
+
+// 'STATISTICS'
+const DEFAULT_STATS_HISTORY_ENABLED: bool = true;
+const DEFAULT_STATS_HISTORY_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(60 * 10);
+const DEFAULT_STATS_HISTORY_RETENTION: Duration = Duration::from_secs(60 * 60 * 24 * 30);
+
+// 'ADMIN API'
+const DEFAULT_ADMIN_API_ENABLED: bool = false;
+const DEFAULT_ADMIN_API_LISTENING_PORT: u16 = 8001;
+const DEFAULT_ADMIN_SIGNATURE_VALIDITY: Duration = Duration::from_secs(60);
 
 // 'DEBUG'
 const DEFAULT_NODE_STATS_LOGGING_DELAY: Duration = Duration::from_millis(60_000);
@@ -49,6 +68,10 @@ fn default_verloc_port() -> u16 {
     DEFAULT_VERLOC_LISTENING_PORT
 }
 
+fn default_measure_gateways() -> bool {
+    DEFAULT_MEASURE_GATEWAYS
+}
+
 fn default_http_api_port() -> u16 {
     DEFAULT_HTTP_API_LISTENING_PORT
 }
@@ -77,6 +100,12 @@ pub struct Config {
     #[serde(default)]
     verloc: Verloc,
     #[serde(default)]
+    rate_limiting: RateLimiting,
+    #[serde(default)]
+    statistics: Statistics,
+    #[serde(default)]
+    admin: Admin,
+    #[serde(default)]
     logging: Logging,
     #[serde(default)]
     debug: Debug,
@@ -144,6 +173,10 @@ impl Config {
                 self::MixNode::default_public_sphinx_key_file(&id);
         }
 
+        if self.mixnode.persistent_storage.as_os_str().is_empty() {
+            self.mixnode.persistent_storage = self::MixNode::default_database_path(&id);
+        }
+
         self.mixnode.id = id;
         self
     }
@@ -226,6 +259,15 @@ impl Config {
         self.mixnode.public_sphinx_key_file.clone()
     }
 
+    pub fn get_persistent_store_path(&self) -> PathBuf {
+        // configs created before the storage got introduced won't have the path set
+        if self.mixnode.persistent_storage.as_os_str().is_empty() {
+            self::MixNode::default_database_path(&self.mixnode.id)
+        } else {
+            self.mixnode.persistent_storage.clone()
+        }
+    }
+
     pub fn get_validator_api_endpoints(&self) -> Vec<Url> {
         self.mixnode.validator_api_urls.clone()
     }
@@ -306,6 +348,62 @@ impl Config {
         self.verloc.retry_timeout
     }
 
+    pub fn get_measure_gateways(&self) -> bool {
+        self.verloc.measure_gateways
+    }
+
+    pub fn get_rate_limiting_enabled(&self) -> bool {
+        self.rate_limiting.enabled
+    }
+
+    pub fn get_maximum_connections_per_ip(&self) -> usize {
+        self.rate_limiting.maximum_connections_per_ip
+    }
+
+    pub fn get_maximum_packets_per_second_per_ip(&self) -> u32 {
+        self.rate_limiting.maximum_packets_per_second_per_ip
+    }
+
+    pub fn get_maximum_packet_burst_per_ip(&self) -> u32 {
+        self.rate_limiting.maximum_packet_burst_per_ip
+    }
+
+    pub fn get_allowlist_topology_peers(&self) -> bool {
+        self.rate_limiting.allowlist_topology_peers
+    }
+
+    pub fn get_allowlist_refresh_rate(&self) -> Duration {
+        self.rate_limiting.allowlist_refresh_rate
+    }
+
+    pub fn get_stats_history_enabled(&self) -> bool {
+        self.statistics.history_enabled
+    }
+
+    pub fn get_stats_history_snapshot_interval(&self) -> Duration {
+        self.statistics.history_snapshot_interval
+    }
+
+    pub fn get_stats_history_retention(&self) -> Duration {
+        self.statistics.history_retention
+    }
+
+    pub fn get_admin_api_enabled(&self) -> bool {
+        self.admin.enabled
+    }
+
+    pub fn get_admin_api_port(&self) -> u16 {
+        self.admin.port
+    }
+
+    pub fn get_admin_api_token(&self) -> Option<&str> {
+        self.admin.token.as_deref()
+    }
+
+    pub fn get_admin_signature_validity(&self) -> Duration {
+        self.admin.signature_validity
+    }
+
     pub fn get_wallet_address(&self) -> &str {
         &self.mixnode.wallet_address
     }
@@ -358,6 +456,10 @@ struct MixNode {
     /// Path to file containing public sphinx key.
     public_sphinx_key_file: PathBuf,
 
+    /// Path to sqlite database containing all persistent data, such as the historical statistics.
+    #[serde(default)]
+    persistent_storage: PathBuf,
+
     /// Addresses to APIs running on validator from which the node gets the view of the network.
     validator_api_urls: Vec<Url>,
 
@@ -385,6 +487,10 @@ impl MixNode {
     fn default_public_sphinx_key_file(id: &str) -> PathBuf {
         Config::default_data_directory(Some(id)).join("public_sphinx.pem")
     }
+
+    fn default_database_path(id: &str) -> PathBuf {
+        Config::default_data_directory(Some(id)).join("db.sqlite")
+    }
 }
 
 impl Default for MixNode {
@@ -401,6 +507,7 @@ impl Default for MixNode {
             public_identity_key_file: Default::default(),
             private_sphinx_key_file: Default::default(),
             public_sphinx_key_file: Default::default(),
+            persistent_storage: Default::default(),
             validator_api_urls: default_api_endpoints(),
             nym_root_directory: Config::default_root_directory(),
             wallet_address: "nymXXXXXXXX".to_string(),
@@ -436,6 +543,10 @@ struct Verloc {
     /// Specifies delay between attempting to run the measurement again if the previous run failed
     /// due to being unable to get the list of nodes.
     retry_timeout: Duration,
+
+    /// Specifies whether gateways should also be measured alongside mixnodes.
+    #[serde(default = "default_measure_gateways")]
+    measure_gateways: bool,
 }
 
 impl Default for Verloc {
@@ -448,6 +559,102 @@ impl Default for Verloc {
             tested_nodes_batch_size: DEFAULT_BATCH_SIZE,
             testing_interval: DEFAULT_TESTING_INTERVAL,
             retry_timeout: DEFAULT_RETRY_TIMEOUT,
+            measure_gateways: DEFAULT_MEASURE_GATEWAYS,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+struct RateLimiting {
+    /// Specifies whether the limits on inbound connections and packets should be enforced at all.
+    enabled: bool,
+
+    /// Maximum number of concurrent mix connections accepted from a single IP address.
+    maximum_connections_per_ip: usize,
+
+    /// Maximum sustained rate of sphinx packets accepted from a single IP address,
+    /// across all of its connections.
+    maximum_packets_per_second_per_ip: u32,
+
+    /// Maximum number of sphinx packets a single IP address is allowed to send in a short burst
+    /// above the sustained rate.
+    maximum_packet_burst_per_ip: u32,
+
+    /// Specifies whether addresses of mixnodes and gateways present in the network topology
+    /// should be exempt from the limits.
+    allowlist_topology_peers: bool,
+
+    /// Delay between subsequent refreshes of the allowlisted topology peers.
+    #[serde(with = "humantime_serde")]
+    allowlist_refresh_rate: Duration,
+}
+
+impl Default for RateLimiting {
+    fn default() -> Self {
+        RateLimiting {
+            enabled: DEFAULT_RATE_LIMITING_ENABLED,
+            maximum_connections_per_ip: DEFAULT_MAXIMUM_CONNECTIONS_PER_IP,
+            maximum_packets_per_second_per_ip: DEFAULT_MAXIMUM_PACKETS_PER_SECOND_PER_IP,
+            maximum_packet_burst_per_ip: DEFAULT_MAXIMUM_PACKET_BURST_PER_IP,
+            allowlist_topology_peers: DEFAULT_ALLOWLIST_TOPOLOGY_PEERS,
+            allowlist_refresh_rate: DEFAULT_ALLOWLIST_REFRESH_RATE,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+struct Statistics {
+    /// Specifies whether periodic snapshots of the node statistics should be persisted
+    /// so that they would survive restarts.
+    history_enabled: bool,
+
+    /// Delay between subsequent statistics snapshots being persisted.
+    #[serde(with = "humantime_serde")]
+    history_snapshot_interval: Duration,
+
+    /// Specifies how long the persisted snapshots are kept before getting removed.
+    #[serde(with = "humantime_serde")]
+    history_retention: Duration,
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Statistics {
+            history_enabled: DEFAULT_STATS_HISTORY_ENABLED,
+            history_snapshot_interval: DEFAULT_STATS_HISTORY_SNAPSHOT_INTERVAL,
+            history_retention: DEFAULT_STATS_HISTORY_RETENTION,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+struct Admin {
+    /// Specifies whether the administrative HTTP API should be started. It is always bound
+    /// to the loopback interface.
+    enabled: bool,
+
+    /// Port used for the administrative HTTP API.
+    port: u16,
+
+    /// Optional bearer token that can be used to authenticate admin requests. If not set,
+    /// requests have to be signed with the node identity key instead.
+    token: Option<String>,
+
+    /// Maximum age of a signed admin request before it gets rejected.
+    #[serde(with = "humantime_serde")]
+    signature_validity: Duration,
+}
+
+impl Default for Admin {
+    fn default() -> Self {
+        Admin {
+            enabled: DEFAULT_ADMIN_API_ENABLED,
+            port: DEFAULT_ADMIN_API_LISTENING_PORT,
+            token: None,
+            signature_validity: DEFAULT_ADMIN_SIGNATURE_VALIDITY,
         }
     }
 }
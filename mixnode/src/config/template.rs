@@ -60,6 +60,9 @@ validator_api_urls = [
     {{/each}}
 ]
 
+# Path to sqlite database containing all persistent data, such as the historical statistics.
+persistent_storage = '{{ mixnode.persistent_storage }}'
+
 # Nym wallet address on the blockchain that should control this mixnode
 wallet_address = '{{ mixnode.wallet_address }}'
 
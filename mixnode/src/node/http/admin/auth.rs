@@ -0,0 +1,331 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::asymmetric::identity;
+use rocket::data::{self, Data, FromData, ToByteUnit};
+use rocket::http::{HeaderMap, Status};
+use rocket::outcome::Outcome;
+use rocket::Request;
+use serde::de::DeserializeOwned;
+use std::io;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+pub(crate) const TIMESTAMP_HEADER: &str = "X-Nym-Admin-Timestamp";
+pub(crate) const SIGNATURE_HEADER: &str = "X-Nym-Admin-Signature";
+
+const BEARER_PREFIX: &str = "Bearer ";
+
+#[derive(Debug, Error)]
+pub(crate) enum AdminAuthError {
+    #[error("the request contained neither a valid token nor a signature")]
+    MissingCredentials,
+
+    #[error("the provided token is invalid")]
+    InvalidToken,
+
+    #[error("the request timestamp is malformed")]
+    MalformedTimestamp,
+
+    #[error("the request has expired or its timestamp is too far in the future")]
+    ExpiredRequest,
+
+    #[error("the request signature is malformed")]
+    MalformedSignature,
+
+    #[error("the request signature is invalid")]
+    InvalidSignature,
+
+    #[error("the request body is too large")]
+    PayloadTooLarge,
+
+    #[error("failed to read the request body - {0}")]
+    Io(#[from] io::Error),
+
+    #[error("the request body is malformed - {0}")]
+    MalformedBody(#[from] serde_json::Error),
+}
+
+impl AdminAuthError {
+    fn status(&self) -> Status {
+        match self {
+            AdminAuthError::PayloadTooLarge => Status::PayloadTooLarge,
+            AdminAuthError::Io(_) => Status::InternalServerError,
+            AdminAuthError::MalformedBody(_) => Status::UnprocessableEntity,
+            _ => Status::Unauthorized,
+        }
+    }
+}
+
+/// Checks whether requests made to the admin API come from the node operator. This is the case
+/// if either the request contains the bearer token set in the config or it has been signed
+/// with the identity key of the node.
+///
+/// The signature has to be made over `"<timestamp>:<METHOD>:<path>:<body>"`, where `timestamp`
+/// is the current unix timestamp also put in the `X-Nym-Admin-Timestamp` header. It can be
+/// produced with the `sign --text` command.
+pub(crate) struct AdminAuthenticator {
+    token: Option<String>,
+    identity: identity::PublicKey,
+    signature_validity: Duration,
+}
+
+impl AdminAuthenticator {
+    pub(crate) fn new(
+        token: Option<String>,
+        identity: identity::PublicKey,
+        signature_validity: Duration,
+    ) -> Self {
+        AdminAuthenticator {
+            // treat empty token the same way as if it was not set at all
+            token: token.filter(|token| !token.is_empty()),
+            identity,
+            signature_validity,
+        }
+    }
+
+    pub(crate) fn signable_message(
+        timestamp: i64,
+        method: &str,
+        path: &str,
+        body: &[u8],
+    ) -> Vec<u8> {
+        let mut message = format!("{}:{}:{}:", timestamp, method, path).into_bytes();
+        message.extend_from_slice(body);
+        message
+    }
+
+    fn check_token(&self, provided: &str) -> Result<(), AdminAuthError> {
+        let expected = match &self.token {
+            Some(token) => token,
+            None => return Err(AdminAuthError::InvalidToken),
+        };
+
+        // compare in constant time so that the token couldn't be guessed byte by byte
+        let provided = provided.as_bytes();
+        let expected = expected.as_bytes();
+        if provided.len() != expected.len() {
+            return Err(AdminAuthError::InvalidToken);
+        }
+        let difference = provided
+            .iter()
+            .zip(expected.iter())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+
+        if difference == 0 {
+            Ok(())
+        } else {
+            Err(AdminAuthError::InvalidToken)
+        }
+    }
+
+    fn check_signature(
+        &self,
+        timestamp: &str,
+        signature: &str,
+        method: &str,
+        path: &str,
+        body: &[u8],
+    ) -> Result<(), AdminAuthError> {
+        let timestamp: i64 = timestamp
+            .parse()
+            .map_err(|_| AdminAuthError::MalformedTimestamp)?;
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("the system clock is set to before the unix epoch")
+            .as_secs() as i64;
+        if (now - timestamp).unsigned_abs() > self.signature_validity.as_secs() {
+            return Err(AdminAuthError::ExpiredRequest);
+        }
+
+        let signature = identity::Signature::from_base58_string(signature)
+            .map_err(|_| AdminAuthError::MalformedSignature)?;
+
+        let message = Self::signable_message(timestamp, method, path, body);
+        self.identity
+            .verify(&message, &signature)
+            .map_err(|_| AdminAuthError::InvalidSignature)
+    }
+
+    pub(crate) fn authenticate(
+        &self,
+        headers: &HeaderMap<'_>,
+        method: &str,
+        path: &str,
+        body: &[u8],
+    ) -> Result<(), AdminAuthError> {
+        if let Some(authorization) = headers.get_one("Authorization") {
+            if let Some(token) = authorization.strip_prefix(BEARER_PREFIX) {
+                return self.check_token(token);
+            }
+        }
+
+        match (
+            headers.get_one(TIMESTAMP_HEADER),
+            headers.get_one(SIGNATURE_HEADER),
+        ) {
+            (Some(timestamp), Some(signature)) => {
+                self.check_signature(timestamp, signature, method, path, body)
+            }
+            _ => Err(AdminAuthError::MissingCredentials),
+        }
+    }
+}
+
+/// Data guard for the admin routes. It authenticates the request, including its entire body,
+/// and only then attempts to deserialize the body as json. An empty body is treated as `null`.
+pub(crate) struct Authenticated<T>(pub(crate) T);
+
+impl<T> Authenticated<T> {
+    pub(crate) fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[rocket::async_trait]
+impl<'r, T: DeserializeOwned> FromData<'r> for Authenticated<T> {
+    type Error = AdminAuthError;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        let authenticator = req
+            .rocket()
+            .state::<AdminAuthenticator>()
+            .expect("the admin authenticator is not managed by the rocket instance");
+
+        let limit = req.limits().get("json").unwrap_or_else(|| 1.mebibytes());
+        let body = match data.open(limit).into_bytes().await {
+            Ok(body) if body.is_complete() => body.into_inner(),
+            Ok(_) => {
+                return Outcome::Failure((Status::PayloadTooLarge, AdminAuthError::PayloadTooLarge))
+            }
+            Err(err) => return Outcome::Failure((Status::InternalServerError, err.into())),
+        };
+
+        let method = req.method().as_str();
+        let path = req.uri().path().as_str();
+        if let Err(err) = authenticator.authenticate(req.headers(), method, path, &body) {
+            warn!("Rejected admin request to {} {} - {}", method, path, err);
+            return Outcome::Failure((err.status(), err));
+        }
+
+        let parsed = if body.is_empty() {
+            serde_json::from_slice(b"null")
+        } else {
+            serde_json::from_slice(&body)
+        };
+
+        match parsed {
+            Ok(value) => Outcome::Success(Authenticated(value)),
+            Err(err) => {
+                let err = AdminAuthError::from(err);
+                Outcome::Failure((err.status(), err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::http::Header;
+
+    fn now() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64
+    }
+
+    fn test_authenticator(token: Option<&str>) -> (AdminAuthenticator, identity::KeyPair) {
+        let keys = identity::KeyPair::new(&mut rand::thread_rng());
+        let authenticator = AdminAuthenticator::new(
+            token.map(ToString::to_string),
+            *keys.public_key(),
+            Duration::from_secs(60),
+        );
+        (authenticator, keys)
+    }
+
+    fn signed_headers(
+        keys: &identity::KeyPair,
+        timestamp: i64,
+        method: &str,
+        path: &str,
+        body: &[u8],
+    ) -> HeaderMap<'static> {
+        let message = AdminAuthenticator::signable_message(timestamp, method, path, body);
+        let signature = keys.private_key().sign(&message).to_base58_string();
+
+        let mut headers = HeaderMap::new();
+        headers.add(Header::new(TIMESTAMP_HEADER, timestamp.to_string()));
+        headers.add(Header::new(SIGNATURE_HEADER, signature));
+        headers
+    }
+
+    #[test]
+    fn requests_with_valid_token_are_accepted() {
+        let (authenticator, _) = test_authenticator(Some("secret-token"));
+
+        let mut headers = HeaderMap::new();
+        headers.add(Header::new("Authorization", "Bearer secret-token"));
+        assert!(authenticator
+            .authenticate(&headers, "POST", "/restart", b"")
+            .is_ok());
+
+        let mut headers = HeaderMap::new();
+        headers.add(Header::new("Authorization", "Bearer secret-tokem"));
+        assert!(authenticator
+            .authenticate(&headers, "POST", "/restart", b"")
+            .is_err());
+    }
+
+    #[test]
+    fn tokens_are_rejected_if_not_configured() {
+        let (authenticator, _) = test_authenticator(Some(""));
+
+        let mut headers = HeaderMap::new();
+        headers.add(Header::new("Authorization", "Bearer "));
+        assert!(authenticator
+            .authenticate(&headers, "POST", "/restart", b"")
+            .is_err());
+        assert!(authenticator
+            .authenticate(&HeaderMap::new(), "POST", "/restart", b"")
+            .is_err());
+    }
+
+    #[test]
+    fn requests_signed_with_identity_key_are_accepted() {
+        let (authenticator, keys) = test_authenticator(None);
+        let body = br#"{"name":"foo"}"#;
+
+        let headers = signed_headers(&keys, now(), "POST", "/description", body);
+        assert!(authenticator
+            .authenticate(&headers, "POST", "/description", body)
+            .is_ok());
+
+        // the signature must cover the body and the path
+        assert!(authenticator
+            .authenticate(&headers, "POST", "/description", br#"{"name":"bar"}"#)
+            .is_err());
+        assert!(authenticator
+            .authenticate(&headers, "POST", "/restart", body)
+            .is_err());
+    }
+
+    #[test]
+    fn stale_or_foreign_signatures_are_rejected() {
+        let (authenticator, keys) = test_authenticator(None);
+
+        let headers = signed_headers(&keys, now() - 120, "POST", "/restart", b"");
+        assert!(authenticator
+            .authenticate(&headers, "POST", "/restart", b"")
+            .is_err());
+
+        let other_keys = identity::KeyPair::new(&mut rand::thread_rng());
+        let headers = signed_headers(&other_keys, now(), "POST", "/restart", b"");
+        assert!(authenticator
+            .authenticate(&headers, "POST", "/restart", b"")
+            .is_err());
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::node::http::admin::auth::Authenticated;
+use crate::node::node_description::{NodeDescription, SharedNodeDescription};
+use futures::channel::mpsc;
+use mixnet_client::{ReconnectionBackoff, ReconnectionBackoffHandle};
+use rocket::http::Status;
+use rocket::response::status::Custom;
+use rocket::serde::json::Json;
+use rocket::State;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+pub(crate) mod auth;
+
+pub(crate) type RestartRequestSender = mpsc::UnboundedSender<()>;
+pub(crate) type RestartRequestReceiver = mpsc::UnboundedReceiver<()>;
+
+pub(crate) struct AdminState {
+    packet_forwarding_backoff: ReconnectionBackoffHandle,
+    restart_sender: RestartRequestSender,
+}
+
+impl AdminState {
+    pub(crate) fn new(
+        packet_forwarding_backoff: ReconnectionBackoffHandle,
+        restart_sender: RestartRequestSender,
+    ) -> Self {
+        AdminState {
+            packet_forwarding_backoff,
+            restart_sender,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct PacketForwardingBackoff {
+    #[serde(with = "humantime_serde")]
+    initial: Duration,
+
+    #[serde(with = "humantime_serde")]
+    maximum: Duration,
+}
+
+impl From<ReconnectionBackoff> for PacketForwardingBackoff {
+    fn from(backoff: ReconnectionBackoff) -> Self {
+        PacketForwardingBackoff {
+            initial: backoff.initial,
+            maximum: backoff.maximum,
+        }
+    }
+}
+
+/// Replaces the description of the node. The change is persisted and immediately visible
+/// on the public `/description` endpoint.
+#[post("/description", data = "<description>")]
+pub(crate) async fn update_description(
+    description: Authenticated<NodeDescription>,
+    state: &State<SharedNodeDescription>,
+) -> Result<Json<NodeDescription>, Custom<String>> {
+    let description = description.into_inner();
+    state.update(description.clone()).await.map_err(|err| {
+        Custom(
+            Status::InternalServerError,
+            format!("failed to save the node description - {}", err),
+        )
+    })?;
+
+    info!("The node description got updated via the admin API");
+    Ok(Json(description))
+}
+
+/// Adjusts the exponential backoff used when reconnecting to nodes we are forwarding packets to.
+/// The change only lasts until the node is restarted.
+#[post("/debug/packet-forwarding-backoff", data = "<backoff>")]
+pub(crate) fn update_packet_forwarding_backoff(
+    backoff: Authenticated<PacketForwardingBackoff>,
+    state: &State<AdminState>,
+) -> Result<Json<PacketForwardingBackoff>, Custom<String>> {
+    let backoff = backoff.into_inner();
+    if backoff.initial.as_millis() == 0 || backoff.initial > backoff.maximum {
+        return Err(Custom(
+            Status::BadRequest,
+            "the initial backoff must be non-zero and not greater than the maximum backoff"
+                .to_string(),
+        ));
+    }
+
+    info!(
+        "Setting packet forwarding backoff to {:?} (initial) and {:?} (maximum)",
+        backoff.initial, backoff.maximum
+    );
+    state.packet_forwarding_backoff.update(ReconnectionBackoff {
+        initial: backoff.initial,
+        maximum: backoff.maximum,
+    });
+
+    Ok(Json(state.packet_forwarding_backoff.current().into()))
+}
+
+/// Requests the node to restart itself. Upon restart the config and keys are reloaded from disk.
+#[post("/restart", data = "<_request>")]
+pub(crate) fn restart(_request: Authenticated<()>, state: &State<AdminState>) -> Status {
+    info!("Received restart request via the admin API");
+    if state.restart_sender.unbounded_send(()).is_err() {
+        // this could only happen if we're already shutting down
+        return Status::ServiceUnavailable;
+    }
+    Status::Accepted
+}
@@ -1,9 +1,11 @@
-use crate::node::node_description::NodeDescription;
+use crate::node::node_description::{NodeDescription, SharedNodeDescription};
 use rocket::serde::json::Json;
 use rocket::State;
 
 /// Returns a description of the node and why someone might want to delegate stake to it.
 #[get("/description")]
-pub(crate) fn description(description: &State<NodeDescription>) -> Json<NodeDescription> {
-    Json(description.inner().clone())
+pub(crate) async fn description(
+    description: &State<SharedNodeDescription>,
+) -> Json<NodeDescription> {
+    Json(description.clone_data().await)
 }
@@ -1,3 +1,4 @@
+pub(crate) mod admin;
 pub(crate) mod description;
 pub(crate) mod stats;
 pub(crate) mod verloc;
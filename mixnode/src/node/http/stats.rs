@@ -1,7 +1,26 @@
 use crate::node::node_statistics::{NodeStats, NodeStatsSimple, SharedNodeStats};
+use crate::node::storage::models::StatsSnapshot;
+use crate::node::storage::PersistentStorage;
+use rocket::http::Status;
+use rocket::response::status::Custom;
 use rocket::serde::json::Json;
 use rocket::State;
 use serde::Serialize;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// if no explicit range is provided, return snapshots from the last day
+const DEFAULT_HISTORY_RANGE: Duration = Duration::from_secs(60 * 60 * 24);
+
+pub(crate) struct StatsHistoryState {
+    // it's only present if persisting the history is enabled in the config
+    storage: Option<PersistentStorage>,
+}
+
+impl StatsHistoryState {
+    pub(crate) fn new(storage: Option<PersistentStorage>) -> Self {
+        StatsHistoryState { storage }
+    }
+}
 
 #[derive(Serialize)]
 #[serde(untagged)]
@@ -27,3 +46,39 @@ pub(crate) async fn stats(
 
     Json(NodeStatsResponse::Simple(snapshot_data.simplify()))
 }
+
+/// Returns persisted snapshots of the node statistics taken between `from` and `to` unix timestamps.
+/// By default, snapshots from the last 24h are returned.
+#[get("/stats/history?<from>&<to>")]
+pub(crate) async fn stats_history(
+    state: &State<StatsHistoryState>,
+    from: Option<i64>,
+    to: Option<i64>,
+) -> Result<Json<Vec<StatsSnapshot>>, Custom<String>> {
+    let storage = state.storage.as_ref().ok_or_else(|| {
+        Custom(
+            Status::NotFound,
+            "statistics history is not enabled on this node".to_string(),
+        )
+    })?;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| now.as_secs() as i64)
+        .unwrap_or_default();
+    let to = to.unwrap_or(now);
+    let from = from.unwrap_or(to - DEFAULT_HISTORY_RANGE.as_secs() as i64);
+
+    if from > to {
+        return Err(Custom(
+            Status::BadRequest,
+            "'from' must not be greater than 'to'".to_string(),
+        ));
+    }
+
+    storage
+        .get_stats_snapshots(from, to)
+        .await
+        .map(Json)
+        .map_err(|err| Custom(Status::InternalServerError, err.to_string()))
+}
@@ -1,4 +1,4 @@
-use mixnode_common::verloc::{AtomicVerlocResult, VerlocResult};
+use mixnode_common::verloc::{AtomicVerlocResult, SignedVerlocResult};
 use rocket::serde::json::Json;
 use rocket::State;
 
@@ -15,9 +15,11 @@ impl VerlocState {
 }
 
 /// Provides verifiable location (verloc) measurements for this mixnode - a list of the
-/// round-trip times, in milliseconds, for all other mixnodes that this node knows about.
+/// round-trip times, in milliseconds, for all other mixnodes and gateways that this node knows about,
+/// including the results for each of their resolved addresses.
+/// The results are signed with the identity key of this mixnode so that they could be verified by third parties.
 #[get("/verloc")]
-pub(crate) async fn verloc(state: &State<VerlocState>) -> Json<VerlocResult> {
+pub(crate) async fn verloc(state: &State<VerlocState>) -> Json<SignedVerlocResult> {
     // since it's impossible to get a mutable reference to the state, we can't cache any results outside the lock : (
-    Json(state.shared.clone_data().await)
+    Json(state.shared.clone_signed_data().await)
 }
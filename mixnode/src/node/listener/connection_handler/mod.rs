@@ -4,6 +4,7 @@
 use crate::node::listener::connection_handler::packet_processing::{
     MixProcessingResult, PacketProcessor,
 };
+use crate::node::listener::rate_limiter::{ConnectionPermit, RateLimiter};
 use crate::node::packet_delayforwarder::PacketDelayForwardSender;
 use futures::StreamExt;
 use log::{error, info};
@@ -22,16 +23,19 @@ pub(crate) mod packet_processing;
 pub(crate) struct ConnectionHandler {
     packet_processor: PacketProcessor,
     delay_forwarding_channel: PacketDelayForwardSender,
+    rate_limiter: RateLimiter,
 }
 
 impl ConnectionHandler {
     pub(crate) fn new(
         packet_processor: PacketProcessor,
         delay_forwarding_channel: PacketDelayForwardSender,
+        rate_limiter: RateLimiter,
     ) -> Self {
         ConnectionHandler {
             packet_processor,
             delay_forwarding_channel,
+            rate_limiter,
         }
     }
 
@@ -69,12 +73,25 @@ impl ConnectionHandler {
         }
     }
 
-    pub(crate) async fn handle_connection(self, conn: TcpStream, remote: SocketAddr) {
+    // the permit is held for the entire duration of the connection so that the slot would only
+    // get freed once the connection is closed
+    pub(crate) async fn handle_connection(
+        self,
+        conn: TcpStream,
+        remote: SocketAddr,
+        _permit: ConnectionPermit,
+    ) {
         debug!("Starting connection handler for {:?}", remote);
         let mut framed_conn = Framed::new(conn, SphinxCodec);
         while let Some(framed_sphinx_packet) = framed_conn.next().await {
             match framed_sphinx_packet {
                 Ok(framed_sphinx_packet) => {
+                    // make sure the source is not flooding us before doing any expensive unwrapping
+                    if !self.rate_limiter.allow_packet(remote.ip()) {
+                        trace!("dropping packet from {} due to rate limiting", remote);
+                        continue;
+                    }
+
                     // TODO: benchmark spawning tokio task with full processing vs just processing it
                     // synchronously (without delaying inside of course,
                     // delay is moved to a global DelayQueue)
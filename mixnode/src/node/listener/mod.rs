@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::node::listener::connection_handler::ConnectionHandler;
+use crate::node::listener::rate_limiter::RateLimiter;
 use log::error;
 use std::net::SocketAddr;
 use std::process;
@@ -9,14 +10,19 @@ use tokio::net::TcpListener;
 use tokio::task::JoinHandle;
 
 pub(crate) mod connection_handler;
+pub(crate) mod rate_limiter;
 
 pub(crate) struct Listener {
     address: SocketAddr,
+    rate_limiter: RateLimiter,
 }
 
 impl Listener {
-    pub(crate) fn new(address: SocketAddr) -> Self {
-        Listener { address }
+    pub(crate) fn new(address: SocketAddr, rate_limiter: RateLimiter) -> Self {
+        Listener {
+            address,
+            rate_limiter,
+        }
     }
 
     async fn run(&mut self, connection_handler: ConnectionHandler) {
@@ -31,8 +37,13 @@ impl Listener {
         loop {
             match listener.accept().await {
                 Ok((socket, remote_addr)) => {
+                    // if the source has too many connections open, just drop the socket
+                    let permit = match self.rate_limiter.try_acquire_connection(remote_addr.ip()) {
+                        Some(permit) => permit,
+                        None => continue,
+                    };
                     let handler = connection_handler.clone();
-                    tokio::spawn(handler.handle_connection(socket, remote_addr));
+                    tokio::spawn(handler.handle_connection(socket, remote_addr, permit));
                 }
                 Err(err) => warn!("Failed to accept incoming connection - {:?}", err),
             }
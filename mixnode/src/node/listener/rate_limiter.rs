@@ -0,0 +1,369 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::node::node_statistics;
+use log::{debug, warn};
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use tokio::time::Instant;
+use url::Url;
+
+// how often we should get rid of state of addresses that have no open connections
+const STALE_ENTRIES_PRUNING_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RateLimiterConfig {
+    /// Specifies whether the limits should be enforced at all.
+    pub(crate) enabled: bool,
+
+    /// Maximum number of concurrent connections accepted from a single IP address.
+    pub(crate) maximum_connections_per_ip: usize,
+
+    /// Sustained number of packets per second accepted from a single IP address.
+    pub(crate) maximum_packets_per_second: u32,
+
+    /// Maximum number of packets accepted from a single IP address in a burst.
+    pub(crate) maximum_packet_burst: u32,
+}
+
+// simple token bucket alongside number of currently open connections
+struct AddressState {
+    active_connections: usize,
+    available_tokens: f64,
+    last_refill: Instant,
+}
+
+impl AddressState {
+    fn new(burst: u32) -> Self {
+        AddressState {
+            active_connections: 0,
+            available_tokens: burst as f64,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self, rate: u32, burst: u32) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.available_tokens = (self.available_tokens + elapsed * rate as f64).min(burst as f64);
+        self.last_refill = now;
+    }
+
+    fn try_consume(&mut self, rate: u32, burst: u32) -> bool {
+        self.refill(rate, burst);
+        if self.available_tokens >= 1.0 {
+            self.available_tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn is_stale(&self, burst: u32) -> bool {
+        self.active_connections == 0 && self.available_tokens >= burst as f64
+    }
+}
+
+struct RateLimiterInner {
+    config: RateLimiterConfig,
+    addresses: Mutex<HashMap<IpAddr, AddressState>>,
+    allowlist: RwLock<HashSet<IpAddr>>,
+    node_stats_update_sender: node_statistics::UpdateSender,
+}
+
+/// Enforces limits on number of concurrent connections and rate of packets per source IP address.
+/// It is checked before any sphinx processing happens so that cheap floods could not
+/// consume our unwrapping CPU.
+#[derive(Clone)]
+pub(crate) struct RateLimiter {
+    inner: Arc<RateLimiterInner>,
+}
+
+/// Represents an accepted connection. Once dropped, the connection slot of the address is freed.
+pub(crate) struct ConnectionPermit {
+    address: IpAddr,
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl Drop for ConnectionPermit {
+    fn drop(&mut self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.release_connection(self.address)
+        }
+    }
+}
+
+impl RateLimiter {
+    pub(crate) fn new(
+        config: RateLimiterConfig,
+        node_stats_update_sender: node_statistics::UpdateSender,
+    ) -> Self {
+        RateLimiter {
+            inner: Arc::new(RateLimiterInner {
+                config,
+                addresses: Mutex::new(HashMap::new()),
+                allowlist: RwLock::new(HashSet::new()),
+                node_stats_update_sender,
+            }),
+        }
+    }
+
+    fn is_exempt(&self, address: IpAddr) -> bool {
+        !self.inner.config.enabled || self.inner.allowlist.read().unwrap().contains(&address)
+    }
+
+    pub(crate) fn update_allowlist(&self, allowlist: HashSet<IpAddr>) {
+        *self.inner.allowlist.write().unwrap() = allowlist
+    }
+
+    /// Attempts to register new connection from the specified address. Returns `None`
+    /// if the address has already reached its maximum number of concurrent connections.
+    pub(crate) fn try_acquire_connection(&self, address: IpAddr) -> Option<ConnectionPermit> {
+        if self.is_exempt(address) {
+            return Some(ConnectionPermit {
+                address,
+                rate_limiter: None,
+            });
+        }
+
+        let config = &self.inner.config;
+        let mut addresses = self.inner.addresses.lock().unwrap();
+        let state = addresses
+            .entry(address)
+            .or_insert_with(|| AddressState::new(config.maximum_packet_burst));
+
+        if state.active_connections >= config.maximum_connections_per_ip {
+            drop(addresses);
+            debug!(
+                "{} has reached its maximum number of concurrent connections ({})",
+                address, config.maximum_connections_per_ip
+            );
+            self.inner
+                .node_stats_update_sender
+                .report_rejected_connection();
+            return None;
+        }
+
+        state.active_connections += 1;
+        Some(ConnectionPermit {
+            address,
+            rate_limiter: Some(self.clone()),
+        })
+    }
+
+    fn release_connection(&self, address: IpAddr) {
+        if let Some(state) = self.inner.addresses.lock().unwrap().get_mut(&address) {
+            state.active_connections = state.active_connections.saturating_sub(1);
+        }
+    }
+
+    /// Checks whether another packet from the specified address can be processed.
+    pub(crate) fn allow_packet(&self, address: IpAddr) -> bool {
+        if self.is_exempt(address) {
+            return true;
+        }
+
+        let config = &self.inner.config;
+        let allowed = self
+            .inner
+            .addresses
+            .lock()
+            .unwrap()
+            .entry(address)
+            .or_insert_with(|| AddressState::new(config.maximum_packet_burst))
+            .try_consume(
+                config.maximum_packets_per_second,
+                config.maximum_packet_burst,
+            );
+
+        if !allowed {
+            self.inner.node_stats_update_sender.report_rate_limited();
+        }
+        allowed
+    }
+
+    fn prune_stale_entries(&self) {
+        let config = &self.inner.config;
+        self.inner.addresses.lock().unwrap().retain(|_, state| {
+            state.refill(
+                config.maximum_packets_per_second,
+                config.maximum_packet_burst,
+            );
+            !state.is_stale(config.maximum_packet_burst)
+        })
+    }
+
+    pub(crate) fn start_pruning(&self) {
+        if !self.inner.config.enabled {
+            return;
+        }
+
+        let rate_limiter = self.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(STALE_ENTRIES_PRUNING_INTERVAL).await;
+                rate_limiter.prune_stale_entries()
+            }
+        });
+    }
+}
+
+/// Periodically obtains the current network topology and exempts addresses of all
+/// known mixnodes and gateways from the rate limiting.
+pub(crate) struct TopologyAllowlistRefresher {
+    rate_limiter: RateLimiter,
+    validator_api_urls: Vec<Url>,
+    refresh_rate: Duration,
+}
+
+impl TopologyAllowlistRefresher {
+    pub(crate) fn new(
+        rate_limiter: RateLimiter,
+        validator_api_urls: Vec<Url>,
+        refresh_rate: Duration,
+    ) -> Self {
+        TopologyAllowlistRefresher {
+            rate_limiter,
+            validator_api_urls,
+            refresh_rate,
+        }
+    }
+
+    async fn resolve_host(host: &str, allowlist: &mut HashSet<IpAddr>) {
+        // the port is irrelevant, we only care about the ip addresses
+        match tokio::net::lookup_host((host, 0)).await {
+            Ok(addresses) => allowlist.extend(addresses.map(|address| address.ip())),
+            Err(err) => debug!("failed to resolve {} - {}", host, err),
+        }
+    }
+
+    async fn refresh_allowlist(&self) {
+        let validator_api = match self.validator_api_urls.choose(&mut thread_rng()) {
+            Some(validator_api) => validator_api,
+            None => {
+                warn!("there are no validator apis available to obtain the topology from");
+                return;
+            }
+        };
+        let validator_client = validator_client::ApiClient::new(validator_api.clone());
+
+        let mixnodes = match validator_client.get_cached_mixnodes().await {
+            Ok(mixnodes) => mixnodes,
+            Err(err) => {
+                warn!("failed to obtain list of mixnodes for the rate limiting allowlist - {}. The old allowlist is going to be kept", err);
+                return;
+            }
+        };
+        let gateways = match validator_client.get_cached_gateways().await {
+            Ok(gateways) => gateways,
+            Err(err) => {
+                warn!("failed to obtain list of gateways for the rate limiting allowlist - {}. The old allowlist is going to be kept", err);
+                return;
+            }
+        };
+
+        let mut allowlist = HashSet::new();
+        for mixnode in &mixnodes {
+            Self::resolve_host(&mixnode.mix_node.host, &mut allowlist).await;
+        }
+        for gateway in &gateways {
+            Self::resolve_host(&gateway.gateway.host, &mut allowlist).await;
+        }
+
+        debug!(
+            "{} addresses are exempt from the rate limiting",
+            allowlist.len()
+        );
+        self.rate_limiter.update_allowlist(allowlist);
+    }
+
+    pub(crate) async fn run(&self) {
+        loop {
+            self.refresh_allowlist().await;
+            tokio::time::sleep(self.refresh_rate).await;
+        }
+    }
+
+    pub(crate) fn start(self) {
+        tokio::spawn(async move { self.run().await });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::channel::mpsc;
+
+    fn test_rate_limiter(
+        maximum_connections_per_ip: usize,
+        maximum_packet_burst: u32,
+    ) -> (
+        RateLimiter,
+        mpsc::UnboundedReceiver<node_statistics::PacketEvent>,
+    ) {
+        let (sender, receiver) = mpsc::unbounded();
+        let rate_limiter = RateLimiter::new(
+            RateLimiterConfig {
+                enabled: true,
+                maximum_connections_per_ip,
+                maximum_packets_per_second: 1,
+                maximum_packet_burst,
+            },
+            node_statistics::UpdateSender::new(sender),
+        );
+        (rate_limiter, receiver)
+    }
+
+    #[tokio::test]
+    async fn connections_are_limited_per_address() {
+        let (rate_limiter, _receiver) = test_rate_limiter(2, 10);
+        let address: IpAddr = "1.2.3.4".parse().unwrap();
+        let other_address: IpAddr = "5.6.7.8".parse().unwrap();
+
+        let first = rate_limiter.try_acquire_connection(address);
+        let second = rate_limiter.try_acquire_connection(address);
+        assert!(first.is_some());
+        assert!(second.is_some());
+        assert!(rate_limiter.try_acquire_connection(address).is_none());
+        assert!(rate_limiter.try_acquire_connection(other_address).is_some());
+
+        // once connection is closed, a new one can be established
+        drop(first);
+        assert!(rate_limiter.try_acquire_connection(address).is_some());
+    }
+
+    #[tokio::test]
+    async fn packets_are_limited_per_address() {
+        tokio::time::pause();
+
+        let (rate_limiter, _receiver) = test_rate_limiter(2, 3);
+        let address: IpAddr = "1.2.3.4".parse().unwrap();
+
+        for _ in 0..3 {
+            assert!(rate_limiter.allow_packet(address));
+        }
+        assert!(!rate_limiter.allow_packet(address));
+
+        // after a second we should have gotten a single new token
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert!(rate_limiter.allow_packet(address));
+        assert!(!rate_limiter.allow_packet(address));
+    }
+
+    #[tokio::test]
+    async fn allowlisted_addresses_are_exempt() {
+        let (rate_limiter, _receiver) = test_rate_limiter(1, 1);
+        let address: IpAddr = "1.2.3.4".parse().unwrap();
+        rate_limiter.update_allowlist(vec![address].into_iter().collect());
+
+        let _first = rate_limiter.try_acquire_connection(address);
+        assert!(rate_limiter.try_acquire_connection(address).is_some());
+        for _ in 0..10 {
+            assert!(rate_limiter.allow_packet(address));
+        }
+    }
+}
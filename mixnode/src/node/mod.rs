@@ -5,26 +5,38 @@ use crate::commands::validate_bech32_address_or_exit;
 use crate::config::persistence::pathfinder::MixNodePathfinder;
 use crate::config::Config;
 use crate::node::http::{
+    admin::{
+        auth::AdminAuthenticator, restart, update_description, update_packet_forwarding_backoff,
+        AdminState, RestartRequestReceiver, RestartRequestSender,
+    },
     description::description,
     not_found,
-    stats::stats,
+    stats::{stats, stats_history, StatsHistoryState},
     verloc::{verloc as verlocRoute, VerlocState},
 };
 use crate::node::listener::connection_handler::packet_processing::PacketProcessor;
 use crate::node::listener::connection_handler::ConnectionHandler;
+use crate::node::listener::rate_limiter::{
+    RateLimiter, RateLimiterConfig, TopologyAllowlistRefresher,
+};
 use crate::node::listener::Listener;
-use crate::node::node_description::NodeDescription;
-use crate::node::node_statistics::SharedNodeStats;
+use crate::node::node_description::{NodeDescription, SharedNodeDescription};
+use crate::node::node_statistics::{SharedNodeStats, StatsHistoryRecorder};
 use crate::node::packet_delayforwarder::{DelayForwarder, PacketDelayForwardSender};
+use crate::node::storage::PersistentStorage;
 use ::crypto::asymmetric::{encryption, identity};
 use config::NymConfig;
+use futures::channel::mpsc;
+use futures::StreamExt;
 use log::{error, info, warn};
+use mixnet_client::ReconnectionBackoffHandle;
 use mixnode_common::verloc::{self, AtomicVerlocResult, VerlocMeasurer};
 use rand::seq::SliceRandom;
 use rand::thread_rng;
-use std::net::SocketAddr;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::process;
 use std::sync::Arc;
+use std::time::Duration;
 use version_checker::parse_version;
 
 mod http;
@@ -32,11 +44,22 @@ mod listener;
 pub(crate) mod node_description;
 mod node_statistics;
 mod packet_delayforwarder;
+mod storage;
+
+// how long we wait before restarting so that the response to the restart request could be sent
+const RESTART_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+/// Describes why the node has stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownReason {
+    Interrupted,
+    RestartRequested,
+}
 
 // the MixNode will live for whole duration of this program
 pub struct MixNode {
     config: Config,
-    descriptor: NodeDescription,
+    descriptor: SharedNodeDescription,
     identity_keypair: Arc<identity::KeyPair>,
     sphinx_keypair: Arc<encryption::KeyPair>,
 }
@@ -53,9 +76,11 @@ impl MixNode {
         }
     }
 
-    fn load_node_description(config: &Config) -> NodeDescription {
-        NodeDescription::load_from_file(Config::default_config_directory(Some(&config.get_id())))
-            .unwrap_or_default()
+    fn load_node_description(config: &Config) -> SharedNodeDescription {
+        let config_directory = Config::default_config_directory(Some(&config.get_id()));
+        let description =
+            NodeDescription::load_from_file(config_directory.clone()).unwrap_or_default();
+        SharedNodeDescription::new(description, config_directory)
     }
 
     /// Loads identity keys stored on disk
@@ -124,6 +149,7 @@ impl MixNode {
         &self,
         atomic_verloc_result: AtomicVerlocResult,
         node_stats_pointer: SharedNodeStats,
+        storage: Option<PersistentStorage>,
     ) {
         info!("Starting HTTP API on http://localhost:8000");
 
@@ -134,16 +160,67 @@ impl MixNode {
         config.port = self.config.get_http_api_port();
 
         let verloc_state = VerlocState::new(atomic_verloc_result);
+        let stats_history_state = StatsHistoryState::new(storage);
         let descriptor = self.descriptor.clone();
 
         tokio::spawn(async move {
             rocket::build()
                 .configure(config)
-                .mount("/", routes![verlocRoute, description, stats])
+                .mount("/", routes![verlocRoute, description, stats, stats_history])
                 .register("/", catchers![not_found])
                 .manage(verloc_state)
                 .manage(descriptor)
                 .manage(node_stats_pointer)
+                .manage(stats_history_state)
+                .launch()
+                .await
+        });
+    }
+
+    fn start_admin_api(
+        &self,
+        packet_forwarding_backoff: ReconnectionBackoffHandle,
+        restart_sender: RestartRequestSender,
+    ) {
+        // the admin api is only ever exposed on the loopback interface
+        let listening_address = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        let port = self.config.get_admin_api_port();
+        info!(
+            "Starting admin HTTP API on http://{}:{}",
+            listening_address, port
+        );
+
+        if self.config.get_admin_api_token().is_none() {
+            info!("No admin API token is set - all admin requests have to be signed with the identity key");
+        }
+
+        let mut config = rocket::config::Config::release_default();
+        config.address = listening_address;
+        config.port = port;
+
+        let authenticator = AdminAuthenticator::new(
+            self.config.get_admin_api_token().map(ToString::to_string),
+            *self.identity_keypair.public_key(),
+            self.config.get_admin_signature_validity(),
+        );
+        let admin_state = AdminState::new(packet_forwarding_backoff, restart_sender);
+        let descriptor = self.descriptor.clone();
+
+        tokio::spawn(async move {
+            rocket::build()
+                .configure(config)
+                .mount(
+                    "/",
+                    routes![
+                        update_description,
+                        update_packet_forwarding_backoff,
+                        restart
+                    ],
+                )
+                .register("/", catchers![not_found])
+                .manage(authenticator)
+                .manage(admin_state)
+                .manage(descriptor)
                 .launch()
                 .await
         });
@@ -161,6 +238,60 @@ impl MixNode {
         (node_stats_pointer, update_sender)
     }
 
+    async fn initialise_storage(&self) -> Option<PersistentStorage> {
+        if !self.config.get_stats_history_enabled() {
+            return None;
+        }
+
+        let path = self.config.get_persistent_store_path();
+        match PersistentStorage::init(path).await {
+            Err(err) => panic!("failed to initialise mixnode storage - {}", err),
+            Ok(storage) => Some(storage),
+        }
+    }
+
+    fn start_stats_history_recorder(
+        &self,
+        node_stats_pointer: SharedNodeStats,
+        storage: PersistentStorage,
+    ) {
+        info!("Starting node stats history recorder...");
+        StatsHistoryRecorder::new(
+            self.config.get_stats_history_snapshot_interval(),
+            self.config.get_stats_history_retention(),
+            node_stats_pointer,
+            storage,
+        )
+        .start();
+    }
+
+    fn start_rate_limiter(
+        &self,
+        node_stats_update_sender: node_statistics::UpdateSender,
+    ) -> RateLimiter {
+        info!("Starting rate limiter...");
+
+        let rate_limiter_config = RateLimiterConfig {
+            enabled: self.config.get_rate_limiting_enabled(),
+            maximum_connections_per_ip: self.config.get_maximum_connections_per_ip(),
+            maximum_packets_per_second: self.config.get_maximum_packets_per_second_per_ip(),
+            maximum_packet_burst: self.config.get_maximum_packet_burst_per_ip(),
+        };
+        let rate_limiter = RateLimiter::new(rate_limiter_config, node_stats_update_sender);
+        rate_limiter.start_pruning();
+
+        if rate_limiter_config.enabled && self.config.get_allowlist_topology_peers() {
+            TopologyAllowlistRefresher::new(
+                rate_limiter.clone(),
+                self.config.get_validator_api_endpoints(),
+                self.config.get_allowlist_refresh_rate(),
+            )
+            .start();
+        }
+
+        rate_limiter
+    }
+
     fn start_socket_listener(
         &self,
         node_stats_update_sender: node_statistics::UpdateSender,
@@ -168,23 +299,29 @@ impl MixNode {
     ) {
         info!("Starting socket listener...");
 
+        let rate_limiter = self.start_rate_limiter(node_stats_update_sender.clone());
+
         let packet_processor =
             PacketProcessor::new(self.sphinx_keypair.private_key(), node_stats_update_sender);
 
-        let connection_handler = ConnectionHandler::new(packet_processor, delay_forwarding_channel);
+        let connection_handler = ConnectionHandler::new(
+            packet_processor,
+            delay_forwarding_channel,
+            rate_limiter.clone(),
+        );
 
         let listening_address = SocketAddr::new(
             self.config.get_listening_address(),
             self.config.get_mix_port(),
         );
 
-        Listener::new(listening_address).start(connection_handler);
+        Listener::new(listening_address, rate_limiter).start(connection_handler);
     }
 
     fn start_packet_delay_forwarder(
         &mut self,
         node_stats_update_sender: node_statistics::UpdateSender,
-    ) -> PacketDelayForwardSender {
+    ) -> (PacketDelayForwardSender, ReconnectionBackoffHandle) {
         info!("Starting packet delay-forwarder...");
 
         let client_config = mixnet_client::Config::new(
@@ -194,15 +331,15 @@ impl MixNode {
             self.config.get_maximum_connection_buffer_size(),
         );
 
-        let mut packet_forwarder = DelayForwarder::new(
-            mixnet_client::Client::new(client_config),
-            node_stats_update_sender,
-        );
+        let mixnet_client = mixnet_client::Client::new(client_config);
+        let backoff_handle = mixnet_client.reconnection_backoff_handle();
+
+        let mut packet_forwarder = DelayForwarder::new(mixnet_client, node_stats_update_sender);
 
         let packet_sender = packet_forwarder.sender();
 
         tokio::spawn(async move { packet_forwarder.run().await });
-        packet_sender
+        (packet_sender, backoff_handle)
     }
 
     fn start_verloc_measurements(&self) -> AtomicVerlocResult {
@@ -235,6 +372,7 @@ impl MixNode {
             .tested_nodes_batch_size(self.config.get_measurement_tested_nodes_batch_size())
             .testing_interval(self.config.get_measurement_testing_interval())
             .retry_timeout(self.config.get_measurement_retry_timeout())
+            .measure_gateways(self.config.get_measure_gateways())
             .validator_api_urls(self.config.get_validator_api_endpoints())
             .build();
 
@@ -268,19 +406,33 @@ impl MixNode {
             .map(|node| node.mix_node.identity_key.clone())
     }
 
-    async fn wait_for_interrupt(&self) {
-        if let Err(e) = tokio::signal::ctrl_c().await {
-            error!(
-                "There was an error while capturing SIGINT - {:?}. We will terminate regardless",
-                e
-            );
+    async fn wait_for_shutdown(
+        &self,
+        mut restart_receiver: RestartRequestReceiver,
+    ) -> ShutdownReason {
+        tokio::select! {
+            res = tokio::signal::ctrl_c() => {
+                if let Err(e) = res {
+                    error!(
+                        "There was an error while capturing SIGINT - {:?}. We will terminate regardless",
+                        e
+                    );
+                }
+                println!(
+                    "Received SIGINT - the mixnode will terminate now (threads are not yet nicely stopped, if you see stack traces that's alright)."
+                );
+                ShutdownReason::Interrupted
+            }
+            // if the admin api is disabled, the sender is dropped and this branch is never selected
+            Some(_) = restart_receiver.next() => {
+                println!("Restart was requested - the mixnode will restart shortly");
+                tokio::time::sleep(RESTART_GRACE_PERIOD).await;
+                ShutdownReason::RestartRequested
+            }
         }
-        println!(
-            "Received SIGINT - the mixnode will terminate now (threads are not yet nicely stopped, if you see stack traces that's alright)."
-        );
     }
 
-    pub async fn run(&mut self) {
+    pub async fn run(&mut self) -> ShutdownReason {
         info!("Starting nym mixnode");
 
         if let Some(duplicate_node_key) = self.check_if_same_ip_node_exists().await {
@@ -291,19 +443,30 @@ impl MixNode {
                     "Our announce-host is identical to an existing node's announce-host! (its key is {:?})",
                     duplicate_node_key
                 );
-                return;
+                return ShutdownReason::Interrupted;
             }
         }
 
+        let storage = self.initialise_storage().await;
+
         let (node_stats_pointer, node_stats_update_sender) = self.start_node_stats_controller();
-        let delay_forwarding_channel =
+        if let Some(storage) = &storage {
+            self.start_stats_history_recorder(node_stats_pointer.clone(), storage.clone());
+        }
+
+        let (delay_forwarding_channel, packet_forwarding_backoff) =
             self.start_packet_delay_forwarder(node_stats_update_sender.clone());
         self.start_socket_listener(node_stats_update_sender, delay_forwarding_channel);
 
         let atomic_verloc_results = self.start_verloc_measurements();
-        self.start_http_api(atomic_verloc_results, node_stats_pointer);
+        self.start_http_api(atomic_verloc_results, node_stats_pointer, storage);
+
+        let (restart_sender, restart_receiver) = mpsc::unbounded();
+        if self.config.get_admin_api_enabled() {
+            self.start_admin_api(packet_forwarding_backoff, restart_sender);
+        }
 
         info!("Finished nym mixnode startup procedure - it should now be able to receive mix traffic!");
-        self.wait_for_interrupt().await
+        self.wait_for_shutdown(restart_receiver).await
     }
 }
@@ -1,7 +1,9 @@
 use serde::Deserialize;
 use serde::Serialize;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::{fs, io};
+use tokio::sync::RwLock;
 
 pub(crate) const DESCRIPTION_FILE: &str = "description.toml";
 
@@ -46,3 +48,31 @@ impl NodeDescription {
         Ok(())
     }
 }
+
+/// Description of the node that can get updated at runtime via the admin API.
+#[derive(Clone)]
+pub(crate) struct SharedNodeDescription {
+    inner: Arc<RwLock<NodeDescription>>,
+    config_path: PathBuf,
+}
+
+impl SharedNodeDescription {
+    pub(crate) fn new(description: NodeDescription, config_path: PathBuf) -> Self {
+        SharedNodeDescription {
+            inner: Arc::new(RwLock::new(description)),
+            config_path,
+        }
+    }
+
+    pub(crate) async fn clone_data(&self) -> NodeDescription {
+        self.inner.read().await.clone()
+    }
+
+    /// Replaces the current description and persists it so that it would survive restarts.
+    pub(crate) async fn update(&self, description: NodeDescription) -> io::Result<()> {
+        let mut guard = self.inner.write().await;
+        NodeDescription::save_to_file(&description, self.config_path.clone())?;
+        *guard = description;
+        Ok(())
+    }
+}
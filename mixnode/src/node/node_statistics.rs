@@ -1,3 +1,5 @@
+use crate::node::storage::models::{PeerStats, StatsSnapshot};
+use crate::node::storage::PersistentStorage;
 use futures::channel::mpsc;
 use futures::lock::Mutex;
 use futures::StreamExt;
@@ -6,7 +8,7 @@ use std::collections::HashMap;
 use std::ops::DerefMut;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::{RwLock, RwLockReadGuard};
 
 // convenience aliases
@@ -32,6 +34,10 @@ impl SharedNodeStats {
                 packets_received_since_last_update: 0,
                 packets_sent_since_last_update: HashMap::new(),
                 packets_explicitly_dropped_since_last_update: HashMap::new(),
+                packets_rate_limited_since_startup: 0,
+                packets_rate_limited_since_last_update: 0,
+                connections_rejected_since_startup: 0,
+                connections_rejected_since_last_update: 0,
             })),
         }
     }
@@ -41,6 +47,8 @@ impl SharedNodeStats {
         new_received: u64,
         new_sent: PacketsMap,
         new_dropped: PacketsMap,
+        new_rate_limited: u64,
+        new_rejected_connections: u64,
     ) {
         let mut guard = self.inner.write().await;
         let snapshot_time = SystemTime::now();
@@ -58,14 +66,19 @@ impl SharedNodeStats {
 
         for (mix, count) in new_dropped.iter() {
             *guard
-                .packets_explicitly_dropped_since_last_update
+                .packets_explicitly_dropped_since_startup
                 .entry(mix.clone())
                 .or_insert(0) += *count;
         }
 
+        guard.packets_rate_limited_since_startup += new_rate_limited;
+        guard.connections_rejected_since_startup += new_rejected_connections;
+
         guard.packets_received_since_last_update = new_received;
         guard.packets_sent_since_last_update = new_sent;
         guard.packets_explicitly_dropped_since_last_update = new_dropped;
+        guard.packets_rate_limited_since_last_update = new_rate_limited;
+        guard.connections_rejected_since_last_update = new_rejected_connections;
     }
 
     pub(crate) async fn clone_data(&self) -> NodeStats {
@@ -100,6 +113,16 @@ pub(crate) struct NodeStats {
 
     // we know for sure we dropped packets to those destinations
     packets_explicitly_dropped_since_last_update: PacketsMap,
+
+    // packets dropped before processing due to exceeding the per-ip rate limit
+    packets_rate_limited_since_startup: u64,
+
+    packets_rate_limited_since_last_update: u64,
+
+    // connections refused due to exceeding the per-ip connection limit
+    connections_rejected_since_startup: u64,
+
+    connections_rejected_since_last_update: u64,
 }
 
 impl NodeStats {
@@ -119,6 +142,10 @@ impl NodeStats {
                 .packets_explicitly_dropped_since_last_update
                 .values()
                 .sum(),
+            packets_rate_limited_since_startup: self.packets_rate_limited_since_startup,
+            packets_rate_limited_since_last_update: self.packets_rate_limited_since_last_update,
+            connections_rejected_since_startup: self.connections_rejected_since_startup,
+            connections_rejected_since_last_update: self.connections_rejected_since_last_update,
         }
     }
 }
@@ -146,12 +173,24 @@ pub(crate) struct NodeStatsSimple {
 
     // we know for sure we dropped those packets
     packets_explicitly_dropped_since_last_update: u64,
+
+    // packets dropped before processing due to exceeding the per-ip rate limit
+    packets_rate_limited_since_startup: u64,
+
+    packets_rate_limited_since_last_update: u64,
+
+    // connections refused due to exceeding the per-ip connection limit
+    connections_rejected_since_startup: u64,
+
+    connections_rejected_since_last_update: u64,
 }
 
 pub(crate) enum PacketEvent {
     Sent(String),
     Received,
     Dropped(String),
+    RateLimited,
+    ConnectionRejected,
 }
 
 #[derive(Debug, Clone)]
@@ -164,6 +203,8 @@ struct PacketDataInner {
     received: AtomicU64,
     sent: Mutex<PacketsMap>,
     dropped: Mutex<PacketsMap>,
+    rate_limited: AtomicU64,
+    rejected_connections: AtomicU64,
 }
 
 impl CurrentPacketData {
@@ -173,6 +214,8 @@ impl CurrentPacketData {
                 received: AtomicU64::new(0),
                 sent: Mutex::new(HashMap::new()),
                 dropped: Mutex::new(HashMap::new()),
+                rate_limited: AtomicU64::new(0),
+                rejected_connections: AtomicU64::new(0),
             }),
         }
     }
@@ -181,6 +224,16 @@ impl CurrentPacketData {
         self.inner.received.fetch_add(1, Ordering::SeqCst);
     }
 
+    fn increment_rate_limited(&self) {
+        self.inner.rate_limited.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn increment_rejected_connections(&self) {
+        self.inner
+            .rejected_connections
+            .fetch_add(1, Ordering::SeqCst);
+    }
+
     async fn increment_sent(&self, destination: String) {
         let mut unlocked = self.inner.sent.lock().await;
         let receiver_count = unlocked.entry(destination).or_insert(0);
@@ -193,15 +246,17 @@ impl CurrentPacketData {
         *dropped_count += 1;
     }
 
-    async fn acquire_and_reset(&self) -> (u64, PacketsMap, PacketsMap) {
+    async fn acquire_and_reset(&self) -> (u64, PacketsMap, PacketsMap, u64, u64) {
         let mut unlocked_sent = self.inner.sent.lock().await;
         let mut unlocked_dropped = self.inner.dropped.lock().await;
         let received = self.inner.received.swap(0, Ordering::SeqCst);
+        let rate_limited = self.inner.rate_limited.swap(0, Ordering::SeqCst);
+        let rejected_connections = self.inner.rejected_connections.swap(0, Ordering::SeqCst);
 
         let sent = std::mem::take(unlocked_sent.deref_mut());
         let dropped = std::mem::take(unlocked_dropped.deref_mut());
 
-        (received, sent, dropped)
+        (received, sent, dropped, rate_limited, rejected_connections)
     }
 }
 
@@ -229,6 +284,10 @@ impl UpdateHandler {
                 PacketEvent::Dropped(destination) => {
                     self.current_data.increment_dropped(destination).await
                 }
+                PacketEvent::RateLimited => self.current_data.increment_rate_limited(),
+                PacketEvent::ConnectionRejected => {
+                    self.current_data.increment_rejected_connections()
+                }
             }
         }
     }
@@ -266,6 +325,20 @@ impl UpdateSender {
             .unbounded_send(PacketEvent::Dropped(destination))
             .unwrap()
     }
+
+    pub(crate) fn report_rate_limited(&self) {
+        // in unbounded_send() failed it means that the receiver channel was disconnected
+        // and hence something weird must have happened without a way of recovering
+        self.0.unbounded_send(PacketEvent::RateLimited).unwrap()
+    }
+
+    pub(crate) fn report_rejected_connection(&self) {
+        // in unbounded_send() failed it means that the receiver channel was disconnected
+        // and hence something weird must have happened without a way of recovering
+        self.0
+            .unbounded_send(PacketEvent::ConnectionRejected)
+            .unwrap()
+    }
 }
 
 // Worker that periodically updates the shared node stats from the current packet data buffer that
@@ -291,8 +364,11 @@ impl StatsUpdater {
 
     async fn update_stats(&self) {
         // grab new data since last update
-        let (received, sent, dropped) = self.current_packet_data.acquire_and_reset().await;
-        self.current_stats.update(received, sent, dropped).await;
+        let (received, sent, dropped, rate_limited, rejected_connections) =
+            self.current_packet_data.acquire_and_reset().await;
+        self.current_stats
+            .update(received, sent, dropped, rate_limited, rejected_connections)
+            .await;
     }
 
     async fn run(&self) {
@@ -354,6 +430,18 @@ impl PacketStatsConsoleLogger {
                 stats.packets_received_since_last_update,
                 difference_secs,
             );
+            if stats.packets_rate_limited_since_startup > 0
+                || stats.connections_rejected_since_startup > 0
+            {
+                info!(
+                    "Since startup rate limited {} packets and rejected {} connections! ({} and {} in last {} seconds)",
+                    stats.packets_rate_limited_since_startup,
+                    stats.connections_rejected_since_startup,
+                    stats.packets_rate_limited_since_last_update,
+                    stats.connections_rejected_since_last_update,
+                    difference_secs,
+                );
+            }
             trace!(
                 "Since startup sent packets to the following: \n{:#?} \n And in last {} seconds: {:#?})",
                 stats.packets_sent_since_startup,
@@ -394,6 +482,138 @@ impl PacketStatsConsoleLogger {
     }
 }
 
+// Keeps track of the "since startup" counters as of the previous snapshot so that each
+// persisted snapshot would only contain data gathered during its own interval.
+struct StatsDeltaTracker {
+    last_snapshot_time: SystemTime,
+    received: u64,
+    sent: PacketsMap,
+    dropped: PacketsMap,
+    rate_limited: u64,
+    rejected_connections: u64,
+}
+
+impl StatsDeltaTracker {
+    fn new(start_time: SystemTime) -> Self {
+        StatsDeltaTracker {
+            last_snapshot_time: start_time,
+            received: 0,
+            sent: HashMap::new(),
+            dropped: HashMap::new(),
+            rate_limited: 0,
+            rejected_connections: 0,
+        }
+    }
+
+    fn unix_timestamp(time: SystemTime) -> i64 {
+        time.duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() as i64)
+            .unwrap_or_default()
+    }
+
+    fn next_snapshot(&mut self, stats: &NodeStats, now: SystemTime) -> StatsSnapshot {
+        let mut peers: HashMap<String, PeerStats> = HashMap::new();
+        for (peer, count) in &stats.packets_sent_since_startup {
+            let previous = self.sent.get(peer).copied().unwrap_or_default();
+            if *count > previous {
+                peers.entry(peer.clone()).or_default().packets_sent = (count - previous) as i64;
+            }
+        }
+        for (peer, count) in &stats.packets_explicitly_dropped_since_startup {
+            let previous = self.dropped.get(peer).copied().unwrap_or_default();
+            if *count > previous {
+                peers.entry(peer.clone()).or_default().packets_dropped = (count - previous) as i64;
+            }
+        }
+
+        let snapshot = StatsSnapshot {
+            timestamp: Self::unix_timestamp(now),
+            interval_seconds: now
+                .duration_since(self.last_snapshot_time)
+                .map(|interval| interval.as_secs() as i64)
+                .unwrap_or_default(),
+            packets_received: stats
+                .packets_received_since_startup
+                .saturating_sub(self.received) as i64,
+            packets_rate_limited: stats
+                .packets_rate_limited_since_startup
+                .saturating_sub(self.rate_limited) as i64,
+            connections_rejected: stats
+                .connections_rejected_since_startup
+                .saturating_sub(self.rejected_connections) as i64,
+            peers,
+        };
+
+        self.last_snapshot_time = now;
+        self.received = stats.packets_received_since_startup;
+        self.sent = stats.packets_sent_since_startup.clone();
+        self.dropped = stats.packets_explicitly_dropped_since_startup.clone();
+        self.rate_limited = stats.packets_rate_limited_since_startup;
+        self.rejected_connections = stats.connections_rejected_since_startup;
+
+        snapshot
+    }
+}
+
+// Worker that periodically persists snapshots of the node stats, so that they would survive
+// restarts, and removes the ones older than the configured retention.
+pub(crate) struct StatsHistoryRecorder {
+    snapshot_interval: Duration,
+    retention: Duration,
+    stats: SharedNodeStats,
+    storage: PersistentStorage,
+    tracker: StatsDeltaTracker,
+}
+
+impl StatsHistoryRecorder {
+    pub(crate) fn new(
+        snapshot_interval: Duration,
+        retention: Duration,
+        stats: SharedNodeStats,
+        storage: PersistentStorage,
+    ) -> Self {
+        StatsHistoryRecorder {
+            snapshot_interval,
+            retention,
+            stats,
+            storage,
+            tracker: StatsDeltaTracker::new(SystemTime::now()),
+        }
+    }
+
+    async fn persist_snapshot(&mut self) {
+        let now = SystemTime::now();
+        let snapshot = {
+            let stats = self.stats.read().await;
+            self.tracker.next_snapshot(&stats, now)
+        };
+
+        if let Err(err) = self.storage.insert_stats_snapshot(&snapshot).await {
+            error!("Failed to persist node statistics snapshot - {}", err);
+        }
+
+        let cutoff = StatsDeltaTracker::unix_timestamp(now) - self.retention.as_secs() as i64;
+        match self.storage.remove_stats_snapshots_before(cutoff).await {
+            Err(err) => error!("Failed to remove old node statistics snapshots - {}", err),
+            Ok(removed) if removed > 0 => {
+                debug!("Removed {} old node statistics snapshots", removed)
+            }
+            _ => (),
+        }
+    }
+
+    async fn run(&mut self) {
+        loop {
+            tokio::time::sleep(self.snapshot_interval).await;
+            self.persist_snapshot().await
+        }
+    }
+
+    pub(crate) fn start(mut self) {
+        tokio::spawn(async move { self.run().await });
+    }
+}
+
 // basically an easy single entry point to start all of the required tasks
 pub struct Controller {
     /// Responsible for handling data coming from UpdateSender
@@ -456,6 +676,42 @@ impl Controller {
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn stats_snapshots_only_contain_new_data() {
+        let stats = SharedNodeStats::new();
+        let start = SystemTime::now();
+        let mut tracker = StatsDeltaTracker::new(start);
+
+        let mut sent = HashMap::new();
+        sent.insert("foo".to_string(), 3);
+        let mut dropped = HashMap::new();
+        dropped.insert("bar".to_string(), 1);
+        stats.update(10, sent, dropped, 2, 1).await;
+
+        let first_time = start + Duration::from_secs(60);
+        let first = tracker.next_snapshot(&*stats.read().await, first_time);
+        assert_eq!(first.interval_seconds, 60);
+        assert_eq!(first.packets_received, 10);
+        assert_eq!(first.packets_rate_limited, 2);
+        assert_eq!(first.connections_rejected, 1);
+        assert_eq!(first.peers["foo"].packets_sent, 3);
+        assert_eq!(first.peers["bar"].packets_dropped, 1);
+
+        let mut sent = HashMap::new();
+        sent.insert("foo".to_string(), 2);
+        sent.insert("baz".to_string(), 5);
+        stats.update(4, sent, HashMap::new(), 0, 0).await;
+
+        let second =
+            tracker.next_snapshot(&*stats.read().await, first_time + Duration::from_secs(30));
+        assert_eq!(second.interval_seconds, 30);
+        assert_eq!(second.packets_received, 4);
+        assert_eq!(second.packets_rate_limited, 0);
+        assert_eq!(second.peers["foo"].packets_sent, 2);
+        assert_eq!(second.peers["baz"].packets_sent, 5);
+        assert!(!second.peers.contains_key("bar"));
+    }
+
     #[tokio::test]
     async fn node_stats_reported_are_received() {
         let logging_delay = Duration::from_millis(20);
@@ -0,0 +1,195 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::node::storage::error::StorageError;
+use crate::node::storage::models::{
+    PeerStats, PersistedPeerStats, PersistedSnapshot, StatsSnapshot,
+};
+use log::{debug, error};
+use sqlx::ConnectOptions;
+use std::collections::HashMap;
+use std::path::Path;
+
+pub(crate) mod error;
+pub(crate) mod models;
+
+// note that clone here is fine as upon cloning the same underlying pool will be used
+#[derive(Clone)]
+pub(crate) struct PersistentStorage {
+    connection_pool: sqlx::SqlitePool,
+}
+
+impl PersistentStorage {
+    /// Initialises `PersistentStorage` using the provided path.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_path`: path to the database.
+    pub(crate) async fn init<P: AsRef<Path>>(database_path: P) -> Result<Self, StorageError> {
+        debug!(
+            "Attempting to connect to database {:?}",
+            database_path.as_ref().as_os_str()
+        );
+
+        let mut opts = sqlx::sqlite::SqliteConnectOptions::new()
+            .filename(database_path)
+            .create_if_missing(true);
+
+        opts.disable_statement_logging();
+
+        let connection_pool = match sqlx::SqlitePool::connect_with(opts).await {
+            Ok(db) => db,
+            Err(err) => {
+                error!("Failed to connect to SQLx database: {}", err);
+                return Err(err.into());
+            }
+        };
+
+        if let Err(err) = sqlx::migrate!("./migrations").run(&connection_pool).await {
+            error!("Failed to perform migration on the SQLx database: {}", err);
+            return Err(err.into());
+        }
+
+        Ok(PersistentStorage { connection_pool })
+    }
+
+    /// Inserts the provided statistics snapshot, alongside all of its per-peer data, into the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot`: statistics snapshot to persist.
+    pub(crate) async fn insert_stats_snapshot(
+        &self,
+        snapshot: &StatsSnapshot,
+    ) -> Result<(), StorageError> {
+        let mut tx = self.connection_pool.begin().await?;
+
+        let snapshot_id = sqlx::query!(
+            r#"
+                INSERT INTO stats_snapshot(timestamp, interval_seconds, packets_received, packets_rate_limited, connections_rejected)
+                VALUES (?, ?, ?, ?, ?)
+            "#,
+            snapshot.timestamp,
+            snapshot.interval_seconds,
+            snapshot.packets_received,
+            snapshot.packets_rate_limited,
+            snapshot.connections_rejected,
+        )
+        .execute(&mut tx)
+        .await?
+        .last_insert_rowid();
+
+        for (peer, stats) in &snapshot.peers {
+            sqlx::query!(
+                r#"
+                    INSERT INTO peer_stats(snapshot_id, peer, packets_sent, packets_dropped)
+                    VALUES (?, ?, ?, ?)
+                "#,
+                snapshot_id,
+                peer,
+                stats.packets_sent,
+                stats.packets_dropped,
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Retrieves all statistics snapshots taken within the specified time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: unix timestamp of the lower bound of the range (inclusive).
+    /// * `to`: unix timestamp of the upper bound of the range (inclusive).
+    pub(crate) async fn get_stats_snapshots(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<StatsSnapshot>, StorageError> {
+        let snapshots = sqlx::query_as!(
+            PersistedSnapshot,
+            r#"
+                SELECT * FROM stats_snapshot
+                WHERE timestamp >= ? AND timestamp <= ?
+                ORDER BY timestamp
+            "#,
+            from,
+            to
+        )
+        .fetch_all(&self.connection_pool)
+        .await?;
+
+        let peer_stats = sqlx::query_as!(
+            PersistedPeerStats,
+            r#"
+                SELECT snapshot_id, peer, packets_sent, packets_dropped FROM peer_stats
+                WHERE snapshot_id IN (
+                    SELECT id FROM stats_snapshot WHERE timestamp >= ? AND timestamp <= ?
+                )
+            "#,
+            from,
+            to
+        )
+        .fetch_all(&self.connection_pool)
+        .await?;
+
+        let mut peers_by_snapshot: HashMap<i64, HashMap<String, PeerStats>> = HashMap::new();
+        for stats in peer_stats {
+            peers_by_snapshot
+                .entry(stats.snapshot_id)
+                .or_default()
+                .insert(
+                    stats.peer,
+                    PeerStats {
+                        packets_sent: stats.packets_sent,
+                        packets_dropped: stats.packets_dropped,
+                    },
+                );
+        }
+
+        Ok(snapshots
+            .into_iter()
+            .map(|snapshot| StatsSnapshot {
+                timestamp: snapshot.timestamp,
+                interval_seconds: snapshot.interval_seconds,
+                packets_received: snapshot.packets_received,
+                packets_rate_limited: snapshot.packets_rate_limited,
+                connections_rejected: snapshot.connections_rejected,
+                peers: peers_by_snapshot.remove(&snapshot.id).unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    /// Removes all statistics snapshots taken before the specified time.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoff`: unix timestamp before which all snapshots should get removed.
+    pub(crate) async fn remove_stats_snapshots_before(
+        &self,
+        cutoff: i64,
+    ) -> Result<u64, StorageError> {
+        let mut tx = self.connection_pool.begin().await?;
+
+        sqlx::query!(
+            r#"
+                DELETE FROM peer_stats
+                WHERE snapshot_id IN (SELECT id FROM stats_snapshot WHERE timestamp < ?)
+            "#,
+            cutoff
+        )
+        .execute(&mut tx)
+        .await?;
+
+        let removed = sqlx::query!("DELETE FROM stats_snapshot WHERE timestamp < ?", cutoff)
+            .execute(&mut tx)
+            .await?
+            .rows_affected();
+
+        tx.commit().await?;
+        Ok(removed)
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+use std::collections::HashMap;
+
+pub(crate) struct PersistedSnapshot {
+    pub(crate) id: i64,
+    pub(crate) timestamp: i64,
+    pub(crate) interval_seconds: i64,
+    pub(crate) packets_received: i64,
+    pub(crate) packets_rate_limited: i64,
+    pub(crate) connections_rejected: i64,
+}
+
+pub(crate) struct PersistedPeerStats {
+    pub(crate) snapshot_id: i64,
+    pub(crate) peer: String,
+    pub(crate) packets_sent: i64,
+    pub(crate) packets_dropped: i64,
+}
+
+/// Statistics of the node gathered over a single snapshot interval.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub(crate) struct StatsSnapshot {
+    /// Unix timestamp of the end of the interval this snapshot covers.
+    pub(crate) timestamp: i64,
+
+    /// Length of the interval covered by this snapshot.
+    pub(crate) interval_seconds: i64,
+
+    pub(crate) packets_received: i64,
+
+    // packets dropped before processing due to exceeding the per-ip rate limit
+    pub(crate) packets_rate_limited: i64,
+
+    // connections refused due to exceeding the per-ip connection limit
+    pub(crate) connections_rejected: i64,
+
+    pub(crate) peers: HashMap<String, PeerStats>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub(crate) struct PeerStats {
+    // note: sent does not imply forwarded. We don't know if it was delivered successfully
+    pub(crate) packets_sent: i64,
+
+    // we know for sure we dropped those packets
+    pub(crate) packets_dropped: i64,
+}
@@ -44,11 +44,18 @@ fn main() {
       mixnet::bond::unbond_gateway,
       mixnet::bond::unbond_mixnode,
       mixnet::bond::update_mixnode,
+      mixnet::bond::update_mixnode_details,
+      mixnet::bond::increase_mixnode_pledge,
+      mixnet::bond::decrease_mixnode_pledge,
+      mixnet::bond::update_gateway_details,
       mixnet::bond::mixnode_bond_details,
       mixnet::bond::gateway_bond_details,
       mixnet::delegate::delegate_to_mixnode,
       mixnet::delegate::get_reverse_mix_delegations_paged,
       mixnet::delegate::undelegate_from_mixnode,
+      mixnet::delegate::redelegate_to_mixnode,
+      mixnet::delegate::get_pending_withdrawals_paged,
+      mixnet::delegate::claim_pending_withdrawals,
       mixnet::send::send,
       utils::outdated_get_approximate_fee,
       utils::major_to_minor,
@@ -59,8 +66,16 @@ fn main() {
       vesting::bond::vesting_bond_mixnode,
       vesting::bond::vesting_unbond_gateway,
       vesting::bond::vesting_unbond_mixnode,
+      vesting::bond::vesting_update_mixnode_details,
+      vesting::bond::vesting_increase_mixnode_pledge,
+      vesting::bond::vesting_decrease_mixnode_pledge,
+      vesting::bond::vesting_update_gateway_details,
       vesting::delegate::vesting_delegate_to_mixnode,
       vesting::delegate::vesting_undelegate_from_mixnode,
+      vesting::delegate::vesting_delegate_to_gateway,
+      vesting::delegate::vesting_undelegate_from_gateway,
+      vesting::delegate::vesting_redelegate_to_mixnode,
+      vesting::delegate::vesting_claim_pending_withdrawals,
       vesting::queries::locked_coins,
       vesting::queries::spendable_coins,
       vesting::queries::vesting_coins,
@@ -70,6 +85,7 @@ fn main() {
       vesting::queries::original_vesting,
       vesting::queries::delegated_free,
       vesting::queries::delegated_vesting,
+      vesting::queries::vesting_schedule,
       validator_api::status::mixnode_core_node_status,
       validator_api::status::gateway_core_node_status,
       validator_api::status::mixnode_status,
@@ -89,7 +105,11 @@ mod test {
     crate::coin::Coin => "../src/types/rust/coin.ts",
     crate::network::Network => "../src/types/rust/network.ts",
     crate::mixnet::account::Balance => "../src/types/rust/balance.ts",
+    crate::vesting::queries::VestingPeriod => "../src/types/rust/vestingperiod.ts",
+    crate::vesting::queries::VestingAccountSchedule => "../src/types/rust/vestingaccountschedule.ts",
     mixnet_contract_common::Gateway => "../src/types/rust/gateway.ts",
+    mixnet_contract_common::MixNodeDetailsUpdate => "../src/types/rust/mixnodedetailsupdate.ts",
+    mixnet_contract_common::GatewayDetailsUpdate => "../src/types/rust/gatewaydetailsupdate.ts",
     crate::mixnet::send::TauriTxResult => "../src/types/rust/tauritxresult.ts",
     crate::mixnet::send::TransactionDetails => "../src/types/rust/transactiondetails.ts",
     validator_client::nymd::fee::helpers::Operation => "../src/types/rust/operation.ts",
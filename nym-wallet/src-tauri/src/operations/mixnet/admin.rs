@@ -2,7 +2,9 @@ use crate::error::BackendError;
 use crate::nymd_client;
 use crate::state::State;
 use cosmwasm_std::Uint128;
-use mixnet_contract_common::ContractStateParams;
+use mixnet_contract_common::{
+  ContractStateParams, GatewayRewardingParams, SlashingDestination, SlashingParams,
+};
 use serde::{Deserialize, Serialize};
 use std::convert::{TryFrom, TryInto};
 use std::sync::Arc;
@@ -15,6 +17,15 @@ pub struct TauriContractStateParams {
   minimum_gateway_pledge: String,
   mixnode_rewarded_set_size: u32,
   mixnode_active_set_size: u32,
+  unbonding_period: u64,
+  slashing_uptime_threshold: u8,
+  slashing_poor_intervals: u32,
+  slashed_percent: u8,
+  slash_delegations: bool,
+  slash_into_reward_pool: bool,
+  gateway_reward_pool_share_percent: u8,
+  gateway_rewarded_set_size: u32,
+  gateway_operator_share_percent: u8,
 }
 
 impl From<ContractStateParams> for TauriContractStateParams {
@@ -24,6 +35,15 @@ impl From<ContractStateParams> for TauriContractStateParams {
       minimum_gateway_pledge: p.minimum_gateway_pledge.to_string(),
       mixnode_rewarded_set_size: p.mixnode_rewarded_set_size,
       mixnode_active_set_size: p.mixnode_active_set_size,
+      unbonding_period: p.unbonding_period,
+      slashing_uptime_threshold: p.slashing.uptime_threshold,
+      slashing_poor_intervals: p.slashing.poor_intervals,
+      slashed_percent: p.slashing.slashed_percent,
+      slash_delegations: p.slashing.slash_delegations,
+      slash_into_reward_pool: p.slashing.destination == SlashingDestination::RewardPool,
+      gateway_reward_pool_share_percent: p.gateway_rewarding.reward_pool_share_percent,
+      gateway_rewarded_set_size: p.gateway_rewarding.rewarded_set_size,
+      gateway_operator_share_percent: p.gateway_rewarding.operator_share_percent,
     }
   }
 }
@@ -37,6 +57,23 @@ impl TryFrom<TauriContractStateParams> for ContractStateParams {
       minimum_gateway_pledge: Uint128::try_from(p.minimum_gateway_pledge.as_str())?,
       mixnode_rewarded_set_size: p.mixnode_rewarded_set_size,
       mixnode_active_set_size: p.mixnode_active_set_size,
+      unbonding_period: p.unbonding_period,
+      slashing: SlashingParams {
+        uptime_threshold: p.slashing_uptime_threshold,
+        poor_intervals: p.slashing_poor_intervals,
+        slashed_percent: p.slashed_percent,
+        slash_delegations: p.slash_delegations,
+        destination: if p.slash_into_reward_pool {
+          SlashingDestination::RewardPool
+        } else {
+          SlashingDestination::Burn
+        },
+      },
+      gateway_rewarding: GatewayRewardingParams {
+        reward_pool_share_percent: p.gateway_reward_pool_share_percent,
+        rewarded_set_size: p.gateway_rewarded_set_size,
+        operator_share_percent: p.gateway_operator_share_percent,
+      },
     })
   }
 }
@@ -3,7 +3,9 @@ use crate::error::BackendError;
 use crate::nymd_client;
 use crate::state::State;
 use crate::{Gateway, MixNode};
-use mixnet_contract_common::{GatewayBond, MixNodeBond};
+use mixnet_contract_common::{
+  GatewayBond, GatewayDetailsUpdate, MixNodeBond, MixNodeDetailsUpdate,
+};
 use std::convert::TryInto;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -61,6 +63,46 @@ pub async fn update_mixnode(
   Ok(())
 }
 
+#[tauri::command]
+pub async fn update_mixnode_details(
+  details: MixNodeDetailsUpdate,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<(), BackendError> {
+  nymd_client!(state).update_mixnode_details(details).await?;
+  Ok(())
+}
+
+#[tauri::command]
+pub async fn increase_mixnode_pledge(
+  amount: Coin,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<(), BackendError> {
+  nymd_client!(state)
+    .increase_mixnode_pledge(amount.try_into()?)
+    .await?;
+  Ok(())
+}
+
+#[tauri::command]
+pub async fn decrease_mixnode_pledge(
+  amount: Coin,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<(), BackendError> {
+  nymd_client!(state)
+    .decrease_mixnode_pledge(amount.try_into()?)
+    .await?;
+  Ok(())
+}
+
+#[tauri::command]
+pub async fn update_gateway_details(
+  details: GatewayDetailsUpdate,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<(), BackendError> {
+  nymd_client!(state).update_gateway_details(details).await?;
+  Ok(())
+}
+
 #[tauri::command]
 pub async fn mixnode_bond_details(
   state: tauri::State<'_, Arc<RwLock<State>>>,
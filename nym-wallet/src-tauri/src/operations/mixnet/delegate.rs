@@ -4,7 +4,7 @@ use crate::nymd_client;
 use crate::state::State;
 use crate::utils::DelegationResult;
 use cosmwasm_std::Coin as CosmWasmCoin;
-use mixnet_contract_common::PagedDelegatorDelegationsResponse;
+use mixnet_contract_common::{PagedDelegatorDelegationsResponse, PagedPendingWithdrawalsResponse};
 use std::convert::TryInto;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -41,6 +41,20 @@ pub async fn undelegate_from_mixnode(
   ))
 }
 
+#[tauri::command]
+pub async fn redelegate_to_mixnode(
+  from: &str,
+  to: &str,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<DelegationResult, BackendError> {
+  nymd_client!(state).redelegate_to_mixnode(from, to).await?;
+  Ok(DelegationResult::new(
+    &nymd_client!(state).address().to_string(),
+    to,
+    None,
+  ))
+}
+
 #[tauri::command]
 pub async fn get_reverse_mix_delegations_paged(
   state: tauri::State<'_, Arc<RwLock<State>>>,
@@ -51,3 +65,22 @@ pub async fn get_reverse_mix_delegations_paged(
       .await?,
   )
 }
+
+#[tauri::command]
+pub async fn get_pending_withdrawals_paged(
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<PagedPendingWithdrawalsResponse, BackendError> {
+  Ok(
+    nymd_client!(state)
+      .get_pending_withdrawals_paged(nymd_client!(state).address(), None, None)
+      .await?,
+  )
+}
+
+#[tauri::command]
+pub async fn claim_pending_withdrawals(
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<(), BackendError> {
+  nymd_client!(state).claim_pending_withdrawals().await?;
+  Ok(())
+}
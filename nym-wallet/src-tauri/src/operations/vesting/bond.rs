@@ -3,6 +3,7 @@ use crate::error::BackendError;
 use crate::nymd_client;
 use crate::state::State;
 use crate::{Gateway, MixNode};
+use mixnet_contract_common::{GatewayDetailsUpdate, MixNodeDetailsUpdate};
 use std::convert::TryInto;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -49,3 +50,47 @@ pub async fn vesting_bond_mixnode(
     .await?;
   Ok(())
 }
+
+#[tauri::command]
+pub async fn vesting_update_mixnode_details(
+  details: MixNodeDetailsUpdate,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<(), BackendError> {
+  nymd_client!(state)
+    .vesting_update_mixnode_details(details)
+    .await?;
+  Ok(())
+}
+
+#[tauri::command]
+pub async fn vesting_increase_mixnode_pledge(
+  amount: Coin,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<(), BackendError> {
+  nymd_client!(state)
+    .vesting_increase_mixnode_pledge(amount.try_into()?)
+    .await?;
+  Ok(())
+}
+
+#[tauri::command]
+pub async fn vesting_decrease_mixnode_pledge(
+  amount: Coin,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<(), BackendError> {
+  nymd_client!(state)
+    .vesting_decrease_mixnode_pledge(amount.try_into()?)
+    .await?;
+  Ok(())
+}
+
+#[tauri::command]
+pub async fn vesting_update_gateway_details(
+  details: GatewayDetailsUpdate,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<(), BackendError> {
+  nymd_client!(state)
+    .vesting_update_gateway_details(details)
+    .await?;
+  Ok(())
+}
@@ -40,3 +40,61 @@ pub async fn vesting_undelegate_from_mixnode(
     None,
   ))
 }
+
+#[tauri::command]
+pub async fn vesting_redelegate_to_mixnode(
+  from: &str,
+  to: &str,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<DelegationResult, BackendError> {
+  nymd_client!(state)
+    .vesting_redelegate_to_mixnode(from, to)
+    .await?;
+  Ok(DelegationResult::new(
+    &nymd_client!(state).address().to_string(),
+    to,
+    None,
+  ))
+}
+
+#[tauri::command]
+pub async fn vesting_delegate_to_gateway(
+  identity: &str,
+  amount: Coin,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<DelegationResult, BackendError> {
+  let delegation: CosmWasmCoin = amount.try_into()?;
+  nymd_client!(state)
+    .vesting_delegate_to_gateway(identity, &delegation)
+    .await?;
+  Ok(DelegationResult::new(
+    &nymd_client!(state).address().to_string(),
+    identity,
+    Some(delegation.into()),
+  ))
+}
+
+#[tauri::command]
+pub async fn vesting_undelegate_from_gateway(
+  identity: &str,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<DelegationResult, BackendError> {
+  nymd_client!(state)
+    .vesting_undelegate_from_gateway(identity)
+    .await?;
+  Ok(DelegationResult::new(
+    &nymd_client!(state).address().to_string(),
+    identity,
+    None,
+  ))
+}
+
+#[tauri::command]
+pub async fn vesting_claim_pending_withdrawals(
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<(), BackendError> {
+  nymd_client!(state)
+    .vesting_claim_pending_withdrawals()
+    .await?;
+  Ok(())
+}
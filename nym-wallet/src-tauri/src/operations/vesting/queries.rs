@@ -3,9 +3,29 @@ use crate::error::BackendError;
 use crate::nymd_client;
 use crate::state::State;
 use cosmwasm_std::Timestamp;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use validator_client::nymd::VestingQueryClient;
+use vesting_contract_common::messages::VestingSchedule;
+
+#[cfg_attr(test, derive(ts_rs::TS))]
+#[derive(Serialize, Deserialize)]
+pub struct VestingPeriod {
+  start_time: u64,
+  end_time: u64,
+  // total amount of tokens vested by the end of this period
+  vested: Coin,
+}
+
+#[cfg_attr(test, derive(ts_rs::TS))]
+#[derive(Serialize, Deserialize)]
+pub struct VestingAccountSchedule {
+  start_time: u64,
+  cliff_time: Option<u64>,
+  linear: bool,
+  periods: Vec<VestingPeriod>,
+}
 
 #[tauri::command]
 pub async fn locked_coins(
@@ -144,3 +164,35 @@ pub async fn delegated_vesting(
       .into(),
   )
 }
+
+#[tauri::command]
+pub async fn vesting_schedule(
+  vesting_account_address: &str,
+  state: tauri::State<'_, Arc<RwLock<State>>>,
+) -> Result<VestingAccountSchedule, BackendError> {
+  let r_state = state.read().await;
+  let client = &r_state.current_client()?.nymd;
+  let account = client.get_account(vesting_account_address).await?;
+
+  // rather than replicating the contract logic for all schedule types,
+  // just ask the contract how much is going to be vested at the end of each period
+  let mut periods = Vec::with_capacity(account.num_vesting_periods());
+  for period in account.periods() {
+    let end_time = period.end_time();
+    let vested = client
+      .vested_coins(vesting_account_address, Some(end_time))
+      .await?;
+    periods.push(VestingPeriod {
+      start_time: period.start_time,
+      end_time: end_time.seconds(),
+      vested: vested.into(),
+    });
+  }
+
+  Ok(VestingAccountSchedule {
+    start_time: account.start_time().seconds(),
+    cliff_time: account.cliff_time().map(|cliff_time| cliff_time.seconds()),
+    linear: matches!(account.schedule(), VestingSchedule::Linear),
+    periods,
+  })
+}
@@ -172,6 +172,97 @@ const AdminForm: React.FC<{
               helperText={errors?.mixnode_active_set_size?.message}
             />
           </Grid>
+          <Grid item xs={12}>
+            <TextField
+              {...register('unbonding_period', { valueAsNumber: true })}
+              required
+              variant="outlined"
+              id="unbonding_period"
+              name="unbonding_period"
+              label="Unbonding period (seconds)"
+              fullWidth
+              error={!!errors.unbonding_period}
+              helperText={errors?.unbonding_period?.message}
+            />
+          </Grid>
+          <Grid item xs={12}>
+            <TextField
+              {...register('slashing_uptime_threshold', { valueAsNumber: true })}
+              required
+              variant="outlined"
+              id="slashing_uptime_threshold"
+              name="slashing_uptime_threshold"
+              label="Slashing uptime threshold (%)"
+              fullWidth
+              error={!!errors.slashing_uptime_threshold}
+              helperText={errors?.slashing_uptime_threshold?.message}
+            />
+          </Grid>
+          <Grid item xs={12}>
+            <TextField
+              {...register('slashing_poor_intervals', { valueAsNumber: true })}
+              required
+              variant="outlined"
+              id="slashing_poor_intervals"
+              name="slashing_poor_intervals"
+              label="Poorly performing intervals before slashing"
+              fullWidth
+              error={!!errors.slashing_poor_intervals}
+              helperText={errors?.slashing_poor_intervals?.message}
+            />
+          </Grid>
+          <Grid item xs={12}>
+            <TextField
+              {...register('slashed_percent', { valueAsNumber: true })}
+              required
+              variant="outlined"
+              id="slashed_percent"
+              name="slashed_percent"
+              label="Slashed pledge (%)"
+              fullWidth
+              error={!!errors.slashed_percent}
+              helperText={errors?.slashed_percent?.message}
+            />
+          </Grid>
+          <Grid item xs={12}>
+            <TextField
+              {...register('gateway_reward_pool_share_percent', { valueAsNumber: true })}
+              required
+              variant="outlined"
+              id="gateway_reward_pool_share_percent"
+              name="gateway_reward_pool_share_percent"
+              label="Reward pool share of gateways (%)"
+              fullWidth
+              error={!!errors.gateway_reward_pool_share_percent}
+              helperText={errors?.gateway_reward_pool_share_percent?.message}
+            />
+          </Grid>
+          <Grid item xs={12}>
+            <TextField
+              {...register('gateway_rewarded_set_size', { valueAsNumber: true })}
+              required
+              variant="outlined"
+              id="gateway_rewarded_set_size"
+              name="gateway_rewarded_set_size"
+              label="Gateway rewarded set size"
+              fullWidth
+              error={!!errors.gateway_rewarded_set_size}
+              helperText={errors?.gateway_rewarded_set_size?.message}
+            />
+          </Grid>
+          <Grid item xs={12}>
+            <TextField
+              {...register('gateway_operator_share_percent', { valueAsNumber: true })}
+              required
+              variant="outlined"
+              id="gateway_operator_share_percent"
+              name="gateway_operator_share_percent"
+              label="Gateway operator reward share (%)"
+              fullWidth
+              error={!!errors.gateway_operator_share_percent}
+              helperText={errors?.gateway_operator_share_percent?.message}
+            />
+          </Grid>
         </Grid>
       </Box>
       <Grid
@@ -6,8 +6,10 @@ import {
   DelegationResult,
   EnumNodeType,
   Gateway,
+  GatewayDetailsUpdate,
   InclusionProbabilityResponse,
   MixNode,
+  MixNodeDetailsUpdate,
   MixnodeStatusResponse,
   Network,
   Operation,
@@ -18,6 +20,8 @@ import {
   TCreateAccount,
   TMixnodeBondDetails,
   TPagedDelegations,
+  TPagedPendingWithdrawals,
+  VestingAccountSchedule,
 } from '../types'
 
 export const createAccount = async (): Promise<TCreateAccount> => await invoke('create_new_account')
@@ -54,6 +58,12 @@ export const undelegate = async ({
   identity: string
 }): Promise<DelegationResult> => await invoke(`undelegate_from_${type}`, { identity })
 
+export const redelegate = async ({ from, to }: { from: string; to: string }): Promise<DelegationResult> =>
+  await invoke('redelegate_to_mixnode', { from, to })
+
+export const vestingRedelegate = async ({ from, to }: { from: string; to: string }): Promise<DelegationResult> =>
+  await invoke('vesting_redelegate_to_mixnode', { from, to })
+
 export const send = async (args: { amount: Coin; address: string; memo: string }): Promise<TauriTxResult> =>
   await invoke('send', args)
 
@@ -85,6 +95,16 @@ export const setContractParams = async (params: TauriContractStateParams): Promi
 export const getReverseMixDelegations = async (): Promise<TPagedDelegations> =>
   await invoke('get_reverse_mix_delegations_paged')
 
+export const getPendingWithdrawals = async (): Promise<TPagedPendingWithdrawals> =>
+  await invoke('get_pending_withdrawals_paged')
+
+export const claimPendingWithdrawals = async () => await invoke('claim_pending_withdrawals')
+
+export const vestingClaimPendingWithdrawals = async () => await invoke('vesting_claim_pending_withdrawals')
+
+export const getVestingSchedule = async (vestingAccountAddress: string): Promise<VestingAccountSchedule> =>
+  await invoke('vesting_schedule', { vestingAccountAddress })
+
 export const getReverseGatewayDelegations = async (): Promise<TPagedDelegations> =>
   await invoke('get_reverse_gateway_delegations_paged')
 
@@ -103,6 +123,30 @@ export const getMixnodeStatus = async (identity: string): Promise<MixnodeStatusR
 export const updateMixnode = async ({ profitMarginPercent }: { profitMarginPercent: number }) =>
   await invoke('update_mixnode', { profitMarginPercent })
 
+export const updateMixnodeDetails = async (details: MixNodeDetailsUpdate) =>
+  await invoke('update_mixnode_details', { details })
+
+export const increaseMixnodePledge = async (amount: Coin) =>
+  await invoke('increase_mixnode_pledge', { amount })
+
+export const decreaseMixnodePledge = async (amount: Coin) =>
+  await invoke('decrease_mixnode_pledge', { amount })
+
+export const updateGatewayDetails = async (details: GatewayDetailsUpdate) =>
+  await invoke('update_gateway_details', { details })
+
+export const vestingUpdateMixnodeDetails = async (details: MixNodeDetailsUpdate) =>
+  await invoke('vesting_update_mixnode_details', { details })
+
+export const vestingIncreaseMixnodePledge = async (amount: Coin) =>
+  await invoke('vesting_increase_mixnode_pledge', { amount })
+
+export const vestingDecreaseMixnodePledge = async (amount: Coin) =>
+  await invoke('vesting_decrease_mixnode_pledge', { amount })
+
+export const vestingUpdateGatewayDetails = async (details: GatewayDetailsUpdate) =>
+  await invoke('vesting_update_gateway_details', { details })
+
 export const getInclusionProbability = async (identity: string): Promise<InclusionProbabilityResponse> =>
   await invoke('mixnode_inclusion_probability', { identity })
 
@@ -44,6 +44,23 @@ export type TPagedDelegations = {
   start_next_after: string
 }
 
+export type TPendingWithdrawal = {
+  id: number
+  owner: string
+  proxy: string | null
+  amount: Coin
+  kind:
+    | { mixnode_unbonding: { mix_identity: string } }
+    | { undelegation: { mix_identity: string } }
+    | { gateway_undelegation: { gateway_identity: string } }
+  release_time: number
+}
+
+export type TPagedPendingWithdrawals = {
+  withdrawals: TPendingWithdrawal[]
+  start_next_after: number | null
+}
+
 export type TMixnodeBondDetails = {
   pledge_amount: Coin
   total_delegation: Coin
@@ -0,0 +1,7 @@
+export interface GatewayDetailsUpdate {
+  host: string;
+  mix_port: number;
+  clients_port: number;
+  location: string;
+  version: string;
+}
\ No newline at end of file
@@ -4,7 +4,9 @@ export * from './coin'
 export * from './delegationresult'
 export * from './denom'
 export * from './gateway'
+export * from './gatewaydetailsupdate'
 export * from './mixnode'
+export * from './mixnodedetailsupdate'
 export * from './operation'
 export * from './stateparams'
 export * from './tauritxresult'
@@ -17,3 +19,5 @@ export * from './mixnodestatus'
 export * from './mixnodestatusresponse'
 export * from './inclusionprobabilityresponse'
 export * from './network'
+export * from './vestingperiod'
+export * from './vestingaccountschedule'
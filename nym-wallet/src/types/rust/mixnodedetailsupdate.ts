@@ -0,0 +1,7 @@
+export interface MixNodeDetailsUpdate {
+  host: string;
+  mix_port: number;
+  verloc_port: number;
+  http_api_port: number;
+  version: string;
+}
\ No newline at end of file
@@ -9,14 +9,32 @@ export type Operation =
   | "UnbondMixnode"
   | "UnbondMixnodeOnBehalf"
   | "UpdateMixnodeConfig"
+  | "UpdateMixnodeDetails"
+  | "IncreaseMixnodePledge"
+  | "DecreaseMixnodePledge"
   | "DelegateToMixnode"
   | "DelegateToMixnodeOnBehalf"
   | "UndelegateFromMixnode"
   | "UndelegateFromMixnodeOnBehalf"
+  | "RedelegateToMixnode"
+  | "RedelegateToMixnodeOnBehalf"
+  | "ClaimPendingWithdrawals"
   | "BondGateway"
   | "BondGatewayOnBehalf"
   | "UnbondGateway"
   | "UnbondGatewayOnBehalf"
+  | "UpdateGatewayDetails"
+  | "DelegateToGateway"
+  | "UndelegateFromGateway"
+  | "CreateFamily"
+  | "JoinFamily"
+  | "LeaveFamily"
+  | "ClaimOperatorReward"
+  | "ClaimDelegatorReward"
+  | "CompoundDelegatorReward"
+  | "ClaimGatewayOperatorReward"
+  | "ClaimGatewayDelegatorReward"
+  | "CompoundGatewayDelegatorReward"
   | "UpdateContractSettings"
   | "BeginMixnodeRewarding"
   | "FinishMixnodeRewarding"
@@ -3,4 +3,13 @@ export interface TauriContractStateParams {
   minimum_gateway_pledge: string;
   mixnode_rewarded_set_size: number;
   mixnode_active_set_size: number;
+  unbonding_period: bigint;
+  slashing_uptime_threshold: number;
+  slashing_poor_intervals: number;
+  slashed_percent: number;
+  slash_delegations: boolean;
+  slash_into_reward_pool: boolean;
+  gateway_reward_pool_share_percent: number;
+  gateway_rewarded_set_size: number;
+  gateway_operator_share_percent: number;
 }
\ No newline at end of file
@@ -0,0 +1,8 @@
+import { VestingPeriod } from "./vestingperiod";
+
+export interface VestingAccountSchedule {
+  start_time: bigint;
+  cliff_time: bigint | null;
+  linear: boolean;
+  periods: Array<VestingPeriod>;
+}
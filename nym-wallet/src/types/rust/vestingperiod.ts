@@ -0,0 +1,7 @@
+import { Coin } from "./coin";
+
+export interface VestingPeriod {
+  start_time: bigint;
+  end_time: bigint;
+  vested: Coin;
+}
@@ -0,0 +1,11 @@
+/*
+ * Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- defaults exist here since otherwise the columns couldn't have been added
+ALTER TABLE rewarding_report
+    ADD COLUMN eligible_gateways INTEGER NOT NULL default 0;
+
+ALTER TABLE rewarding_report
+    ADD COLUMN possibly_unrewarded_gateways INTEGER NOT NULL default 0;
@@ -0,0 +1,29 @@
+/*
+ * Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- signed summaries of test runs of all network monitors we know about (including our own)
+CREATE TABLE monitor_run_summary
+(
+    id               INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    monitor_identity VARCHAR NOT NULL,
+    timestamp        INTEGER NOT NULL,
+    signature        VARCHAR NOT NULL,
+
+    UNIQUE (monitor_identity, timestamp)
+);
+
+CREATE TABLE monitor_run_node_result
+(
+    id                     INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    monitor_run_summary_id INTEGER NOT NULL,
+    node_identity          VARCHAR NOT NULL,
+    is_mixnode             BOOLEAN NOT NULL,
+    reliability            INTEGER NOT NULL,
+
+    FOREIGN KEY (monitor_run_summary_id) REFERENCES monitor_run_summary (id) ON DELETE CASCADE
+);
+
+CREATE INDEX `monitor_run_summary_timestamp` ON `monitor_run_summary` (`timestamp`);
+CREATE INDEX `monitor_run_node_result_identity` ON `monitor_run_node_result` (`node_identity`, `is_mixnode`);
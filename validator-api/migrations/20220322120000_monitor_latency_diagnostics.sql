@@ -0,0 +1,32 @@
+/*
+ * Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- those are nullable as we don't have this information for any of the older test runs
+ALTER TABLE testing_route
+    ADD COLUMN reliability INTEGER;
+
+ALTER TABLE testing_route
+    ADD COLUMN latency_ms INTEGER;
+
+-- latency and failure information of each tested node, attributed based on its results across all test routes
+CREATE TABLE node_diagnostics
+(
+    id                INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    monitor_run_id    INTEGER NOT NULL,
+    node_identity     VARCHAR NOT NULL,
+    is_mixnode        BOOLEAN NOT NULL,
+    -- only applicable to mixnodes
+    layer             INTEGER,
+    -- null if none of the packets sent to the node got delivered
+    mean_latency_ms   INTEGER,
+    excess_latency_ms INTEGER,
+    tested_routes     INTEGER NOT NULL,
+    failed_routes     INTEGER NOT NULL,
+    timestamp         INTEGER NOT NULL,
+
+    FOREIGN KEY (monitor_run_id) REFERENCES monitor_run (id)
+);
+
+CREATE INDEX `node_diagnostics_index` ON `node_diagnostics` (`node_identity`, `is_mixnode`, `timestamp` desc);
@@ -0,0 +1,31 @@
+/*
+ * Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- per-node progress of the interval rewarding, so that it could be resumed after the process
+-- crashes without having to query the rewarding status of every single node
+CREATE TABLE rewarding_job
+(
+    id                    INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    interval_rewarding_id INTEGER NOT NULL,
+    node_identity         VARCHAR NOT NULL,
+    is_mixnode            BOOLEAN NOT NULL,
+    total_delegations     INTEGER NOT NULL,
+
+    -- json-encoded reward parameters as determined at the beginning of the rewarding.
+    -- they have to be reused when resuming as the reward pool shrinks with every rewarded node
+    reward_params         VARCHAR NOT NULL,
+
+    -- 0: pending, 1: submitted, 2: rewarded, 3: failed
+    status                INTEGER NOT NULL,
+    attempts              INTEGER NOT NULL DEFAULT 0,
+
+    -- gas estimated for the last batch this node was part of
+    estimated_batch_gas   INTEGER,
+    error_message         VARCHAR,
+    last_updated          INTEGER NOT NULL,
+
+    FOREIGN KEY (interval_rewarding_id) REFERENCES interval_rewarding (id),
+    UNIQUE (interval_rewarding_id, node_identity, is_mixnode)
+);
@@ -0,0 +1,11 @@
+/*
+ * Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- indices for the range queries of the historical node status api
+CREATE INDEX `monitor_run_timestamp` ON `monitor_run` (`timestamp`);
+CREATE INDEX `interval_rewarding_end_timestamp` ON `interval_rewarding` (`interval_end_timestamp`);
+CREATE INDEX `rewarding_job_node` ON `rewarding_job` (`node_identity`, `is_mixnode`);
+CREATE INDEX `mixnode_historical_uptime_index` ON `mixnode_historical_uptime` (`mixnode_details_id`, `date`);
+CREATE INDEX `gateway_historical_uptime_index` ON `gateway_historical_uptime` (`gateway_details_id`, `date`);
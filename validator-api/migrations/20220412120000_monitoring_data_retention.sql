@@ -0,0 +1,43 @@
+/*
+ * Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- daily aggregates of the raw network monitor data, kept after the per-run rows get pruned
+CREATE TABLE monitor_run_daily_summary
+(
+    -- 'YYYY-MM-DD'
+    date         VARCHAR NOT NULL PRIMARY KEY,
+    monitor_runs INTEGER NOT NULL
+);
+
+CREATE TABLE node_daily_summary
+(
+    node_identity       VARCHAR NOT NULL,
+    is_mixnode          BOOLEAN NOT NULL,
+    -- 'YYYY-MM-DD'
+    date                VARCHAR NOT NULL,
+    tests               INTEGER NOT NULL DEFAULT 0,
+    -- null if the node has only appeared in the diagnostics
+    average_reliability REAL,
+    tested_routes       INTEGER NOT NULL DEFAULT 0,
+    failed_routes       INTEGER NOT NULL DEFAULT 0,
+    mean_latency_ms     REAL,
+
+    PRIMARY KEY (node_identity, is_mixnode, date)
+);
+
+-- history of the pruning runs, exposed alongside the database metrics
+CREATE TABLE data_pruning
+(
+    id          INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    -- all raw data older than this timestamp got removed
+    cutoff      INTEGER NOT NULL,
+    pruned_rows INTEGER NOT NULL,
+    timestamp   INTEGER NOT NULL
+);
+
+CREATE INDEX `mixnode_status_timestamp` ON `mixnode_status` (`timestamp`);
+CREATE INDEX `gateway_status_timestamp` ON `gateway_status` (`timestamp`);
+CREATE INDEX `node_diagnostics_timestamp` ON `node_diagnostics` (`timestamp`);
+CREATE INDEX `testing_route_monitor_run` ON `testing_route` (`monitor_run_id`);
@@ -32,8 +32,14 @@ const DEFAULT_MINIMUM_TEST_ROUTES: usize = 1;
 const DEFAULT_ROUTE_TEST_PACKETS: usize = 1000;
 const DEFAULT_PER_NODE_TEST_PACKETS: usize = 3;
 
+const DEFAULT_MONITOR_PEER_SYNC_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
 const DEFAULT_CACHE_INTERVAL: Duration = Duration::from_secs(30);
 const DEFAULT_MONITOR_THRESHOLD: u8 = 60;
+const DEFAULT_MAXIMUM_REWARDING_BATCH_GAS: u64 = 10_000_000;
+
+const DEFAULT_RAW_DATA_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+const DEFAULT_PRUNING_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
 
 #[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct Config {
@@ -176,6 +182,30 @@ pub struct NetworkMonitor {
 
     /// Number of test packets sent to each node during regular monitor test run.
     per_node_test_packets: usize,
+
+    /// Path to file containing private identity key of the network monitor, used for signing
+    /// summaries of its test runs.
+    private_identity_key_file: PathBuf,
+
+    /// Path to file containing public identity key of the network monitor.
+    public_identity_key_file: PathBuf,
+
+    /// Other validator apis running network monitors whose signed test run summaries
+    /// should be included when determining node uptimes.
+    monitor_peers: Vec<MonitorPeer>,
+
+    /// Specifies the interval at which test run summaries are fetched from the monitor peers.
+    #[serde(with = "humantime_serde")]
+    peer_sync_interval: Duration,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MonitorPeer {
+    /// Address of the validator api of the peer.
+    pub api_url: Url,
+
+    /// Base58-encoded identity key of the peer's network monitor.
+    pub identity_key: String,
 }
 
 impl NetworkMonitor {
@@ -183,6 +213,14 @@ impl NetworkMonitor {
     fn default_backup_bandwidth_token_keys_dir() -> PathBuf {
         Config::default_data_directory(None).join("backup_bandwidth_token_keys_dir")
     }
+
+    fn default_private_identity_key_file() -> PathBuf {
+        Config::default_data_directory(None).join("private_monitor_identity.pem")
+    }
+
+    fn default_public_identity_key_file() -> PathBuf {
+        Config::default_data_directory(None).join("public_monitor_identity.pem")
+    }
 }
 
 impl Default for NetworkMonitor {
@@ -208,6 +246,10 @@ impl Default for NetworkMonitor {
             minimum_test_routes: DEFAULT_MINIMUM_TEST_ROUTES,
             route_test_packets: DEFAULT_ROUTE_TEST_PACKETS,
             per_node_test_packets: DEFAULT_PER_NODE_TEST_PACKETS,
+            private_identity_key_file: Self::default_private_identity_key_file(),
+            public_identity_key_file: Self::default_public_identity_key_file(),
+            monitor_peers: Vec::new(),
+            peer_sync_interval: DEFAULT_MONITOR_PEER_SYNC_INTERVAL,
         }
     }
 }
@@ -217,18 +259,42 @@ impl Default for NetworkMonitor {
 pub struct NodeStatusAPI {
     /// Path to the database file containing uptime statuses for all mixnodes and gateways.
     database_path: PathBuf,
+
+    /// Specifies for how long the raw per-run network monitor data is kept before it gets
+    /// rolled into daily summaries and removed. If rewarding is enabled, data of the interval
+    /// that has not been rewarded yet is never removed.
+    #[serde(with = "humantime_serde")]
+    raw_data_retention: Duration,
+
+    /// Specifies the interval at which the outdated raw data is pruned.
+    #[serde(with = "humantime_serde")]
+    pruning_interval: Duration,
+
+    /// Specifies whether the raw data should be exported to compressed files before being removed.
+    archive_pruned_data: bool,
+
+    /// Path to directory to which the compressed daily archives of pruned data are written.
+    archive_directory: PathBuf,
 }
 
 impl NodeStatusAPI {
     fn default_database_path() -> PathBuf {
         Config::default_data_directory(None).join("db.sqlite")
     }
+
+    fn default_archive_directory() -> PathBuf {
+        Config::default_data_directory(None).join("archive")
+    }
 }
 
 impl Default for NodeStatusAPI {
     fn default() -> Self {
         NodeStatusAPI {
             database_path: Self::default_database_path(),
+            raw_data_retention: DEFAULT_RAW_DATA_RETENTION,
+            pruning_interval: DEFAULT_PRUNING_INTERVAL,
+            archive_pruned_data: true,
+            archive_directory: Self::default_archive_directory(),
         }
     }
 }
@@ -238,12 +304,32 @@ impl Default for NodeStatusAPI {
 pub struct TopologyCacher {
     #[serde(with = "humantime_serde")]
     caching_interval: Duration,
+
+    /// Path to file containing private identity key of this validator api, used for signing
+    /// the topology responses.
+    private_identity_key_file: PathBuf,
+
+    /// Path to file containing public identity key of this validator api. Clients wishing to
+    /// verify the signed topology responses have to be configured with it.
+    public_identity_key_file: PathBuf,
+}
+
+impl TopologyCacher {
+    fn default_private_identity_key_file() -> PathBuf {
+        Config::default_data_directory(None).join("private_api_identity.pem")
+    }
+
+    fn default_public_identity_key_file() -> PathBuf {
+        Config::default_data_directory(None).join("public_api_identity.pem")
+    }
 }
 
 impl Default for TopologyCacher {
     fn default() -> Self {
         TopologyCacher {
             caching_interval: DEFAULT_CACHE_INTERVAL,
+            private_identity_key_file: Self::default_private_identity_key_file(),
+            public_identity_key_file: Self::default_public_identity_key_file(),
         }
     }
 }
@@ -261,6 +347,10 @@ pub struct Rewarding {
     /// distribute rewards for given interval.
     /// Note, only values in range 0-100 are valid
     minimum_interval_monitor_threshold: u8,
+
+    /// Maximum amount of gas a single batch of rewarding messages is estimated to use.
+    /// Batches estimated to go above it are split into smaller ones before being sent.
+    maximum_batch_gas: u64,
 }
 
 impl Default for Rewarding {
@@ -269,6 +359,7 @@ impl Default for Rewarding {
             enabled: false,
             mnemonic: String::default(),
             minimum_interval_monitor_threshold: DEFAULT_MONITOR_THRESHOLD,
+            maximum_batch_gas: DEFAULT_MAXIMUM_REWARDING_BATCH_GAS,
         }
     }
 }
@@ -424,14 +515,54 @@ impl Config {
         self.network_monitor.per_node_test_packets
     }
 
+    pub fn get_private_monitor_identity_key_file(&self) -> PathBuf {
+        self.network_monitor.private_identity_key_file.clone()
+    }
+
+    pub fn get_public_monitor_identity_key_file(&self) -> PathBuf {
+        self.network_monitor.public_identity_key_file.clone()
+    }
+
+    pub fn get_monitor_peers(&self) -> Vec<MonitorPeer> {
+        self.network_monitor.monitor_peers.clone()
+    }
+
+    pub fn get_monitor_peer_sync_interval(&self) -> Duration {
+        self.network_monitor.peer_sync_interval
+    }
+
     pub fn get_caching_interval(&self) -> Duration {
         self.topology_cacher.caching_interval
     }
 
+    pub fn get_private_api_identity_key_file(&self) -> PathBuf {
+        self.topology_cacher.private_identity_key_file.clone()
+    }
+
+    pub fn get_public_api_identity_key_file(&self) -> PathBuf {
+        self.topology_cacher.public_identity_key_file.clone()
+    }
+
     pub fn get_node_status_api_database_path(&self) -> PathBuf {
         self.node_status_api.database_path.clone()
     }
 
+    pub fn get_raw_data_retention(&self) -> Duration {
+        self.node_status_api.raw_data_retention
+    }
+
+    pub fn get_pruning_interval(&self) -> Duration {
+        self.node_status_api.pruning_interval
+    }
+
+    pub fn get_archive_pruned_data(&self) -> bool {
+        self.node_status_api.archive_pruned_data
+    }
+
+    pub fn get_archive_directory(&self) -> PathBuf {
+        self.node_status_api.archive_directory.clone()
+    }
+
     // fix dead code warnings as this method is only ever used with coconut feature
     #[cfg(feature = "coconut")]
     pub fn get_all_validator_api_endpoints(&self) -> Vec<Url> {
@@ -441,4 +572,8 @@ impl Config {
     pub fn get_minimum_interval_monitor_threshold(&self) -> u8 {
         self.rewarding.minimum_interval_monitor_threshold
     }
+
+    pub fn get_maximum_rewarding_batch_gas(&self) -> u64 {
+        self.rewarding.maximum_batch_gas
+    }
 }
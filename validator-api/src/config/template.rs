@@ -83,12 +83,56 @@ route_test_packets = {{ network_monitor.route_test_packets }}
 
 # Number of test packets sent to each node during regular monitor test run.
 per_node_test_packets = {{ network_monitor.per_node_test_packets }}
+
+# Path to file containing private identity key of the network monitor, used for signing
+# summaries of its test runs.
+private_identity_key_file = '{{ network_monitor.private_identity_key_file }}'
+
+# Path to file containing public identity key of the network monitor.
+public_identity_key_file = '{{ network_monitor.public_identity_key_file }}'
+
+# Other validator apis running network monitors whose signed test run summaries
+# should be included when determining node uptimes.
+monitor_peers = [
+    {{#each network_monitor.monitor_peers }}
+        { api_url = '{{this.api_url}}', identity_key = '{{this.identity_key}}' },
+    {{/each}}
+]
+
+# Specifies the interval at which test run summaries are fetched from the monitor peers.
+peer_sync_interval = '{{ network_monitor.peer_sync_interval }}'
     
 [node_status_api]
 
 # Path to the database file containing uptime statuses for all mixnodes and gateways.
 database_path = '{{ node_status_api.database_path }}'
 
+# Specifies for how long the raw per-run network monitor data is kept before it gets
+# rolled into daily summaries and removed. If rewarding is enabled, data of the interval
+# that has not been rewarded yet is never removed.
+raw_data_retention = '{{ node_status_api.raw_data_retention }}'
+
+# Specifies the interval at which the outdated raw data is pruned.
+pruning_interval = '{{ node_status_api.pruning_interval }}'
+
+# Specifies whether the raw data should be exported to compressed files before being removed.
+archive_pruned_data = {{ node_status_api.archive_pruned_data }}
+
+# Path to directory to which the compressed daily archives of pruned data are written.
+archive_directory = '{{ node_status_api.archive_directory }}'
+
+##### topology cacher config options #####
+
+[topology_cacher]
+
+# Path to file containing private identity key of this validator api, used for signing
+# the topology responses.
+private_identity_key_file = '{{ topology_cacher.private_identity_key_file }}'
+
+# Path to file containing public identity key of this validator api. Clients wishing to
+# verify the signed topology responses have to be configured with it.
+public_identity_key_file = '{{ topology_cacher.public_identity_key_file }}'
+
 ##### rewarding config options #####
 
 [rewarding]
@@ -104,5 +148,9 @@ mnemonic = '{{ rewarding.mnemonic }}'
 # Note, only values in range 0-100 are valid
 minimum_interval_monitor_threshold = {{ rewarding.minimum_interval_monitor_threshold }}
 
+# Maximum amount of gas a single batch of rewarding messages is estimated to use.
+# Batches estimated to go above it are split into smaller ones before being sent.
+maximum_batch_gas = {{ rewarding.maximum_batch_gas }}
+
 "#
 }
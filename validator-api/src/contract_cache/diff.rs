@@ -0,0 +1,225 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use mixnet_contract_common::{GatewayBond, IdentityKey, MixNodeBond};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ChangedBond<T> {
+    pub identity: IdentityKey,
+    // paths of all the fields that got modified, e.g. "mix_node.host"
+    pub changed_fields: Vec<String>,
+    pub bond: T,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct BondsDiff<T> {
+    pub bonded: Vec<T>,
+    pub unbonded: Vec<IdentityKey>,
+    pub changed: Vec<ChangedBond<T>>,
+}
+
+impl<T> BondsDiff<T> {
+    pub fn is_empty(&self) -> bool {
+        self.bonded.is_empty() && self.unbonded.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct SetDiff {
+    pub entered: Vec<IdentityKey>,
+    pub left: Vec<IdentityKey>,
+}
+
+impl SetDiff {
+    pub fn is_empty(&self) -> bool {
+        self.entered.is_empty() && self.left.is_empty()
+    }
+}
+
+/// Changes to the contract state observed between two consecutive refreshes of the validator cache.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ValidatorCacheDiff {
+    pub as_at: i64,
+    pub mixnodes: BondsDiff<MixNodeBond>,
+    pub gateways: BondsDiff<GatewayBond>,
+    pub rewarded_set: SetDiff,
+    pub active_set: SetDiff,
+}
+
+impl ValidatorCacheDiff {
+    pub fn is_empty(&self) -> bool {
+        self.mixnodes.is_empty()
+            && self.gateways.is_empty()
+            && self.rewarded_set.is_empty()
+            && self.active_set.is_empty()
+    }
+}
+
+pub(crate) fn diff_bonds<T, F>(old: &[T], new: &[T], identity: F) -> BondsDiff<T>
+where
+    T: Serialize + Clone,
+    F: Fn(&T) -> &IdentityKey,
+{
+    let old_bonds = old
+        .iter()
+        .map(|bond| (identity(bond), bond))
+        .collect::<HashMap<_, _>>();
+    let new_identities = new.iter().map(&identity).collect::<HashSet<_>>();
+
+    let mut bonded = Vec::new();
+    let mut changed = Vec::new();
+    for bond in new {
+        match old_bonds.get(identity(bond)) {
+            None => bonded.push(bond.clone()),
+            Some(old_bond) => {
+                let changed_fields = changed_fields(old_bond, bond);
+                if !changed_fields.is_empty() {
+                    changed.push(ChangedBond {
+                        identity: identity(bond).clone(),
+                        changed_fields,
+                        bond: bond.clone(),
+                    })
+                }
+            }
+        }
+    }
+
+    let unbonded = old
+        .iter()
+        .map(&identity)
+        .filter(|old_identity| !new_identities.contains(old_identity))
+        .cloned()
+        .collect();
+
+    BondsDiff {
+        bonded,
+        unbonded,
+        changed,
+    }
+}
+
+pub(crate) fn diff_sets(old: &[MixNodeBond], new: &[MixNodeBond]) -> SetDiff {
+    let old_identities = old
+        .iter()
+        .map(|bond| bond.identity())
+        .collect::<HashSet<_>>();
+    let new_identities = new
+        .iter()
+        .map(|bond| bond.identity())
+        .collect::<HashSet<_>>();
+
+    SetDiff {
+        entered: new
+            .iter()
+            .map(|bond| bond.identity())
+            .filter(|identity| !old_identities.contains(identity))
+            .cloned()
+            .collect(),
+        left: old
+            .iter()
+            .map(|bond| bond.identity())
+            .filter(|identity| !new_identities.contains(identity))
+            .cloned()
+            .collect(),
+    }
+}
+
+// compares the serialized representations so that we wouldn't need to update this code
+// every time a field is added to a bond
+fn changed_fields<T: Serialize>(old: &T, new: &T) -> Vec<String> {
+    let mut changed = Vec::new();
+    match (serde_json::to_value(old), serde_json::to_value(new)) {
+        (Ok(old), Ok(new)) => collect_changed_fields(&old, &new, "", &mut changed),
+        _ => error!("failed to serialize bonds for comparison"),
+    }
+    changed
+}
+
+fn collect_changed_fields(old: &Value, new: &Value, path: &str, changed: &mut Vec<String>) {
+    match (old, new) {
+        (Value::Object(old_fields), Value::Object(new_fields)) => {
+            let keys = old_fields
+                .keys()
+                .chain(new_fields.keys())
+                .collect::<HashSet<_>>();
+            let mut keys = keys.into_iter().collect::<Vec<_>>();
+            keys.sort();
+
+            for key in keys {
+                let field_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match (old_fields.get(key), new_fields.get(key)) {
+                    (Some(old_value), Some(new_value)) => {
+                        collect_changed_fields(old_value, new_value, &field_path, changed)
+                    }
+                    _ => changed.push(field_path),
+                }
+            }
+        }
+        (old, new) if old != new => changed.push(path.to_string()),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mixnet_contract_common::{Addr, Coin, Gateway};
+
+    fn gateway_bond(identity: &str, host: &str) -> GatewayBond {
+        GatewayBond::new(
+            Coin::new(100_000_000, "unym"),
+            Addr::unchecked("gateway-owner"),
+            1,
+            Gateway {
+                host: host.to_string(),
+                mix_port: 1789,
+                clients_port: 9000,
+                location: "Somewhere".to_string(),
+                sphinx_key: "sphinx".to_string(),
+                identity_key: identity.to_string(),
+                version: "1.0.0".to_string(),
+            },
+            None,
+        )
+    }
+
+    #[test]
+    fn bonded_unbonded_and_changed_gateways_are_detected() {
+        let old = vec![
+            gateway_bond("unchanged", "1.1.1.1"),
+            gateway_bond("unbonded", "2.2.2.2"),
+            gateway_bond("changed", "3.3.3.3"),
+        ];
+        let new = vec![
+            gateway_bond("unchanged", "1.1.1.1"),
+            gateway_bond("changed", "4.4.4.4"),
+            gateway_bond("bonded", "5.5.5.5"),
+        ];
+
+        let diff = diff_bonds(&old, &new, |bond| bond.identity());
+        assert_eq!(diff.bonded, vec![gateway_bond("bonded", "5.5.5.5")]);
+        assert_eq!(diff.unbonded, vec!["unbonded".to_string()]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].identity, "changed");
+        assert_eq!(
+            diff.changed[0].changed_fields,
+            vec!["gateway.host".to_string()]
+        );
+    }
+
+    #[test]
+    fn identical_bonds_produce_empty_diff() {
+        let bonds = vec![
+            gateway_bond("first", "1.1.1.1"),
+            gateway_bond("second", "2.2.2.2"),
+        ];
+        assert!(diff_bonds(&bonds, &bonds, |bond| bond.identity()).is_empty());
+    }
+}
@@ -1,13 +1,16 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::contract_cache::diff::{diff_bonds, diff_sets, ValidatorCacheDiff};
+use crate::contract_cache::signing::TopologySigner;
 use crate::nymd_client::Client;
 use crate::rewarding::IntervalRewardParams;
 use ::time::OffsetDateTime;
 use anyhow::Result;
 use config::defaults::VALIDATOR_API_VERSION;
 use mixnet_contract_common::{
-    GatewayBond, IdentityKey, IdentityKeyRef, Interval, MixNodeBond, RewardedSetNodeStatus,
+    GatewayBond, IdentityKey, IdentityKeyRef, Interval, LayerAssignment, MixNodeBond,
+    RewardedSetNodeStatus,
 };
 
 use rocket::fairing::AdHoc;
@@ -16,12 +19,17 @@ use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::{Notify, RwLock};
+use tokio::sync::{broadcast, Notify, RwLock};
 use tokio::time;
 use validator_api_requests::models::MixnodeStatus;
 use validator_client::nymd::CosmWasmClient;
 
+pub(crate) mod diff;
 pub(crate) mod routes;
+pub(crate) mod signing;
+
+// number of cache diffs that can be buffered for a slow subscriber before it starts missing them
+const DIFF_CHANNEL_CAPACITY: usize = 16;
 
 pub struct ValidatorCacheRefresher<C> {
     nymd_client: Client<C>,
@@ -34,6 +42,7 @@ pub struct ValidatorCacheRefresher<C> {
 pub struct ValidatorCache {
     initialised: Arc<AtomicBool>,
     inner: Arc<RwLock<ValidatorCacheInner>>,
+    diff_sender: broadcast::Sender<Arc<ValidatorCacheDiff>>,
 }
 
 struct ValidatorCacheInner {
@@ -42,6 +51,7 @@ struct ValidatorCacheInner {
 
     rewarded_set: Cache<Vec<MixNodeBond>>,
     active_set: Cache<Vec<MixNodeBond>>,
+    layer_assignment: Cache<Vec<LayerAssignment>>,
 
     current_reward_params: Cache<IntervalRewardParams>,
     current_interval: Cache<Interval>,
@@ -56,6 +66,9 @@ fn current_unix_timestamp() -> i64 {
 pub struct Cache<T> {
     value: T,
     as_at: i64,
+    // height of the block at which the cache refresh has started, i.e. the value reflects
+    // the contract state from at least that height
+    block_height: u64,
 }
 
 impl<T: Clone> Cache<T> {
@@ -63,18 +76,24 @@ impl<T: Clone> Cache<T> {
         Cache {
             value,
             as_at: current_unix_timestamp(),
+            block_height: 0,
         }
     }
 
-    fn update(&mut self, value: T) {
+    fn update(&mut self, value: T, block_height: u64) {
         self.value = value;
-        self.as_at = current_unix_timestamp()
+        self.as_at = current_unix_timestamp();
+        self.block_height = block_height;
     }
 
     pub fn timestamp(&self) -> i64 {
         self.as_at
     }
 
+    pub fn block_height(&self) -> u64 {
+        self.block_height
+    }
+
     pub fn into_inner(self) -> T {
         self.value
     }
@@ -95,23 +114,34 @@ impl<C> ValidatorCacheRefresher<C> {
         }
     }
 
+    // note: the returned bonds have their layers replaced with the ones assigned for the current rewarded set
     fn collect_rewarded_and_active_set_details(
         &self,
         all_mixnodes: &[MixNodeBond],
         rewarded_set_identities: Vec<(IdentityKey, RewardedSetNodeStatus)>,
+        layer_assignment: &[LayerAssignment],
     ) -> (Vec<MixNodeBond>, Vec<MixNodeBond>) {
         let mut active_set = Vec::new();
         let mut rewarded_set = Vec::new();
         let rewarded_set_identities = rewarded_set_identities
             .into_iter()
             .collect::<HashMap<_, _>>();
+        let layer_assignment = layer_assignment
+            .iter()
+            .map(|assigned| (&assigned.identity, assigned.layer))
+            .collect::<HashMap<_, _>>();
 
         for mix in all_mixnodes {
             if let Some(status) = rewarded_set_identities.get(mix.identity()) {
-                rewarded_set.push(mix.clone());
+                let mut mix = mix.clone();
+                if let Some(assigned_layer) = layer_assignment.get(mix.identity()) {
+                    mix.layer = *assigned_layer;
+                }
+
                 if status.is_active() {
                     active_set.push(mix.clone())
                 }
+                rewarded_set.push(mix);
             }
         }
 
@@ -122,14 +152,21 @@ impl<C> ValidatorCacheRefresher<C> {
     where
         C: CosmWasmClient + Sync,
     {
+        // obtained before querying the contract so that the cached values would never
+        // be older than the height we announce
+        let block_height = self.nymd_client.current_block_height().await?;
         let (mixnodes, gateways) = tokio::try_join!(
             self.nymd_client.get_mixnodes(),
             self.nymd_client.get_gateways(),
         )?;
 
         let rewarded_set_identities = self.nymd_client.get_rewarded_set_identities().await?;
-        let (rewarded_set, active_set) =
-            self.collect_rewarded_and_active_set_details(&mixnodes, rewarded_set_identities);
+        let layer_assignment = self.nymd_client.get_layer_assignment().await?;
+        let (rewarded_set, active_set) = self.collect_rewarded_and_active_set_details(
+            &mixnodes,
+            rewarded_set_identities,
+            &layer_assignment,
+        );
 
         let interval_rewarding_params = self
             .nymd_client
@@ -149,8 +186,10 @@ impl<C> ValidatorCacheRefresher<C> {
                 gateways,
                 rewarded_set,
                 active_set,
+                layer_assignment,
                 interval_rewarding_params,
                 current_interval,
+                block_height,
             )
             .await;
 
@@ -192,15 +231,17 @@ impl<C> ValidatorCacheRefresher<C> {
 
 impl ValidatorCache {
     fn new() -> Self {
+        let (diff_sender, _) = broadcast::channel(DIFF_CHANNEL_CAPACITY);
         ValidatorCache {
             initialised: Arc::new(AtomicBool::new(false)),
             inner: Arc::new(RwLock::new(ValidatorCacheInner::new())),
+            diff_sender,
         }
     }
 
-    pub fn stage() -> AdHoc {
+    pub(crate) fn stage(signer: TopologySigner) -> AdHoc {
         AdHoc::on_ignite("Validator Cache Stage", |rocket| async {
-            rocket.manage(Self::new()).mount(
+            rocket.manage(Self::new()).manage(signer).mount(
                 // this format! is so ugly...
                 format!("/{}", VALIDATOR_API_VERSION),
                 routes![
@@ -208,6 +249,12 @@ impl ValidatorCache {
                     routes::get_gateways,
                     routes::get_active_set,
                     routes::get_rewarded_set,
+                    routes::get_layer_assignment,
+                    routes::get_signed_gateways,
+                    routes::get_signed_active_set,
+                    routes::get_signed_rewarded_set,
+                    routes::get_signed_layer_assignment,
+                    routes::get_cache_events,
                 ],
             )
         })
@@ -219,19 +266,42 @@ impl ValidatorCache {
         gateways: Vec<GatewayBond>,
         rewarded_set: Vec<MixNodeBond>,
         active_set: Vec<MixNodeBond>,
+        layer_assignment: Vec<LayerAssignment>,
         interval_rewarding_params: IntervalRewardParams,
         current_interval: Interval,
+        block_height: u64,
     ) {
         let mut inner = self.inner.write().await;
 
-        inner.mixnodes.update(mixnodes);
-        inner.gateways.update(gateways);
-        inner.rewarded_set.update(rewarded_set);
-        inner.active_set.update(active_set);
+        // there's no point in announcing the entire network as newly bonded upon the first refresh
+        if self.initialised() {
+            let diff = ValidatorCacheDiff {
+                as_at: current_unix_timestamp(),
+                mixnodes: diff_bonds(&inner.mixnodes.value, &mixnodes, |bond| bond.identity()),
+                gateways: diff_bonds(&inner.gateways.value, &gateways, |bond| bond.identity()),
+                rewarded_set: diff_sets(&inner.rewarded_set.value, &rewarded_set),
+                active_set: diff_sets(&inner.active_set.value, &active_set),
+            };
+
+            // sending only fails if there are no subscribers, which is perfectly fine
+            if !diff.is_empty() {
+                let _ = self.diff_sender.send(Arc::new(diff));
+            }
+        }
+
+        inner.mixnodes.update(mixnodes, block_height);
+        inner.gateways.update(gateways, block_height);
+        inner.rewarded_set.update(rewarded_set, block_height);
+        inner.active_set.update(active_set, block_height);
+        inner
+            .layer_assignment
+            .update(layer_assignment, block_height);
         inner
             .current_reward_params
-            .update(interval_rewarding_params);
-        inner.current_interval.update(current_interval);
+            .update(interval_rewarding_params, block_height);
+        inner
+            .current_interval
+            .update(current_interval, block_height);
     }
 
     pub async fn mixnodes(&self) -> Cache<Vec<MixNodeBond>> {
@@ -250,6 +320,10 @@ impl ValidatorCache {
         self.inner.read().await.active_set.clone()
     }
 
+    pub async fn layer_assignment(&self) -> Cache<Vec<LayerAssignment>> {
+        self.inner.read().await.layer_assignment.clone()
+    }
+
     pub(crate) async fn interval_reward_params(&self) -> Cache<IntervalRewardParams> {
         self.inner.read().await.current_reward_params.clone()
     }
@@ -296,6 +370,10 @@ impl ValidatorCache {
         self.mixnode_details(&identity).await.1
     }
 
+    pub(crate) fn subscribe_to_diffs(&self) -> broadcast::Receiver<Arc<ValidatorCacheDiff>> {
+        self.diff_sender.subscribe()
+    }
+
     pub fn initialised(&self) -> bool {
         self.initialised.load(Ordering::Relaxed)
     }
@@ -320,6 +398,7 @@ impl ValidatorCacheInner {
             gateways: Cache::default(),
             rewarded_set: Cache::default(),
             active_set: Cache::default(),
+            layer_assignment: Cache::default(),
             current_reward_params: Cache::new(IntervalRewardParams::new_empty()),
             // setting it to a dummy value on creation is fine, as nothing will be able to ready from it
             // since 'initialised' flag won't be set
@@ -1,10 +1,17 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::contract_cache::signing::TopologySigner;
 use crate::contract_cache::ValidatorCache;
-use mixnet_contract_common::{GatewayBond, MixNodeBond};
+use crate::node_status_api::models::ErrorResponse;
+use mixnet_contract_common::{GatewayBond, LayerAssignment, MixNodeBond};
+use rocket::http::Status;
+use rocket::response::stream::{Event, EventStream};
 use rocket::serde::json::Json;
-use rocket::State;
+use rocket::tokio::select;
+use rocket::tokio::sync::broadcast::error::RecvError;
+use rocket::{Shutdown, State};
+use validator_api_requests::models::{SignedTopologyResponse, TopologySnapshotKind};
 
 #[get("/mixnodes")]
 pub(crate) async fn get_mixnodes(cache: &State<ValidatorCache>) -> Json<Vec<MixNodeBond>> {
@@ -25,3 +32,100 @@ pub(crate) async fn get_rewarded_set(cache: &State<ValidatorCache>) -> Json<Vec<
 pub(crate) async fn get_active_set(cache: &State<ValidatorCache>) -> Json<Vec<MixNodeBond>> {
     Json(cache.active_set().await.value)
 }
+
+#[get("/mixnodes/layers")]
+pub(crate) async fn get_layer_assignment(
+    cache: &State<ValidatorCache>,
+) -> Json<Vec<LayerAssignment>> {
+    Json(cache.layer_assignment().await.value)
+}
+
+fn ensure_initialised(cache: &ValidatorCache) -> Result<(), ErrorResponse> {
+    // we must not vouch for the empty placeholder values present before the first refresh
+    if cache.initialised() {
+        Ok(())
+    } else {
+        Err(ErrorResponse::new(
+            "the validator cache has not been initialised yet",
+            Status::ServiceUnavailable,
+        ))
+    }
+}
+
+// the signed variants allow clients to verify the topology came from an api they trust,
+// regardless of how it reached them
+#[get("/gateways/signed")]
+pub(crate) async fn get_signed_gateways(
+    cache: &State<ValidatorCache>,
+    signer: &State<TopologySigner>,
+) -> Result<Json<SignedTopologyResponse>, ErrorResponse> {
+    ensure_initialised(cache)?;
+    Ok(Json(signer.sign(
+        TopologySnapshotKind::Gateways,
+        cache.gateways().await,
+    )))
+}
+
+#[get("/mixnodes/active/signed")]
+pub(crate) async fn get_signed_active_set(
+    cache: &State<ValidatorCache>,
+    signer: &State<TopologySigner>,
+) -> Result<Json<SignedTopologyResponse>, ErrorResponse> {
+    ensure_initialised(cache)?;
+    Ok(Json(signer.sign(
+        TopologySnapshotKind::ActiveSet,
+        cache.active_set().await,
+    )))
+}
+
+#[get("/mixnodes/rewarded/signed")]
+pub(crate) async fn get_signed_rewarded_set(
+    cache: &State<ValidatorCache>,
+    signer: &State<TopologySigner>,
+) -> Result<Json<SignedTopologyResponse>, ErrorResponse> {
+    ensure_initialised(cache)?;
+    Ok(Json(signer.sign(
+        TopologySnapshotKind::RewardedSet,
+        cache.rewarded_set().await,
+    )))
+}
+
+#[get("/mixnodes/layers/signed")]
+pub(crate) async fn get_signed_layer_assignment(
+    cache: &State<ValidatorCache>,
+    signer: &State<TopologySigner>,
+) -> Result<Json<SignedTopologyResponse>, ErrorResponse> {
+    ensure_initialised(cache)?;
+    Ok(Json(signer.sign(
+        TopologySnapshotKind::LayerAssignment,
+        cache.layer_assignment().await,
+    )))
+}
+
+// streams changes to the cache as they are observed, so that the clients wouldn't need to
+// keep polling the full lists
+#[get("/events")]
+pub(crate) async fn get_cache_events(
+    cache: &State<ValidatorCache>,
+    mut shutdown: Shutdown,
+) -> EventStream![] {
+    let mut diffs = cache.subscribe_to_diffs();
+    EventStream! {
+        loop {
+            let diff = select! {
+                diff = diffs.recv() => match diff {
+                    Ok(diff) => diff,
+                    Err(RecvError::Closed) => break,
+                    // the client should re-fetch the full lists as it has missed some of the changes
+                    Err(RecvError::Lagged(missed)) => {
+                        yield Event::data(missed.to_string()).event("lagged");
+                        continue;
+                    }
+                },
+                _ = &mut shutdown => break,
+            };
+
+            yield Event::json(&*diff).event("diff");
+        }
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use crate::contract_cache::Cache;
+use crypto::asymmetric::identity;
+use serde::Serialize;
+use std::sync::Arc;
+use validator_api_requests::models::{
+    SignedTopologyResponse, TopologySnapshot, TopologySnapshotKind,
+};
+
+#[derive(Clone)]
+pub(crate) struct TopologySigner {
+    identity_keypair: Arc<identity::KeyPair>,
+}
+
+impl TopologySigner {
+    pub(crate) fn new(config: &Config) -> Self {
+        let key_paths = pemstore::KeyPairPath::new(
+            config.get_private_api_identity_key_file(),
+            config.get_public_api_identity_key_file(),
+        );
+
+        TopologySigner {
+            identity_keypair: Arc::new(crate::identity::load_or_generate_identity(
+                &key_paths,
+                "validator api",
+            )),
+        }
+    }
+
+    pub(crate) fn identity(&self) -> String {
+        self.identity_keypair.public_key().to_base58_string()
+    }
+
+    pub(crate) fn sign<T>(
+        &self,
+        kind: TopologySnapshotKind,
+        cache: Cache<T>,
+    ) -> SignedTopologyResponse
+    where
+        T: Serialize + Clone,
+    {
+        let snapshot = TopologySnapshot {
+            kind,
+            block_height: cache.block_height(),
+            as_at: cache.timestamp(),
+            nodes: cache.into_inner(),
+        };
+        let snapshot =
+            serde_json::to_string(&snapshot).expect("failed to serialize topology snapshot");
+
+        let signature = self
+            .identity_keypair
+            .private_key()
+            .sign(snapshot.as_bytes())
+            .to_base58_string();
+
+        SignedTopologyResponse {
+            snapshot,
+            signer: self.identity(),
+            signature,
+        }
+    }
+}
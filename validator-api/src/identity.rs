@@ -0,0 +1,31 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::asymmetric::identity;
+use log::{error, info};
+
+/// Loads the identity keypair from the specified files, or generates (and attempts to store)
+/// a fresh one if it couldn't be loaded.
+///
+/// # Arguments
+///
+/// * `key_paths`: paths to the files containing the private and public keys.
+/// * `owner`: human-readable name of the component using the keys, used for logging.
+pub(crate) fn load_or_generate_identity(
+    key_paths: &pemstore::KeyPairPath,
+    owner: &str,
+) -> identity::KeyPair {
+    if let Ok(keypair) = pemstore::load_keypair(key_paths) {
+        return keypair;
+    }
+
+    info!("Generating new identity keys for the {}", owner);
+    let mut rng = rand_07::rngs::OsRng;
+    let keypair = identity::KeyPair::new(&mut rng);
+    if let Err(err) = pemstore::store_keypair(&keypair, key_paths) {
+        // we can still sign with the keys, but anyone verifying our signatures will have to be
+        // told about the new key after every restart
+        error!("Failed to store {} identity keys - {}", owner, err);
+    }
+    keypair
+}
@@ -5,14 +5,20 @@
 extern crate rocket;
 
 use crate::config::Config;
+use crate::contract_cache::signing::TopologySigner;
 use crate::contract_cache::ValidatorCacheRefresher;
+use crate::monitor_consensus::{MonitorConsensus, MonitorPeersSynchroniser};
 use crate::network_monitor::NetworkMonitorBuilder;
+use crate::node_status_api::pruner::{export_monitoring_data, MonitoringDataPruner};
 use crate::node_status_api::uptime_updater::HistoricalUptimeUpdater;
 use crate::nymd_client::Client;
+use crate::rewarding::simulator::{
+    format_simulation, RewardingSimulator, RewardingSnapshot, SimulationFormat,
+};
 use crate::rewarding::Rewarder;
 use crate::storage::ValidatorApiStorage;
 use ::config::NymConfig;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use clap::{crate_version, App, Arg, ArgMatches};
 use contract_cache::ValidatorCache;
 use log::{info, warn};
@@ -20,9 +26,11 @@ use rocket::fairing::AdHoc;
 use rocket::http::Method;
 use rocket::{Ignite, Rocket};
 use rocket_cors::{AllowedHeaders, AllowedOrigins, Cors};
+use std::path::Path;
 use std::process;
 use std::sync::Arc;
 use std::time::Duration;
+use time::OffsetDateTime;
 use tokio::sync::Notify;
 use url::Url;
 use validator_client::nymd::SigningNymdClient;
@@ -34,6 +42,8 @@ use coconut::InternalSignRequest;
 
 pub(crate) mod config;
 pub(crate) mod contract_cache;
+mod identity;
+mod monitor_consensus;
 mod network_monitor;
 mod node_status_api;
 pub(crate) mod nymd_client;
@@ -66,6 +76,13 @@ const ETH_PRIVATE_KEY: &str = "eth_private_key";
 
 const REWARDING_MONITOR_THRESHOLD_ARG: &str = "monitor-threshold";
 
+const SIMULATE_REWARDING_ARG: &str = "simulate-rewarding";
+const SIMULATION_FORMAT_ARG: &str = "simulation-format";
+const SIMULATION_OUTPUT_ARG: &str = "simulation-output";
+
+const EXPORT_MONITORING_DATA_ARG: &str = "export-monitoring-data";
+const EXPORT_UNTIL_ARG: &str = "export-until";
+
 fn parse_validators(raw: &str) -> Vec<Url> {
     raw.split(',')
         .map(|raw_validator| {
@@ -168,6 +185,40 @@ fn parse_args<'a>() -> ArgMatches<'a> {
                 .long(REWARDING_MONITOR_THRESHOLD_ARG)
                 .requires(REWARDING_ENABLED)
         )
+        .arg(
+            Arg::with_name(SIMULATE_REWARDING_ARG)
+                .help("Path to a previously saved rewarding snapshot. If provided, rewards for its interval are simulated offline, using locally stored network monitor data, and the process exits")
+                .takes_value(true)
+                .long(SIMULATE_REWARDING_ARG)
+        )
+        .arg(
+            Arg::with_name(SIMULATION_FORMAT_ARG)
+                .help("Output format of the rewarding simulation")
+                .takes_value(true)
+                .possible_values(&["json", "csv"])
+                .long(SIMULATION_FORMAT_ARG)
+                .requires(SIMULATE_REWARDING_ARG)
+        )
+        .arg(
+            Arg::with_name(SIMULATION_OUTPUT_ARG)
+                .help("Path to the file the rewarding simulation results should be written to. If not provided, they are written to stdout")
+                .takes_value(true)
+                .long(SIMULATION_OUTPUT_ARG)
+                .requires(SIMULATE_REWARDING_ARG)
+        )
+        .arg(
+            Arg::with_name(EXPORT_MONITORING_DATA_ARG)
+                .help("Path to a directory. If provided, the raw network monitoring data of all complete days is exported to it as compressed archives, without removing it from the database, and the process exits")
+                .takes_value(true)
+                .long(EXPORT_MONITORING_DATA_ARG)
+        )
+        .arg(
+            Arg::with_name(EXPORT_UNTIL_ARG)
+                .help("Unix timestamp before which the raw network monitoring data should be exported. If not provided, all data before the start of the current day is exported")
+                .takes_value(true)
+                .long(EXPORT_UNTIL_ARG)
+                .requires(EXPORT_MONITORING_DATA_ARG)
+        )
         .arg(
             Arg::with_name(TESTNET_MODE_ARG_NAME)
                 .long(TESTNET_MODE_ARG_NAME)
@@ -349,12 +400,14 @@ fn setup_network_monitor<'a>(
     // get instances of managed states
     let node_status_storage = rocket.state::<ValidatorApiStorage>().unwrap().clone();
     let validator_cache = rocket.state::<ValidatorCache>().unwrap().clone();
+    let monitor_consensus = rocket.state::<MonitorConsensus>().unwrap().clone();
 
     Some(NetworkMonitorBuilder::new(
         config,
         system_version,
         node_status_storage,
         validator_cache,
+        monitor_consensus,
     ))
 }
 
@@ -375,6 +428,7 @@ async fn setup_rewarder(
         // get instances of managed states
         let node_status_storage = rocket.state::<ValidatorApiStorage>().unwrap().clone();
         let validator_cache = rocket.state::<ValidatorCache>().unwrap().clone();
+        let monitor_consensus = rocket.state::<MonitorConsensus>().unwrap();
         let rewarding_interval_length = nymd_client.get_current_interval().await?.length();
 
         Ok(Some(Rewarder::new(
@@ -383,6 +437,8 @@ async fn setup_rewarder(
             node_status_storage,
             expected_monitor_test_runs(config, rewarding_interval_length),
             config.get_minimum_interval_monitor_threshold(),
+            config.get_maximum_rewarding_batch_gas(),
+            monitor_consensus.trusted_monitors().to_vec(),
         )))
     } else if config.get_rewarding_enabled() {
         warn!("Cannot enable rewarding with the network monitor being disabled");
@@ -397,18 +453,23 @@ async fn setup_rocket(config: &Config, liftoff_notify: Arc<Notify>) -> Result<Ro
     let rocket = rocket::build()
         .attach(setup_cors()?)
         .attach(setup_liftoff_notify(liftoff_notify))
-        .attach(ValidatorCache::stage());
+        .attach(ValidatorCache::stage(TopologySigner::new(config)));
 
     #[cfg(feature = "coconut")]
     let rocket = rocket.attach(InternalSignRequest::stage(config.keypair()));
 
     // see if we should start up network monitor and if so, attach the node status api
     if config.get_network_monitor_enabled() {
+        let monitor_consensus = MonitorConsensus::new(config);
+        let rewarding_simulator =
+            RewardingSimulator::new(config, monitor_consensus.trusted_monitors().to_vec());
         Ok(rocket
             .attach(storage::ValidatorApiStorage::stage(
                 config.get_node_status_api_database_path(),
             ))
             .attach(node_status_api::stage_full())
+            .attach(monitor_consensus::routes::stage(monitor_consensus))
+            .attach(rewarding::routes::stage(rewarding_simulator))
             .ignite()
             .await?)
     } else {
@@ -419,6 +480,72 @@ async fn setup_rocket(config: &Config, liftoff_notify: Arc<Notify>) -> Result<Ro
     }
 }
 
+async fn run_offline_rewarding_simulation(
+    config: &Config,
+    matches: &ArgMatches<'_>,
+    snapshot_path: &str,
+) -> Result<()> {
+    let snapshot: RewardingSnapshot =
+        serde_json::from_str(&std::fs::read_to_string(snapshot_path)?)?;
+    let format = match matches.value_of(SIMULATION_FORMAT_ARG) {
+        Some(format) => format
+            .parse::<SimulationFormat>()
+            .map_err(anyhow::Error::msg)?,
+        None => SimulationFormat::Json,
+    };
+
+    let storage = ValidatorApiStorage::connect(&config.get_node_status_api_database_path())
+        .await
+        .ok_or_else(|| anyhow!("could not open the node status database"))?;
+
+    let simulation = snapshot
+        .simulate(
+            &storage,
+            config.get_network_monitor_run_interval(),
+            config.get_minimum_interval_monitor_threshold(),
+            MonitorConsensus::new(config).trusted_monitors(),
+        )
+        .await?;
+
+    let output = format_simulation(&simulation, format);
+    match matches.value_of(SIMULATION_OUTPUT_ARG) {
+        Some(output_path) => {
+            std::fs::write(output_path, output)?;
+            info!(
+                "Rewarding simulation results were written to {}",
+                output_path
+            );
+        }
+        None => println!("{}", output),
+    }
+
+    Ok(())
+}
+
+async fn run_offline_monitoring_data_export(
+    config: &Config,
+    matches: &ArgMatches<'_>,
+    export_directory: &str,
+) -> Result<()> {
+    let until = match matches.value_of(EXPORT_UNTIL_ARG) {
+        Some(until) => until.parse::<i64>()?,
+        None => OffsetDateTime::now_utc().unix_timestamp(),
+    };
+
+    let storage = ValidatorApiStorage::connect(&config.get_node_status_api_database_path())
+        .await
+        .ok_or_else(|| anyhow!("could not open the node status database"))?;
+
+    let archives = export_monitoring_data(&storage, Path::new(export_directory), until).await?;
+    info!(
+        "Exported raw monitoring data of {} days to {}",
+        archives.len(),
+        export_directory
+    );
+
+    Ok(())
+}
+
 async fn run_validator_api(matches: ArgMatches<'static>) -> Result<()> {
     let system_version = env!("CARGO_PKG_VERSION");
 
@@ -444,6 +571,15 @@ async fn run_validator_api(matches: ArgMatches<'static>) -> Result<()> {
         return Ok(());
     }
 
+    // simulating rewards from a snapshot does not require any connection to the chain
+    if let Some(snapshot_path) = matches.value_of(SIMULATE_REWARDING_ARG) {
+        return run_offline_rewarding_simulation(&config, &matches, snapshot_path).await;
+    }
+
+    if let Some(export_directory) = matches.value_of(EXPORT_MONITORING_DATA_ARG) {
+        return run_offline_monitoring_data_export(&config, &matches, export_directory).await;
+    }
+
     #[cfg(feature = "coconut")]
     if matches.is_present(COCONUT_ONLY_FLAG) {
         // this simplifies everything - we just want to run coconut things
@@ -482,9 +618,25 @@ async fn run_validator_api(matches: ArgMatches<'static>) -> Result<()> {
         // setup our daily uptime updater. Note that if network monitor is disabled, then we have
         // no data for the updates and hence we don't need to start it up
         let storage = rocket.state::<ValidatorApiStorage>().unwrap().clone();
-        let uptime_updater = HistoricalUptimeUpdater::new(storage);
+        let uptime_updater = HistoricalUptimeUpdater::new(storage.clone());
         tokio::spawn(async move { uptime_updater.run().await });
 
+        // roll up (and possibly archive) the raw monitoring data once it's no longer needed
+        let data_pruner = MonitoringDataPruner::new(&config, storage.clone());
+        tokio::spawn(async move { data_pruner.run().await });
+
+        // keep pulling signed test run summaries of other network monitors so that node uptimes
+        // would not depend solely on our own measurements
+        let monitor_peers = config.get_monitor_peers();
+        if !monitor_peers.is_empty() {
+            let peers_synchroniser = MonitorPeersSynchroniser::new(
+                monitor_peers,
+                storage,
+                config.get_monitor_peer_sync_interval(),
+            );
+            tokio::spawn(async move { peers_synchroniser.run().await });
+        }
+
         if let Some(rewarder) = setup_rewarder(&config, &rocket, &nymd_client).await? {
             info!("Periodic rewarding is starting...");
 
@@ -0,0 +1,357 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+// Rather than trusting results of a single network monitor, every monitor signs summaries of its
+// test runs and periodically pulls the summaries of all of its configured peers. Node uptimes
+// used for rewarding are then the median of the uptimes reported by all monitors that have
+// produced sufficient number of test runs in given interval.
+
+use crate::config::{Config, MonitorPeer};
+use crate::network_monitor::monitor::summary_producer::NodeResult;
+use crate::node_status_api::models::Uptime;
+use crate::storage::ValidatorApiStorage;
+use crypto::asymmetric::identity;
+use log::{debug, error, warn};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+use validator_api_requests::models::{
+    MonitorRunSummary, MonitorScore, NodeReliability, SignedMonitorRunSummary,
+};
+use validator_client::validator_api;
+
+pub(crate) mod routes;
+
+#[derive(Clone)]
+pub(crate) struct MonitorConsensus {
+    identity_keypair: Arc<identity::KeyPair>,
+    run_interval: Duration,
+    minimum_monitor_threshold: u8,
+
+    // identities of the configured peers alongside our own one. Summaries of any other monitor,
+    // such as a peer that has since been removed from the config, are ignored.
+    trusted_monitors: Arc<Vec<String>>,
+}
+
+impl MonitorConsensus {
+    pub(crate) fn new(config: &Config) -> Self {
+        let identity_keypair = load_or_generate_identity(config);
+        let mut trusted_monitors = config
+            .get_monitor_peers()
+            .into_iter()
+            .map(|peer| peer.identity_key)
+            .collect::<Vec<_>>();
+        trusted_monitors.push(identity_keypair.public_key().to_base58_string());
+
+        MonitorConsensus {
+            identity_keypair: Arc::new(identity_keypair),
+            run_interval: config.get_network_monitor_run_interval(),
+            minimum_monitor_threshold: config.get_minimum_interval_monitor_threshold(),
+            trusted_monitors: Arc::new(trusted_monitors),
+        }
+    }
+
+    pub(crate) fn identity(&self) -> String {
+        self.identity_keypair.public_key().to_base58_string()
+    }
+
+    /// Identities of all network monitors whose results are taken into consideration.
+    pub(crate) fn trusted_monitors(&self) -> &[String] {
+        &self.trusted_monitors
+    }
+
+    /// Number of test runs a monitor has to have performed within the specified interval
+    /// for its results to be taken into consideration.
+    pub(crate) fn minimum_monitor_runs(&self, since: i64, until: i64) -> usize {
+        let interval_length = (until - since).max(0) as u64;
+        let expected_runs = interval_length / self.run_interval.as_secs().max(1);
+        (expected_runs * self.minimum_monitor_threshold as u64 / 100) as usize
+    }
+
+    pub(crate) fn sign_run_results(
+        &self,
+        timestamp: i64,
+        mixnode_results: &[NodeResult],
+        gateway_results: &[NodeResult],
+    ) -> SignedMonitorRunSummary {
+        let summary = MonitorRunSummary {
+            monitor_identity: self.identity(),
+            timestamp,
+            mixnode_results: to_sorted_reliabilities(mixnode_results),
+            gateway_results: to_sorted_reliabilities(gateway_results),
+        };
+
+        let signature = self
+            .identity_keypair
+            .private_key()
+            .sign(&summary_signing_payload(&summary))
+            .to_base58_string();
+
+        SignedMonitorRunSummary { summary, signature }
+    }
+}
+
+fn load_or_generate_identity(config: &Config) -> identity::KeyPair {
+    let key_paths = pemstore::KeyPairPath::new(
+        config.get_private_monitor_identity_key_file(),
+        config.get_public_monitor_identity_key_file(),
+    );
+    crate::identity::load_or_generate_identity(&key_paths, "network monitor")
+}
+
+fn to_sorted_reliabilities(results: &[NodeResult]) -> Vec<NodeReliability> {
+    let mut reliabilities = results
+        .iter()
+        .map(|result| NodeReliability {
+            identity: result.identity.clone(),
+            reliability: result.reliability,
+        })
+        .collect::<Vec<_>>();
+    reliabilities.sort_by(|a, b| a.identity.cmp(&b.identity));
+    reliabilities
+}
+
+fn summary_signing_payload(summary: &MonitorRunSummary) -> Vec<u8> {
+    // serialization of a plain struct is deterministic, so the verifier is going to reconstruct
+    // exactly the same bytes
+    serde_json::to_vec(summary).expect("failed to serialize monitor run summary")
+}
+
+/// Checks whether the summary has been produced and signed by the monitor with the specified identity.
+pub(crate) fn verify_summary(
+    signed_summary: &SignedMonitorRunSummary,
+    expected_identity: &str,
+) -> bool {
+    if signed_summary.summary.monitor_identity != expected_identity {
+        return false;
+    }
+
+    let public_key = match identity::PublicKey::from_base58_string(expected_identity) {
+        Ok(key) => key,
+        Err(_) => return false,
+    };
+    let signature = match identity::Signature::from_base58_string(&signed_summary.signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+
+    public_key
+        .verify(
+            &summary_signing_payload(&signed_summary.summary),
+            &signature,
+        )
+        .is_ok()
+}
+
+/// Determines the median uptime out of scores of all monitors that had sufficient number of
+/// test runs. Returns `None` if there were no such monitors.
+pub(crate) fn aggregate_monitor_scores(scores: &[MonitorScore]) -> Option<Uptime> {
+    let mut uptimes = scores
+        .iter()
+        .filter(|score| score.included)
+        .map(|score| score.uptime)
+        .collect::<Vec<_>>();
+
+    if uptimes.is_empty() {
+        return None;
+    }
+    uptimes.sort_unstable();
+
+    let mid = uptimes.len() / 2;
+    let median = if uptimes.len() % 2 == 0 {
+        (uptimes[mid - 1] as f32 + uptimes[mid] as f32) / 2.0
+    } else {
+        uptimes[mid] as f32
+    };
+
+    // all values are in the 0-100 range, so their median has to be as well
+    Uptime::from_uptime_sum(median, 1).ok()
+}
+
+struct Peer {
+    identity: String,
+    client: validator_api::Client,
+}
+
+pub(crate) struct MonitorPeersSynchroniser {
+    peers: Vec<Peer>,
+    storage: ValidatorApiStorage,
+    sync_interval: Duration,
+}
+
+impl MonitorPeersSynchroniser {
+    pub(crate) fn new(
+        peers: Vec<MonitorPeer>,
+        storage: ValidatorApiStorage,
+        sync_interval: Duration,
+    ) -> Self {
+        MonitorPeersSynchroniser {
+            peers: peers
+                .into_iter()
+                .map(|peer| Peer {
+                    identity: peer.identity_key,
+                    client: validator_api::Client::new(peer.api_url),
+                })
+                .collect(),
+            storage,
+            sync_interval,
+        }
+    }
+
+    async fn sync_peer(&self, peer: &Peer) {
+        let since = match self
+            .storage
+            .get_latest_monitor_run_summary_timestamp(&peer.identity)
+            .await
+        {
+            Ok(timestamp) => timestamp,
+            Err(err) => {
+                error!(
+                    "Failed to obtain the latest summary timestamp of monitor {} - {}",
+                    peer.identity, err
+                );
+                return;
+            }
+        };
+
+        let summaries = match peer.client.get_monitor_run_summaries(since).await {
+            Ok(summaries) => summaries,
+            Err(err) => {
+                warn!(
+                    "Failed to obtain test run summaries of monitor {} - {}",
+                    peer.identity, err
+                );
+                return;
+            }
+        };
+
+        let mut inserted = 0;
+        for signed_summary in summaries {
+            if !verify_summary(&signed_summary, &peer.identity) {
+                warn!(
+                    "Received test run summary with invalid signature from monitor {}",
+                    peer.identity
+                );
+                continue;
+            }
+
+            match self
+                .storage
+                .insert_signed_monitor_run_summary(&signed_summary)
+                .await
+            {
+                Ok(true) => inserted += 1,
+                Ok(false) => (),
+                Err(err) => {
+                    error!(
+                        "Failed to store test run summary of monitor {} - {}",
+                        peer.identity, err
+                    );
+                    return;
+                }
+            }
+        }
+
+        debug!(
+            "Stored {} new test run summaries of monitor {}",
+            inserted, peer.identity
+        );
+    }
+
+    pub(crate) async fn run(&self) {
+        loop {
+            for peer in &self.peers {
+                self.sync_peer(peer).await;
+            }
+            sleep(self.sync_interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(uptime: u8, included: bool) -> MonitorScore {
+        MonitorScore {
+            monitor_identity: String::new(),
+            monitor_runs: 0,
+            uptime,
+            included,
+        }
+    }
+
+    #[test]
+    fn aggregating_monitor_scores() {
+        assert!(aggregate_monitor_scores(&[]).is_none());
+        assert!(aggregate_monitor_scores(&[score(90, false)]).is_none());
+
+        let single = aggregate_monitor_scores(&[score(42, true)]).unwrap();
+        assert_eq!(42, single.u8());
+
+        // a single dishonest monitor can't move the result
+        let odd = [score(0, true), score(95, true), score(97, true)];
+        assert_eq!(95, aggregate_monitor_scores(&odd).unwrap().u8());
+
+        let even = [
+            score(100, true),
+            score(90, true),
+            score(80, true),
+            score(10, true),
+            score(0, false),
+        ];
+        assert_eq!(85, aggregate_monitor_scores(&even).unwrap().u8());
+    }
+
+    #[test]
+    fn verifying_summary_signatures() {
+        let mut rng = rand_07::rngs::OsRng;
+        let consensus = MonitorConsensus {
+            identity_keypair: Arc::new(identity::KeyPair::new(&mut rng)),
+            run_interval: Duration::from_secs(900),
+            minimum_monitor_threshold: 60,
+            trusted_monitors: Arc::new(vec![]),
+        };
+
+        let results = vec![
+            NodeResult {
+                identity: "b".to_string(),
+                owner: "owner".to_string(),
+                reliability: 100,
+            },
+            NodeResult {
+                identity: "a".to_string(),
+                owner: "owner".to_string(),
+                reliability: 50,
+            },
+        ];
+
+        let signed = consensus.sign_run_results(1234, &results, &[]);
+        assert_eq!("a", signed.summary.mixnode_results[0].identity);
+        assert!(verify_summary(&signed, &consensus.identity()));
+
+        let other_identity = identity::KeyPair::new(&mut rng)
+            .public_key()
+            .to_base58_string();
+        assert!(!verify_summary(&signed, &other_identity));
+
+        let mut tampered = signed;
+        tampered.summary.mixnode_results[0].reliability = 100;
+        assert!(!verify_summary(&tampered, &consensus.identity()));
+    }
+
+    #[test]
+    fn minimum_monitor_runs_depends_on_threshold() {
+        let mut rng = rand_07::rngs::OsRng;
+        let consensus = MonitorConsensus {
+            identity_keypair: Arc::new(identity::KeyPair::new(&mut rng)),
+            run_interval: Duration::from_secs(900),
+            minimum_monitor_threshold: 50,
+            trusted_monitors: Arc::new(vec![]),
+        };
+
+        // 96 runs a day
+        assert_eq!(48, consensus.minimum_monitor_runs(0, 86400));
+        assert_eq!(0, consensus.minimum_monitor_runs(86400, 0));
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::monitor_consensus::MonitorConsensus;
+use crate::node_status_api::models::ErrorResponse;
+use crate::node_status_api::ONE_DAY;
+use crate::storage::ValidatorApiStorage;
+use rocket::fairing::AdHoc;
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::State;
+use time::OffsetDateTime;
+use validator_api_requests::models::SignedMonitorRunSummary;
+
+pub(crate) fn stage(monitor_consensus: MonitorConsensus) -> AdHoc {
+    AdHoc::on_ignite("Monitor Consensus Stage", |rocket| async {
+        rocket
+            .manage(monitor_consensus)
+            .mount("/v1/monitor", routes![get_monitor_run_summaries])
+    })
+}
+
+// only ever returns summaries produced by our own monitor. Peers are expected to get summaries
+// of other monitors directly from them
+#[get("/summaries?<since>")]
+pub(crate) async fn get_monitor_run_summaries(
+    monitor_consensus: &State<MonitorConsensus>,
+    storage: &State<ValidatorApiStorage>,
+    since: Option<i64>,
+) -> Result<Json<Vec<SignedMonitorRunSummary>>, ErrorResponse> {
+    let since = since.unwrap_or_else(|| (OffsetDateTime::now_utc() - ONE_DAY).unix_timestamp());
+
+    storage
+        .get_signed_monitor_run_summaries(&monitor_consensus.identity(), since)
+        .await
+        .map(Json)
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))
+}
@@ -8,6 +8,7 @@ use std::sync::Arc;
 
 use crate::config::Config;
 use crate::contract_cache::ValidatorCache;
+use crate::monitor_consensus::MonitorConsensus;
 use crate::network_monitor::monitor::preparer::PacketPreparer;
 use crate::network_monitor::monitor::processor::{
     ReceivedProcessor, ReceivedProcessorReceiver, ReceivedProcessorSender,
@@ -33,6 +34,7 @@ pub(crate) struct NetworkMonitorBuilder<'a> {
     system_version: String,
     node_status_storage: ValidatorApiStorage,
     validator_cache: ValidatorCache,
+    monitor_consensus: MonitorConsensus,
 }
 
 impl<'a> NetworkMonitorBuilder<'a> {
@@ -41,12 +43,14 @@ impl<'a> NetworkMonitorBuilder<'a> {
         system_version: &str,
         node_status_storage: ValidatorApiStorage,
         validator_cache: ValidatorCache,
+        monitor_consensus: MonitorConsensus,
     ) -> Self {
         NetworkMonitorBuilder {
             config,
             system_version: system_version.to_string(),
             node_status_storage,
             validator_cache,
+            monitor_consensus,
         }
     }
 
@@ -110,6 +114,7 @@ impl<'a> NetworkMonitorBuilder<'a> {
             received_processor,
             summary_producer,
             self.node_status_storage,
+            self.monitor_consensus,
         );
 
         NetworkMonitorRunnables {
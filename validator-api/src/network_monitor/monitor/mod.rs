@@ -2,16 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::config::Config;
+use crate::monitor_consensus::MonitorConsensus;
 use crate::network_monitor::monitor::preparer::PacketPreparer;
-use crate::network_monitor::monitor::processor::ReceivedProcessor;
+use crate::network_monitor::monitor::processor::{ReceivedProcessor, ReceivedTestPacket};
 use crate::network_monitor::monitor::sender::PacketSender;
 use crate::network_monitor::monitor::summary_producer::{SummaryProducer, TestSummary};
-use crate::network_monitor::test_packet::TestPacket;
 use crate::network_monitor::test_route::TestRoute;
 use crate::storage::ValidatorApiStorage;
 use log::{debug, error, info};
 use std::collections::{HashMap, HashSet};
 use std::process;
+use time::OffsetDateTime;
 use tokio::time::{sleep, Duration, Instant};
 
 pub(crate) mod gateway_clients_cache;
@@ -29,6 +30,7 @@ pub(super) struct Monitor {
     received_processor: ReceivedProcessor,
     summary_producer: SummaryProducer,
     node_status_storage: ValidatorApiStorage,
+    monitor_consensus: MonitorConsensus,
     run_interval: Duration,
     gateway_ping_interval: Duration,
     packet_delivery_timeout: Duration,
@@ -52,6 +54,7 @@ impl Monitor {
         received_processor: ReceivedProcessor,
         summary_producer: SummaryProducer,
         node_status_storage: ValidatorApiStorage,
+        monitor_consensus: MonitorConsensus,
     ) -> Self {
         Monitor {
             test_nonce: 1,
@@ -60,6 +63,7 @@ impl Monitor {
             received_processor,
             summary_producer,
             node_status_storage,
+            monitor_consensus,
             run_interval: config.get_network_monitor_run_interval(),
             gateway_ping_interval: config.get_gateway_ping_interval(),
             packet_delivery_timeout: config.get_packet_delivery_timeout(),
@@ -72,6 +76,13 @@ impl Monitor {
     // while it might have been cleaner to put this into a separate `Notifier` structure,
     // I don't see much point considering it's only a single, small, method
     async fn submit_new_node_statuses(&self, test_summary: TestSummary) {
+        // sign the results so that they could be shared with (and verified by) other monitors
+        let signed_summary = self.monitor_consensus.sign_run_results(
+            OffsetDateTime::now_utc().unix_timestamp(),
+            &test_summary.mixnode_results,
+            &test_summary.gateway_results,
+        );
+
         // indicate our run has completed successfully and should be used in any future
         // uptime calculations
         if let Err(err) = self
@@ -79,11 +90,8 @@ impl Monitor {
             .insert_monitor_run_results(
                 test_summary.mixnode_results,
                 test_summary.gateway_results,
-                test_summary
-                    .route_results
-                    .into_iter()
-                    .map(|result| result.route)
-                    .collect(),
+                test_summary.route_results,
+                test_summary.node_diagnostics,
             )
             .await
         {
@@ -95,12 +103,28 @@ impl Monitor {
             // TODO: slightly more graceful shutdown here
             process::exit(1);
         }
+
+        if let Err(err) = self
+            .node_status_storage
+            .insert_signed_monitor_run_summary(&signed_summary)
+            .await
+        {
+            error!(
+                "Failed to submit signed monitor run summary to the database - {}",
+                err
+            );
+        }
     }
 
-    fn analyse_received_test_route_packets(&self, packets: &[TestPacket]) -> HashMap<u64, usize> {
+    fn analyse_received_test_route_packets(
+        &self,
+        packets: &[ReceivedTestPacket],
+    ) -> HashMap<u64, usize> {
         let mut received = HashMap::new();
-        for packet in packets {
-            *received.entry(packet.route_id).or_insert(0usize) += 1usize
+        for received_packet in packets {
+            *received
+                .entry(received_packet.packet.route_id)
+                .or_insert(0usize) += 1usize
         }
 
         received
@@ -129,12 +153,12 @@ impl Monitor {
         }
 
         self.received_processor.set_route_test_nonce().await;
-        self.packet_sender.send_packets(packets).await;
+        let send_times = self.packet_sender.send_packets(packets).await;
 
         // give the packets some time to traverse the network
         sleep(self.packet_delivery_timeout).await;
 
-        let received = self.received_processor.return_received().await;
+        let received = self.received_processor.return_received(&send_times).await;
         let mut results = self.analyse_received_test_route_packets(&received);
 
         // create entry for routes that might have not forwarded a single packet
@@ -245,7 +269,8 @@ impl Monitor {
             .await;
 
         debug!("Sending packets to all gateways...");
-        self.packet_sender
+        let send_times = self
+            .packet_sender
             .send_packets(prepared_packets.packets)
             .await;
 
@@ -258,7 +283,7 @@ impl Monitor {
         // give the packets some time to traverse the network
         sleep(self.packet_delivery_timeout).await;
 
-        let received = self.received_processor.return_received().await;
+        let received = self.received_processor.return_received(&send_times).await;
         let total_received = received.len();
 
         let summary = self.summary_producer.produce_summary(
@@ -68,6 +68,8 @@ pub(crate) struct TestedNode {
     pub(crate) identity: String,
     pub(crate) owner: String,
     pub(crate) node_type: NodeType,
+    /// Layer of the node within the mix network. Only applicable to mixnodes.
+    pub(crate) layer: Option<u8>,
 }
 
 impl<'a> From<&'a mix::Node> for TestedNode {
@@ -76,6 +78,7 @@ impl<'a> From<&'a mix::Node> for TestedNode {
             identity: node.identity_key.to_base58_string(),
             owner: node.owner.clone(),
             node_type: NodeType::Mixnode,
+            layer: Some(node.layer as u8),
         }
     }
 }
@@ -86,6 +89,7 @@ impl<'a> From<&'a gateway::Node> for TestedNode {
             identity: node.identity_key.to_base58_string(),
             owner: node.owner.clone(),
             node_type: NodeType::Gateway,
+            layer: None,
         }
     }
 }
@@ -161,11 +165,13 @@ impl PacketPreparer {
         if self.chunker.is_none() {
             self.chunker = Some(Chunker::new(packet_recipient));
         }
+        // note: the packets are sent only after all of them got prepared, so the latency is measured
+        // from the moment the sender has actually sent them rather than from this timestamp
         let mut mix_packets = self
             .chunker
             .as_mut()
             .unwrap()
-            .prepare_packets_from(packet.to_bytes(), topology, packet_recipient)
+            .prepare_packets_from(packet.stamped_now().to_bytes(), topology, packet_recipient)
             .await;
         assert_eq!(
             mix_packets.len(),
@@ -381,7 +387,7 @@ impl PacketPreparer {
             let mix_packet = self
                 .wrap_test_packet(&test_packet, route.topology(), recipient)
                 .await;
-            mix_packets.push(mix_packet)
+            mix_packets.push((test_packet.id(), mix_packet))
         }
 
         GatewayPackets::new(
@@ -487,7 +493,7 @@ impl PacketPreparer {
                     let mix_packet = self
                         .wrap_test_packet(&test_packet, &topology, recipient)
                         .await;
-                    mix_packets.push(mix_packet);
+                    mix_packets.push((test_packet.id(), mix_packet));
                 }
             }
 
@@ -509,7 +515,7 @@ impl PacketPreparer {
                     let mix_packet = self
                         .wrap_test_packet(&test_packet, &topology, recipient)
                         .await;
-                    gateway_mix_packets.push(mix_packet);
+                    gateway_mix_packets.push((test_packet.id(), mix_packet));
                 }
 
                 // and push it into existing struct (if it's a "core" gateway being tested against another route)
@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::network_monitor::gateways_reader::GatewayMessages;
-use crate::network_monitor::test_packet::TestPacket;
+use crate::network_monitor::monitor::sender::PacketSendTimes;
+use crate::network_monitor::test_packet::{current_unix_timestamp_millis, TestPacket};
 use crate::network_monitor::ROUTE_TESTING_TEST_NONCE;
 use crypto::asymmetric::encryption;
 use futures::channel::mpsc;
@@ -13,6 +14,7 @@ use nymsphinx::receiver::MessageReceiver;
 use std::fmt::{self, Display, Formatter};
 use std::mem;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub(crate) type ReceivedProcessorSender = mpsc::UnboundedSender<GatewayMessages>;
 pub(crate) type ReceivedProcessorReceiver = mpsc::UnboundedReceiver<GatewayMessages>;
@@ -43,6 +45,28 @@ impl Display for ProcessingError {
     }
 }
 
+/// Test packet alongside the time it took it to traverse the network.
+#[derive(Debug, Clone)]
+pub(crate) struct ReceivedTestPacket {
+    pub(crate) packet: TestPacket,
+    pub(crate) latency: Duration,
+}
+
+impl ReceivedTestPacket {
+    fn new(packet: TestPacket, send_times: &PacketSendTimes, received_timestamp: u64) -> Self {
+        // the timestamp embedded in the packet is taken when it's being prepared, which might
+        // be a while before the packet actually leaves, so prefer the time recorded by the sender
+        let sent_timestamp = send_times
+            .get(&packet.id())
+            .copied()
+            .unwrap_or(packet.sent_timestamp);
+
+        // if our clock went backwards, the best we can do is to treat it as instant delivery
+        let latency = Duration::from_millis(received_timestamp.saturating_sub(sent_timestamp));
+        ReceivedTestPacket { packet, latency }
+    }
+}
+
 // we can't use Notify due to possible edge case where both notification are consumed at once
 enum LockPermit {
     Release,
@@ -63,12 +87,15 @@ struct ReceivedProcessorInner {
     /// Structure responsible for decrypting and recovering plaintext message from received ciphertexts.
     message_receiver: MessageReceiver,
 
-    /// Vector containing all received (and decrypted) packets in the current test run.
-    received_packets: Vec<TestPacket>,
+    /// Vector containing all received (and decrypted) packets in the current test run
+    /// alongside the unix timestamps (in milliseconds) at which they were received.
+    received_packets: Vec<(TestPacket, u64)>,
 }
 
 impl ReceivedProcessorInner {
     fn on_message(&mut self, message: Vec<u8>) -> Result<(), ProcessingError> {
+        let received_timestamp = current_unix_timestamp_millis();
+
         // if the nonce is none it means the packet was received during the 'waiting' for the
         // next test run
         if self.test_nonce.is_none() {
@@ -96,12 +123,13 @@ impl ReceivedProcessorInner {
             return Err(ProcessingError::NonMatchingNonce(test_packet.test_nonce()));
         }
 
-        self.received_packets.push(test_packet);
+        self.received_packets
+            .push((test_packet, received_timestamp));
 
         Ok(())
     }
 
-    fn finish_run(&mut self) -> Vec<TestPacket> {
+    fn finish_run(&mut self) -> Vec<(TestPacket, u64)> {
         self.test_nonce = None;
         mem::take(&mut self.received_packets)
     }
@@ -212,7 +240,15 @@ impl ReceivedProcessor {
             .expect("processing task has died!");
     }
 
-    pub(super) async fn return_received(&mut self) -> Vec<TestPacket> {
+    /// Returns all packets received in the current test run.
+    ///
+    /// # Arguments
+    ///
+    /// * `send_times`: times at which the tests of this run were sent, as recorded by the sender.
+    pub(super) async fn return_received(
+        &mut self,
+        send_times: &PacketSendTimes,
+    ) -> Vec<ReceivedTestPacket> {
         // ask for the lock back
         self.permit_changer
             .as_mut()
@@ -234,5 +270,10 @@ impl ReceivedProcessor {
             .expect("processing task has died!");
 
         received
+            .into_iter()
+            .map(|(packet, received_timestamp)| {
+                ReceivedTestPacket::new(packet, send_times, received_timestamp)
+            })
+            .collect()
     }
 }
@@ -6,6 +6,7 @@ use crate::network_monitor::monitor::gateway_clients_cache::{
 };
 use crate::network_monitor::monitor::gateways_pinger::GatewayPinger;
 use crate::network_monitor::monitor::receiver::{GatewayClientUpdate, GatewayClientUpdateSender};
+use crate::network_monitor::test_packet::{current_unix_timestamp_millis, TestPacketId};
 use crypto::asymmetric::identity::{self, PUBLIC_KEY_LENGTH};
 use futures::channel::mpsc;
 use futures::stream::{self, FuturesUnordered, StreamExt};
@@ -16,10 +17,11 @@ use gateway_client::{AcknowledgementReceiver, GatewayClient, MixnetMessageReceiv
 use log::{debug, info, trace, warn};
 use nymsphinx::forwarding::packet::MixPacket;
 use pin_project::pin_project;
+use std::collections::HashMap;
 use std::mem;
 use std::num::NonZeroUsize;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::Poll;
 use std::time::Duration;
 
@@ -27,6 +29,9 @@ use gateway_client::bandwidth::BandwidthController;
 
 const TIME_CHUNK_SIZE: Duration = Duration::from_millis(50);
 
+/// Unix timestamps (in milliseconds) at which the first packet of each test got actually sent.
+pub(crate) type PacketSendTimes = HashMap<TestPacketId, u64>;
+
 // If we're below 10MB of bandwidth, claim some more
 const REMAINING_BANDWIDTH_THRESHOLD: i64 = 10 * 1000 * 1000;
 
@@ -38,15 +43,15 @@ pub(crate) struct GatewayPackets {
     /// Public key of the target gateway.
     pub(crate) pub_key: identity::PublicKey,
 
-    /// All the packets that are going to get sent to the gateway.
-    pub(crate) packets: Vec<MixPacket>,
+    /// All the packets that are going to get sent to the gateway alongside ids of the tests they belong to.
+    pub(crate) packets: Vec<(TestPacketId, MixPacket)>,
 }
 
 impl GatewayPackets {
     pub(crate) fn new(
         clients_address: String,
         pub_key: identity::PublicKey,
-        packets: Vec<MixPacket>,
+        packets: Vec<(TestPacketId, MixPacket)>,
     ) -> Self {
         GatewayPackets {
             clients_address,
@@ -63,7 +68,7 @@ impl GatewayPackets {
         }
     }
 
-    pub(super) fn push_packets(&mut self, mut packets: Vec<MixPacket>) {
+    pub(super) fn push_packets(&mut self, mut packets: Vec<(TestPacketId, MixPacket)>) {
         if self.packets.is_empty() {
             self.packets = packets
         } else if self.packets.len() > packets.len() {
@@ -215,10 +220,28 @@ impl PacketSender {
         )
     }
 
+    // the sending is deliberately spread in time, so remember when each test has actually been
+    // sent in order for its latency not to include the time it has spent waiting in our queue
+    fn record_send_time(
+        send_times: &Mutex<PacketSendTimes>,
+        packets: &[(TestPacketId, MixPacket)],
+    ) {
+        let now = current_unix_timestamp_millis();
+        let mut send_times = send_times.lock().expect("send times lock got poisoned");
+        for (id, _) in packets {
+            send_times.entry(*id).or_insert(now);
+        }
+    }
+
+    fn without_ids(packets: Vec<(TestPacketId, MixPacket)>) -> Vec<MixPacket> {
+        packets.into_iter().map(|(_, packet)| packet).collect()
+    }
+
     async fn attempt_to_send_packets(
         client: &mut GatewayClient,
-        mut mix_packets: Vec<MixPacket>,
+        mut mix_packets: Vec<(TestPacketId, MixPacket)>,
         max_sending_rate: usize,
+        send_times: &Mutex<PacketSendTimes>,
     ) -> Result<(), GatewayClientError> {
         let gateway_id = client.gateway_identity().to_base58_string();
         info!(
@@ -230,7 +253,10 @@ impl PacketSender {
 
         if mix_packets.len() <= max_sending_rate {
             debug!(target: "MessageSender","Everything is going to get sent as one.");
-            client.batch_send_mix_packets(mix_packets).await?;
+            Self::record_send_time(send_times, &mix_packets);
+            client
+                .batch_send_mix_packets(Self::without_ids(mix_packets))
+                .await?;
         } else {
             let packets_per_time_chunk =
                 (max_sending_rate as f64 * TIME_CHUNK_SIZE.as_secs_f64()) as usize;
@@ -243,7 +269,7 @@ impl PacketSender {
                 max_sending_rate, total_expected_time, gateway_id
             );
 
-            fn split_off_vec(vec: &mut Vec<MixPacket>, at: usize) -> Option<Vec<MixPacket>> {
+            fn split_off_vec<T>(vec: &mut Vec<T>, at: usize) -> Option<Vec<T>> {
                 if vec.is_empty() {
                     None
                 } else {
@@ -259,11 +285,14 @@ impl PacketSender {
             // splitting a vector into multiple vectors
             while let Some(retained) = split_off_vec(&mut mix_packets, packets_per_time_chunk) {
                 trace!(target: "MessageSender","Sending {} packets...", mix_packets.len());
+                Self::record_send_time(send_times, &mix_packets);
 
                 if mix_packets.len() == 1 {
-                    client.send_mix_packet(mix_packets.pop().unwrap()).await?;
+                    client.send_mix_packet(mix_packets.pop().unwrap().1).await?;
                 } else {
-                    client.batch_send_mix_packets(mix_packets).await?;
+                    client
+                        .batch_send_mix_packets(Self::without_ids(mix_packets))
+                        .await?;
                 }
 
                 tokio::time::sleep(TIME_CHUNK_SIZE).await;
@@ -347,6 +376,7 @@ impl PacketSender {
         fresh_gateway_client_data: Arc<FreshGatewayClientData>,
         client: Option<GatewayClientHandle>,
         max_sending_rate: usize,
+        send_times: Arc<Mutex<PacketSendTimes>>,
     ) -> Option<GatewayClientHandle> {
         let existing_client = client.is_some();
 
@@ -394,7 +424,12 @@ impl PacketSender {
 
         match tokio::time::timeout(
             timeout,
-            Self::attempt_to_send_packets(unwrapped_client, packets.packets, max_sending_rate),
+            Self::attempt_to_send_packets(
+                unwrapped_client,
+                packets.packets,
+                max_sending_rate,
+                &send_times,
+            ),
         )
         .await
         {
@@ -457,7 +492,8 @@ impl PacketSender {
         }
     }
 
-    pub(super) async fn send_packets(&mut self, packets: Vec<GatewayPackets>) {
+    /// Sends all the packets to their gateways and returns the times at which the tests were sent.
+    pub(super) async fn send_packets(&mut self, packets: Vec<GatewayPackets>) -> PacketSendTimes {
         // we know that each of the elements in the packets array will only ever access a single,
         // unique element from the existing clients
 
@@ -468,6 +504,7 @@ impl PacketSender {
             None
         };
         let max_sending_rate = self.max_sending_rate;
+        let send_times = Arc::new(Mutex::new(HashMap::new()));
 
         let guard = self.active_gateway_clients.lock().await;
         // this clippy warning is a false positive as we cannot get rid of the collect by moving
@@ -498,15 +535,19 @@ impl PacketSender {
         let used_clients = ForEachConcurrentClientUse::new(
             stream::iter(stream_data.into_iter()),
             max_concurrent_clients,
-            |(packets, fresh_data, client)| async move {
-                Self::send_gateway_packets(
-                    gateway_connection_timeout,
-                    packets,
-                    fresh_data,
-                    client,
-                    max_sending_rate,
-                )
-                .await
+            |(packets, fresh_data, client)| {
+                let send_times = Arc::clone(&send_times);
+                async move {
+                    Self::send_gateway_packets(
+                        gateway_connection_timeout,
+                        packets,
+                        fresh_data,
+                        client,
+                        max_sending_rate,
+                        send_times,
+                    )
+                    .await
+                }
             },
         )
         .await
@@ -515,6 +556,9 @@ impl PacketSender {
         .collect();
 
         self.merge_client_handles(used_clients).await;
+
+        let mut send_times = send_times.lock().expect("send times lock got poisoned");
+        mem::take(&mut *send_times)
     }
 }
 
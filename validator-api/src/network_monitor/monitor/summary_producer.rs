@@ -2,10 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::network_monitor::monitor::preparer::{InvalidNode, TestedNode};
-use crate::network_monitor::test_packet::TestPacket;
+use crate::network_monitor::monitor::processor::ReceivedTestPacket;
 use crate::network_monitor::test_route::TestRoute;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
 // just some approximate measures to print to stdout (well, technically stderr since it's being printed via log)
 const EXCEPTIONAL_THRESHOLD: u8 = 95; // 95 - 100
@@ -13,6 +14,10 @@ const FINE_THRESHOLD: u8 = 80; // 80 - 95
 const POOR_THRESHOLD: u8 = 60; // 60 - 80
 const UNRELIABLE_THRESHOLD: u8 = 1; // 1 - 60
 
+// if route reliability is below this value, any packets lost on it are attributed to the route
+// itself rather than to the individual nodes being tested through it
+const HEALTHY_ROUTE_THRESHOLD: u8 = FINE_THRESHOLD;
+
 // I didn't have time to implement it for this PR, however, an idea for the future is as follows:
 // After testing network against N routes, if any one of them is worse than ALLOWED_RELIABILITY_DEVIATION
 // from the average result, remove this data and recalculate scores.
@@ -38,13 +43,46 @@ impl NodeResult {
 #[derive(Debug, Clone)]
 pub(crate) struct RouteResult {
     pub(crate) route: TestRoute,
-    reliability: u8,
+    pub(crate) reliability: u8,
+
+    /// Median end-to-end latency of all packets received through this route.
+    pub(crate) latency: Option<Duration>,
 }
 
 impl RouteResult {
-    pub(crate) fn new(route: TestRoute, reliability: u8) -> Self {
-        RouteResult { route, reliability }
+    pub(crate) fn new(route: TestRoute, reliability: u8, latency: Option<Duration>) -> Self {
+        RouteResult {
+            route,
+            reliability,
+            latency,
+        }
     }
+
+    fn is_healthy(&self) -> bool {
+        self.reliability >= HEALTHY_ROUTE_THRESHOLD
+    }
+}
+
+/// Latency and failure information of particular node, attributed based on its results
+/// across all test routes.
+#[derive(Debug)]
+pub(crate) struct NodeDiagnostics {
+    pub(crate) identity: String,
+    pub(crate) is_mixnode: bool,
+    pub(crate) layer: Option<u8>,
+
+    /// Mean end-to-end latency of all packets sent to this node.
+    pub(crate) mean_latency: Option<Duration>,
+
+    /// Mean difference between latency of packets sent to this node and the median latency
+    /// of the route they were sent through, i.e. the delay introduced by the node itself.
+    pub(crate) excess_latency_ms: Option<i64>,
+
+    /// Number of healthy routes the node got tested against.
+    pub(crate) tested_routes: usize,
+
+    /// Number of healthy routes on which not all packets sent to this node got delivered.
+    pub(crate) failed_routes: usize,
 }
 
 #[derive(Default, Debug)]
@@ -156,8 +194,8 @@ impl Display for TestReport {
         for route_result in &self.route_results {
             writeln!(
                 f,
-                "{:?}, reliability: {:.2}",
-                route_result.route, route_result.reliability
+                "{:?}, reliability: {:.2}, latency: {:?}",
+                route_result.route, route_result.reliability, route_result.latency
             )?;
         }
 
@@ -224,6 +262,7 @@ pub(crate) struct TestSummary {
     pub(crate) mixnode_results: Vec<NodeResult>,
     pub(crate) gateway_results: Vec<NodeResult>,
     pub(crate) route_results: Vec<RouteResult>,
+    pub(crate) node_diagnostics: Vec<NodeDiagnostics>,
 }
 
 impl TestSummary {
@@ -261,7 +300,7 @@ impl SummaryProducer {
         &self,
         tested_mixnodes: Vec<TestedNode>,
         tested_gateways: Vec<TestedNode>,
-        received_packets: Vec<TestPacket>,
+        received_packets: Vec<ReceivedTestPacket>,
         invalid_mixnodes: Vec<InvalidNode>,
         invalid_gateways: Vec<InvalidNode>,
         test_routes: &[TestRoute],
@@ -270,18 +309,34 @@ impl SummaryProducer {
         let mut raw_gateway_results = HashMap::new();
 
         let mut raw_route_results = HashMap::new();
+        let mut route_latencies: HashMap<_, Vec<Duration>> = HashMap::new();
+
+        // per node, per route latencies of all received packets
+        let mut node_route_latencies: HashMap<_, HashMap<u64, Vec<Duration>>> = HashMap::new();
 
         // we expect each route to receive this many packets in the ideal world
         let per_route_expected =
             (tested_mixnodes.len() + tested_gateways.len()) * self.per_node_test_packets;
         let per_node_expected = test_routes.len() * self.per_node_test_packets;
 
-        for tested_mixnode in tested_mixnodes {
-            raw_mixnode_results.insert((tested_mixnode.identity, tested_mixnode.owner), 0);
+        for tested_mixnode in &tested_mixnodes {
+            raw_mixnode_results.insert(
+                (
+                    tested_mixnode.identity.clone(),
+                    tested_mixnode.owner.clone(),
+                ),
+                0,
+            );
         }
 
-        for tested_gateway in tested_gateways {
-            raw_gateway_results.insert((tested_gateway.identity, tested_gateway.owner), 0);
+        for tested_gateway in &tested_gateways {
+            raw_gateway_results.insert(
+                (
+                    tested_gateway.identity.clone(),
+                    tested_gateway.owner.clone(),
+                ),
+                0,
+            );
         }
 
         for invalid_mixnode in invalid_mixnodes {
@@ -297,15 +352,28 @@ impl SummaryProducer {
         }
 
         for received in received_packets {
-            let id_owner = (received.pub_key.to_base58_string(), received.owner);
-
-            if received.node_type.is_mixnode() {
+            let identity = received.packet.pub_key.to_base58_string();
+            let route_id = received.packet.route_id;
+
+            node_route_latencies
+                .entry((identity.clone(), received.packet.node_type))
+                .or_default()
+                .entry(route_id)
+                .or_default()
+                .push(received.latency);
+            route_latencies
+                .entry(route_id)
+                .or_default()
+                .push(received.latency);
+
+            let id_owner = (identity, received.packet.owner);
+            if received.packet.node_type.is_mixnode() {
                 *raw_mixnode_results.entry(id_owner).or_default() += 1usize;
             } else {
                 *raw_gateway_results.entry(id_owner).or_default() += 1usize;
             }
 
-            *raw_route_results.entry(received.route_id).or_default() += 1usize;
+            *raw_route_results.entry(route_id).or_default() += 1usize;
         }
 
         let mixnode_results = raw_mixnode_results
@@ -326,18 +394,33 @@ impl SummaryProducer {
             })
             .collect();
 
-        let route_results = raw_route_results
+        let route_results: Vec<_> = raw_route_results
             .into_iter()
             .filter_map(|(id, received)| {
                 let reliability =
                     (received as f32 / per_route_expected as f32 * 100.0).round() as u8;
+                let latency = route_latencies
+                    .get_mut(&id)
+                    .and_then(|latencies| median_latency(latencies));
 
                 // this might be suboptimal as we're going through the entire slice every time
                 // but realistically this slice will never have more than ~ 10 elements AT MOST
                 test_routes
                     .iter()
                     .find(|route| route.id() == id)
-                    .map(|route| RouteResult::new(route.clone(), reliability))
+                    .map(|route| RouteResult::new(route.clone(), reliability, latency))
+            })
+            .collect();
+
+        let node_diagnostics = tested_mixnodes
+            .iter()
+            .chain(tested_gateways.iter())
+            .map(|node| {
+                self.node_diagnostics(
+                    node,
+                    node_route_latencies.get(&(node.identity.clone(), node.node_type)),
+                    &route_results,
+                )
             })
             .collect();
 
@@ -345,6 +428,106 @@ impl SummaryProducer {
             mixnode_results,
             gateway_results,
             route_results,
+            node_diagnostics,
         }
     }
+
+    fn node_diagnostics(
+        &self,
+        node: &TestedNode,
+        route_latencies: Option<&HashMap<u64, Vec<Duration>>>,
+        route_results: &[RouteResult],
+    ) -> NodeDiagnostics {
+        let mut tested_routes = 0;
+        let mut failed_routes = 0;
+        let mut all_latencies = Vec::new();
+        let mut excess_latencies = Vec::new();
+
+        for route_result in route_results {
+            let latencies = route_latencies
+                .and_then(|routes| routes.get(&route_result.route.id()))
+                .map(|latencies| latencies.as_slice())
+                .unwrap_or_default();
+            all_latencies.extend_from_slice(latencies);
+
+            // if the route itself was faulty, we can't really tell whether the node was at fault
+            if !route_result.is_healthy() {
+                continue;
+            }
+
+            tested_routes += 1;
+            if latencies.len() < self.per_node_test_packets {
+                failed_routes += 1;
+            }
+
+            if let Some(route_latency) = route_result.latency {
+                excess_latencies.extend(
+                    latencies.iter().map(|latency| {
+                        latency.as_millis() as i64 - route_latency.as_millis() as i64
+                    }),
+                );
+            }
+        }
+
+        let mean_latency = if all_latencies.is_empty() {
+            None
+        } else {
+            Some(all_latencies.iter().sum::<Duration>() / all_latencies.len() as u32)
+        };
+
+        let excess_latency_ms = if excess_latencies.is_empty() {
+            None
+        } else {
+            Some(excess_latencies.iter().sum::<i64>() / excess_latencies.len() as i64)
+        };
+
+        NodeDiagnostics {
+            identity: node.identity.clone(),
+            is_mixnode: node.node_type.is_mixnode(),
+            layer: node.layer,
+            mean_latency,
+            excess_latency_ms,
+            tested_routes,
+            failed_routes,
+        }
+    }
+}
+
+fn median_latency(latencies: &mut [Duration]) -> Option<Duration> {
+    if latencies.is_empty() {
+        return None;
+    }
+    latencies.sort_unstable();
+
+    let mid = latencies.len() / 2;
+    if latencies.len() % 2 == 0 {
+        Some((latencies[mid - 1] + latencies[mid]) / 2)
+    } else {
+        Some(latencies[mid])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn median_latency_of_received_packets() {
+        assert!(median_latency(&mut []).is_none());
+
+        let mut odd = [
+            Duration::from_millis(300),
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+        ];
+        assert_eq!(Some(Duration::from_millis(200)), median_latency(&mut odd));
+
+        let mut even = [
+            Duration::from_millis(400),
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+            Duration::from_millis(300),
+        ];
+        assert_eq!(Some(Duration::from_millis(250)), median_latency(&mut even));
+    }
 }
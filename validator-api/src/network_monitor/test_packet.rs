@@ -8,6 +8,7 @@ use std::fmt::{self, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::mem;
 use std::str::Utf8Error;
+use time::OffsetDateTime;
 use topology::{gateway, mix};
 
 #[repr(u8)]
@@ -55,10 +56,20 @@ impl From<Utf8Error> for TestPacketError {
     }
 }
 
+/// Identifies all test packets of a test run that target particular node through particular route.
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub(crate) struct TestPacketId {
+    route_id: u64,
+    pub_key: [u8; identity::PUBLIC_KEY_LENGTH],
+    node_type: NodeType,
+}
+
 #[derive(Eq, Clone, Debug)]
 pub(crate) struct TestPacket {
     pub(crate) route_id: u64,
     pub(crate) test_nonce: u64,
+    /// Unix timestamp (in milliseconds) at which the packet got prepared for sending.
+    pub(crate) sent_timestamp: u64,
     pub(crate) pub_key: identity::PublicKey,
     pub(crate) owner: String,
     pub(crate) node_type: NodeType,
@@ -68,11 +79,12 @@ impl Display for TestPacket {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "TestPacket {{ pub_key: {}, owner: {}, route: {} test nonce: {} }}",
+            "TestPacket {{ pub_key: {}, owner: {}, route: {} test nonce: {} sent at: {} }}",
             self.pub_key.to_base58_string(),
             self.owner,
             self.route_id,
-            self.test_nonce
+            self.test_nonce,
+            self.sent_timestamp
         )
     }
 }
@@ -81,6 +93,7 @@ impl Hash for TestPacket {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.route_id.hash(state);
         self.test_nonce.hash(state);
+        self.sent_timestamp.hash(state);
         self.pub_key.to_bytes().hash(state);
         self.owner.hash(state);
         self.node_type.hash(state);
@@ -91,6 +104,7 @@ impl PartialEq for TestPacket {
     fn eq(&self, other: &Self) -> bool {
         self.route_id == other.route_id
             && self.test_nonce == other.test_nonce
+            && self.sent_timestamp == other.sent_timestamp
             && self.pub_key.to_bytes() == other.pub_key.to_bytes()
             && self.owner == other.owner
             && self.node_type == other.node_type
@@ -104,6 +118,7 @@ impl TestPacket {
             owner: mix.owner.clone(),
             route_id,
             test_nonce,
+            sent_timestamp: 0,
             node_type: NodeType::Mixnode,
         }
     }
@@ -114,6 +129,7 @@ impl TestPacket {
             owner: gateway.owner.clone(),
             route_id,
             test_nonce,
+            sent_timestamp: 0,
             node_type: NodeType::Gateway,
         }
     }
@@ -128,6 +144,7 @@ impl TestPacket {
         TestPacket {
             route_id,
             test_nonce,
+            sent_timestamp: 0,
             pub_key,
             owner,
             node_type,
@@ -138,9 +155,26 @@ impl TestPacket {
         self.test_nonce
     }
 
+    pub(crate) fn id(&self) -> TestPacketId {
+        TestPacketId {
+            route_id: self.route_id,
+            pub_key: self.pub_key.to_bytes(),
+            node_type: self.node_type,
+        }
+    }
+
+    /// Returns copy of this packet marked as being sent at this very moment.
+    pub(crate) fn stamped_now(&self) -> Self {
+        TestPacket {
+            sent_timestamp: current_unix_timestamp_millis(),
+            ..self.clone()
+        }
+    }
+
     pub(crate) fn to_bytes(&self) -> Vec<u8> {
         IntoIterator::into_iter(self.route_id.to_be_bytes())
             .chain(IntoIterator::into_iter(self.test_nonce.to_be_bytes()))
+            .chain(IntoIterator::into_iter(self.sent_timestamp.to_be_bytes()))
             .chain(std::iter::once(self.node_type as u8))
             .chain(self.pub_key.to_bytes().iter().cloned())
             .chain(self.owner.as_bytes().iter().cloned())
@@ -148,27 +182,29 @@ impl TestPacket {
     }
 
     pub(crate) fn try_from_bytes(b: &[u8]) -> Result<Self, TestPacketError> {
-        // route id + test nonce size
+        // route id + test nonce + sent timestamp size
         let n = mem::size_of::<u64>();
 
-        if b.len() < 2 * n + 1 + identity::PUBLIC_KEY_LENGTH {
+        if b.len() < 3 * n + 1 + identity::PUBLIC_KEY_LENGTH {
             return Err(TestPacketError::IncompletePacket);
         }
 
         // those unwraps can't fail as we've already checked for the size
         let route_id = u64::from_be_bytes(b[0..n].try_into().unwrap());
         let test_nonce = u64::from_be_bytes(b[n..2 * n].try_into().unwrap());
-        let node_type = NodeType::try_from(b[2 * n])?;
+        let sent_timestamp = u64::from_be_bytes(b[2 * n..3 * n].try_into().unwrap());
+        let node_type = NodeType::try_from(b[3 * n])?;
 
         let pub_key = identity::PublicKey::from_bytes(
-            &b[2 * n + 1..2 * n + 1 + identity::PUBLIC_KEY_LENGTH],
+            &b[3 * n + 1..3 * n + 1 + identity::PUBLIC_KEY_LENGTH],
         )?;
-        let owner = std::str::from_utf8(&b[2 * n + 1 + identity::PUBLIC_KEY_LENGTH..])?;
+        let owner = std::str::from_utf8(&b[3 * n + 1 + identity::PUBLIC_KEY_LENGTH..])?;
 
         Ok(TestPacket {
             route_id,
             node_type,
             test_nonce,
+            sent_timestamp,
             pub_key,
             owner: owner.to_owned(),
         })
@@ -181,10 +217,15 @@ impl From<TestPacket> for TestedNode {
             identity: packet.pub_key.to_base58_string(),
             owner: packet.owner,
             node_type: packet.node_type,
+            layer: None,
         }
     }
 }
 
+pub(crate) fn current_unix_timestamp_millis() -> u64 {
+    (OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000) as u64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,7 +241,8 @@ mod tests {
             42,
             123,
             NodeType::Mixnode,
-        );
+        )
+        .stamped_now();
 
         let bytes = packet.to_bytes();
         let recovered = TestPacket::try_from_bytes(&bytes).unwrap();
@@ -6,6 +6,7 @@ use std::time::Duration;
 
 pub(crate) mod local_guard;
 pub(crate) mod models;
+pub(crate) mod pruner;
 pub(crate) mod routes;
 pub(crate) mod uptime_updater;
 pub(crate) mod utils;
@@ -23,8 +24,16 @@ pub(crate) fn stage_full() -> AdHoc {
                 routes::gateway_report,
                 routes::mixnode_uptime_history,
                 routes::gateway_uptime_history,
+                routes::mixnode_history_range,
+                routes::gateway_history_range,
                 routes::mixnode_core_status_count,
                 routes::gateway_core_status_count,
+                routes::mixnode_monitor_scores,
+                routes::gateway_monitor_scores,
+                routes::mixnode_latency,
+                routes::gateway_latency,
+                routes::layer_latencies,
+                routes::database_metrics,
                 routes::get_mixnode_status,
                 routes::get_mixnode_reward_estimation,
                 routes::get_mixnode_stake_saturation,
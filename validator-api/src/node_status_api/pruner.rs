@@ -0,0 +1,339 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use crate::node_status_api::models::ValidatorApiStorageError;
+use crate::node_status_api::ONE_DAY;
+use crate::storage::ValidatorApiStorage;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use log::{debug, error, info, warn};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+use time::{Date, OffsetDateTime};
+use tokio::time::interval;
+
+// node reports and the daily uptime updates rely on (at least) the last 24h of raw data
+// and the cutoff is aligned to the start of the day, so we need to keep one more day on top of that
+const MINIMUM_RAW_DATA_RETENTION: Duration = Duration::from_secs(2 * 86400);
+
+#[derive(Debug, Error)]
+pub(crate) enum PruningError {
+    #[error("We run into storage issues - {0}")]
+    StorageError(ValidatorApiStorageError),
+
+    #[error("Failed to serialize the monitoring data archive - {0}")]
+    SerializationError(#[from] serde_json::Error),
+
+    #[error("Failed to write the monitoring data archive to {path} - {source}")]
+    ArchiveWriteError { path: PathBuf, source: io::Error },
+
+    #[error("The archiving task did not finish - {0}")]
+    ArchivingTaskFailure(#[from] tokio::task::JoinError),
+
+    #[error("Timestamp {0} is out of the supported range")]
+    InvalidTimestamp(i64),
+}
+
+impl From<ValidatorApiStorageError> for PruningError {
+    fn from(err: ValidatorApiStorageError) -> Self {
+        PruningError::StorageError(err)
+    }
+}
+
+/// Periodically rolls the raw network monitoring data that is past the retention period
+/// into the daily summaries and removes it, optionally archiving it beforehand.
+pub(crate) struct MonitoringDataPruner {
+    storage: ValidatorApiStorage,
+    raw_data_retention: Duration,
+    pruning_interval: Duration,
+    archive_directory: Option<PathBuf>,
+
+    // if we're distributing rewards, we must never remove data of intervals that are yet to be rewarded
+    rewarding_enabled: bool,
+}
+
+impl MonitoringDataPruner {
+    pub(crate) fn new(config: &Config, storage: ValidatorApiStorage) -> Self {
+        let mut raw_data_retention = config.get_raw_data_retention();
+        if raw_data_retention < MINIMUM_RAW_DATA_RETENTION {
+            warn!(
+                "The configured raw data retention of {:?} is too short. {:?} is going to be used instead",
+                raw_data_retention, MINIMUM_RAW_DATA_RETENTION
+            );
+            raw_data_retention = MINIMUM_RAW_DATA_RETENTION;
+        }
+
+        let archive_directory = if config.get_archive_pruned_data() {
+            Some(config.get_archive_directory())
+        } else {
+            None
+        };
+
+        MonitoringDataPruner {
+            storage,
+            raw_data_retention,
+            pruning_interval: config.get_pruning_interval(),
+            archive_directory,
+            rewarding_enabled: config.get_rewarding_enabled(),
+        }
+    }
+
+    /// Determines the timestamp before which all raw data can be removed. Returns `None` if
+    /// nothing can be removed yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `now`: current time.
+    async fn determine_cutoff(&self, now: OffsetDateTime) -> Result<Option<i64>, PruningError> {
+        let rewarding_limit = if self.rewarding_enabled {
+            match self
+                .storage
+                .get_most_recent_interval_rewarding_entry()
+                .await?
+            {
+                // keep one day of buffer before any interval that might still need its data
+                Some(entry) if entry.finished => {
+                    Some(entry.interval_end_timestamp - ONE_DAY.as_secs() as i64)
+                }
+                Some(entry) => Some(entry.interval_start_timestamp - ONE_DAY.as_secs() as i64),
+                None => {
+                    debug!("No interval has been rewarded yet - the raw data is not going to be pruned");
+                    return Ok(None);
+                }
+            }
+        } else {
+            None
+        };
+
+        Ok(Some(pruning_cutoff(
+            now.unix_timestamp(),
+            self.raw_data_retention,
+            rewarding_limit,
+        )))
+    }
+
+    async fn prune(&self) -> Result<(), PruningError> {
+        let cutoff = match self.determine_cutoff(OffsetDateTime::now_utc()).await? {
+            Some(cutoff) => cutoff,
+            None => return Ok(()),
+        };
+
+        let oldest = match self.storage.get_oldest_raw_data_timestamp().await? {
+            Some(oldest) if oldest < cutoff => oldest,
+            _ => {
+                debug!("There is no raw monitoring data to prune");
+                return Ok(());
+            }
+        };
+
+        info!("Pruning raw monitoring data older than {}...", cutoff);
+
+        let mut pruned_rows = 0;
+        let mut day_start = start_of_day(oldest);
+        while day_start < cutoff {
+            let day_end = day_start + ONE_DAY.as_secs() as i64;
+            let date = date_of(day_start)?;
+
+            // if the archiving fails, we do not remove anything so that no data would get lost
+            if let Some(archive_directory) = &self.archive_directory {
+                if let Some(archive) =
+                    archive_day(&self.storage, archive_directory, date, day_start, day_end).await?
+                {
+                    debug!(
+                        "Raw monitoring data of {} was archived to {:?}",
+                        date, archive
+                    );
+                }
+            }
+
+            pruned_rows += self
+                .storage
+                .prune_raw_monitoring_data(&date.to_string(), day_start, day_end)
+                .await?;
+            day_start = day_end;
+        }
+
+        self.storage
+            .insert_data_pruning(cutoff, pruned_rows)
+            .await?;
+        info!("Pruned {} rows of raw monitoring data", pruned_rows);
+
+        Ok(())
+    }
+
+    async fn log_database_metrics(&self) -> Result<(), PruningError> {
+        let metrics = self.storage.get_database_metrics().await?;
+        info!(
+            "The database takes {} bytes ({} of which are free pages)",
+            metrics.size_bytes, metrics.free_bytes
+        );
+        for table in metrics.tables {
+            debug!("{} - {} rows", table.table, table.rows);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn run(&self) {
+        let mut interval = interval(self.pruning_interval);
+        loop {
+            interval.tick().await;
+            if let Err(err) = self.prune().await {
+                // as with the uptime updater, this implies some underlying issues with our
+                // database (or the filesystem) that might affect the entire program
+                error!("We failed to prune the raw monitoring data - {}", err)
+            }
+            if let Err(err) = self.log_database_metrics().await {
+                error!("We failed to obtain the database metrics - {}", err)
+            }
+        }
+    }
+}
+
+/// Exports, without removing anything, the raw monitoring data of all complete days before
+/// the provided timestamp into the specified directory. Returns paths to the created archives.
+///
+/// # Arguments
+///
+/// * `storage`: storage holding the monitoring data.
+/// * `directory`: directory the archives should be written to.
+/// * `until`: unix timestamp before which the data should be exported.
+pub(crate) async fn export_monitoring_data(
+    storage: &ValidatorApiStorage,
+    directory: &Path,
+    until: i64,
+) -> Result<Vec<PathBuf>, PruningError> {
+    let until = start_of_day(until);
+    let mut archives = Vec::new();
+
+    let mut day_start = match storage.get_oldest_raw_data_timestamp().await? {
+        Some(oldest) => start_of_day(oldest),
+        None => return Ok(archives),
+    };
+    while day_start < until {
+        let day_end = day_start + ONE_DAY.as_secs() as i64;
+        let date = date_of(day_start)?;
+        if let Some(archive) = archive_day(storage, directory, date, day_start, day_end).await? {
+            archives.push(archive)
+        }
+        day_start = day_end;
+    }
+
+    Ok(archives)
+}
+
+/// Writes all raw monitoring data of the specified day into a gzip-compressed json file.
+/// Returns path to the created archive or `None` if there was nothing to archive.
+async fn archive_day(
+    storage: &ValidatorApiStorage,
+    directory: &Path,
+    date: Date,
+    from: i64,
+    to: i64,
+) -> Result<Option<PathBuf>, PruningError> {
+    let archive = storage
+        .get_monitoring_data_archive(&date.to_string(), from, to)
+        .await?;
+    if archive.is_empty() {
+        return Ok(None);
+    }
+
+    let data = serde_json::to_vec(&archive)?;
+    let directory = directory.to_owned();
+    let file_stem = format!("monitoring-data-{}", date);
+
+    // compressing a day worth of data might take a while, so don't block the runtime with it
+    let path =
+        tokio::task::spawn_blocking(move || write_archive(&directory, &file_stem, &data)).await??;
+
+    Ok(Some(path))
+}
+
+fn write_archive(directory: &Path, file_stem: &str, data: &[u8]) -> Result<PathBuf, PruningError> {
+    let write_err = |path: &Path, source| PruningError::ArchiveWriteError {
+        path: path.to_owned(),
+        source,
+    };
+
+    fs::create_dir_all(directory).map_err(|source| write_err(directory, source))?;
+
+    // never overwrite an existing archive, for example one made by an earlier export of the same day
+    let mut path = directory.join(format!("{}.json.gz", file_stem));
+    if path.exists() {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        path = directory.join(format!("{}-{}.json.gz", file_stem, now));
+    }
+
+    let file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .map_err(|source| write_err(&path, source))?;
+
+    let mut encoder = GzEncoder::new(file, Compression::default());
+    encoder
+        .write_all(data)
+        .and_then(|_| encoder.finish())
+        .map_err(|source| write_err(&path, source))?;
+
+    Ok(path)
+}
+
+fn start_of_day(timestamp: i64) -> i64 {
+    let day = ONE_DAY.as_secs() as i64;
+    timestamp - timestamp.rem_euclid(day)
+}
+
+fn date_of(timestamp: i64) -> Result<Date, PruningError> {
+    OffsetDateTime::from_unix_timestamp(timestamp)
+        .map(|datetime| datetime.date())
+        .map_err(|_| PruningError::InvalidTimestamp(timestamp))
+}
+
+/// Determines the start of the day before which all raw data can be removed, so that only
+/// data older than the retention period and not needed for rewarding gets removed.
+fn pruning_cutoff(now: i64, retention: Duration, rewarding_limit: Option<i64>) -> i64 {
+    let retention_limit = now - retention.as_secs() as i64;
+    let cutoff = match rewarding_limit {
+        Some(rewarding_limit) => retention_limit.min(rewarding_limit),
+        None => retention_limit,
+    };
+    start_of_day(cutoff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamps_are_aligned_to_start_of_day() {
+        assert_eq!(start_of_day(0), 0);
+        assert_eq!(start_of_day(86_399), 0);
+        assert_eq!(start_of_day(86_400), 86_400);
+        assert_eq!(start_of_day(1_649_764_800), 1_649_721_600);
+    }
+
+    #[test]
+    fn pruning_cutoff_respects_retention_and_rewarding() {
+        // 2022-04-12 12:00:00 UTC
+        let now = 1_649_764_800;
+        let week = Duration::from_secs(7 * 86_400);
+
+        // 2022-04-05 00:00:00 UTC
+        assert_eq!(pruning_cutoff(now, week, None), 1_649_116_800);
+
+        // data of an interval that has not been rewarded yet must be kept
+        // even if it's past the retention period
+        assert_eq!(
+            pruning_cutoff(now, week, Some(1_648_900_000)),
+            1_648_857_600
+        );
+
+        // but the retention period still applies if the rewarding is up to date
+        assert_eq!(pruning_cutoff(now, week, Some(now - 86_400)), 1_649_116_800);
+    }
+}
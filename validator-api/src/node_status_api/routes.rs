@@ -1,20 +1,29 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::monitor_consensus::{aggregate_monitor_scores, MonitorConsensus};
 use crate::node_status_api::models::{
     ErrorResponse, GatewayStatusReport, GatewayUptimeHistory, MixnodeStatusReport,
     MixnodeUptimeHistory,
 };
+use crate::node_status_api::utils::HistoryBuckets;
+use crate::node_status_api::{ONE_DAY, ONE_HOUR};
 use crate::storage::ValidatorApiStorage;
 use crate::ValidatorCache;
 use rocket::http::Status;
 use rocket::serde::json::Json;
 use rocket::State;
+use time::OffsetDateTime;
 use validator_api_requests::models::{
-    CoreNodeStatusResponse, InclusionProbabilityResponse, MixnodeStatusResponse,
-    RewardEstimationResponse, StakeSaturationResponse,
+    CoreNodeStatusResponse, DatabaseMetricsResponse, HistoryBucketing,
+    InclusionProbabilityResponse, LayerLatencyResponse, MixnodeStatusResponse, NodeHistoryResponse,
+    NodeLatencyResponse, NodeMonitorScoresResponse, RewardEstimationResponse,
+    StakeSaturationResponse,
 };
 
+// maximum number of buckets that can be requested in a single historical query
+const MAX_HISTORY_BUCKETS: usize = 1000;
+
 #[get("/mixnode/<identity>/report")]
 pub(crate) async fn mixnode_report(
     storage: &State<ValidatorApiStorage>,
@@ -97,6 +106,222 @@ pub(crate) async fn gateway_core_status_count(
     })
 }
 
+async fn node_history(
+    cache: &ValidatorCache,
+    storage: &ValidatorApiStorage,
+    identity: &str,
+    is_mixnode: bool,
+    from: Option<i64>,
+    to: Option<i64>,
+    bucket: Option<&str>,
+) -> Result<Json<NodeHistoryResponse>, ErrorResponse> {
+    let bucketing = match bucket {
+        Some(bucket) => bucket
+            .parse::<HistoryBucketing>()
+            .map_err(|err| ErrorResponse::new(err, Status::BadRequest))?,
+        None => HistoryBucketing::Hourly,
+    };
+
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    let to = to.unwrap_or(now);
+    let from = from.unwrap_or_else(|| to.saturating_sub(ONE_DAY.as_secs() as i64));
+    if from >= to {
+        return Err(ErrorResponse::new(
+            "'from' must be smaller than 'to'",
+            Status::BadRequest,
+        ));
+    }
+
+    let (anchor, bucket_length) = match bucketing {
+        HistoryBucketing::Hourly => (0, ONE_HOUR.as_secs() as i64),
+        HistoryBucketing::Daily => (0, ONE_DAY.as_secs() as i64),
+        HistoryBucketing::Interval => {
+            let current_interval = cache.current_interval().await.into_inner();
+            (
+                current_interval.start_unix_timestamp(),
+                current_interval.length().as_secs() as i64,
+            )
+        }
+    };
+    if bucket_length <= 0 {
+        return Err(ErrorResponse::new(
+            "the rewarding interval length is not known yet",
+            Status::ServiceUnavailable,
+        ));
+    }
+
+    // the values come straight from the query, so make sure the bucket arithmetic can't overflow
+    if from < 0 || to > now + bucket_length {
+        return Err(ErrorResponse::new(
+            "the requested range must not start before the unix epoch nor end more than a single bucket into the future",
+            Status::BadRequest,
+        ));
+    }
+
+    let buckets = HistoryBuckets::new(from, to, anchor, bucket_length);
+    if buckets.count > MAX_HISTORY_BUCKETS {
+        return Err(ErrorResponse::new(
+            format!(
+                "the requested range would result in {} buckets, while at most {} are allowed",
+                buckets.count, MAX_HISTORY_BUCKETS
+            ),
+            Status::BadRequest,
+        ));
+    }
+
+    storage
+        .get_node_history(identity, is_mixnode, from, to, buckets, bucketing)
+        .await
+        .map(|buckets| {
+            Json(NodeHistoryResponse {
+                identity: identity.to_string(),
+                from,
+                to,
+                bucketing,
+                buckets,
+            })
+        })
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))
+}
+
+#[get("/mixnode/<identity>/history/range?<from>&<to>&<bucket>")]
+pub(crate) async fn mixnode_history_range(
+    cache: &State<ValidatorCache>,
+    storage: &State<ValidatorApiStorage>,
+    identity: &str,
+    from: Option<i64>,
+    to: Option<i64>,
+    bucket: Option<&str>,
+) -> Result<Json<NodeHistoryResponse>, ErrorResponse> {
+    node_history(cache, storage, identity, true, from, to, bucket).await
+}
+
+#[get("/gateway/<identity>/history/range?<from>&<to>&<bucket>")]
+pub(crate) async fn gateway_history_range(
+    cache: &State<ValidatorCache>,
+    storage: &State<ValidatorApiStorage>,
+    identity: &str,
+    from: Option<i64>,
+    to: Option<i64>,
+    bucket: Option<&str>,
+) -> Result<Json<NodeHistoryResponse>, ErrorResponse> {
+    node_history(cache, storage, identity, false, from, to, bucket).await
+}
+
+async fn node_monitor_scores(
+    monitor_consensus: &MonitorConsensus,
+    storage: &ValidatorApiStorage,
+    identity: &str,
+    is_mixnode: bool,
+    since: Option<i64>,
+    until: Option<i64>,
+) -> Result<Json<NodeMonitorScoresResponse>, ErrorResponse> {
+    let now = OffsetDateTime::now_utc();
+    let until = until.unwrap_or_else(|| now.unix_timestamp());
+    let since = since.unwrap_or_else(|| (now - ONE_DAY).unix_timestamp());
+
+    let monitor_scores = storage
+        .get_node_monitor_scores(
+            identity,
+            is_mixnode,
+            since,
+            until,
+            monitor_consensus.minimum_monitor_runs(since, until),
+            monitor_consensus.trusted_monitors(),
+        )
+        .await
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))?;
+    let aggregated_uptime = aggregate_monitor_scores(&monitor_scores).map(|uptime| uptime.u8());
+
+    Ok(Json(NodeMonitorScoresResponse {
+        identity: identity.to_string(),
+        since,
+        until,
+        monitor_scores,
+        aggregated_uptime,
+    }))
+}
+
+#[get("/mixnode/<identity>/monitor-scores?<since>&<until>")]
+pub(crate) async fn mixnode_monitor_scores(
+    monitor_consensus: &State<MonitorConsensus>,
+    storage: &State<ValidatorApiStorage>,
+    identity: &str,
+    since: Option<i64>,
+    until: Option<i64>,
+) -> Result<Json<NodeMonitorScoresResponse>, ErrorResponse> {
+    node_monitor_scores(monitor_consensus, storage, identity, true, since, until).await
+}
+
+#[get("/gateway/<identity>/monitor-scores?<since>&<until>")]
+pub(crate) async fn gateway_monitor_scores(
+    monitor_consensus: &State<MonitorConsensus>,
+    storage: &State<ValidatorApiStorage>,
+    identity: &str,
+    since: Option<i64>,
+    until: Option<i64>,
+) -> Result<Json<NodeMonitorScoresResponse>, ErrorResponse> {
+    node_monitor_scores(monitor_consensus, storage, identity, false, since, until).await
+}
+
+async fn node_latency(
+    storage: &ValidatorApiStorage,
+    identity: &str,
+    is_mixnode: bool,
+    since: Option<i64>,
+) -> Result<Json<NodeLatencyResponse>, ErrorResponse> {
+    let since = since.unwrap_or_else(|| (OffsetDateTime::now_utc() - ONE_DAY).unix_timestamp());
+
+    storage
+        .get_node_latency(identity, is_mixnode, since)
+        .await
+        .map(Json)
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))
+}
+
+#[get("/mixnode/<identity>/latency?<since>")]
+pub(crate) async fn mixnode_latency(
+    storage: &State<ValidatorApiStorage>,
+    identity: &str,
+    since: Option<i64>,
+) -> Result<Json<NodeLatencyResponse>, ErrorResponse> {
+    node_latency(storage, identity, true, since).await
+}
+
+#[get("/gateway/<identity>/latency?<since>")]
+pub(crate) async fn gateway_latency(
+    storage: &State<ValidatorApiStorage>,
+    identity: &str,
+    since: Option<i64>,
+) -> Result<Json<NodeLatencyResponse>, ErrorResponse> {
+    node_latency(storage, identity, false, since).await
+}
+
+#[get("/latency/layers?<since>")]
+pub(crate) async fn layer_latencies(
+    storage: &State<ValidatorApiStorage>,
+    since: Option<i64>,
+) -> Result<Json<LayerLatencyResponse>, ErrorResponse> {
+    let since = since.unwrap_or_else(|| (OffsetDateTime::now_utc() - ONE_DAY).unix_timestamp());
+
+    storage
+        .get_layer_latencies(since)
+        .await
+        .map(Json)
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))
+}
+
+#[get("/database")]
+pub(crate) async fn database_metrics(
+    storage: &State<ValidatorApiStorage>,
+) -> Result<Json<DatabaseMetricsResponse>, ErrorResponse> {
+    storage
+        .get_database_metrics()
+        .await
+        .map(Json)
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))
+}
+
 #[get("/mixnode/<identity>/status")]
 pub(crate) async fn get_mixnode_status(
     cache: &State<ValidatorCache>,
@@ -111,6 +336,7 @@ pub(crate) async fn get_mixnode_status(
 pub(crate) async fn get_mixnode_reward_estimation(
     cache: &State<ValidatorCache>,
     storage: &State<ValidatorApiStorage>,
+    monitor_consensus: &State<MonitorConsensus>,
     identity: String,
 ) -> Result<Json<RewardEstimationResponse>, ErrorResponse> {
     let (bond, status) = cache.mixnode_details(&identity).await;
@@ -120,11 +346,16 @@ pub(crate) async fn get_mixnode_reward_estimation(
         let interval_reward_params = interval_reward_params.into_inner();
 
         let current_interval = cache.current_interval().await.into_inner();
+        let interval_start = current_interval.start_unix_timestamp();
+        let interval_end = current_interval.end_unix_timestamp();
         let uptime = storage
-            .get_average_mixnode_uptime_in_interval(
+            .get_aggregated_uptime_in_interval(
                 &identity,
-                current_interval.start_unix_timestamp(),
-                current_interval.end_unix_timestamp(),
+                true,
+                interval_start,
+                interval_end,
+                monitor_consensus.minimum_monitor_runs(interval_start, interval_end),
+                monitor_consensus.trusted_monitors(),
             )
             .await
             .map_err(|err| ErrorResponse::new(err.to_string(), Status::NotFound))?;
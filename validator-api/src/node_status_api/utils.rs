@@ -107,3 +107,83 @@ impl NodeUptimes {
         }
     }
 }
+
+// A helper struct describing the time buckets historical node data is aggregated into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct HistoryBuckets {
+    // unix timestamp of the beginning of the first bucket
+    pub(crate) origin: i64,
+    pub(crate) length: i64,
+    pub(crate) count: usize,
+}
+
+impl HistoryBuckets {
+    /// Determines the buckets covering the [`from`, `to`) range that are aligned such that one of
+    /// them would begin at `anchor`, for example at the beginning of a rewarding interval.
+    /// Note that `length` has to be positive and all of the timestamps have to be within
+    /// a sane range, i.e. not anywhere near the `i64` limits.
+    pub(crate) fn new(from: i64, to: i64, anchor: i64, length: i64) -> Self {
+        let origin = from - (from - anchor).rem_euclid(length);
+        let count = if to > origin {
+            ((to - origin + length - 1) / length) as usize
+        } else {
+            0
+        };
+
+        HistoryBuckets {
+            origin,
+            length,
+            count,
+        }
+    }
+
+    pub(crate) fn start(&self, index: usize) -> i64 {
+        self.origin + index as i64 * self.length
+    }
+
+    pub(crate) fn end(&self, index: usize) -> i64 {
+        self.start(index + 1)
+    }
+
+    pub(crate) fn index_of(&self, timestamp: i64) -> Option<usize> {
+        if timestamp < self.origin {
+            return None;
+        }
+        let index = ((timestamp - self.origin) / self.length) as usize;
+        if index < self.count {
+            Some(index)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_buckets_are_aligned_with_the_anchor() {
+        let buckets = HistoryBuckets::new(3_700, 10_000, 0, 3_600);
+        assert_eq!(buckets.origin, 3_600);
+        assert_eq!(buckets.count, 2);
+        assert_eq!(buckets.start(1), 7_200);
+        assert_eq!(buckets.end(1), 10_800);
+
+        // anchor in the future (e.g. start of the current interval)
+        let buckets = HistoryBuckets::new(1_000, 2_000, 5_050, 100);
+        assert_eq!(buckets.origin, 950);
+        assert_eq!(buckets.count, 11);
+    }
+
+    #[test]
+    fn timestamps_are_assigned_to_correct_buckets() {
+        let buckets = HistoryBuckets::new(100, 400, 0, 100);
+        assert_eq!(buckets.count, 3);
+        assert_eq!(buckets.index_of(99), None);
+        assert_eq!(buckets.index_of(100), Some(0));
+        assert_eq!(buckets.index_of(250), Some(1));
+        assert_eq!(buckets.index_of(399), Some(2));
+        assert_eq!(buckets.index_of(400), None);
+    }
+}
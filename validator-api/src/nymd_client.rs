@@ -2,12 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::config::Config;
-use crate::rewarding::{error::RewardingError, IntervalRewardParams, MixnodeToReward};
+use crate::rewarding::{
+    error::RewardingError, GatewayToReward, IntervalRewardParams, MixnodeToReward,
+};
 use config::defaults::{default_network, DEFAULT_VALIDATOR_API_PORT};
 use mixnet_contract_common::{
-    ContractStateParams, Delegation, ExecuteMsg, GatewayBond, IdentityKey, Interval, MixNodeBond,
-    MixnodeRewardingStatusResponse, RewardedSetNodeStatus, RewardedSetUpdateDetails,
-    MIXNODE_DELEGATORS_PAGE_LIMIT,
+    ContractStateParams, Delegation, ExecuteMsg, GatewayBond, GatewayRewardingStatusResponse,
+    IdentityKey, Interval, LayerAssignment, MixNodeBond, MixnodeRewardingStatusResponse,
+    RewardedSetNodeStatus, RewardedSetSeed, RewardedSetUpdateDetails,
+    GATEWAY_DELEGATORS_PAGE_LIMIT, MIXNODE_DELEGATORS_PAGE_LIMIT,
 };
 use serde::Serialize;
 use std::sync::Arc;
@@ -100,6 +103,15 @@ impl<C> Client<C> {
         Ok(time)
     }
 
+    pub(crate) async fn current_block_height(&self) -> Result<u64, ValidatorClientError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        let height = self.0.read().await.nymd.get_current_block_height().await?;
+
+        Ok(height.value())
+    }
+
     pub(crate) async fn get_current_interval(&self) -> Result<Interval, ValidatorClientError>
     where
         C: CosmWasmClient + Sync,
@@ -114,6 +126,20 @@ impl<C> Client<C> {
         self.0.read().await.get_all_nymd_mixnodes().await
     }
 
+    pub(crate) async fn get_mixnodes_at_height(
+        &self,
+        height: u64,
+    ) -> Result<Vec<MixNodeBond>, ValidatorClientError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        self.0
+            .read()
+            .await
+            .get_all_nymd_mixnodes_at_height(height)
+            .await
+    }
+
     pub(crate) async fn get_gateways(&self) -> Result<Vec<GatewayBond>, ValidatorClientError>
     where
         C: CosmWasmClient + Sync,
@@ -144,6 +170,7 @@ impl<C> Client<C> {
         let state = this.get_contract_settings().await?;
         let reward_pool = this.get_reward_pool().await?;
         let interval_reward_percent = this.get_interval_reward_percent().await?;
+        let period_reward_pool = (reward_pool / 100) * interval_reward_percent as u128;
 
         let interval_reward_params = IntervalRewardParams {
             reward_pool,
@@ -151,13 +178,29 @@ impl<C> Client<C> {
             sybil_resistance_percent: this.get_sybil_resistance_percent().await?,
             rewarded_set_size: state.mixnode_rewarded_set_size,
             active_set_size: state.mixnode_active_set_size,
-            period_reward_pool: (reward_pool / 100) * interval_reward_percent as u128,
+            period_reward_pool,
             active_set_work_factor: this.get_active_set_work_factor().await?,
+            gateway_reward_pool: state
+                .gateway_rewarding
+                .gateway_reward_pool(period_reward_pool),
         };
 
         Ok(interval_reward_params)
     }
 
+    pub(crate) async fn get_pending_delegation_slashings(
+        &self,
+    ) -> Result<Vec<IdentityKey>, ValidatorClientError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        self.0
+            .read()
+            .await
+            .get_all_nymd_pending_delegation_slashings()
+            .await
+    }
+
     pub(crate) async fn get_rewarding_status(
         &self,
         mix_identity: mixnet_contract_common::IdentityKey,
@@ -173,13 +216,27 @@ impl<C> Client<C> {
             .await
     }
 
+    pub(crate) async fn get_gateway_rewarding_status(
+        &self,
+        gateway_identity: IdentityKey,
+        interval_id: u32,
+    ) -> Result<GatewayRewardingStatusResponse, ValidatorClientError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        self.0
+            .read()
+            .await
+            .get_gateway_rewarding_status(gateway_identity, interval_id)
+            .await
+    }
+
     /// Obtains the hash of a block specified by the provided height.
     /// If the resulting digest is empty, a `None` is returned instead.
     ///
     /// # Arguments
     ///
     /// * `height`: height of the block for which we want to obtain the hash.
-    #[allow(dead_code)]
     pub(crate) async fn get_block_hash(
         &self,
         height: u32,
@@ -209,6 +266,20 @@ impl<C> Client<C> {
             .await
     }
 
+    pub(crate) async fn get_gateway_delegations(
+        &self,
+        identity: IdentityKey,
+    ) -> Result<Vec<Delegation>, ValidatorClientError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        self.0
+            .read()
+            .await
+            .get_all_nymd_single_gateway_delegations(identity)
+            .await
+    }
+
     pub(crate) async fn get_rewarded_set_identities(
         &self,
     ) -> Result<Vec<(IdentityKey, RewardedSetNodeStatus)>, ValidatorClientError>
@@ -222,6 +293,15 @@ impl<C> Client<C> {
             .await
     }
 
+    pub(crate) async fn get_layer_assignment(
+        &self,
+    ) -> Result<Vec<LayerAssignment>, ValidatorClientError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        self.0.read().await.get_all_nymd_layer_assignment().await
+    }
+
     pub(crate) async fn get_current_rewarded_set_update_details(
         &self,
     ) -> Result<RewardedSetUpdateDetails, ValidatorClientError>
@@ -247,6 +327,8 @@ impl<C> Client<C> {
         &self,
         rewarded_set: Vec<IdentityKey>,
         expected_active_set_size: u32,
+        seed: RewardedSetSeed,
+        layer_assignment: Vec<LayerAssignment>,
     ) -> Result<(), ValidatorClientError>
     where
         C: SigningCosmWasmClient + Sync,
@@ -255,7 +337,12 @@ impl<C> Client<C> {
             .write()
             .await
             .nymd
-            .write_rewarded_set(rewarded_set, expected_active_set_size)
+            .write_rewarded_set(
+                rewarded_set,
+                expected_active_set_size,
+                seed,
+                layer_assignment,
+            )
             .await?;
         Ok(())
     }
@@ -321,10 +408,53 @@ impl<C> Client<C> {
             .await
     }
 
+    /// Simulates rewarding of the provided batch of mixnodes and returns the amount of gas
+    /// the transaction is expected to use.
+    pub(crate) async fn estimate_mixnodes_rewarding_gas(
+        &self,
+        nodes: &[MixnodeToReward],
+        interval_id: u32,
+    ) -> Result<u64, RewardingError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let msgs: Vec<(ExecuteMsg, _)> = nodes
+            .iter()
+            .map(|node| node.to_reward_execute_msg(interval_id))
+            .zip(std::iter::repeat(Vec::new()))
+            .collect();
+
+        let memo = format!("simulating rewarding of {} mixnodes", msgs.len());
+
+        self.simulate_execute_multiple(msgs, memo).await
+    }
+
+    /// Simulates rewarding of the provided batch of gateways and returns the amount of gas
+    /// the transaction is expected to use.
+    pub(crate) async fn estimate_gateways_rewarding_gas(
+        &self,
+        gateways: &[GatewayToReward],
+        interval_id: u32,
+    ) -> Result<u64, RewardingError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let msgs: Vec<(ExecuteMsg, _)> = gateways
+            .iter()
+            .map(|gateway| gateway.to_reward_execute_msg(interval_id))
+            .zip(std::iter::repeat(Vec::new()))
+            .collect();
+
+        let memo = format!("simulating rewarding of {} gateways", msgs.len());
+
+        self.simulate_execute_multiple(msgs, memo).await
+    }
+
     pub(crate) async fn reward_mixnodes_with_single_page_of_delegators(
         &self,
         nodes: &[MixnodeToReward],
         interval_id: u32,
+        estimated_gas: Option<u64>,
     ) -> Result<(), RewardingError>
     where
         C: SigningCosmWasmClient + Sync,
@@ -336,11 +466,169 @@ impl<C> Client<C> {
             .collect();
 
         let memo = format!("rewarding {} mixnodes", msgs.len());
+        let fee = self.estimated_gas_fee(estimated_gas).await;
+
+        self.execute_multiple_with_retry(msgs, fee, memo).await
+    }
 
+    pub(crate) async fn reward_gateways_with_single_page_of_delegators(
+        &self,
+        gateways: &[GatewayToReward],
+        interval_id: u32,
+        estimated_gas: Option<u64>,
+    ) -> Result<(), RewardingError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let msgs: Vec<(ExecuteMsg, _)> = gateways
+            .iter()
+            .map(|gateway| gateway.to_reward_execute_msg(interval_id))
+            .zip(std::iter::repeat(Vec::new()))
+            .collect();
+
+        let memo = format!("rewarding {} gateways", msgs.len());
+        let fee = self.estimated_gas_fee(estimated_gas).await;
+
+        self.execute_multiple_with_retry(msgs, fee, memo).await
+    }
+
+    pub(crate) async fn report_mixnodes_performance(
+        &self,
+        nodes: &[MixnodeToReward],
+        interval_id: u32,
+    ) -> Result<(), RewardingError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let msgs: Vec<(ExecuteMsg, _)> = nodes
+            .iter()
+            .map(|node| node.to_performance_report_execute_msg(interval_id))
+            .zip(std::iter::repeat(Vec::new()))
+            .collect();
+
+        let memo = format!("reporting performance of {} mixnodes", msgs.len());
+
+        self.execute_multiple_with_retry(msgs, Default::default(), memo)
+            .await
+    }
+
+    pub(crate) async fn slash_next_mix_delegators(
+        &self,
+        mix_identity: IdentityKey,
+        interval_id: u32,
+    ) -> Result<(), RewardingError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let msg = (
+            ExecuteMsg::SlashNextMixDelegators {
+                mix_identity,
+                interval_id,
+            },
+            vec![],
+        );
+
+        let memo = "slashing delegators".to_string();
+        self.execute_multiple_with_retry(vec![msg], Default::default(), memo)
+            .await
+    }
+
+    pub(crate) async fn reward_gateway_and_all_delegators(
+        &self,
+        gateway: &GatewayToReward,
+        interval_id: u32,
+    ) -> Result<(), RewardingError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        // the logic is identical to the one used for mixnodes, i.e. start with the operator
+        // and the first page of delegators
+        let msgs = vec![(gateway.to_reward_execute_msg(interval_id), vec![])];
+        let memo = format!(
+            "gateway operator + {} delegators rewarding",
+            GATEWAY_DELEGATORS_PAGE_LIMIT
+        );
         self.execute_multiple_with_retry(msgs, Default::default(), memo)
+            .await?;
+
+        // and then reward rest of delegators (if applicable)
+        if gateway.total_delegations > GATEWAY_DELEGATORS_PAGE_LIMIT {
+            let mut remaining_delegators =
+                gateway.total_delegations - GATEWAY_DELEGATORS_PAGE_LIMIT;
+            let delegator_rewarding_msg = (
+                gateway.to_next_delegator_reward_execute_msg(interval_id),
+                vec![],
+            );
+
+            while remaining_delegators > 0 {
+                let delegators_in_call = remaining_delegators.min(GATEWAY_DELEGATORS_PAGE_LIMIT);
+                let msgs = vec![delegator_rewarding_msg.clone()];
+                let memo = format!(
+                    "rewarding another {} gateway delegators",
+                    delegators_in_call
+                );
+                self.execute_multiple_with_retry(msgs, Default::default(), memo)
+                    .await?;
+
+                remaining_delegators =
+                    remaining_delegators.saturating_sub(GATEWAY_DELEGATORS_PAGE_LIMIT)
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn reward_gateway_delegators(
+        &self,
+        gateway: &GatewayToReward,
+        interval_id: u32,
+    ) -> Result<(), RewardingError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let delegator_rewarding_msg = (
+            gateway.to_next_delegator_reward_execute_msg(interval_id),
+            vec![],
+        );
+
+        let memo = "rewarding gateway delegators".to_string();
+        self.execute_multiple_with_retry(vec![delegator_rewarding_msg], Default::default(), memo)
             .await
     }
 
+    // if we have already simulated the transaction, there's no point in doing it again
+    // when determining the fee
+    async fn estimated_gas_fee(&self, estimated_gas: Option<u64>) -> Fee
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        match estimated_gas {
+            Some(gas) => self.0.read().await.nymd.simulated_gas_fee(gas.into()),
+            None => Default::default(),
+        }
+    }
+
+    async fn simulate_execute_multiple<M>(
+        &self,
+        msgs: Vec<(M, Vec<CosmosCoin>)>,
+        memo: String,
+    ) -> Result<u64, RewardingError>
+    where
+        C: SigningCosmWasmClient + Sync,
+        M: Serialize + Clone + Send,
+    {
+        let client_guard = self.0.read().await;
+        let contract = client_guard
+            .get_mixnet_contract_address()
+            .ok_or(RewardingError::UnspecifiedContractAddress)?;
+
+        let gas = client_guard
+            .nymd
+            .simulate_execute_multiple(&contract, msgs, memo)
+            .await?;
+        Ok(gas.value())
+    }
+
     async fn execute_multiple_with_retry<M>(
         &self,
         msgs: Vec<(M, Vec<CosmosCoin>)>,
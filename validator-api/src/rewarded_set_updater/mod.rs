@@ -14,9 +14,7 @@
 
 use crate::contract_cache::ValidatorCache;
 use crate::nymd_client::Client;
-use mixnet_contract_common::{IdentityKey, MixNodeBond};
-use rand::prelude::SliceRandom;
-use rand::rngs::OsRng;
+use mixnet_contract_common::{assign_layers, determine_rewarded_set, RewardedSetSeed};
 use std::sync::Arc;
 use tokio::sync::Notify;
 use validator_client::nymd::SigningNymdClient;
@@ -40,44 +38,72 @@ impl RewardedSetUpdater {
         }
     }
 
-    fn determine_rewarded_set(
-        &self,
-        mixnodes: Vec<MixNodeBond>,
-        nodes_to_select: u32,
-    ) -> Vec<IdentityKey> {
-        if mixnodes.is_empty() {
-            return Vec::new();
-        }
-
-        let mut rng = OsRng;
+    // the seed is the hash of the first block at which we were allowed to update the rewarded set,
+    // which is something we can't influence and that anyone can look up afterwards
+    async fn rewarded_set_seed(&self) -> Option<RewardedSetSeed> {
+        let update_details = match self
+            .nymd_client
+            .get_current_rewarded_set_update_details()
+            .await
+        {
+            Ok(details) => details,
+            Err(err) => {
+                log::error!("failed to obtain rewarded set update details - {}", err);
+                return None;
+            }
+        };
 
-        // generate list of mixnodes and their relatively weight (by total stake)
-        let choices = mixnodes
-            .into_iter()
-            .map(|mix| {
-                // note that the theoretical maximum possible stake is equal to the total
-                // supply of all tokens, i.e. 1B (which is 1 quadrillion of native tokens, i.e. 10^15 ~ 2^50)
-                // which is way below maximum value of f64, so the cast is fine
-                let total_stake = mix.total_bond().unwrap_or_default() as f64;
-                (mix.mix_node.identity_key, total_stake)
-            }) // if for some reason node is invalid, treat it as 0 stake/weight
-            .collect::<Vec<_>>();
+        let seed_height = update_details.last_refreshed_block + update_details.refresh_rate_blocks;
+        if seed_height >= update_details.current_height {
+            // we were notified too early, the next cache refresh will try again
+            log::warn!(
+                "the block at height {} can't be used as the rewarded set seed yet (current height is {})",
+                seed_height,
+                update_details.current_height
+            );
+            return None;
+        }
 
-        // the unwrap here is fine as an error can only be thrown under one of the following conditions:
-        // - our mixnode list is empty - we have already checked for that
-        // - we have invalid weights, i.e. less than zero or NaNs - it shouldn't happen in our case as we safely cast down from u128
-        // - all weights are zero - it's impossible in our case as the list of nodes is not empty and weight is proportional to stake. You must have non-zero stake in order to bond
-        // - we have more than u32::MAX values (which is incredibly unrealistic to have 4B mixnodes bonded... literally every other person on the planet would need one)
-        choices
-            .choose_multiple_weighted(&mut rng, nodes_to_select as usize, |item| item.1)
-            .unwrap()
-            .map(|(identity, _weight)| identity.clone())
-            .collect()
+        match self.nymd_client.get_block_hash(seed_height as u32).await {
+            Ok(Some(hash)) => Some(RewardedSetSeed::new(seed_height, hash)),
+            Ok(None) => {
+                log::error!("block at height {} does not have a hash", seed_height);
+                None
+            }
+            Err(err) => {
+                log::error!(
+                    "failed to obtain hash of the block at height {} - {}",
+                    seed_height,
+                    err
+                );
+                None
+            }
+        }
     }
 
     async fn update_rewarded_set(&self) {
-        // we know the entries are not stale, as a matter of fact they were JUST updated, since we got notified
-        let all_nodes = self.validator_cache.mixnodes().await.into_inner();
+        let seed = match self.rewarded_set_seed().await {
+            Some(seed) => seed,
+            None => return,
+        };
+        // unwrap is fine as we have just created the seed from a valid hash
+        let seed_bytes = seed.seed().unwrap();
+
+        // rather than using whatever the cache has seen most recently, read the bonds as they were
+        // at the seed height, which is stored alongside the seed, so that anyone could reproduce
+        // (and verify) the selection afterwards
+        let all_nodes = match self.nymd_client.get_mixnodes_at_height(seed.height).await {
+            Ok(nodes) => nodes,
+            Err(err) => {
+                log::error!(
+                    "failed to obtain the mixnode bonds at height {} - {}",
+                    seed.height,
+                    err
+                );
+                return;
+            }
+        };
+        // the set sizes are checked against the current contract settings when the set is written
         let rewarding_params = self
             .validator_cache
             .interval_reward_params()
@@ -88,10 +114,19 @@ impl RewardedSetUpdater {
         let active_set_size = rewarding_params.active_set_size;
 
         // note that top k nodes are in the active set
-        let new_rewarded_set = self.determine_rewarded_set(all_nodes, rewarded_set_size);
+        let new_rewarded_set = determine_rewarded_set(seed_bytes, &all_nodes, rewarded_set_size);
+        log::info!(
+            "selected {} nodes for the rewarded set using the hash of block {} as the seed",
+            new_rewarded_set.len(),
+            seed.height
+        );
+
+        // rather than relying on layers chosen at bonding time, rebalance them with every new set
+        let layer_assignment = assign_layers(&new_rewarded_set, active_set_size, &all_nodes);
+
         if let Err(err) = self
             .nymd_client
-            .write_rewarded_set(new_rewarded_set, active_set_size)
+            .write_rewarded_set(new_rewarded_set, active_set_size, seed, layer_assignment)
             .await
         {
             log::error!("failed to update the rewarded set - {}", err)
@@ -24,6 +24,12 @@ pub(crate) enum RewardingError {
     #[error("Failed to query the smart contract - {0}")]
     ValidatorClientError(ValidatorClientError),
 
+    #[error("Failed to restore the rewarding job of {identity} - {source}")]
+    MalformedRewardingJob {
+        identity: String,
+        source: serde_json::Error,
+    },
+
     #[error("Error downcasting u128 -> u64")]
     DowncastingError {
         #[from]
@@ -58,4 +64,14 @@ impl RewardingError {
             _ => false,
         }
     }
+
+    pub fn is_out_of_gas(&self) -> bool {
+        match &self {
+            RewardingError::ContractExecutionFailure(nymd_err)
+            | RewardingError::ValidatorClientError(ValidatorClientError::NymdError(nymd_err)) => {
+                nymd_err.is_out_of_gas()
+            }
+            _ => false,
+        }
+    }
 }
@@ -2,21 +2,25 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::contract_cache::ValidatorCache;
-use crate::node_status_api::ONE_DAY;
 use crate::nymd_client::Client;
 use crate::rewarding::error::RewardingError;
 use crate::storage::models::{
-    FailedMixnodeRewardChunk, PossiblyUnrewardedMixnode, RewardingReport,
+    FailedMixnodeRewardChunk, PossiblyUnrewardedMixnode, RewardingJob, RewardingJobStatus,
+    RewardingReport,
 };
 use crate::storage::ValidatorApiStorage;
 use config::defaults::DENOM;
 use log::{error, info};
 use mixnet_contract_common::mixnode::NodeRewardParams;
 use mixnet_contract_common::{
-    ExecuteMsg, IdentityKey, Interval, MixNodeBond, RewardingStatus, MIXNODE_DELEGATORS_PAGE_LIMIT,
+    Delegation, ExecuteMsg, GatewayBond, GatewayRewardParams, GatewayRewardingStatus, IdentityKey,
+    Interval, MixNodeBond, RewardingStatus, GATEWAY_DELEGATORS_PAGE_LIMIT,
+    MIXNODE_DELEGATORS_PAGE_LIMIT,
 };
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
 use std::collections::HashSet;
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 use std::process;
 use std::time::Duration;
 use time::OffsetDateTime;
@@ -24,8 +28,13 @@ use tokio::time::sleep;
 use validator_client::nymd::SigningNymdClient;
 
 pub(crate) mod error;
+pub(crate) mod routes;
+pub(crate) mod simulator;
 
-#[derive(Copy, Clone)]
+// maximum number of performance reports sent in a single transaction
+const PERFORMANCE_REPORT_CHUNK_SIZE: usize = 25;
+
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub(crate) struct IntervalRewardParams {
     pub(crate) reward_pool: u128,
     pub(crate) circulating_supply: u128,
@@ -34,6 +43,10 @@ pub(crate) struct IntervalRewardParams {
     pub(crate) active_set_size: u32,
     pub(crate) period_reward_pool: u128,
     pub(crate) active_set_work_factor: u8,
+
+    // part of the `period_reward_pool` reserved for the gateways
+    #[serde(default)]
+    pub(crate) gateway_reward_pool: u128,
 }
 
 impl IntervalRewardParams {
@@ -50,26 +63,40 @@ impl IntervalRewardParams {
             active_set_size: 0,
             period_reward_pool: 0,
             active_set_work_factor: 0,
+            gateway_reward_pool: 0,
         }
     }
 
-    pub(crate) fn estimate_reward(
-        &self,
-        node: &MixNodeBond,
-        uptime: u8,
-        in_active_set: bool,
-    ) -> Result<(u64, u64, u64), RewardingError> {
-        let node_reward_params = NodeRewardParams::new(
-            self.period_reward_pool,
+    /// Part of the interval reward pool that is distributed between the mixnodes, i.e. without
+    /// the share reserved for the gateways.
+    pub(crate) fn mixnode_period_reward_pool(&self) -> u128 {
+        self.period_reward_pool
+            .saturating_sub(self.gateway_reward_pool)
+    }
+
+    /// Constructs the rewarding parameters of a mixnode with the provided uptime for this interval.
+    pub(crate) fn node_reward_params(&self, uptime: u8, in_active_set: bool) -> NodeRewardParams {
+        NodeRewardParams::new(
+            self.mixnode_period_reward_pool(),
             self.rewarded_set_size.into(),
             self.active_set_size.into(),
+            // Reward blockstamp gets set in the contract call
             0,
             self.circulating_supply,
             uptime.into(),
             self.sybil_resistance_percent,
             in_active_set,
             self.active_set_work_factor,
-        );
+        )
+    }
+
+    pub(crate) fn estimate_reward(
+        &self,
+        node: &MixNodeBond,
+        uptime: u8,
+        in_active_set: bool,
+    ) -> Result<(u64, u64, u64), RewardingError> {
+        let node_reward_params = self.node_reward_params(uptime, in_active_set);
 
         let total_node_reward = node.reward(&node_reward_params);
         let operator_reward = node.operator_reward(&node_reward_params);
@@ -120,6 +147,171 @@ impl MixnodeToReward {
             interval_id,
         }
     }
+
+    pub(crate) fn to_performance_report_execute_msg(&self, interval_id: u32) -> ExecuteMsg {
+        ExecuteMsg::ReportMixnodePerformance {
+            identity: self.identity.clone(),
+            params: self.params(),
+            interval_id,
+        }
+    }
+}
+
+impl From<&MixnodeToReward> for RewardingJob {
+    fn from(node: &MixnodeToReward) -> Self {
+        RewardingJob {
+            node_identity: node.identity.clone(),
+            is_mixnode: true,
+            total_delegations: node.total_delegations as i64,
+            reward_params: serde_json::to_string(&node.params)
+                .expect("failed to serialize node reward params"),
+            status: RewardingJobStatus::Pending.into(),
+        }
+    }
+}
+
+impl TryFrom<&RewardingJob> for MixnodeToReward {
+    type Error = RewardingError;
+
+    fn try_from(job: &RewardingJob) -> Result<Self, Self::Error> {
+        let params = serde_json::from_str(&job.reward_params).map_err(|source| {
+            RewardingError::MalformedRewardingJob {
+                identity: job.node_identity.clone(),
+                source,
+            }
+        })?;
+
+        Ok(MixnodeToReward {
+            identity: job.node_identity.clone(),
+            total_delegations: job.total_delegations as usize,
+            params,
+        })
+    }
+}
+
+/// Bonded gateway competing for a place in the gateway rewarded set.
+pub(crate) struct GatewayRewardCandidate<'a> {
+    pub(crate) bond: &'a GatewayBond,
+    pub(crate) uptime: u8,
+    pub(crate) delegations: Vec<Delegation>,
+}
+
+impl GatewayRewardCandidate<'_> {
+    fn total_stake(&self) -> u128 {
+        self.bond.pledge_amount.amount.u128()
+            + self
+                .delegations
+                .iter()
+                .map(|delegation| delegation.amount.amount.u128())
+                .sum::<u128>()
+    }
+}
+
+/// Chooses at most `rewarded_set_size` gateways that are going to be rewarded in the interval.
+/// Gateways with higher uptime are preferred, with ties broken by their total stake and then
+/// by their identity, so that the selection is fully deterministic. Gateways without any uptime
+/// would not receive anything, so they are never selected.
+///
+/// # Arguments
+///
+/// * `candidates`: all currently bonded gateways.
+/// * `rewarded_set_size`: size of the gateway rewarded set, as set in the contract.
+pub(crate) fn select_rewarded_gateways(
+    mut candidates: Vec<GatewayRewardCandidate<'_>>,
+    rewarded_set_size: u32,
+) -> Vec<GatewayRewardCandidate<'_>> {
+    candidates.retain(|candidate| candidate.uptime > 0);
+    candidates.sort_by_cached_key(|candidate| {
+        (
+            Reverse(candidate.uptime),
+            Reverse(candidate.total_stake()),
+            candidate.bond.identity().clone(),
+        )
+    });
+    candidates.truncate(rewarded_set_size as usize);
+    candidates
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct GatewayToReward {
+    pub(crate) identity: IdentityKey,
+
+    /// Total number of individual addresses that have delegated to this particular gateway
+    pub(crate) total_delegations: usize,
+
+    pub(crate) params: GatewayRewardParams,
+}
+
+impl GatewayToReward {
+    pub(crate) fn to_reward_execute_msg(&self, interval_id: u32) -> ExecuteMsg {
+        ExecuteMsg::RewardGateway {
+            identity: self.identity.clone(),
+            params: self.params,
+            interval_id,
+        }
+    }
+
+    pub(crate) fn to_next_delegator_reward_execute_msg(&self, interval_id: u32) -> ExecuteMsg {
+        ExecuteMsg::RewardNextGatewayDelegators {
+            gateway_identity: self.identity.clone(),
+            interval_id,
+        }
+    }
+}
+
+impl From<&GatewayToReward> for RewardingJob {
+    fn from(gateway: &GatewayToReward) -> Self {
+        RewardingJob {
+            node_identity: gateway.identity.clone(),
+            is_mixnode: false,
+            total_delegations: gateway.total_delegations as i64,
+            reward_params: serde_json::to_string(&gateway.params)
+                .expect("failed to serialize gateway reward params"),
+            status: RewardingJobStatus::Pending.into(),
+        }
+    }
+}
+
+impl TryFrom<&RewardingJob> for GatewayToReward {
+    type Error = RewardingError;
+
+    fn try_from(job: &RewardingJob) -> Result<Self, Self::Error> {
+        let params = serde_json::from_str(&job.reward_params).map_err(|source| {
+            RewardingError::MalformedRewardingJob {
+                identity: job.node_identity.clone(),
+                source,
+            }
+        })?;
+
+        Ok(GatewayToReward {
+            identity: job.node_identity.clone(),
+            total_delegations: job.total_delegations as usize,
+            params,
+        })
+    }
+}
+
+/// Nodes that are to be rewarded in particular interval alongside the work that still has to be
+/// done for them, which is relevant if the rewarding got resumed after a crash.
+pub(crate) struct NodesToReward<T> {
+    /// All nodes that were eligible to receive rewards in the interval.
+    eligible: Vec<T>,
+
+    /// Nodes for which no rewarding transaction has been sent yet.
+    to_distribute: Vec<T>,
+
+    /// Nodes which rewarding is yet to be confirmed.
+    to_verify: Vec<T>,
+}
+
+impl<T: Clone> NodesToReward<T> {
+    fn new(eligible: Vec<T>) -> Self {
+        NodesToReward {
+            to_distribute: eligible.clone(),
+            to_verify: eligible.clone(),
+            eligible,
+        }
+    }
 }
 
 pub(crate) struct FailedMixnodeRewardChunkDetails {
@@ -145,6 +337,13 @@ pub(crate) struct Rewarder {
     /// Minimum percentage of network monitor test runs reports required in order to distribute
     /// rewards.
     minimum_interval_monitor_threshold: u8,
+
+    /// Maximum amount of gas a single batch of rewarding transactions is allowed to use
+    /// before it gets split into smaller ones.
+    maximum_batch_gas: u64,
+
+    /// Identities of the network monitors whose results are used for determining node uptimes.
+    trusted_monitors: Vec<String>,
 }
 
 impl Rewarder {
@@ -154,6 +353,8 @@ impl Rewarder {
         storage: ValidatorApiStorage,
         expected_interval_monitor_runs: usize,
         minimum_interval_monitor_threshold: u8,
+        maximum_batch_gas: u64,
+        trusted_monitors: Vec<String>,
     ) -> Self {
         Rewarder {
             nymd_client,
@@ -161,9 +362,17 @@ impl Rewarder {
             storage,
             expected_interval_monitor_runs,
             minimum_interval_monitor_threshold,
+            maximum_batch_gas,
+            trusted_monitors,
         }
     }
 
+    /// Number of test runs a network monitor has to have performed during the interval for its
+    /// results to be used when determining node uptimes.
+    fn minimum_monitor_runs(&self) -> usize {
+        self.expected_interval_monitor_runs * self.minimum_interval_monitor_threshold as usize / 100
+    }
+
     /// Obtains the current number of delegators that have delegated their stake towards this particular mixnode.
     ///
     /// # Arguments
@@ -207,6 +416,10 @@ impl Rewarder {
             "---- Interval reward pool: {} {}",
             interval_reward_params.period_reward_pool, DENOM
         );
+        info!(
+            "------ Reserved for gateways: {} {}",
+            interval_reward_params.gateway_reward_pool, DENOM
+        );
         info!(
             "-- Circulating supply: {} {}",
             interval_reward_params.circulating_supply, DENOM
@@ -239,32 +452,97 @@ impl Rewarder {
         for (rewarded_node, total_delegations) in nodes_with_delegations.into_iter() {
             let uptime = self
                 .storage
-                .get_average_mixnode_uptime_in_interval(
+                .get_aggregated_uptime_in_interval(
                     rewarded_node.identity(),
+                    true,
                     interval.start_unix_timestamp(),
                     interval.end_unix_timestamp(),
+                    self.minimum_monitor_runs(),
+                    &self.trusted_monitors,
                 )
                 .await?;
 
             eligible_nodes.push(MixnodeToReward {
                 identity: rewarded_node.identity().clone(),
                 total_delegations,
-                params: NodeRewardParams::new(
+                params: interval_reward_params
+                    .node_reward_params(uptime.u8(), active_set.contains(rewarded_node.identity())),
+            })
+        }
+
+        Ok(eligible_nodes)
+    }
+
+    /// Obtain the list of all currently bonded gateways, determine their uptime in the provided
+    /// interval alongside their delegator count and choose the gateway rewarded set out of them.
+    ///
+    /// Returns an empty list if gateway rewarding is disabled in the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval`: current rewarding interval
+    async fn determine_eligible_gateways(
+        &self,
+        interval: Interval,
+    ) -> Result<Vec<GatewayToReward>, RewardingError> {
+        let contract_settings = self.nymd_client.get_contract_settings().await?;
+        if !contract_settings.gateway_rewarding.is_enabled() {
+            return Ok(Vec::new());
+        }
+
+        let interval_reward_params = self
+            .nymd_client
+            .get_current_interval_reward_params()
+            .await?;
+
+        let all_gateways = self.validator_cache.gateways().await.into_inner();
+        let mut candidates = Vec::with_capacity(all_gateways.len());
+        for gateway in &all_gateways {
+            let delegations = self
+                .nymd_client
+                .get_gateway_delegations(gateway.identity().clone())
+                .await?;
+            let uptime = self
+                .storage
+                .get_aggregated_uptime_in_interval(
+                    gateway.identity(),
+                    false,
+                    interval.start_unix_timestamp(),
+                    interval.end_unix_timestamp(),
+                    self.minimum_monitor_runs(),
+                    &self.trusted_monitors,
+                )
+                .await?;
+
+            candidates.push(GatewayRewardCandidate {
+                bond: gateway,
+                uptime: uptime.u8(),
+                delegations,
+            })
+        }
+
+        let rewarded_set_size = contract_settings.gateway_rewarding.rewarded_set_size;
+        let eligible_gateways = select_rewarded_gateways(candidates, rewarded_set_size)
+            .into_iter()
+            .map(|candidate| GatewayToReward {
+                identity: candidate.bond.identity().clone(),
+                total_delegations: candidate.delegations.len(),
+                params: GatewayRewardParams::new(
                     interval_reward_params.period_reward_pool,
-                    interval_reward_params.rewarded_set_size.into(),
-                    interval_reward_params.active_set_size.into(),
+                    candidate.uptime.into(),
                     // Reward blockstamp gets set in the contract call
                     0,
-                    interval_reward_params.circulating_supply,
-                    uptime.u8().into(),
-                    interval_reward_params.sybil_resistance_percent,
-                    active_set.contains(rewarded_node.identity()),
-                    interval_reward_params.active_set_work_factor,
                 ),
             })
-        }
+            .collect::<Vec<_>>();
 
-        Ok(eligible_nodes)
+        info!(
+            "{} out of {} bonded gateways are going to be rewarded",
+            eligible_gateways.len(),
+            all_gateways.len()
+        );
+
+        Ok(eligible_gateways)
     }
 
     /// Check whether every node, and their delegators, on the provided list were fully rewarded
@@ -274,15 +552,23 @@ impl Rewarder {
     /// somebody created a new delegation thus causing the "last" delegator to possibly be pushed
     /// onto the next page that the validator API was not aware of.
     ///
+    /// Returns lists of nodes that were not rewarded at all, that still have delegators pending
+    /// rewards and that were fully rewarded, respectively.
+    ///
     /// * `eligible_mixnodes`: list of the nodes that were eligible to receive rewards.
     /// * `interval_id`: nonce associated with the current rewarding interval
     async fn verify_rewarding_completion(
         &self,
         eligible_mixnodes: &[MixnodeToReward],
         current_rewarding_nonce: u32,
-    ) -> (Vec<MixnodeToReward>, Vec<MixnodeToReward>) {
+    ) -> (
+        Vec<MixnodeToReward>,
+        Vec<MixnodeToReward>,
+        Vec<MixnodeToReward>,
+    ) {
         let mut unrewarded = Vec::new();
         let mut further_delegators_present = Vec::new();
+        let mut rewarded = Vec::new();
         for mix in eligible_mixnodes {
             match self
                 .nymd_client
@@ -297,7 +583,7 @@ impl Rewarder {
                     Some(RewardingStatus::PendingNextDelegatorPage(_)) => {
                         further_delegators_present.push(mix.clone())
                     }
-                    Some(RewardingStatus::Complete(_)) => {}
+                    Some(RewardingStatus::Complete(_)) => rewarded.push(mix.clone()),
                 },
                 Err(err) => {
                     error!(
@@ -307,7 +593,100 @@ impl Rewarder {
                 }
             }
         }
-        (unrewarded, further_delegators_present)
+        (unrewarded, further_delegators_present, rewarded)
+    }
+
+    /// Updates the stored status of rewarding jobs of the specified nodes.
+    ///
+    /// Failures are only logged as the smart contract prevents double rewarding anyway
+    /// and the stored jobs are only used for resuming the rewarding and reporting its progress.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval_rewarding_id`: id of the current interval rewarding as stored in the database.
+    /// * `identities`: identities of the nodes which jobs should get updated.
+    /// * `is_mixnode`: indicates whether the nodes are mixnodes or gateways.
+    /// * `status`: new status of the jobs.
+    /// * `estimated_gas`: gas estimated for the batch the nodes were rewarded in, if applicable.
+    /// * `error_message`: error that occurred while rewarding the nodes, if applicable.
+    async fn update_rewarding_jobs_status(
+        &self,
+        interval_rewarding_id: i64,
+        identities: Vec<IdentityKey>,
+        is_mixnode: bool,
+        status: RewardingJobStatus,
+        estimated_gas: Option<u64>,
+        error_message: Option<String>,
+    ) {
+        if identities.is_empty() {
+            return;
+        }
+
+        if let Err(err) = self
+            .storage
+            .update_rewarding_jobs_status(
+                interval_rewarding_id,
+                &identities,
+                is_mixnode,
+                status,
+                estimated_gas,
+                error_message,
+            )
+            .await
+        {
+            error!(
+                "failed to update status of {} rewarding jobs - {}",
+                identities.len(),
+                err
+            )
+        }
+    }
+
+    /// Simulates rewarding of the provided batch of mixnodes to determine whether it fits within
+    /// the gas limit.
+    ///
+    /// Returns the estimated gas alongside the batch, which is None if the simulation has failed,
+    /// or the two halves of the batch if it has to be split.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch`: mixnodes that are meant to be rewarded in a single transaction.
+    /// * `interval_id`: nonce associated with the current rewarding interval.
+    async fn estimate_mixnodes_batch_gas(
+        &self,
+        mut batch: Vec<MixnodeToReward>,
+        interval_id: u32,
+    ) -> Result<(Vec<MixnodeToReward>, Option<u64>), (Vec<MixnodeToReward>, Vec<MixnodeToReward>)>
+    {
+        let estimated_gas = match self
+            .nymd_client
+            .estimate_mixnodes_rewarding_gas(&batch, interval_id)
+            .await
+        {
+            Ok(gas) => gas,
+            Err(err) => {
+                // let the fee get determined during the actual execution instead
+                warn!(
+                    "failed to estimate gas for rewarding {} mixnodes - {}",
+                    batch.len(),
+                    err
+                );
+                return Ok((batch, None));
+            }
+        };
+
+        if estimated_gas > self.maximum_batch_gas && batch.len() > 1 {
+            info!(
+                "rewarding {} mixnodes is estimated to use {} gas, which is above the limit of {}. Splitting the batch...",
+                batch.len(),
+                estimated_gas,
+                self.maximum_batch_gas
+            );
+            let second_half = batch.split_off(batch.len() / 2);
+            Err((batch, second_half))
+        } else {
+            Ok((batch, Some(estimated_gas)))
+        }
     }
 
     // Utility function to print to the stdout rewarding progress
@@ -338,15 +717,20 @@ impl Rewarder {
     /// Only returns errors for problems originating from before smart contract was called, i.e.
     /// we know for sure not a single node has been rewarded.
     ///
+    /// Batches are simulated before being sent and are split in half whenever they would use more
+    /// than the maximum allowed gas or run out of gas during execution.
+    ///
     /// # Arguments
     ///
     /// * `eligible_mixnodes`: list of the nodes that are eligible to receive rewards.
     /// * `interval_id`: nonce associated with the current rewarding interval.
+    /// * `interval_rewarding_id`: id of the current interval rewarding as stored in the database.
     /// * `retry`: flag to indicate whether this is a retry attempt for rewarding particular nodes.
     async fn distribute_rewards_to_mixnodes(
         &self,
         eligible_mixnodes: &[MixnodeToReward],
         interval_id: u32,
+        interval_rewarding_id: i64,
         retry: bool,
     ) -> Option<Vec<FailedMixnodeRewardChunkDetails>> {
         if retry {
@@ -401,6 +785,16 @@ impl Rewarder {
         // start rewarding, first the nodes that are dealt with individually, i.e. nodes that
         // need to have their own special blocks due to number of delegators
         for mix in individually_rewarded {
+            self.update_rewarding_jobs_status(
+                interval_rewarding_id,
+                vec![mix.identity.clone()],
+                true,
+                RewardingJobStatus::Submitted,
+                None,
+                None,
+            )
+            .await;
+
             if let Err(err) = self
                 .nymd_client
                 .reward_mixnode_and_all_delegators(mix, interval_id)
@@ -411,6 +805,15 @@ impl Rewarder {
                 // however, still we want to wait until we're sure we're into the next block
                 if !err.is_tendermint_duplicate() {
                     error!("failed to reward mixnode with all delegators... - {}", err);
+                    self.update_rewarding_jobs_status(
+                        interval_rewarding_id,
+                        vec![mix.identity.clone()],
+                        true,
+                        RewardingJobStatus::Failed,
+                        None,
+                        Some(err.to_string()),
+                    )
+                    .await;
                     failed_chunks.push(FailedMixnodeRewardChunkDetails {
                         possibly_unrewarded: vec![mix.clone()],
                         error_message: err.to_string(),
@@ -423,21 +826,79 @@ impl Rewarder {
             self.print_rewarding_progress(total_rewarded, eligible_mixnodes.len(), retry);
         }
 
-        // then we move onto the chunks
-        for mix_chunk in batch_rewarded {
+        // then we move onto the chunks, which might get split further if they turn out
+        // to be too expensive to be included in a single transaction
+        let mut remaining_chunks = batch_rewarded;
+        remaining_chunks.reverse();
+        while let Some(mix_chunk) = remaining_chunks.pop() {
             if mix_chunk.is_empty() {
                 continue;
             }
+
+            let (mix_chunk, estimated_gas) = match self
+                .estimate_mixnodes_batch_gas(mix_chunk, interval_id)
+                .await
+            {
+                Ok(estimated) => estimated,
+                Err((first_half, second_half)) => {
+                    remaining_chunks.push(second_half);
+                    remaining_chunks.push(first_half);
+                    continue;
+                }
+            };
+
+            let identities = mix_chunk
+                .iter()
+                .map(|mix| mix.identity.clone())
+                .collect::<Vec<_>>();
+            self.update_rewarding_jobs_status(
+                interval_rewarding_id,
+                identities.clone(),
+                true,
+                RewardingJobStatus::Submitted,
+                estimated_gas,
+                None,
+            )
+            .await;
+
             if let Err(err) = self
                 .nymd_client
-                .reward_mixnodes_with_single_page_of_delegators(&mix_chunk, interval_id)
+                .reward_mixnodes_with_single_page_of_delegators(
+                    &mix_chunk,
+                    interval_id,
+                    estimated_gas,
+                )
                 .await
             {
+                if err.is_out_of_gas() && mix_chunk.len() > 1 {
+                    // our estimation was off, so try again with smaller batches
+                    // (failed transaction did not modify any state)
+                    warn!(
+                        "rewarding {} mixnodes has run out of gas. Splitting the batch... - {}",
+                        mix_chunk.len(),
+                        err
+                    );
+                    let mut first_half = mix_chunk;
+                    let second_half = first_half.split_off(first_half.len() / 2);
+                    remaining_chunks.push(second_half);
+                    remaining_chunks.push(first_half);
+                    continue;
+                }
+
                 // this is a super weird edge case that we didn't catch change to sequence and
                 // resent rewards unnecessarily, but the mempool saved us from executing it again
                 // however, still we want to wait until we're sure we're into the next block
                 if !err.is_tendermint_duplicate() {
                     error!("failed to reward mixnodes... - {}", err);
+                    self.update_rewarding_jobs_status(
+                        interval_rewarding_id,
+                        identities,
+                        true,
+                        RewardingJobStatus::Failed,
+                        estimated_gas,
+                        Some(err.to_string()),
+                    )
+                    .await;
                     failed_chunks.push(FailedMixnodeRewardChunkDetails {
                         possibly_unrewarded: mix_chunk.to_vec(),
                         error_message: err.to_string(),
@@ -457,6 +918,294 @@ impl Rewarder {
         }
     }
 
+    /// Using the list of gateways eligible for rewards, gives out the rewards by calling the smart
+    /// contract. Similarly to mixnodes, gateways with more than a single page of delegators are
+    /// rewarded individually while the rest are batched together.
+    ///
+    /// Returns list of gateways that might have not been rewarded due to a smart contract
+    /// execution error.
+    ///
+    /// # Arguments
+    ///
+    /// * `eligible_gateways`: list of the gateways that are eligible to receive rewards.
+    /// * `interval_id`: nonce associated with the current rewarding interval.
+    /// * `interval_rewarding_id`: id of the current interval rewarding as stored in the database.
+    async fn distribute_rewards_to_gateways(
+        &self,
+        eligible_gateways: &[GatewayToReward],
+        interval_id: u32,
+        interval_rewarding_id: i64,
+    ) -> Vec<GatewayToReward> {
+        info!(
+            "Attempting to reward {} gateways...",
+            eligible_gateways.len()
+        );
+
+        let mut possibly_unrewarded = Vec::new();
+
+        let mut individually_rewarded = Vec::new();
+        let mut batch_rewarded = vec![vec![]];
+        let mut current_batch_total = 0;
+
+        for gateway in eligible_gateways {
+            if gateway.total_delegations > GATEWAY_DELEGATORS_PAGE_LIMIT {
+                individually_rewarded.push(gateway)
+            } else if current_batch_total + gateway.total_delegations
+                < GATEWAY_DELEGATORS_PAGE_LIMIT
+            {
+                batch_rewarded.last_mut().unwrap().push(gateway.clone());
+                current_batch_total += gateway.total_delegations;
+            } else {
+                batch_rewarded.push(vec![gateway.clone()]);
+                current_batch_total = gateway.total_delegations;
+            }
+        }
+
+        for gateway in individually_rewarded {
+            self.update_rewarding_jobs_status(
+                interval_rewarding_id,
+                vec![gateway.identity.clone()],
+                false,
+                RewardingJobStatus::Submitted,
+                None,
+                None,
+            )
+            .await;
+
+            if let Err(err) = self
+                .nymd_client
+                .reward_gateway_and_all_delegators(gateway, interval_id)
+                .await
+            {
+                if !err.is_tendermint_duplicate() {
+                    error!("failed to reward gateway with all delegators... - {}", err);
+                    self.update_rewarding_jobs_status(
+                        interval_rewarding_id,
+                        vec![gateway.identity.clone()],
+                        false,
+                        RewardingJobStatus::Failed,
+                        None,
+                        Some(err.to_string()),
+                    )
+                    .await;
+                    possibly_unrewarded.push(gateway.clone());
+                }
+                sleep(Duration::from_secs(11)).await;
+            }
+        }
+
+        let mut remaining_chunks = batch_rewarded;
+        remaining_chunks.reverse();
+        while let Some(mut gateway_chunk) = remaining_chunks.pop() {
+            if gateway_chunk.is_empty() {
+                continue;
+            }
+
+            let estimated_gas = match self
+                .nymd_client
+                .estimate_gateways_rewarding_gas(&gateway_chunk, interval_id)
+                .await
+            {
+                Ok(gas) => Some(gas),
+                Err(err) => {
+                    warn!(
+                        "failed to estimate gas for rewarding {} gateways - {}",
+                        gateway_chunk.len(),
+                        err
+                    );
+                    None
+                }
+            };
+
+            if let Some(gas) = estimated_gas {
+                if gas > self.maximum_batch_gas && gateway_chunk.len() > 1 {
+                    info!(
+                        "rewarding {} gateways is estimated to use {} gas, which is above the limit of {}. Splitting the batch...",
+                        gateway_chunk.len(),
+                        gas,
+                        self.maximum_batch_gas
+                    );
+                    let second_half = gateway_chunk.split_off(gateway_chunk.len() / 2);
+                    remaining_chunks.push(second_half);
+                    remaining_chunks.push(gateway_chunk);
+                    continue;
+                }
+            }
+
+            let identities = gateway_chunk
+                .iter()
+                .map(|gateway| gateway.identity.clone())
+                .collect::<Vec<_>>();
+            self.update_rewarding_jobs_status(
+                interval_rewarding_id,
+                identities.clone(),
+                false,
+                RewardingJobStatus::Submitted,
+                estimated_gas,
+                None,
+            )
+            .await;
+
+            if let Err(err) = self
+                .nymd_client
+                .reward_gateways_with_single_page_of_delegators(
+                    &gateway_chunk,
+                    interval_id,
+                    estimated_gas,
+                )
+                .await
+            {
+                if err.is_out_of_gas() && gateway_chunk.len() > 1 {
+                    warn!(
+                        "rewarding {} gateways has run out of gas. Splitting the batch... - {}",
+                        gateway_chunk.len(),
+                        err
+                    );
+                    let second_half = gateway_chunk.split_off(gateway_chunk.len() / 2);
+                    remaining_chunks.push(second_half);
+                    remaining_chunks.push(gateway_chunk);
+                    continue;
+                }
+
+                if !err.is_tendermint_duplicate() {
+                    error!("failed to reward gateways... - {}", err);
+                    self.update_rewarding_jobs_status(
+                        interval_rewarding_id,
+                        identities,
+                        false,
+                        RewardingJobStatus::Failed,
+                        estimated_gas,
+                        Some(err.to_string()),
+                    )
+                    .await;
+                    possibly_unrewarded.extend(gateway_chunk);
+                }
+                sleep(Duration::from_secs(11)).await;
+            }
+        }
+
+        possibly_unrewarded
+    }
+
+    /// Check whether every gateway, and their delegators, on the provided list were fully rewarded
+    /// in the specified interval.
+    ///
+    /// Returns lists of gateways that were not rewarded at all, that still have delegators pending
+    /// rewards and that were fully rewarded, respectively.
+    ///
+    /// * `eligible_gateways`: list of the gateways that were eligible to receive rewards.
+    /// * `interval_id`: nonce associated with the current rewarding interval
+    async fn verify_gateway_rewarding_completion(
+        &self,
+        eligible_gateways: &[GatewayToReward],
+        interval_id: u32,
+    ) -> (
+        Vec<GatewayToReward>,
+        Vec<GatewayToReward>,
+        Vec<GatewayToReward>,
+    ) {
+        let mut unrewarded = Vec::new();
+        let mut further_delegators_present = Vec::new();
+        let mut rewarded = Vec::new();
+        for gateway in eligible_gateways {
+            match self
+                .nymd_client
+                .get_gateway_rewarding_status(gateway.identity.clone(), interval_id)
+                .await
+            {
+                Ok(rewarding_status) => match rewarding_status.status {
+                    None => unrewarded.push(gateway.clone()),
+                    Some(GatewayRewardingStatus::PendingNextDelegatorPage(_)) => {
+                        further_delegators_present.push(gateway.clone())
+                    }
+                    Some(GatewayRewardingStatus::Complete(_)) => rewarded.push(gateway.clone()),
+                },
+                Err(err) => {
+                    error!(
+                        "failed to query rewarding status of gateway {} - {}",
+                        gateway.identity, err
+                    )
+                }
+            }
+        }
+        (unrewarded, further_delegators_present, rewarded)
+    }
+
+    /// Distributes rewards to all the eligible gateways and makes sure they, and their delegators,
+    /// were actually rewarded.
+    ///
+    /// Returns the number of gateways that might have not been rewarded.
+    ///
+    /// * `gateways`: the gateways that are eligible to receive rewards alongside their progress.
+    /// * `interval_id`: nonce associated with the current rewarding interval.
+    /// * `interval_rewarding_id`: id of the current interval rewarding as stored in the database.
+    async fn reward_gateways(
+        &self,
+        gateways: &NodesToReward<GatewayToReward>,
+        interval_id: u32,
+        interval_rewarding_id: i64,
+    ) -> i64 {
+        let possibly_unrewarded = self
+            .distribute_rewards_to_gateways(
+                &gateways.to_distribute,
+                interval_id,
+                interval_rewarding_id,
+            )
+            .await;
+
+        let mut gateways_to_verify = gateways.to_verify.clone();
+        let mut retries_allowed = 5;
+        while retries_allowed > 0 {
+            let (unrewarded, mut pending_delegators, rewarded) = self
+                .verify_gateway_rewarding_completion(&gateways_to_verify, interval_id)
+                .await;
+            self.update_rewarding_jobs_status(
+                interval_rewarding_id,
+                rewarded
+                    .into_iter()
+                    .map(|gateway| gateway.identity)
+                    .collect(),
+                false,
+                RewardingJobStatus::Rewarded,
+                None,
+                None,
+            )
+            .await;
+            if unrewarded.is_empty() && pending_delegators.is_empty() {
+                break;
+            }
+
+            if !unrewarded.is_empty() {
+                self.distribute_rewards_to_gateways(
+                    &unrewarded,
+                    interval_id,
+                    interval_rewarding_id,
+                )
+                .await;
+            }
+
+            for gateway in &pending_delegators {
+                if let Err(err) = self
+                    .nymd_client
+                    .reward_gateway_delegators(gateway, interval_id)
+                    .await
+                {
+                    warn!(
+                        "failed to attempt to reward missed delegators of gateway {} - {}",
+                        gateway.identity, err
+                    )
+                }
+            }
+
+            gateways_to_verify = unrewarded;
+            gateways_to_verify.append(&mut pending_delegators);
+
+            retries_allowed -= 1;
+        }
+
+        possibly_unrewarded.len() as i64
+    }
+
     /// For each mixnode on the list, try to "continue" rewarding its delegators.
     /// Note: due to the checks inside the smart contract, it's impossible to accidentally
     /// reward the same mixnode (or delegator) twice during particular rewarding interval.
@@ -489,6 +1238,241 @@ impl Rewarder {
         }
     }
 
+    /// Reports the uptime of all the nodes to the smart contract so that the ones that have been
+    /// consistently performing poorly in the active set could get slashed.
+    ///
+    /// Failures are only logged as they do not affect the rewarding itself.
+    ///
+    /// * `nodes`: mixnodes that were eligible to receive rewards in this interval.
+    /// * `interval_id`: nonce associated with the current rewarding interval.
+    async fn report_mixnodes_performance(&self, nodes: &[MixnodeToReward], interval_id: u32) {
+        info!("Reporting performance of {} mixnodes...", nodes.len());
+
+        for chunk in nodes.chunks(PERFORMANCE_REPORT_CHUNK_SIZE) {
+            if let Err(err) = self
+                .nymd_client
+                .report_mixnodes_performance(chunk, interval_id)
+                .await
+            {
+                if !err.is_tendermint_duplicate() {
+                    error!("failed to report performance of mixnodes... - {}", err);
+                }
+                sleep(Duration::from_secs(11)).await;
+            }
+        }
+    }
+
+    /// Slashes the remaining delegations of the nodes that got slashed when their performance
+    /// was reported, one page per node at a time, until there's nothing left to slash.
+    ///
+    /// Failures are only logged as the contract keeps track of the remaining stake and the slashing
+    /// is going to be continued at the end of the next interval.
+    ///
+    /// * `interval_id`: nonce associated with the current rewarding interval.
+    async fn slash_remaining_delegations(&self, interval_id: u32) {
+        loop {
+            let pending = match self.nymd_client.get_pending_delegation_slashings().await {
+                Ok(pending) => pending,
+                Err(err) => {
+                    error!(
+                        "failed to obtain the pending delegation slashings - {}",
+                        err
+                    );
+                    return;
+                }
+            };
+            if pending.is_empty() {
+                return;
+            }
+
+            info!("Slashing delegations of {} mixnodes...", pending.len());
+            for mix_identity in pending {
+                if let Err(err) = self
+                    .nymd_client
+                    .slash_next_mix_delegators(mix_identity.clone(), interval_id)
+                    .await
+                {
+                    error!(
+                        "failed to slash delegations of mixnode {} - {}",
+                        mix_identity, err
+                    );
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Determines which of the nodes stored as rewarding jobs of an interrupted interval rewarding
+    /// still have to be rewarded.
+    ///
+    /// Nodes which rewarding transaction has already been sent are checked against the contract
+    /// so that they would not be sent again unnecessarily.
+    ///
+    /// # Arguments
+    ///
+    /// * `jobs`: stored rewarding jobs of the interval rewarding.
+    /// * `interval_id`: nonce associated with the current rewarding interval.
+    /// * `interval_rewarding_id`: id of the current interval rewarding as stored in the database.
+    async fn resume_mixnodes_rewarding(
+        &self,
+        jobs: &[RewardingJob],
+        interval_id: u32,
+        interval_rewarding_id: i64,
+    ) -> Result<NodesToReward<MixnodeToReward>, RewardingError> {
+        let mut nodes = NodesToReward::new(Vec::new());
+        let mut already_submitted = Vec::new();
+
+        for job in jobs.iter().filter(|job| job.is_mixnode) {
+            let mix = MixnodeToReward::try_from(job)?;
+            match RewardingJobStatus::from_u8(job.status) {
+                Some(RewardingJobStatus::Pending) => {
+                    nodes.to_distribute.push(mix.clone());
+                    nodes.to_verify.push(mix.clone());
+                }
+                Some(RewardingJobStatus::Rewarded) => {}
+                _ => already_submitted.push(mix.clone()),
+            }
+            nodes.eligible.push(mix);
+        }
+
+        let (mut unrewarded, mut pending_delegators, rewarded) = self
+            .verify_rewarding_completion(&already_submitted, interval_id)
+            .await;
+        self.update_rewarding_jobs_status(
+            interval_rewarding_id,
+            rewarded.into_iter().map(|mix| mix.identity).collect(),
+            true,
+            RewardingJobStatus::Rewarded,
+            None,
+            None,
+        )
+        .await;
+
+        nodes.to_distribute.extend(unrewarded.iter().cloned());
+        nodes.to_verify.append(&mut unrewarded);
+        nodes.to_verify.append(&mut pending_delegators);
+
+        Ok(nodes)
+    }
+
+    /// Determines which of the gateways stored as rewarding jobs of an interrupted interval rewarding
+    /// still have to be rewarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `jobs`: stored rewarding jobs of the interval rewarding.
+    /// * `interval_id`: nonce associated with the current rewarding interval.
+    /// * `interval_rewarding_id`: id of the current interval rewarding as stored in the database.
+    async fn resume_gateways_rewarding(
+        &self,
+        jobs: &[RewardingJob],
+        interval_id: u32,
+        interval_rewarding_id: i64,
+    ) -> Result<NodesToReward<GatewayToReward>, RewardingError> {
+        let mut gateways = NodesToReward::new(Vec::new());
+        let mut already_submitted = Vec::new();
+
+        for job in jobs.iter().filter(|job| !job.is_mixnode) {
+            let gateway = GatewayToReward::try_from(job)?;
+            match RewardingJobStatus::from_u8(job.status) {
+                Some(RewardingJobStatus::Pending) => {
+                    gateways.to_distribute.push(gateway.clone());
+                    gateways.to_verify.push(gateway.clone());
+                }
+                Some(RewardingJobStatus::Rewarded) => {}
+                _ => already_submitted.push(gateway.clone()),
+            }
+            gateways.eligible.push(gateway);
+        }
+
+        let (mut unrewarded, mut pending_delegators, rewarded) = self
+            .verify_gateway_rewarding_completion(&already_submitted, interval_id)
+            .await;
+        self.update_rewarding_jobs_status(
+            interval_rewarding_id,
+            rewarded
+                .into_iter()
+                .map(|gateway| gateway.identity)
+                .collect(),
+            false,
+            RewardingJobStatus::Rewarded,
+            None,
+            None,
+        )
+        .await;
+
+        gateways.to_distribute.extend(unrewarded.iter().cloned());
+        gateways.to_verify.append(&mut unrewarded);
+        gateways.to_verify.append(&mut pending_delegators);
+
+        Ok(gateways)
+    }
+
+    /// Determines the nodes that are going to get rewarded in this interval and persists them
+    /// as rewarding jobs, so that the procedure could get resumed if interrupted.
+    ///
+    /// If jobs already exist for the interval rewarding, they are used instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval_rewarding_id`: id of the current interval rewarding as stored in the database.
+    /// * `interval`: current rewarding interval
+    async fn prepare_rewarding_jobs(
+        &self,
+        interval_rewarding_id: i64,
+        interval: Interval,
+    ) -> Result<
+        (
+            NodesToReward<MixnodeToReward>,
+            NodesToReward<GatewayToReward>,
+        ),
+        RewardingError,
+    > {
+        let existing_jobs = self
+            .storage
+            .get_rewarding_jobs(interval_rewarding_id)
+            .await?;
+        if !existing_jobs.is_empty() {
+            info!(
+                "Resuming rewarding for interval {} using {} stored rewarding jobs",
+                interval,
+                existing_jobs.len()
+            );
+            let mixnodes = self
+                .resume_mixnodes_rewarding(&existing_jobs, interval.id(), interval_rewarding_id)
+                .await?;
+            let gateways = self
+                .resume_gateways_rewarding(&existing_jobs, interval.id(), interval_rewarding_id)
+                .await?;
+            return Ok((mixnodes, gateways));
+        }
+
+        let eligible_mixnodes = self.determine_eligible_mixnodes(interval).await?;
+        if eligible_mixnodes.is_empty() {
+            return Err(RewardingError::NoMixnodesToReward);
+        }
+
+        // determine gateway rewards before any mixnode got rewarded so that both would be based
+        // on the same state of the reward pool
+        let eligible_gateways = self.determine_eligible_gateways(interval).await?;
+
+        // the reward params have to be persisted as the reward pool is going to shrink as the nodes
+        // get rewarded, so we wouldn't be able to recompute them after resuming
+        let jobs = eligible_mixnodes
+            .iter()
+            .map(RewardingJob::from)
+            .chain(eligible_gateways.iter().map(RewardingJob::from))
+            .collect();
+        self.storage
+            .insert_rewarding_jobs(interval_rewarding_id, jobs)
+            .await?;
+
+        Ok((
+            NodesToReward::new(eligible_mixnodes),
+            NodesToReward::new(eligible_gateways),
+        ))
+    }
+
     /// Using the list of active mixnode and gateways, determine which of them are eligible for
     /// rewarding and distribute the rewards.
     ///
@@ -503,17 +1487,21 @@ impl Rewarder {
     ) -> Result<(RewardingReport, Option<FailureData>), RewardingError> {
         let mut failure_data = FailureData::default();
 
-        let eligible_mixnodes = self.determine_eligible_mixnodes(interval).await?;
-        if eligible_mixnodes.is_empty() {
-            return Err(RewardingError::NoMixnodesToReward);
-        }
-        let total_eligible = eligible_mixnodes.len();
+        let (mixnodes, gateways) = self
+            .prepare_rewarding_jobs(interval_rewarding_database_id, interval)
+            .await?;
+        let total_eligible = mixnodes.eligible.len();
 
         failure_data.mixnodes = self
-            .distribute_rewards_to_mixnodes(&eligible_mixnodes, interval.id(), false)
+            .distribute_rewards_to_mixnodes(
+                &mixnodes.to_distribute,
+                interval.id(),
+                interval_rewarding_database_id,
+                false,
+            )
             .await;
 
-        let mut nodes_to_verify = eligible_mixnodes;
+        let mut nodes_to_verify = mixnodes.to_verify.clone();
 
         // if there's some underlying networking error or something, don't keep retrying forever
         let mut retries_allowed = 5;
@@ -521,9 +1509,18 @@ impl Rewarder {
             if retries_allowed <= 0 {
                 break;
             }
-            let (unrewarded, mut pending_delegators) = self
+            let (unrewarded, mut pending_delegators, rewarded) = self
                 .verify_rewarding_completion(&nodes_to_verify, interval.id())
                 .await;
+            self.update_rewarding_jobs_status(
+                interval_rewarding_database_id,
+                rewarded.into_iter().map(|mix| mix.identity).collect(),
+                true,
+                RewardingJobStatus::Rewarded,
+                None,
+                None,
+            )
+            .await;
             if unrewarded.is_empty() && pending_delegators.is_empty() {
                 // we're all good - everyone got their rewards
                 break;
@@ -531,8 +1528,13 @@ impl Rewarder {
 
             if !unrewarded.is_empty() {
                 // no need to save failure data as we already know about those from the very first run
-                self.distribute_rewards_to_mixnodes(&unrewarded, interval.id(), true)
-                    .await;
+                self.distribute_rewards_to_mixnodes(
+                    &unrewarded,
+                    interval.id(),
+                    interval_rewarding_database_id,
+                    true,
+                )
+                .await;
             }
 
             if !pending_delegators.is_empty() {
@@ -547,6 +1549,13 @@ impl Rewarder {
             retries_allowed -= 1;
         }
 
+        let possibly_unrewarded_gateways = if gateways.eligible.is_empty() {
+            0
+        } else {
+            self.reward_gateways(&gateways, interval.id(), interval_rewarding_database_id)
+                .await
+        };
+
         let report = RewardingReport {
             interval_rewarding_id: interval_rewarding_database_id,
             eligible_mixnodes: total_eligible as i64,
@@ -560,8 +1569,14 @@ impl Rewarder {
                         .sum::<usize>() as i64
                 })
                 .unwrap_or_default(),
+            eligible_gateways: gateways.eligible.len() as i64,
+            possibly_unrewarded_gateways,
         };
 
+        self.report_mixnodes_performance(&mixnodes.eligible, interval.id())
+            .await;
+        self.slash_remaining_delegations(interval.id()).await;
+
         self.nymd_client.advance_current_interval().await?;
 
         if failure_data.mixnodes.is_none() {
@@ -615,6 +1630,9 @@ impl Rewarder {
     /// Determines whether this validator has already distributed rewards for the specified interval
     /// so that it wouldn't accidentally attempt to do it again.
     ///
+    /// Unfinished rewarding is not treated as performed if its progress has been persisted,
+    /// since it can be safely resumed.
+    ///
     /// # Arguments
     ///
     /// * `interval`: interval to check
@@ -630,6 +1648,14 @@ impl Rewarder {
             // log error if the attempt wasn't finished. This error implies the process has crashed
             // during the rewards distribution
             if !entry.finished {
+                if !self.storage.get_rewarding_jobs(entry.id).await?.is_empty() {
+                    warn!(
+                        "We haven't finished distributing rewards at {}. The rewarding is going to be resumed",
+                        interval
+                    );
+                    return Ok(false);
+                }
+
                 error!(
                     "It seems that we haven't successfully finished distributing rewards at {}",
                     interval
@@ -673,14 +1699,23 @@ impl Rewarder {
         );
 
         // insert information about beginning the procedure (so that if we crash during it,
-        // we wouldn't attempt to possibly double reward operators)
-        let interval_rewarding_id = self
+        // we wouldn't attempt to possibly double reward operators), unless we're resuming
+        // an interrupted attempt
+        let interval_rewarding_id = match self
             .storage
-            .insert_started_interval_rewarding(
-                interval.start_unix_timestamp(),
-                interval.end_unix_timestamp(),
-            )
-            .await?;
+            .get_interval_rewarding_entry(interval.start_unix_timestamp())
+            .await?
+        {
+            Some(entry) if !entry.finished => entry.id,
+            _ => {
+                self.storage
+                    .insert_started_interval_rewarding(
+                        interval.start_unix_timestamp(),
+                        interval.end_unix_timestamp(),
+                    )
+                    .await?
+            }
+        };
 
         let (report, failure_data) = self
             .distribute_rewards(interval_rewarding_id, interval)
@@ -701,12 +1736,6 @@ impl Rewarder {
             }
         }
 
-        // since we have already performed rewards, purge everything older than the end of this interval
-        // (+one day of buffer) as we're never going to need it again (famous last words...)
-        // note that usually end of interval is equal to the current time
-        let cutoff = (interval.end() - ONE_DAY).unix_timestamp();
-        self.storage.purge_old_statuses(cutoff).await?;
-
         Ok(())
     }
 
@@ -830,3 +1859,72 @@ impl Rewarder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewarding_jobs_preserve_reward_params() {
+        let mix = MixnodeToReward {
+            identity: "mix".to_string(),
+            total_delegations: 42,
+            params: NodeRewardParams::new(1_000_000, 100, 50, 0, 250_000_000, 95, 30, true, 10),
+        };
+        let job = RewardingJob::from(&mix);
+        assert!(job.is_mixnode);
+        assert_eq!(job.status, u8::from(RewardingJobStatus::Pending));
+
+        let restored = MixnodeToReward::try_from(&job).unwrap();
+        assert_eq!(restored.identity, mix.identity);
+        assert_eq!(restored.total_delegations, mix.total_delegations);
+        assert_eq!(restored.params, mix.params);
+
+        let gateway = GatewayToReward {
+            identity: "gateway".to_string(),
+            total_delegations: 3,
+            params: GatewayRewardParams::new(1_000_000, 87, 0),
+        };
+        let job = RewardingJob::from(&gateway);
+        assert!(!job.is_mixnode);
+
+        let restored = GatewayToReward::try_from(&job).unwrap();
+        assert_eq!(restored.identity, gateway.identity);
+        assert_eq!(restored.params, gateway.params);
+    }
+
+    #[test]
+    fn gateway_reward_pool_is_not_distributed_to_mixnodes() {
+        let mut interval_reward_params = IntervalRewardParams::new_empty();
+        interval_reward_params.period_reward_pool = 1_000_000;
+        assert_eq!(
+            1_000_000,
+            interval_reward_params
+                .node_reward_params(100, true)
+                .period_reward_pool()
+        );
+
+        interval_reward_params.gateway_reward_pool = 100_000;
+        assert_eq!(
+            900_000,
+            interval_reward_params
+                .node_reward_params(100, true)
+                .period_reward_pool()
+        );
+    }
+
+    #[test]
+    fn malformed_rewarding_job_is_rejected() {
+        let job = RewardingJob {
+            node_identity: "mix".to_string(),
+            is_mixnode: true,
+            total_delegations: 0,
+            reward_params: "not json".to_string(),
+            status: RewardingJobStatus::Pending.into(),
+        };
+        assert!(matches!(
+            MixnodeToReward::try_from(&job),
+            Err(RewardingError::MalformedRewardingJob { .. })
+        ));
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::contract_cache::ValidatorCache;
+use crate::node_status_api::models::ErrorResponse;
+use crate::rewarding::simulator::{
+    format_simulation, RewardingSimulator, RewardingSnapshot, SimulationFormat,
+};
+use crate::storage::ValidatorApiStorage;
+use rocket::fairing::AdHoc;
+use rocket::http::{ContentType, Status};
+use rocket::serde::json::Json;
+use rocket::State;
+use validator_api_requests::models::RewardingStatusResponse;
+
+pub(crate) fn stage(simulator: RewardingSimulator) -> AdHoc {
+    AdHoc::on_ignite("Rewarding Simulation Stage", |rocket| async {
+        rocket.manage(simulator).mount(
+            "/v1/rewarding",
+            routes![
+                simulate_rewarding,
+                get_rewarding_snapshot,
+                get_rewarding_status
+            ],
+        )
+    })
+}
+
+// nothing is ever submitted to the chain here and the result is computed at most once per
+// interval and refresh of the validator cache, so repeated requests are cheap
+#[get("/simulate?<format>")]
+pub(crate) async fn simulate_rewarding(
+    simulator: &State<RewardingSimulator>,
+    cache: &State<ValidatorCache>,
+    storage: &State<ValidatorApiStorage>,
+    format: Option<&str>,
+) -> Result<(ContentType, String), ErrorResponse> {
+    let format = match format {
+        Some(format) => format
+            .parse::<SimulationFormat>()
+            .map_err(|err| ErrorResponse::new(err, Status::BadRequest))?,
+        None => SimulationFormat::Json,
+    };
+
+    let simulation = simulator
+        .simulate(cache, storage)
+        .await
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))?;
+
+    let content_type = match format {
+        SimulationFormat::Json => ContentType::JSON,
+        SimulationFormat::Csv => ContentType::CSV,
+    };
+    Ok((content_type, format_simulation(&simulation, format)))
+}
+
+// the snapshot can be saved and used for running the simulation offline
+#[get("/snapshot")]
+pub(crate) async fn get_rewarding_snapshot(
+    simulator: &State<RewardingSimulator>,
+    cache: &State<ValidatorCache>,
+) -> Result<Json<RewardingSnapshot>, ErrorResponse> {
+    simulator
+        .snapshot(cache)
+        .await
+        .map(Json)
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))
+}
+
+#[get("/status")]
+pub(crate) async fn get_rewarding_status(
+    storage: &State<ValidatorApiStorage>,
+) -> Result<Json<RewardingStatusResponse>, ErrorResponse> {
+    match storage.get_current_rewarding_status().await {
+        Ok(Some(status)) => Ok(Json(status)),
+        Ok(None) => Err(ErrorResponse::new(
+            "no rewarding has been performed yet",
+            Status::NotFound,
+        )),
+        Err(err) => Err(ErrorResponse::new(
+            err.to_string(),
+            Status::InternalServerError,
+        )),
+    }
+}
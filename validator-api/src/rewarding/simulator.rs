@@ -0,0 +1,632 @@
+// Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+// Dry-run of the interval rewarding. It goes through exactly the same reward computation as the
+// `Rewarder` would, but rather than submitting any transactions, it outputs the per-node and
+// per-delegator amounts so that any changes to the rewarding could be audited before going live.
+
+use crate::config::Config;
+use crate::contract_cache::ValidatorCache;
+use crate::nymd_client::Client;
+use crate::rewarding::error::RewardingError;
+use crate::rewarding::{select_rewarded_gateways, GatewayRewardCandidate, IntervalRewardParams};
+use crate::storage::ValidatorApiStorage;
+use mixnet_contract_common::{
+    Delegation, GatewayBond, GatewayDelegatorRewardParams, GatewayRewardParams,
+    GatewayRewardingParams, IdentityKey, Interval, MixNodeBond,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+use time::OffsetDateTime;
+use tokio::sync::Mutex;
+use validator_api_requests::models::{
+    RewardingSimulationResponse, SimulatedDelegatorReward, SimulatedNodeReward,
+};
+use validator_client::nymd::QueryNymdClient;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SimulationFormat {
+    Json,
+    Csv,
+}
+
+impl FromStr for SimulationFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(SimulationFormat::Json),
+            "csv" => Ok(SimulationFormat::Csv),
+            other => Err(format!(
+                "{} is not a supported simulation output format",
+                other
+            )),
+        }
+    }
+}
+
+impl Display for SimulationFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationFormat::Json => write!(f, "json"),
+            SimulationFormat::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// State of the mixnet contract required to compute rewards for an interval. It can be saved
+/// to a file and later used to run the simulation offline.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct RewardingSnapshot {
+    interval: Interval,
+    interval_reward_params: IntervalRewardParams,
+    gateway_rewarding: GatewayRewardingParams,
+    mixnodes: Vec<MixNodeBond>,
+    active_set: Vec<IdentityKey>,
+    gateways: Vec<GatewayBond>,
+    mixnode_delegations: HashMap<IdentityKey, Vec<Delegation>>,
+    gateway_delegations: HashMap<IdentityKey, Vec<Delegation>>,
+}
+
+impl RewardingSnapshot {
+    /// Obtains uptimes of all nodes in the snapshot interval and determines the rewards
+    /// that would have been distributed if the interval was rewarded now.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage`: storage containing the network monitor test results.
+    /// * `monitor_run_interval`: expected delay between subsequent network monitor test runs.
+    /// * `minimum_interval_monitor_threshold`: minimum percentage of test runs a network monitor
+    ///    has to have performed in the interval for its results to be used.
+    /// * `trusted_monitors`: identities of the network monitors whose results should be used.
+    pub(crate) async fn simulate(
+        &self,
+        storage: &ValidatorApiStorage,
+        monitor_run_interval: Duration,
+        minimum_interval_monitor_threshold: u8,
+        trusted_monitors: &[String],
+    ) -> Result<RewardingSimulationResponse, RewardingError> {
+        let expected_monitor_runs =
+            self.interval.length().as_secs() / monitor_run_interval.as_secs().max(1);
+        let minimum_monitor_runs =
+            expected_monitor_runs as usize * minimum_interval_monitor_threshold as usize / 100;
+
+        let mut mixnode_uptimes = HashMap::new();
+        for mixnode in &self.mixnodes {
+            let uptime = storage
+                .get_aggregated_uptime_in_interval(
+                    mixnode.identity(),
+                    true,
+                    self.interval.start_unix_timestamp(),
+                    self.interval.end_unix_timestamp(),
+                    minimum_monitor_runs,
+                    trusted_monitors,
+                )
+                .await?;
+            mixnode_uptimes.insert(mixnode.identity().clone(), uptime.u8());
+        }
+
+        let mut gateway_uptimes = HashMap::new();
+        for gateway in &self.gateways {
+            let uptime = storage
+                .get_aggregated_uptime_in_interval(
+                    gateway.identity(),
+                    false,
+                    self.interval.start_unix_timestamp(),
+                    self.interval.end_unix_timestamp(),
+                    minimum_monitor_runs,
+                    trusted_monitors,
+                )
+                .await?;
+            gateway_uptimes.insert(gateway.identity().clone(), uptime.u8());
+        }
+
+        Ok(self.simulate_with_uptimes(&mixnode_uptimes, &gateway_uptimes))
+    }
+
+    fn simulate_with_uptimes(
+        &self,
+        mixnode_uptimes: &HashMap<IdentityKey, u8>,
+        gateway_uptimes: &HashMap<IdentityKey, u8>,
+    ) -> RewardingSimulationResponse {
+        let mixnodes = self
+            .mixnodes
+            .iter()
+            .map(|mixnode| {
+                let uptime = mixnode_uptimes
+                    .get(mixnode.identity())
+                    .copied()
+                    .unwrap_or_default();
+                self.simulate_mixnode(mixnode, uptime)
+            })
+            .collect::<Vec<_>>();
+
+        // mirror the `Rewarder` and do not bother with gateways if their rewarding is disabled,
+        // otherwise only simulate the rewards of the gateway rewarded set
+        let gateways = if self.gateway_rewarding.is_enabled() {
+            let candidates = self
+                .gateways
+                .iter()
+                .map(|gateway| GatewayRewardCandidate {
+                    bond: gateway,
+                    uptime: gateway_uptimes
+                        .get(gateway.identity())
+                        .copied()
+                        .unwrap_or_default(),
+                    delegations: Self::delegations(&self.gateway_delegations, gateway.identity()),
+                })
+                .collect();
+
+            select_rewarded_gateways(candidates, self.gateway_rewarding.rewarded_set_size)
+                .into_iter()
+                .map(|candidate| self.simulate_gateway(candidate))
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+
+        RewardingSimulationResponse {
+            interval_id: self.interval.id(),
+            interval_start: self.interval.start_unix_timestamp(),
+            interval_end: self.interval.end_unix_timestamp(),
+            period_reward_pool: self.interval_reward_params.period_reward_pool,
+            total_mixnode_rewards: mixnodes.iter().map(|node| node.total_node_reward).sum(),
+            total_gateway_rewards: gateways.iter().map(|node| node.total_node_reward).sum(),
+            mixnodes,
+            gateways,
+            as_at: OffsetDateTime::now_utc().unix_timestamp(),
+        }
+    }
+
+    fn delegations(
+        delegations: &HashMap<IdentityKey, Vec<Delegation>>,
+        identity: &str,
+    ) -> Vec<Delegation> {
+        delegations.get(identity).cloned().unwrap_or_default()
+    }
+
+    fn simulate_mixnode(&self, mixnode: &MixNodeBond, uptime: u8) -> SimulatedNodeReward {
+        let in_active_set = self.active_set.contains(mixnode.identity());
+        let params = self
+            .interval_reward_params
+            .node_reward_params(uptime, in_active_set);
+
+        let delegator_rewards = Self::delegations(&self.mixnode_delegations, mixnode.identity())
+            .into_iter()
+            .map(|delegation| SimulatedDelegatorReward {
+                owner: delegation.owner.to_string(),
+                amount: delegation.amount.amount.u128(),
+                reward: mixnode.reward_delegation(delegation.amount.amount, &params),
+            })
+            .collect::<Vec<_>>();
+
+        SimulatedNodeReward {
+            identity: mixnode.identity().clone(),
+            owner: mixnode.owner().to_string(),
+            pledge: mixnode.pledge_amount.amount.u128(),
+            total_delegation: mixnode.total_delegation.amount.u128(),
+            uptime,
+            in_active_set,
+            total_node_reward: mixnode
+                .reward(&params)
+                .reward()
+                .checked_to_num::<u128>()
+                .unwrap_or_default(),
+            operator_reward: mixnode.operator_reward(&params),
+            delegators_reward: delegator_rewards.iter().map(|reward| reward.reward).sum(),
+            delegator_rewards,
+        }
+    }
+
+    fn simulate_gateway(&self, candidate: GatewayRewardCandidate<'_>) -> SimulatedNodeReward {
+        let GatewayRewardCandidate {
+            bond: gateway,
+            uptime,
+            delegations,
+        } = candidate;
+        let total_delegation = delegations
+            .iter()
+            .map(|delegation| delegation.amount.amount)
+            .sum();
+
+        let params = GatewayRewardParams::new(
+            self.interval_reward_params.period_reward_pool,
+            uptime.into(),
+            0,
+        );
+        let total_reward = self.gateway_rewarding.gateway_reward(&params);
+        let (operator_reward, delegators_reward) = self.gateway_rewarding.split_reward(
+            total_reward,
+            gateway.pledge_amount.amount,
+            total_delegation,
+        );
+        let delegator_params =
+            GatewayDelegatorRewardParams::new(delegators_reward, total_delegation, 0);
+
+        let delegator_rewards = delegations
+            .into_iter()
+            .map(|delegation| SimulatedDelegatorReward {
+                owner: delegation.owner.to_string(),
+                amount: delegation.amount.amount.u128(),
+                reward: delegator_params
+                    .determine_delegation_reward(delegation.amount.amount)
+                    .u128(),
+            })
+            .collect();
+
+        SimulatedNodeReward {
+            identity: gateway.identity().clone(),
+            owner: gateway.owner().to_string(),
+            pledge: gateway.pledge_amount.amount.u128(),
+            total_delegation: total_delegation.u128(),
+            uptime,
+            in_active_set: false,
+            total_node_reward: total_reward.u128(),
+            operator_reward: operator_reward.u128(),
+            delegators_reward: delegators_reward.u128(),
+            delegator_rewards,
+        }
+    }
+}
+
+/// Flattens the simulation results into CSV with a single row per every reward recipient.
+pub(crate) fn simulation_to_csv(simulation: &RewardingSimulationResponse) -> String {
+    let mut csv =
+        String::from("node_type,identity,uptime,in_active_set,recipient,role,stake,reward\n");
+
+    let nodes = simulation
+        .mixnodes
+        .iter()
+        .map(|node| ("mixnode", node))
+        .chain(simulation.gateways.iter().map(|node| ("gateway", node)));
+
+    for (node_type, node) in nodes {
+        csv.push_str(&format!(
+            "{},{},{},{},{},operator,{},{}\n",
+            node_type,
+            node.identity,
+            node.uptime,
+            node.in_active_set,
+            node.owner,
+            node.pledge,
+            node.operator_reward
+        ));
+        for delegator in &node.delegator_rewards {
+            csv.push_str(&format!(
+                "{},{},{},{},{},delegator,{},{}\n",
+                node_type,
+                node.identity,
+                node.uptime,
+                node.in_active_set,
+                delegator.owner,
+                delegator.amount,
+                delegator.reward
+            ));
+        }
+    }
+
+    csv
+}
+
+/// Serializes the simulation results in the specified format.
+pub(crate) fn format_simulation(
+    simulation: &RewardingSimulationResponse,
+    format: SimulationFormat,
+) -> String {
+    match format {
+        SimulationFormat::Json => serde_json::to_string_pretty(simulation)
+            .expect("failed to serialize rewarding simulation"),
+        SimulationFormat::Csv => simulation_to_csv(simulation),
+    }
+}
+
+/// Snapshot (and the simulation based on it) captured for particular interval
+/// and refresh of the validator cache.
+struct CachedSimulation {
+    interval_id: u32,
+    cache_block_height: u64,
+    snapshot: RewardingSnapshot,
+    simulation: Option<RewardingSimulationResponse>,
+}
+
+pub(crate) struct RewardingSimulator {
+    nymd_client: Client<QueryNymdClient>,
+    monitor_run_interval: Duration,
+    minimum_interval_monitor_threshold: u8,
+    trusted_monitors: Vec<String>,
+
+    // capturing the snapshot requires querying delegations of every single bonded node,
+    // so it's only ever done once per interval and refresh of the validator cache
+    cached: Mutex<Option<CachedSimulation>>,
+}
+
+impl RewardingSimulator {
+    pub(crate) fn new(config: &Config, trusted_monitors: Vec<String>) -> Self {
+        RewardingSimulator {
+            nymd_client: Client::new_query(config),
+            monitor_run_interval: config.get_network_monitor_run_interval(),
+            minimum_interval_monitor_threshold: config.get_minimum_interval_monitor_threshold(),
+            trusted_monitors,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Captures the current state of the mixnet contract required to reward the current interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `validator_cache`: cache of the bonded mixnodes, gateways and the active set.
+    async fn capture_snapshot(
+        &self,
+        validator_cache: &ValidatorCache,
+    ) -> Result<RewardingSnapshot, RewardingError> {
+        let interval = self.nymd_client.get_current_interval().await?;
+        let interval_reward_params = self
+            .nymd_client
+            .get_current_interval_reward_params()
+            .await?;
+        let gateway_rewarding = self
+            .nymd_client
+            .get_contract_settings()
+            .await?
+            .gateway_rewarding;
+
+        let mixnodes = validator_cache.mixnodes().await.into_inner();
+        let gateways = validator_cache.gateways().await.into_inner();
+        let active_set = validator_cache
+            .active_set()
+            .await
+            .into_inner()
+            .into_iter()
+            .map(|bond| bond.mix_node.identity_key)
+            .collect();
+
+        let mut mixnode_delegations = HashMap::with_capacity(mixnodes.len());
+        for mixnode in &mixnodes {
+            let delegations = self
+                .nymd_client
+                .get_mixnode_delegations(mixnode.identity().clone())
+                .await?;
+            mixnode_delegations.insert(mixnode.identity().clone(), delegations);
+        }
+
+        let mut gateway_delegations = HashMap::with_capacity(gateways.len());
+        for gateway in &gateways {
+            let delegations = self
+                .nymd_client
+                .get_gateway_delegations(gateway.identity().clone())
+                .await?;
+            gateway_delegations.insert(gateway.identity().clone(), delegations);
+        }
+
+        Ok(RewardingSnapshot {
+            interval,
+            interval_reward_params,
+            gateway_rewarding,
+            mixnodes,
+            active_set,
+            gateways,
+            mixnode_delegations,
+            gateway_delegations,
+        })
+    }
+
+    /// Returns the snapshot of the current interval, capturing a new one only if the interval
+    /// or the validator cache has changed since the previous call.
+    ///
+    /// # Arguments
+    ///
+    /// * `validator_cache`: cache of the bonded mixnodes, gateways and the active set.
+    pub(crate) async fn snapshot(
+        &self,
+        validator_cache: &ValidatorCache,
+    ) -> Result<RewardingSnapshot, RewardingError> {
+        let mut cached = self.cached.lock().await;
+        self.refresh_cached(&mut cached, validator_cache).await?;
+
+        // the above call guarantees the cache is populated
+        Ok(cached.as_ref().unwrap().snapshot.clone())
+    }
+
+    /// Simulates rewarding of the current interval based on the current contract state
+    /// and the network monitor results gathered so far. The result is reused until either
+    /// the interval or the validator cache changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `validator_cache`: cache of the bonded mixnodes, gateways and the active set.
+    /// * `storage`: storage containing the network monitor test results.
+    pub(crate) async fn simulate(
+        &self,
+        validator_cache: &ValidatorCache,
+        storage: &ValidatorApiStorage,
+    ) -> Result<RewardingSimulationResponse, RewardingError> {
+        let mut cached = self.cached.lock().await;
+        self.refresh_cached(&mut cached, validator_cache).await?;
+
+        // the above call guarantees the cache is populated
+        let cached = cached.as_mut().unwrap();
+        if let Some(simulation) = &cached.simulation {
+            return Ok(simulation.clone());
+        }
+
+        let simulation = cached
+            .snapshot
+            .simulate(
+                storage,
+                self.monitor_run_interval,
+                self.minimum_interval_monitor_threshold,
+                &self.trusted_monitors,
+            )
+            .await?;
+        cached.simulation = Some(simulation.clone());
+        Ok(simulation)
+    }
+
+    async fn refresh_cached(
+        &self,
+        cached: &mut Option<CachedSimulation>,
+        validator_cache: &ValidatorCache,
+    ) -> Result<(), RewardingError> {
+        let interval_id = validator_cache.current_interval().await.into_inner().id();
+        let cache_block_height = validator_cache.mixnodes().await.block_height();
+
+        if let Some(cached) = cached {
+            if cached.interval_id == interval_id && cached.cache_block_height == cache_block_height
+            {
+                return Ok(());
+            }
+        }
+
+        let snapshot = self.capture_snapshot(validator_cache).await?;
+        *cached = Some(CachedSimulation {
+            interval_id,
+            cache_block_height,
+            snapshot,
+            simulation: None,
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mixnet_contract_common::{Addr, Coin, Gateway};
+
+    fn gateway_bond(identity: &str, pledge: u128) -> GatewayBond {
+        GatewayBond::new(
+            Coin::new(pledge, "unym"),
+            Addr::unchecked("gateway-owner"),
+            1,
+            Gateway {
+                host: "1.1.1.1".to_string(),
+                mix_port: 1789,
+                clients_port: 9000,
+                location: "Somewhere".to_string(),
+                sphinx_key: "sphinx".to_string(),
+                identity_key: identity.to_string(),
+                version: "1.0.0".to_string(),
+            },
+            None,
+        )
+    }
+
+    fn snapshot(gateway_rewarding: GatewayRewardingParams) -> RewardingSnapshot {
+        let mut interval_reward_params = IntervalRewardParams::new_empty();
+        interval_reward_params.period_reward_pool = 1_000_000;
+
+        let mut gateway_delegations = HashMap::new();
+        gateway_delegations.insert(
+            "gateway".to_string(),
+            vec![
+                Delegation::new(
+                    Addr::unchecked("alice"),
+                    "gateway".to_string(),
+                    Coin::new(300, "unym"),
+                    1,
+                    None,
+                ),
+                Delegation::new(
+                    Addr::unchecked("bob"),
+                    "gateway".to_string(),
+                    Coin::new(100, "unym"),
+                    1,
+                    None,
+                ),
+            ],
+        );
+
+        RewardingSnapshot {
+            interval: Interval::new(1, OffsetDateTime::UNIX_EPOCH, Duration::from_secs(3600)),
+            interval_reward_params,
+            gateway_rewarding,
+            mixnodes: Vec::new(),
+            active_set: Vec::new(),
+            gateways: vec![gateway_bond("gateway", 600)],
+            mixnode_delegations: HashMap::new(),
+            gateway_delegations,
+        }
+    }
+
+    #[test]
+    fn simulating_gateway_rewards() {
+        let snapshot = snapshot(GatewayRewardingParams {
+            reward_pool_share_percent: 10,
+            rewarded_set_size: 10,
+            operator_share_percent: 50,
+        });
+        let mut uptimes = HashMap::new();
+        uptimes.insert("gateway".to_string(), 100);
+
+        let simulation = snapshot.simulate_with_uptimes(&HashMap::new(), &uptimes);
+        assert_eq!(1, simulation.gateways.len());
+
+        let gateway = &simulation.gateways[0];
+        // 10% of the pool split between 10 gateways
+        assert_eq!(10_000, gateway.total_node_reward);
+        // half to the operator, remainder split 600:400 between the pledge and delegations
+        assert_eq!(8_000, gateway.operator_reward);
+        assert_eq!(2_000, gateway.delegators_reward);
+        assert_eq!(1_500, gateway.delegator_rewards[0].reward);
+        assert_eq!(500, gateway.delegator_rewards[1].reward);
+        assert_eq!(10_000, simulation.total_gateway_rewards);
+
+        let csv = simulation_to_csv(&simulation);
+        let rows = csv.lines().collect::<Vec<_>>();
+        assert_eq!(4, rows.len());
+        assert_eq!(
+            "gateway,gateway,100,false,gateway-owner,operator,600,8000",
+            rows[1]
+        );
+        assert_eq!("gateway,gateway,100,false,bob,delegator,100,500", rows[3]);
+    }
+
+    #[test]
+    fn only_gateway_rewarded_set_is_simulated() {
+        let mut snapshot = snapshot(GatewayRewardingParams {
+            reward_pool_share_percent: 10,
+            rewarded_set_size: 1,
+            operator_share_percent: 50,
+        });
+        snapshot.gateways.push(gateway_bond("whale", 2000));
+        snapshot.gateways.push(gateway_bond("offline", 5000));
+
+        // with the same uptime, the gateway with the bigger stake is preferred
+        let mut uptimes = HashMap::new();
+        uptimes.insert("gateway".to_string(), 100);
+        uptimes.insert("whale".to_string(), 100);
+        let simulation = snapshot.simulate_with_uptimes(&HashMap::new(), &uptimes);
+        assert_eq!(1, simulation.gateways.len());
+        assert_eq!("whale", simulation.gateways[0].identity);
+        assert_eq!(100_000, simulation.total_gateway_rewards);
+
+        // but uptime always comes first
+        uptimes.insert("whale".to_string(), 90);
+        let simulation = snapshot.simulate_with_uptimes(&HashMap::new(), &uptimes);
+        assert_eq!(1, simulation.gateways.len());
+        assert_eq!("gateway", simulation.gateways[0].identity);
+
+        // and gateways without any uptime are never selected
+        let simulation = snapshot.simulate_with_uptimes(&HashMap::new(), &HashMap::new());
+        assert!(simulation.gateways.is_empty());
+    }
+
+    #[test]
+    fn disabled_gateway_rewarding_is_not_simulated() {
+        let snapshot = snapshot(GatewayRewardingParams::default());
+        let simulation = snapshot.simulate_with_uptimes(&HashMap::new(), &HashMap::new());
+        assert!(simulation.gateways.is_empty());
+        assert_eq!(0, simulation.total_gateway_rewards);
+    }
+
+    #[test]
+    fn parsing_simulation_format() {
+        assert_eq!(SimulationFormat::Json, "json".parse().unwrap());
+        assert_eq!(SimulationFormat::Csv, "CSV".parse().unwrap());
+        assert!("xml".parse::<SimulationFormat>().is_err());
+    }
+}
@@ -1,14 +1,23 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::network_monitor::monitor::summary_producer::NodeResult;
+use crate::network_monitor::monitor::summary_producer::{NodeDiagnostics, NodeResult};
 use crate::node_status_api::models::{HistoricalUptime, Uptime};
 use crate::node_status_api::utils::ActiveNodeStatuses;
 use crate::storage::models::{
-    ActiveNode, FailedMixnodeRewardChunk, IntervalRewarding, NodeStatus, PossiblyUnrewardedMixnode,
-    RewardingReport, TestingRoute,
+    ActiveNode, FailedMixnodeRewardChunk, IntervalRewarding, LayerLatencyEntry,
+    MonitorNodeReliability, MonitorRunNodeResult, MonitorRunsBucket, MonitorRunsCount,
+    NodeDiagnosticsEntry, NodeRewardingOutcomeEntry, NodeStatus, NodeStatusBucket,
+    PossiblyUnrewardedMixnode, RewardingJob, RewardingJobStatus, RewardingJobsCount,
+    RewardingReport, StoredMonitorRunSummary, TestingRoute,
+};
+use crate::storage::models::{
+    ArchivedMonitorRun, ArchivedNodeDiagnostics, ArchivedNodeStatus, ArchivedTestingRoute,
+    DataPruningEntry, DatabaseSize, MonitorRunDailySummaryEntry, NodeDailySummaryEntry,
+    TableRowCountEntry,
 };
 use std::convert::TryFrom;
+use validator_api_requests::models::MonitorRunSummary;
 
 #[derive(Clone)]
 pub(crate) struct StorageManager {
@@ -386,20 +395,124 @@ impl StorageManager {
         sqlx::query!(
             r#"
                 INSERT INTO testing_route 
-                (gateway_id, layer1_mix_id, layer2_mix_id, layer3_mix_id, monitor_run_id) 
-                VALUES (?, ?, ?, ?, ?);
+                (gateway_id, layer1_mix_id, layer2_mix_id, layer3_mix_id, monitor_run_id, reliability, latency_ms) 
+                VALUES (?, ?, ?, ?, ?, ?, ?);
             "#,
             testing_route.gateway_id,
             testing_route.layer1_mix_id,
             testing_route.layer2_mix_id,
             testing_route.layer3_mix_id,
             testing_route.monitor_run_id,
+            testing_route.reliability,
+            testing_route.latency_ms,
         )
         .execute(&self.connection_pool)
         .await?;
         Ok(())
     }
 
+    /// Saves latency and failure diagnostics of all nodes tested during particular
+    /// network monitor test run.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor_run_id`: id (as saved in the database) of the associated network monitor test run.
+    /// * `timestamp`: unix timestamp indicating when the measurements took place.
+    /// * `node_diagnostics`: diagnostics of each node that got tested.
+    pub(super) async fn submit_node_diagnostics(
+        &self,
+        monitor_run_id: i64,
+        timestamp: i64,
+        node_diagnostics: Vec<NodeDiagnostics>,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.connection_pool.begin().await?;
+        for diagnostics in node_diagnostics {
+            let mean_latency_ms = diagnostics
+                .mean_latency
+                .map(|latency| latency.as_millis() as i64);
+            let tested_routes = diagnostics.tested_routes as i64;
+            let failed_routes = diagnostics.failed_routes as i64;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO node_diagnostics
+                    (monitor_run_id, node_identity, is_mixnode, layer, mean_latency_ms, excess_latency_ms, tested_routes, failed_routes, timestamp)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?);
+                "#,
+                monitor_run_id,
+                diagnostics.identity,
+                diagnostics.is_mixnode,
+                diagnostics.layer,
+                mean_latency_ms,
+                diagnostics.excess_latency_ms,
+                tested_routes,
+                failed_routes,
+                timestamp,
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
+
+    /// Gets all latency diagnostics of the particular node since the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity (base58-encoded public key) of the node.
+    /// * `is_mixnode`: indicates whether the node is a mixnode or a gateway.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    pub(super) async fn get_node_diagnostics_since(
+        &self,
+        identity: &str,
+        is_mixnode: bool,
+        since: i64,
+    ) -> Result<Vec<NodeDiagnosticsEntry>, sqlx::Error> {
+        sqlx::query_as!(
+            NodeDiagnosticsEntry,
+            r#"
+                SELECT timestamp, mean_latency_ms, excess_latency_ms, tested_routes, failed_routes
+                    FROM node_diagnostics
+                    WHERE node_identity = ? AND is_mixnode = ? AND timestamp > ?
+                    ORDER BY timestamp;
+            "#,
+            identity,
+            is_mixnode,
+            since,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets average latencies of mixnodes in each layer since the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    pub(super) async fn get_layer_latencies_since(
+        &self,
+        since: i64,
+    ) -> Result<Vec<LayerLatencyEntry>, sqlx::Error> {
+        sqlx::query_as!(
+            LayerLatencyEntry,
+            r#"
+                SELECT
+                    layer as "layer!: u8",
+                    COUNT(DISTINCT node_identity) as "nodes!: i32",
+                    AVG(mean_latency_ms) as "mean_latency_ms: f64",
+                    AVG(excess_latency_ms) as "mean_excess_latency_ms: f64"
+                    FROM node_diagnostics
+                    WHERE is_mixnode = true AND layer IS NOT NULL AND timestamp > ?
+                    GROUP BY layer
+                    ORDER BY layer;
+            "#,
+            since,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
     /// Get the number of times mixnode with the particular id is present in any `testing_route`
     /// since the provided unix timestamp.
     ///
@@ -570,36 +683,369 @@ impl StorageManager {
         Ok(count)
     }
 
-    /// Removes all statuses for all mixnodes that are older than the
-    /// provided timestamp. This method is indirectly called at every reward cycle.
+    /// Gets the average reliability and the number of tests of the particular mixnode within
+    /// the specified time range, grouped into buckets of the specified length.
     ///
     /// # Arguments
     ///
-    /// * `until`: timestamp specifying the purge cutoff.
-    pub(super) async fn purge_old_mixnode_statuses(
+    /// * `identity`: identity (base58-encoded public key) of the mixnode.
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    /// * `origin`: unix timestamp of the beginning of the very first bucket. It must not be greater than `from`.
+    /// * `bucket_length`: length of each bucket in seconds.
+    pub(super) async fn get_mixnode_status_buckets(
         &self,
-        timestamp: i64,
-    ) -> Result<(), sqlx::Error> {
-        sqlx::query!("DELETE FROM mixnode_status WHERE timestamp < ?", timestamp)
-            .execute(&self.connection_pool)
-            .await?;
-        Ok(())
+        identity: &str,
+        from: i64,
+        to: i64,
+        origin: i64,
+        bucket_length: i64,
+    ) -> Result<Vec<NodeStatusBucket>, sqlx::Error> {
+        sqlx::query_as!(
+            NodeStatusBucket,
+            r#"
+                SELECT (mixnode_status.timestamp - ?) / ? as "bucket!: i64",
+                    AVG(mixnode_status.reliability) as "average_reliability!: f64",
+                    COUNT(*) as "tests!: i32"
+                    FROM mixnode_status
+                    JOIN mixnode_details
+                    ON mixnode_status.mixnode_details_id = mixnode_details.id
+                    WHERE mixnode_details.identity = ? AND mixnode_status.timestamp >= ? AND mixnode_status.timestamp < ?
+                    GROUP BY 1
+                    ORDER BY 1
+            "#,
+            origin,
+            bucket_length,
+            identity,
+            from,
+            to,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
     }
 
-    /// Removes all statuses for all gateways that are older than the
-    /// provided timestamp. This method is indirectly called at every reward cycle.
+    /// Gets the average reliability and the number of tests of the particular gateway within
+    /// the specified time range, grouped into buckets of the specified length.
     ///
     /// # Arguments
     ///
-    /// * `until`: timestamp specifying the purge cutoff.
-    pub(super) async fn purge_old_gateway_statuses(
+    /// * `identity`: identity (base58-encoded public key) of the gateway.
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    /// * `origin`: unix timestamp of the beginning of the very first bucket. It must not be greater than `from`.
+    /// * `bucket_length`: length of each bucket in seconds.
+    pub(super) async fn get_gateway_status_buckets(
         &self,
-        timestamp: i64,
-    ) -> Result<(), sqlx::Error> {
-        sqlx::query!("DELETE FROM gateway_status WHERE timestamp < ?", timestamp)
-            .execute(&self.connection_pool)
+        identity: &str,
+        from: i64,
+        to: i64,
+        origin: i64,
+        bucket_length: i64,
+    ) -> Result<Vec<NodeStatusBucket>, sqlx::Error> {
+        sqlx::query_as!(
+            NodeStatusBucket,
+            r#"
+                SELECT (gateway_status.timestamp - ?) / ? as "bucket!: i64",
+                    AVG(gateway_status.reliability) as "average_reliability!: f64",
+                    COUNT(*) as "tests!: i32"
+                    FROM gateway_status
+                    JOIN gateway_details
+                    ON gateway_status.gateway_details_id = gateway_details.id
+                    WHERE gateway_details.identity = ? AND gateway_status.timestamp >= ? AND gateway_status.timestamp < ?
+                    GROUP BY 1
+                    ORDER BY 1
+            "#,
+            origin,
+            bucket_length,
+            identity,
+            from,
+            to,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets the number of network monitor test runs performed within the specified time range,
+    /// grouped into buckets of the specified length.
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    /// * `origin`: unix timestamp of the beginning of the very first bucket. It must not be greater than `from`.
+    /// * `bucket_length`: length of each bucket in seconds.
+    pub(super) async fn get_monitor_runs_buckets(
+        &self,
+        from: i64,
+        to: i64,
+        origin: i64,
+        bucket_length: i64,
+    ) -> Result<Vec<MonitorRunsBucket>, sqlx::Error> {
+        sqlx::query_as!(
+            MonitorRunsBucket,
+            r#"
+                SELECT (timestamp - ?) / ? as "bucket!: i64", COUNT(*) as "runs!: i32"
+                    FROM monitor_run
+                    WHERE timestamp >= ? AND timestamp < ?
+                    GROUP BY 1
+                    ORDER BY 1
+            "#,
+            origin,
+            bucket_length,
+            from,
+            to,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets the daily summaries of the pruned raw data of particular node within the specified dates.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity (base58-encoded public key) of the node.
+    /// * `is_mixnode`: indicates whether the node is a mixnode or a gateway.
+    /// * `from_date`: ISO 8601 date indicating the lower bound (inclusive) of the range.
+    /// * `to_date`: ISO 8601 date indicating the upper bound (inclusive) of the range.
+    pub(super) async fn get_node_daily_summaries(
+        &self,
+        identity: &str,
+        is_mixnode: bool,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<Vec<NodeDailySummaryEntry>, sqlx::Error> {
+        sqlx::query_as!(
+            NodeDailySummaryEntry,
+            r#"
+                SELECT date, tests, average_reliability
+                    FROM node_daily_summary
+                    WHERE node_identity = ? AND is_mixnode = ? AND date >= ? AND date <= ?
+                    ORDER BY date
+            "#,
+            identity,
+            is_mixnode,
+            from_date,
+            to_date,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets the daily summaries of the pruned network monitor test runs within the specified dates.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_date`: ISO 8601 date indicating the lower bound (inclusive) of the range.
+    /// * `to_date`: ISO 8601 date indicating the upper bound (inclusive) of the range.
+    pub(super) async fn get_monitor_run_daily_summaries(
+        &self,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<Vec<MonitorRunDailySummaryEntry>, sqlx::Error> {
+        sqlx::query_as!(
+            MonitorRunDailySummaryEntry,
+            r#"
+                SELECT date, monitor_runs
+                    FROM monitor_run_daily_summary
+                    WHERE date >= ? AND date <= ?
+                    ORDER BY date
+            "#,
+            from_date,
+            to_date,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Inserts signed summary of a network monitor test run alongside all of its node results.
+    /// Returns `false` if a summary of the same monitor with the same timestamp already existed.
+    ///
+    /// # Arguments
+    ///
+    /// * `summary`: summary of the test run.
+    /// * `signature`: base58-encoded signature of the monitor on the summary.
+    pub(super) async fn insert_monitor_run_summary(
+        &self,
+        summary: &MonitorRunSummary,
+        signature: &str,
+    ) -> Result<bool, sqlx::Error> {
+        // insert it all in a transaction to make sure we never end up with partial summaries
+        let mut tx = self.connection_pool.begin().await?;
+
+        let res = sqlx::query!(
+            r#"
+                INSERT OR IGNORE INTO monitor_run_summary(monitor_identity, timestamp, signature)
+                VALUES (?, ?, ?);
+            "#,
+            summary.monitor_identity,
+            summary.timestamp,
+            signature,
+        )
+        .execute(&mut tx)
+        .await?;
+
+        if res.rows_affected() == 0 {
+            return Ok(false);
+        }
+        let summary_id = res.last_insert_rowid();
+
+        let mixnode_results = summary.mixnode_results.iter().map(|res| (res, true));
+        let gateway_results = summary.gateway_results.iter().map(|res| (res, false));
+        for (node_result, is_mixnode) in mixnode_results.chain(gateway_results) {
+            sqlx::query!(
+                r#"
+                    INSERT INTO monitor_run_node_result
+                    (monitor_run_summary_id, node_identity, is_mixnode, reliability)
+                    VALUES (?, ?, ?, ?);
+                "#,
+                summary_id,
+                node_result.identity,
+                is_mixnode,
+                node_result.reliability,
+            )
+            .execute(&mut tx)
             .await?;
-        Ok(())
+        }
+
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    /// Gets all test run summaries of the specified network monitor that were created
+    /// after the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor_identity`: base58-encoded identity of the network monitor.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    pub(super) async fn get_monitor_run_summaries_since(
+        &self,
+        monitor_identity: &str,
+        since: i64,
+    ) -> Result<Vec<StoredMonitorRunSummary>, sqlx::Error> {
+        sqlx::query_as!(
+            StoredMonitorRunSummary,
+            r#"
+                SELECT id, monitor_identity, timestamp, signature
+                    FROM monitor_run_summary
+                    WHERE monitor_identity = ? AND timestamp > ?
+                    ORDER BY timestamp;
+            "#,
+            monitor_identity,
+            since,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets all node results of the particular test run summary.
+    ///
+    /// # Arguments
+    ///
+    /// * `summary_id`: id (as saved in the database) of the test run summary.
+    pub(super) async fn get_monitor_run_node_results(
+        &self,
+        summary_id: i64,
+    ) -> Result<Vec<MonitorRunNodeResult>, sqlx::Error> {
+        sqlx::query_as!(
+            MonitorRunNodeResult,
+            r#"
+                SELECT node_identity, is_mixnode as "is_mixnode: bool", reliability as "reliability: u8"
+                    FROM monitor_run_node_result
+                    WHERE monitor_run_summary_id = ?
+                    ORDER BY id;
+            "#,
+            summary_id,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets timestamp of the most recent test run summary of the specified network monitor.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor_identity`: base58-encoded identity of the network monitor.
+    pub(super) async fn get_latest_monitor_run_summary_timestamp(
+        &self,
+        monitor_identity: &str,
+    ) -> Result<Option<i64>, sqlx::Error> {
+        let timestamp = sqlx::query!(
+            r#"
+                SELECT timestamp
+                    FROM monitor_run_summary
+                    WHERE monitor_identity = ?
+                    ORDER BY timestamp DESC
+                    LIMIT 1;
+            "#,
+            monitor_identity,
+        )
+        .fetch_optional(&self.connection_pool)
+        .await?
+        .map(|row| row.timestamp);
+
+        Ok(timestamp)
+    }
+
+    /// Obtains number of test runs, within the specified interval, of each network monitor
+    /// we have summaries of.
+    ///
+    /// # Arguments
+    ///
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the upper bound interval of the selection.
+    pub(super) async fn get_monitor_run_summaries_count(
+        &self,
+        since: i64,
+        until: i64,
+    ) -> Result<Vec<MonitorRunsCount>, sqlx::Error> {
+        sqlx::query_as!(
+            MonitorRunsCount,
+            r#"
+                SELECT monitor_identity, COUNT(*) as "runs!: i32"
+                    FROM monitor_run_summary
+                    WHERE timestamp > ? AND timestamp < ?
+                    GROUP BY monitor_identity;
+            "#,
+            since,
+            until,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets all reliability results of the particular node, as reported by all network monitors,
+    /// within the specified time interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity (base58-encoded public key) of the node.
+    /// * `is_mixnode`: indicates whether the node is a mixnode or a gateway.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the upper bound interval of the selection.
+    pub(super) async fn get_monitor_node_reliabilities(
+        &self,
+        identity: &str,
+        is_mixnode: bool,
+        since: i64,
+        until: i64,
+    ) -> Result<Vec<MonitorNodeReliability>, sqlx::Error> {
+        sqlx::query_as!(
+            MonitorNodeReliability,
+            r#"
+                SELECT monitor_identity, reliability as "reliability: u8"
+                    FROM monitor_run_node_result
+                    JOIN monitor_run_summary
+                    ON monitor_run_summary.id = monitor_run_node_result.monitor_run_summary_id
+                    WHERE node_identity = ? AND is_mixnode = ? AND timestamp > ? AND timestamp < ?;
+            "#,
+            identity,
+            is_mixnode,
+            since,
+            until,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
     }
 
     /// Returns public key, owner and id of all mixnodes that have had any statuses submitted
@@ -715,24 +1161,6 @@ impl StorageManager {
         Ok(())
     }
 
-    // /// Tries to obtain the most recent interval rewarding entry currently stored.
-    // ///
-    // /// Returns None if no data exists.
-    // pub(super) async fn get_most_recent_interval_rewarding_entry(
-    //     &self,
-    // ) -> Result<Option<IntervalRewarding>, sqlx::Error> {
-    //     sqlx::query_as!(
-    //         IntervalRewarding,
-    //         r#"
-    //             SELECT * FROM interval_rewarding
-    //             ORDER BY interval_timestamp DESC
-    //             LIMIT 1
-    //         "#,
-    //     )
-    //     .fetch_optional(&self.connection_pool)
-    //     .await
-    // }
-
     /// Tries to obtain the interval rewarding entry that has the provided timestamp.
     ///
     /// Returns None if no data exists.
@@ -756,6 +1184,197 @@ impl StorageManager {
         .await
     }
 
+    /// Tries to obtain the most recent interval rewarding entry currently stored.
+    ///
+    /// Returns None if no data exists.
+    pub(super) async fn get_most_recent_interval_rewarding_entry(
+        &self,
+    ) -> Result<Option<IntervalRewarding>, sqlx::Error> {
+        sqlx::query_as!(
+            IntervalRewarding,
+            r#"
+                SELECT * FROM interval_rewarding
+                ORDER BY interval_start_timestamp DESC
+                LIMIT 1
+            "#,
+        )
+        .fetch_optional(&self.connection_pool)
+        .await
+    }
+
+    /// Inserts rewarding jobs of all the nodes to be rewarded in the particular interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval_rewarding_id`: id of the interval rewarding the jobs belong to.
+    /// * `jobs`: jobs to insert.
+    /// * `timestamp`: unix timestamp of the insertion.
+    pub(super) async fn insert_rewarding_jobs(
+        &self,
+        interval_rewarding_id: i64,
+        jobs: Vec<RewardingJob>,
+        timestamp: i64,
+    ) -> Result<(), sqlx::Error> {
+        // either all the jobs are inserted or none of them are
+        let mut tx = self.connection_pool.begin().await?;
+        for job in jobs {
+            sqlx::query!(
+                r#"
+                    INSERT INTO rewarding_job
+                    (interval_rewarding_id, node_identity, is_mixnode, total_delegations, reward_params, status, last_updated)
+                    VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+                interval_rewarding_id,
+                job.node_identity,
+                job.is_mixnode,
+                job.total_delegations,
+                job.reward_params,
+                job.status,
+                timestamp,
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
+
+    /// Gets all rewarding jobs of the particular interval rewarding.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval_rewarding_id`: id of the interval rewarding.
+    pub(super) async fn get_rewarding_jobs(
+        &self,
+        interval_rewarding_id: i64,
+    ) -> Result<Vec<RewardingJob>, sqlx::Error> {
+        sqlx::query_as!(
+            RewardingJob,
+            r#"
+                SELECT node_identity, is_mixnode as "is_mixnode: bool", total_delegations, reward_params, status as "status: u8"
+                FROM rewarding_job
+                WHERE interval_rewarding_id = ?
+                ORDER BY id
+            "#,
+            interval_rewarding_id
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Updates status of the rewarding jobs of the specified nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval_rewarding_id`: id of the interval rewarding the jobs belong to.
+    /// * `identities`: identities of the nodes which jobs should be updated.
+    /// * `is_mixnode`: indicates whether the nodes are mixnodes or gateways.
+    /// * `status`: new status of the jobs.
+    /// * `estimated_batch_gas`: gas estimated for the batch the nodes were sent in, if applicable.
+    /// * `error_message`: error encountered while rewarding the nodes, if applicable.
+    /// * `timestamp`: unix timestamp of the update.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) async fn update_rewarding_jobs_status(
+        &self,
+        interval_rewarding_id: i64,
+        identities: &[String],
+        is_mixnode: bool,
+        status: u8,
+        estimated_batch_gas: Option<i64>,
+        error_message: Option<String>,
+        timestamp: i64,
+    ) -> Result<(), sqlx::Error> {
+        // every transition into the 'submitted' state is a new attempt
+        let is_attempt = status == u8::from(RewardingJobStatus::Submitted);
+
+        let mut tx = self.connection_pool.begin().await?;
+        for identity in identities {
+            sqlx::query!(
+                r#"
+                    UPDATE rewarding_job
+                    SET status = ?,
+                        attempts = attempts + ?,
+                        estimated_batch_gas = COALESCE(?, estimated_batch_gas),
+                        error_message = ?,
+                        last_updated = ?
+                    WHERE interval_rewarding_id = ? AND node_identity = ? AND is_mixnode = ?
+                "#,
+                status,
+                is_attempt,
+                estimated_batch_gas,
+                error_message,
+                timestamp,
+                interval_rewarding_id,
+                identity,
+                is_mixnode,
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
+
+    /// Counts rewarding jobs of the particular interval rewarding grouped by the node type
+    /// and their status.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval_rewarding_id`: id of the interval rewarding.
+    pub(super) async fn get_rewarding_jobs_counts(
+        &self,
+        interval_rewarding_id: i64,
+    ) -> Result<Vec<RewardingJobsCount>, sqlx::Error> {
+        sqlx::query_as!(
+            RewardingJobsCount,
+            r#"
+                SELECT is_mixnode as "is_mixnode: bool", status as "status: u8", COUNT(*) as "count!: i32"
+                FROM rewarding_job
+                WHERE interval_rewarding_id = ?
+                GROUP BY is_mixnode, status
+            "#,
+            interval_rewarding_id
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets the rewarding outcomes of the particular node in all the intervals that have ended
+    /// within the specified time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity (base58-encoded public key) of the node.
+    /// * `is_mixnode`: indicates whether the node is a mixnode or a gateway.
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    pub(super) async fn get_node_rewarding_outcomes(
+        &self,
+        identity: &str,
+        is_mixnode: bool,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<NodeRewardingOutcomeEntry>, sqlx::Error> {
+        sqlx::query_as!(
+            NodeRewardingOutcomeEntry,
+            r#"
+                SELECT interval_rewarding.interval_start_timestamp, interval_rewarding.interval_end_timestamp, rewarding_job.status as "status: u8"
+                    FROM rewarding_job
+                    JOIN interval_rewarding
+                    ON rewarding_job.interval_rewarding_id = interval_rewarding.id
+                    WHERE rewarding_job.node_identity = ? AND rewarding_job.is_mixnode = ?
+                    AND interval_rewarding.interval_end_timestamp >= ? AND interval_rewarding.interval_end_timestamp < ?
+                    ORDER BY interval_rewarding.interval_end_timestamp
+            "#,
+            identity,
+            is_mixnode,
+            from,
+            to,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
     /// Inserts new rewarding report into the database.
     ///
     /// # Arguments
@@ -768,12 +1387,14 @@ impl StorageManager {
         sqlx::query!(
             r#"
                 INSERT INTO rewarding_report
-                (interval_rewarding_id, eligible_mixnodes, possibly_unrewarded_mixnodes)
-                VALUES (?, ?, ?);
+                (interval_rewarding_id, eligible_mixnodes, possibly_unrewarded_mixnodes, eligible_gateways, possibly_unrewarded_gateways)
+                VALUES (?, ?, ?, ?, ?);
             "#,
             report.interval_rewarding_id,
             report.eligible_mixnodes,
             report.possibly_unrewarded_mixnodes,
+            report.eligible_gateways,
+            report.possibly_unrewarded_gateways,
         )
         .execute(&self.connection_pool)
         .await?;
@@ -886,4 +1507,452 @@ impl StorageManager {
 
         Ok(active_day_statuses)
     }
+
+    /// Gets timestamp of the oldest raw network monitoring data that is still stored, i.e.
+    /// monitor runs, node statuses, latency diagnostics or monitor run summaries.
+    pub(super) async fn get_oldest_raw_data_timestamp(&self) -> Result<Option<i64>, sqlx::Error> {
+        let timestamp = sqlx::query!(
+            r#"
+                SELECT MIN(timestamp) as "timestamp: i64" FROM (
+                    SELECT MIN(timestamp) as timestamp FROM monitor_run
+                    UNION ALL
+                    SELECT MIN(timestamp) FROM mixnode_status
+                    UNION ALL
+                    SELECT MIN(timestamp) FROM gateway_status
+                    UNION ALL
+                    SELECT MIN(timestamp) FROM node_diagnostics
+                    UNION ALL
+                    SELECT MIN(timestamp) FROM monitor_run_summary
+                );
+            "#
+        )
+        .fetch_one(&self.connection_pool)
+        .await?
+        .timestamp;
+
+        Ok(timestamp)
+    }
+
+    /// Gets all network monitor test runs that have occurred within the specified time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    pub(super) async fn get_monitor_runs_in_range(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<ArchivedMonitorRun>, sqlx::Error> {
+        sqlx::query_as!(
+            ArchivedMonitorRun,
+            r#"
+                SELECT id, timestamp
+                    FROM monitor_run
+                    WHERE timestamp >= ? AND timestamp < ?
+                    ORDER BY timestamp;
+            "#,
+            from,
+            to,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets statuses of all mixnodes that were inserted within the specified time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    pub(super) async fn get_all_mixnode_statuses_in_range(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<ArchivedNodeStatus>, sqlx::Error> {
+        sqlx::query_as!(
+            ArchivedNodeStatus,
+            r#"
+                SELECT identity, reliability as "reliability: u8", timestamp
+                    FROM mixnode_status
+                    JOIN mixnode_details
+                    ON mixnode_status.mixnode_details_id = mixnode_details.id
+                    WHERE timestamp >= ? AND timestamp < ?
+                    ORDER BY timestamp;
+            "#,
+            from,
+            to,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets statuses of all gateways that were inserted within the specified time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    pub(super) async fn get_all_gateway_statuses_in_range(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<ArchivedNodeStatus>, sqlx::Error> {
+        sqlx::query_as!(
+            ArchivedNodeStatus,
+            r#"
+                SELECT identity, reliability as "reliability: u8", timestamp
+                    FROM gateway_status
+                    JOIN gateway_details
+                    ON gateway_status.gateway_details_id = gateway_details.id
+                    WHERE timestamp >= ? AND timestamp < ?
+                    ORDER BY timestamp;
+            "#,
+            from,
+            to,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets all testing routes used by the network monitor test runs that have occurred
+    /// within the specified time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    pub(super) async fn get_testing_routes_in_range(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<ArchivedTestingRoute>, sqlx::Error> {
+        sqlx::query_as!(
+            ArchivedTestingRoute,
+            r#"
+                SELECT
+                    testing_route.monitor_run_id,
+                    gateway_details.identity as gateway,
+                    layer1.identity as layer1_mix,
+                    layer2.identity as layer2_mix,
+                    layer3.identity as layer3_mix,
+                    testing_route.reliability as "reliability: u8",
+                    testing_route.latency_ms
+                    FROM testing_route
+                    JOIN monitor_run ON testing_route.monitor_run_id = monitor_run.id
+                    JOIN gateway_details ON testing_route.gateway_id = gateway_details.id
+                    JOIN mixnode_details layer1 ON testing_route.layer1_mix_id = layer1.id
+                    JOIN mixnode_details layer2 ON testing_route.layer2_mix_id = layer2.id
+                    JOIN mixnode_details layer3 ON testing_route.layer3_mix_id = layer3.id
+                    WHERE monitor_run.timestamp >= ? AND monitor_run.timestamp < ?
+                    ORDER BY testing_route.monitor_run_id;
+            "#,
+            from,
+            to,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets latency diagnostics of all nodes that were gathered within the specified time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    pub(super) async fn get_all_node_diagnostics_in_range(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<ArchivedNodeDiagnostics>, sqlx::Error> {
+        sqlx::query_as!(
+            ArchivedNodeDiagnostics,
+            r#"
+                SELECT
+                    monitor_run_id,
+                    node_identity,
+                    is_mixnode as "is_mixnode: bool",
+                    layer as "layer: u8",
+                    mean_latency_ms,
+                    excess_latency_ms,
+                    tested_routes,
+                    failed_routes,
+                    timestamp
+                    FROM node_diagnostics
+                    WHERE timestamp >= ? AND timestamp < ?
+                    ORDER BY timestamp;
+            "#,
+            from,
+            to,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets test run summaries of all network monitors that were created within the specified
+    /// time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    pub(super) async fn get_all_monitor_run_summaries_in_range(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<StoredMonitorRunSummary>, sqlx::Error> {
+        sqlx::query_as!(
+            StoredMonitorRunSummary,
+            r#"
+                SELECT id, monitor_identity, timestamp, signature
+                    FROM monitor_run_summary
+                    WHERE timestamp >= ? AND timestamp < ?
+                    ORDER BY timestamp;
+            "#,
+            from,
+            to,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Rolls all raw network monitoring data from the specified time range into the daily
+    /// summaries of the provided date and removes it afterwards. Returns the number of removed rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `date_iso_8601`: date of the daily summaries the data should be rolled into.
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    pub(super) async fn roll_up_and_remove_raw_data(
+        &self,
+        date_iso_8601: &str,
+        from: i64,
+        to: i64,
+    ) -> Result<u64, sqlx::Error> {
+        // do it all in a transaction so that we would never remove data that did not get rolled up
+        let mut tx = self.connection_pool.begin().await?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO monitor_run_daily_summary (date, monitor_runs)
+                SELECT ?, COUNT(*) FROM monitor_run WHERE timestamp >= ? AND timestamp < ?
+                ON CONFLICT (date) DO UPDATE SET monitor_runs = monitor_runs + excluded.monitor_runs;
+            "#,
+            date_iso_8601,
+            from,
+            to,
+        )
+        .execute(&mut tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO node_daily_summary (node_identity, is_mixnode, date, tests, average_reliability)
+                SELECT identity, true, ?, COUNT(*), AVG(reliability)
+                    FROM mixnode_status
+                    JOIN mixnode_details
+                    ON mixnode_status.mixnode_details_id = mixnode_details.id
+                    WHERE timestamp >= ? AND timestamp < ?
+                    GROUP BY identity
+                ON CONFLICT (node_identity, is_mixnode, date) DO UPDATE SET
+                    average_reliability = (IFNULL(average_reliability, 0) * tests + excluded.average_reliability * excluded.tests) / (tests + excluded.tests),
+                    tests = tests + excluded.tests;
+            "#,
+            date_iso_8601,
+            from,
+            to,
+        )
+        .execute(&mut tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO node_daily_summary (node_identity, is_mixnode, date, tests, average_reliability)
+                SELECT identity, false, ?, COUNT(*), AVG(reliability)
+                    FROM gateway_status
+                    JOIN gateway_details
+                    ON gateway_status.gateway_details_id = gateway_details.id
+                    WHERE timestamp >= ? AND timestamp < ?
+                    GROUP BY identity
+                ON CONFLICT (node_identity, is_mixnode, date) DO UPDATE SET
+                    average_reliability = (IFNULL(average_reliability, 0) * tests + excluded.average_reliability * excluded.tests) / (tests + excluded.tests),
+                    tests = tests + excluded.tests;
+            "#,
+            date_iso_8601,
+            from,
+            to,
+        )
+        .execute(&mut tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO node_daily_summary (node_identity, is_mixnode, date, tested_routes, failed_routes, mean_latency_ms)
+                SELECT node_identity, is_mixnode, ?, SUM(tested_routes), SUM(failed_routes), SUM(CAST(mean_latency_ms AS REAL) * tested_routes) / SUM(CASE WHEN mean_latency_ms IS NULL THEN 0 ELSE tested_routes END)
+                    FROM node_diagnostics
+                    WHERE timestamp >= ? AND timestamp < ?
+                    GROUP BY node_identity, is_mixnode
+                ON CONFLICT (node_identity, is_mixnode, date) DO UPDATE SET
+                    mean_latency_ms = CASE
+                        WHEN mean_latency_ms IS NULL THEN excluded.mean_latency_ms
+                        WHEN excluded.mean_latency_ms IS NULL THEN mean_latency_ms
+                        ELSE (mean_latency_ms * tested_routes + excluded.mean_latency_ms * excluded.tested_routes) / (tested_routes + excluded.tested_routes)
+                    END,
+                    tested_routes = tested_routes + excluded.tested_routes,
+                    failed_routes = failed_routes + excluded.failed_routes;
+            "#,
+            date_iso_8601,
+            from,
+            to,
+        )
+        .execute(&mut tx)
+        .await?;
+
+        let mut removed = 0;
+
+        // testing routes reference the monitor runs so they have to go first
+        removed += sqlx::query!(
+            r#"
+                DELETE FROM testing_route WHERE monitor_run_id IN (
+                    SELECT id FROM monitor_run WHERE timestamp >= ? AND timestamp < ?
+                );
+            "#,
+            from,
+            to,
+        )
+        .execute(&mut tx)
+        .await?
+        .rows_affected();
+
+        removed += sqlx::query!(
+            "DELETE FROM node_diagnostics WHERE timestamp >= ? AND timestamp < ?",
+            from,
+            to,
+        )
+        .execute(&mut tx)
+        .await?
+        .rows_affected();
+
+        removed += sqlx::query!(
+            "DELETE FROM mixnode_status WHERE timestamp >= ? AND timestamp < ?",
+            from,
+            to,
+        )
+        .execute(&mut tx)
+        .await?
+        .rows_affected();
+
+        removed += sqlx::query!(
+            "DELETE FROM gateway_status WHERE timestamp >= ? AND timestamp < ?",
+            from,
+            to,
+        )
+        .execute(&mut tx)
+        .await?
+        .rows_affected();
+
+        removed += sqlx::query!(
+            "DELETE FROM monitor_run WHERE timestamp >= ? AND timestamp < ?",
+            from,
+            to,
+        )
+        .execute(&mut tx)
+        .await?
+        .rows_affected();
+
+        // node results of the summaries get removed alongside them
+        removed += sqlx::query!(
+            "DELETE FROM monitor_run_summary WHERE timestamp >= ? AND timestamp < ?",
+            from,
+            to,
+        )
+        .execute(&mut tx)
+        .await?
+        .rows_affected();
+
+        tx.commit().await?;
+        Ok(removed)
+    }
+
+    /// Saves information about pruning of the raw network monitoring data.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoff`: unix timestamp before which all the raw data got removed.
+    /// * `pruned_rows`: total number of removed rows.
+    /// * `timestamp`: unix timestamp at which the pruning has finished.
+    pub(super) async fn insert_data_pruning(
+        &self,
+        cutoff: i64,
+        pruned_rows: i64,
+        timestamp: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "INSERT INTO data_pruning(cutoff, pruned_rows, timestamp) VALUES (?, ?, ?)",
+            cutoff,
+            pruned_rows,
+            timestamp,
+        )
+        .execute(&self.connection_pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Tries to obtain the most recent pruning of the raw network monitoring data.
+    pub(super) async fn get_last_data_pruning(
+        &self,
+    ) -> Result<Option<DataPruningEntry>, sqlx::Error> {
+        sqlx::query_as!(
+            DataPruningEntry,
+            "SELECT cutoff, pruned_rows, timestamp FROM data_pruning ORDER BY id DESC LIMIT 1",
+        )
+        .fetch_optional(&self.connection_pool)
+        .await
+    }
+
+    /// Gets the total size of the database alongside the space taken by its free pages.
+    pub(super) async fn get_database_size(&self) -> Result<DatabaseSize, sqlx::Error> {
+        sqlx::query_as!(
+            DatabaseSize,
+            r#"
+                SELECT
+                    page_count * page_size as "size_bytes!: i64",
+                    freelist_count * page_size as "free_bytes!: i64"
+                    FROM pragma_page_count(), pragma_page_size(), pragma_freelist_count();
+            "#
+        )
+        .fetch_one(&self.connection_pool)
+        .await
+    }
+
+    /// Gets number of rows in each of the tables holding the network monitoring data.
+    pub(super) async fn get_monitoring_tables_row_counts(
+        &self,
+    ) -> Result<Vec<TableRowCountEntry>, sqlx::Error> {
+        sqlx::query_as!(
+            TableRowCountEntry,
+            r#"
+                SELECT 'monitor_run' as "table_name!: String", COUNT(*) as "rows!: i64" FROM monitor_run
+                UNION ALL
+                SELECT 'testing_route', COUNT(*) FROM testing_route
+                UNION ALL
+                SELECT 'mixnode_status', COUNT(*) FROM mixnode_status
+                UNION ALL
+                SELECT 'gateway_status', COUNT(*) FROM gateway_status
+                UNION ALL
+                SELECT 'node_diagnostics', COUNT(*) FROM node_diagnostics
+                UNION ALL
+                SELECT 'monitor_run_summary', COUNT(*) FROM monitor_run_summary
+                UNION ALL
+                SELECT 'monitor_run_node_result', COUNT(*) FROM monitor_run_node_result
+                UNION ALL
+                SELECT 'monitor_run_daily_summary', COUNT(*) FROM monitor_run_daily_summary
+                UNION ALL
+                SELECT 'node_daily_summary', COUNT(*) FROM node_daily_summary;
+            "#
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
 }
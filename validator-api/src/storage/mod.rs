@@ -1,23 +1,32 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::network_monitor::monitor::summary_producer::NodeResult;
-use crate::network_monitor::test_route::TestRoute;
+use crate::monitor_consensus::aggregate_monitor_scores;
+use crate::network_monitor::monitor::summary_producer::{NodeDiagnostics, NodeResult, RouteResult};
 use crate::node_status_api::models::{
     GatewayStatusReport, GatewayUptimeHistory, MixnodeStatusReport, MixnodeUptimeHistory, Uptime,
     ValidatorApiStorageError,
 };
+use crate::node_status_api::utils::HistoryBuckets;
 use crate::node_status_api::{ONE_DAY, ONE_HOUR};
 use crate::storage::manager::StorageManager;
 use crate::storage::models::{
-    FailedMixnodeRewardChunk, IntervalRewarding, NodeStatus, PossiblyUnrewardedMixnode,
-    RewardingReport, TestingRoute,
+    FailedMixnodeRewardChunk, IntervalRewarding, MonitoringDataArchive, NodeStatus,
+    PossiblyUnrewardedMixnode, RewardingJob, RewardingJobStatus, RewardingReport,
+    StoredMonitorRunSummary, TestingRoute,
 };
 use rocket::fairing::{self, AdHoc};
 use rocket::{Build, Rocket};
 use sqlx::ConnectOptions;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 use time::OffsetDateTime;
+use validator_api_requests::models::{
+    DataPruning, DatabaseMetricsResponse, HistoryBucketing, LayerLatency, LayerLatencyResponse,
+    MonitorRunSummary, MonitorScore, NodeHistoryBucket, NodeLatencyResponse, NodeLatencySample,
+    NodeReliability, NodeRewardingOutcome, RewardingProgress, RewardingStatusResponse,
+    SignedMonitorRunSummary, TableRowCount,
+};
 
 pub(crate) mod manager;
 pub(crate) mod models;
@@ -29,11 +38,16 @@ pub(crate) struct ValidatorApiStorage {
 }
 
 impl ValidatorApiStorage {
-    async fn init(rocket: Rocket<Build>, database_path: PathBuf) -> fairing::Result {
+    /// Connects to the database at the specified path and runs all the pending migrations.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_path`: path to the sqlite database file.
+    pub(crate) async fn connect(database_path: &Path) -> Option<Self> {
         // TODO: we can inject here more stuff based on our validator-api global config
         // struct. Maybe different pool size or timeout intervals?
         let mut opts = sqlx::sqlite::SqliteConnectOptions::new()
-            .filename(&database_path)
+            .filename(database_path)
             .create_if_missing(true);
 
         // TODO: do we want auto_vacuum ?
@@ -44,22 +58,27 @@ impl ValidatorApiStorage {
             Ok(db) => db,
             Err(e) => {
                 error!("Failed to connect to SQLx database: {}", e);
-                return Err(rocket);
+                return None;
             }
         };
 
         if let Err(e) = sqlx::migrate!("./migrations").run(&connection_pool).await {
             error!("Failed to initialize SQLx database: {}", e);
-            return Err(rocket);
+            return None;
         }
 
         info!("Database migration finished!");
 
-        let storage = ValidatorApiStorage {
+        Some(ValidatorApiStorage {
             manager: StorageManager { connection_pool },
-        };
+        })
+    }
 
-        Ok(rocket.manage(storage))
+    async fn init(rocket: Rocket<Build>, database_path: PathBuf) -> fairing::Result {
+        match ValidatorApiStorage::connect(&database_path).await {
+            Some(storage) => Ok(rocket.manage(storage)),
+            None => Err(rocket),
+        }
     }
 
     pub(crate) fn stage(database_path: PathBuf) -> AdHoc {
@@ -261,6 +280,167 @@ impl ValidatorApiStorage {
         ))
     }
 
+    /// Obtains uptime, number of performed tests and rewarding outcomes of the particular node
+    /// within the specified time range aggregated into the provided buckets.
+    ///
+    /// Note that the raw data is pruned after the configured retention period. Daily buckets that
+    /// are no longer covered by it are served from the daily summaries created during the pruning
+    /// (or the stored historical daily uptimes), while hourly and interval buckets stay empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity (base58-encoded public key) of the node.
+    /// * `is_mixnode`: indicates whether the node is a mixnode or a gateway.
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    /// * `buckets`: the buckets the data should get aggregated into.
+    /// * `bucketing`: the granularity of the buckets.
+    pub(crate) async fn get_node_history(
+        &self,
+        identity: &str,
+        is_mixnode: bool,
+        from: i64,
+        to: i64,
+        buckets: HistoryBuckets,
+        bucketing: HistoryBucketing,
+    ) -> Result<Vec<NodeHistoryBucket>, ValidatorApiStorageError> {
+        let statuses = if is_mixnode {
+            self.manager
+                .get_mixnode_status_buckets(identity, from, to, buckets.origin, buckets.length)
+                .await
+        } else {
+            self.manager
+                .get_gateway_status_buckets(identity, from, to, buckets.origin, buckets.length)
+                .await
+        }
+        .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+
+        let monitor_runs = self
+            .manager
+            .get_monitor_runs_buckets(from, to, buckets.origin, buckets.length)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+
+        let rewarding_outcomes = self
+            .manager
+            .get_node_rewarding_outcomes(identity, is_mixnode, from, to)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+
+        let mut history = (0..buckets.count)
+            .map(|index| NodeHistoryBucket {
+                start: buckets.start(index),
+                end: buckets.end(index),
+                uptime: None,
+                node_tests: 0,
+                monitor_runs: 0,
+                rewarding: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+
+        for status in statuses {
+            if let Some(bucket) = history.get_mut(status.bucket as usize) {
+                bucket.uptime = Some(status.average_reliability.round() as u8);
+                bucket.node_tests = status.tests as u32;
+            }
+        }
+
+        for runs in monitor_runs {
+            if let Some(bucket) = history.get_mut(runs.bucket as usize) {
+                bucket.monitor_runs = runs.runs as u32;
+            }
+        }
+
+        for entry in rewarding_outcomes {
+            let status = match RewardingJobStatus::from_u8(entry.status) {
+                Some(status) => status,
+                None => {
+                    error!("Encountered unknown rewarding job status {}", entry.status);
+                    continue;
+                }
+            };
+            if let Some(index) = buckets.index_of(entry.interval_end_timestamp) {
+                history[index].rewarding.push(NodeRewardingOutcome {
+                    interval_start: entry.interval_start_timestamp,
+                    interval_end: entry.interval_end_timestamp,
+                    outcome: status.into(),
+                })
+            }
+        }
+
+        if bucketing == HistoryBucketing::Daily {
+            // the raw data of whole days gets pruned at once, so any bucket without it is either
+            // fully covered by the daily summaries or not covered by any data at all
+            let (from_date, to_date) = match (
+                OffsetDateTime::from_unix_timestamp(from),
+                OffsetDateTime::from_unix_timestamp(to),
+            ) {
+                (Ok(from), Ok(to)) => (from.date().to_string(), to.date().to_string()),
+                _ => return Ok(history),
+            };
+
+            let node_summaries = self
+                .manager
+                .get_node_daily_summaries(identity, is_mixnode, &from_date, &to_date)
+                .await
+                .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?
+                .into_iter()
+                .map(|summary| (summary.date.clone(), summary))
+                .collect::<HashMap<_, _>>();
+
+            let monitor_run_summaries = self
+                .manager
+                .get_monitor_run_daily_summaries(&from_date, &to_date)
+                .await
+                .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?
+                .into_iter()
+                .map(|summary| (summary.date, summary.monitor_runs))
+                .collect::<HashMap<_, _>>();
+
+            for bucket in history.iter_mut() {
+                let date = match OffsetDateTime::from_unix_timestamp(bucket.start) {
+                    Ok(datetime) => datetime.date().to_string(),
+                    Err(_) => continue,
+                };
+                if bucket.node_tests == 0 {
+                    if let Some(summary) = node_summaries.get(&date) {
+                        bucket.uptime = summary
+                            .average_reliability
+                            .map(|reliability| reliability.round() as u8);
+                        bucket.node_tests = summary.tests as u32;
+                    }
+                }
+                if bucket.monitor_runs == 0 {
+                    if let Some(runs) = monitor_run_summaries.get(&date) {
+                        bucket.monitor_runs = *runs as u32;
+                    }
+                }
+            }
+
+            let historical_uptimes = if is_mixnode {
+                self.manager.get_mixnode_historical_uptimes(identity).await
+            } else {
+                self.manager.get_gateway_historical_uptimes(identity).await
+            }
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?
+            .into_iter()
+            .map(|historical| (historical.date, historical.uptime))
+            .collect::<HashMap<_, _>>();
+
+            for bucket in history.iter_mut().filter(|bucket| bucket.uptime.is_none()) {
+                let date = match OffsetDateTime::from_unix_timestamp(bucket.start) {
+                    Ok(datetime) => datetime.date().to_string(),
+                    Err(_) => continue,
+                };
+                if let Some(uptime) = historical_uptimes.get(&date) {
+                    bucket.uptime = Some(uptime.u8());
+                }
+            }
+        }
+
+        Ok(history)
+    }
+
     /// Based on the data available in the validator API, determines the average uptime of particular
     /// mixnode during the specified time interval.
     ///
@@ -309,6 +489,54 @@ impl ValidatorApiStorage {
         Ok(uptime)
     }
 
+    /// Based on the data available in the validator API, determines the average uptime of particular
+    /// gateway during the specified time interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: base58-encoded identity of the gateway.
+    /// * `start`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `end`: unix timestamp indicating the upper bound interval of the selection.
+    pub(crate) async fn get_average_gateway_uptime_in_interval(
+        &self,
+        identity: &str,
+        start: i64,
+        end: i64,
+    ) -> Result<Uptime, ValidatorApiStorageError> {
+        let gateway_database_id = match self
+            .manager
+            .get_gateway_id(identity)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?
+        {
+            Some(id) => id,
+            None => return Ok(Uptime::zero()),
+        };
+
+        let monitor_runs = self.get_monitor_runs_count(start, end).await?;
+        let gateway_statuses = self
+            .manager
+            .get_gateway_statuses_by_id(gateway_database_id, start, end)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+
+        let mut total: f32 = 0.0;
+        for gateway_status in gateway_statuses {
+            total += gateway_status.reliability as f32;
+        }
+
+        let uptime = match Uptime::from_uptime_sum(total, monitor_runs) {
+            Ok(uptime) => uptime,
+            Err(_) => {
+                // this should really ever happen...
+                error!("gateway {} has uptime > 100!", identity);
+                Uptime::default()
+            }
+        };
+
+        Ok(uptime)
+    }
+
     /// Obtain status reports of mixnodes that were active in the specified time interval.
     ///
     /// # Arguments
@@ -397,17 +625,20 @@ impl ValidatorApiStorage {
         Ok(reports)
     }
 
-    /// Saves information about test route used during the network monitor run to the database.
+    /// Saves information about test route used during the network monitor run, alongside its
+    /// reliability and latency, to the database.
     ///
     /// # Arguments
     ///
     /// * `monitor_run_id` id (as saved in the database) of the associated network monitor test run.
-    /// * `test_route`: one of the test routes used during network testing.
+    /// * `route_result`: result of one of the test routes used during network testing.
     async fn insert_test_route(
         &self,
         monitor_run_id: i64,
-        test_route: TestRoute,
+        route_result: RouteResult,
     ) -> Result<(), ValidatorApiStorageError> {
+        let test_route = route_result.route;
+
         // we MUST have those entries in the database, otherwise the route wouldn't have been chosen
         // in the first place
         let layer1_mix_id = self
@@ -445,6 +676,10 @@ impl ValidatorApiStorage {
                 layer2_mix_id,
                 layer3_mix_id,
                 monitor_run_id,
+                reliability: route_result.reliability,
+                latency_ms: route_result
+                    .latency
+                    .map(|latency| latency.as_millis() as i64),
             })
             .await
             .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
@@ -523,11 +758,13 @@ impl ValidatorApiStorage {
     /// * `mixnode_results`:
     /// * `gateway_results`:
     /// * `route_results`:
+    /// * `node_diagnostics`:
     pub(crate) async fn insert_monitor_run_results(
         &self,
         mixnode_results: Vec<NodeResult>,
         gateway_results: Vec<NodeResult>,
-        test_routes: Vec<TestRoute>,
+        route_results: Vec<RouteResult>,
+        node_diagnostics: Vec<NodeDiagnostics>,
     ) -> Result<(), ValidatorApiStorageError> {
         info!("Submitting new node results to the database. There are {} mixnode results and {} gateway results", mixnode_results.len(), gateway_results.len());
 
@@ -549,13 +786,99 @@ impl ValidatorApiStorage {
             .await
             .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
 
-        for test_route in test_routes {
-            self.insert_test_route(monitor_run_id, test_route).await?;
+        self.manager
+            .submit_node_diagnostics(monitor_run_id, now, node_diagnostics)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+
+        for route_result in route_results {
+            self.insert_test_route(monitor_run_id, route_result).await?;
         }
 
         Ok(())
     }
 
+    /// Obtains latency diagnostics of the particular node from all network monitor test runs
+    /// since the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: base58-encoded identity of the node.
+    /// * `is_mixnode`: indicates whether the node is a mixnode or a gateway.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    pub(crate) async fn get_node_latency(
+        &self,
+        identity: &str,
+        is_mixnode: bool,
+        since: i64,
+    ) -> Result<NodeLatencyResponse, ValidatorApiStorageError> {
+        let entries = self
+            .manager
+            .get_node_diagnostics_since(identity, is_mixnode, since)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+
+        fn mean(values: impl Iterator<Item = i64>) -> Option<i64> {
+            let (sum, count) = values.fold((0i64, 0i64), |(sum, count), v| (sum + v, count + 1));
+            if count == 0 {
+                None
+            } else {
+                Some(sum / count)
+            }
+        }
+
+        let mean_latency_ms = mean(entries.iter().filter_map(|entry| entry.mean_latency_ms));
+        let mean_excess_latency_ms =
+            mean(entries.iter().filter_map(|entry| entry.excess_latency_ms));
+
+        let samples = entries
+            .into_iter()
+            .map(|entry| NodeLatencySample {
+                timestamp: entry.timestamp,
+                mean_latency_ms: entry.mean_latency_ms,
+                excess_latency_ms: entry.excess_latency_ms,
+                tested_routes: entry.tested_routes as u32,
+                failed_routes: entry.failed_routes as u32,
+            })
+            .collect();
+
+        Ok(NodeLatencyResponse {
+            identity: identity.to_string(),
+            since,
+            mean_latency_ms,
+            mean_excess_latency_ms,
+            samples,
+        })
+    }
+
+    /// Obtains average latencies of mixnodes in each of the layers since the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    pub(crate) async fn get_layer_latencies(
+        &self,
+        since: i64,
+    ) -> Result<LayerLatencyResponse, ValidatorApiStorageError> {
+        let layers = self
+            .manager
+            .get_layer_latencies_since(since)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?
+            .into_iter()
+            .map(|entry| LayerLatency {
+                layer: entry.layer,
+                nodes: entry.nodes as u32,
+                mean_latency_ms: entry.mean_latency_ms.map(|latency| latency.round() as i64),
+                mean_excess_latency_ms: entry
+                    .mean_excess_latency_ms
+                    .map(|latency| latency.round() as i64),
+            })
+            .collect();
+
+        Ok(LayerLatencyResponse { since, layers })
+    }
+
     /// Obtains number of network monitor test runs that have occurred within the specified interval.
     ///
     /// # Arguments
@@ -580,6 +903,223 @@ impl ValidatorApiStorage {
         Ok(run_count as usize)
     }
 
+    /// Inserts a signed summary of a network monitor test run, either produced by our own
+    /// monitor or obtained from one of the peers. Returns `false` if the summary already existed.
+    ///
+    /// # Arguments
+    ///
+    /// * `signed_summary`: the summary alongside the signature of the monitor that produced it.
+    pub(crate) async fn insert_signed_monitor_run_summary(
+        &self,
+        signed_summary: &SignedMonitorRunSummary,
+    ) -> Result<bool, ValidatorApiStorageError> {
+        self.manager
+            .insert_monitor_run_summary(&signed_summary.summary, &signed_summary.signature)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
+    }
+
+    /// Obtains all signed test run summaries of the specified network monitor that were created
+    /// after the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor_identity`: base58-encoded identity of the network monitor.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    pub(crate) async fn get_signed_monitor_run_summaries(
+        &self,
+        monitor_identity: &str,
+        since: i64,
+    ) -> Result<Vec<SignedMonitorRunSummary>, ValidatorApiStorageError> {
+        let stored_summaries = self
+            .manager
+            .get_monitor_run_summaries_since(monitor_identity, since)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+
+        self.attach_monitor_run_node_results(stored_summaries).await
+    }
+
+    /// Obtains node results of each of the provided stored test run summaries and combines them
+    /// into complete signed summaries.
+    ///
+    /// # Arguments
+    ///
+    /// * `stored_summaries`: test run summaries as saved in the database.
+    async fn attach_monitor_run_node_results(
+        &self,
+        stored_summaries: Vec<StoredMonitorRunSummary>,
+    ) -> Result<Vec<SignedMonitorRunSummary>, ValidatorApiStorageError> {
+        let mut signed_summaries = Vec::with_capacity(stored_summaries.len());
+        for stored in stored_summaries {
+            let node_results = self
+                .manager
+                .get_monitor_run_node_results(stored.id)
+                .await
+                .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+
+            let mut mixnode_results = Vec::new();
+            let mut gateway_results = Vec::new();
+            for node_result in node_results {
+                let reliability = NodeReliability {
+                    identity: node_result.node_identity,
+                    reliability: node_result.reliability,
+                };
+                if node_result.is_mixnode {
+                    mixnode_results.push(reliability)
+                } else {
+                    gateway_results.push(reliability)
+                }
+            }
+
+            signed_summaries.push(SignedMonitorRunSummary {
+                summary: MonitorRunSummary {
+                    monitor_identity: stored.monitor_identity,
+                    timestamp: stored.timestamp,
+                    mixnode_results,
+                    gateway_results,
+                },
+                signature: stored.signature,
+            })
+        }
+
+        Ok(signed_summaries)
+    }
+
+    /// Obtains timestamp of the most recent test run summary of the specified network monitor.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor_identity`: base58-encoded identity of the network monitor.
+    pub(crate) async fn get_latest_monitor_run_summary_timestamp(
+        &self,
+        monitor_identity: &str,
+    ) -> Result<Option<i64>, ValidatorApiStorageError> {
+        self.manager
+            .get_latest_monitor_run_summary_timestamp(monitor_identity)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
+    }
+
+    /// Based on the test run summaries of the trusted network monitors, determines the score
+    /// each of them has assigned to the particular node during the specified time interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: base58-encoded identity of the node.
+    /// * `is_mixnode`: indicates whether the node is a mixnode or a gateway.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `until`: unix timestamp indicating the upper bound interval of the selection.
+    /// * `minimum_monitor_runs`: number of test runs a monitor has to have performed in the
+    ///    interval for its score to be included in the aggregate.
+    /// * `trusted_monitors`: identities of the network monitors whose results should be used.
+    pub(crate) async fn get_node_monitor_scores(
+        &self,
+        identity: &str,
+        is_mixnode: bool,
+        since: i64,
+        until: i64,
+        minimum_monitor_runs: usize,
+        trusted_monitors: &[String],
+    ) -> Result<Vec<MonitorScore>, ValidatorApiStorageError> {
+        let mut runs_counts = self
+            .manager
+            .get_monitor_run_summaries_count(since, until)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+        runs_counts.retain(|runs_count| trusted_monitors.contains(&runs_count.monitor_identity));
+
+        let reliabilities = self
+            .manager
+            .get_monitor_node_reliabilities(identity, is_mixnode, since, until)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+
+        let mut totals: BTreeMap<String, f32> = BTreeMap::new();
+        for reliability in reliabilities {
+            *totals.entry(reliability.monitor_identity).or_default() +=
+                reliability.reliability as f32;
+        }
+
+        let mut scores = Vec::with_capacity(runs_counts.len());
+        for runs_count in runs_counts {
+            // this should never be negative, but let's not panic if somehow it was
+            let monitor_runs = runs_count.runs.max(0) as usize;
+            let total = totals
+                .get(&runs_count.monitor_identity)
+                .copied()
+                .unwrap_or_default();
+
+            let uptime = match Uptime::from_uptime_sum(total, monitor_runs) {
+                Ok(uptime) => uptime,
+                Err(_) => {
+                    // this should really ever happen...
+                    error!(
+                        "monitor {} reported uptime > 100 for {}!",
+                        runs_count.monitor_identity, identity
+                    );
+                    Uptime::default()
+                }
+            };
+
+            scores.push(MonitorScore {
+                monitor_identity: runs_count.monitor_identity,
+                monitor_runs,
+                uptime: uptime.u8(),
+                included: monitor_runs >= minimum_monitor_runs,
+            })
+        }
+        scores.sort_by(|a, b| a.monitor_identity.cmp(&b.monitor_identity));
+
+        Ok(scores)
+    }
+
+    /// Determines uptime of particular node during the specified time interval as the median
+    /// of uptimes reported by all trusted network monitors with sufficient number of test runs.
+    /// If there are no such monitors, it falls back to only using the local monitor data.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: base58-encoded identity of the node.
+    /// * `is_mixnode`: indicates whether the node is a mixnode or a gateway.
+    /// * `start`: unix timestamp indicating the lower bound interval of the selection.
+    /// * `end`: unix timestamp indicating the upper bound interval of the selection.
+    /// * `minimum_monitor_runs`: number of test runs a monitor has to have performed in the
+    ///    interval for its score to be taken into consideration.
+    /// * `trusted_monitors`: identities of the network monitors whose results should be used.
+    pub(crate) async fn get_aggregated_uptime_in_interval(
+        &self,
+        identity: &str,
+        is_mixnode: bool,
+        start: i64,
+        end: i64,
+        minimum_monitor_runs: usize,
+        trusted_monitors: &[String],
+    ) -> Result<Uptime, ValidatorApiStorageError> {
+        let scores = self
+            .get_node_monitor_scores(
+                identity,
+                is_mixnode,
+                start,
+                end,
+                minimum_monitor_runs,
+                trusted_monitors,
+            )
+            .await?;
+
+        if let Some(uptime) = aggregate_monitor_scores(&scores) {
+            return Ok(uptime);
+        }
+
+        if is_mixnode {
+            self.get_average_mixnode_uptime_in_interval(identity, start, end)
+                .await
+        } else {
+            self.get_average_gateway_uptime_in_interval(identity, start, end)
+                .await
+        }
+    }
+
     /// Given lists of reports of all monitor-active mixnodes and gateways, inserts the data into the
     /// historical uptime tables. This method is called at a 24h timer.
     ///
@@ -657,26 +1197,155 @@ impl ValidatorApiStorage {
             .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
     }
 
-    /// Removes all ipv4 and ipv6 statuses for all mixnodes and gateways that are older than the
-    /// provided timestamp. This method is called at every reward cycle.
+    /// Obtains timestamp of the oldest raw network monitoring data that is still stored.
+    pub(crate) async fn get_oldest_raw_data_timestamp(
+        &self,
+    ) -> Result<Option<i64>, ValidatorApiStorageError> {
+        self.manager
+            .get_oldest_raw_data_timestamp()
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
+    }
+
+    /// Obtains all raw network monitoring data gathered within the specified time range,
+    /// so that it could be archived before getting pruned.
     ///
     /// # Arguments
     ///
-    /// * `until`: timestamp specifying the purge cutoff.
-    pub(crate) async fn purge_old_statuses(
+    /// * `date_iso_8601`: date the archived data belongs to.
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    pub(crate) async fn get_monitoring_data_archive(
         &self,
-        until: i64,
-    ) -> Result<(), ValidatorApiStorageError> {
-        self.manager
-            .purge_old_mixnode_statuses(until)
+        date_iso_8601: &str,
+        from: i64,
+        to: i64,
+    ) -> Result<MonitoringDataArchive, ValidatorApiStorageError> {
+        let monitor_runs = self
+            .manager
+            .get_monitor_runs_in_range(from, to)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+        let mixnode_statuses = self
+            .manager
+            .get_all_mixnode_statuses_in_range(from, to)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+        let gateway_statuses = self
+            .manager
+            .get_all_gateway_statuses_in_range(from, to)
             .await
             .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+        let testing_routes = self
+            .manager
+            .get_testing_routes_in_range(from, to)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+        let node_diagnostics = self
+            .manager
+            .get_all_node_diagnostics_in_range(from, to)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+        let stored_summaries = self
+            .manager
+            .get_all_monitor_run_summaries_in_range(from, to)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+        let monitor_run_summaries = self
+            .attach_monitor_run_node_results(stored_summaries)
+            .await?;
+
+        Ok(MonitoringDataArchive {
+            date: date_iso_8601.to_owned(),
+            monitor_runs,
+            mixnode_statuses,
+            gateway_statuses,
+            testing_routes,
+            node_diagnostics,
+            monitor_run_summaries,
+        })
+    }
+
+    /// Rolls all raw network monitoring data from the specified time range into the daily
+    /// summaries and removes it. Returns the number of removed rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `date_iso_8601`: date of the daily summaries the data should be rolled into.
+    /// * `from`: unix timestamp indicating the lower bound (inclusive) of the range.
+    /// * `to`: unix timestamp indicating the upper bound (exclusive) of the range.
+    pub(crate) async fn prune_raw_monitoring_data(
+        &self,
+        date_iso_8601: &str,
+        from: i64,
+        to: i64,
+    ) -> Result<u64, ValidatorApiStorageError> {
         self.manager
-            .purge_old_gateway_statuses(until)
+            .roll_up_and_remove_raw_data(date_iso_8601, from, to)
             .await
             .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
     }
 
+    /// Saves information about finished pruning of the raw network monitoring data.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoff`: unix timestamp before which all the raw data got removed.
+    /// * `pruned_rows`: total number of removed rows.
+    pub(crate) async fn insert_data_pruning(
+        &self,
+        cutoff: i64,
+        pruned_rows: u64,
+    ) -> Result<(), ValidatorApiStorageError> {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        self.manager
+            .insert_data_pruning(cutoff, pruned_rows as i64, now)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
+    }
+
+    /// Obtains the size of the database, the number of rows in each of the monitoring tables,
+    /// alongside information about the pruning of the raw data.
+    pub(crate) async fn get_database_metrics(
+        &self,
+    ) -> Result<DatabaseMetricsResponse, ValidatorApiStorageError> {
+        let size = self
+            .manager
+            .get_database_size()
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+        let tables = self
+            .manager
+            .get_monitoring_tables_row_counts()
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?
+            .into_iter()
+            .map(|entry| TableRowCount {
+                table: entry.table_name,
+                rows: entry.rows,
+            })
+            .collect();
+        let oldest_raw_data = self.get_oldest_raw_data_timestamp().await?;
+        let last_pruning = self
+            .manager
+            .get_last_data_pruning()
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?
+            .map(|entry| DataPruning {
+                cutoff: entry.cutoff,
+                pruned_rows: entry.pruned_rows,
+                timestamp: entry.timestamp,
+            });
+
+        Ok(DatabaseMetricsResponse {
+            size_bytes: size.size_bytes,
+            free_bytes: size.free_bytes,
+            tables,
+            oldest_raw_data,
+            last_pruning,
+        })
+    }
+
     ////////////////////////////////////////////////////////////////////////
     // TODO: Should all of the below really return a "ValidatorApiStorageError" Errors?
     ////////////////////////////////////////////////////////////////////////
@@ -699,17 +1368,17 @@ impl ValidatorApiStorage {
             .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
     }
 
-    // /// Tries to obtain the most recent interval rewarding entry currently stored.
-    // ///
-    // /// Returns None if no data exists.
-    // pub(crate) async fn get_most_recent_interval_rewarding_entry(
-    //     &self,
-    // ) -> Result<Option<IntervalRewarding>, ValidatorApiStorageError> {
-    //     self.manager
-    //         .get_most_recent_interval_rewarding_entry()
-    //         .await
-    //         .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
-    // }
+    /// Tries to obtain the most recent interval rewarding entry currently stored.
+    ///
+    /// Returns None if no data exists.
+    pub(crate) async fn get_most_recent_interval_rewarding_entry(
+        &self,
+    ) -> Result<Option<IntervalRewarding>, ValidatorApiStorageError> {
+        self.manager
+            .get_most_recent_interval_rewarding_entry()
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
+    }
 
     /// Tries to obtain the interval rewarding entry that has the provided timestamp.
     ///
@@ -749,6 +1418,120 @@ impl ValidatorApiStorage {
             .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
     }
 
+    /// Inserts rewarding jobs of all the nodes to be rewarded in the particular interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval_rewarding_id`: id of the interval rewarding the jobs belong to.
+    /// * `jobs`: jobs to insert.
+    pub(crate) async fn insert_rewarding_jobs(
+        &self,
+        interval_rewarding_id: i64,
+        jobs: Vec<RewardingJob>,
+    ) -> Result<(), ValidatorApiStorageError> {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+
+        self.manager
+            .insert_rewarding_jobs(interval_rewarding_id, jobs, now)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
+    }
+
+    /// Gets all rewarding jobs of the particular interval rewarding.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval_rewarding_id`: id of the interval rewarding.
+    pub(crate) async fn get_rewarding_jobs(
+        &self,
+        interval_rewarding_id: i64,
+    ) -> Result<Vec<RewardingJob>, ValidatorApiStorageError> {
+        self.manager
+            .get_rewarding_jobs(interval_rewarding_id)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
+    }
+
+    /// Updates status of the rewarding jobs of the specified nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval_rewarding_id`: id of the interval rewarding the jobs belong to.
+    /// * `identities`: identities of the nodes which jobs should be updated.
+    /// * `is_mixnode`: indicates whether the nodes are mixnodes or gateways.
+    /// * `status`: new status of the jobs.
+    /// * `estimated_batch_gas`: gas estimated for the batch the nodes were sent in, if applicable.
+    /// * `error_message`: error encountered while rewarding the nodes, if applicable.
+    pub(crate) async fn update_rewarding_jobs_status(
+        &self,
+        interval_rewarding_id: i64,
+        identities: &[String],
+        is_mixnode: bool,
+        status: RewardingJobStatus,
+        estimated_batch_gas: Option<u64>,
+        error_message: Option<String>,
+    ) -> Result<(), ValidatorApiStorageError> {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+
+        self.manager
+            .update_rewarding_jobs_status(
+                interval_rewarding_id,
+                identities,
+                is_mixnode,
+                status.into(),
+                estimated_batch_gas.map(|gas| gas as i64),
+                error_message,
+                now,
+            )
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)
+    }
+
+    /// Obtains progress of the most recent interval rewarding.
+    ///
+    /// Returns None if rewarding has never been performed.
+    pub(crate) async fn get_current_rewarding_status(
+        &self,
+    ) -> Result<Option<RewardingStatusResponse>, ValidatorApiStorageError> {
+        let entry = match self.get_most_recent_interval_rewarding_entry().await? {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+
+        let counts = self
+            .manager
+            .get_rewarding_jobs_counts(entry.id)
+            .await
+            .map_err(|_| ValidatorApiStorageError::InternalDatabaseError)?;
+
+        let mut mixnodes = RewardingProgress::default();
+        let mut gateways = RewardingProgress::default();
+        for count in counts {
+            let progress = if count.is_mixnode {
+                &mut mixnodes
+            } else {
+                &mut gateways
+            };
+            let count_value = count.count as u32;
+            progress.total += count_value;
+            match RewardingJobStatus::from_u8(count.status) {
+                Some(RewardingJobStatus::Pending) => progress.pending += count_value,
+                Some(RewardingJobStatus::Submitted) => progress.submitted += count_value,
+                Some(RewardingJobStatus::Rewarded) => progress.rewarded += count_value,
+                Some(RewardingJobStatus::Failed) => progress.failed += count_value,
+                None => error!("Encountered unknown rewarding job status {}", count.status),
+            }
+        }
+
+        Ok(Some(RewardingStatusResponse {
+            interval_start: entry.interval_start_timestamp,
+            interval_end: entry.interval_end_timestamp,
+            finished: entry.finished,
+            mixnodes,
+            gateways,
+        }))
+    }
+
     /// Inserts new failed mixnode reward chunk information into the database.
     /// Returns id of the newly created entry.
     ///
@@ -1,6 +1,9 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use serde::Serialize;
+use validator_api_requests::models::{RewardingOutcome, SignedMonitorRunSummary};
+
 // Internally used struct to catch results from the database to calculate uptimes for given mixnode/gateway
 pub(crate) struct NodeStatus {
     pub(crate) timestamp: i64,
@@ -20,14 +23,13 @@ pub(crate) struct TestingRoute {
     pub(crate) layer2_mix_id: i64,
     pub(crate) layer3_mix_id: i64,
     pub(crate) monitor_run_id: i64,
+    pub(crate) reliability: u8,
+    pub(crate) latency_ms: Option<i64>,
 }
 
 pub(crate) struct IntervalRewarding {
-    #[allow(dead_code)]
     pub(crate) id: i64,
-    #[allow(dead_code)]
     pub(crate) interval_start_timestamp: i64,
-    #[allow(dead_code)]
     pub(crate) interval_end_timestamp: i64,
     pub(crate) finished: bool,
 }
@@ -39,6 +41,10 @@ pub(crate) struct RewardingReport {
     pub(crate) eligible_mixnodes: i64,
 
     pub(crate) possibly_unrewarded_mixnodes: i64,
+
+    pub(crate) eligible_gateways: i64,
+
+    pub(crate) possibly_unrewarded_gateways: i64,
 }
 
 pub(crate) struct FailedMixnodeRewardChunk {
@@ -53,3 +59,220 @@ pub(crate) struct PossiblyUnrewardedMixnode {
     pub(crate) identity: String,
     pub(crate) uptime: u8,
 }
+
+/// Progress of the rewarding of particular node within an interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RewardingJobStatus {
+    /// No rewarding transaction has been sent for the node yet.
+    Pending = 0,
+
+    /// Rewarding transaction has been sent, but its outcome is not yet known.
+    Submitted = 1,
+
+    /// The node, alongside all its delegators, got rewarded.
+    Rewarded = 2,
+
+    /// Rewarding transaction has failed and the node might have not been rewarded.
+    Failed = 3,
+}
+
+impl RewardingJobStatus {
+    pub(crate) fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(RewardingJobStatus::Pending),
+            1 => Some(RewardingJobStatus::Submitted),
+            2 => Some(RewardingJobStatus::Rewarded),
+            3 => Some(RewardingJobStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+impl From<RewardingJobStatus> for u8 {
+    fn from(status: RewardingJobStatus) -> Self {
+        status as u8
+    }
+}
+
+impl From<RewardingJobStatus> for RewardingOutcome {
+    fn from(status: RewardingJobStatus) -> Self {
+        match status {
+            RewardingJobStatus::Pending => RewardingOutcome::Pending,
+            RewardingJobStatus::Submitted => RewardingOutcome::Submitted,
+            RewardingJobStatus::Rewarded => RewardingOutcome::Rewarded,
+            RewardingJobStatus::Failed => RewardingOutcome::Failed,
+        }
+    }
+}
+
+pub(crate) struct RewardingJob {
+    pub(crate) node_identity: String,
+    pub(crate) is_mixnode: bool,
+    pub(crate) total_delegations: i64,
+
+    // json-encoded `NodeRewardParams` or `GatewayRewardParams`
+    pub(crate) reward_params: String,
+    pub(crate) status: u8,
+}
+
+pub(crate) struct RewardingJobsCount {
+    pub(crate) is_mixnode: bool,
+    pub(crate) status: u8,
+    pub(crate) count: i32,
+}
+
+pub(crate) struct StoredMonitorRunSummary {
+    pub(crate) id: i64,
+    pub(crate) monitor_identity: String,
+    pub(crate) timestamp: i64,
+    pub(crate) signature: String,
+}
+
+pub(crate) struct MonitorRunNodeResult {
+    pub(crate) node_identity: String,
+    pub(crate) is_mixnode: bool,
+    pub(crate) reliability: u8,
+}
+
+// Internally used struct to catch number of test runs performed by particular monitor
+pub(crate) struct MonitorRunsCount {
+    pub(crate) monitor_identity: String,
+    pub(crate) runs: i32,
+}
+
+// Internally used struct to catch reliability of particular node as reported by given monitor
+pub(crate) struct MonitorNodeReliability {
+    pub(crate) monitor_identity: String,
+    pub(crate) reliability: u8,
+}
+
+// Internally used struct to catch latency diagnostics of particular node from a single monitor run
+pub(crate) struct NodeDiagnosticsEntry {
+    pub(crate) timestamp: i64,
+    pub(crate) mean_latency_ms: Option<i64>,
+    pub(crate) excess_latency_ms: Option<i64>,
+    pub(crate) tested_routes: i64,
+    pub(crate) failed_routes: i64,
+}
+
+// Internally used struct to catch average latencies of all nodes in particular mix layer
+pub(crate) struct LayerLatencyEntry {
+    pub(crate) layer: u8,
+    pub(crate) nodes: i32,
+    pub(crate) mean_latency_ms: Option<f64>,
+    pub(crate) mean_excess_latency_ms: Option<f64>,
+}
+
+// Internally used struct to catch aggregated reliability of particular node within a time bucket
+pub(crate) struct NodeStatusBucket {
+    pub(crate) bucket: i64,
+    pub(crate) average_reliability: f64,
+    pub(crate) tests: i32,
+}
+
+// Internally used struct to catch number of our own monitor runs within a time bucket
+pub(crate) struct MonitorRunsBucket {
+    pub(crate) bucket: i64,
+    pub(crate) runs: i32,
+}
+
+// Internally used struct to catch the daily summary of the pruned data of particular node
+pub(crate) struct NodeDailySummaryEntry {
+    pub(crate) date: String,
+    pub(crate) tests: i64,
+    pub(crate) average_reliability: Option<f64>,
+}
+
+// Internally used struct to catch the daily summary of the pruned monitor runs
+pub(crate) struct MonitorRunDailySummaryEntry {
+    pub(crate) date: String,
+    pub(crate) monitor_runs: i64,
+}
+
+// Internally used struct to catch rewarding outcome of particular node in given interval
+pub(crate) struct NodeRewardingOutcomeEntry {
+    pub(crate) interval_start_timestamp: i64,
+    pub(crate) interval_end_timestamp: i64,
+    pub(crate) status: u8,
+}
+
+// Internally used struct to catch the most recent pruning of the raw monitoring data
+pub(crate) struct DataPruningEntry {
+    pub(crate) cutoff: i64,
+    pub(crate) pruned_rows: i64,
+    pub(crate) timestamp: i64,
+}
+
+// Internally used struct to catch the size of the database file
+pub(crate) struct DatabaseSize {
+    pub(crate) size_bytes: i64,
+    pub(crate) free_bytes: i64,
+}
+
+// Internally used struct to catch number of rows in particular table
+pub(crate) struct TableRowCountEntry {
+    pub(crate) table_name: String,
+    pub(crate) rows: i64,
+}
+
+#[derive(Serialize)]
+pub(crate) struct ArchivedMonitorRun {
+    pub(crate) id: i64,
+    pub(crate) timestamp: i64,
+}
+
+#[derive(Serialize)]
+pub(crate) struct ArchivedNodeStatus {
+    pub(crate) identity: String,
+    pub(crate) reliability: u8,
+    pub(crate) timestamp: i64,
+}
+
+#[derive(Serialize)]
+pub(crate) struct ArchivedTestingRoute {
+    pub(crate) monitor_run_id: i64,
+    pub(crate) gateway: String,
+    pub(crate) layer1_mix: String,
+    pub(crate) layer2_mix: String,
+    pub(crate) layer3_mix: String,
+    pub(crate) reliability: u8,
+    pub(crate) latency_ms: Option<i64>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct ArchivedNodeDiagnostics {
+    pub(crate) monitor_run_id: i64,
+    pub(crate) node_identity: String,
+    pub(crate) is_mixnode: bool,
+    pub(crate) layer: Option<u8>,
+    pub(crate) mean_latency_ms: Option<i64>,
+    pub(crate) excess_latency_ms: Option<i64>,
+    pub(crate) tested_routes: i64,
+    pub(crate) failed_routes: i64,
+    pub(crate) timestamp: i64,
+}
+
+/// All raw network monitoring data gathered during a single (UTC) day, as exported
+/// before it gets pruned from the database.
+#[derive(Serialize)]
+pub(crate) struct MonitoringDataArchive {
+    // 'YYYY-MM-DD'
+    pub(crate) date: String,
+    pub(crate) monitor_runs: Vec<ArchivedMonitorRun>,
+    pub(crate) mixnode_statuses: Vec<ArchivedNodeStatus>,
+    pub(crate) gateway_statuses: Vec<ArchivedNodeStatus>,
+    pub(crate) testing_routes: Vec<ArchivedTestingRoute>,
+    pub(crate) node_diagnostics: Vec<ArchivedNodeDiagnostics>,
+    pub(crate) monitor_run_summaries: Vec<SignedMonitorRunSummary>,
+}
+
+impl MonitoringDataArchive {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.monitor_runs.is_empty()
+            && self.mixnode_statuses.is_empty()
+            && self.gateway_statuses.is_empty()
+            && self.testing_routes.is_empty()
+            && self.node_diagnostics.is_empty()
+            && self.monitor_run_summaries.is_empty()
+    }
+}
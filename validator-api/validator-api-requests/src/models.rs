@@ -3,6 +3,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -69,3 +70,295 @@ impl fmt::Display for InclusionProbabilityResponse {
         )
     }
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct NodeReliability {
+    pub identity: String,
+    pub reliability: u8,
+}
+
+/// Results of a single network monitor test run, as produced by the monitor identified
+/// by `monitor_identity`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct MonitorRunSummary {
+    /// Base58-encoded ed25519 public key of the monitor that performed the test run.
+    pub monitor_identity: String,
+    pub timestamp: i64,
+    pub mixnode_results: Vec<NodeReliability>,
+    pub gateway_results: Vec<NodeReliability>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct SignedMonitorRunSummary {
+    pub summary: MonitorRunSummary,
+
+    /// Base58-encoded signature of the monitor on the json-serialized `summary`.
+    pub signature: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct MonitorScore {
+    pub monitor_identity: String,
+    pub monitor_runs: usize,
+    pub uptime: u8,
+
+    /// Indicates whether this monitor has produced enough test runs in the queried interval
+    /// for its score to be included in the aggregate.
+    pub included: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct NodeMonitorScoresResponse {
+    pub identity: String,
+    pub since: i64,
+    pub until: i64,
+    pub monitor_scores: Vec<MonitorScore>,
+    pub aggregated_uptime: Option<u8>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct NodeLatencySample {
+    pub timestamp: i64,
+    pub mean_latency_ms: Option<i64>,
+
+    /// Latency introduced by the node on top of the typical latency of the test route.
+    pub excess_latency_ms: Option<i64>,
+    pub tested_routes: u32,
+    pub failed_routes: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct NodeLatencyResponse {
+    pub identity: String,
+    pub since: i64,
+    pub mean_latency_ms: Option<i64>,
+    pub mean_excess_latency_ms: Option<i64>,
+    pub samples: Vec<NodeLatencySample>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct LayerLatency {
+    pub layer: u8,
+    pub nodes: u32,
+    pub mean_latency_ms: Option<i64>,
+    pub mean_excess_latency_ms: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct LayerLatencyResponse {
+    pub since: i64,
+    pub layers: Vec<LayerLatency>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct TableRowCount {
+    pub table: String,
+    pub rows: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct DataPruning {
+    /// All raw monitoring data older than this timestamp got removed.
+    pub cutoff: i64,
+    pub pruned_rows: i64,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct DatabaseMetricsResponse {
+    pub size_bytes: i64,
+
+    /// Space taken by the pages that got freed, but not yet reclaimed by the filesystem.
+    pub free_bytes: i64,
+    pub tables: Vec<TableRowCount>,
+
+    /// Timestamp of the oldest raw (per test run) monitoring data that is still stored.
+    pub oldest_raw_data: Option<i64>,
+    pub last_pruning: Option<DataPruning>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct SimulatedDelegatorReward {
+    pub owner: String,
+    pub amount: u128,
+    pub reward: u128,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct SimulatedNodeReward {
+    pub identity: String,
+    pub owner: String,
+    pub pledge: u128,
+    pub total_delegation: u128,
+    pub uptime: u8,
+
+    /// Always false for gateways as they do not form the active set.
+    pub in_active_set: bool,
+    pub total_node_reward: u128,
+    pub operator_reward: u128,
+    pub delegators_reward: u128,
+    pub delegator_rewards: Vec<SimulatedDelegatorReward>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct RewardingSimulationResponse {
+    pub interval_id: u32,
+    pub interval_start: i64,
+    pub interval_end: i64,
+    pub period_reward_pool: u128,
+    pub total_mixnode_rewards: u128,
+    pub total_gateway_rewards: u128,
+    pub mixnodes: Vec<SimulatedNodeReward>,
+    pub gateways: Vec<SimulatedNodeReward>,
+    pub as_at: i64,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct RewardingProgress {
+    pub total: u32,
+    pub pending: u32,
+    pub submitted: u32,
+    pub rewarded: u32,
+    pub failed: u32,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct RewardingStatusResponse {
+    pub interval_start: i64,
+    pub interval_end: i64,
+    pub finished: bool,
+    pub mixnodes: RewardingProgress,
+    pub gateways: RewardingProgress,
+}
+
+/// Granularity of the buckets the historical node data is aggregated into.
+/// Data older than the retention period of the validator api is only available in daily buckets.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryBucketing {
+    Hourly,
+    Daily,
+    // aligned with the rewarding intervals of the mixnet contract
+    Interval,
+}
+
+impl FromStr for HistoryBucketing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "hourly" => Ok(HistoryBucketing::Hourly),
+            "daily" => Ok(HistoryBucketing::Daily),
+            "interval" => Ok(HistoryBucketing::Interval),
+            other => Err(format!(
+                "{} is not a valid bucketing. Use 'hourly', 'daily' or 'interval'",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for HistoryBucketing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryBucketing::Hourly => write!(f, "hourly"),
+            HistoryBucketing::Daily => write!(f, "daily"),
+            HistoryBucketing::Interval => write!(f, "interval"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum RewardingOutcome {
+    Pending,
+    Submitted,
+    Rewarded,
+    Failed,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct NodeRewardingOutcome {
+    pub interval_start: i64,
+    pub interval_end: i64,
+    pub outcome: RewardingOutcome,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct NodeHistoryBucket {
+    pub start: i64,
+    pub end: i64,
+    // average reliability of the node, if it has been tested at all in this bucket
+    pub uptime: Option<u8>,
+    pub node_tests: u32,
+    pub monitor_runs: u32,
+    // rewarding of the intervals that have ended within this bucket
+    pub rewarding: Vec<NodeRewardingOutcome>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct NodeHistoryResponse {
+    pub identity: String,
+    pub from: i64,
+    pub to: i64,
+    pub bucketing: HistoryBucketing,
+    pub buckets: Vec<NodeHistoryBucket>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum TopologySnapshotKind {
+    ActiveSet,
+    RewardedSet,
+    Gateways,
+    LayerAssignment,
+}
+
+/// Part of the network topology as seen by the validator api after refreshing its cache
+/// at the specified block height.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TopologySnapshot<T> {
+    // included so that a signed snapshot of one kind (say, the rewarded set)
+    // couldn't be passed off as another one (say, the active set)
+    pub kind: TopologySnapshotKind,
+    pub block_height: u64,
+    pub as_at: i64,
+    pub nodes: T,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct SignedTopologyResponse {
+    /// Json-serialized `TopologySnapshot`. It's kept as a string so that the signature could be
+    /// verified on exactly the bytes that were signed.
+    pub snapshot: String,
+
+    /// Base58-encoded identity key of the validator api that signed the snapshot.
+    pub signer: String,
+
+    /// Base58-encoded signature on the `snapshot` bytes.
+    pub signature: String,
+}